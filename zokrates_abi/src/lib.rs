@@ -5,7 +5,7 @@ pub enum Inputs<T> {
     Abi(Values<T>),
 }
 
-impl<T: From<usize>> Encode<T> for Inputs<T> {
+impl<T: From<usize> + TryFrom<u128>> Encode<T> for Inputs<T> {
     fn encode(self) -> Vec<T> {
         match self {
             Inputs::Raw(v) => v,
@@ -14,8 +14,12 @@ impl<T: From<usize>> Encode<T> for Inputs<T> {
     }
 }
 
+use std::convert::TryFrom;
 use std::fmt;
-use zokrates_core::typed_absy::types::{ConcreteType, UBitwidth};
+use std::str::FromStr;
+use zokrates_core::typed_absy::types::{
+    ConcreteEnumType, ConcreteEnumVariant, ConcreteType, IntBitwidth,
+};
 
 use zokrates_field::Field;
 
@@ -42,10 +46,26 @@ pub enum Value<T> {
     U16(u16),
     U32(u32),
     U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
     Field(T),
     Boolean(bool),
     Array(Vec<Value<T>>),
     Struct(Vec<(String, Value<T>)>),
+    Tuple(Vec<Value<T>>),
+    Enum {
+        /// the index of the active variant
+        tag: usize,
+        /// the name of the active variant
+        variant: String,
+        /// the payload of the active variant
+        payload: Vec<Value<T>>,
+        /// the number of elements taken by the payloads of the variants before and after the active one
+        padding: (usize, usize),
+    },
 }
 
 #[derive(PartialEq, Debug)]
@@ -59,6 +79,11 @@ impl<T: Field> fmt::Display for Value<T> {
             Value::U16(v) => write!(f, "{:#06x}", v),
             Value::U32(v) => write!(f, "{:#010x}", v),
             Value::U64(v) => write!(f, "{:#018x}", v),
+            Value::U128(v) => write!(f, "{:#034x}", v),
+            Value::I8(v) => write!(f, "{}", v),
+            Value::I16(v) => write!(f, "{}", v),
+            Value::I32(v) => write!(f, "{}", v),
+            Value::I64(v) => write!(f, "{}", v),
             Value::Boolean(v) => write!(f, "{}", v),
             Value::Array(v) => write!(
                 f,
@@ -76,6 +101,32 @@ impl<T: Field> fmt::Display for Value<T> {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
+            Value::Tuple(v) => write!(
+                f,
+                "({}{})",
+                v.iter()
+                    .map(|v| format!("{}", v))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                if v.len() == 1 { "," } else { "" }
+            ),
+            Value::Enum {
+                variant, payload, ..
+            } => {
+                write!(f, "{}", variant)?;
+                if !payload.is_empty() {
+                    write!(
+                        f,
+                        "({})",
+                        payload
+                            .iter()
+                            .map(|v| format!("{}", v))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -84,13 +135,13 @@ pub trait Encode<T> {
     fn encode(self) -> Vec<T>;
 }
 
-pub trait Decode<T> {
+pub trait Decode<T>: Sized {
     type Expected;
 
-    fn decode(raw: Vec<T>, expected: Self::Expected) -> Self;
+    fn decode(raw: Vec<T>, expected: Self::Expected) -> Result<Self, Error>;
 }
 
-impl<T: From<usize>> Encode<T> for Value<T> {
+impl<T: From<usize> + TryFrom<u128>> Encode<T> for Value<T> {
     fn encode(self) -> Vec<T> {
         match self {
             Value::Field(t) => vec![t],
@@ -98,9 +149,26 @@ impl<T: From<usize>> Encode<T> for Value<T> {
             Value::U16(t) => vec![T::from(t as usize)],
             Value::U32(t) => vec![T::from(t as usize)],
             Value::U64(t) => vec![T::from(t as usize)],
+            Value::U128(t) => vec![T::try_from(t)
+                .unwrap_or_else(|_| unreachable!("u128 values should fit in the encoding type"))],
+            Value::I8(t) => vec![T::from(t as u8 as usize)],
+            Value::I16(t) => vec![T::from(t as u16 as usize)],
+            Value::I32(t) => vec![T::from(t as u32 as usize)],
+            Value::I64(t) => vec![T::from(t as u64 as usize)],
             Value::Boolean(b) => vec![if b { 1.into() } else { 0.into() }],
             Value::Array(a) => a.into_iter().flat_map(|v| v.encode()).collect(),
             Value::Struct(s) => s.into_iter().flat_map(|(_, v)| v.encode()).collect(),
+            Value::Tuple(t) => t.into_iter().flat_map(|v| v.encode()).collect(),
+            Value::Enum {
+                tag,
+                payload,
+                padding,
+                ..
+            } => std::iter::once(T::from(tag))
+                .chain((0..padding.0).map(|_| T::from(0)))
+                .chain(payload.into_iter().flat_map(|v| v.encode()))
+                .chain((0..padding.1).map(|_| T::from(0)))
+                .collect(),
         }
     }
 }
@@ -108,8 +176,8 @@ impl<T: From<usize>> Encode<T> for Value<T> {
 impl<T: Field> Decode<T> for Values<T> {
     type Expected = Vec<ConcreteType>;
 
-    fn decode(raw: Vec<T>, expected: Self::Expected) -> Self {
-        Values(
+    fn decode(raw: Vec<T>, expected: Self::Expected) -> Result<Self, Error> {
+        Ok(Values(
             expected
                 .into_iter()
                 .scan(0, |state, e| {
@@ -118,32 +186,47 @@ impl<T: Field> Decode<T> for Values<T> {
                     *state = new_state;
                     Some(res)
                 })
-                .collect(),
-        )
+                .collect::<Result<_, _>>()?,
+        ))
     }
 }
 
 impl<T: Field> Decode<T> for Value<T> {
     type Expected = ConcreteType;
 
-    fn decode(raw: Vec<T>, expected: Self::Expected) -> Self {
+    fn decode(raw: Vec<T>, expected: Self::Expected) -> Result<Self, Error> {
         let mut raw = raw;
 
-        match expected {
-            ConcreteType::Int => unreachable!(),
+        let value = match expected {
+            ConcreteType::Int | ConcreteType::Generic(..) => unreachable!(),
             ConcreteType::FieldElement => Value::Field(raw.pop().unwrap()),
-            ConcreteType::Uint(UBitwidth::B8) => {
+            ConcreteType::Uint(IntBitwidth::B8) => {
                 Value::U8(raw.pop().unwrap().to_dec_string().parse().unwrap())
             }
-            ConcreteType::Uint(UBitwidth::B16) => {
+            ConcreteType::Uint(IntBitwidth::B16) => {
                 Value::U16(raw.pop().unwrap().to_dec_string().parse().unwrap())
             }
-            ConcreteType::Uint(UBitwidth::B32) => {
+            ConcreteType::Uint(IntBitwidth::B32) => {
                 Value::U32(raw.pop().unwrap().to_dec_string().parse().unwrap())
             }
-            ConcreteType::Uint(UBitwidth::B64) => {
+            ConcreteType::Uint(IntBitwidth::B64) => {
                 Value::U64(raw.pop().unwrap().to_dec_string().parse().unwrap())
             }
+            ConcreteType::Uint(IntBitwidth::B128) => {
+                Value::U128(raw.pop().unwrap().to_dec_string().parse().unwrap())
+            }
+            ConcreteType::Uint(IntBitwidth::I8) => {
+                Value::I8(raw.pop().unwrap().to_dec_string().parse::<u8>().unwrap() as i8)
+            }
+            ConcreteType::Uint(IntBitwidth::I16) => {
+                Value::I16(raw.pop().unwrap().to_dec_string().parse::<u16>().unwrap() as i16)
+            }
+            ConcreteType::Uint(IntBitwidth::I32) => {
+                Value::I32(raw.pop().unwrap().to_dec_string().parse::<u32>().unwrap() as i32)
+            }
+            ConcreteType::Uint(IntBitwidth::I64) => {
+                Value::I64(raw.pop().unwrap().to_dec_string().parse::<u64>().unwrap() as i64)
+            }
             ConcreteType::Boolean => {
                 let v = raw.pop().unwrap();
                 Value::Boolean(if v == 0.into() {
@@ -157,7 +240,7 @@ impl<T: Field> Decode<T> for Value<T> {
             ConcreteType::Array(array_type) => Value::Array(
                 raw.chunks(array_type.ty.get_primitive_count())
                     .map(|c| Value::decode(c.to_vec(), *array_type.ty.clone()))
-                    .collect(),
+                    .collect::<Result<_, _>>()?,
             ),
             ConcreteType::Struct(members) => Value::Struct(
                 members
@@ -166,15 +249,85 @@ impl<T: Field> Decode<T> for Value<T> {
                         let new_state = *state + member.ty.get_primitive_count();
                         let res = Value::decode(raw[*state..new_state].to_vec(), *member.ty);
                         *state = new_state;
-                        Some((member.id, res))
+                        let id = member.id;
+                        Some(res.map(|res| (id, res)))
                     })
-                    .collect(),
+                    .collect::<Result<_, _>>()?,
             ),
-        }
+            ConcreteType::Tuple(tuple_type) => Value::Tuple(
+                tuple_type
+                    .elements
+                    .into_iter()
+                    .scan(0, |state, ty| {
+                        let new_state = *state + ty.get_primitive_count();
+                        let res = Value::decode(raw[*state..new_state].to_vec(), ty);
+                        *state = new_state;
+                        Some(res)
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            ConcreteType::Enum(enum_type) => {
+                let tag: usize = raw[0].to_dec_string().parse().map_err(|_| {
+                    Error::Conversion(format!("Invalid tag {} for enum `{}`", raw[0], enum_type))
+                })?;
+                let (padding, variant) = enum_padding(&enum_type, tag)?;
+                let offset = 1 + padding.0;
+
+                Value::Enum {
+                    tag,
+                    payload: variant
+                        .payload
+                        .elements
+                        .into_iter()
+                        .scan(offset, |state, ty| {
+                            let new_state = *state + ty.get_primitive_count();
+                            let res = Value::decode(raw[*state..new_state].to_vec(), ty);
+                            *state = new_state;
+                            Some(res)
+                        })
+                        .collect::<Result<_, _>>()?,
+                    variant: variant.id,
+                    padding,
+                }
+            }
+        };
+
+        Ok(value)
     }
 }
 
-impl<T: From<usize>> Encode<T> for Values<T> {
+/// Return the number of elements taken by the payloads of the variants before and after the variant at index `tag`, as well as this variant
+fn enum_padding(
+    enum_type: &ConcreteEnumType,
+    tag: usize,
+) -> Result<((usize, usize), ConcreteEnumVariant), Error> {
+    let count = |variants: &[ConcreteEnumVariant]| -> usize {
+        variants
+            .iter()
+            .flat_map(|v| v.payload.elements.iter())
+            .map(|ty| ty.get_primitive_count())
+            .sum()
+    };
+
+    let variant = enum_type.variants.get(tag).ok_or_else(|| {
+        Error::Conversion(format!(
+            "Invalid tag {} for enum `{}` with {} variant(s)",
+            tag,
+            enum_type,
+            enum_type.variants.len()
+        ))
+    })?;
+
+    Ok((
+        (
+            count(&enum_type.variants[..tag]),
+            count(&enum_type.variants[tag + 1..]),
+        ),
+        variant.clone(),
+    ))
+}
+
+impl<T: From<usize> + TryFrom<u128>> Encode<T> for Values<T> {
     fn encode(self) -> Vec<T> {
         self.0.into_iter().flat_map(|v| v.encode()).collect()
     }
@@ -188,6 +341,11 @@ impl<T: Field> Value<T> {
             Value::U16(u) => serde_json::Value::String(format!("{:#06x}", u)),
             Value::U32(u) => serde_json::Value::String(format!("{:#010x}", u)),
             Value::U64(u) => serde_json::Value::String(format!("{:#018x}", u)),
+            Value::U128(u) => serde_json::Value::String(format!("{:#034x}", u)),
+            Value::I8(i) => serde_json::Value::String(i.to_string()),
+            Value::I16(i) => serde_json::Value::String(i.to_string()),
+            Value::I32(i) => serde_json::Value::String(i.to_string()),
+            Value::I64(i) => serde_json::Value::String(i.to_string()),
             Value::Boolean(b) => serde_json::Value::Bool(b),
             Value::Array(a) => {
                 serde_json::Value::Array(a.into_iter().map(|e| e.into_serde_json()).collect())
@@ -197,6 +355,23 @@ impl<T: Field> Value<T> {
                     .map(|(k, v)| (k, v.into_serde_json()))
                     .collect(),
             ),
+            Value::Tuple(t) => {
+                serde_json::Value::Array(t.into_iter().map(|e| e.into_serde_json()).collect())
+            }
+            Value::Enum {
+                variant, payload, ..
+            } => match payload.is_empty() {
+                true => serde_json::Value::String(variant),
+                false => serde_json::Value::Object(
+                    std::iter::once((
+                        variant,
+                        serde_json::Value::Array(
+                            payload.into_iter().map(|e| e.into_serde_json()).collect(),
+                        ),
+                    ))
+                    .collect(),
+                ),
+            },
         }
     }
 }
@@ -207,6 +382,43 @@ impl<T: Field> Values<T> {
     }
 }
 
+fn parse_enum_value<T: Field>(
+    enum_type: &ConcreteEnumType,
+    variant: String,
+    payload: Vec<serde_json::Value>,
+) -> Result<Value<T>, Error> {
+    let (tag, _) = enum_type.variant(&variant).ok_or_else(|| {
+        Error::Type(format!(
+            "Variant `{}` not found in enum `{}`",
+            variant, enum_type
+        ))
+    })?;
+
+    let (padding, variant) = enum_padding(enum_type, tag)?;
+
+    if variant.payload.elements_count() != payload.len() {
+        return Err(Error::Type(format!(
+            "Expected {} value(s) for variant `{}`, found {}",
+            variant.payload.elements_count(),
+            variant.id,
+            payload.len()
+        )));
+    }
+
+    Ok(Value::Enum {
+        tag,
+        payload: variant
+            .payload
+            .elements
+            .into_iter()
+            .zip(payload.into_iter())
+            .map(|(ty, v)| parse_value(v, ty))
+            .collect::<Result<_, _>>()?,
+        variant: variant.id,
+        padding,
+    })
+}
+
 fn parse_value<T: Field>(
     value: serde_json::Value,
     expected_type: ConcreteType,
@@ -218,30 +430,36 @@ fn parse_value<T: Field>(
                 .map(Value::Field)
                 .map_err(|_| Error::Type(format!("Could not parse `{}` to field type", s)))
         }
-        (ConcreteType::Uint(UBitwidth::B8), serde_json::Value::String(s)) => s
+        (ConcreteType::Uint(IntBitwidth::B8), serde_json::Value::String(s)) => s
             .as_str()
             .parse::<u8>()
             .or_else(|_| u8::from_str_radix(s.as_str().trim_start_matches("0x"), 16))
             .map(Value::U8)
             .map_err(|_| Error::Type(format!("Could not parse `{}` to u8 type", s))),
-        (ConcreteType::Uint(UBitwidth::B16), serde_json::Value::String(s)) => s
+        (ConcreteType::Uint(IntBitwidth::B16), serde_json::Value::String(s)) => s
             .as_str()
             .parse::<u16>()
             .or_else(|_| u16::from_str_radix(s.as_str().trim_start_matches("0x"), 16))
             .map(Value::U16)
             .map_err(|_| Error::Type(format!("Could not parse `{}` to u16 type", s))),
-        (ConcreteType::Uint(UBitwidth::B32), serde_json::Value::String(s)) => s
+        (ConcreteType::Uint(IntBitwidth::B32), serde_json::Value::String(s)) => s
             .as_str()
             .parse::<u32>()
             .or_else(|_| u32::from_str_radix(s.as_str().trim_start_matches("0x"), 16))
             .map(Value::U32)
             .map_err(|_| Error::Type(format!("Could not parse `{}` to u32 type", s))),
-        (ConcreteType::Uint(UBitwidth::B64), serde_json::Value::String(s)) => s
+        (ConcreteType::Uint(IntBitwidth::B64), serde_json::Value::String(s)) => s
             .as_str()
             .parse::<u64>()
             .or_else(|_| u64::from_str_radix(s.as_str().trim_start_matches("0x"), 16))
             .map(Value::U64)
             .map_err(|_| Error::Type(format!("Could not parse `{}` to u64 type", s))),
+        (ConcreteType::Uint(IntBitwidth::B128), serde_json::Value::String(s)) => s
+            .as_str()
+            .parse::<u128>()
+            .or_else(|_| u128::from_str_radix(s.as_str().trim_start_matches("0x"), 16))
+            .map(Value::U128)
+            .map_err(|_| Error::Type(format!("Could not parse `{}` to u128 type", s))),
         (ConcreteType::Boolean, serde_json::Value::Bool(b)) => Ok(Value::Boolean(b)),
         (ConcreteType::Array(array_type), serde_json::Value::Array(a)) => {
             let size = array_type.size;
@@ -283,6 +501,59 @@ fn parse_value<T: Field>(
                 ))
             }
         }
+        (ConcreteType::Tuple(tuple_type), serde_json::Value::Array(a)) => {
+            let size = tuple_type.elements_count();
+            if a.len() != size {
+                Err(Error::Type(format!(
+                    "Expected tuple of size {}, found array of size {}",
+                    size,
+                    a.len()
+                )))
+            } else {
+                tuple_type
+                    .elements
+                    .iter()
+                    .zip(a.into_iter())
+                    .map(|(ty, v)| parse_value(v, ty.clone()))
+                    .collect::<Result<_, _>>()
+                    .map(Value::Tuple)
+            }
+        }
+        (ConcreteType::Enum(enum_type), serde_json::Value::String(s)) => {
+            parse_enum_value(enum_type, s, vec![])
+        }
+        (ConcreteType::Enum(enum_type), serde_json::Value::Object(o)) => {
+            if o.len() != 1 {
+                Err(Error::Type(format!(
+                    "Expected a single variant of enum `{}`, found {}",
+                    enum_type,
+                    o.len()
+                )))
+            } else {
+                let (variant, payload) = o.into_iter().next().unwrap();
+                match payload {
+                    serde_json::Value::Array(payload) => {
+                        parse_enum_value(enum_type, variant, payload)
+                    }
+                    v => Err(Error::Type(format!(
+                        "Expected the payload of variant `{}` to be an array, found `{}`",
+                        variant, v
+                    ))),
+                }
+            }
+        }
+        (ConcreteType::Uint(IntBitwidth::I8), v) => parse_signed(&v)
+            .map(Value::I8)
+            .ok_or_else(|| Error::Type(format!("Could not parse `{}` to i8 type", v))),
+        (ConcreteType::Uint(IntBitwidth::I16), v) => parse_signed(&v)
+            .map(Value::I16)
+            .ok_or_else(|| Error::Type(format!("Could not parse `{}` to i16 type", v))),
+        (ConcreteType::Uint(IntBitwidth::I32), v) => parse_signed(&v)
+            .map(Value::I32)
+            .ok_or_else(|| Error::Type(format!("Could not parse `{}` to i32 type", v))),
+        (ConcreteType::Uint(IntBitwidth::I64), v) => parse_signed(&v)
+            .map(Value::I64)
+            .ok_or_else(|| Error::Type(format!("Could not parse `{}` to i64 type", v))),
         (_, serde_json::Value::Number(n)) => Err(Error::Conversion(format!(
             "Value `{}` isn't allowed, did you mean `\"{}\"`?",
             n, n
@@ -294,6 +565,15 @@ fn parse_value<T: Field>(
     }
 }
 
+/// Parse a signed integer given either as a JSON number or as a decimal string
+fn parse_signed<I: TryFrom<i64> + FromStr>(value: &serde_json::Value) -> Option<I> {
+    match value {
+        serde_json::Value::Number(n) => n.as_i64().and_then(|n| I::try_from(n).ok()),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
 pub fn parse_strict<T: Field>(s: &str, types: Vec<ConcreteType>) -> Result<Values<T>, Error> {
     let values: serde_json::Value =
         serde_json::from_str(s).map_err(|e| Error::Json(e.to_string()))?;
@@ -332,10 +612,25 @@ pub fn parse_strict_json<T: Field>(
 mod tests {
     use super::*;
     use zokrates_core::typed_absy::types::{
-        ConcreteStructMember, ConcreteStructType, ConcreteType,
+        ConcreteStructMember, ConcreteStructType, ConcreteTupleType, ConcreteType,
     };
     use zokrates_field::Bn128Field;
 
+    fn shape() -> ConcreteType {
+        ConcreteType::Enum(ConcreteEnumType::new(
+            "".into(),
+            "Shape".into(),
+            vec![
+                ConcreteEnumVariant::new("Empty".into(), vec![]),
+                ConcreteEnumVariant::new("Circle".into(), vec![ConcreteType::FieldElement]),
+                ConcreteEnumVariant::new(
+                    "Rectangle".into(),
+                    vec![ConcreteType::FieldElement, ConcreteType::Boolean],
+                ),
+            ],
+        ))
+    }
+
     #[test]
     fn numbers() {
         let s = "[1, 2]";
@@ -371,10 +666,10 @@ mod tests {
             parse_strict::<Bn128Field>(
                 s,
                 vec![
-                    ConcreteType::Uint(UBitwidth::B8),
-                    ConcreteType::Uint(UBitwidth::B16),
-                    ConcreteType::Uint(UBitwidth::B32),
-                    ConcreteType::Uint(UBitwidth::B64)
+                    ConcreteType::Uint(IntBitwidth::B8),
+                    ConcreteType::Uint(IntBitwidth::B16),
+                    ConcreteType::Uint(IntBitwidth::B32),
+                    ConcreteType::Uint(IntBitwidth::B64)
                 ]
             )
             .unwrap(),
@@ -388,17 +683,74 @@ mod tests {
 
         let s = r#"["0x1234"]"#;
         assert_eq!(
-            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(UBitwidth::B32)]).unwrap(),
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(IntBitwidth::B32)]).unwrap(),
             Values(vec![Value::U32(4660u32)])
         );
 
         let s = r#"["0x1234"]"#;
         assert_eq!(
-            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(UBitwidth::B8)]).unwrap_err(),
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(IntBitwidth::B8)]).unwrap_err(),
             Error::Type("Could not parse `0x1234` to u8 type".into())
         );
     }
 
+    #[test]
+    fn u128s() {
+        let s =
+            r#"["0x12345678123456781234567812345678", "340282366920938463463374607431768211455"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(
+                s,
+                vec![
+                    ConcreteType::Uint(IntBitwidth::B128),
+                    ConcreteType::Uint(IntBitwidth::B128)
+                ]
+            )
+            .unwrap(),
+            Values(vec![
+                Value::U128(0x12345678123456781234567812345678),
+                Value::U128(u128::MAX)
+            ])
+        );
+
+        let s = r#"["0x123456781234567812345678123456780"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(IntBitwidth::B128)]).unwrap_err(),
+            Error::Type(
+                "Could not parse `0x123456781234567812345678123456780` to u128 type".into()
+            )
+        );
+    }
+
+    #[test]
+    fn ints() {
+        let s = r#"[-18, "-4660", 305419896, "-1311768465173141112"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(
+                s,
+                vec![
+                    ConcreteType::Uint(IntBitwidth::I8),
+                    ConcreteType::Uint(IntBitwidth::I16),
+                    ConcreteType::Uint(IntBitwidth::I32),
+                    ConcreteType::Uint(IntBitwidth::I64)
+                ]
+            )
+            .unwrap(),
+            Values(vec![
+                Value::I8(-18i8),
+                Value::I16(-4660i16),
+                Value::I32(305419896i32),
+                Value::I64(-1311768465173141112i64)
+            ])
+        );
+
+        let s = r#"[-129]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(IntBitwidth::I8)]).unwrap_err(),
+            Error::Type("Could not parse `-129` to i8 type".into())
+        );
+    }
+
     #[test]
     fn bools() {
         let s = "[true, false]";
@@ -504,6 +856,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tuple() {
+        let s = r#"[["42", true]]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(
+                s,
+                vec![ConcreteType::Tuple(ConcreteTupleType::new(vec![
+                    ConcreteType::FieldElement,
+                    ConcreteType::Boolean
+                ]))]
+            )
+            .unwrap(),
+            Values(vec![Value::Tuple(vec![
+                Value::Field(42.into()),
+                Value::Boolean(true)
+            ])])
+        );
+
+        let s = r#"[["42"]]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(
+                s,
+                vec![ConcreteType::Tuple(ConcreteTupleType::new(vec![
+                    ConcreteType::FieldElement,
+                    ConcreteType::Boolean
+                ]))]
+            )
+            .unwrap_err(),
+            Error::Type("Expected tuple of size 2, found array of size 1".into())
+        );
+    }
+
+    #[test]
+    fn enumeration() {
+        let s = r#"[{"Circle": ["42"]}, "Empty"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![shape(), shape()]).unwrap(),
+            Values(vec![
+                Value::Enum {
+                    tag: 1,
+                    variant: "Circle".into(),
+                    payload: vec![Value::Field(42.into())],
+                    padding: (0, 2)
+                },
+                Value::Enum {
+                    tag: 0,
+                    variant: "Empty".into(),
+                    payload: vec![],
+                    padding: (0, 3)
+                }
+            ])
+        );
+
+        let s = r#"[{"Triangle": []}]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![shape()]).unwrap_err(),
+            Error::Type("Variant `Triangle` not found in enum `Shape`".into())
+        );
+
+        let s = r#"[{"Rectangle": ["42"]}]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![shape()]).unwrap_err(),
+            Error::Type("Expected 2 value(s) for variant `Rectangle`, found 1".into())
+        );
+    }
+
     #[test]
     fn into_serde() {
         let values = Values::<Bn128Field>(vec![
@@ -555,6 +973,12 @@ mod tests {
             assert_eq!(v.encode(), vec![1, 2]);
         }
 
+        #[test]
+        fn i8s() {
+            let v = Values::<usize>(vec![Value::I8(1), Value::I8(-2)]);
+            assert_eq!(v.encode(), vec![1, 254]);
+        }
+
         #[test]
         fn bools() {
             let v: Values<usize> = Values(vec![Value::Boolean(true), Value::Boolean(false)]);
@@ -579,5 +1003,85 @@ mod tests {
             )]);
             assert_eq!(v.encode(), vec![42]);
         }
+
+        #[test]
+        fn tuple() {
+            let v: Values<usize> = Values(vec![Value::Tuple(vec![
+                Value::Field(42),
+                Value::Boolean(true),
+            ])]);
+            assert_eq!(v.encode(), vec![42, 1]);
+        }
+
+        #[test]
+        fn enumeration() {
+            let v: Values<usize> = Values(vec![Value::Enum {
+                tag: 1,
+                variant: "Circle".into(),
+                payload: vec![Value::Field(42)],
+                padding: (0, 2),
+            }]);
+            assert_eq!(v.encode(), vec![1, 42, 0, 0]);
+        }
+    }
+
+    mod decode {
+        use super::*;
+
+        #[test]
+        fn u128s() {
+            let v = Values::<Bn128Field>::decode(
+                vec![u128::MAX.into()],
+                vec![ConcreteType::Uint(IntBitwidth::B128)],
+            )
+            .unwrap();
+            assert_eq!(v, Values(vec![Value::U128(u128::MAX)]));
+            assert_eq!(v.encode(), vec![Bn128Field::from(u128::MAX)]);
+        }
+
+        #[test]
+        fn i32s() {
+            let v = Values::<Bn128Field>::decode(
+                vec![7.into(), 4294967289u32.into()],
+                vec![
+                    ConcreteType::Uint(IntBitwidth::I32),
+                    ConcreteType::Uint(IntBitwidth::I32),
+                ],
+            )
+            .unwrap();
+            assert_eq!(v, Values(vec![Value::I32(7), Value::I32(-7)]));
+        }
+
+        #[test]
+        fn enumeration() {
+            let v = Values::<Bn128Field>::decode(
+                vec![2.into(), 0.into(), 42.into(), 1.into()],
+                vec![shape()],
+            )
+            .unwrap();
+            assert_eq!(
+                v,
+                Values(vec![Value::Enum {
+                    tag: 2,
+                    variant: "Rectangle".into(),
+                    payload: vec![Value::Field(42.into()), Value::Boolean(true)],
+                    padding: (1, 0)
+                }])
+            );
+        }
+
+        #[test]
+        fn enumeration_invalid_tag() {
+            let v = Values::<Bn128Field>::decode(
+                vec![3.into(), 0.into(), 42.into(), 1.into()],
+                vec![shape()],
+            );
+            assert_eq!(
+                v.unwrap_err(),
+                Error::Conversion(String::from(
+                    "Invalid tag 3 for enum `Shape` with 3 variant(s)"
+                ))
+            );
+        }
     }
 }
@@ -22,8 +22,9 @@ fn main() {
 
     env_logger::init();
 
+    // errors go to the standard error, which keeps the standard output parseable with `--message-format json`
     cli().unwrap_or_else(|e| {
-        println!("{}", e);
+        eprintln!("{}", e);
         std::process::exit(1);
     })
 }
@@ -44,6 +45,7 @@ fn cli() -> Result<(), String> {
         .subcommands(vec![
             compile::subcommand(),
             check::subcommand(),
+            fmt::subcommand(),
             compute_witness::subcommand(),
             #[cfg(feature = "ark")]
             universal_setup::subcommand(),
@@ -54,6 +56,7 @@ fn cli() -> Result<(), String> {
             generate_proof::subcommand(),
             generate_smtlib2::subcommand(),
             print_proof::subcommand(),
+            profile::subcommand(),
             #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
             verify::subcommand()])
         .get_matches();
@@ -61,6 +64,7 @@ fn cli() -> Result<(), String> {
     match matches.subcommand() {
         ("compile", Some(sub_matches)) => compile::exec(sub_matches),
         ("check", Some(sub_matches)) => check::exec(sub_matches),
+        ("fmt", Some(sub_matches)) => fmt::exec(sub_matches),
         ("compute-witness", Some(sub_matches)) => compute_witness::exec(sub_matches),
         #[cfg(feature = "ark")]
         ("universal-setup", Some(sub_matches)) => universal_setup::exec(sub_matches),
@@ -71,6 +75,7 @@ fn cli() -> Result<(), String> {
         ("generate-proof", Some(sub_matches)) => generate_proof::exec(sub_matches),
         ("generate-smtlib2", Some(sub_matches)) => generate_smtlib2::exec(sub_matches),
         ("print-proof", Some(sub_matches)) => print_proof::exec(sub_matches),
+        ("profile", Some(sub_matches)) => profile::exec(sub_matches),
         #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
         ("verify", Some(sub_matches)) => verify::exec(sub_matches),
         _ => unreachable!(),
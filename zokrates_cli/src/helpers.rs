@@ -1,5 +1,8 @@
 use crate::constants::*;
 use std::convert::TryFrom;
+use std::path::Path;
+use zokrates_core::compile::CompileError;
+use zokrates_core::diagnostics::Diagnostic;
 
 #[derive(Debug)]
 pub enum CurveParameter {
@@ -115,3 +118,54 @@ impl TryFrom<(&str, &str, &str)> for Parameters {
         }.map(|_: ()| Parameters(backend, curve, proving_scheme))
     }
 }
+
+/// Render a diagnostic of the compiler in the given message format, with its file relative to the current directory.
+/// In the human readable format, the excerpt of the source it points to is included if the file can be read
+pub fn render_diagnostic(diagnostic: Diagnostic, message_format: &str) -> String {
+    let source = std::fs::read_to_string(&diagnostic.file).ok();
+
+    let file = diagnostic
+        .file
+        .canonicalize()
+        .unwrap_or_else(|_| diagnostic.file.clone());
+    let file = file
+        .strip_prefix(std::env::current_dir().unwrap())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|_| file.clone());
+
+    let diagnostic = Diagnostic { file, ..diagnostic };
+
+    match message_format {
+        "json" => serde_json::to_string(&diagnostic).unwrap(),
+        _ => diagnostic.render(source.as_deref()),
+    }
+}
+
+/// Report the errors of a failed compilation in the given message format, returning the message to fail with.
+/// In the JSON format, the diagnostics are printed to the standard output one per line and the message is only a summary
+pub fn report_errors(errors: &[CompileError], message_format: &str, summary: &str) -> String {
+    let diagnostics = errors
+        .iter()
+        .map(|e| render_diagnostic(Diagnostic::from(e), message_format));
+
+    match message_format {
+        "json" => {
+            diagnostics.for_each(|d| println!("{}", d));
+            format!("{}: {} error(s) found", summary, errors.len())
+        }
+        _ => format!(
+            "{}:\n\n{}",
+            summary,
+            diagnostics.collect::<Vec<_>>().join("\n")
+        ),
+    }
+}
+
+/// Print a message about the progress of a command. In the JSON format, it is printed to the standard error so that
+/// the standard output only contains diagnostics
+pub fn report_progress(message: &str, message_format: &str) {
+    match message_format {
+        "json" => eprintln!("{}", message),
+        _ => println!("{}", message),
+    }
+}
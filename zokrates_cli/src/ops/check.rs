@@ -1,11 +1,12 @@
 use crate::constants;
-use crate::helpers::CurveParameter;
+use crate::helpers::{render_diagnostic, report_errors, report_progress, CurveParameter};
 use clap::{App, Arg, ArgMatches, SubCommand};
 use std::convert::TryFrom;
 use std::fs::File;
 use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
-use zokrates_core::compile::{check, CompileConfig, CompileError};
+use zokrates_core::compile::{check, CompileConfig};
+use zokrates_core::diagnostics::Diagnostic;
 use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Bw6_761Field, Field};
 use zokrates_fs_resolver::FileSystemResolver;
 
@@ -46,6 +47,21 @@ pub fn subcommand() -> App<'static, 'static> {
             .help("Isolate the execution of branches: a panic in a branch only makes the program panic if this branch is being logically executed")
             .required(false)
         )
+        .arg(
+            Arg::with_name("deny-warnings")
+                .long("deny-warnings")
+                .help("Fail if the program triggers any warning")
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("message-format")
+                .long("message-format")
+                .help("Format of the errors and warnings: human readable with source excerpts, or one JSON object per line")
+                .takes_value(true)
+                .required(false)
+                .possible_values(&["human", "json"])
+                .default_value("human"),
+        )
 }
 
 pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
@@ -59,7 +75,12 @@ pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
 }
 
 fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
-    println!("Checking {}\n", sub_matches.value_of("input").unwrap());
+    let message_format = sub_matches.value_of("message-format").unwrap();
+
+    report_progress(
+        &format!("Checking {}\n", sub_matches.value_of("input").unwrap()),
+        message_format,
+    );
     let path = PathBuf::from(sub_matches.value_of("input").unwrap());
 
     let file = File::open(path.clone())
@@ -69,17 +90,6 @@ fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
     let mut source = String::new();
     reader.read_to_string(&mut source).unwrap();
 
-    let fmt_error = |e: &CompileError| {
-        let file = e.file().canonicalize().unwrap();
-        format!(
-            "{}:{}",
-            file.strip_prefix(std::env::current_dir().unwrap())
-                .unwrap_or_else(|_| file.as_path())
-                .display(),
-            e.value()
-        )
-    };
-
     let stdlib_path = sub_matches.value_of("stdlib-path").unwrap();
     match Path::new(stdlib_path).exists() {
         true => Ok(()),
@@ -93,17 +103,26 @@ fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
         CompileConfig::default().isolate_branches(sub_matches.is_present("isolate-branches"));
 
     let resolver = FileSystemResolver::with_stdlib_root(stdlib_path);
-    let _ = check::<T, _>(source, path, Some(&resolver), &config).map_err(|e| {
-        format!(
-            "Check failed:\n\n{}",
-            e.0.iter()
-                .map(|e| fmt_error(e))
-                .collect::<Vec<_>>()
-                .join("\n\n")
-        )
-    })?;
+    let warnings = check::<T, _>(source, path, Some(&resolver), &config)
+        .map_err(|e| report_errors(&e.0, message_format, "Check failed"))?;
+
+    // in the JSON format, all diagnostics are printed to the standard output for tools to consume
+    for warning in &warnings {
+        let warning = render_diagnostic(Diagnostic::from(warning), message_format);
+        match message_format {
+            "json" => println!("{}", warning),
+            _ => eprintln!("{}", warning),
+        }
+    }
+
+    if sub_matches.is_present("deny-warnings") && !warnings.is_empty() {
+        return Err(format!(
+            "Check failed: {} warning(s) found with --deny-warnings",
+            warnings.len()
+        ));
+    }
 
-    println!("Program checked, no errors found.");
+    report_progress("Program checked, no errors found.", message_format);
 
     Ok(())
 }
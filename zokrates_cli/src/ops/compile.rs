@@ -1,12 +1,13 @@
 use crate::constants;
-use crate::helpers::CurveParameter;
+use crate::helpers::{render_diagnostic, report_errors, report_progress, CurveParameter};
 use clap::{App, Arg, ArgMatches, SubCommand};
 use serde_json::to_writer_pretty;
 use std::convert::TryFrom;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
-use zokrates_core::compile::{compile, CompilationArtifacts, CompileConfig, CompileError};
+use zokrates_core::compile::{compile, CompilationArtifacts, CompileConfig};
+use zokrates_core::diagnostics::Diagnostic;
 use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Bw6_761Field, Field};
 use zokrates_fs_resolver::FileSystemResolver;
 
@@ -60,6 +61,17 @@ pub fn subcommand() -> App<'static, 'static> {
         .long("isolate-branches")
         .help("Isolate the execution of branches: a panic in a branch only makes the program panic if this branch is being logically executed")
         .required(false)
+    ).arg(Arg::with_name("deny-warnings")
+        .long("deny-warnings")
+        .help("Fail if the program triggers any warning")
+        .required(false)
+    ).arg(Arg::with_name("message-format")
+        .long("message-format")
+        .help("Format of the errors and warnings: human readable with source excerpts, or one JSON object per line")
+        .takes_value(true)
+        .required(false)
+        .possible_values(&["human", "json"])
+        .default_value("human")
     ).arg(Arg::with_name("ztf")
         .long("ztf")
         .help("Write human readable output (ztf)")
@@ -83,41 +95,22 @@ pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
     }
 }
 
-fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
-    // TODO: remove the warning once light flag is removed entirely
-    if sub_matches.is_present("light") {
-        println!(
-            "Warning: the --light flag is deprecated and will be removed in a coming release.\n\
-            Terminal output is now off by default and can be activated with the --verbose flag.\n\
-            Human-readable output file (ztf) is now off by default and can be activated with the --ztf flag.\n"
-        )
-    }
-
-    println!("Compiling {}\n", sub_matches.value_of("input").unwrap());
-    let path = PathBuf::from(sub_matches.value_of("input").unwrap());
-    let bin_output_path = Path::new(sub_matches.value_of("output").unwrap());
-    let abi_spec_path = Path::new(sub_matches.value_of("abi-spec").unwrap());
-    let hr_output_path = bin_output_path.to_path_buf().with_extension("ztf");
-
+/// Compile the program at `path` with the stdlib path and the compilation flags found in `sub_matches`
+pub(crate) fn compile_input<T: Field>(
+    sub_matches: &ArgMatches,
+    path: &Path,
+) -> Result<CompilationArtifacts<T>, String> {
     log::debug!("Load entry point file {}", path.display());
 
-    let file = File::open(path.clone())
-        .map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+    let file =
+        File::open(path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
 
     let mut reader = BufReader::new(file);
     let mut source = String::new();
     reader.read_to_string(&mut source).unwrap();
 
-    let fmt_error = |e: &CompileError| {
-        let file = e.file().canonicalize().unwrap();
-        format!(
-            "{}:{}",
-            file.strip_prefix(std::env::current_dir().unwrap())
-                .unwrap_or_else(|_| file.as_path())
-                .display(),
-            e.value()
-        )
-    };
+    // this function is shared with other subcommands, which do not necessarily have a message format option
+    let message_format = sub_matches.value_of("message-format").unwrap_or("human");
 
     let stdlib_path = sub_matches.value_of("stdlib-path").unwrap();
     match Path::new(stdlib_path).exists() {
@@ -136,16 +129,50 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     log::debug!("Compile");
 
-    let artifacts: CompilationArtifacts<T> = compile(source, path, Some(&resolver), &config)
-        .map_err(|e| {
-            format!(
-                "Compilation failed:\n\n{}",
-                e.0.iter()
-                    .map(|e| fmt_error(e))
-                    .collect::<Vec<_>>()
-                    .join("\n\n")
-            )
-        })?;
+    let artifacts = compile(source, path.to_path_buf(), Some(&resolver), &config)
+        .map_err(|e| report_errors(&e.0, message_format, "Compilation failed"))?;
+
+    // in the JSON format, all diagnostics are printed to the standard output for tools to consume
+    for warning in artifacts.warnings() {
+        let warning = render_diagnostic(Diagnostic::from(warning), message_format);
+        match message_format {
+            "json" => println!("{}", warning),
+            _ => eprintln!("{}", warning),
+        }
+    }
+
+    match sub_matches.is_present("deny-warnings") && !artifacts.warnings().is_empty() {
+        true => Err(format!(
+            "Compilation failed: {} warning(s) found with --deny-warnings",
+            artifacts.warnings().len()
+        )),
+        false => Ok(artifacts),
+    }
+}
+
+fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
+    let message_format = sub_matches.value_of("message-format").unwrap();
+
+    // TODO: remove the warning once light flag is removed entirely
+    if sub_matches.is_present("light") {
+        report_progress(
+            "Warning: the --light flag is deprecated and will be removed in a coming release.\n\
+            Terminal output is now off by default and can be activated with the --verbose flag.\n\
+            Human-readable output file (ztf) is now off by default and can be activated with the --ztf flag.\n",
+            message_format,
+        )
+    }
+
+    report_progress(
+        &format!("Compiling {}\n", sub_matches.value_of("input").unwrap()),
+        message_format,
+    );
+    let path = PathBuf::from(sub_matches.value_of("input").unwrap());
+    let bin_output_path = Path::new(sub_matches.value_of("output").unwrap());
+    let abi_spec_path = Path::new(sub_matches.value_of("abi-spec").unwrap());
+    let hr_output_path = bin_output_path.to_path_buf().with_extension("ztf");
+
+    let artifacts: CompilationArtifacts<T> = compile_input(sub_matches, &path)?;
 
     let program_flattened = artifacts.prog();
 
@@ -173,10 +200,16 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     if sub_matches.is_present("verbose") {
         // debugging output
-        println!("Compiled program:\n{}", program_flattened);
+        report_progress(
+            &format!("Compiled program:\n{}", program_flattened),
+            message_format,
+        );
     }
 
-    println!("Compiled code written to '{}'", bin_output_path.display());
+    report_progress(
+        &format!("Compiled code written to '{}'", bin_output_path.display()),
+        message_format,
+    );
 
     if sub_matches.is_present("ztf") {
         // write human-readable output file
@@ -191,9 +224,15 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
             .flush()
             .map_err(|_| "Unable to flush buffer".to_string())?;
 
-        println!("Human readable code to '{}'", hr_output_path.display());
+        report_progress(
+            &format!("Human readable code to '{}'", hr_output_path.display()),
+            message_format,
+        );
     }
 
-    println!("Number of constraints: {}", num_constraints);
+    report_progress(
+        &format!("Number of constraints: {}", num_constraints),
+        message_format,
+    );
     Ok(())
 }
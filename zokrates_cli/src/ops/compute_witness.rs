@@ -168,7 +168,9 @@ fn cli_compute<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Resu
     use zokrates_abi::Decode;
 
     let results_json_value: serde_json::Value =
-        zokrates_abi::Values::decode(witness.return_values(), signature.outputs).into_serde_json();
+        zokrates_abi::Values::decode(witness.return_values(), signature.outputs)
+            .map_err(|e| format!("Could not decode the return values: {}", e))?
+            .into_serde_json();
 
     if verbose {
         println!("\nWitness: \n{}\n", results_json_value);
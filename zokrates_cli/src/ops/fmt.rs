@@ -0,0 +1,108 @@
+use crate::helpers::render_diagnostic;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs;
+use std::path::{Path, PathBuf};
+use zokrates_core::compile::CompileErrorInner;
+use zokrates_core::diagnostics::Diagnostic;
+use zokrates_core::format::format;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("fmt")
+        .about("Formats programs in the canonical style")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Paths of the source files, or of directories to format all the source files of")
+                .value_name("PATH")
+                .takes_value(true)
+                .multiple(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("check")
+                .long("check")
+                .help("Do not write the files, and fail if any of them is not formatted")
+                .required(false),
+        )
+}
+
+/// Find the source files at `path`, looking into directories recursively
+fn sources(path: &Path) -> Result<Vec<PathBuf>, String> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut res = vec![];
+
+    for entry in fs::read_dir(path)
+        .map_err(|why| format!("Could not read directory {}: {}", path.display(), why))?
+    {
+        let path = entry.map_err(|why| why.to_string())?.path();
+
+        if path.is_dir() {
+            res.extend(sources(&path)?);
+        } else if path.extension().map(|e| e == "zok").unwrap_or(false) {
+            res.push(path);
+        }
+    }
+
+    res.sort();
+    Ok(res)
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let check = sub_matches.is_present("check");
+
+    let mut paths = vec![];
+    for input in sub_matches.values_of("input").unwrap() {
+        paths.extend(sources(Path::new(input))?);
+    }
+
+    let mut invalid = 0;
+    let mut unformatted = 0;
+
+    for path in paths {
+        let source = fs::read_to_string(&path)
+            .map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+        let formatted = match format(&source) {
+            Ok(formatted) => formatted,
+            Err(e) => {
+                let error = CompileErrorInner::from(e).in_file(&path);
+                eprintln!("{}", render_diagnostic(Diagnostic::from(&error), "human"));
+                invalid += 1;
+                continue;
+            }
+        };
+
+        if formatted == source {
+            continue;
+        }
+
+        if check {
+            println!("{} is not formatted", path.display());
+            unformatted += 1;
+        } else {
+            fs::write(&path, formatted)
+                .map_err(|why| format!("Could not write {}: {}", path.display(), why))?;
+            println!("Formatted {}", path.display());
+        }
+    }
+
+    if invalid > 0 {
+        return Err(format!(
+            "Formatting failed: {} file(s) could not be parsed",
+            invalid
+        ));
+    }
+
+    if unformatted > 0 {
+        return Err(format!(
+            "Check failed: {} file(s) are not formatted",
+            unformatted
+        ));
+    }
+
+    Ok(())
+}
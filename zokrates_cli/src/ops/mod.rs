@@ -2,10 +2,12 @@ pub mod check;
 pub mod compile;
 pub mod compute_witness;
 pub mod export_verifier;
+pub mod fmt;
 #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
 pub mod generate_proof;
 pub mod generate_smtlib2;
 pub mod print_proof;
+pub mod profile;
 #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
 pub mod setup;
 #[cfg(feature = "ark")]
@@ -0,0 +1,94 @@
+use crate::constants;
+use crate::helpers::CurveParameter;
+use crate::ops::compile::compile_input;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use serde_json::to_string_pretty;
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use zokrates_core::compile::CompilationArtifacts;
+use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Bw6_761Field, Field};
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("profile")
+        .about("Compiles a program and reports the number of constraints and directives per function, call site and line")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the source code")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("stdlib-path")
+                .long("stdlib-path")
+                .help("Path to the standard library")
+                .value_name("PATH")
+                .takes_value(true)
+                .required(false)
+                .env("ZOKRATES_STDLIB")
+                .default_value(constants::DEFAULT_STDLIB_PATH.as_str()),
+        )
+        .arg(
+            Arg::with_name("curve")
+                .short("c")
+                .long("curve")
+                .help("Curve to be used in the compilation")
+                .takes_value(true)
+                .required(false)
+                .possible_values(constants::CURVES)
+                .default_value(constants::BN128),
+        )
+        .arg(
+            Arg::with_name("format")
+                .short("f")
+                .long("format")
+                .help("Format of the report: a human readable table, JSON, or folded stacks for flamegraph tools")
+                .takes_value(true)
+                .required(false)
+                .possible_values(&["table", "json", "folded"])
+                .default_value("table"),
+        )
+        .arg(
+            Arg::with_name("allow-unconstrained-variables")
+                .long("allow-unconstrained-variables")
+                .help("Allow unconstrained variables by inserting dummy constraints")
+                .required(false),
+        )
+        .arg(Arg::with_name("isolate-branches")
+            .long("isolate-branches")
+            .help("Isolate the execution of branches: a panic in a branch only makes the program panic if this branch is being logically executed")
+            .required(false)
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let curve = CurveParameter::try_from(sub_matches.value_of("curve").unwrap())?;
+    match curve {
+        CurveParameter::Bn128 => cli_profile::<Bn128Field>(sub_matches),
+        CurveParameter::Bls12_377 => cli_profile::<Bls12_377Field>(sub_matches),
+        CurveParameter::Bls12_381 => cli_profile::<Bls12_381Field>(sub_matches),
+        CurveParameter::Bw6_761 => cli_profile::<Bw6_761Field>(sub_matches),
+    }
+}
+
+fn cli_profile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
+    let path = PathBuf::from(sub_matches.value_of("input").unwrap());
+
+    let artifacts: CompilationArtifacts<T> = compile_input(sub_matches, &path)?;
+
+    let profile = artifacts.prog().profile();
+
+    match sub_matches.value_of("format").unwrap() {
+        "json" => println!(
+            "{}",
+            to_string_pretty(&profile)
+                .map_err(|why| format!("Could not serialize profile: {}", why))?
+        ),
+        "folded" => print!("{}", profile.folded()),
+        _ => print!("{}", profile),
+    }
+
+    Ok(())
+}
@@ -447,4 +447,90 @@ mod integration {
         .succeeds()
         .unwrap();
     }
+
+    #[test]
+    #[ignore]
+    fn test_compile_stdlib_import_without_warnings() {
+        // the constant conditions of the standard library are not reported as warnings of the program importing it
+        let tmp_dir = TempDir::new(".tmp").unwrap();
+        let tmp_base = tmp_dir.path();
+        let program_path = tmp_base.join("main.zok");
+
+        fs::write(
+            &program_path,
+            r#"import "utils/pack/bool/unpack"
+
+def main(field a) -> bool[8]:
+    return unpack::<8>(a)
+"#,
+        )
+        .unwrap();
+
+        let stdlib = std::fs::canonicalize("../zokrates_stdlib/stdlib").unwrap();
+
+        assert_cli::Assert::command(&[
+            env!("CARGO_BIN_EXE_zokrates"),
+            "compile",
+            "-i",
+            program_path.to_str().unwrap(),
+            "--stdlib-path",
+            stdlib.to_str().unwrap(),
+            "-o",
+            tmp_base.join("out").to_str().unwrap(),
+            "-s",
+            tmp_base.join("abi.json").to_str().unwrap(),
+            "--deny-warnings",
+        ])
+        .succeeds()
+        .unwrap();
+    }
+
+    #[test]
+    #[ignore]
+    fn test_check_json_message_format() {
+        // in the JSON format, the diagnostics are the only output on the standard output
+        let tmp_dir = TempDir::new(".tmp").unwrap();
+        let program_path = tmp_dir.path().join("main.zok");
+
+        fs::write(
+            &program_path,
+            r#"def main() -> field:
+    return a + b
+"#,
+        )
+        .unwrap();
+
+        let stdlib = std::fs::canonicalize("../zokrates_stdlib/stdlib").unwrap();
+
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_zokrates"))
+            .args(&[
+                "check",
+                "-i",
+                program_path.to_str().unwrap(),
+                "--stdlib-path",
+                stdlib.to_str().unwrap(),
+                "--message-format",
+                "json",
+            ])
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let diagnostics: Vec<serde_json::Value> = stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert!(!diagnostics.is_empty());
+        assert!(diagnostics
+            .iter()
+            .all(|d| d["severity"] == serde_json::json!("error")));
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.starts_with("Checking "));
+        assert!(stderr.contains("Check failed: "));
+    }
 }
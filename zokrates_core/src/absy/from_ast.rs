@@ -6,12 +6,23 @@ use zokrates_pest_ast as pest;
 
 impl<'ast> From<pest::File<'ast>> for absy::Module<'ast> {
     fn from(file: pest::File<'ast>) -> absy::Module<'ast> {
-        absy::Module::with_symbols(file.declarations.into_iter().flat_map(|d| match d {
-            pest::SymbolDeclaration::Import(i) => import_directive_to_symbol_vec(i),
-            pest::SymbolDeclaration::Constant(c) => vec![c.into()],
-            pest::SymbolDeclaration::Struct(s) => vec![s.into()],
-            pest::SymbolDeclaration::Function(f) => vec![f.into()],
-        }))
+        let immutable_by_default = file
+            .pragmas
+            .iter()
+            .any(|p| matches!(p, pest::Pragma::ImmutableByDefault(..)));
+
+        absy::Module {
+            immutable_by_default,
+            ..absy::Module::with_symbols(file.declarations.into_iter().flat_map(|d| match d {
+                pest::SymbolDeclaration::Import(i) => import_directive_to_symbol_vec(i),
+                pest::SymbolDeclaration::Constant(c) => vec![c.into()],
+                pest::SymbolDeclaration::Struct(s) => vec![s.into()],
+                pest::SymbolDeclaration::Enum(e) => vec![e.into()],
+                pest::SymbolDeclaration::Type(t) => vec![t.into()],
+                pest::SymbolDeclaration::Function(f) => vec![f.into()],
+                pest::SymbolDeclaration::Impl(i) => vec![i.into()],
+            }))
+        }
     }
 }
 
@@ -34,6 +45,7 @@ fn import_directive_to_symbol_vec(
             .span(span.clone());
 
             vec![absy::SymbolDeclaration {
+                public: false,
                 id: alias.unwrap_or(id),
                 symbol: absy::Symbol::Here(absy::SymbolDefinition::Import(import)),
             }
@@ -42,6 +54,23 @@ fn import_directive_to_symbol_vec(
         pest::ImportDirective::From(import) => {
             let span = import.span;
             let source = Path::new(import.source.span.as_str());
+            let public = import.public.is_some();
+
+            if import.glob.is_some() {
+                let import = absy::CanonicalImport {
+                    source,
+                    id: absy::SymbolIdentifier::from(absy::GLOB_IMPORT_ID),
+                }
+                .span(span.clone());
+
+                return vec![absy::SymbolDeclaration {
+                    public,
+                    id: absy::GLOB_IMPORT_ID,
+                    symbol: absy::Symbol::Here(absy::SymbolDefinition::Import(import)),
+                }
+                .span(span)];
+            }
+
             import
                 .symbols
                 .into_iter()
@@ -60,6 +89,7 @@ fn import_directive_to_symbol_vec(
                     .span(span.clone());
 
                     absy::SymbolDeclaration {
+                        public,
                         id: alias,
                         symbol: absy::Symbol::Here(absy::SymbolDefinition::Import(import)),
                     }
@@ -77,12 +107,13 @@ impl<'ast> From<pest::StructDefinition<'ast>> for absy::SymbolDeclarationNode<'a
         let span = definition.span;
 
         let id = definition.id.span.as_str();
+        let public = definition.public.is_some();
 
         let ty = absy::StructDefinition {
             generics: definition
                 .generics
                 .into_iter()
-                .map(absy::ConstantGenericNode::from)
+                .map(absy::GenericDeclarationNode::from)
                 .collect(),
             fields: definition
                 .fields
@@ -93,6 +124,7 @@ impl<'ast> From<pest::StructDefinition<'ast>> for absy::SymbolDeclarationNode<'a
         .span(span.clone());
 
         absy::SymbolDeclaration {
+            public,
             id,
             symbol: absy::Symbol::Here(absy::SymbolDefinition::Struct(ty)),
         }
@@ -114,20 +146,68 @@ impl<'ast> From<pest::StructField<'ast>> for absy::StructDefinitionFieldNode<'as
     }
 }
 
+impl<'ast> From<pest::EnumDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::EnumDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let span = definition.span;
+
+        let id = definition.id.span.as_str();
+        let public = definition.public.is_some();
+
+        let ty = absy::EnumDefinition {
+            variants: definition
+                .variants
+                .into_iter()
+                .map(absy::EnumDefinitionVariantNode::from)
+                .collect(),
+        }
+        .span(span.clone());
+
+        absy::SymbolDeclaration {
+            public,
+            id,
+            symbol: absy::Symbol::Here(absy::SymbolDefinition::Enum(ty)),
+        }
+        .span(span)
+    }
+}
+
+impl<'ast> From<pest::EnumVariant<'ast>> for absy::EnumDefinitionVariantNode<'ast> {
+    fn from(variant: pest::EnumVariant<'ast>) -> absy::EnumDefinitionVariantNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let span = variant.span;
+
+        let id = variant.id.span.as_str();
+
+        let types = variant
+            .types
+            .into_iter()
+            .map(absy::UnresolvedTypeNode::from)
+            .collect();
+
+        absy::EnumDefinitionVariant { id, types }.span(span)
+    }
+}
+
 impl<'ast> From<pest::ConstantDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
     fn from(definition: pest::ConstantDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
         use crate::absy::NodeValue;
 
         let span = definition.span;
         let id = definition.id.span.as_str();
+        let public = definition.public.is_some();
 
         let ty = absy::ConstantDefinition {
             ty: definition.ty.into(),
             expression: definition.expression.into(),
+            embedded: false,
         }
         .span(span.clone());
 
         absy::SymbolDeclaration {
+            public,
             id,
             symbol: absy::Symbol::Here(absy::SymbolDefinition::Constant(ty)),
         }
@@ -135,6 +215,33 @@ impl<'ast> From<pest::ConstantDefinition<'ast>> for absy::SymbolDeclarationNode<
     }
 }
 
+impl<'ast> From<pest::TypeDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::TypeDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let span = definition.span;
+        let id = definition.id.span.as_str();
+        let public = definition.public.is_some();
+
+        let ty = absy::TypeDefinition {
+            generics: definition
+                .generics
+                .into_iter()
+                .map(absy::GenericDeclarationNode::from)
+                .collect(),
+            ty: definition.ty.into(),
+        }
+        .span(span.clone());
+
+        absy::SymbolDeclaration {
+            public,
+            id,
+            symbol: absy::Symbol::Here(absy::SymbolDefinition::Type(ty)),
+        }
+        .span(span)
+    }
+}
+
 impl<'ast> From<pest::FunctionDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
     fn from(function: pest::FunctionDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
         use crate::absy::NodeValue;
@@ -146,7 +253,7 @@ impl<'ast> From<pest::FunctionDefinition<'ast>> for absy::SymbolDeclarationNode<
                 function
                     .generics
                     .into_iter()
-                    .map(absy::ConstantGenericNode::from)
+                    .map(absy::GenericDeclarationNode::from)
                     .collect(),
             )
             .inputs(
@@ -167,6 +274,7 @@ impl<'ast> From<pest::FunctionDefinition<'ast>> for absy::SymbolDeclarationNode<
             );
 
         let id = function.id.span.as_str();
+        let public = function.public.is_some();
 
         let function = absy::Function {
             arguments: function
@@ -184,6 +292,7 @@ impl<'ast> From<pest::FunctionDefinition<'ast>> for absy::SymbolDeclarationNode<
         .span(span.clone());
 
         absy::SymbolDeclaration {
+            public,
             id,
             symbol: absy::Symbol::Here(absy::SymbolDefinition::Function(function)),
         }
@@ -191,13 +300,107 @@ impl<'ast> From<pest::FunctionDefinition<'ast>> for absy::SymbolDeclarationNode<
     }
 }
 
-impl<'ast> From<pest::IdentifierExpression<'ast>> for absy::ConstantGenericNode<'ast> {
-    fn from(g: pest::IdentifierExpression<'ast>) -> absy::ConstantGenericNode<'ast> {
-        use absy::NodeValue;
+impl<'ast> From<pest::ImplDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::ImplDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let span = definition.span;
+        let id = definition.id.span.as_str();
+
+        let impl_definition = absy::ImplDefinition {
+            methods: definition
+                .methods
+                .into_iter()
+                .map(|m| method_definition_from_ast(id, m))
+                .collect(),
+        }
+        .span(span.clone());
+
+        absy::SymbolDeclaration {
+            public: false,
+            id,
+            symbol: absy::Symbol::Here(absy::SymbolDefinition::Impl(impl_definition)),
+        }
+        .span(span)
+    }
+}
 
-        let name = g.span.as_str();
+/// Convert a method of the struct `struct_id` to a function taking the receiver `self` as its first argument
+fn method_definition_from_ast<'ast>(
+    struct_id: &'ast str,
+    method: pest::MethodDefinition<'ast>,
+) -> absy::MethodDefinitionNode<'ast> {
+    use crate::absy::NodeValue;
+
+    let span = method.span;
+
+    let receiver_type =
+        absy::UnresolvedType::User(struct_id.to_string(), None).span(method.receiver.span.clone());
+
+    let receiver = absy::Parameter::public(
+        absy::Variable::new("self", receiver_type.clone()).span(method.receiver.span.clone()),
+    )
+    .span(method.receiver.span);
+
+    let signature = absy::UnresolvedSignature::new()
+        .generics(
+            method
+                .generics
+                .into_iter()
+                .map(absy::GenericDeclarationNode::from)
+                .collect(),
+        )
+        .inputs(
+            std::iter::once(receiver_type)
+                .chain(
+                    method
+                        .parameters
+                        .clone()
+                        .into_iter()
+                        .map(|p| absy::UnresolvedTypeNode::from(p.ty)),
+                )
+                .collect(),
+        )
+        .outputs(
+            method
+                .returns
+                .into_iter()
+                .map(absy::UnresolvedTypeNode::from)
+                .collect(),
+        );
+
+    let function = absy::Function {
+        arguments: std::iter::once(receiver)
+            .chain(method.parameters.into_iter().map(absy::ParameterNode::from))
+            .collect(),
+        statements: method
+            .statements
+            .into_iter()
+            .flat_map(statements_from_statement)
+            .collect(),
+        signature,
+    }
+    .span(span.clone());
+
+    absy::MethodDefinition {
+        id: method.id.span.as_str(),
+        function,
+    }
+    .span(span)
+}
 
-        name.span(g.span)
+impl<'ast> From<pest::GenericParameter<'ast>> for absy::GenericDeclarationNode<'ast> {
+    fn from(g: pest::GenericParameter<'ast>) -> absy::GenericDeclarationNode<'ast> {
+        use absy::NodeValue;
+
+        match g {
+            pest::GenericParameter::Constant(id) => {
+                absy::GenericDeclaration::constant(id.span.as_str()).span(id.span)
+            }
+            pest::GenericParameter::Type(t) => {
+                absy::GenericDeclaration::type_parameter(t.id.span.as_str()).span(t.span)
+            }
+        }
     }
 }
 
@@ -217,6 +420,7 @@ impl<'ast> From<pest::Parameter<'ast>> for absy::ParameterNode<'ast> {
             param.id.span.as_str(),
             absy::UnresolvedTypeNode::from(param.ty),
         )
+        .mutable(param.mutable.is_some())
         .span(param.id.span);
 
         absy::Parameter::new(variable, private).span(param.span)
@@ -227,6 +431,7 @@ fn statements_from_statement(statement: pest::Statement) -> Vec<absy::StatementN
     match statement {
         pest::Statement::Definition(s) => statements_from_definition(s),
         pest::Statement::Iteration(s) => vec![absy::StatementNode::from(s)],
+        pest::Statement::IfElse(s) => vec![absy::StatementNode::from(s)],
         pest::Statement::Assertion(s) => vec![absy::StatementNode::from(s)],
         pest::Statement::Return(s) => vec![absy::StatementNode::from(s)],
     }
@@ -251,6 +456,7 @@ fn statements_from_definition(definition: pest::DefinitionStatement) -> Vec<absy
                             i.identifier.span.as_str(),
                             absy::UnresolvedTypeNode::from(i.ty),
                         )
+                        .mutable(i.mutable.is_some())
                         .span(i.identifier.span.clone()),
                     )
                     .span(definition.span.clone());
@@ -294,6 +500,7 @@ fn statements_from_definition(definition: pest::DefinitionStatement) -> Vec<absy
                                 id.span.as_str(),
                                 absy::UnresolvedTypeNode::from(ty),
                             )
+                            .mutable(i.mutable.is_some())
                             .span(id.span),
                         )
                         .span(i.span),
@@ -346,8 +553,11 @@ impl<'ast> From<pest::AssertionStatement<'ast>> for absy::StatementNode<'ast> {
     fn from(statement: pest::AssertionStatement<'ast>) -> absy::StatementNode<'ast> {
         use crate::absy::NodeValue;
 
-        absy::Statement::Assertion(absy::ExpressionNode::from(statement.expression))
-            .span(statement.span)
+        absy::Statement::Assertion(
+            absy::ExpressionNode::from(statement.expression),
+            statement.message.map(|m| m.value),
+        )
+        .span(statement.span)
     }
 }
 
@@ -370,6 +580,31 @@ impl<'ast> From<pest::IterationStatement<'ast>> for absy::StatementNode<'ast> {
     }
 }
 
+impl<'ast> From<pest::IfElseStatement<'ast>> for absy::StatementNode<'ast> {
+    fn from(statement: pest::IfElseStatement<'ast>) -> absy::StatementNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let condition = absy::ExpressionNode::from(statement.condition);
+        let consequence = statements_from_block(statement.consequence);
+        // `else if` is turned into an alternative holding a single if/else statement
+        let alternative = match statement.alternative {
+            Some(pest::ElseStatement::IfElse(box s)) => vec![absy::StatementNode::from(s)],
+            Some(pest::ElseStatement::Block(b)) => statements_from_block(b),
+            None => vec![],
+        };
+
+        absy::Statement::IfElse(condition, consequence, alternative).span(statement.span)
+    }
+}
+
+fn statements_from_block(block: pest::BlockStatement) -> Vec<absy::StatementNode> {
+    block
+        .statements
+        .into_iter()
+        .flat_map(statements_from_statement)
+        .collect()
+}
+
 impl<'ast> From<pest::Expression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(expression: pest::Expression<'ast>) -> absy::ExpressionNode<'ast> {
         match expression {
@@ -380,8 +615,13 @@ impl<'ast> From<pest::Expression<'ast>> for absy::ExpressionNode<'ast> {
             pest::Expression::Postfix(e) => absy::ExpressionNode::from(e),
             pest::Expression::InlineArray(e) => absy::ExpressionNode::from(e),
             pest::Expression::InlineStruct(e) => absy::ExpressionNode::from(e),
+            pest::Expression::InlineTuple(e) => absy::ExpressionNode::from(e),
             pest::Expression::ArrayInitializer(e) => absy::ExpressionNode::from(e),
+            pest::Expression::ArrayComprehension(e) => absy::ExpressionNode::from(e),
             pest::Expression::Unary(e) => absy::ExpressionNode::from(e),
+            pest::Expression::Cast(e) => absy::ExpressionNode::from(e),
+            pest::Expression::Match(e) => absy::ExpressionNode::from(e),
+            pest::Expression::EnumValue(e) => absy::ExpressionNode::from(e),
         }
     }
 }
@@ -454,6 +694,14 @@ impl<'ast> From<pest::BinaryExpression<'ast>> for absy::ExpressionNode<'ast> {
                 box absy::ExpressionNode::from(*expression.left),
                 box absy::ExpressionNode::from(*expression.right),
             ),
+            pest::BinaryOperator::LeftRotate => absy::Expression::LeftRotate(
+                box absy::ExpressionNode::from(*expression.left),
+                box absy::ExpressionNode::from(*expression.right),
+            ),
+            pest::BinaryOperator::RightRotate => absy::Expression::RightRotate(
+                box absy::ExpressionNode::from(*expression.left),
+                box absy::ExpressionNode::from(*expression.right),
+            ),
             pest::BinaryOperator::BitAnd => absy::Expression::BitAnd(
                 box absy::ExpressionNode::from(*expression.left),
                 box absy::ExpressionNode::from(*expression.right),
@@ -570,6 +818,77 @@ impl<'ast> From<pest::InlineStructExpression<'ast>> for absy::ExpressionNode<'as
     }
 }
 
+impl<'ast> From<pest::InlineTupleExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(tuple: pest::InlineTupleExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+        absy::Expression::InlineTuple(
+            tuple
+                .elements
+                .into_iter()
+                .map(absy::ExpressionNode::from)
+                .collect(),
+        )
+        .span(tuple.span)
+    }
+}
+
+impl<'ast> From<pest::EnumValueExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(e: pest::EnumValueExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+        absy::Expression::EnumValue(
+            e.ty.span.as_str().to_string(),
+            e.variant.span.as_str(),
+            e.arguments
+                .map(|a| {
+                    a.expressions
+                        .into_iter()
+                        .map(absy::ExpressionNode::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        )
+        .span(e.span)
+    }
+}
+
+impl<'ast> From<pest::MatchExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(e: pest::MatchExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+        absy::Expression::Match(
+            box absy::ExpressionNode::from(*e.expression),
+            e.arms.into_iter().map(absy::MatchArmNode::from).collect(),
+        )
+        .span(e.span)
+    }
+}
+
+impl<'ast> From<pest::MatchArm<'ast>> for absy::MatchArmNode<'ast> {
+    fn from(arm: pest::MatchArm<'ast>) -> absy::MatchArmNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let pattern = match arm.pattern {
+            pest::Pattern::Wildcard(_) => absy::Pattern::Wildcard,
+            pest::Pattern::Variant(p) => absy::Pattern::Variant(
+                p.ty.span.as_str().to_string(),
+                p.variant.span.as_str(),
+                p.bindings
+                    .into_iter()
+                    .map(|b| match b {
+                        pest::PatternBinding::Wildcard(_) => None,
+                        pest::PatternBinding::Identifier(i) => Some(i.span.as_str()),
+                    })
+                    .collect(),
+            ),
+        };
+
+        absy::MatchArm {
+            pattern,
+            expression: absy::ExpressionNode::from(arm.expression),
+        }
+        .span(arm.span)
+    }
+}
+
 impl<'ast> From<pest::ArrayInitializerExpression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(initializer: pest::ArrayInitializerExpression<'ast>) -> absy::ExpressionNode<'ast> {
         use crate::absy::NodeValue;
@@ -580,6 +899,22 @@ impl<'ast> From<pest::ArrayInitializerExpression<'ast>> for absy::ExpressionNode
     }
 }
 
+impl<'ast> From<pest::ArrayComprehensionExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(comprehension: pest::ArrayComprehensionExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let expression = absy::ExpressionNode::from(*comprehension.expression);
+        let ty = absy::UnresolvedTypeNode::from(comprehension.ty);
+        let index = absy::Variable::new(comprehension.index.span.as_str(), ty)
+            .span(comprehension.index.span);
+        let from = absy::ExpressionNode::from(*comprehension.from);
+        let to = absy::ExpressionNode::from(*comprehension.to);
+
+        absy::Expression::ArrayComprehension(box expression, box index, box from, box to)
+            .span(comprehension.span)
+    }
+}
+
 impl<'ast> From<pest::UnaryExpression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(unary: pest::UnaryExpression<'ast>) -> absy::ExpressionNode<'ast> {
         use crate::absy::NodeValue;
@@ -595,11 +930,22 @@ impl<'ast> From<pest::UnaryExpression<'ast>> for absy::ExpressionNode<'ast> {
     }
 }
 
+impl<'ast> From<pest::CastExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(cast: pest::CastExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+
+        absy::Expression::Cast(
+            box absy::ExpressionNode::from(*cast.expression),
+            box absy::UnresolvedTypeNode::from(cast.ty),
+        )
+        .span(cast.span)
+    }
+}
+
 impl<'ast> From<pest::PostfixExpression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(expression: pest::PostfixExpression<'ast>) -> absy::ExpressionNode<'ast> {
         use crate::absy::NodeValue;
 
-        let id_str = expression.id.span.as_str();
         let id = absy::ExpressionNode::from(expression.id);
 
         // pest::PostFixExpression contains an array of "accesses": `a(34)[42]` is represented as `[a, [Call(34), Select(42)]]`, but absy::ExpressionNode
@@ -607,33 +953,28 @@ impl<'ast> From<pest::PostfixExpression<'ast>> for absy::ExpressionNode<'ast> {
 
         // we start with the id, and we fold the array of accesses by wrapping the current value
         expression.accesses.into_iter().fold(id, |acc, a| match a {
-            pest::Access::Call(a) => match acc.value {
-                absy::Expression::Identifier(_) => absy::Expression::FunctionCall(
-                    &id_str,
-                    a.explicit_generics.map(|explicit_generics| {
-                        explicit_generics
-                            .values
-                            .into_iter()
-                            .map(|i| match i {
-                                pest::ConstantGenericValue::Underscore(_) => None,
-                                pest::ConstantGenericValue::Value(v) => {
-                                    Some(absy::ExpressionNode::from(v))
-                                }
-                                pest::ConstantGenericValue::Identifier(i) => {
-                                    Some(absy::Expression::Identifier(i.span.as_str()).span(i.span))
-                                }
-                            })
-                            .collect()
-                    }),
-                    a.arguments
-                        .expressions
-                        .into_iter()
-                        .map(absy::ExpressionNode::from)
-                        .collect(),
-                ),
-                e => unimplemented!("only identifiers are callable, found \"{}\"", e),
+            pest::Access::Call(a) => {
+                let generics = a
+                    .explicit_generics
+                    .map(generic_arguments_from_explicit_generics);
+                let arguments = a
+                    .arguments
+                    .expressions
+                    .into_iter()
+                    .map(absy::ExpressionNode::from)
+                    .collect();
+
+                match acc {
+                    // `a.foo(b)` is a call to the method `foo` on `a`
+                    absy::Node {
+                        value: absy::Expression::Member(receiver, box id),
+                        ..
+                    } => absy::Expression::MethodCall(receiver, id, generics, arguments),
+                    // other callees are checked during semantic analysis
+                    acc => absy::Expression::FunctionCall(box acc, generics, arguments),
+                }
+                .span(a.span)
             }
-            .span(a.span),
             pest::Access::Select(a) => {
                 absy::Expression::Select(box acc, box absy::RangeOrExpression::from(a.expression))
                     .span(a.span)
@@ -641,60 +982,170 @@ impl<'ast> From<pest::PostfixExpression<'ast>> for absy::ExpressionNode<'ast> {
             pest::Access::Member(m) => {
                 absy::Expression::Member(box acc, box m.id.span.as_str()).span(m.span)
             }
+            pest::Access::Element(e) => {
+                absy::Expression::Element(box acc, parse_digits(e.index.span.as_str(), 10))
+                    .span(e.span)
+            }
         })
     }
 }
 
-impl<'ast> From<pest::DecimalLiteralExpression<'ast>> for absy::ExpressionNode<'ast> {
-    fn from(expression: pest::DecimalLiteralExpression<'ast>) -> absy::ExpressionNode<'ast> {
-        use crate::absy::NodeValue;
+fn generic_arguments_from_explicit_generics(
+    explicit_generics: pest::ExplicitGenerics,
+) -> Vec<Option<absy::GenericArgument>> {
+    use crate::absy::NodeValue;
 
-        match expression.suffix {
-            Some(suffix) => match suffix {
-                pest::DecimalSuffix::Field(_) => absy::Expression::FieldConstant(
-                    BigUint::parse_bytes(&expression.value.span.as_str().as_bytes(), 10).unwrap(),
-                ),
-                pest::DecimalSuffix::U64(_) => {
-                    absy::Expression::U64Constant(expression.value.span.as_str().parse().unwrap())
-                }
-                pest::DecimalSuffix::U32(_) => {
-                    absy::Expression::U32Constant(expression.value.span.as_str().parse().unwrap())
-                }
-                pest::DecimalSuffix::U16(_) => {
-                    absy::Expression::U16Constant(expression.value.span.as_str().parse().unwrap())
-                }
-                pest::DecimalSuffix::U8(_) => {
-                    absy::Expression::U8Constant(expression.value.span.as_str().parse().unwrap())
-                }
+    explicit_generics
+        .values
+        .into_iter()
+        .map(|v| match v {
+            pest::ConstantGenericValue::Underscore(_) => None,
+            pest::ConstantGenericValue::Value(v) => Some(absy::GenericArgument::Expression(
+                absy::ExpressionNode::from(v),
+            )),
+            pest::ConstantGenericValue::Identifier(i) => Some(absy::GenericArgument::Expression(
+                absy::Expression::Identifier(i.span.as_str()).span(i.span),
+            )),
+            pest::ConstantGenericValue::Type(t) => Some(absy::GenericArgument::Type(
+                absy::UnresolvedTypeNode::from(t),
+            )),
+        })
+        .collect()
+}
+
+/// Parse the digits of a number literal in base `radix`, ignoring `_` separators
+fn parse_digits(digits: &str, radix: u32) -> BigUint {
+    let digits: String = digits.chars().filter(|c| *c != '_').collect();
+    BigUint::parse_bytes(digits.as_bytes(), radix).unwrap()
+}
+
+/// Build a constant of type `u{bitwidth}` from the `literal` which denotes `value`
+fn uint_constant<'ast>(
+    value: BigUint,
+    bitwidth: usize,
+    literal: &'ast str,
+    span: pest::Span<'ast>,
+) -> absy::ExpressionNode<'ast> {
+    use crate::absy::NodeValue;
+
+    match value.bits() <= bitwidth {
+        true => {
+            let value = u128::from_str_radix(&value.to_str_radix(16), 16).unwrap();
+            match bitwidth {
+                8 => absy::Expression::U8Constant(value as u8),
+                16 => absy::Expression::U16Constant(value as u16),
+                32 => absy::Expression::U32Constant(value as u32),
+                64 => absy::Expression::U64Constant(value as u64),
+                128 => absy::Expression::U128Constant(value),
+                _ => unreachable!(),
             }
-            .span(expression.span),
-            None => absy::Expression::IntConstant(
-                BigUint::parse_bytes(&expression.value.span.as_str().as_bytes(), 10).unwrap(),
-            )
-            .span(expression.span),
         }
+        false => absy::Expression::OutOfRangeUintConstant(literal, bitwidth),
+    }
+    .span(span)
+}
+
+/// Build a constant of the type given by `suffix`, or an untyped constant if there is no suffix
+fn suffixed_constant<'ast>(
+    value: BigUint,
+    suffix: Option<pest::DecimalSuffix<'ast>>,
+    literal: &'ast str,
+    span: pest::Span<'ast>,
+) -> absy::ExpressionNode<'ast> {
+    use crate::absy::NodeValue;
+
+    match suffix {
+        Some(pest::DecimalSuffix::Field(_)) => absy::Expression::FieldConstant(value),
+        Some(pest::DecimalSuffix::U128(_)) => return uint_constant(value, 128, literal, span),
+        Some(pest::DecimalSuffix::U64(_)) => return uint_constant(value, 64, literal, span),
+        Some(pest::DecimalSuffix::U32(_)) => return uint_constant(value, 32, literal, span),
+        Some(pest::DecimalSuffix::U16(_)) => return uint_constant(value, 16, literal, span),
+        Some(pest::DecimalSuffix::U8(_)) => return uint_constant(value, 8, literal, span),
+        // the magnitude of signed literals is checked against their type when the negation is known
+        Some(pest::DecimalSuffix::I64(_)) => absy::Expression::I64Constant(value),
+        Some(pest::DecimalSuffix::I32(_)) => absy::Expression::I32Constant(value),
+        Some(pest::DecimalSuffix::I16(_)) => absy::Expression::I16Constant(value),
+        Some(pest::DecimalSuffix::I8(_)) => absy::Expression::I8Constant(value),
+        None => absy::Expression::IntConstant(value),
+    }
+    .span(span)
+}
+
+impl<'ast> From<pest::DecimalLiteralExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(expression: pest::DecimalLiteralExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        let digits = expression.value.span.as_str();
+
+        suffixed_constant(
+            parse_digits(digits, 10),
+            expression.suffix,
+            digits,
+            expression.span,
+        )
     }
 }
 
 impl<'ast> From<pest::HexLiteralExpression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(expression: pest::HexLiteralExpression<'ast>) -> absy::ExpressionNode<'ast> {
-        use crate::absy::NodeValue;
-
-        match expression.value {
-            pest::HexNumberExpression::U64(e) => {
-                absy::Expression::U64Constant(u64::from_str_radix(&e.span.as_str(), 16).unwrap())
+        let digits = expression.value.span.as_str();
+        let value = parse_digits(digits, 16);
+        let len = digits.chars().filter(|c| *c != '_').count();
+        // the literal without its suffix, including its `0x` prefix
+        let literal = &expression.span.as_str()[..2 + digits.len()];
+
+        // without a suffix, the number of digits gives the width, and other lengths are untyped
+        match (expression.suffix, len) {
+            (None, len) if [2, 4, 8, 16, 32].contains(&len) => {
+                uint_constant(value, len * 4, literal, expression.span)
             }
-            pest::HexNumberExpression::U32(e) => {
-                absy::Expression::U32Constant(u32::from_str_radix(&e.span.as_str(), 16).unwrap())
-            }
-            pest::HexNumberExpression::U16(e) => {
-                absy::Expression::U16Constant(u16::from_str_radix(&e.span.as_str(), 16).unwrap())
-            }
-            pest::HexNumberExpression::U8(e) => {
-                absy::Expression::U8Constant(u8::from_str_radix(&e.span.as_str(), 16).unwrap())
+            (suffix, _) => suffixed_constant(value, suffix, literal, expression.span),
+        }
+    }
+}
+
+impl<'ast> From<pest::BinaryLiteralExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(expression: pest::BinaryLiteralExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        let digits = expression.value.span.as_str();
+        let value = parse_digits(digits, 2);
+        let len = digits.chars().filter(|c| *c != '_').count();
+        // the literal without its suffix, including its `0b` prefix
+        let literal = &expression.span.as_str()[..2 + digits.len()];
+
+        // without a suffix, the number of digits gives the width, and other lengths are untyped
+        match (expression.suffix, len) {
+            (None, len) if [8, 16, 32, 64, 128].contains(&len) => {
+                uint_constant(value, len, literal, expression.span)
             }
+            (suffix, _) => suffixed_constant(value, suffix, literal, expression.span),
         }
-        .span(expression.span)
+    }
+}
+
+impl<'ast> From<pest::StringLiteralExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(expression: pest::StringLiteralExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+
+        // the grammar only accepts ASCII characters and valid escape sequences
+        let mut bytes = vec![];
+        let mut chars = expression.content.value.bytes();
+
+        while let Some(c) = chars.next() {
+            bytes.push(match c {
+                b'\\' => match chars.next().unwrap() {
+                    b'x' => {
+                        let digits = [chars.next().unwrap(), chars.next().unwrap()];
+                        u8::from_str_radix(std::str::from_utf8(&digits).unwrap(), 16).unwrap()
+                    }
+                    b'n' => b'\n',
+                    b'r' => b'\r',
+                    b't' => b'\t',
+                    b'0' => b'\0',
+                    c => c,
+                },
+                c => c,
+            });
+        }
+
+        absy::Expression::ByteStringConstant(bytes).span(expression.span)
     }
 }
 
@@ -708,6 +1159,8 @@ impl<'ast> From<pest::LiteralExpression<'ast>> for absy::ExpressionNode<'ast> {
             }
             pest::LiteralExpression::DecimalLiteral(n) => absy::ExpressionNode::from(n),
             pest::LiteralExpression::HexLiteral(n) => absy::ExpressionNode::from(n),
+            pest::LiteralExpression::BinaryLiteral(n) => absy::ExpressionNode::from(n),
+            pest::LiteralExpression::StringLiteral(s) => absy::ExpressionNode::from(s),
         }
     }
 }
@@ -742,6 +1195,9 @@ impl<'ast> From<pest::Assignee<'ast>> for absy::AssigneeNode<'ast> {
                 pest::AssigneeAccess::Member(m) => {
                     absy::Assignee::Member(box acc, box m.id.span.as_str())
                 }
+                pest::AssigneeAccess::Element(e) => {
+                    absy::Assignee::Element(box acc, parse_digits(e.index.span.as_str(), 10))
+                }
             }
             .span(span.clone())
         })
@@ -761,34 +1217,38 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode<'ast> {
                 pest::BasicType::U16(t) => UnresolvedType::Uint(16).span(t.span),
                 pest::BasicType::U32(t) => UnresolvedType::Uint(32).span(t.span),
                 pest::BasicType::U64(t) => UnresolvedType::Uint(64).span(t.span),
+                pest::BasicType::U128(t) => UnresolvedType::Uint(128).span(t.span),
+                pest::BasicType::I8(t) => UnresolvedType::Int(8).span(t.span),
+                pest::BasicType::I16(t) => UnresolvedType::Int(16).span(t.span),
+                pest::BasicType::I32(t) => UnresolvedType::Int(32).span(t.span),
+                pest::BasicType::I64(t) => UnresolvedType::Int(64).span(t.span),
             },
             pest::Type::Array(t) => {
                 let inner_type = match t.ty {
-                    pest::BasicOrStructType::Basic(t) => match t {
+                    pest::BasicOrStructOrTupleType::Basic(t) => match t {
                         pest::BasicType::Field(t) => UnresolvedType::FieldElement.span(t.span),
                         pest::BasicType::Boolean(t) => UnresolvedType::Boolean.span(t.span),
                         pest::BasicType::U8(t) => UnresolvedType::Uint(8).span(t.span),
                         pest::BasicType::U16(t) => UnresolvedType::Uint(16).span(t.span),
                         pest::BasicType::U32(t) => UnresolvedType::Uint(32).span(t.span),
                         pest::BasicType::U64(t) => UnresolvedType::Uint(64).span(t.span),
+                        pest::BasicType::U128(t) => UnresolvedType::Uint(128).span(t.span),
+                        pest::BasicType::I8(t) => UnresolvedType::Int(8).span(t.span),
+                        pest::BasicType::I16(t) => UnresolvedType::Int(16).span(t.span),
+                        pest::BasicType::I32(t) => UnresolvedType::Int(32).span(t.span),
+                        pest::BasicType::I64(t) => UnresolvedType::Int(64).span(t.span),
                     },
-                    pest::BasicOrStructType::Struct(t) => UnresolvedType::User(
+                    pest::BasicOrStructOrTupleType::Struct(t) => UnresolvedType::User(
                         t.id.span.as_str().to_string(),
-                        t.explicit_generics.map(|explicit_generics| {
-                            explicit_generics
-                                .values
-                                .into_iter()
-                                .map(|i| match i {
-                                    pest::ConstantGenericValue::Underscore(_) => None,
-                                    pest::ConstantGenericValue::Value(v) => {
-                                        Some(absy::ExpressionNode::from(v))
-                                    }
-                                    pest::ConstantGenericValue::Identifier(i) => Some(
-                                        absy::Expression::Identifier(i.span.as_str()).span(i.span),
-                                    ),
-                                })
-                                .collect()
-                        }),
+                        t.explicit_generics
+                            .map(generic_arguments_from_explicit_generics),
+                    )
+                    .span(t.span),
+                    pest::BasicOrStructOrTupleType::Tuple(t) => UnresolvedType::Tuple(
+                        t.elements
+                            .into_iter()
+                            .map(absy::UnresolvedTypeNode::from)
+                            .collect(),
                     )
                     .span(t.span),
                 };
@@ -808,23 +1268,17 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode<'ast> {
             }
             pest::Type::Struct(s) => UnresolvedType::User(
                 s.id.span.as_str().to_string(),
-                s.explicit_generics.map(|explicit_generics| {
-                    explicit_generics
-                        .values
-                        .into_iter()
-                        .map(|i| match i {
-                            pest::ConstantGenericValue::Underscore(_) => None,
-                            pest::ConstantGenericValue::Value(v) => {
-                                Some(absy::ExpressionNode::from(v))
-                            }
-                            pest::ConstantGenericValue::Identifier(i) => {
-                                Some(absy::Expression::Identifier(i.span.as_str()).span(i.span))
-                            }
-                        })
-                        .collect()
-                }),
+                s.explicit_generics
+                    .map(generic_arguments_from_explicit_generics),
             )
             .span(s.span),
+            pest::Type::Tuple(t) => UnresolvedType::Tuple(
+                t.elements
+                    .into_iter()
+                    .map(absy::UnresolvedTypeNode::from)
+                    .collect(),
+            )
+            .span(t.span),
         }
     }
 }
@@ -841,6 +1295,7 @@ mod tests {
         let ast = pest::generate_ast(&source).unwrap();
         let expected: absy::Module = absy::Module {
             symbols: vec![absy::SymbolDeclaration {
+                public: false,
                 id: &source[4..8],
                 symbol: absy::Symbol::Here(absy::SymbolDefinition::Function(
                     absy::Function {
@@ -862,6 +1317,7 @@ mod tests {
                 )),
             }
             .into()],
+            immutable_by_default: false,
         };
         assert_eq!(absy::Module::from(ast), expected);
     }
@@ -872,6 +1328,7 @@ mod tests {
         let ast = pest::generate_ast(&source).unwrap();
         let expected: absy::Module = absy::Module {
             symbols: vec![absy::SymbolDeclaration {
+                public: false,
                 id: &source[4..8],
                 symbol: absy::Symbol::Here(absy::SymbolDefinition::Function(
                     absy::Function {
@@ -891,6 +1348,7 @@ mod tests {
                 )),
             }
             .into()],
+            immutable_by_default: false,
         };
         assert_eq!(absy::Module::from(ast), expected);
     }
@@ -902,6 +1360,7 @@ mod tests {
 
         let expected: absy::Module = absy::Module {
             symbols: vec![absy::SymbolDeclaration {
+                public: false,
                 id: &source[4..8],
                 symbol: absy::Symbol::Here(absy::SymbolDefinition::Function(
                     absy::Function {
@@ -943,6 +1402,7 @@ mod tests {
                 )),
             }
             .into()],
+            immutable_by_default: false,
         };
 
         assert_eq!(absy::Module::from(ast), expected);
@@ -955,6 +1415,7 @@ mod tests {
         fn wrap(ty: UnresolvedType<'static>) -> absy::Module<'static> {
             absy::Module {
                 symbols: vec![absy::SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: absy::Symbol::Here(absy::SymbolDefinition::Function(
                         absy::Function {
@@ -975,6 +1436,7 @@ mod tests {
                     )),
                 }
                 .into()],
+                immutable_by_default: false,
             }
         }
 
@@ -1023,11 +1485,97 @@ mod tests {
         }
     }
 
+    mod strings {
+        use super::*;
+
+        #[test]
+        fn byte_strings() {
+            // byte strings and strings are both arrays of the bytes they contain, after unescaping
+            let vectors = vec![
+                (r#"b"hello""#, b"hello".to_vec()),
+                (r#""hello""#, b"hello".to_vec()),
+                (r#"b"\x00\x7f\n\r\t\0""#, vec![0, 127, 10, 13, 9, 0]),
+                (r#""\\\"'""#, b"\\\"'".to_vec()),
+            ];
+
+            for (literal, expected) in vectors {
+                let source = format!("def main(): return {}", literal);
+                let ast = pest::generate_ast(&source).unwrap();
+                let module = absy::Module::from(ast);
+
+                let statement = match &module.symbols[0].value.symbol {
+                    absy::Symbol::Here(absy::SymbolDefinition::Function(f)) => {
+                        f.value.statements[0].value.clone()
+                    }
+                    _ => unreachable!(),
+                };
+
+                match statement {
+                    absy::Statement::Return(e) => assert_eq!(
+                        e.value.expressions[0].value,
+                        absy::Expression::ByteStringConstant(expected)
+                    ),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    mod numbers {
+        use super::*;
+
+        #[test]
+        fn literals() {
+            // hex and binary literals are typed by their suffix or their number of digits, and `_` separators are ignored
+            let vectors = vec![
+                (
+                    "1_000_000",
+                    absy::Expression::IntConstant(1000000usize.into()),
+                ),
+                ("1_000_u32", absy::Expression::U32Constant(1000)),
+                ("0xff", absy::Expression::U8Constant(255)),
+                ("0xdead_beef", absy::Expression::U32Constant(0xdeadbeef)),
+                ("0x0ff", absy::Expression::IntConstant(255usize.into())),
+                ("0x1_u16", absy::Expression::U16Constant(1)),
+                ("0b1010_0110", absy::Expression::U8Constant(0b1010_0110)),
+                ("0b1010", absy::Expression::IntConstant(10usize.into())),
+                ("0b1010u64", absy::Expression::U64Constant(10)),
+                ("0b1010f", absy::Expression::FieldConstant(10usize.into())),
+                (
+                    "0x1ff_u8",
+                    absy::Expression::OutOfRangeUintConstant("0x1ff", 8),
+                ),
+                ("300u8", absy::Expression::OutOfRangeUintConstant("300", 8)),
+            ];
+
+            for (literal, expected) in vectors {
+                let source = format!("def main(): return {}", literal);
+                let ast = pest::generate_ast(&source).unwrap();
+                let module = absy::Module::from(ast);
+
+                let statement = match &module.symbols[0].value.symbol {
+                    absy::Symbol::Here(absy::SymbolDefinition::Function(f)) => {
+                        f.value.statements[0].value.clone()
+                    }
+                    _ => unreachable!(),
+                };
+
+                match statement {
+                    absy::Statement::Return(e) => {
+                        assert_eq!(e.value.expressions[0].value, expected)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
     mod postfix {
         use super::*;
         fn wrap(expression: absy::Expression<'static>) -> absy::Module {
             absy::Module {
                 symbols: vec![absy::SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: absy::Symbol::Here(absy::SymbolDefinition::Function(
                         absy::Function {
@@ -1045,6 +1593,7 @@ mod tests {
                     )),
                 }
                 .into()],
+                immutable_by_default: false,
             }
         }
 
@@ -1082,7 +1631,7 @@ mod tests {
                     "a(3)[4]",
                     absy::Expression::Select(
                         box absy::Expression::FunctionCall(
-                            "a",
+                            box absy::Expression::Identifier("a").into(),
                             None,
                             vec![absy::Expression::IntConstant(3usize.into()).into()],
                         )
@@ -1097,7 +1646,7 @@ mod tests {
                     absy::Expression::Select(
                         box absy::Expression::Select(
                             box absy::Expression::FunctionCall(
-                                "a",
+                                box absy::Expression::Identifier("a").into(),
                                 None,
                                 vec![absy::Expression::IntConstant(3usize.into()).into()],
                             )
@@ -1123,21 +1672,44 @@ mod tests {
         }
 
         #[test]
-        #[should_panic]
         fn call_array_element() {
-            // a call after an array access should be rejected
+            // a call after an array access is kept, and rejected during semantic analysis
             let source = "def main(): return a[2](3)";
             let ast = pest::generate_ast(&source).unwrap();
-            absy::Module::from(ast);
+            assert_eq!(
+                absy::Module::from(ast),
+                wrap(absy::Expression::FunctionCall(
+                    box absy::Expression::Select(
+                        box absy::Expression::Identifier("a").into(),
+                        box absy::RangeOrExpression::Expression(
+                            absy::Expression::IntConstant(2usize.into()).into(),
+                        ),
+                    )
+                    .into(),
+                    None,
+                    vec![absy::Expression::IntConstant(3usize.into()).into()],
+                ))
+            );
         }
 
         #[test]
-        #[should_panic]
         fn call_call_result() {
-            // a call after a call should be rejected
+            // a call after a call is kept, and rejected during semantic analysis
             let source = "def main(): return a(2)(3)";
             let ast = pest::generate_ast(&source).unwrap();
-            absy::Module::from(ast);
+            assert_eq!(
+                absy::Module::from(ast),
+                wrap(absy::Expression::FunctionCall(
+                    box absy::Expression::FunctionCall(
+                        box absy::Expression::Identifier("a").into(),
+                        None,
+                        vec![absy::Expression::IntConstant(2usize.into()).into()],
+                    )
+                    .into(),
+                    None,
+                    vec![absy::Expression::IntConstant(3usize.into()).into()],
+                ))
+            );
         }
     }
     #[test]
@@ -1232,6 +1804,7 @@ mod tests {
                     ty: pest::Type::Basic(pest::BasicType::Field(pest::FieldType {
                         span: span.clone(),
                     })),
+                    mutable: None,
                     identifier: pest::IdentifierExpression {
                         value: String::from("a"),
                         span: span.clone(),
@@ -13,7 +13,7 @@ pub mod variable;
 
 pub use crate::absy::node::{Node, NodeValue};
 pub use crate::absy::parameter::{Parameter, ParameterNode};
-use crate::absy::types::{FunctionIdentifier, UnresolvedSignature, UnresolvedType, UserTypeId};
+use crate::absy::types::{UnresolvedSignature, UnresolvedType, UserTypeId};
 pub use crate::absy::variable::{Variable, VariableNode};
 use crate::embed::FlatEmbed;
 use std::path::{Path, PathBuf};
@@ -21,7 +21,7 @@ use std::path::{Path, PathBuf};
 use std::fmt;
 
 use num_bigint::BigUint;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 /// An identifier of a function or a variable
 pub type Identifier<'ast> = &'ast str;
@@ -42,6 +42,9 @@ pub struct Program<'ast> {
     pub main: OwnedModuleId,
 }
 
+/// The identifier used for glob imports such as `from "./foo" import *`
+pub const GLOB_IMPORT_ID: Identifier<'static> = "*";
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct SymbolIdentifier<'ast> {
     pub id: Identifier<'ast>,
@@ -123,6 +126,8 @@ impl<'ast> fmt::Display for SymbolImport<'ast> {
 /// A declaration of a symbol
 #[derive(Debug, PartialEq, Clone)]
 pub struct SymbolDeclaration<'ast> {
+    /// Whether the symbol was declared with the `pub` modifier
+    pub public: bool,
     pub id: Identifier<'ast>,
     pub symbol: Symbol<'ast>,
 }
@@ -132,8 +137,11 @@ pub struct SymbolDeclaration<'ast> {
 pub enum SymbolDefinition<'ast> {
     Import(CanonicalImportNode<'ast>),
     Struct(StructDefinitionNode<'ast>),
+    Enum(EnumDefinitionNode<'ast>),
     Constant(ConstantDefinitionNode<'ast>),
+    Type(TypeDefinitionNode<'ast>),
     Function(FunctionNode<'ast>),
+    Impl(ImplDefinitionNode<'ast>),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -145,6 +153,9 @@ pub enum Symbol<'ast> {
 
 impl<'ast> fmt::Display for SymbolDeclaration<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.public {
+            write!(f, "pub ")?;
+        }
         match &self.symbol {
             Symbol::Here(ref symbol) => match symbol {
                 SymbolDefinition::Import(ref i) => write!(
@@ -154,14 +165,17 @@ impl<'ast> fmt::Display for SymbolDeclaration<'ast> {
                     i.value.id
                 ),
                 SymbolDefinition::Struct(ref t) => write!(f, "struct {}{}", self.id, t),
+                SymbolDefinition::Enum(ref t) => write!(f, "enum {} {}", self.id, t),
                 SymbolDefinition::Constant(ref c) => write!(
                     f,
                     "const {} {} = {}",
                     c.value.ty, self.id, c.value.expression
                 ),
+                SymbolDefinition::Type(ref t) => write!(f, "type {}{}", self.id, t),
                 SymbolDefinition::Function(ref func) => {
                     write!(f, "def {}{}", self.id, func)
                 }
+                SymbolDefinition::Impl(ref i) => write!(f, "impl {} {}", self.id, i),
             },
             Symbol::There(ref i) => write!(
                 f,
@@ -184,14 +198,57 @@ pub type SymbolDeclarationNode<'ast> = Node<SymbolDeclaration<'ast>>;
 pub struct Module<'ast> {
     /// Symbols of the module
     pub symbols: Declarations<'ast>,
+    /// Whether variables of the module must be declared `mut` to be assigned to after their definition
+    pub immutable_by_default: bool,
 }
 
 impl<'ast> Module<'ast> {
     pub fn with_symbols<I: IntoIterator<Item = SymbolDeclarationNode<'ast>>>(i: I) -> Self {
         Module {
             symbols: i.into_iter().collect(),
+            immutable_by_default: false,
         }
     }
+
+    /// Returns whether this module exports all of its symbols, which is the case if none of them is declared `pub`
+    pub fn exports_all(&self) -> bool {
+        !self.symbols.iter().any(|s| s.value.public)
+    }
+
+    /// Returns whether the symbol `id` can be imported from this module
+    ///
+    /// # Remarks
+    /// * Returns `true` if no symbol called `id` is declared, so that the error reported is that the symbol does not exist
+    pub fn exports(&self, id: Identifier<'ast>) -> bool {
+        let mut declarations = self.symbols.iter().filter(|s| s.value.id == id).peekable();
+
+        declarations.peek().is_none() || self.exports_all() || declarations.any(|s| s.value.public)
+    }
+
+    /// Returns the identifiers of the symbols brought into scope by a glob import of this module
+    ///
+    /// # Remarks
+    /// * A module which declares no `pub` symbol exports the symbols it defines, but not the ones it imports, including
+    ///   the constants imported from the embeds
+    /// * `main` is never imported by a glob import, as it is the entry point of the module rather than part of its interface.
+    ///   A glob import of a module which exports nothing else is rejected, as `main` must be imported by name
+    pub fn glob_exports(&self) -> BTreeSet<Identifier<'ast>> {
+        let exports_all = self.exports_all();
+
+        self.symbols
+            .iter()
+            .filter(|s| s.value.id != "main")
+            .filter(|s| match exports_all {
+                true => match &s.value.symbol {
+                    Symbol::Here(SymbolDefinition::Constant(c)) => !c.value.embedded,
+                    Symbol::Here(..) => true,
+                    _ => false,
+                },
+                false => s.value.public,
+            })
+            .map(|s| s.value.id)
+            .collect()
+    }
 }
 
 pub type UnresolvedTypeNode<'ast> = Node<UnresolvedType<'ast>>;
@@ -199,7 +256,7 @@ pub type UnresolvedTypeNode<'ast> = Node<UnresolvedType<'ast>>;
 /// A struct type definition
 #[derive(Debug, Clone, PartialEq)]
 pub struct StructDefinition<'ast> {
-    pub generics: Vec<ConstantGenericNode<'ast>>,
+    pub generics: Vec<GenericDeclarationNode<'ast>>,
     pub fields: Vec<StructDefinitionFieldNode<'ast>>,
 }
 
@@ -238,10 +295,57 @@ impl<'ast> fmt::Display for StructDefinitionField<'ast> {
 
 type StructDefinitionFieldNode<'ast> = Node<StructDefinitionField<'ast>>;
 
+/// An enum type definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDefinition<'ast> {
+    pub variants: Vec<EnumDefinitionVariantNode<'ast>>,
+}
+
+impl<'ast> fmt::Display for EnumDefinition<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{{")?;
+        for variant in &self.variants {
+            writeln!(f, "  {},", variant)?;
+        }
+        write!(f, "}}",)
+    }
+}
+
+pub type EnumDefinitionNode<'ast> = Node<EnumDefinition<'ast>>;
+
+/// A variant of an enum type definition, with the types of its payload
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDefinitionVariant<'ast> {
+    pub id: Identifier<'ast>,
+    pub types: Vec<UnresolvedTypeNode<'ast>>,
+}
+
+impl<'ast> fmt::Display for EnumDefinitionVariant<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.id)?;
+        if !self.types.is_empty() {
+            write!(
+                f,
+                "({})",
+                self.types
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub type EnumDefinitionVariantNode<'ast> = Node<EnumDefinitionVariant<'ast>>;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ConstantDefinition<'ast> {
     pub ty: UnresolvedTypeNode<'ast>,
     pub expression: ExpressionNode<'ast>,
+    /// Whether the constant is imported from the embeds rather than defined in its module
+    pub embedded: bool,
 }
 
 pub type ConstantDefinitionNode<'ast> = Node<ConstantDefinition<'ast>>;
@@ -252,6 +356,65 @@ impl<'ast> fmt::Display for ConstantDefinition<'ast> {
     }
 }
 
+/// A type alias definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDefinition<'ast> {
+    pub generics: Vec<GenericDeclarationNode<'ast>>,
+    pub ty: UnresolvedTypeNode<'ast>,
+}
+
+pub type TypeDefinitionNode<'ast> = Node<TypeDefinition<'ast>>;
+
+impl<'ast> fmt::Display for TypeDefinition<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.generics.is_empty() {
+            write!(
+                f,
+                "<{}>",
+                self.generics
+                    .iter()
+                    .map(|g| g.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        write!(f, " = {}", self.ty)
+    }
+}
+
+/// A block of methods implemented on a struct
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImplDefinition<'ast> {
+    pub methods: Vec<MethodDefinitionNode<'ast>>,
+}
+
+pub type ImplDefinitionNode<'ast> = Node<ImplDefinition<'ast>>;
+
+impl<'ast> fmt::Display for ImplDefinition<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{{")?;
+        for method in &self.methods {
+            writeln!(f, "{}", method)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// A method, whose function takes the receiver `self` as its first argument
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodDefinition<'ast> {
+    pub id: Identifier<'ast>,
+    pub function: FunctionNode<'ast>,
+}
+
+pub type MethodDefinitionNode<'ast> = Node<MethodDefinition<'ast>>;
+
+impl<'ast> fmt::Display for MethodDefinition<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "def {}{}", self.id, self.function)
+    }
+}
+
 impl<'ast> fmt::Display for Module<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let res = self
@@ -259,11 +422,53 @@ impl<'ast> fmt::Display for Module<'ast> {
             .iter()
             .map(|x| format!("{}", x))
             .collect::<Vec<_>>();
+        if self.immutable_by_default {
+            writeln!(f, "#pragma immutable_by_default")?;
+        }
         write!(f, "{}", res.join("\n"))
     }
 }
 
-pub type ConstantGenericNode<'ast> = Node<Identifier<'ast>>;
+/// The kind of a generic parameter, as stated in its declaration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GenericKind {
+    Constant,
+    Type,
+}
+
+/// A generic parameter declaration, for example `N` for a constant or `type T` for a type
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericDeclaration<'ast> {
+    pub id: Identifier<'ast>,
+    pub kind: GenericKind,
+}
+
+impl<'ast> GenericDeclaration<'ast> {
+    pub fn constant(id: Identifier<'ast>) -> Self {
+        GenericDeclaration {
+            id,
+            kind: GenericKind::Constant,
+        }
+    }
+
+    pub fn type_parameter(id: Identifier<'ast>) -> Self {
+        GenericDeclaration {
+            id,
+            kind: GenericKind::Type,
+        }
+    }
+}
+
+impl<'ast> fmt::Display for GenericDeclaration<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            GenericKind::Constant => write!(f, "{}", self.id),
+            GenericKind::Type => write!(f, "type {}", self.id),
+        }
+    }
+}
+
+pub type GenericDeclarationNode<'ast> = Node<GenericDeclaration<'ast>>;
 
 /// A function defined locally
 #[derive(Debug, Clone, PartialEq)]
@@ -316,6 +521,7 @@ pub enum Assignee<'ast> {
     Identifier(Identifier<'ast>),
     Select(Box<AssigneeNode<'ast>>, Box<RangeOrExpression<'ast>>),
     Member(Box<AssigneeNode<'ast>>, Box<Identifier<'ast>>),
+    Element(Box<AssigneeNode<'ast>>, BigUint),
 }
 
 pub type AssigneeNode<'ast> = Node<Assignee<'ast>>;
@@ -326,6 +532,7 @@ impl<'ast> fmt::Display for Assignee<'ast> {
             Assignee::Identifier(ref s) => write!(f, "{}", s),
             Assignee::Select(ref a, ref e) => write!(f, "{}[{}]", a, e),
             Assignee::Member(ref s, ref m) => write!(f, "{}.{}", s, m),
+            Assignee::Element(ref s, ref index) => write!(f, "{}.{}", s, index),
         }
     }
 }
@@ -337,13 +544,18 @@ pub enum Statement<'ast> {
     Return(ExpressionListNode<'ast>),
     Declaration(VariableNode<'ast>),
     Definition(AssigneeNode<'ast>, ExpressionNode<'ast>),
-    Assertion(ExpressionNode<'ast>),
+    Assertion(ExpressionNode<'ast>, Option<String>),
     For(
         VariableNode<'ast>,
         ExpressionNode<'ast>,
         ExpressionNode<'ast>,
         Vec<StatementNode<'ast>>,
     ),
+    IfElse(
+        ExpressionNode<'ast>,
+        Vec<StatementNode<'ast>>,
+        Vec<StatementNode<'ast>>,
+    ),
     MultipleDefinition(Vec<AssigneeNode<'ast>>, ExpressionNode<'ast>),
 }
 
@@ -355,7 +567,13 @@ impl<'ast> fmt::Display for Statement<'ast> {
             Statement::Return(ref expr) => write!(f, "return {}", expr),
             Statement::Declaration(ref var) => write!(f, "{}", var),
             Statement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
-            Statement::Assertion(ref e) => write!(f, "assert({})", e),
+            Statement::Assertion(ref e, ref message) => {
+                write!(f, "assert({}", e)?;
+                if let Some(m) = message {
+                    write!(f, ", \"{}\"", m)?;
+                }
+                write!(f, ")")
+            }
             Statement::For(ref var, ref start, ref stop, ref list) => {
                 writeln!(f, "for {} in {}..{} do", var, start, stop)?;
                 for l in list {
@@ -363,6 +581,17 @@ impl<'ast> fmt::Display for Statement<'ast> {
                 }
                 write!(f, "\tendfor")
             }
+            Statement::IfElse(ref condition, ref consequence, ref alternative) => {
+                writeln!(f, "if {} {{", condition)?;
+                for l in consequence {
+                    writeln!(f, "\t\t{}", l)?;
+                }
+                writeln!(f, "\t}} else {{")?;
+                for l in alternative {
+                    writeln!(f, "\t\t{}", l)?;
+                }
+                write!(f, "\t}}")
+            }
             Statement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -454,6 +683,39 @@ impl<'ast> fmt::Display for Range<'ast> {
     }
 }
 
+/// A value given to a generic parameter, either a constant like `3` in `foo::<3>()` or a type like `field` in `Pair<field>`
+///
+/// An identifier can refer to a constant or to a type, so it is kept as an expression until we know the kind of the parameter
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenericArgument<'ast> {
+    Expression(ExpressionNode<'ast>),
+    Type(UnresolvedTypeNode<'ast>),
+}
+
+impl<'ast> GenericArgument<'ast> {
+    /// Interpret this argument as a type, which is possible for types and identifiers
+    pub fn into_type(self) -> Result<UnresolvedTypeNode<'ast>, ExpressionNode<'ast>> {
+        match self {
+            GenericArgument::Type(t) => Ok(t),
+            GenericArgument::Expression(e) => match e.value {
+                Expression::Identifier(id) => {
+                    Ok(UnresolvedType::User(id.into(), None).start_end(e.start, e.end))
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+impl<'ast> fmt::Display for GenericArgument<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenericArgument::Expression(e) => write!(f, "{}", e),
+            GenericArgument::Type(t) => write!(f, "{}", t),
+        }
+    }
+}
+
 /// An expression
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expression<'ast> {
@@ -464,6 +726,14 @@ pub enum Expression<'ast> {
     U16Constant(u16),
     U32Constant(u32),
     U64Constant(u64),
+    U128Constant(u128),
+    /// A literal with an unsigned integer type which is too small for its value, reported during semantic analysis
+    OutOfRangeUintConstant(&'ast str, usize),
+    I8Constant(BigUint),
+    I16Constant(BigUint),
+    I32Constant(BigUint),
+    I64Constant(BigUint),
+    ByteStringConstant(Vec<u8>),
     Identifier(Identifier<'ast>),
     Add(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Sub(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
@@ -479,8 +749,14 @@ pub enum Expression<'ast> {
         Box<ExpressionNode<'ast>>,
     ),
     FunctionCall(
-        FunctionIdentifier<'ast>,
-        Option<Vec<Option<ExpressionNode<'ast>>>>,
+        Box<ExpressionNode<'ast>>,
+        Option<Vec<Option<GenericArgument<'ast>>>>,
+        Vec<ExpressionNode<'ast>>,
+    ),
+    MethodCall(
+        Box<ExpressionNode<'ast>>,
+        Identifier<'ast>,
+        Option<Vec<Option<GenericArgument<'ast>>>>,
         Vec<ExpressionNode<'ast>>,
     ),
     Lt(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
@@ -492,15 +768,28 @@ pub enum Expression<'ast> {
     Not(Box<ExpressionNode<'ast>>),
     InlineArray(Vec<SpreadOrExpression<'ast>>),
     ArrayInitializer(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
+    ArrayComprehension(
+        Box<ExpressionNode<'ast>>,
+        Box<VariableNode<'ast>>,
+        Box<ExpressionNode<'ast>>,
+        Box<ExpressionNode<'ast>>,
+    ),
     InlineStruct(UserTypeId, Vec<(Identifier<'ast>, ExpressionNode<'ast>)>),
+    InlineTuple(Vec<ExpressionNode<'ast>>),
+    EnumValue(UserTypeId, Identifier<'ast>, Vec<ExpressionNode<'ast>>),
+    Match(Box<ExpressionNode<'ast>>, Vec<MatchArmNode<'ast>>),
     Select(Box<ExpressionNode<'ast>>, Box<RangeOrExpression<'ast>>),
     Member(Box<ExpressionNode<'ast>>, Box<Identifier<'ast>>),
+    Element(Box<ExpressionNode<'ast>>, BigUint),
     Or(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitXor(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitAnd(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitOr(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     LeftShift(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     RightShift(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
+    LeftRotate(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
+    RightRotate(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
+    Cast(Box<ExpressionNode<'ast>>, Box<UnresolvedTypeNode<'ast>>),
 }
 
 pub type ExpressionNode<'ast> = Node<Expression<'ast>>;
@@ -513,7 +802,26 @@ impl<'ast> fmt::Display for Expression<'ast> {
             Expression::U16Constant(ref i) => write!(f, "{}", i),
             Expression::U32Constant(ref i) => write!(f, "{}", i),
             Expression::U64Constant(ref i) => write!(f, "{}", i),
+            Expression::U128Constant(ref i) => write!(f, "{}", i),
+            Expression::OutOfRangeUintConstant(literal, bitwidth) => {
+                write!(f, "{}_u{}", literal, bitwidth)
+            }
+            Expression::I8Constant(ref i) => write!(f, "{}", i),
+            Expression::I16Constant(ref i) => write!(f, "{}", i),
+            Expression::I32Constant(ref i) => write!(f, "{}", i),
+            Expression::I64Constant(ref i) => write!(f, "{}", i),
             Expression::IntConstant(ref i) => write!(f, "{}", i),
+            Expression::ByteStringConstant(ref bytes) => write!(
+                f,
+                "b\"{}\"",
+                bytes
+                    .iter()
+                    .map(|b| match b {
+                        b'\'' => "'".to_string(),
+                        b => std::ascii::escape_default(*b).to_string(),
+                    })
+                    .collect::<String>()
+            ),
             Expression::Identifier(ref var) => write!(f, "{}", var),
             Expression::Add(ref lhs, ref rhs) => write!(f, "({} + {})", lhs, rhs),
             Expression::Sub(ref lhs, ref rhs) => write!(f, "({} - {})", lhs, rhs),
@@ -552,6 +860,30 @@ impl<'ast> fmt::Display for Expression<'ast> {
                 }
                 write!(f, ")")
             }
+            Expression::MethodCall(ref e, ref id, ref g, ref p) => {
+                write!(f, "{}.{}", e, id)?;
+                if let Some(g) = g {
+                    write!(
+                        f,
+                        "::<{}>",
+                        g.iter()
+                            .map(|g| g
+                                .as_ref()
+                                .map(|g| g.to_string())
+                                .unwrap_or_else(|| "_".into()))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )?;
+                }
+                write!(
+                    f,
+                    "({})",
+                    p.iter()
+                        .map(|p| p.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
             Expression::Lt(ref lhs, ref rhs) => write!(f, "({} < {})", lhs, rhs),
             Expression::Le(ref lhs, ref rhs) => write!(f, "({} <= {})", lhs, rhs),
             Expression::Eq(ref lhs, ref rhs) => write!(f, "({} == {})", lhs, rhs),
@@ -570,6 +902,9 @@ impl<'ast> fmt::Display for Expression<'ast> {
                 write!(f, "]")
             }
             Expression::ArrayInitializer(ref e, ref count) => write!(f, "[{}; {}]", e, count),
+            Expression::ArrayComprehension(ref e, ref var, ref from, ref to) => {
+                write!(f, "[{} for {} in {}..{}]", e, var, from, to)
+            }
             Expression::InlineStruct(ref id, ref members) => {
                 write!(f, "{} {{", id)?;
                 for (i, (member_id, e)) in members.iter().enumerate() {
@@ -580,18 +915,107 @@ impl<'ast> fmt::Display for Expression<'ast> {
                 }
                 write!(f, "}}")
             }
+            Expression::InlineTuple(ref exprs) => {
+                write!(f, "(")?;
+                for (i, e) in exprs.iter().enumerate() {
+                    write!(f, "{}", e)?;
+                    if i < exprs.len() - 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                if exprs.len() == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+            Expression::EnumValue(ref ty, ref variant, ref arguments) => {
+                write!(f, "{}::{}", ty, variant)?;
+                if !arguments.is_empty() {
+                    write!(
+                        f,
+                        "({})",
+                        arguments
+                            .iter()
+                            .map(|a| a.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            Expression::Match(ref e, ref arms) => {
+                write!(f, "match {} {{ ", e)?;
+                for (i, arm) in arms.iter().enumerate() {
+                    write!(f, "{}", arm)?;
+                    if i < arms.len() - 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                write!(f, " }}")
+            }
             Expression::Select(ref array, ref index) => write!(f, "{}[{}]", array, index),
             Expression::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
+            Expression::Element(ref tuple, ref index) => write!(f, "{}.{}", tuple, index),
             Expression::Or(ref lhs, ref rhs) => write!(f, "({} || {})", lhs, rhs),
             Expression::BitXor(ref lhs, ref rhs) => write!(f, "({} ^ {})", lhs, rhs),
             Expression::BitAnd(ref lhs, ref rhs) => write!(f, "({} & {})", lhs, rhs),
             Expression::BitOr(ref lhs, ref rhs) => write!(f, "({} | {})", lhs, rhs),
             Expression::LeftShift(ref lhs, ref rhs) => write!(f, "({} << {})", lhs, rhs),
             Expression::RightShift(ref lhs, ref rhs) => write!(f, "({} >> {})", lhs, rhs),
+            Expression::LeftRotate(ref lhs, ref rhs) => write!(f, "({} <<< {})", lhs, rhs),
+            Expression::RightRotate(ref lhs, ref rhs) => write!(f, "({} >>> {})", lhs, rhs),
+            Expression::Cast(ref e, ref ty) => write!(f, "({} as {})", e, ty),
         }
     }
 }
 
+/// A pattern in a match arm
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern<'ast> {
+    /// `_`, which matches any variant
+    Wildcard,
+    /// `Type::Variant(a, _)`, which matches a given variant and binds its payload. `None` is used for ignored elements
+    Variant(UserTypeId, Identifier<'ast>, Vec<Option<Identifier<'ast>>>),
+}
+
+impl<'ast> fmt::Display for Pattern<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Pattern::Wildcard => write!(f, "_"),
+            Pattern::Variant(ty, variant, bindings) => {
+                write!(f, "{}::{}", ty, variant)?;
+                if !bindings.is_empty() {
+                    write!(
+                        f,
+                        "({})",
+                        bindings
+                            .iter()
+                            .map(|b| b.unwrap_or("_"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An arm of a match expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm<'ast> {
+    pub pattern: Pattern<'ast>,
+    pub expression: ExpressionNode<'ast>,
+}
+
+impl<'ast> fmt::Display for MatchArm<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.expression)
+    }
+}
+
+pub type MatchArmNode<'ast> = Node<MatchArm<'ast>>;
+
 /// A list of expressions, used in return statements
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct ExpressionList<'ast> {
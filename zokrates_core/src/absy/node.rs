@@ -83,8 +83,14 @@ impl<'ast> NodeValue for SymbolDeclaration<'ast> {}
 impl<'ast> NodeValue for UnresolvedType<'ast> {}
 impl<'ast> NodeValue for StructDefinition<'ast> {}
 impl<'ast> NodeValue for StructDefinitionField<'ast> {}
+impl<'ast> NodeValue for EnumDefinition<'ast> {}
+impl<'ast> NodeValue for EnumDefinitionVariant<'ast> {}
+impl<'ast> NodeValue for MatchArm<'ast> {}
 impl<'ast> NodeValue for ConstantDefinition<'ast> {}
+impl<'ast> NodeValue for TypeDefinition<'ast> {}
 impl<'ast> NodeValue for Function<'ast> {}
+impl<'ast> NodeValue for ImplDefinition<'ast> {}
+impl<'ast> NodeValue for MethodDefinition<'ast> {}
 impl<'ast> NodeValue for Module<'ast> {}
 impl<'ast> NodeValue for CanonicalImport<'ast> {}
 impl<'ast> NodeValue for SymbolImport<'ast> {}
@@ -93,6 +99,7 @@ impl<'ast> NodeValue for Parameter<'ast> {}
 impl<'ast> NodeValue for Spread<'ast> {}
 impl<'ast> NodeValue for Range<'ast> {}
 impl<'ast> NodeValue for Identifier<'ast> {}
+impl<'ast> NodeValue for GenericDeclaration<'ast> {}
 
 impl<T: PartialEq> PartialEq for Node<T> {
     fn eq(&self, other: &Node<T>) -> bool {
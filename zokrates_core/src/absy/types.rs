@@ -1,4 +1,5 @@
 use crate::absy::ExpressionNode;
+use crate::absy::GenericArgument;
 use crate::absy::UnresolvedTypeNode;
 use std::fmt;
 
@@ -11,8 +12,10 @@ pub enum UnresolvedType<'ast> {
     FieldElement,
     Boolean,
     Uint(usize),
+    Int(usize),
     Array(Box<UnresolvedTypeNode<'ast>>, ExpressionNode<'ast>),
-    User(UserTypeId, Option<Vec<Option<ExpressionNode<'ast>>>>),
+    User(UserTypeId, Option<Vec<Option<GenericArgument<'ast>>>>),
+    Tuple(Vec<UnresolvedTypeNode<'ast>>),
 }
 
 impl<'ast> fmt::Display for UnresolvedType<'ast> {
@@ -21,6 +24,7 @@ impl<'ast> fmt::Display for UnresolvedType<'ast> {
             UnresolvedType::FieldElement => write!(f, "field"),
             UnresolvedType::Boolean => write!(f, "bool"),
             UnresolvedType::Uint(bitwidth) => write!(f, "u{}", bitwidth),
+            UnresolvedType::Int(bitwidth) => write!(f, "i{}", bitwidth),
             UnresolvedType::Array(ref ty, ref size) => write!(f, "{}[{}]", ty, size),
             UnresolvedType::User(ref id, ref generics) => {
                 write!(
@@ -46,6 +50,19 @@ impl<'ast> fmt::Display for UnresolvedType<'ast> {
                         .unwrap_or_default()
                 )
             }
+            UnresolvedType::Tuple(ref elements) => {
+                write!(f, "(")?;
+                for (i, e) in elements.iter().enumerate() {
+                    write!(f, "{}", e)?;
+                    if i < elements.len() - 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                if elements.len() == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -61,14 +78,14 @@ pub type FunctionIdentifier<'ast> = &'ast str;
 pub use self::signature::UnresolvedSignature;
 
 mod signature {
-    use crate::absy::ConstantGenericNode;
+    use crate::absy::GenericDeclarationNode;
     use std::fmt;
 
     use crate::absy::UnresolvedTypeNode;
 
     #[derive(Clone, PartialEq, Default)]
     pub struct UnresolvedSignature<'ast> {
-        pub generics: Vec<ConstantGenericNode<'ast>>,
+        pub generics: Vec<GenericDeclarationNode<'ast>>,
         pub inputs: Vec<UnresolvedTypeNode<'ast>>,
         pub outputs: Vec<UnresolvedTypeNode<'ast>>,
     }
@@ -108,7 +125,7 @@ mod signature {
             UnresolvedSignature::default()
         }
 
-        pub fn generics(mut self, generics: Vec<ConstantGenericNode<'ast>>) -> Self {
+        pub fn generics(mut self, generics: Vec<GenericDeclarationNode<'ast>>) -> Self {
             self.generics = generics;
             self
         }
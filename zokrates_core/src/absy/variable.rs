@@ -8,6 +8,7 @@ use crate::absy::Identifier;
 pub struct Variable<'ast> {
     pub id: Identifier<'ast>,
     pub _type: UnresolvedTypeNode<'ast>,
+    pub is_mutable: bool,
 }
 
 pub type VariableNode<'ast> = Node<Variable<'ast>>;
@@ -17,9 +18,15 @@ impl<'ast> Variable<'ast> {
         Variable {
             id: id.into(),
             _type: t,
+            is_mutable: false,
         }
     }
 
+    pub fn mutable(mut self, is_mutable: bool) -> Self {
+        self.is_mutable = is_mutable;
+        self
+    }
+
     pub fn get_type(&self) -> &UnresolvedType<'ast> {
         &self._type.value
     }
@@ -27,12 +34,19 @@ impl<'ast> Variable<'ast> {
 
 impl<'ast> fmt::Display for Variable<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}", self._type, self.id,)
+        match self.is_mutable {
+            true => write!(f, "{} mut {}", self._type, self.id),
+            false => write!(f, "{} {}", self._type, self.id),
+        }
     }
 }
 
 impl<'ast> fmt::Debug for Variable<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Variable(type: {:?}, id: {:?})", self._type, self.id,)
+        write!(
+            f,
+            "Variable(type: {:?}, id: {:?}, is_mutable: {:?})",
+            self._type, self.id, self.is_mutable
+        )
     }
 }
@@ -0,0 +1,310 @@
+//! Module containing the built-in `as` casts
+//!
+//! A cast `e as T` is checked in `semantics` and replaced by a call to a function generated here. These functions
+//! only call the bit embeds, so that a cast costs the same as the equivalent function in `utils/casts` of the
+//! standard library.
+
+use crate::embed::FlatEmbed;
+use crate::typed_absy::types::{DeclarationFunctionKey, DeclarationSignature, DeclarationType};
+use crate::typed_absy::*;
+use std::convert::TryFrom;
+use std::fmt;
+use zokrates_field::Field;
+
+/// The identifier of the generated cast functions. As `as` is a keyword, it cannot clash with user-defined functions.
+pub const CAST_FUNCTION_ID: &str = "as";
+
+/// The types which can appear on either side of a cast
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CastType {
+    FieldElement,
+    Boolean,
+    Uint(IntBitwidth),
+}
+
+impl fmt::Display for CastType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", DeclarationType::from(*self))
+    }
+}
+
+impl<'ast> From<CastType> for DeclarationType<'ast> {
+    fn from(t: CastType) -> Self {
+        match t {
+            CastType::FieldElement => DeclarationType::FieldElement,
+            CastType::Boolean => DeclarationType::Boolean,
+            CastType::Uint(bitwidth) => DeclarationType::Uint(bitwidth),
+        }
+    }
+}
+
+impl<'ast, T> TryFrom<&Type<'ast, T>> for CastType {
+    type Error = ();
+
+    fn try_from(t: &Type<'ast, T>) -> Result<Self, Self::Error> {
+        match t {
+            Type::FieldElement => Ok(CastType::FieldElement),
+            Type::Boolean => Ok(CastType::Boolean),
+            Type::Uint(bitwidth) => Ok(CastType::Uint(*bitwidth)),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The key of `embed` in the module `module_id`. The embeds are declared under the identifier of the cast functions,
+/// which their signatures tell apart: unlike their own identifiers, it does not start with `_`, so calls to them are
+/// inlined and reduced like the calls of any other function.
+fn embed_key<'ast>(module_id: &TypedModuleId, embed: FlatEmbed) -> DeclarationFunctionKey<'ast> {
+    DeclarationFunctionKey::with_location(module_id.to_path_buf(), CAST_FUNCTION_ID)
+        .signature(embed.signature())
+}
+
+/// The key of the function casting from `from` to `to` in the module `module_id`
+pub fn key<'ast>(
+    module_id: &TypedModuleId,
+    from: CastType,
+    to: CastType,
+) -> DeclarationFunctionKey<'ast> {
+    DeclarationFunctionKey::with_location(module_id.to_path_buf(), CAST_FUNCTION_ID).signature(
+        DeclarationSignature::new()
+            .inputs(vec![from.into()])
+            .outputs(vec![to.into()]),
+    )
+}
+
+/// A helper to build calls to the bit embeds, keeping track of the embeds used
+struct EmbedCalls<'a> {
+    module_id: &'a TypedModuleId,
+    embeds: Vec<FlatEmbed>,
+}
+
+impl<'a> EmbedCalls<'a> {
+    fn key<'ast>(&mut self, embed: FlatEmbed) -> DeclarationFunctionKey<'ast> {
+        self.embeds.push(embed);
+        embed_key(self.module_id, embed)
+    }
+
+    fn decompose<'ast, T: Field>(
+        &mut self,
+        bitwidth: IntBitwidth,
+        e: UExpression<'ast, T>,
+    ) -> ArrayExpression<'ast, T> {
+        ArrayExpression::function_call(
+            self.key(FlatEmbed::decomposition(bitwidth)),
+            vec![],
+            vec![e.into()],
+        )
+        .annotate(Type::Boolean, bitwidth.to_usize() as u32)
+    }
+
+    fn recompose<'ast, T: Field>(
+        &mut self,
+        bitwidth: IntBitwidth,
+        bits: ArrayExpression<'ast, T>,
+    ) -> UExpression<'ast, T> {
+        UExpression::function_call(
+            self.key(FlatEmbed::recomposition(bitwidth)),
+            vec![],
+            vec![bits.into()],
+        )
+        .annotate(bitwidth)
+    }
+
+    fn unpack<'ast, T: Field>(
+        &mut self,
+        bitwidth: usize,
+        e: FieldElementExpression<'ast, T>,
+    ) -> ArrayExpression<'ast, T> {
+        ArrayExpression::function_call(
+            self.key(FlatEmbed::Unpack),
+            vec![Some(
+                UExpressionInner::Value(bitwidth as u128).annotate(IntBitwidth::B32),
+            )],
+            vec![e.into()],
+        )
+        .annotate(Type::Boolean, bitwidth as u32)
+    }
+}
+
+/// The value of the integer `x` of bitwidth `from` as a field element, which is negative for negative signed integers
+fn uint_to_field<'ast, T: Field>(
+    embeds: &mut EmbedCalls,
+    statements: &mut Vec<TypedStatement<'ast, T>>,
+    from: IntBitwidth,
+    x: Identifier<'ast>,
+) -> FieldElementExpression<'ast, T> {
+    let n = from.to_usize();
+    let bits_id: Identifier<'ast> = "bits".into();
+
+    statements.push(TypedStatement::Definition(
+        Variable::array(bits_id.clone(), Type::Boolean, n as u32).into(),
+        embeds
+            .decompose(from, UExpressionInner::Identifier(x).annotate(from))
+            .into(),
+    ));
+
+    let bits = ArrayExpressionInner::Identifier(bits_id).annotate(Type::Boolean, n as u32);
+
+    (0..n)
+        .map(|i| {
+            let weight = T::from(2).pow(n - i - 1);
+            // the sign bit has a negative weight in two's complement
+            let weight = match from.is_signed() && i == 0 {
+                true => T::zero() - weight,
+                false => weight,
+            };
+            FieldElementExpression::if_else(
+                BooleanExpression::select(bits.clone(), i as u32),
+                FieldElementExpression::Number(weight),
+                FieldElementExpression::Number(T::zero()),
+            )
+        })
+        .fold(FieldElementExpression::Number(T::zero()), |acc, e| {
+            FieldElementExpression::Add(box acc, box e)
+        })
+}
+
+/// Generate the function casting from `from` to `to` in the module `module_id`, along with the embeds it calls
+///
+/// # Remarks
+/// * Narrowing integer casts keep the least significant bits
+/// * Widening integer casts sign-extend signed sources and zero-extend unsigned ones
+/// * `field as uN` checks that the field element fits in `N` bits
+/// * `field as iN` checks that the field element is in `[-2**(N-1), 2**(N-1))`
+/// * `bool` is cast to `0` or `1`
+/// * Casting a number to `bool` checks that it is `0` or `1`
+pub fn functions<'ast, T: Field>(
+    module_id: &TypedModuleId,
+    from: CastType,
+    to: CastType,
+) -> Vec<(DeclarationFunctionKey<'ast>, TypedFunctionSymbol<'ast, T>)> {
+    assert!(from != to);
+
+    let mut embeds = EmbedCalls {
+        module_id,
+        embeds: vec![],
+    };
+
+    let x: Identifier<'ast> = "x".into();
+    let bits_id: Identifier<'ast> = "bits".into();
+
+    let mut statements = vec![];
+
+    let res: TypedExpression<'ast, T> = match (from, to) {
+        (CastType::Boolean, CastType::FieldElement) => FieldElementExpression::if_else(
+            BooleanExpression::Identifier(x),
+            FieldElementExpression::Number(T::one()),
+            FieldElementExpression::Number(T::zero()),
+        )
+        .into(),
+        (CastType::Boolean, CastType::Uint(to)) => UExpression::if_else(
+            BooleanExpression::Identifier(x),
+            UExpressionInner::Value(1).annotate(to),
+            UExpressionInner::Value(0).annotate(to),
+        )
+        .into(),
+        (CastType::FieldElement, CastType::Uint(to)) if !to.is_signed() => {
+            let bits = embeds.unpack(to.to_usize(), FieldElementExpression::Identifier(x));
+            embeds.recompose(to, bits).into()
+        }
+        (CastType::FieldElement, CastType::Uint(to)) => {
+            // offsetting by 2**(N-1) maps [-2**(N-1), 2**(N-1)) to [0, 2**N), which is checked by unpacking to N bits.
+            // Removing the offset modulo 2**N gives the two's complement of the input.
+            let n = to.to_usize();
+            let offset = T::from(2).pow(n - 1);
+            let bits = embeds.unpack(
+                n,
+                FieldElementExpression::Add(
+                    box FieldElementExpression::Identifier(x),
+                    box FieldElementExpression::Number(offset),
+                ),
+            );
+            (embeds.recompose(to, bits) - UExpressionInner::Value(1 << (n - 1)).annotate(to)).into()
+        }
+        (CastType::FieldElement, CastType::Boolean) => {
+            // unpacking to a single bit checks that the value is 0 or 1
+            let bits = embeds.unpack(1, FieldElementExpression::Identifier(x));
+            BooleanExpression::select(bits, 0u32).into()
+        }
+        (CastType::Uint(from), CastType::FieldElement) => {
+            uint_to_field(&mut embeds, &mut statements, from, x).into()
+        }
+        (CastType::Uint(from), CastType::Boolean) => {
+            let value = uint_to_field(&mut embeds, &mut statements, from, x);
+            let bits = embeds.unpack(1, value);
+            BooleanExpression::select(bits, 0u32).into()
+        }
+        (CastType::Uint(from), CastType::Uint(to)) => {
+            let (n, m) = (from.to_usize(), to.to_usize());
+
+            let bits = if m <= n {
+                // keep the m least significant bits
+                let bits = embeds.decompose(from, UExpressionInner::Identifier(x).annotate(from));
+                match m < n {
+                    true => ArrayExpressionInner::Slice(
+                        box bits,
+                        box ((n - m) as u32).into(),
+                        box (n as u32).into(),
+                    )
+                    .annotate(Type::Boolean, m as u32),
+                    false => bits,
+                }
+            } else {
+                statements.push(TypedStatement::Definition(
+                    Variable::array(bits_id.clone(), Type::Boolean, n as u32).into(),
+                    embeds
+                        .decompose(from, UExpressionInner::Identifier(x).annotate(from))
+                        .into(),
+                ));
+
+                let bits =
+                    ArrayExpressionInner::Identifier(bits_id).annotate(Type::Boolean, n as u32);
+
+                // extend with the sign bit for signed sources, with zeroes otherwise
+                let extension: BooleanExpression<'ast, T> = match from.is_signed() {
+                    true => BooleanExpression::select(bits.clone(), 0u32),
+                    false => BooleanExpression::Value(false),
+                };
+
+                ArrayExpressionInner::Value(
+                    std::iter::repeat(TypedExpressionOrSpread::Expression(extension.into()))
+                        .take(m - n)
+                        .chain(std::iter::once(TypedExpressionOrSpread::Spread(
+                            bits.into(),
+                        )))
+                        .collect::<Vec<_>>()
+                        .into(),
+                )
+                .annotate(Type::Boolean, m as u32)
+            };
+
+            embeds.recompose(to, bits).into()
+        }
+        _ => unreachable!(),
+    };
+
+    statements.push(TypedStatement::Return(vec![res]));
+
+    let function = TypedFunction {
+        arguments: vec![DeclarationParameter {
+            id: DeclarationVariable::with_id_and_type("x", from.into()),
+            private: false,
+        }],
+        statements,
+        signature: DeclarationSignature::new()
+            .inputs(vec![from.into()])
+            .outputs(vec![to.into()]),
+    };
+
+    std::iter::once((
+        key(module_id, from, to),
+        TypedFunctionSymbol::Here(function),
+    ))
+    .chain(embeds.embeds.into_iter().map(|embed| {
+        (
+            embed_key(module_id, embed),
+            TypedFunctionSymbol::Flat(embed),
+        )
+    }))
+    .collect()
+}
@@ -12,10 +12,11 @@ use crate::semantics::{self, Checker};
 use crate::static_analysis;
 use crate::static_analysis::Analyse;
 use crate::typed_absy::abi::Abi;
+use crate::typed_absy::TypedProgram;
 use crate::zir::ZirProgram;
 use macros::process_macros;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -28,6 +29,7 @@ use zokrates_pest_ast as pest;
 pub struct CompilationArtifacts<T: Field> {
     prog: ir::Prog<T>,
     abi: Abi,
+    warnings: Vec<CompileWarning>,
 }
 
 impl<T: Field> CompilationArtifacts<T> {
@@ -38,6 +40,10 @@ impl<T: Field> CompilationArtifacts<T> {
     pub fn abi(&self) -> &Abi {
         &self.abi
     }
+
+    pub fn warnings(&self) -> &[CompileWarning] {
+        &self.warnings
+    }
 }
 
 #[derive(Debug)]
@@ -162,6 +168,74 @@ impl fmt::Display for CompileErrorInner {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum CompileWarningInner {
+    SemanticWarning(semantics::WarningInner),
+    AnalysisWarning(static_analysis::Warning),
+}
+
+impl CompileWarningInner {
+    pub fn in_file(self, context: &Path) -> CompileWarning {
+        CompileWarning {
+            value: self,
+            file: context.to_path_buf(),
+        }
+    }
+}
+
+/// A construct which is legal but likely to be a mistake, found while compiling the program
+#[derive(Debug, PartialEq)]
+pub struct CompileWarning {
+    file: PathBuf,
+    value: CompileWarningInner,
+}
+
+impl CompileWarning {
+    pub fn file(&self) -> &PathBuf {
+        &self.file
+    }
+
+    pub fn value(&self) -> &CompileWarningInner {
+        &self.value
+    }
+}
+
+impl From<semantics::Warning> for CompileWarning {
+    fn from(warning: semantics::Warning) -> Self {
+        CompileWarning {
+            value: CompileWarningInner::SemanticWarning(warning.inner),
+            file: warning.module_id,
+        }
+    }
+}
+
+impl From<static_analysis::Warning> for CompileWarningInner {
+    fn from(warning: static_analysis::Warning) -> Self {
+        CompileWarningInner::AnalysisWarning(warning)
+    }
+}
+
+impl fmt::Display for CompileWarningInner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CompileWarningInner::SemanticWarning(ref w) => {
+                let location = w
+                    .pos()
+                    .map(|p| format!("{}", p.0))
+                    .unwrap_or_else(|| "".to_string());
+                write!(f, "{}\n\t{}", location, w.message())
+            }
+            CompileWarningInner::AnalysisWarning(ref w) => {
+                let location = w
+                    .position()
+                    .map(|p| format!("{}", p))
+                    .unwrap_or_else(|| "".to_string());
+                write!(f, "{}\n\t{}", location, w)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct CompileConfig {
     pub allow_unconstrained_variables: bool,
@@ -189,7 +263,8 @@ pub fn compile<T: Field, E: Into<imports::Error>>(
 ) -> Result<CompilationArtifacts<T>, CompileErrors> {
     let arena = Arena::new();
 
-    let (typed_ast, abi) = check_with_arena(source, location.clone(), resolver, config, &arena)?;
+    let (typed_ast, abi, warnings) =
+        check_with_arena(source, location.clone(), resolver, config, &arena)?;
 
     // flatten input program
     log::debug!("Flatten");
@@ -216,6 +291,7 @@ pub fn compile<T: Field, E: Into<imports::Error>>(
     Ok(CompilationArtifacts {
         prog: optimized_ir_prog,
         abi,
+        warnings,
     })
 }
 
@@ -224,10 +300,11 @@ pub fn check<T: Field, E: Into<imports::Error>>(
     location: FilePath,
     resolver: Option<&dyn Resolver<E>>,
     config: &CompileConfig,
-) -> Result<(), CompileErrors> {
+) -> Result<Vec<CompileWarning>, CompileErrors> {
     let arena = Arena::new();
 
-    check_with_arena::<T, _>(source, location, resolver, config, &arena).map(|_| ())
+    check_with_arena::<T, _>(source, location, resolver, config, &arena)
+        .map(|(_, _, warnings)| warnings)
 }
 
 fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
@@ -236,17 +313,41 @@ fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
     resolver: Option<&dyn Resolver<E>>,
     config: &CompileConfig,
     arena: &'ast Arena<String>,
-) -> Result<(ZirProgram<'ast, T>, Abi), CompileErrors> {
-    let source = arena.alloc(source);
+) -> Result<(ZirProgram<'ast, T>, Abi, Vec<CompileWarning>), CompileErrors> {
+    let source: &str = arena.alloc(source);
 
     log::debug!("Parse program with entry file {}", location.display());
 
-    let compiled = parse_program::<T, E>(source, location, resolver, &arena)?;
+    let (compiled, syntax_errors) =
+        parse_program_with_recovery::<T, E>(source, location.clone(), resolver, &arena)?;
 
     log::debug!("Check semantics");
 
-    // check semantics
-    let typed_ast = Checker::check(compiled)
+    // check semantics. If the entry point has syntax errors, the parts of it which could be parsed are checked, and
+    // the semantic errors found there are reported along with the syntax errors
+    let checked = Checker::check(compiled);
+
+    if !syntax_errors.is_empty() {
+        // the code which could not be parsed may declare variables which are used later on, so the errors pointing at
+        // the names of these variables are not reported
+        let names = recovered_names(source, &syntax_errors);
+
+        let semantic_errors = checked
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(CompileError::from)
+            .filter(|e| {
+                !(e.file() == &location
+                    && matches!(pointed_text(source, e), Some(text) if names.contains(text)))
+            });
+
+        return Err(CompileErrors(
+            syntax_errors.into_iter().chain(semantic_errors).collect(),
+        ));
+    }
+
+    let (typed_ast, semantic_warnings) = checked
         .map_err(|errors| CompileErrors(errors.into_iter().map(CompileError::from).collect()))?;
 
     let main_module = typed_ast.main.clone();
@@ -254,9 +355,102 @@ fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
     log::debug!("Run static analysis");
 
     // analyse (unroll and constant propagation)
-    typed_ast
+    let (zir, abi, analysis_warnings) = typed_ast
         .analyse(config)
-        .map_err(|e| CompileErrors(vec![CompileErrorInner::from(e).in_file(&main_module)]))
+        .map_err(|e| CompileErrors(vec![CompileErrorInner::from(e).in_file(&main_module)]))?;
+
+    let warnings = semantic_warnings
+        .into_iter()
+        .map(CompileWarning::from)
+        .chain(
+            analysis_warnings
+                .into_iter()
+                .map(|w| CompileWarningInner::from(w).in_file(&main_module)),
+        )
+        .collect();
+
+    Ok((zir, abi, warnings))
+}
+
+/// The names of the variables declared by the statements of `source` which were blanked out to recover from
+/// `syntax_errors`. Syntax errors are found on the first line of these statements, which is where the variables
+/// are declared
+fn recovered_names<'a>(source: &'a str, syntax_errors: &[CompileError]) -> HashSet<&'a str> {
+    let lines: Vec<&str> = source.split('\n').collect();
+
+    syntax_errors
+        .iter()
+        .filter_map(|e| match e.value() {
+            CompileErrorInner::ParserError(e) => lines.get(e.line_col().0 .0 - 1).copied(),
+            _ => None,
+        })
+        .flat_map(declared_names)
+        .collect()
+}
+
+/// The names declared on the left hand side of a definition such as `field[2] a, mut u32 b = ...`
+fn declared_names(line: &str) -> Vec<&str> {
+    let bytes = line.as_bytes();
+
+    // find the `=` of the definition, which is not part of `==`, `!=`, `<=`, `>=` or `=>`
+    let definition = (0..bytes.len()).find(|i| {
+        bytes[*i] == b'='
+            && !matches!(bytes.get(i + 1), Some(b'=') | Some(b'>'))
+            && !(*i > 0 && matches!(bytes[i - 1], b'=' | b'!' | b'<' | b'>'))
+    });
+
+    let lhs = match definition {
+        Some(index) => &line[..index],
+        None => return vec![],
+    };
+
+    // a declaration is made of a type followed by a name, while an assignment only has an assignee
+    lhs.split(',')
+        .filter_map(|declaration| {
+            let words: Vec<_> = declaration.split_whitespace().collect();
+            match words.len() >= 2 {
+                true => words.last().copied(),
+                false => None,
+            }
+        })
+        .filter(|name| name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+        .collect()
+}
+
+/// The text of `source` which the error `e` points to, if it is on a single line
+fn pointed_text<'a>(source: &'a str, e: &CompileError) -> Option<&'a str> {
+    let (start, end) = match e.value() {
+        CompileErrorInner::SemanticError(e) => (*e.pos())?,
+        _ => return None,
+    };
+
+    if start.line != end.line || end.col < start.col {
+        return None;
+    }
+
+    let line = source.split('\n').nth(start.line.checked_sub(1)?)?;
+    let from = line.char_indices().nth(start.col.checked_sub(1)?)?.0;
+    let to = line
+        .char_indices()
+        .nth(end.col - 1)
+        .map(|(i, _)| i)
+        .unwrap_or_else(|| line.len());
+
+    Some(&line[from..to])
+}
+
+/// Check the semantics of a parsed program, returning the typed program along with the warnings found
+pub fn check_semantics<T: Field>(
+    program: Program,
+) -> Result<(TypedProgram<T>, Vec<CompileWarning>), CompileErrors> {
+    Checker::check(program)
+        .map(|(typed_program, warnings)| {
+            (
+                typed_program,
+                warnings.into_iter().map(CompileWarning::from).collect(),
+            )
+        })
+        .map_err(|errors| CompileErrors(errors.into_iter().map(CompileError::from).collect()))
 }
 
 pub fn parse_program<'ast, T: Field, E: Into<imports::Error>>(
@@ -265,16 +459,42 @@ pub fn parse_program<'ast, T: Field, E: Into<imports::Error>>(
     resolver: Option<&dyn Resolver<E>>,
     arena: &'ast Arena<String>,
 ) -> Result<Program<'ast>, CompileErrors> {
+    let (program, syntax_errors) =
+        parse_program_with_recovery::<T, E>(source, location, resolver, arena)?;
+
+    match syntax_errors.is_empty() {
+        true => Ok(program),
+        false => Err(CompileErrors(syntax_errors)),
+    }
+}
+
+/// Parse the program with entry point `source`, recovering from the syntax errors of the entry point. Return the
+/// program made of the code which could be parsed, along with these syntax errors
+pub fn parse_program_with_recovery<'ast, T: Field, E: Into<imports::Error>>(
+    source: &'ast str,
+    location: FilePath,
+    resolver: Option<&dyn Resolver<E>>,
+    arena: &'ast Arena<String>,
+) -> Result<(Program<'ast>, Vec<CompileError>), CompileErrors> {
     let mut modules = HashMap::new();
 
-    let main = parse_module::<T, E>(&source, location.clone(), resolver, &mut modules, &arena)?;
+    let (main, syntax_errors) = parse_module_with_recovery::<T, E>(
+        &source,
+        location.clone(),
+        resolver,
+        &mut modules,
+        &arena,
+    )?;
 
     modules.insert(location.clone(), main);
 
-    Ok(Program {
-        main: location,
-        modules,
-    })
+    Ok((
+        Program {
+            main: location,
+            modules,
+        },
+        syntax_errors,
+    ))
 }
 
 pub fn parse_module<'ast, T: Field, E: Into<imports::Error>>(
@@ -284,15 +504,56 @@ pub fn parse_module<'ast, T: Field, E: Into<imports::Error>>(
     modules: &mut HashMap<OwnedModuleId, Module<'ast>>,
     arena: &'ast Arena<String>,
 ) -> Result<Module<'ast>, CompileErrors> {
+    let (module, syntax_errors) =
+        parse_module_with_recovery::<T, E>(source, location, resolver, modules, arena)?;
+
+    match syntax_errors.is_empty() {
+        true => Ok(module),
+        false => Err(CompileErrors(syntax_errors)),
+    }
+}
+
+/// Parse the module `source`, recovering from its syntax errors. Return the module made of the code which could be
+/// parsed, along with these syntax errors
+fn parse_module_with_recovery<'ast, T: Field, E: Into<imports::Error>>(
+    source: &'ast str,
+    location: FilePath,
+    resolver: Option<&dyn Resolver<E>>,
+    modules: &mut HashMap<OwnedModuleId, Module<'ast>>,
+    arena: &'ast Arena<String>,
+) -> Result<(Module<'ast>, Vec<CompileError>), CompileErrors> {
     log::debug!("Generate pest AST for {}", location.display());
 
-    let ast = pest::generate_ast(&source)
-        .map_err(|e| CompileErrors::from(CompileErrorInner::from(e).in_file(&location)))?;
+    let (ast, syntax_errors) = match pest::generate_ast(&source) {
+        Ok(ast) => (ast, vec![]),
+        Err(_) => {
+            log::debug!("Recover from syntax errors in {}", location.display());
+
+            let (errors, recovered) = pest::recover(&source);
+
+            let errors: Vec<_> = errors
+                .into_iter()
+                .map(|e| CompileErrorInner::from(e).in_file(&location))
+                .collect();
+
+            match recovered {
+                // the ast refers to the recovered source, so we keep it in the arena
+                Some(recovered) => (pest::generate_ast(arena.alloc(recovered)).unwrap(), errors),
+                None => return Err(CompileErrors(errors)),
+            }
+        }
+    };
 
     log::debug!("Process macros for {}", location.display());
 
-    let ast = process_macros::<T>(ast)
-        .map_err(|e| CompileErrors::from(CompileErrorInner::from(e).in_file(&location)))?;
+    let ast = match process_macros::<T>(ast) {
+        Ok(ast) => ast,
+        Err(e) => {
+            let mut errors = syntax_errors;
+            errors.push(CompileErrorInner::from(e).in_file(&location));
+            return Err(CompileErrors(errors));
+        }
+    };
 
     log::debug!("Generate absy for {}", location.display());
 
@@ -300,13 +561,19 @@ pub fn parse_module<'ast, T: Field, E: Into<imports::Error>>(
 
     log::debug!("Apply imports to absy for {}", location.display());
 
-    Importer::apply_imports::<T, E>(
+    match Importer::apply_imports::<T, E>(
         module_without_imports,
         location.clone(),
         resolver,
         modules,
         &arena,
-    )
+    ) {
+        Ok(module) => Ok((module, syntax_errors)),
+        // the errors found in the code which could be parsed come after the syntax errors
+        Err(errors) => Err(CompileErrors(
+            syntax_errors.into_iter().chain(errors.0).collect(),
+        )),
+    }
 }
 
 #[cfg(test)]
@@ -350,6 +617,297 @@ mod test {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn conflicting_glob_imports() {
+        // two glob imports bringing different symbols under the same name are rejected
+
+        struct CustomResolver;
+
+        impl<E> Resolver<E> for CustomResolver {
+            fn resolve(
+                &self,
+                _: PathBuf,
+                import_location: PathBuf,
+            ) -> Result<(String, PathBuf), E> {
+                let loc = import_location.display().to_string();
+                match loc.as_str() {
+                    "foo" | "bar" => Ok((
+                        r#"
+pub def scale(field x) -> field:
+    return 2 * x
+"#
+                        .into(),
+                        import_location,
+                    )),
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        let source = r#"
+from "foo" import *
+from "bar" import *
+
+def main() -> field:
+    return scale(1)
+"#
+        .to_string();
+        let res = compile::<Bn128Field, io::Error>(
+            source,
+            "main".into(),
+            Some(&CustomResolver),
+            &CompileConfig::default(),
+        );
+
+        let errors = res.unwrap_err().0;
+
+        assert_eq!(errors.len(), 1);
+        match errors[0].value() {
+            CompileErrorInner::ImportError(e) => {
+                assert_eq!(
+                    e.message(),
+                    "scale is imported from both foo and bar by glob imports"
+                );
+                assert_eq!(e.pos().unwrap().0.line, 3);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn glob_imports_skip_embeds() {
+        // the constants a module imports from the embeds are not brought by a glob import of this module
+
+        struct CustomResolver;
+
+        impl<E> Resolver<E> for CustomResolver {
+            fn resolve(
+                &self,
+                _: PathBuf,
+                import_location: PathBuf,
+            ) -> Result<(String, PathBuf), E> {
+                let loc = import_location.display().to_string();
+                match loc.as_str() {
+                    "foo" => Ok((
+                        r#"
+from "EMBED" import FIELD_SIZE_IN_BITS
+
+def size() -> u32:
+    return FIELD_SIZE_IN_BITS
+"#
+                        .into(),
+                        import_location,
+                    )),
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        let source = r#"
+from "foo" import *
+
+def main() -> u32:
+    return size() + FIELD_SIZE_IN_BITS
+"#
+        .to_string();
+        let res = compile::<Bn128Field, io::Error>(
+            source,
+            "main".into(),
+            Some(&CustomResolver),
+            &CompileConfig::default(),
+        );
+
+        let errors = res.unwrap_err().0;
+
+        assert_eq!(errors.len(), 1);
+        match errors[0].value() {
+            CompileErrorInner::SemanticError(e) => {
+                assert_eq!(
+                    e.message(),
+                    "Identifier \"FIELD_SIZE_IN_BITS\" is undefined"
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn warnings() {
+        let source = r#"
+def main(field a) -> field:
+    field b = 1
+    assert(1 == 1)
+    return if 1 == 2 then 0 else a fi
+"#
+        .to_string();
+        let artifacts = compile::<Bn128Field, io::Error>(
+            source,
+            "main".into(),
+            None,
+            &CompileConfig::default(),
+        )
+        .unwrap();
+
+        let warnings: Vec<_> = artifacts
+            .warnings()
+            .iter()
+            .map(|w| (w.file().display().to_string(), w.value().to_string()))
+            .collect();
+
+        assert_eq!(
+            warnings,
+            vec![
+                ("main".into(), "3:11\n\tUnused variable `b`".into()),
+                ("main".into(), "4:5\n\tAssertion is always true".into()),
+                (
+                    "main".into(),
+                    "5:5\n\tCondition `1f == 2f` is always false".into()
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn no_warnings_for_unrolled_conditions() {
+        // the condition on the loop index is only constant once the loop is unrolled
+        let source = r#"
+def main(field a) -> field:
+    field b = a
+    for u32 i in 0..2 do
+        b = b + if i == 0 then 1 else 2 fi
+    endfor
+    return b
+"#
+        .to_string();
+        let artifacts = compile::<Bn128Field, io::Error>(
+            source,
+            "main".into(),
+            None,
+            &CompileConfig::default(),
+        )
+        .unwrap();
+
+        assert!(artifacts.warnings().is_empty());
+    }
+
+    #[test]
+    fn warnings_for_folded_conditions() {
+        // the condition on `x` is constant in the code as written, the one on `N` only in this instantiation of `foo`
+        let source = r#"
+def foo<N>(field a) -> field:
+    return if N == 1 then a else 0 fi
+
+def main(field a) -> field:
+    field x = 1
+    field y = foo::<1>(a)
+    return if x == 1 then y else 0 fi
+"#
+        .to_string();
+        let artifacts = compile::<Bn128Field, io::Error>(
+            source,
+            "main".into(),
+            None,
+            &CompileConfig::default(),
+        )
+        .unwrap();
+
+        let warnings: Vec<_> = artifacts
+            .warnings()
+            .iter()
+            .map(|w| w.value().to_string())
+            .collect();
+
+        assert_eq!(
+            warnings,
+            vec!["8:5\n\tCondition `x == 1f` is always true".to_string()]
+        );
+    }
+
+    #[test]
+    fn syntax_errors() {
+        // all syntax errors are reported, followed by the semantic errors found in the code which could be parsed
+        let source = r#"
+def foo() -> field:
+    field a = 1 +
+    return 1
+
+def main() -> field:
+    field b = (2
+    return c
+"#
+        .to_string();
+        let res = compile::<Bn128Field, io::Error>(
+            source,
+            "main".into(),
+            None,
+            &CompileConfig::default(),
+        );
+
+        let errors = res.unwrap_err().0;
+
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(
+            errors[0].value(),
+            CompileErrorInner::ParserError(_)
+        ));
+        assert!(matches!(
+            errors[1].value(),
+            CompileErrorInner::ParserError(_)
+        ));
+        match errors[2].value() {
+            CompileErrorInner::SemanticError(e) => {
+                assert_eq!(e.message(), "Identifier \"c\" is undefined");
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn syntax_error_in_declaration() {
+        // the uses of `a` do not produce errors as it is declared by the statement which could not be parsed
+        let source = r#"
+def main() -> field:
+    field a = (1
+    field b = a + 1
+    return b + c
+"#
+        .to_string();
+        let res = compile::<Bn128Field, io::Error>(
+            source,
+            "main".into(),
+            None,
+            &CompileConfig::default(),
+        );
+
+        let errors = res.unwrap_err().0;
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            errors[0].value(),
+            CompileErrorInner::ParserError(_)
+        ));
+        match errors[1].value() {
+            CompileErrorInner::SemanticError(e) => {
+                assert_eq!(e.message(), "Identifier \"c\" is undefined");
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn names_declared_by_a_line() {
+        assert_eq!(
+            super::declared_names("    field[2] a, mut u32 b = foo("),
+            vec!["a", "b"]
+        );
+        assert_eq!(super::declared_names("    a = a + 1"), Vec::<&str>::new());
+        assert_eq!(
+            super::declared_names("    assert(a == b"),
+            Vec::<&str>::new()
+        );
+        assert_eq!(super::declared_names("    bool c = a <= b +"), vec!["c"]);
+    }
+
     mod abi {
         use super::*;
         use crate::typed_absy::abi::*;
@@ -0,0 +1,398 @@
+//! Module containing the diagnostics reported to the user
+//!
+//! A `Diagnostic` is built from a `CompileError` or a `CompileWarning`. It can be rendered along with
+//! an excerpt of the source code it points to, or serialized for tools such as editors and CI bots.
+
+use crate::compile::{CompileError, CompileErrorInner, CompileWarning, CompileWarningInner};
+use crate::parser::Position;
+use crate::semantics::ErrorContext;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A range of the source code, along with an optional message about it
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Label {
+    pub start: Position,
+    pub end: Position,
+    pub message: Option<String>,
+}
+
+impl Label {
+    pub fn new(start: Position, end: Position) -> Self {
+        Label {
+            start,
+            end,
+            message: None,
+        }
+    }
+
+    pub fn with_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub file: PathBuf,
+    pub message: String,
+    /// The code this diagnostic is about, if it is known
+    pub label: Option<Label>,
+    /// Other code of the same file related to this diagnostic
+    pub secondary_labels: Vec<Label>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new<S: Into<String>>(severity: Severity, file: PathBuf, message: S) -> Self {
+        Diagnostic {
+            severity,
+            file,
+            message: message.into(),
+            label: None,
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn with_secondary_label(mut self, label: Label) -> Self {
+        self.secondary_labels.push(label);
+        self
+    }
+
+    pub fn with_note<S: Into<String>>(mut self, note: S) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Render this diagnostic for a terminal. If `source` is the content of the file, the lines pointed to by the
+    /// labels are printed with carets underneath
+    pub fn render(&self, source: Option<&str>) -> String {
+        let mut res = String::new();
+
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+
+        writeln!(res, "{}: {}", severity, self.message).unwrap();
+
+        let lines: Vec<&str> = source.map(|s| s.lines().collect()).unwrap_or_default();
+
+        // the labels pointing to lines we can print, in the order of the source. The primary label is underlined
+        // with carets, the secondary ones with dashes
+        let mut excerpts: Vec<(&Label, char)> = self
+            .label
+            .iter()
+            .map(|l| (l, '^'))
+            .chain(self.secondary_labels.iter().map(|l| (l, '-')))
+            .filter(|(l, _)| l.start.line >= 1 && l.start.line <= lines.len())
+            .collect();
+        excerpts.sort_by_key(|(l, _)| l.start);
+
+        let gutter = excerpts
+            .iter()
+            .map(|(l, _)| l.start.line.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        match &self.label {
+            Some(label) => writeln!(
+                res,
+                "{:gutter$}--> {}:{}",
+                "",
+                self.file.display(),
+                label.start,
+                gutter = gutter
+            ),
+            None => writeln!(
+                res,
+                "{:gutter$}--> {}",
+                "",
+                self.file.display(),
+                gutter = gutter
+            ),
+        }
+        .unwrap();
+
+        if !excerpts.is_empty() {
+            writeln!(res, "{:gutter$} |", "", gutter = gutter).unwrap();
+        }
+
+        for (label, marker) in excerpts {
+            let line = lines[label.start.line - 1];
+
+            // the columns are one-based and count characters
+            let end_col = match label.end.line == label.start.line {
+                true => label.end.col,
+                false => line.chars().count() + 1,
+            };
+
+            // keep the tabs of the line so that the markers are aligned with it
+            let padding: String = line
+                .chars()
+                .take(label.start.col.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+
+            let width = std::cmp::max(end_col.saturating_sub(label.start.col), 1);
+
+            writeln!(
+                res,
+                "{:>gutter$} | {}",
+                label.start.line,
+                line,
+                gutter = gutter
+            )
+            .unwrap();
+            write!(
+                res,
+                "{:gutter$} | {}{}",
+                "",
+                padding,
+                marker.to_string().repeat(width),
+                gutter = gutter
+            )
+            .unwrap();
+            match &label.message {
+                Some(message) => writeln!(res, " {}", message),
+                None => writeln!(res),
+            }
+            .unwrap();
+        }
+
+        for note in &self.notes {
+            writeln!(res, "{:gutter$} = note: {}", "", note, gutter = gutter).unwrap();
+        }
+
+        res
+    }
+}
+
+impl From<&CompileError> for Diagnostic {
+    fn from(e: &CompileError) -> Self {
+        let file = e.file().clone();
+
+        match e.value() {
+            CompileErrorInner::ParserError(e) => {
+                let ((start_line, start_col), (end_line, end_col)) = e.line_col();
+                Diagnostic::new(Severity::Error, file, "Syntax error").with_label(
+                    Label::new(
+                        Position {
+                            line: start_line,
+                            col: start_col,
+                        },
+                        Position {
+                            line: end_line,
+                            col: end_col,
+                        },
+                    )
+                    .with_message(e.message()),
+                )
+            }
+            CompileErrorInner::ImportError(e) => {
+                with_pos(Diagnostic::new(Severity::Error, file, e.message()), e.pos())
+            }
+            CompileErrorInner::SemanticError(e) => e.context().iter().fold(
+                with_pos(Diagnostic::new(Severity::Error, file, e.message()), e.pos()),
+                |d, c| match c {
+                    ErrorContext::Label((start, end), message) => d.with_secondary_label(
+                        Label::new(*start, *end).with_message(message.clone()),
+                    ),
+                    ErrorContext::Note(note) => d.with_note(note.clone()),
+                },
+            ),
+            CompileErrorInner::AnalysisError(e) => Diagnostic::new(
+                Severity::Error,
+                file,
+                e.to_string(),
+            )
+            .with_note(
+                "this error was found once functions were inlined, so its location is not known",
+            ),
+            CompileErrorInner::MacroError(e) => {
+                Diagnostic::new(Severity::Error, file, e.to_string())
+            }
+            CompileErrorInner::ReadError(e) => {
+                Diagnostic::new(Severity::Error, file, e.to_string())
+            }
+        }
+    }
+}
+
+impl From<&CompileWarning> for Diagnostic {
+    fn from(w: &CompileWarning) -> Self {
+        let file = w.file().clone();
+
+        match w.value() {
+            CompileWarningInner::SemanticWarning(w) => with_pos(
+                Diagnostic::new(Severity::Warning, file, w.message()),
+                w.pos(),
+            ),
+            CompileWarningInner::AnalysisWarning(w) => {
+                let d = Diagnostic::new(Severity::Warning, file, w.to_string());
+                match w.position() {
+                    Some(p) => d.with_label(Label::new(p, p)),
+                    None => d,
+                }
+            }
+        }
+    }
+}
+
+fn with_pos(d: Diagnostic, pos: &Option<(Position, Position)>) -> Diagnostic {
+    match pos {
+        Some((start, end)) => d.with_label(Label::new(*start, *end)),
+        None => d,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic() -> Diagnostic {
+        Diagnostic::new(
+            Severity::Error,
+            "main.zok".into(),
+            "Identifier \"b\" is undefined",
+        )
+        .with_label(Label::new(
+            Position { line: 2, col: 12 },
+            Position { line: 2, col: 13 },
+        ))
+    }
+
+    #[test]
+    fn render_with_source() {
+        let source = "def main(field a) -> field:\n    return b\n";
+
+        assert_eq!(
+            diagnostic().render(Some(source)),
+            r#"error: Identifier "b" is undefined
+ --> main.zok:2:12
+  |
+2 |     return b
+  |            ^
+"#
+        );
+    }
+
+    #[test]
+    fn render_without_source() {
+        assert_eq!(
+            diagnostic().render(None),
+            "error: Identifier \"b\" is undefined\n--> main.zok:2:12\n"
+        );
+    }
+
+    #[test]
+    fn render_secondary_label_and_note() {
+        let source = "def main() -> field:\n    field a = 1\n    field a = 2\n    return a\n";
+
+        let d = Diagnostic::new(
+            Severity::Error,
+            "main.zok".into(),
+            "Duplicate declaration for variable named a",
+        )
+        .with_label(Label::new(
+            Position { line: 3, col: 11 },
+            Position { line: 3, col: 12 },
+        ))
+        .with_secondary_label(
+            Label::new(Position { line: 2, col: 11 }, Position { line: 2, col: 12 })
+                .with_message("first declared here"),
+        )
+        .with_note("variables can only be shadowed by loop variables");
+
+        assert_eq!(
+            d.render(Some(source)),
+            r#"error: Duplicate declaration for variable named a
+ --> main.zok:3:11
+  |
+2 |     field a = 1
+  |           - first declared here
+3 |     field a = 2
+  |           ^
+  = note: variables can only be shadowed by loop variables
+"#
+        );
+    }
+
+    #[test]
+    fn from_compile_error() {
+        let source = "def main(field a) -> field:\n    return b\n".to_string();
+
+        let errors = crate::compile::compile::<zokrates_field::Bn128Field, std::io::Error>(
+            source,
+            "main.zok".into(),
+            None,
+            &crate::compile::CompileConfig::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(Diagnostic::from(&errors.0[0]), diagnostic());
+    }
+
+    #[test]
+    fn from_compile_error_with_context() {
+        let source =
+            "def main() -> field:\n    field a = 1\n    field a = 2\n    return a\n".to_string();
+
+        let errors = crate::compile::compile::<zokrates_field::Bn128Field, std::io::Error>(
+            source,
+            "main.zok".into(),
+            None,
+            &crate::compile::CompileConfig::default(),
+        )
+        .unwrap_err();
+
+        let d = Diagnostic::from(&errors.0[0]);
+
+        assert_eq!(d.message, "Duplicate declaration for variable named a");
+        assert_eq!(
+            d.secondary_labels,
+            vec![
+                Label::new(Position { line: 2, col: 11 }, Position { line: 2, col: 12 })
+                    .with_message("first declared here")
+            ]
+        );
+        assert_eq!(
+            d.notes,
+            vec!["variables can only be shadowed by loop variables".to_string()]
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_value(&diagnostic()).unwrap(),
+            serde_json::json!({
+                "severity": "error",
+                "file": "main.zok",
+                "message": "Identifier \"b\" is undefined",
+                "label": {
+                    "start": { "line": 2, "col": 12 },
+                    "end": { "line": 2, "col": 13 },
+                    "message": null
+                },
+                "secondary_labels": [],
+                "notes": []
+            })
+        );
+    }
+}
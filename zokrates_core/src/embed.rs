@@ -5,7 +5,7 @@ use crate::flat_absy::{
 use crate::solvers::Solver;
 use crate::typed_absy::types::{
     ConcreteGenericsAssignment, DeclarationConstant, DeclarationSignature, DeclarationType,
-    GenericIdentifier,
+    GenericIdentifier, IntBitwidth,
 };
 use std::collections::HashMap;
 use zokrates_field::Field;
@@ -34,10 +34,20 @@ pub enum FlatEmbed {
     U16ToBits,
     U32ToBits,
     U64ToBits,
+    U128ToBits,
     U8FromBits,
     U16FromBits,
     U32FromBits,
     U64FromBits,
+    U128FromBits,
+    I8ToBits,
+    I16ToBits,
+    I32ToBits,
+    I64ToBits,
+    I8FromBits,
+    I16FromBits,
+    I32FromBits,
+    I64FromBits,
     #[cfg(feature = "bellman")]
     Sha256Round,
     #[cfg(feature = "ark")]
@@ -110,6 +120,12 @@ impl FlatEmbed {
                     DeclarationType::Boolean,
                     64usize,
                 ))]),
+            FlatEmbed::U128ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(128)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    128usize,
+                ))]),
             FlatEmbed::U8FromBits => DeclarationSignature::new()
                 .outputs(vec![DeclarationType::uint(8)])
                 .inputs(vec![DeclarationType::array((
@@ -134,6 +150,60 @@ impl FlatEmbed {
                     DeclarationType::Boolean,
                     64usize,
                 ))]),
+            FlatEmbed::U128FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(128)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    128usize,
+                ))]),
+            FlatEmbed::I8ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(IntBitwidth::I8)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    8usize,
+                ))]),
+            FlatEmbed::I16ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(IntBitwidth::I16)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    16usize,
+                ))]),
+            FlatEmbed::I32ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(IntBitwidth::I32)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    32usize,
+                ))]),
+            FlatEmbed::I64ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(IntBitwidth::I64)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    64usize,
+                ))]),
+            FlatEmbed::I8FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(IntBitwidth::I8)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    8usize,
+                ))]),
+            FlatEmbed::I16FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(IntBitwidth::I16)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    16usize,
+                ))]),
+            FlatEmbed::I32FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(IntBitwidth::I32)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    32usize,
+                ))]),
+            FlatEmbed::I64FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(IntBitwidth::I64)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    64usize,
+                ))]),
             #[cfg(feature = "bellman")]
             FlatEmbed::Sha256Round => DeclarationSignature::new()
                 .inputs(vec![
@@ -187,8 +257,39 @@ impl FlatEmbed {
                 _ => unreachable!(),
             });
 
-        assert_eq!(gen.len(), assignment.0.len());
-        gen.map(|g| *assignment.0.get(&g).unwrap() as u32).collect()
+        assert_eq!(gen.len(), assignment.constants.len());
+        gen.map(|g| *assignment.constants.get(&g).unwrap() as u32)
+            .collect()
+    }
+
+    /// The embed decomposing an integer of a given bitwidth into its big-endian bits
+    pub fn decomposition(bitwidth: IntBitwidth) -> Self {
+        match bitwidth {
+            IntBitwidth::B8 => FlatEmbed::U8ToBits,
+            IntBitwidth::B16 => FlatEmbed::U16ToBits,
+            IntBitwidth::B32 => FlatEmbed::U32ToBits,
+            IntBitwidth::B64 => FlatEmbed::U64ToBits,
+            IntBitwidth::B128 => FlatEmbed::U128ToBits,
+            IntBitwidth::I8 => FlatEmbed::I8ToBits,
+            IntBitwidth::I16 => FlatEmbed::I16ToBits,
+            IntBitwidth::I32 => FlatEmbed::I32ToBits,
+            IntBitwidth::I64 => FlatEmbed::I64ToBits,
+        }
+    }
+
+    /// The embed recomposing an integer of a given bitwidth from its big-endian bits
+    pub fn recomposition(bitwidth: IntBitwidth) -> Self {
+        match bitwidth {
+            IntBitwidth::B8 => FlatEmbed::U8FromBits,
+            IntBitwidth::B16 => FlatEmbed::U16FromBits,
+            IntBitwidth::B32 => FlatEmbed::U32FromBits,
+            IntBitwidth::B64 => FlatEmbed::U64FromBits,
+            IntBitwidth::B128 => FlatEmbed::U128FromBits,
+            IntBitwidth::I8 => FlatEmbed::I8FromBits,
+            IntBitwidth::I16 => FlatEmbed::I16FromBits,
+            IntBitwidth::I32 => FlatEmbed::I32FromBits,
+            IntBitwidth::I64 => FlatEmbed::I64FromBits,
+        }
     }
 
     pub fn id(&self) -> &'static str {
@@ -199,10 +300,20 @@ impl FlatEmbed {
             FlatEmbed::U16ToBits => "_U16_TO_BITS",
             FlatEmbed::U32ToBits => "_U32_TO_BITS",
             FlatEmbed::U64ToBits => "_U64_TO_BITS",
+            FlatEmbed::U128ToBits => "_U128_TO_BITS",
             FlatEmbed::U8FromBits => "_U8_FROM_BITS",
             FlatEmbed::U16FromBits => "_U16_FROM_BITS",
             FlatEmbed::U32FromBits => "_U32_FROM_BITS",
             FlatEmbed::U64FromBits => "_U64_FROM_BITS",
+            FlatEmbed::U128FromBits => "_U128_FROM_BITS",
+            FlatEmbed::I8ToBits => "_I8_TO_BITS",
+            FlatEmbed::I16ToBits => "_I16_TO_BITS",
+            FlatEmbed::I32ToBits => "_I32_TO_BITS",
+            FlatEmbed::I64ToBits => "_I64_TO_BITS",
+            FlatEmbed::I8FromBits => "_I8_FROM_BITS",
+            FlatEmbed::I16FromBits => "_I16_FROM_BITS",
+            FlatEmbed::I32FromBits => "_I32_FROM_BITS",
+            FlatEmbed::I64FromBits => "_I64_FROM_BITS",
             #[cfg(feature = "bellman")]
             FlatEmbed::Sha256Round => "_SHA256_ROUND",
             #[cfg(feature = "ark")]
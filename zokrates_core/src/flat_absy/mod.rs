@@ -14,6 +14,7 @@ pub use self::flat_variable::FlatVariable;
 use serde::{Deserialize, Serialize};
 
 use crate::solvers::Solver;
+use crate::source_map::{CallStack, SourceLocation};
 use std::collections::HashMap;
 use std::fmt;
 use zokrates_field::Field;
@@ -43,9 +44,10 @@ pub enum RuntimeError {
     Euclidean,
     ShaXor,
     Division,
-    Source,
+    SourceAssertion(String),
     ArgumentBitness,
     SelectRangeCheck,
+    EnumTagCheck,
 }
 
 impl RuntimeError {
@@ -54,11 +56,26 @@ impl RuntimeError {
 
         !matches!(
             self,
-            Source | Inverse | LtSum | SelectRangeCheck | ArgumentBitness
+            SourceAssertion(_)
+                | Inverse
+                | LtSum
+                | SelectRangeCheck
+                | ArgumentBitness
+                | EnumTagCheck
         )
     }
 }
 
+impl From<crate::zir::RuntimeError> for RuntimeError {
+    fn from(error: crate::zir::RuntimeError) -> Self {
+        match error {
+            crate::zir::RuntimeError::SourceAssertion(s) => RuntimeError::SourceAssertion(s),
+            crate::zir::RuntimeError::SelectRangeCheck => RuntimeError::SelectRangeCheck,
+            crate::zir::RuntimeError::EnumTagCheck => RuntimeError::EnumTagCheck,
+        }
+    }
+}
+
 impl fmt::Display for RuntimeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use RuntimeError::*;
@@ -87,9 +104,10 @@ impl fmt::Display for RuntimeError {
             Euclidean => "Euclidean check failed",
             ShaXor => "Internal Sha check failed",
             Division => "Division check failed",
-            Source => "User assertion failed",
+            SourceAssertion(m) => m.as_str(),
             ArgumentBitness => "Argument bitness check failed",
             SelectRangeCheck => "Out of bounds array access",
+            EnumTagCheck => "Invalid enum variant",
         };
 
         write!(f, "{}", msg)?;
@@ -179,6 +197,7 @@ pub enum FlatStatement<T: Field> {
     Condition(FlatExpression<T>, FlatExpression<T>, RuntimeError),
     Definition(FlatVariable, FlatExpression<T>),
     Directive(FlatDirective<T>),
+    Location(SourceLocation, CallStack),
 }
 
 impl<T: Field> fmt::Display for FlatStatement<T> {
@@ -190,6 +209,7 @@ impl<T: Field> fmt::Display for FlatStatement<T> {
                 write!(f, "{} == {} // {}", lhs, rhs, message)
             }
             FlatStatement::Directive(ref d) => write!(f, "{}", d),
+            FlatStatement::Location(ref location, _) => write!(f, "// {}", location),
         }
     }
 }
@@ -203,6 +223,9 @@ impl<T: Field> fmt::Debug for FlatStatement<T> {
                 write!(f, "FlatCondition({:?}, {:?}, {:?})", lhs, rhs, error)
             }
             FlatStatement::Directive(ref d) => write!(f, "{:?}", d),
+            FlatStatement::Location(ref location, ref stack) => {
+                write!(f, "FlatLocation({}, {:?})", location, stack)
+            }
         }
     }
 }
@@ -241,6 +264,7 @@ impl<T: Field> FlatStatement<T> {
                     ..d
                 })
             }
+            FlatStatement::Location(location, stack) => FlatStatement::Location(location, stack),
         }
     }
 }
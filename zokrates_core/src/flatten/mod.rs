@@ -7,11 +7,12 @@
 
 mod utils;
 
-use self::utils::flat_expression_from_bits;
+use self::utils::{flat_expression_from_bits, split_limbs};
 use crate::ir::Interpreter;
 
 use crate::compile::CompileConfig;
 use crate::embed::FlatEmbed;
+use crate::flat_absy::RuntimeError;
 use crate::flat_absy::*;
 use crate::solvers::Solver;
 use crate::zir::types::{Type, UBitwidth};
@@ -915,6 +916,14 @@ impl<'ast, T: Field> Flattener<'ast, T> {
 
                 self.eq_check(statements_flattened, lhs, rhs)
             }
+            BooleanExpression::UintBit(box e, index) => {
+                // the bit is read from the decomposition of the operand, which is free if it is already known
+                let e = self.flatten_uint_expression(statements_flattened, e);
+
+                let e_bits = e.bits.unwrap();
+
+                e_bits[index as usize].clone()
+            }
             BooleanExpression::FieldLe(box lhs, box rhs) => {
                 let lt = self.flatten_boolean_expression(
                     statements_flattened,
@@ -1128,22 +1137,32 @@ impl<'ast, T: Field> Flattener<'ast, T> {
             .collect();
 
         match embed {
-            FlatEmbed::U8ToBits => self.u_to_bits(params.pop().unwrap(), 8.into()),
-            FlatEmbed::U16ToBits => self.u_to_bits(params.pop().unwrap(), 16.into()),
-            FlatEmbed::U32ToBits => self.u_to_bits(params.pop().unwrap(), 32.into()),
-            FlatEmbed::U64ToBits => self.u_to_bits(params.pop().unwrap(), 64.into()),
-            FlatEmbed::U8FromBits => {
+            FlatEmbed::U8ToBits | FlatEmbed::I8ToBits => {
+                self.u_to_bits(params.pop().unwrap(), 8.into())
+            }
+            FlatEmbed::U16ToBits | FlatEmbed::I16ToBits => {
+                self.u_to_bits(params.pop().unwrap(), 16.into())
+            }
+            FlatEmbed::U32ToBits | FlatEmbed::I32ToBits => {
+                self.u_to_bits(params.pop().unwrap(), 32.into())
+            }
+            FlatEmbed::U64ToBits | FlatEmbed::I64ToBits => {
+                self.u_to_bits(params.pop().unwrap(), 64.into())
+            }
+            FlatEmbed::U128ToBits => self.u_to_bits(params.pop().unwrap(), 128.into()),
+            FlatEmbed::U8FromBits | FlatEmbed::I8FromBits => {
                 vec![self.bits_to_u(params, 8.into())]
             }
-            FlatEmbed::U16FromBits => {
+            FlatEmbed::U16FromBits | FlatEmbed::I16FromBits => {
                 vec![self.bits_to_u(params, 16.into())]
             }
-            FlatEmbed::U32FromBits => {
+            FlatEmbed::U32FromBits | FlatEmbed::I32FromBits => {
                 vec![self.bits_to_u(params, 32.into())]
             }
-            FlatEmbed::U64FromBits => {
+            FlatEmbed::U64FromBits | FlatEmbed::I64FromBits => {
                 vec![self.bits_to_u(params, 64.into())]
             }
+            FlatEmbed::U128FromBits => vec![self.bits_to_u(params, 128.into())],
             FlatEmbed::BitArrayLe => {
                 // get the length of the bit arrays
                 let len = generics[0];
@@ -1246,6 +1265,9 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                 inputs: new_inputs,
                             })
                         }
+                        FlatStatement::Location(location, stack) => {
+                            FlatStatement::Location(location, stack)
+                        }
                     })
                     .collect();
 
@@ -1347,6 +1369,62 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         FlatUExpression::with_bits(xor)
     }
 
+    /// Multiplies two reduced uints whose product may not fit in the field
+    ///
+    /// Writing `a = a_high * 2**h + a_low` with `h` half the bitwidth `w`, and similarly for `b`, we have
+    /// `a * b mod 2**w = (a_low * b_low + 2**h * ((a_high * b_low + a_low * b_high) mod 2**h)) mod 2**w`
+    /// where all intermediate values stay below `2**(w + 1)`
+    fn limb_mult(
+        &mut self,
+        statements_flattened: &mut FlatStatements<T>,
+        bitwidth: UBitwidth,
+        left_bits: Vec<FlatExpression<T>>,
+        right_bits: Vec<FlatExpression<T>>,
+    ) -> FlatUExpression<T> {
+        let limb_bitwidth = bitwidth.to_usize() / 2;
+
+        let (left_high, left_low) = split_limbs(left_bits);
+        let (right_high, right_low) = split_limbs(right_bits);
+
+        let low = self.define(
+            FlatExpression::Mult(box left_low.clone(), box right_low.clone()),
+            statements_flattened,
+        );
+        let cross_left = self.define(
+            FlatExpression::Mult(box left_high, box right_low),
+            statements_flattened,
+        );
+        let cross_right = self.define(
+            FlatExpression::Mult(box left_low, box right_high),
+            statements_flattened,
+        );
+
+        // the cross term is smaller than `2**(w + 1)`, keep its `h` lowest bits
+        let cross_bits = self.get_bits(
+            &FlatUExpression::with_field(FlatExpression::Add(
+                box cross_left.into(),
+                box cross_right.into(),
+            )),
+            bitwidth.to_usize() + 1,
+            limb_bitwidth.into(),
+            statements_flattened,
+        );
+
+        // shift the cross bits by `h` bit by bit so that the result stays a linear combination
+        FlatUExpression::with_field(cross_bits.into_iter().rev().enumerate().fold(
+            low.into(),
+            |acc, (index, bit)| {
+                FlatExpression::Add(
+                    box acc,
+                    box FlatExpression::Mult(
+                        box FlatExpression::Number(T::from(2).pow(limb_bitwidth + index)),
+                        box bit,
+                    ),
+                )
+            },
+        ))
+    }
+
     fn euclidean_division(
         &mut self,
         statements_flattened: &mut FlatStatements<T>,
@@ -1357,9 +1435,9 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         let left_flattened = self
             .flatten_uint_expression(statements_flattened, left)
             .get_field_unchecked();
-        let right_flattened = self
-            .flatten_uint_expression(statements_flattened, right)
-            .get_field_unchecked();
+        let right_flattened = self.flatten_uint_expression(statements_flattened, right);
+        let right_bits = right_flattened.bits.clone();
+        let right_flattened = right_flattened.get_field_unchecked();
         let n = if left_flattened.is_linear() {
             left_flattened
         } else {
@@ -1403,7 +1481,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         }));
 
         // q in range
-        let _ = self.get_bits(
+        let q_bits = self.get_bits(
             &FlatUExpression::with_field(FlatExpression::from(q)),
             target_bitwidth.to_usize(),
             target_bitwidth,
@@ -1422,19 +1500,68 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         let _ = self.get_bits(
             &FlatUExpression::with_field(FlatExpression::Add(
                 box FlatExpression::Sub(box r.into(), box d.clone()),
-                box FlatExpression::Number(T::from(2_u128.pow(target_bitwidth.to_usize() as u32))),
+                box FlatExpression::Number(T::from(2).pow(target_bitwidth.to_usize())),
             )),
             target_bitwidth.to_usize(),
             target_bitwidth,
             statements_flattened,
         );
 
-        // q*d == n - r
-        statements_flattened.push(FlatStatement::Condition(
-            FlatExpression::Sub(box n, box r.into()),
-            FlatExpression::Mult(box q.into(), box d),
-            RuntimeError::Euclidean,
-        ));
+        match target_bitwidth.requires_limbs::<T>() {
+            true => {
+                // `q*d` could overflow the field, so we check `q*d == n - r` on limbs, see `limb_mult`
+                let (q_high, q_low) = split_limbs(q_bits);
+                let (d_high, d_low) = split_limbs(right_bits.unwrap());
+
+                // q_high*d_high == 0
+                statements_flattened.push(FlatStatement::Condition(
+                    FlatExpression::Number(T::zero()),
+                    FlatExpression::Mult(box q_high.clone(), box d_high.clone()),
+                    RuntimeError::Euclidean,
+                ));
+
+                let low = self.define(
+                    FlatExpression::Mult(box q_low.clone(), box d_low.clone()),
+                    statements_flattened,
+                );
+                let cross_left = self.define(
+                    FlatExpression::Mult(box q_high, box d_low),
+                    statements_flattened,
+                );
+                let cross_right = self.define(
+                    FlatExpression::Mult(box q_low, box d_high),
+                    statements_flattened,
+                );
+
+                // q_low*d_low + 2**(w/2)*q_high*d_low + 2**(w/2)*q_low*d_high == n - r
+                let shift = T::from(2).pow(target_bitwidth.to_usize() / 2);
+                statements_flattened.push(FlatStatement::Condition(
+                    FlatExpression::Sub(box n, box r.into()),
+                    FlatExpression::Add(
+                        box FlatExpression::Add(
+                            box low.into(),
+                            box FlatExpression::Mult(
+                                box FlatExpression::Number(shift.clone()),
+                                box cross_left.into(),
+                            ),
+                        ),
+                        box FlatExpression::Mult(
+                            box FlatExpression::Number(shift),
+                            box cross_right.into(),
+                        ),
+                    ),
+                    RuntimeError::Euclidean,
+                ));
+            }
+            false => {
+                // q*d == n - r
+                statements_flattened.push(FlatStatement::Condition(
+                    FlatExpression::Sub(box n, box r.into()),
+                    FlatExpression::Mult(box q.into(), box d),
+                    RuntimeError::Euclidean,
+                ));
+            }
+        }
 
         (q.into(), r.into())
     }
@@ -1592,6 +1719,56 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                         .collect::<Vec<_>>(),
                 )
             }
+            UExpressionInner::LeftRotate(box e, by) => {
+                // a rotation is a permutation of the bits, which does not require any constraint
+                let e = self.flatten_uint_expression(statements_flattened, e);
+
+                let mut e_bits = e.bits.unwrap();
+
+                assert_eq!(e_bits.len(), target_bitwidth.to_usize());
+
+                e_bits.rotate_left(by as usize);
+
+                FlatUExpression::with_bits(e_bits)
+            }
+            UExpressionInner::RightRotate(box e, by) => {
+                let e = self.flatten_uint_expression(statements_flattened, e);
+
+                let mut e_bits = e.bits.unwrap();
+
+                assert_eq!(e_bits.len(), target_bitwidth.to_usize());
+
+                e_bits.rotate_right(by as usize);
+
+                FlatUExpression::with_bits(e_bits)
+            }
+            UExpressionInner::Slice(box e, from, to) => {
+                let e = self.flatten_uint_expression(statements_flattened, e);
+
+                let e_bits = e.bits.unwrap();
+
+                let bits = e_bits[from as usize..to as usize].to_vec();
+
+                assert_eq!(bits.len(), target_bitwidth.to_usize());
+
+                FlatUExpression::with_bits(bits)
+            }
+            UExpressionInner::Mult(box left, box right)
+                if target_bitwidth.requires_limbs::<T>()
+                    && left.metadata.as_ref().unwrap().should_reduce.is_true()
+                    && right.metadata.as_ref().unwrap().should_reduce.is_true() =>
+            {
+                let left_bits = self
+                    .flatten_uint_expression(statements_flattened, left)
+                    .bits
+                    .unwrap();
+                let right_bits = self
+                    .flatten_uint_expression(statements_flattened, right)
+                    .bits
+                    .unwrap();
+
+                self.limb_mult(statements_flattened, target_bitwidth, left_bits, right_bits)
+            }
             UExpressionInner::Mult(box left, box right) => {
                 let left_flattened = self
                     .flatten_uint_expression(statements_flattened, left)
@@ -2367,13 +2544,13 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                         .insert(FlatExpression::Identifier(var), bits);
                 }
             }
-            ZirStatement::Assertion(e) => {
+            ZirStatement::Assertion(e, error) => {
                 match e {
                     BooleanExpression::And(..) => {
                         for boolean in e.into_conjunction_iterator() {
                             self.flatten_statement(
                                 statements_flattened,
-                                ZirStatement::Assertion(boolean),
+                                ZirStatement::Assertion(boolean, error.clone()),
                             )
                         }
                     }
@@ -2381,7 +2558,12 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                         let lhs = self.flatten_field_expression(statements_flattened, lhs);
                         let rhs = self.flatten_field_expression(statements_flattened, rhs);
 
-                        self.flatten_equality_assertion(statements_flattened, lhs, rhs)
+                        self.flatten_equality_assertion(
+                            statements_flattened,
+                            lhs,
+                            rhs,
+                            error.into(),
+                        )
                     }
                     BooleanExpression::UintEq(box lhs, box rhs) => {
                         let lhs = self
@@ -2391,13 +2573,23 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                             .flatten_uint_expression(statements_flattened, rhs)
                             .get_field_unchecked();
 
-                        self.flatten_equality_assertion(statements_flattened, lhs, rhs)
+                        self.flatten_equality_assertion(
+                            statements_flattened,
+                            lhs,
+                            rhs,
+                            error.into(),
+                        )
                     }
                     BooleanExpression::BoolEq(box lhs, box rhs) => {
                         let lhs = self.flatten_boolean_expression(statements_flattened, lhs);
                         let rhs = self.flatten_boolean_expression(statements_flattened, rhs);
 
-                        self.flatten_equality_assertion(statements_flattened, lhs, rhs)
+                        self.flatten_equality_assertion(
+                            statements_flattened,
+                            lhs,
+                            rhs,
+                            error.into(),
+                        )
                     }
                     _ => {
                         // naive approach: flatten the boolean to a single field element and constrain it to 1
@@ -2407,14 +2599,14 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                             statements_flattened.push(FlatStatement::Condition(
                                 e,
                                 FlatExpression::Number(T::from(1)),
-                                RuntimeError::Source,
+                                error.into(),
                             ));
                         } else {
                             // swap so that left side is linear
                             statements_flattened.push(FlatStatement::Condition(
                                 FlatExpression::Number(T::from(1)),
                                 e,
-                                RuntimeError::Source,
+                                error.into(),
                             ));
                         }
                     }
@@ -2454,10 +2646,15 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                             .collect();
 
                         match embed {
-                            FlatEmbed::U64FromBits
+                            FlatEmbed::U128FromBits
+                            | FlatEmbed::U64FromBits
                             | FlatEmbed::U32FromBits
                             | FlatEmbed::U16FromBits
-                            | FlatEmbed::U8FromBits => {
+                            | FlatEmbed::U8FromBits
+                            | FlatEmbed::I64FromBits
+                            | FlatEmbed::I32FromBits
+                            | FlatEmbed::I16FromBits
+                            | FlatEmbed::I8FromBits => {
                                 let bits = exprs
                                     .into_iter()
                                     .map(|e| {
@@ -2472,6 +2669,9 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     }
                 }
             }
+            ZirStatement::Location(location, stack) => {
+                statements_flattened.push(FlatStatement::Location(location, stack));
+            }
         }
     }
 
@@ -2521,11 +2721,13 @@ impl<'ast, T: Field> Flattener<'ast, T> {
     /// * `statements_flattened` - `FlatStatements<T>` Vector where new flattened statements can be added.
     /// * `lhs` - `FlatExpression<T>` Left-hand side of the equality expression.
     /// * `rhs` - `FlatExpression<T>` Right-hand side of the equality expression.
+    /// * `error` - `RuntimeError` Error reported if the equality does not hold.
     fn flatten_equality_assertion(
         &mut self,
         statements_flattened: &mut FlatStatements<T>,
         lhs: FlatExpression<T>,
         rhs: FlatExpression<T>,
+        error: RuntimeError,
     ) {
         let (lhs, rhs) = match (lhs, rhs) {
             (FlatExpression::Mult(box x, box y), z) | (z, FlatExpression::Mult(box x, box y)) => (
@@ -2543,7 +2745,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 ),
             ),
         };
-        statements_flattened.push(FlatStatement::Condition(lhs, rhs, RuntimeError::Source));
+        statements_flattened.push(FlatStatement::Condition(lhs, rhs, error));
     }
 
     /// Identifies a non-linear expression by assigning it to a new identifier.
@@ -2676,10 +2878,13 @@ mod tests {
                     Variable::boolean("y".into()),
                     BooleanExpression::Value(true).into(),
                 ),
-                ZirStatement::Assertion(BooleanExpression::BoolEq(
-                    box BooleanExpression::Identifier("x".into()),
-                    box BooleanExpression::Identifier("y".into()),
-                )),
+                ZirStatement::Assertion(
+                    BooleanExpression::BoolEq(
+                        box BooleanExpression::Identifier("x".into()),
+                        box BooleanExpression::Identifier("y".into()),
+                    ),
+                    crate::zir::RuntimeError::mock(),
+                ),
             ],
             signature: Signature {
                 inputs: vec![],
@@ -2708,7 +2913,7 @@ mod tests {
                         box FlatExpression::Identifier(FlatVariable::new(0)),
                         box FlatExpression::Number(Bn128Field::from(1)),
                     ),
-                    RuntimeError::Source,
+                    crate::zir::RuntimeError::mock().into(),
                 ),
             ],
         };
@@ -2738,13 +2943,16 @@ mod tests {
                     Variable::field_element("y"),
                     FieldElementExpression::Number(Bn128Field::from(2)).into(),
                 ),
-                ZirStatement::Assertion(BooleanExpression::FieldEq(
-                    box FieldElementExpression::Add(
-                        box FieldElementExpression::Identifier("x".into()),
-                        box FieldElementExpression::Number(Bn128Field::from(1)),
+                ZirStatement::Assertion(
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Add(
+                            box FieldElementExpression::Identifier("x".into()),
+                            box FieldElementExpression::Number(Bn128Field::from(1)),
+                        ),
+                        box FieldElementExpression::Identifier("y".into()),
                     ),
-                    box FieldElementExpression::Identifier("y".into()),
-                )),
+                    crate::zir::RuntimeError::mock(),
+                ),
             ],
             signature: Signature {
                 inputs: vec![],
@@ -2776,7 +2984,7 @@ mod tests {
                         ),
                         box FlatExpression::Number(Bn128Field::from(1)),
                     ),
-                    RuntimeError::Source,
+                    crate::zir::RuntimeError::mock().into(),
                 ),
             ],
         };
@@ -2808,12 +3016,15 @@ mod tests {
                             .metadata(metadata.clone()),
                     ),
                 ),
-                ZirStatement::Assertion(BooleanExpression::UintEq(
-                    box UExpressionInner::Identifier("x".into())
-                        .annotate(32)
-                        .metadata(metadata.clone()),
-                    box UExpressionInner::Value(42).annotate(32).metadata(metadata),
-                )),
+                ZirStatement::Assertion(
+                    BooleanExpression::UintEq(
+                        box UExpressionInner::Identifier("x".into())
+                            .annotate(32)
+                            .metadata(metadata.clone()),
+                        box UExpressionInner::Value(42).annotate(32).metadata(metadata),
+                    ),
+                    crate::zir::RuntimeError::mock(),
+                ),
             ],
             signature: Signature {
                 inputs: vec![],
@@ -2838,7 +3049,7 @@ mod tests {
                         box FlatExpression::Identifier(FlatVariable::new(0)),
                         box FlatExpression::Number(Bn128Field::from(1)),
                     ),
-                    RuntimeError::Source,
+                    crate::zir::RuntimeError::mock().into(),
                 ),
             ],
         };
@@ -2868,10 +3079,13 @@ mod tests {
                     Variable::field_element("y"),
                     FieldElementExpression::Number(Bn128Field::from(2)).into(),
                 ),
-                ZirStatement::Assertion(BooleanExpression::FieldEq(
-                    box FieldElementExpression::Identifier("x".into()),
-                    box FieldElementExpression::Identifier("y".into()),
-                )),
+                ZirStatement::Assertion(
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Identifier("x".into()),
+                        box FieldElementExpression::Identifier("y".into()),
+                    ),
+                    crate::zir::RuntimeError::mock(),
+                ),
             ],
             signature: Signature {
                 inputs: vec![],
@@ -2900,7 +3114,7 @@ mod tests {
                         box FlatExpression::Identifier(FlatVariable::new(0)),
                         box FlatExpression::Number(Bn128Field::from(1)),
                     ),
-                    RuntimeError::Source,
+                    crate::zir::RuntimeError::mock().into(),
                 ),
             ],
         };
@@ -2936,13 +3150,16 @@ mod tests {
                     Variable::field_element("z"),
                     FieldElementExpression::Number(Bn128Field::from(4)).into(),
                 ),
-                ZirStatement::Assertion(BooleanExpression::FieldEq(
-                    box FieldElementExpression::Mult(
-                        box FieldElementExpression::Identifier("x".into()),
-                        box FieldElementExpression::Identifier("y".into()),
+                ZirStatement::Assertion(
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Mult(
+                            box FieldElementExpression::Identifier("x".into()),
+                            box FieldElementExpression::Identifier("y".into()),
+                        ),
+                        box FieldElementExpression::Identifier("z".into()),
                     ),
-                    box FieldElementExpression::Identifier("z".into()),
-                )),
+                    crate::zir::RuntimeError::mock(),
+                ),
             ],
             signature: Signature {
                 inputs: vec![],
@@ -2975,7 +3192,7 @@ mod tests {
                         box FlatExpression::Identifier(FlatVariable::new(0)),
                         box FlatExpression::Identifier(FlatVariable::new(1)),
                     ),
-                    RuntimeError::Source,
+                    crate::zir::RuntimeError::mock().into(),
                 ),
             ],
         };
@@ -3011,13 +3228,16 @@ mod tests {
                     Variable::field_element("z"),
                     FieldElementExpression::Number(Bn128Field::from(4)).into(),
                 ),
-                ZirStatement::Assertion(BooleanExpression::FieldEq(
-                    box FieldElementExpression::Identifier("z".into()),
-                    box FieldElementExpression::Mult(
-                        box FieldElementExpression::Identifier("x".into()),
-                        box FieldElementExpression::Identifier("y".into()),
+                ZirStatement::Assertion(
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Identifier("z".into()),
+                        box FieldElementExpression::Mult(
+                            box FieldElementExpression::Identifier("x".into()),
+                            box FieldElementExpression::Identifier("y".into()),
+                        ),
                     ),
-                )),
+                    crate::zir::RuntimeError::mock(),
+                ),
             ],
             signature: Signature {
                 inputs: vec![],
@@ -3050,7 +3270,7 @@ mod tests {
                         box FlatExpression::Identifier(FlatVariable::new(0)),
                         box FlatExpression::Identifier(FlatVariable::new(1)),
                     ),
-                    RuntimeError::Source,
+                    crate::zir::RuntimeError::mock().into(),
                 ),
             ],
         };
@@ -3093,16 +3313,19 @@ mod tests {
                     Variable::field_element("t"),
                     FieldElementExpression::Number(Bn128Field::from(2)).into(),
                 ),
-                ZirStatement::Assertion(BooleanExpression::FieldEq(
-                    box FieldElementExpression::Mult(
-                        box FieldElementExpression::Identifier("x".into()),
-                        box FieldElementExpression::Identifier("y".into()),
-                    ),
-                    box FieldElementExpression::Mult(
-                        box FieldElementExpression::Identifier("z".into()),
-                        box FieldElementExpression::Identifier("t".into()),
+                ZirStatement::Assertion(
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Mult(
+                            box FieldElementExpression::Identifier("x".into()),
+                            box FieldElementExpression::Identifier("y".into()),
+                        ),
+                        box FieldElementExpression::Mult(
+                            box FieldElementExpression::Identifier("z".into()),
+                            box FieldElementExpression::Identifier("t".into()),
+                        ),
                     ),
-                )),
+                    crate::zir::RuntimeError::mock(),
+                ),
             ],
             signature: Signature {
                 inputs: vec![],
@@ -3146,7 +3369,7 @@ mod tests {
                         box FlatExpression::Identifier(FlatVariable::new(0)),
                         box FlatExpression::Identifier(FlatVariable::new(1)),
                     ),
-                    RuntimeError::Source,
+                    crate::zir::RuntimeError::mock().into(),
                 ),
             ],
         };
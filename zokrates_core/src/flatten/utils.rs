@@ -29,3 +29,14 @@ pub fn flat_expression_from_bits<T: Field>(v: Vec<FlatExpression<T>>) -> FlatExp
             .collect::<Vec<_>>(),
     )
 }
+
+/// Split the big-endian bits of a uint into its high and low limbs, each of half its bitwidth
+pub fn split_limbs<T: Field>(
+    mut bits: Vec<FlatExpression<T>>,
+) -> (FlatExpression<T>, FlatExpression<T>) {
+    let low = bits.split_off(bits.len() / 2);
+    (
+        flat_expression_from_bits(bits),
+        flat_expression_from_bits(low),
+    )
+}
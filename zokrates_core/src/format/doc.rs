@@ -0,0 +1,342 @@
+//! A small pretty printer, in the style of Wadler's "A prettier printer"
+//!
+//! A `Doc` describes a piece of output along with the places where it may be broken over several lines. Each group
+//! is printed on a single line if it fits in the remaining width, and with all its line breaks broken otherwise.
+
+/// The number of spaces of each indentation level
+pub const INDENT: usize = 4;
+
+#[derive(Debug, Clone)]
+pub enum Doc {
+    Text(String),
+    /// A line break, printed as `flat` if the enclosing group fits on the line, or as `broken` followed by a new line
+    Line {
+        flat: &'static str,
+        broken: &'static str,
+    },
+    /// A line break which is always taken, forcing the enclosing groups to be broken
+    HardLine,
+    /// Indent the lines started inside a document by one more level
+    Nest(Box<Doc>),
+    Group(Box<Doc>),
+    Concat(Vec<Doc>),
+    /// Alternating contents and line breaks, where each line break is only taken if the content which follows it does
+    /// not fit on the line
+    Fill(Vec<Doc>),
+}
+
+/// A line break printed as nothing when flat
+pub const SOFTLINE: Doc = Doc::Line {
+    flat: "",
+    broken: "",
+};
+
+/// A line break printed as a space when flat
+pub const LINE: Doc = Doc::Line {
+    flat: " ",
+    broken: "",
+};
+
+/// A line break where the grammar does not allow a new line, which then has to be escaped with a backslash
+pub const CONTINUATION: Doc = Doc::Line {
+    flat: " ",
+    broken: " \\",
+};
+
+pub fn text<S: Into<String>>(s: S) -> Doc {
+    Doc::Text(s.into())
+}
+
+pub fn concat(docs: Vec<Doc>) -> Doc {
+    Doc::Concat(docs)
+}
+
+pub fn nest(doc: Doc) -> Doc {
+    Doc::Nest(Box::new(doc))
+}
+
+pub fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+/// Join `docs` with a comma followed by `separator`
+pub fn join(docs: Vec<Doc>, separator: Doc) -> Doc {
+    let mut res = Vec::with_capacity(docs.len() * 3);
+
+    for (i, doc) in docs.into_iter().enumerate() {
+        if i > 0 {
+            res.push(text(","));
+            res.push(separator.clone());
+        }
+        res.push(doc);
+    }
+
+    concat(res)
+}
+
+/// Join `docs` with a comma followed by `separator`, which is only broken before the elements which do not fit on the
+/// line
+pub fn fill(docs: Vec<Doc>, separator: Doc) -> Doc {
+    let count = docs.len();
+    let mut res = Vec::with_capacity(count * 2);
+
+    for (i, doc) in docs.into_iter().enumerate() {
+        if i > 0 {
+            res.push(separator.clone());
+        }
+
+        res.push(if i + 1 < count {
+            concat(vec![doc, text(",")])
+        } else {
+            doc
+        });
+    }
+
+    Doc::Fill(res)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+/// A document left to print
+#[derive(Debug, Clone, Copy)]
+enum Command<'a> {
+    Print(usize, Mode, &'a Doc),
+    /// The rest of a fill, starting with a line break
+    Fill(usize, &'a [Doc]),
+}
+
+/// Render `doc` starting on a line indented by `indent` spaces, in lines of at most `width` characters where possible
+pub fn render(doc: &Doc, indent: usize, width: usize) -> String {
+    let mut res = String::new();
+    let mut column = indent;
+    // the indentation is only written before some text, so that lines are never left with trailing whitespace
+    let mut pending = Some(indent);
+
+    let mut stack = vec![Command::Print(indent, Mode::Break, doc)];
+
+    while let Some(command) = stack.pop() {
+        let (indent, mode, doc) = match command {
+            Command::Print(indent, mode, doc) => (indent, mode, doc),
+            Command::Fill(_, []) => continue,
+            Command::Fill(indent, docs) => {
+                let (separator, content) = (&docs[0], &docs[1]);
+                let (rest, reserved) = fill_rest(&docs[2..], &stack);
+
+                let flat = fits(
+                    width as isize - column as isize - flat_width(separator) - reserved,
+                    content,
+                    rest,
+                );
+                // an element starting a new line is only broken itself if it does not fit there either
+                let mode =
+                    if flat || fits(width as isize - indent as isize - reserved, content, rest) {
+                        Mode::Flat
+                    } else {
+                        Mode::Break
+                    };
+
+                stack.push(Command::Fill(indent, &docs[2..]));
+                stack.push(Command::Print(indent, mode, content));
+                stack.push(Command::Print(
+                    indent,
+                    if flat { Mode::Flat } else { Mode::Break },
+                    separator,
+                ));
+                continue;
+            }
+        };
+
+        let s: &str = match doc {
+            Doc::Text(s) => s,
+            Doc::Line { flat, .. } if mode == Mode::Flat => flat,
+            Doc::Line { broken, .. } => {
+                res.push_str(broken);
+                res.push('\n');
+                column = indent;
+                pending = Some(indent);
+                continue;
+            }
+            Doc::HardLine => {
+                res.push('\n');
+                column = indent;
+                pending = Some(indent);
+                continue;
+            }
+            Doc::Nest(doc) => {
+                stack.push(Command::Print(indent + INDENT, mode, doc));
+                continue;
+            }
+            Doc::Group(doc) => {
+                let mode = match mode {
+                    Mode::Flat => Mode::Flat,
+                    Mode::Break if fits(width as isize - column as isize, doc, &stack) => {
+                        Mode::Flat
+                    }
+                    Mode::Break => Mode::Break,
+                };
+                stack.push(Command::Print(indent, mode, doc));
+                continue;
+            }
+            Doc::Concat(docs) => {
+                stack.extend(
+                    docs.iter()
+                        .rev()
+                        .map(|doc| Command::Print(indent, mode, doc)),
+                );
+                continue;
+            }
+            Doc::Fill(docs) if mode == Mode::Flat || docs.is_empty() => {
+                stack.extend(
+                    docs.iter()
+                        .rev()
+                        .map(|doc| Command::Print(indent, mode, doc)),
+                );
+                continue;
+            }
+            Doc::Fill(docs) => {
+                let (rest, reserved) = fill_rest(&docs[1..], &stack);
+                let mode = match fits(width as isize - column as isize - reserved, &docs[0], rest) {
+                    true => Mode::Flat,
+                    false => Mode::Break,
+                };
+
+                stack.push(Command::Fill(indent, &docs[1..]));
+                stack.push(Command::Print(indent, mode, &docs[0]));
+                continue;
+            }
+        };
+
+        if s.is_empty() {
+            continue;
+        }
+
+        if let Some(indent) = pending.take() {
+            res.extend(std::iter::repeat(' ').take(indent));
+        }
+
+        res.push_str(s);
+        column = match s.rfind('\n') {
+            Some(i) => s[i + 1..].chars().count(),
+            None => column + s.chars().count(),
+        };
+    }
+
+    res
+}
+
+/// What follows an element of a fill on its line, given the `remaining` separators and elements of the fill: either
+/// the rest of the document after the last element, or the width reserved for the next separator if it is broken
+fn fill_rest<'a, 'b>(remaining: &[Doc], stack: &'b [Command<'a>]) -> (&'b [Command<'a>], isize) {
+    match remaining.first() {
+        Some(Doc::Line { broken, .. }) => (&[], broken.len() as isize),
+        Some(_) => (&[], 0),
+        None => (stack, 0),
+    }
+}
+
+fn flat_width(doc: &Doc) -> isize {
+    match doc {
+        Doc::Text(s) => s.chars().count() as isize,
+        Doc::Line { flat, .. } => flat.len() as isize,
+        Doc::HardLine => 0,
+        Doc::Nest(doc) | Doc::Group(doc) => flat_width(doc),
+        Doc::Concat(docs) | Doc::Fill(docs) => docs.iter().map(flat_width).sum(),
+    }
+}
+
+/// Check whether `doc` printed flat, followed by the rest of the line, fits in `width` characters
+fn fits(mut width: isize, doc: &Doc, rest: &[Command]) -> bool {
+    let mut stack = vec![(Mode::Flat, doc)];
+    let mut rest = rest.iter().rev();
+
+    loop {
+        if width < 0 {
+            return false;
+        }
+
+        let (mode, doc) = match stack.pop() {
+            Some(next) => next,
+            None => match rest.next() {
+                Some(Command::Print(_, mode, doc)) => (*mode, *doc),
+                // the line may be broken before the rest of a fill
+                Some(Command::Fill(..)) | None => return true,
+            },
+        };
+
+        match doc {
+            // a text spanning several lines, such as a block comment, cannot be printed flat
+            Doc::Text(s) if s.contains('\n') => return mode == Mode::Break,
+            Doc::Text(s) => width -= s.chars().count() as isize,
+            Doc::Line { flat, .. } if mode == Mode::Flat => width -= flat.len() as isize,
+            // the line ends here, with the text marking the line break
+            Doc::Line { broken, .. } => return width >= broken.len() as isize,
+            Doc::HardLine => return mode == Mode::Break,
+            Doc::Nest(doc) | Doc::Group(doc) => stack.push((mode, doc)),
+            Doc::Concat(docs) | Doc::Fill(docs) => {
+                stack.extend(docs.iter().rev().map(|doc| (mode, doc)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(name: &str, arguments: &[&str]) -> Doc {
+        group(concat(vec![
+            text(format!("{}(", name)),
+            nest(fill(
+                arguments.iter().map(|a| text(*a)).collect(),
+                CONTINUATION,
+            )),
+            text(")"),
+        ]))
+    }
+
+    #[test]
+    fn flat_when_it_fits() {
+        assert_eq!(render(&call("foo", &["a", "b"]), 4, 20), "    foo(a, b)");
+    }
+
+    #[test]
+    fn broken_when_too_long() {
+        assert_eq!(
+            render(&call("foo", &["aaaaaa", "bbbbbb"]), 4, 20),
+            "    foo(aaaaaa, \\\n        bbbbbb)"
+        );
+    }
+
+    #[test]
+    fn hard_line_breaks_groups() {
+        let doc = group(concat(vec![
+            text("["),
+            nest(concat(vec![Doc::HardLine, text("a")])),
+            SOFTLINE,
+            text("]"),
+        ]));
+
+        assert_eq!(render(&doc, 0, 100), "[\n    a\n]");
+    }
+
+    #[test]
+    fn fill_lines() {
+        let numbers = (0..10).map(|i| text(format!("{},", i))).collect::<Vec<_>>();
+        let mut docs = vec![];
+        for (i, number) in numbers.into_iter().enumerate() {
+            if i > 0 {
+                docs.push(LINE);
+            }
+            docs.push(number);
+        }
+
+        assert_eq!(
+            render(&Doc::Fill(docs), 0, 8),
+            "0, 1, 2,\n3, 4, 5,\n6, 7, 8,\n9,"
+        );
+    }
+}
@@ -0,0 +1,1382 @@
+//! Module containing the source formatter, which prints programs in a canonical layout
+//!
+//! The program is parsed with `zokrates_pest_ast` and printed back from its syntax tree, so that the output does not
+//! depend on the layout of the source, except for its comments and blank lines. Comments are not part of the syntax
+//! tree: they are collected from the source, and printed next to the closest line of the output.
+
+mod doc;
+
+use self::doc::{concat, fill, group, join, nest, text, Doc, CONTINUATION, INDENT, LINE, SOFTLINE};
+use zokrates_pest_ast as pest;
+
+/// The width under which lines are kept where possible
+const MAX_WIDTH: usize = 100;
+
+/// Format a program, failing if it is not syntactically valid
+pub fn format(source: &str) -> Result<String, pest::Error> {
+    let file = pest::generate_ast(source)?;
+
+    let mut formatter = Formatter::new(source);
+    formatter.file(&file);
+
+    Ok(formatter.output)
+}
+
+/// A comment of the source, `end` being excluded
+#[derive(Debug, Clone, Copy)]
+struct Comment {
+    start: usize,
+    end: usize,
+}
+
+/// Find the comments of `source`, skipping the contents of strings
+fn comments(source: &str) -> Vec<Comment> {
+    let bytes = source.as_bytes();
+    let mut res = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match (bytes[i], bytes.get(i + 1)) {
+            (b'"', _) => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            (b'/', Some(b'/')) => {
+                let start = i;
+                while i < bytes.len() && bytes[i] != b'\n' && bytes[i] != b'\r' {
+                    i += 1;
+                }
+                res.push(Comment { start, end: i });
+            }
+            (b'/', Some(b'*')) => {
+                let start = i;
+                i = source[i + 2..]
+                    .find("*/")
+                    .map(|end| i + 2 + end + 2)
+                    .unwrap_or(bytes.len());
+                res.push(Comment { start, end: i });
+            }
+            _ => i += 1,
+        }
+    }
+
+    res
+}
+
+// The precedence of expressions, from the loosest to the tightest binding. Binary operators other than `**` bind
+// looser than `UNARY`.
+const COMPARISON: u8 = 3;
+const BIT_OR: u8 = 4;
+const SHIFT: u8 = 7;
+const UNARY: u8 = 10;
+const POWER: u8 = 11;
+const TERM: u8 = 12;
+
+fn binary_precedence(op: &pest::BinaryOperator) -> u8 {
+    use pest::BinaryOperator::*;
+
+    match op {
+        Or => 1,
+        And => 2,
+        Eq | NotEq | Lt | Lte | Gt | Gte => COMPARISON,
+        BitOr => BIT_OR,
+        BitXor => 5,
+        BitAnd => 6,
+        LeftShift | RightShift | LeftRotate | RightRotate => SHIFT,
+        Add | Sub => 8,
+        Mul | Div | Rem => 9,
+        Pow => POWER,
+    }
+}
+
+/// Whether an operand is parenthesized under a binary operator even though it binds tighter, as the precedence of
+/// bitwise operators and shifts is not obvious when they are mixed with other operators
+fn clarify(operator: u8, operand: u8) -> bool {
+    operator < operand
+        && operand < UNARY
+        && match operator {
+            COMPARISON => operand <= SHIFT,
+            BIT_OR..=SHIFT => true,
+            _ => false,
+        }
+}
+
+fn precedence(e: &pest::Expression) -> u8 {
+    match e {
+        pest::Expression::Binary(b) => binary_precedence(&b.op),
+        pest::Expression::Unary(_) | pest::Expression::Cast(_) => UNARY,
+        _ => TERM,
+    }
+}
+
+fn binary_operator(op: &pest::BinaryOperator) -> &'static str {
+    use pest::BinaryOperator::*;
+
+    match op {
+        Or => "||",
+        And => "&&",
+        Eq => "==",
+        NotEq => "!=",
+        Lt => "<",
+        Lte => "<=",
+        Gt => ">",
+        Gte => ">=",
+        BitOr => "|",
+        BitXor => "^",
+        BitAnd => "&",
+        LeftShift => "<<",
+        RightShift => ">>",
+        LeftRotate => "<<<",
+        RightRotate => ">>>",
+        Add => "+",
+        Sub => "-",
+        Mul => "*",
+        Div => "/",
+        Rem => "%",
+        Pow => "**",
+    }
+}
+
+fn basic_type(ty: &pest::BasicType) -> &'static str {
+    match ty {
+        pest::BasicType::Field(_) => "field",
+        pest::BasicType::Boolean(_) => "bool",
+        pest::BasicType::U8(_) => "u8",
+        pest::BasicType::U16(_) => "u16",
+        pest::BasicType::U32(_) => "u32",
+        pest::BasicType::U64(_) => "u64",
+        pest::BasicType::U128(_) => "u128",
+        pest::BasicType::I8(_) => "i8",
+        pest::BasicType::I16(_) => "i16",
+        pest::BasicType::I32(_) => "i32",
+        pest::BasicType::I64(_) => "i64",
+    }
+}
+
+/// Whether the last token of `e` is an identifier, which would be read as the start of an inline struct if followed
+/// by `{}`
+fn ends_with_identifier(e: &pest::Expression) -> bool {
+    match e {
+        pest::Expression::Identifier(_) => true,
+        pest::Expression::Binary(b) => match b.op {
+            pest::BinaryOperator::Pow => false,
+            _ => ends_with_identifier(&b.right),
+        },
+        pest::Expression::Unary(u) => ends_with_identifier(&u.expression),
+        _ => false,
+    }
+}
+
+fn is_block(declaration: &pest::SymbolDeclaration) -> bool {
+    matches!(
+        declaration,
+        pest::SymbolDeclaration::Function(_)
+            | pest::SymbolDeclaration::Struct(_)
+            | pest::SymbolDeclaration::Enum(_)
+            | pest::SymbolDeclaration::Impl(_)
+    )
+}
+
+/// Whether `e` is short enough for lists of such expressions to be filled rather than printed one per line
+fn is_simple(e: &pest::Expression) -> bool {
+    match e {
+        pest::Expression::Literal(_) | pest::Expression::Identifier(_) => true,
+        pest::Expression::Unary(u) => matches!(
+            *u.expression,
+            pest::Expression::Literal(_) | pest::Expression::Identifier(_)
+        ),
+        _ => false,
+    }
+}
+
+fn parens(doc: Doc) -> Doc {
+    concat(vec![text("("), doc, text(")")])
+}
+
+fn public(public: &Option<pest::PubModifier>) -> Doc {
+    text(if public.is_some() { "pub " } else { "" })
+}
+
+fn generics_declaration(generics: &[pest::GenericParameter]) -> Doc {
+    if generics.is_empty() {
+        return text("");
+    }
+
+    text(format!(
+        "<{}>",
+        generics
+            .iter()
+            .map(|g| match g {
+                pest::GenericParameter::Type(t) => format!("type {}", t.id.value),
+                pest::GenericParameter::Constant(c) => c.value.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+struct Formatter<'a> {
+    source: &'a str,
+    comments: Vec<Comment>,
+    /// Whether each comment was printed already
+    printed: Vec<bool>,
+    output: String,
+    /// The indentation level of the lines being printed
+    level: usize,
+    /// The position in the source up to which it was printed, used to keep blank lines
+    position: usize,
+    /// Whether the next line starts a block, in which case it is not preceded by a blank line
+    block_start: bool,
+    /// Whether the next line must be preceded by a blank line
+    separate: bool,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(source: &'a str) -> Self {
+        let comments = comments(source);
+
+        Formatter {
+            source,
+            printed: vec![false; comments.len()],
+            comments,
+            output: String::new(),
+            level: 0,
+            position: 0,
+            block_start: true,
+            separate: false,
+        }
+    }
+
+    fn comment(&self, c: Comment) -> &'a str {
+        let s = &self.source[c.start..c.end];
+
+        if s.starts_with("//") {
+            s.trim_end()
+        } else {
+            s
+        }
+    }
+
+    /// Take the comments starting in `[from, to)` which were not printed yet
+    fn take(&mut self, from: usize, to: usize) -> Vec<Comment> {
+        self.take_where(|c| from <= c.start && c.start < to)
+    }
+
+    fn take_where<P: Fn(&Comment) -> bool>(&mut self, predicate: P) -> Vec<Comment> {
+        let mut res = vec![];
+
+        for (c, printed) in self.comments.iter().zip(self.printed.iter_mut()) {
+            if !*printed && predicate(c) {
+                *printed = true;
+                res.push(*c);
+            }
+        }
+
+        res
+    }
+
+    /// Take the comments following `position` on the same line of the source, separated from it by spaces or commas
+    fn take_trailing(&mut self, mut position: usize) -> Vec<Comment> {
+        let mut res = vec![];
+
+        loop {
+            position = self.source.len()
+                - self.source[position..]
+                    .trim_start_matches(|c| c == ' ' || c == '\t' || c == ',')
+                    .len();
+
+            match self.comments.binary_search_by_key(&position, |c| c.start) {
+                Ok(i) if !self.printed[i] => {
+                    self.printed[i] = true;
+                    res.push(self.comments[i]);
+                    position = self.comments[i].end;
+                }
+                _ => return res,
+            }
+        }
+    }
+
+    /// The column of `position` in the source
+    fn column(&self, position: usize) -> usize {
+        self.source[self.line_start(position)..position]
+            .chars()
+            .count()
+    }
+
+    /// The indentation of the line of `position` in the source
+    fn indentation(&self, position: usize) -> usize {
+        let start = self.line_start(position);
+
+        self.source[start..position]
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .count()
+    }
+
+    fn line_start(&self, position: usize) -> usize {
+        self.source[..position]
+            .rfind('\n')
+            .map(|i| i + 1)
+            .unwrap_or(0)
+    }
+
+    /// Find the end of the first occurrence of `token` at or after `position`, outside of comments
+    fn find(&self, mut position: usize, token: &str) -> usize {
+        while position < self.source.len() {
+            if let Ok(i) = self.comments.binary_search_by_key(&position, |c| c.start) {
+                position = self.comments[i].end;
+                continue;
+            }
+
+            if self.source[position..].starts_with(token) {
+                return position + token.len();
+            }
+
+            position += self.source[position..].chars().next().unwrap().len_utf8();
+        }
+
+        position
+    }
+
+    /// Move `position` back over whitespace and comments
+    fn trim_end(&self, mut position: usize) -> usize {
+        loop {
+            position = self.source[..position]
+                .trim_end_matches(|c: char| c.is_whitespace() || c == '\\')
+                .len();
+
+            match self
+                .comments
+                .iter()
+                .find(|c| c.start < position && position <= c.end)
+            {
+                Some(c) => position = c.start,
+                None => return position,
+            }
+        }
+    }
+
+    /// Whether the source has a blank line between `from` and `to`
+    fn has_blank_line(&self, from: usize, to: usize) -> bool {
+        if from >= to {
+            return false;
+        }
+
+        let lines: Vec<_> = self.source[from..to].split('\n').collect();
+
+        lines.len() > 2
+            && lines[1..lines.len() - 1].iter().any(|l| {
+                l.trim_matches(|c: char| c.is_whitespace() || c == '\\')
+                    .is_empty()
+            })
+    }
+
+    /// Start a new line of output for the source starting at `start`, after a blank line if the source has one there
+    fn new_line(&mut self, start: usize) {
+        let blank = self.separate || self.has_blank_line(self.position, start);
+
+        if blank && !self.block_start {
+            self.output.push('\n');
+        }
+
+        self.block_start = false;
+        self.separate = false;
+    }
+
+    fn comment_line(&mut self, c: Comment) {
+        self.new_line(c.start);
+
+        let comment = self.comment(c);
+        self.output
+            .extend(std::iter::repeat(' ').take(self.level * INDENT));
+        self.output.push_str(comment);
+        self.output.push('\n');
+
+        self.position = self.position.max(c.end);
+    }
+
+    /// Print `doc` for the source in `[start, end)`, along with the comments it contains and the comments which follow
+    /// it on the same line
+    fn line(&mut self, start: usize, end: usize, doc: Doc) {
+        // spans can extend over the whitespace and comments which follow them
+        let end = self.trim_end(end);
+
+        // comments inside the line cannot be kept in place, so they are moved before it
+        for c in self.take(0, end) {
+            self.comment_line(c);
+        }
+
+        self.new_line(start);
+        self.output
+            .push_str(&doc::render(&doc, self.level * INDENT, MAX_WIDTH));
+        self.position = self.position.max(end);
+
+        for c in self.take_trailing(end) {
+            let comment = self.comment(c);
+            self.output.push(' ');
+            self.output.push_str(comment);
+            self.position = c.end;
+        }
+
+        self.output.push('\n');
+    }
+
+    /// Print the line closing a block
+    fn close(&mut self, start: usize, end: usize, doc: Doc) {
+        self.block_start = true;
+        self.line(start, end, doc);
+    }
+
+    /// Print the comments before `end`
+    fn flush(&mut self, end: usize) {
+        for c in self.take(0, end) {
+            self.comment_line(c);
+        }
+    }
+
+    fn file(&mut self, file: &pest::File) {
+        for pragma in &file.pragmas {
+            self.pragma(pragma);
+        }
+
+        let mut previous = None;
+
+        for declaration in &file.declarations {
+            // functions and type definitions are always separated from their neighbours by a blank line
+            if previous.map(is_block).unwrap_or(false) || is_block(declaration) {
+                self.separate = previous.is_some() || !file.pragmas.is_empty();
+            }
+
+            self.declaration(declaration);
+            previous = Some(declaration);
+        }
+
+        self.flush(self.source.len());
+    }
+
+    fn pragma(&mut self, pragma: &pest::Pragma) {
+        match pragma {
+            pest::Pragma::Curve(p) => self.line(
+                p.span.start(),
+                p.span.end(),
+                text(format!("#pragma curve {}", p.curve.name)),
+            ),
+            pest::Pragma::ImmutableByDefault(p) => self.line(
+                p.span.start(),
+                p.span.end(),
+                text("#pragma immutable_by_default"),
+            ),
+        }
+    }
+
+    fn declaration(&mut self, declaration: &pest::SymbolDeclaration) {
+        match declaration {
+            pest::SymbolDeclaration::Import(pest::ImportDirective::Main(i)) => {
+                let mut docs = vec![text(format!("import \"{}\"", i.source.span.as_str()))];
+
+                if let Some(alias) = &i.alias {
+                    docs.push(text(format!(" as {}", alias.value)));
+                }
+
+                self.line(i.span.start(), self.trim_end(i.span.end()), concat(docs));
+            }
+            pest::SymbolDeclaration::Import(pest::ImportDirective::From(i)) => {
+                let symbols = match i.glob {
+                    Some(_) => text("*"),
+                    None => nest(fill(
+                        i.symbols
+                            .iter()
+                            .map(|s| match &s.alias {
+                                Some(alias) => text(format!("{} as {}", s.id.value, alias.value)),
+                                None => text(&s.id.value),
+                            })
+                            .collect(),
+                        CONTINUATION,
+                    )),
+                };
+
+                let doc = group(concat(vec![
+                    public(&i.public),
+                    text(format!("from \"{}\" import ", i.source.span.as_str())),
+                    symbols,
+                ]));
+
+                self.line(i.span.start(), self.trim_end(i.span.end()), doc);
+            }
+            pest::SymbolDeclaration::Constant(c) => {
+                let doc = concat(vec![
+                    public(&c.public),
+                    text("const "),
+                    self.ty(&c.ty),
+                    text(format!(" {} = ", c.id.value)),
+                    self.expression(&c.expression),
+                ]);
+
+                self.line(c.span.start(), self.trim_end(c.span.end()), doc);
+            }
+            pest::SymbolDeclaration::Type(t) => {
+                let doc = concat(vec![
+                    public(&t.public),
+                    text(format!("type {}", t.id.value)),
+                    generics_declaration(&t.generics),
+                    text(" = "),
+                    self.ty(&t.ty),
+                ]);
+
+                self.line(t.span.start(), self.trim_end(t.span.end()), doc);
+            }
+            pest::SymbolDeclaration::Struct(s) => {
+                let header = concat(vec![
+                    public(&s.public),
+                    text(format!("struct {}", s.id.value)),
+                    generics_declaration(&s.generics),
+                    text(" {"),
+                ]);
+                let end = self.trim_end(s.span.end());
+
+                if s.fields.is_empty() {
+                    return self.line(s.span.start(), end, concat(vec![header, text("}")]));
+                }
+
+                let header_end = s.generics.last().map_or(&s.id.span, |g| g.span()).end();
+                self.line(s.span.start(), self.find(header_end, "{"), header);
+
+                self.level += 1;
+                self.block_start = true;
+
+                for field in &s.fields {
+                    let doc = concat(vec![
+                        self.ty(&field.ty),
+                        text(format!(" {}", field.id.value)),
+                    ]);
+                    self.line(field.span.start(), field.span.end(), doc);
+                }
+
+                self.flush(end - 1);
+                self.level -= 1;
+
+                self.close(end - 1, end, text("}"));
+            }
+            pest::SymbolDeclaration::Enum(e) => {
+                let end = self.trim_end(e.span.end());
+                let header = concat(vec![
+                    public(&e.public),
+                    text(format!("enum {} {{", e.id.value)),
+                ]);
+                self.line(e.span.start(), self.find(e.id.span.end(), "{"), header);
+
+                self.level += 1;
+                self.block_start = true;
+
+                for (i, variant) in e.variants.iter().enumerate() {
+                    let mut docs = vec![text(&variant.id.value)];
+
+                    if !variant.types.is_empty() {
+                        let types = variant.types.iter().map(|ty| self.ty(ty)).collect();
+                        docs.push(group(concat(vec![
+                            text("("),
+                            nest(fill(types, CONTINUATION)),
+                            text(")"),
+                        ])));
+                    }
+
+                    if i + 1 < e.variants.len() {
+                        docs.push(text(","));
+                    }
+
+                    self.line(variant.span.start(), variant.span.end(), concat(docs));
+                }
+
+                self.flush(end - 1);
+                self.level -= 1;
+
+                self.close(end - 1, end, text("}"));
+            }
+            pest::SymbolDeclaration::Function(f) => {
+                let header = self.signature(
+                    &f.public,
+                    &f.id,
+                    &f.generics,
+                    None,
+                    &f.parameters,
+                    &f.returns,
+                );
+
+                let parameters_end = f
+                    .parameters
+                    .last()
+                    .map(|p| p.span.end())
+                    .unwrap_or_else(|| f.generics.last().map_or(&f.id.span, |g| g.span()).end());
+                let header_end = self.find(self.find(parameters_end, ")"), ":");
+
+                self.line(f.span.start(), header_end, header);
+                self.function_body(
+                    &f.statements,
+                    self.indentation(f.span.start()),
+                    f.span.end(),
+                );
+            }
+            pest::SymbolDeclaration::Impl(i) => {
+                let end = self.trim_end(i.span.end());
+                self.line(
+                    i.span.start(),
+                    self.find(i.id.span.end(), "{"),
+                    text(format!("impl {} {{", i.id.value)),
+                );
+
+                self.level += 1;
+                self.block_start = true;
+
+                for (index, m) in i.methods.iter().enumerate() {
+                    self.separate = index > 0;
+
+                    let header = self.signature(
+                        &None,
+                        &m.id,
+                        &m.generics,
+                        Some(&m.receiver),
+                        &m.parameters,
+                        &m.returns,
+                    );
+
+                    let parameters_end = m
+                        .parameters
+                        .last()
+                        .map(|p| p.span.end())
+                        .unwrap_or_else(|| m.receiver.span.end());
+                    let header_end = self.find(self.find(parameters_end, ")"), ":");
+
+                    self.line(m.span.start(), header_end, header);
+                    self.function_body(
+                        &m.statements,
+                        self.indentation(m.span.start()),
+                        m.span.end(),
+                    );
+                }
+
+                self.flush(end - 1);
+                self.level -= 1;
+
+                self.close(end - 1, end, text("}"));
+            }
+        }
+    }
+
+    fn signature(
+        &mut self,
+        public_modifier: &Option<pest::PubModifier>,
+        id: &pest::IdentifierExpression,
+        generics: &[pest::GenericParameter],
+        receiver: Option<&pest::SelfParameter>,
+        parameters: &[pest::Parameter],
+        returns: &[pest::Type],
+    ) -> Doc {
+        let mut parameter_docs: Vec<_> = receiver.map(|_| text("self")).into_iter().collect();
+
+        for p in parameters {
+            let visibility = match p.visibility {
+                Some(pest::Visibility::Public(_)) => "public ",
+                Some(pest::Visibility::Private(_)) => "private ",
+                None => "",
+            };
+
+            parameter_docs.push(concat(vec![
+                text(visibility),
+                self.ty(&p.ty),
+                text(if p.mutable.is_some() { " mut " } else { " " }),
+                text(&p.id.value),
+            ]));
+        }
+
+        let returns = match returns {
+            [] => text(""),
+            // a single tuple has to be parenthesized, so that it is not read as a list of types
+            [ty] if !matches!(ty, pest::Type::Tuple(_)) => concat(vec![text(" -> "), self.ty(ty)]),
+            returns => {
+                let types = returns.iter().map(|ty| self.ty(ty)).collect();
+                concat(vec![
+                    text(" -> ("),
+                    nest(fill(types, CONTINUATION)),
+                    text(")"),
+                ])
+            }
+        };
+
+        group(concat(vec![
+            public(public_modifier),
+            text(format!("def {}", id.value)),
+            generics_declaration(generics),
+            text("("),
+            nest(fill(parameter_docs, CONTINUATION)),
+            text(")"),
+            returns,
+            text(":"),
+        ]))
+    }
+
+    /// Print the statements of a function, along with the comments which follow them and are indented further than
+    /// the line of the function, at `indentation` in the source
+    fn function_body(&mut self, statements: &[pest::Statement], indentation: usize, end: usize) {
+        self.level += 1;
+        self.block_start = true;
+
+        for s in statements {
+            self.statement(s);
+        }
+
+        // comments which are not indented are left to the next declaration
+        let indented: Vec<_> = self
+            .comments
+            .iter()
+            .filter(|c| c.start < end && self.column(c.start) > indentation)
+            .map(|c| c.start)
+            .collect();
+
+        for c in self.take_where(|c| indented.contains(&c.start)) {
+            self.comment_line(c);
+        }
+
+        self.level -= 1;
+    }
+
+    /// Print the statements of a block closed at `close`, along with the comments before it
+    fn block(&mut self, statements: &[pest::Statement], close: usize) {
+        self.level += 1;
+        self.block_start = true;
+
+        for s in statements {
+            self.statement(s);
+        }
+
+        self.flush(close);
+        self.level -= 1;
+    }
+
+    fn statement(&mut self, statement: &pest::Statement) {
+        match statement {
+            pest::Statement::Return(s) => {
+                let doc = match s.expressions.len() {
+                    0 => text("return"),
+                    1 => concat(vec![text("return "), self.expression(&s.expressions[0])]),
+                    _ => {
+                        let expressions =
+                            s.expressions.iter().map(|e| self.expression(e)).collect();
+                        group(concat(vec![
+                            text("return "),
+                            nest(fill(expressions, CONTINUATION)),
+                        ]))
+                    }
+                };
+
+                self.line(s.span.start(), s.span.end(), doc);
+            }
+            pest::Statement::Definition(s) => {
+                let lhs = s
+                    .lhs
+                    .iter()
+                    .map(|a| match a {
+                        pest::TypedIdentifierOrAssignee::TypedIdentifier(t) => concat(vec![
+                            self.ty(&t.ty),
+                            text(if t.mutable.is_some() { " mut " } else { " " }),
+                            text(&t.identifier.value),
+                        ]),
+                        pest::TypedIdentifierOrAssignee::Assignee(a) => self.assignee(a),
+                    })
+                    .collect();
+
+                let doc = concat(vec![
+                    join(lhs, text(" ")),
+                    text(" = "),
+                    self.expression(&s.expression),
+                ]);
+
+                self.line(s.span.start(), s.span.end(), doc);
+            }
+            pest::Statement::Assertion(s) => {
+                let mut docs = vec![text("assert("), self.expression(&s.expression)];
+
+                if let Some(message) = &s.message {
+                    docs.push(text(format!(", {}", message.span.as_str())));
+                }
+
+                docs.push(text(")"));
+
+                self.line(s.span.start(), s.span.end(), concat(docs));
+            }
+            pest::Statement::Iteration(s) => {
+                let header = concat(vec![
+                    text("for "),
+                    self.ty(&s.ty),
+                    text(format!(" {} in ", s.index.value)),
+                    self.expression(&s.from),
+                    text(".."),
+                    self.expression(&s.to),
+                    text(" do"),
+                ]);
+
+                self.line(s.span.start(), self.find(s.to.span().end(), "do"), header);
+
+                let close = s.span.end() - "endfor".len();
+                self.block(&s.statements, close);
+                self.close(close, s.span.end(), text("endfor"));
+            }
+            pest::Statement::IfElse(s) => {
+                let mut s = s;
+                let mut start = s.span.start();
+                let mut keyword = "if ";
+
+                loop {
+                    let mut condition = self.expression(&s.condition);
+
+                    if s.consequence.statements.is_empty() && ends_with_identifier(&s.condition) {
+                        condition = parens(condition);
+                    }
+
+                    self.line(
+                        start,
+                        s.consequence.span.start() + 1,
+                        concat(vec![text(keyword), condition, text(" {")]),
+                    );
+
+                    let close = s.consequence.span.end() - 1;
+                    self.block(&s.consequence.statements, close);
+
+                    match &s.alternative {
+                        None => return self.close(close, close + 1, text("}")),
+                        Some(pest::ElseStatement::IfElse(alternative)) => {
+                            s = alternative;
+                            start = close;
+                            keyword = "} else if ";
+                            self.block_start = true;
+                        }
+                        Some(pest::ElseStatement::Block(alternative)) => {
+                            self.close(close, alternative.span.start() + 1, text("} else {"));
+
+                            let close = alternative.span.end() - 1;
+                            self.block(&alternative.statements, close);
+                            return self.close(close, close + 1, text("}"));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn assignee(&mut self, assignee: &pest::Assignee) -> Doc {
+        let mut docs = vec![text(&assignee.id.value)];
+
+        for access in &assignee.accesses {
+            docs.push(match access {
+                pest::AssigneeAccess::Select(a) => self.array_access(a),
+                pest::AssigneeAccess::Member(m) => text(format!(".{}", m.id.value)),
+                pest::AssigneeAccess::Element(e) => text(format!(".{}", e.index.span.as_str())),
+            });
+        }
+
+        concat(docs)
+    }
+
+    fn array_access(&mut self, access: &pest::ArrayAccess) -> Doc {
+        let index = match &access.expression {
+            pest::RangeOrExpression::Expression(e) => self.expression(e),
+            pest::RangeOrExpression::Range(r) => {
+                let from = r.from.as_ref().map(|from| self.expression(&from.0));
+                let to = r.to.as_ref().map(|to| self.expression(&to.0));
+
+                concat(vec![
+                    from.unwrap_or_else(|| text("")),
+                    text(".."),
+                    to.unwrap_or_else(|| text("")),
+                ])
+            }
+        };
+
+        concat(vec![text("["), index, text("]")])
+    }
+
+    fn ty(&mut self, ty: &pest::Type) -> Doc {
+        match ty {
+            pest::Type::Basic(ty) => text(basic_type(ty)),
+            pest::Type::Struct(ty) => self.struct_type(&ty.id, &ty.explicit_generics),
+            pest::Type::Tuple(ty) => self.tuple_type(ty),
+            pest::Type::Array(ty) => {
+                let mut docs = vec![match &ty.ty {
+                    pest::BasicOrStructOrTupleType::Basic(ty) => text(basic_type(ty)),
+                    pest::BasicOrStructOrTupleType::Struct(ty) => {
+                        self.struct_type(&ty.id, &ty.explicit_generics)
+                    }
+                    pest::BasicOrStructOrTupleType::Tuple(ty) => self.tuple_type(ty),
+                }];
+
+                for dimension in &ty.dimensions {
+                    docs.push(text("["));
+                    docs.push(self.expression(dimension));
+                    docs.push(text("]"));
+                }
+
+                concat(docs)
+            }
+        }
+    }
+
+    fn struct_type(
+        &mut self,
+        id: &pest::IdentifierExpression,
+        generics: &Option<pest::ExplicitGenerics>,
+    ) -> Doc {
+        match generics {
+            Some(generics) => concat(vec![text(&id.value), self.explicit_generics(generics)]),
+            None => text(&id.value),
+        }
+    }
+
+    fn tuple_type(&mut self, ty: &pest::TupleType) -> Doc {
+        let elements: Vec<_> = ty.elements.iter().map(|ty| self.ty(ty)).collect();
+
+        match elements.len() {
+            1 => concat(vec![text("("), concat(elements), text(",)")]),
+            _ => concat(vec![text("("), join(elements, text(" ")), text(")")]),
+        }
+    }
+
+    fn explicit_generics(&mut self, generics: &pest::ExplicitGenerics) -> Doc {
+        let values = generics
+            .values
+            .iter()
+            .map(|v| match v {
+                pest::ConstantGenericValue::Value(l) => text(l.span().as_str()),
+                pest::ConstantGenericValue::Identifier(i) => text(&i.value),
+                pest::ConstantGenericValue::Underscore(_) => text("_"),
+                pest::ConstantGenericValue::Type(ty) => self.ty(ty),
+            })
+            .collect();
+
+        concat(vec![text("<"), join(values, text(" ")), text(">")])
+    }
+
+    fn arguments(&mut self, arguments: &pest::Arguments) -> Doc {
+        let expressions: Vec<_> = arguments
+            .expressions
+            .iter()
+            .map(|e| self.expression(e))
+            .collect();
+
+        // a single argument is not indented, so that a list passed to a function is laid out as if it was not
+        let expressions = match expressions.len() {
+            1 => concat(expressions),
+            _ => nest(fill(expressions, CONTINUATION)),
+        };
+
+        group(concat(vec![text("("), expressions, text(")")]))
+    }
+
+    /// Print `e` as the operand of an operator, in parentheses if it binds looser than `precedence`
+    fn operand(&mut self, e: &pest::Expression, precedence: u8) -> Doc {
+        let doc = self.expression(e);
+
+        if self::precedence(e) < precedence {
+            parens(doc)
+        } else {
+            doc
+        }
+    }
+
+    fn expression(&mut self, e: &pest::Expression) -> Doc {
+        match e {
+            pest::Expression::Identifier(i) => text(&i.value),
+            pest::Expression::Literal(l) => text(l.span().as_str()),
+            pest::Expression::Binary(b) => self.binary(b),
+            pest::Expression::Unary(u) => {
+                let op = match u.op {
+                    pest::UnaryOperator::Pos(_) => "+",
+                    pest::UnaryOperator::Neg(_) => "-",
+                    pest::UnaryOperator::Not(_) => "!",
+                };
+
+                concat(vec![text(op), self.operand(&u.expression, POWER)])
+            }
+            pest::Expression::Cast(c) => concat(vec![
+                self.operand(&c.expression, UNARY),
+                text(" as "),
+                self.ty(&c.ty),
+            ]),
+            pest::Expression::Ternary(t) => group(concat(vec![
+                text("if "),
+                self.expression(&t.first),
+                nest(concat(vec![
+                    CONTINUATION,
+                    text("then "),
+                    self.expression(&t.second),
+                    CONTINUATION,
+                    text("else "),
+                    self.expression(&t.third),
+                    text(" fi"),
+                ])),
+            ])),
+            pest::Expression::Postfix(p) => {
+                let mut docs = vec![text(&p.id.value)];
+
+                for access in &p.accesses {
+                    docs.push(match access {
+                        pest::Access::Call(c) => {
+                            let generics = c
+                                .explicit_generics
+                                .as_ref()
+                                .map(|g| concat(vec![text("::"), self.explicit_generics(g)]));
+
+                            concat(vec![
+                                generics.unwrap_or_else(|| text("")),
+                                self.arguments(&c.arguments),
+                            ])
+                        }
+                        pest::Access::Select(a) => self.array_access(a),
+                        pest::Access::Member(m) => text(format!(".{}", m.id.value)),
+                        pest::Access::Element(e) => text(format!(".{}", e.index.span.as_str())),
+                    });
+                }
+
+                concat(docs)
+            }
+            pest::Expression::InlineArray(a) => {
+                let items = a
+                    .expressions
+                    .iter()
+                    .map(|e| match e {
+                        pest::SpreadOrExpression::Spread(s) => (
+                            concat(vec![text("..."), self.expression(&s.expression)]),
+                            s.span.start(),
+                            s.span.end(),
+                        ),
+                        pest::SpreadOrExpression::Expression(e) => {
+                            (self.expression(e), e.span().start(), e.span().end())
+                        }
+                    })
+                    .collect();
+
+                let fill = a.expressions.iter().all(|e| match e {
+                    pest::SpreadOrExpression::Expression(e) => is_simple(e),
+                    pest::SpreadOrExpression::Spread(_) => false,
+                });
+
+                self.list("[", items, "]", &a.span, fill)
+            }
+            pest::Expression::InlineStruct(s) => {
+                let items = s
+                    .members
+                    .iter()
+                    .map(|m| {
+                        let doc = concat(vec![
+                            text(format!("{}: ", m.id.value)),
+                            self.expression(&m.expression),
+                        ]);
+                        (doc, m.span.start(), m.span.end())
+                    })
+                    .collect();
+
+                concat(vec![
+                    text(format!("{} ", s.ty.value)),
+                    self.list("{", items, "}", &s.span, false),
+                ])
+            }
+            pest::Expression::InlineTuple(t) => {
+                let items = t
+                    .elements
+                    .iter()
+                    .map(|e| (self.expression(e), e.span().start(), e.span().end()))
+                    .collect();
+
+                self.list("(", items, ")", &t.span, false)
+            }
+            pest::Expression::ArrayInitializer(a) => concat(vec![
+                text("["),
+                self.expression(&a.value),
+                text("; "),
+                self.expression(&a.count),
+                text("]"),
+            ]),
+            pest::Expression::ArrayComprehension(a) => concat(vec![
+                text("["),
+                self.expression(&a.expression),
+                text(" for "),
+                self.ty(&a.ty),
+                text(format!(" {} in ", a.index.value)),
+                self.expression(&a.from),
+                text(".."),
+                self.expression(&a.to),
+                text("]"),
+            ]),
+            pest::Expression::Match(m) => {
+                let items = m
+                    .arms
+                    .iter()
+                    .map(|arm| {
+                        let pattern = match &arm.pattern {
+                            pest::Pattern::Wildcard(_) => "_".to_string(),
+                            pest::Pattern::Variant(v) if v.bindings.is_empty() => {
+                                format!("{}::{}", v.ty.value, v.variant.value)
+                            }
+                            pest::Pattern::Variant(v) => format!(
+                                "{}::{}({})",
+                                v.ty.value,
+                                v.variant.value,
+                                v.bindings
+                                    .iter()
+                                    .map(|b| match b {
+                                        pest::PatternBinding::Wildcard(_) => "_",
+                                        pest::PatternBinding::Identifier(i) => i.value.as_str(),
+                                    })
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                        };
+
+                        let doc = concat(vec![
+                            text(format!("{} => ", pattern)),
+                            self.expression(&arm.expression),
+                        ]);
+                        (doc, arm.span.start(), arm.span.end())
+                    })
+                    .collect();
+
+                concat(vec![
+                    text("match "),
+                    self.expression(&m.expression),
+                    text(" "),
+                    self.list("{", items, "}", &m.span, false),
+                ])
+            }
+            pest::Expression::EnumValue(e) => {
+                let value = text(format!("{}::{}", e.ty.value, e.variant.value));
+
+                match &e.arguments {
+                    Some(arguments) => concat(vec![value, self.arguments(arguments)]),
+                    None => value,
+                }
+            }
+        }
+    }
+
+    fn binary(&mut self, e: &pest::BinaryExpression) -> Doc {
+        if let pest::BinaryOperator::Pow = e.op {
+            // the exponent is either a primary expression or parenthesized
+            let exponent = match *e.right {
+                pest::Expression::Identifier(_) | pest::Expression::Literal(_) => {
+                    self.expression(&e.right)
+                }
+                _ => parens(self.expression(&e.right)),
+            };
+
+            return concat(vec![self.operand(&e.left, TERM), text(" ** "), exponent]);
+        }
+
+        let precedence = binary_precedence(&e.op);
+
+        // operators are left associative, so chains of operators of the same precedence are printed flat
+        let mut operations = vec![];
+        let mut left = e;
+
+        let first = loop {
+            operations.push((&left.op, &*left.right));
+
+            match &*left.left {
+                pest::Expression::Binary(b) if binary_precedence(&b.op) == precedence => left = b,
+                e => break e,
+            }
+        };
+
+        let mut docs = vec![];
+
+        for (op, right) in operations.into_iter().rev() {
+            docs.push(text(format!(" {}", binary_operator(op))));
+            docs.push(CONTINUATION);
+            docs.push(self.binary_operand(right, precedence, true));
+        }
+
+        group(concat(vec![
+            self.binary_operand(first, precedence, false),
+            nest(concat(docs)),
+        ]))
+    }
+
+    fn binary_operand(&mut self, e: &pest::Expression, operator: u8, right: bool) -> Doc {
+        let operand = precedence(e);
+        let doc = self.expression(e);
+
+        if operand < operator || (right && operand == operator) || clarify(operator, operand) {
+            parens(doc)
+        } else {
+            doc
+        }
+    }
+
+    /// Print a list between `open` and `close`, on one line if it fits, and otherwise with one element per line or, if
+    /// `fill` is set, with as many elements per line as fit. Each item comes with the span of its source, which is used
+    /// to keep the comments around it.
+    fn list(
+        &mut self,
+        open: &str,
+        items: Vec<(Doc, usize, usize)>,
+        close: &str,
+        span: &pest::Span,
+        fill: bool,
+    ) -> Doc {
+        let close_position = span.end() - close.len();
+        // braces are padded with spaces when the list fits on one line
+        let padded = open == "{";
+        // a tuple of a single element is marked by a trailing comma
+        let trailing_comma = open == "(" && items.len() == 1;
+
+        let count = items.len();
+        let mut from = span.start();
+        let mut has_comments = false;
+        let mut elements = vec![];
+
+        for (i, (doc, start, end)) in items.into_iter().enumerate() {
+            let end = self.trim_end(end);
+            let mut element = vec![];
+
+            for c in self.take(from, start) {
+                element.push(text(self.comment(c)));
+                element.push(Doc::HardLine);
+                has_comments = true;
+            }
+
+            element.push(doc);
+
+            if i + 1 < count || trailing_comma {
+                element.push(text(","));
+            }
+
+            from = end;
+
+            for c in self.take_trailing(end) {
+                element.push(text(format!(" {}", self.comment(c))));
+                has_comments = true;
+                from = c.end;
+            }
+
+            elements.push(concat(element));
+        }
+
+        let dangling = self.take(from, close_position);
+
+        if !has_comments && dangling.is_empty() {
+            if elements.is_empty() {
+                return text(format!("{}{}", open, close));
+            }
+
+            let line = if padded { LINE } else { SOFTLINE };
+
+            let mut inner = vec![];
+            for (i, element) in elements.into_iter().enumerate() {
+                if i > 0 {
+                    inner.push(LINE);
+                }
+                inner.push(element);
+            }
+
+            let inner = if fill {
+                Doc::Fill(inner)
+            } else {
+                concat(inner)
+            };
+
+            return group(concat(vec![
+                text(open),
+                nest(concat(vec![line.clone(), inner])),
+                line,
+                text(close),
+            ]));
+        }
+
+        // comments force the list to be broken, with one element per line
+        let mut inner = vec![];
+
+        for element in elements {
+            inner.push(Doc::HardLine);
+            inner.push(element);
+        }
+
+        for c in dangling {
+            inner.push(Doc::HardLine);
+            inner.push(text(self.comment(c)));
+        }
+
+        concat(vec![
+            text(open),
+            nest(concat(inner)),
+            Doc::HardLine,
+            text(close),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_formats(source: &str, expected: &str) {
+        let formatted = format(source).unwrap();
+        assert_eq!(formatted, expected);
+        // formatting is idempotent
+        assert_eq!(format(&formatted).unwrap(), expected);
+    }
+
+    #[test]
+    fn layout() {
+        assert_formats(
+            "import \"./foo\"   as   foo\ndef   main( private field a,field b)->(field,field):\n  field c=a+b\n  return  c,a\n",
+            "import \"./foo\" as foo\n\ndef main(private field a, field b) -> (field, field):\n    field c = a + b\n    return c, a\n",
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        assert_formats(
+            "def main(u32 a) -> u32:\n\tu32 mut b = 0\n\tfor u32 i in 0..a do\n\t\tb = b + i\n\n\n\t\tb = b * 2\n\tendfor\n\tif a == 0 {\n\t\tb = 1\n\t}   else {\n\t\tb = 2\n\t}\n\treturn b\n",
+            "def main(u32 a) -> u32:\n    u32 mut b = 0\n    for u32 i in 0..a do\n        b = b + i\n\n        b = b * 2\n    endfor\n    if a == 0 {\n        b = 1\n    } else {\n        b = 2\n    }\n    return b\n",
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_formats(
+            "// header\n\n/* foo */ def main(field a /* a */) -> field: // signature\n    // before\n    field b = [\n        a, // first\n        a\n    ]\n    return b[0] // result\n    // end of main\n// end of file\n",
+            "// header\n\n/* foo */\n/* a */\ndef main(field a) -> field: // signature\n    // before\n    field b = [\n        a, // first\n        a\n    ]\n    return b[0] // result\n    // end of main\n// end of file\n",
+        );
+    }
+
+    #[test]
+    fn parentheses() {
+        assert_formats(
+            "def main(field a, field b) -> field:\n    return ((a + b) * (a - (b - a))) + (a ** 2) + (-a) ** (a + 1) + ((a * b))\n",
+            "def main(field a, field b) -> field:\n    return (a + b) * (a - (b - a)) + a ** 2 + (-a) ** (a + 1) + a * b\n",
+        );
+    }
+
+    #[test]
+    fn long_lines() {
+        let arguments: Vec<_> = (0..30).map(|i| format!("a{}", i)).collect();
+        let source = format!(
+            "def main() -> field:\n    return foo({}) + bar([{}])\n",
+            arguments.join(","),
+            arguments.join(",")
+        );
+
+        let formatted = format(&source).unwrap();
+
+        assert!(formatted.lines().all(|l| l.len() <= MAX_WIDTH));
+        assert_eq!(format(&formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn syntax_error() {
+        assert!(format("def main() -> field:\n    return +\n").is_err());
+    }
+}
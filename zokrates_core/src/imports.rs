@@ -9,7 +9,7 @@ use crate::compile::parse_module;
 use crate::compile::{CompileErrorInner, CompileErrors};
 use crate::embed::FlatEmbed;
 use crate::parser::Position;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -75,27 +75,112 @@ impl Importer {
         modules: &mut HashMap<OwnedModuleId, Module<'ast>>,
         arena: &'ast Arena<String>,
     ) -> Result<Module<'ast>, CompileErrors> {
-        let symbols: Vec<_> = destination
+        // symbols declared or imported explicitly take precedence over the ones brought by glob imports
+        let explicit_symbols: HashSet<_> = destination
             .symbols
-            .into_iter()
-            .map(|s| match s.value.symbol {
+            .iter()
+            .map(|s| s.value.id)
+            .filter(|id| *id != GLOB_IMPORT_ID)
+            .collect();
+
+        // the symbols brought by glob imports, to detect conflicts between them
+        let mut glob_symbols: HashMap<Identifier<'ast>, SymbolImport<'ast>> = HashMap::new();
+
+        let mut symbols = vec![];
+
+        for s in destination.symbols {
+            match s.value.symbol {
                 Symbol::Here(SymbolDefinition::Import(import)) => {
-                    Importer::resolve::<T, E>(import, &location, resolver, modules, arena)
+                    let pos = import.pos();
+                    let is_glob = import.value.id.id == GLOB_IMPORT_ID;
+
+                    let declarations = Importer::resolve::<T, E>(
+                        import,
+                        s.value.public,
+                        &location,
+                        resolver,
+                        modules,
+                        arena,
+                    )?;
+
+                    if !is_glob {
+                        symbols.extend(declarations);
+                        continue;
+                    }
+
+                    for declaration in declarations {
+                        let id = declaration.value.id;
+
+                        if explicit_symbols.contains(id) {
+                            continue;
+                        }
+
+                        let import = match &declaration.value.symbol {
+                            Symbol::There(import) => {
+                                Importer::original(import.value.clone(), modules)
+                            }
+                            _ => {
+                                unreachable!("glob imports should only resolve to imported symbols")
+                            }
+                        };
+
+                        match glob_symbols.insert(id, import.clone()) {
+                            // the same symbol can be brought several times
+                            Some(previous) if previous == import => {}
+                            Some(previous) => {
+                                return Err(CompileErrorInner::ImportError(
+                                    Error::new(format!(
+                                        "{} is imported from both {} and {} by glob imports",
+                                        id,
+                                        previous.module_id.display(),
+                                        import.module_id.display()
+                                    ))
+                                    .with_pos(Some(pos)),
+                                )
+                                .in_file(&location)
+                                .into());
+                            }
+                            None => symbols.push(declaration),
+                        }
+                    }
                 }
-                _ => Ok(s),
-            })
-            .collect::<Result<_, _>>()?;
+                _ => symbols.push(s),
+            }
+        }
 
-        Ok(Module::with_symbols(symbols))
+        Ok(Module {
+            symbols,
+            immutable_by_default: destination.immutable_by_default,
+        })
+    }
+
+    /// Follow the re-exports of `import` to the module in which the symbol is declared
+    fn original<'ast>(
+        import: SymbolImport<'ast>,
+        modules: &HashMap<OwnedModuleId, Module<'ast>>,
+    ) -> SymbolImport<'ast> {
+        let mut declarations = modules[&import.module_id]
+            .symbols
+            .iter()
+            .filter(|s| s.value.id == import.symbol_id);
+
+        match (declarations.next(), declarations.next()) {
+            (Some(declaration), None) => match &declaration.value.symbol {
+                Symbol::There(i) => Importer::original(i.value.clone(), modules),
+                _ => import,
+            },
+            _ => import,
+        }
     }
 
     fn resolve<'ast, T: Field, E: Into<Error>>(
         import: CanonicalImportNode<'ast>,
+        public: bool,
         location: &Path,
         resolver: Option<&dyn Resolver<E>>,
         modules: &mut HashMap<OwnedModuleId, Module<'ast>>,
         arena: &'ast Arena<String>,
-    ) -> Result<SymbolDeclarationNode<'ast>, CompileErrors> {
+    ) -> Result<Vec<SymbolDeclarationNode<'ast>>, CompileErrors> {
         let pos = import.pos();
         let module_id = import.value.source;
         let symbol = import.value.id;
@@ -118,6 +203,7 @@ impl Importer {
                         .into());
                     } else {
                         SymbolDeclaration {
+                            public,
                             id: symbol.get_alias(),
                             symbol: Symbol::Flat(FlatEmbed::Sha256Round),
                         }
@@ -139,62 +225,132 @@ impl Importer {
                         .into());
                     } else {
                         SymbolDeclaration {
+                            public,
                             id: symbol.get_alias(),
                             symbol: Symbol::Flat(FlatEmbed::SnarkVerifyBls12377),
                         }
                     }
                 }
                 "unpack" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::Unpack),
                 },
                 "bit_array_le" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::BitArrayLe),
                 },
+                "u128_to_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::U128ToBits),
+                },
                 "u64_to_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U64ToBits),
                 },
                 "u32_to_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U32ToBits),
                 },
                 "u16_to_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U16ToBits),
                 },
                 "u8_to_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U8ToBits),
                 },
+                "u128_from_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::U128FromBits),
+                },
                 "u64_from_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U64FromBits),
                 },
                 "u32_from_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U32FromBits),
                 },
                 "u16_from_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U16FromBits),
                 },
                 "u8_from_bits" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U8FromBits),
                 },
+                "i64_to_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I64ToBits),
+                },
+                "i32_to_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I32ToBits),
+                },
+                "i16_to_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I16ToBits),
+                },
+                "i8_to_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I8ToBits),
+                },
+                "i64_from_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I64FromBits),
+                },
+                "i32_from_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I32FromBits),
+                },
+                "i16_from_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I16FromBits),
+                },
+                "i8_from_bits" => SymbolDeclaration {
+                    public,
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I8FromBits),
+                },
                 "FIELD_SIZE_IN_BITS" => SymbolDeclaration {
+                    public,
                     id: symbol.get_alias(),
                     symbol: Symbol::Here(SymbolDefinition::Constant(
                         ConstantDefinition {
                             ty: UnresolvedType::Uint(32).into(),
                             expression: Expression::U32Constant(T::get_required_bits() as u32)
                                 .into(),
+                            embedded: true,
                         }
                         .start_end(pos.0, pos.1),
                     )),
                 },
+                GLOB_IMPORT_ID => {
+                    return Err(CompileErrorInner::ImportError(
+                        Error::new("Glob imports of embeds are not supported").with_pos(Some(pos)),
+                    )
+                    .in_file(location)
+                    .into());
+                }
                 s => {
                     return Err(CompileErrorInner::ImportError(
                         Error::new(format!("Embed {} not found", s)).with_pos(Some(pos)),
@@ -238,7 +394,66 @@ impl Importer {
                             }
                         };
 
+                        if symbol.id == GLOB_IMPORT_ID {
+                            let module = modules.get(&new_location).unwrap();
+                            let exports = module.glob_exports();
+
+                            // a glob import which brings nothing is a mistake, most likely an attempt to import the `main` function
+                            if exports.is_empty() {
+                                let declares_main =
+                                    module.symbols.iter().any(|s| s.value.id == "main");
+
+                                let hint = match declares_main {
+                                    true => ": import its `main` function by name instead",
+                                    false => "",
+                                };
+
+                                return Err(CompileErrorInner::ImportError(
+                                    Error::new(format!(
+                                        "Module {} exports no symbol to import with a glob import{}",
+                                        module_id.display(),
+                                        hint
+                                    ))
+                                    .with_pos(Some(pos)),
+                                )
+                                .in_file(location)
+                                .into());
+                            }
+
+                            return Ok(exports
+                                .into_iter()
+                                .map(|id| {
+                                    SymbolDeclaration {
+                                        public,
+                                        id,
+                                        symbol: Symbol::There(
+                                            SymbolImport::with_id_in_module(
+                                                id,
+                                                new_location.clone(),
+                                            )
+                                            .start_end(pos.0, pos.1),
+                                        ),
+                                    }
+                                    .start_end(pos.0, pos.1)
+                                })
+                                .collect());
+                        }
+
+                        if !modules.get(&new_location).unwrap().exports(symbol.id) {
+                            return Err(CompileErrorInner::ImportError(
+                                Error::new(format!(
+                                    "Symbol {} in module {} is private",
+                                    symbol.id,
+                                    module_id.display()
+                                ))
+                                .with_pos(Some(pos)),
+                            )
+                            .in_file(location)
+                            .into());
+                        }
+
                         SymbolDeclaration {
+                            public,
                             id: &alias,
                             symbol: Symbol::There(
                                 SymbolImport::with_id_in_module(symbol.id, new_location)
@@ -264,6 +479,6 @@ impl Importer {
             },
         };
 
-        Ok(symbol_declaration.start_end(pos.0, pos.1))
+        Ok(vec![symbol_declaration.start_end(pos.0, pos.1)])
     }
 }
@@ -35,18 +35,30 @@ pub trait Folder<T: Field>: Sized {
 }
 
 pub fn fold_module<T: Field, F: Folder<T>>(f: &mut F, p: Prog<T>) -> Prog<T> {
+    let arguments = p
+        .arguments
+        .into_iter()
+        .map(|a| f.fold_argument(a))
+        .collect();
+
+    // keep track of the number of statements each statement is folded into, to keep the source map aligned
+    let mut counts = vec![];
+
+    let statements = p
+        .statements
+        .into_iter()
+        .flat_map(|s| {
+            let s = f.fold_statement(s);
+            counts.push(s.len());
+            s
+        })
+        .collect();
+
     Prog {
-        arguments: p
-            .arguments
-            .into_iter()
-            .map(|a| f.fold_argument(a))
-            .collect(),
-        statements: p
-            .statements
-            .into_iter()
-            .flat_map(|s| f.fold_statement(s))
-            .collect(),
+        arguments,
+        statements,
         returns: p.returns.into_iter().map(|v| f.fold_variable(v)).collect(),
+        source_map: p.source_map.map(|m| m.realign(&counts)),
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::flat_absy::{FlatDirective, FlatExpression, FlatProg, FlatStatement, FlatVariable};
 use crate::ir::{Directive, LinComb, Prog, QuadComb, Statement};
+use crate::source_map::{CallStack, SourceLocation, SourceMap};
 use zokrates_field::Field;
 
 impl<T: Field> QuadComb<T> {
@@ -32,33 +33,48 @@ impl<T: Field> From<FlatProg<T>> for Prog<T> {
             .next()
             .unwrap();
 
+        let mut statements: Vec<Statement<T>> = vec![];
+        let mut source_map = SourceMap::default();
+
+        // the location of the statement being converted, updated as we go through the location markers
+        let mut location: Option<(SourceLocation, CallStack)> = None;
+        let mut return_location = None;
+
+        for s in main.statements {
+            match s {
+                FlatStatement::Location(l, stack) => location = Some((l, stack)),
+                FlatStatement::Return(..) => return_location = location.clone(),
+                s => {
+                    statements.push(s.into());
+                    source_map.push(location.as_ref().map(|(l, stack)| (l, stack.as_slice())));
+                }
+            }
+        }
+
+        let returns = return_expressions
+            .iter()
+            .enumerate()
+            .map(|(index, _)| FlatVariable::public(index))
+            .collect();
+
+        for (index, expression) in return_expressions.into_iter().enumerate() {
+            statements.push(Statement::Constraint(
+                QuadComb::from_flat_expression(expression),
+                FlatVariable::public(index).into(),
+                None,
+            ));
+            source_map.push(
+                return_location
+                    .as_ref()
+                    .map(|(l, stack)| (l, stack.as_slice())),
+            );
+        }
+
         Prog {
             arguments: main.arguments,
-            returns: return_expressions
-                .iter()
-                .enumerate()
-                .map(|(index, _)| FlatVariable::public(index))
-                .collect(),
-            statements: main
-                .statements
-                .into_iter()
-                .filter_map(|s| match s {
-                    FlatStatement::Return(..) => None,
-                    s => Some(s.into()),
-                })
-                .chain(
-                    return_expressions
-                        .into_iter()
-                        .enumerate()
-                        .map(|(index, expression)| {
-                            Statement::Constraint(
-                                QuadComb::from_flat_expression(expression),
-                                FlatVariable::public(index).into(),
-                                None,
-                            )
-                        }),
-                )
-                .collect(),
+            returns,
+            statements,
+            source_map: Some(source_map),
         }
     }
 }
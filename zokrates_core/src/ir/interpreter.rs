@@ -1,4 +1,5 @@
 use crate::flat_absy::flat_variable::FlatVariable;
+use crate::flat_absy::RuntimeError;
 use crate::ir::{LinComb, Prog, QuadComb, Statement, Witness};
 use crate::solvers::Solver;
 use serde::{Deserialize, Serialize};
@@ -42,7 +43,7 @@ impl Interpreter {
             witness.insert(arg.id, value.clone());
         }
 
-        for statement in program.statements.iter() {
+        for (index, statement) in program.statements.iter().enumerate() {
             match statement {
                 Statement::Constraint(quad, lin, message) => match lin.is_assignee(&witness) {
                     true => {
@@ -53,13 +54,23 @@ impl Interpreter {
                         let lhs_value = quad.evaluate(&witness).unwrap();
                         let rhs_value = lin.evaluate(&witness).unwrap();
                         if lhs_value != rhs_value {
+                            let description = message
+                                .as_ref()
+                                .map(|m| m.to_string())
+                                .unwrap_or_else(|| "Unknown".to_string());
+
+                            // source assertions already point at their location
+                            let message = match (message, program.location(index)) {
+                                (Some(RuntimeError::SourceAssertion(_)), _) | (_, None) => {
+                                    description
+                                }
+                                (_, Some(location)) => format!("{} at {}", description, location),
+                            };
+
                             return Err(Error::UnsatisfiedConstraint {
                                 left: lhs_value.to_dec_string(),
                                 right: rhs_value.to_dec_string(),
-                                message: message
-                                    .as_ref()
-                                    .map(|m| m.to_string())
-                                    .unwrap_or_else(|| "Unknown".to_string()),
+                                message,
                             });
                         }
                     }
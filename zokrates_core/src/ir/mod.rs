@@ -1,6 +1,7 @@
 use crate::flat_absy::flat_parameter::FlatParameter;
 use crate::flat_absy::{FlatVariable, RuntimeError};
 use crate::solvers::Solver;
+use crate::source_map::{SourceLocation, SourceMap};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::Hash;
@@ -10,6 +11,7 @@ mod expression;
 pub mod folder;
 mod from_flat;
 mod interpreter;
+pub mod profile;
 mod serialize;
 pub mod smtlib2;
 pub mod visitor;
@@ -20,6 +22,7 @@ pub use self::expression::{CanonicalLinComb, LinComb};
 pub use self::serialize::ProgEnum;
 
 pub use self::interpreter::{Error, ExecutionResult, Interpreter};
+pub use self::profile::Profile;
 pub use self::witness::Witness;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
@@ -79,6 +82,8 @@ pub struct Prog<T> {
     pub statements: Vec<Statement<T>>,
     pub arguments: Vec<FlatParameter>,
     pub returns: Vec<FlatVariable>,
+    /// The source location of each statement, if known
+    pub source_map: Option<SourceMap>,
 }
 
 impl<T: Field> Prog<T> {
@@ -93,6 +98,11 @@ impl<T: Field> Prog<T> {
         self.arguments.len()
     }
 
+    /// Get the source location of the statement at `index`, if known
+    pub fn location(&self, index: usize) -> Option<SourceLocation> {
+        self.source_map.as_ref().and_then(|m| m.get(index))
+    }
+
     pub fn public_inputs(&self, witness: &Witness<T>) -> Vec<T> {
         self.arguments
             .iter()
@@ -116,7 +126,11 @@ impl<T: Field> fmt::Display for Prog<T> {
             self.returns.len(),
             self.statements
                 .iter()
-                .map(|s| format!("\t{}", s))
+                .enumerate()
+                .map(|(index, s)| match self.location(index) {
+                    Some(location) => format!("\t{} // {}", s, location),
+                    None => format!("\t{}", s),
+                })
                 .collect::<Vec<_>>()
                 .join("\n"),
             self.returns
@@ -0,0 +1,256 @@
+//! Module containing the computation of constraint profiles, which report where the constraints
+//! and directives of a program come from, based on its source map
+
+use crate::ir::{Prog, Statement};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use zokrates_field::Field;
+
+/// The name used for statements which cannot be traced back to the source
+const UNKNOWN: &str = "<unknown>";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize)]
+pub struct Count {
+    pub constraints: usize,
+    pub directives: usize,
+}
+
+impl Count {
+    fn record<T>(&mut self, s: &Statement<T>) {
+        match s {
+            Statement::Constraint(..) => self.constraints += 1,
+            Statement::Directive(..) => self.directives += 1,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Entry {
+    pub name: String,
+    #[serde(flatten)]
+    pub count: Count,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Profile {
+    pub total: Count,
+    /// Counts per function, excluding the functions it calls, sorted by decreasing constraint count
+    pub functions: Vec<Entry>,
+    /// Counts per call site, including the functions called from there, sorted by decreasing constraint count
+    pub call_sites: Vec<Entry>,
+    /// Counts per line, sorted by file and line
+    pub lines: Vec<Entry>,
+    /// Counts per call stack, with frames separated by `;`
+    pub stacks: Vec<Entry>,
+}
+
+fn sorted(counts: BTreeMap<String, Count>) -> Vec<Entry> {
+    let mut entries: Vec<_> = counts
+        .into_iter()
+        .map(|(name, count)| Entry { name, count })
+        .collect();
+    entries.sort_by(|a, b| b.count.constraints.cmp(&a.count.constraints));
+    entries
+}
+
+impl Profile {
+    /// Render the constraint counts per call stack in the folded format used by flamegraph tools
+    pub fn folded(&self) -> String {
+        self.stacks
+            .iter()
+            .filter(|e| e.count.constraints > 0)
+            .map(|e| format!("{} {}\n", e.name.replace(' ', "_"), e.count.constraints))
+            .collect()
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Total: {} constraints, {} directives",
+            self.total.constraints, self.total.directives
+        )?;
+
+        for (title, entries) in &[
+            ("Functions", &self.functions),
+            ("Call sites", &self.call_sites),
+            ("Lines", &self.lines),
+        ] {
+            writeln!(f)?;
+            writeln!(f, "{}", title)?;
+            writeln!(f, "{:>12} {:>12}  location", "constraints", "directives")?;
+            for e in entries.iter() {
+                writeln!(
+                    f,
+                    "{:>12} {:>12}  {}",
+                    e.count.constraints, e.count.directives, e.name
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Field> Prog<T> {
+    /// Count the constraints and directives of this program per function, call site and line
+    pub fn profile(&self) -> Profile {
+        let mut total = Count::default();
+        let mut functions = BTreeMap::<String, Count>::new();
+        let mut call_sites = BTreeMap::<String, Count>::new();
+        let mut lines = BTreeMap::<(String, usize), Count>::new();
+        let mut stacks = BTreeMap::<String, Count>::new();
+
+        let source_map = self.source_map.as_ref();
+
+        for (index, s) in self.statements.iter().enumerate() {
+            total.record(s);
+
+            let location = source_map.and_then(|m| m.get(index));
+            let stack = source_map.and_then(|m| m.stack(index)).unwrap_or(&[]);
+
+            let function = stack
+                .last()
+                .map(|frame| frame.function.clone())
+                .unwrap_or_else(|| UNKNOWN.to_string());
+            functions.entry(function).or_default().record(s);
+
+            for frame in stack.iter().filter(|frame| frame.call_site.is_some()) {
+                call_sites.entry(frame.to_string()).or_default().record(s);
+            }
+
+            let line = location
+                .map(|l| (l.file, l.position.line))
+                .unwrap_or_else(|| (UNKNOWN.to_string(), 0));
+            lines.entry(line).or_default().record(s);
+
+            let stack = match stack.is_empty() {
+                true => UNKNOWN.to_string(),
+                false => stack
+                    .iter()
+                    .map(|frame| frame.function.as_str())
+                    .collect::<Vec<_>>()
+                    .join(";"),
+            };
+            stacks.entry(stack).or_default().record(s);
+        }
+
+        Profile {
+            total,
+            functions: sorted(functions),
+            call_sites: sorted(call_sites),
+            lines: lines
+                .into_iter()
+                .map(|((file, line), count)| Entry {
+                    name: match line {
+                        0 => file,
+                        line => format!("{}:{}", file, line),
+                    },
+                    count,
+                })
+                .collect(),
+            stacks: stacks
+                .into_iter()
+                .map(|(name, count)| Entry { name, count })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatVariable;
+    use crate::ir::{Directive, LinComb, QuadComb};
+    use crate::parser::Position;
+    use crate::solvers::Solver;
+    use crate::source_map::{Frame, SourceLocation, SourceMap};
+    use zokrates_field::Bn128Field;
+
+    #[test]
+    fn profile() {
+        // def main(_0) -> (1):
+        //     # _1 = Div(_0, _0)                    // main.zok:2:5 in foo, called at main.zok:6:5
+        //     (1 * _0) * (1 * _1) == 1 * _0         // main.zok:3:5 in foo, called at main.zok:6:5
+        //     (1 * ~one) * (1 * _1) == 1 * ~out_0   // main.zok:7:5 in main
+        //     return ~out_0
+
+        let _0 = FlatVariable::new(0);
+        let _1 = FlatVariable::new(1);
+        let one = FlatVariable::one();
+        let out_0 = FlatVariable::public(0);
+
+        let location = |line| SourceLocation::new("main.zok", Position { line, col: 5 });
+
+        let main = vec![Frame::new("main.zok/main", None)];
+        let foo = vec![
+            Frame::new("main.zok/main", None),
+            Frame::new("main.zok/foo", Some(location(6))),
+        ];
+
+        let mut source_map = SourceMap::default();
+        source_map.push(Some((&location(2), &foo)));
+        source_map.push(Some((&location(3), &foo)));
+        source_map.push(Some((&location(7), &main)));
+
+        let p: Prog<Bn128Field> = Prog {
+            arguments: vec![],
+            statements: vec![
+                Statement::Directive(Directive {
+                    inputs: vec![LinComb::from(_0).into(), LinComb::from(_0).into()],
+                    outputs: vec![_1],
+                    solver: Solver::Div,
+                }),
+                Statement::constraint(
+                    QuadComb::from_linear_combinations(LinComb::from(_0), LinComb::from(_1)),
+                    LinComb::from(_0),
+                ),
+                Statement::constraint(
+                    QuadComb::from_linear_combinations(LinComb::from(one), LinComb::from(_1)),
+                    LinComb::from(out_0),
+                ),
+            ],
+            returns: vec![out_0],
+            source_map: Some(source_map),
+        };
+
+        let count = |constraints, directives| Count {
+            constraints,
+            directives,
+        };
+        let entry = |name: &str, count| Entry {
+            name: name.to_string(),
+            count,
+        };
+
+        let profile = p.profile();
+
+        assert_eq!(
+            profile,
+            Profile {
+                total: count(2, 1),
+                functions: vec![
+                    entry("main.zok/foo", count(1, 1)),
+                    entry("main.zok/main", count(1, 0))
+                ],
+                call_sites: vec![entry("main.zok/foo (called at main.zok:6:5)", count(1, 1))],
+                lines: vec![
+                    entry("main.zok:2", count(0, 1)),
+                    entry("main.zok:3", count(1, 0)),
+                    entry("main.zok:7", count(1, 0))
+                ],
+                stacks: vec![
+                    entry("main.zok/main", count(1, 0)),
+                    entry("main.zok/main;main.zok/foo", count(1, 1))
+                ]
+            }
+        );
+
+        assert_eq!(
+            profile.folded(),
+            "main.zok/main 1\nmain.zok/main;main.zok/foo 1\n"
+        );
+    }
+}
@@ -1,10 +1,13 @@
-use crate::ir::Prog;
+use crate::flat_absy::{FlatParameter, FlatVariable, RuntimeError};
+use crate::ir::{Directive, LinComb, Prog, QuadComb, Statement};
 use bincode::{deserialize_from, serialize_into, Infinite};
+use serde::{Deserialize, Serialize};
 use std::io::{Read, Write};
 use zokrates_field::*;
 
 const ZOKRATES_MAGIC: &[u8; 4] = &[0x5a, 0x4f, 0x4b, 0];
 const ZOKRATES_VERSION_1: &[u8; 4] = &[0, 0, 0, 1];
+const ZOKRATES_VERSION_2: &[u8; 4] = &[0, 0, 0, 2];
 
 #[derive(PartialEq, Debug)]
 pub enum ProgEnum {
@@ -14,14 +17,127 @@ pub enum ProgEnum {
     Bw6_761Program(Prog<Bw6_761Field>),
 }
 
+/// The layout of a program in version 1, before source maps were introduced
+#[derive(Serialize, Deserialize)]
+struct ProgV1<T> {
+    statements: Vec<StatementV1<T>>,
+    arguments: Vec<FlatParameter>,
+    returns: Vec<FlatVariable>,
+}
+
+/// The layout of a statement in version 1
+#[derive(Serialize, Deserialize)]
+enum StatementV1<T> {
+    Constraint(QuadComb<T>, LinComb<T>, Option<RuntimeErrorV1>),
+    Directive(Directive<T>),
+}
+
+/// The runtime errors of version 1, frozen so that their encoding does not change with `RuntimeError`
+#[derive(Serialize, Deserialize)]
+enum RuntimeErrorV1 {
+    BellmanConstraint,
+    BellmanOneBinding,
+    BellmanInputBinding,
+    ArkConstraint,
+    ArkOneBinding,
+    ArkInputBinding,
+    Bitness,
+    Sum,
+    Equal,
+    Le,
+    BranchIsolation,
+    ConstantLtBitness,
+    ConstantLtSum,
+    LtBitness,
+    LtSum,
+    LtFinalBitness,
+    LtFinalSum,
+    Or,
+    Xor,
+    Inverse,
+    Euclidean,
+    ShaXor,
+    Division,
+    Source,
+    ArgumentBitness,
+    SelectRangeCheck,
+}
+
+impl From<RuntimeErrorV1> for RuntimeError {
+    fn from(e: RuntimeErrorV1) -> RuntimeError {
+        match e {
+            RuntimeErrorV1::BellmanConstraint => RuntimeError::BellmanConstraint,
+            RuntimeErrorV1::BellmanOneBinding => RuntimeError::BellmanOneBinding,
+            RuntimeErrorV1::BellmanInputBinding => RuntimeError::BellmanInputBinding,
+            RuntimeErrorV1::ArkConstraint => RuntimeError::ArkConstraint,
+            RuntimeErrorV1::ArkOneBinding => RuntimeError::ArkOneBinding,
+            RuntimeErrorV1::ArkInputBinding => RuntimeError::ArkInputBinding,
+            RuntimeErrorV1::Bitness => RuntimeError::Bitness,
+            RuntimeErrorV1::Sum => RuntimeError::Sum,
+            RuntimeErrorV1::Equal => RuntimeError::Equal,
+            RuntimeErrorV1::Le => RuntimeError::Le,
+            RuntimeErrorV1::BranchIsolation => RuntimeError::BranchIsolation,
+            RuntimeErrorV1::ConstantLtBitness => RuntimeError::ConstantLtBitness,
+            RuntimeErrorV1::ConstantLtSum => RuntimeError::ConstantLtSum,
+            RuntimeErrorV1::LtBitness => RuntimeError::LtBitness,
+            RuntimeErrorV1::LtSum => RuntimeError::LtSum,
+            RuntimeErrorV1::LtFinalBitness => RuntimeError::LtFinalBitness,
+            RuntimeErrorV1::LtFinalSum => RuntimeError::LtFinalSum,
+            RuntimeErrorV1::Or => RuntimeError::Or,
+            RuntimeErrorV1::Xor => RuntimeError::Xor,
+            RuntimeErrorV1::Inverse => RuntimeError::Inverse,
+            RuntimeErrorV1::Euclidean => RuntimeError::Euclidean,
+            RuntimeErrorV1::ShaXor => RuntimeError::ShaXor,
+            RuntimeErrorV1::Division => RuntimeError::Division,
+            // assertions did not carry a message in version 1
+            RuntimeErrorV1::Source => {
+                RuntimeError::SourceAssertion(String::from("User assertion failed"))
+            }
+            RuntimeErrorV1::ArgumentBitness => RuntimeError::ArgumentBitness,
+            RuntimeErrorV1::SelectRangeCheck => RuntimeError::SelectRangeCheck,
+        }
+    }
+}
+
+impl<T> From<StatementV1<T>> for Statement<T> {
+    fn from(s: StatementV1<T>) -> Statement<T> {
+        match s {
+            StatementV1::Constraint(quad, lin, error) => {
+                Statement::Constraint(quad, lin, error.map(RuntimeError::from))
+            }
+            StatementV1::Directive(d) => Statement::Directive(d),
+        }
+    }
+}
+
+impl<T> From<ProgV1<T>> for Prog<T> {
+    fn from(p: ProgV1<T>) -> Prog<T> {
+        Prog {
+            statements: p.statements.into_iter().map(Statement::from).collect(),
+            arguments: p.arguments,
+            returns: p.returns,
+            source_map: None,
+        }
+    }
+}
+
 impl<T: Field> Prog<T> {
     pub fn serialize<W: Write>(&self, mut w: W) {
         w.write_all(ZOKRATES_MAGIC).unwrap();
-        w.write_all(ZOKRATES_VERSION_1).unwrap();
+        w.write_all(ZOKRATES_VERSION_2).unwrap();
         w.write_all(&T::id()).unwrap();
 
         serialize_into(&mut w, self, Infinite).unwrap();
     }
+
+    fn deserialize_versioned<R: Read>(mut r: R, version: &[u8; 4]) -> Self {
+        if version == ZOKRATES_VERSION_1 {
+            let p: ProgV1<T> = deserialize_from(&mut r, Infinite).unwrap();
+            p.into()
+        } else {
+            deserialize_from(&mut r, Infinite).unwrap()
+        }
+    }
 }
 
 impl ProgEnum {
@@ -32,12 +148,12 @@ impl ProgEnum {
             .map_err(|_| String::from("Cannot read magic number"))?;
 
         if &magic == ZOKRATES_MAGIC {
-            // Check the version, 1
+            // Check the version, 1 or 2
             let mut version = [0; 4];
             r.read_exact(&mut version)
                 .map_err(|_| String::from("Cannot read version"))?;
 
-            if &version == ZOKRATES_VERSION_1 {
+            if &version == ZOKRATES_VERSION_1 || &version == ZOKRATES_VERSION_2 {
                 // Check the curve identifier, deserializing accordingly
                 let mut curve = [0; 4];
                 r.read_exact(&mut curve)
@@ -45,16 +161,16 @@ impl ProgEnum {
 
                 match curve {
                     m if m == Bls12_381Field::id() => Ok(ProgEnum::Bls12_381Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
+                        Prog::deserialize_versioned(r, &version),
                     )),
                     m if m == Bn128Field::id() => Ok(ProgEnum::Bn128Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
+                        Prog::deserialize_versioned(r, &version),
                     )),
                     m if m == Bls12_377Field::id() => Ok(ProgEnum::Bls12_377Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
+                        Prog::deserialize_versioned(r, &version),
                     )),
                     m if m == Bw6_761Field::id() => Ok(ProgEnum::Bw6_761Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
+                        Prog::deserialize_versioned(r, &version),
                     )),
                     _ => Err(String::from("Unknown curve identifier")),
                 }
@@ -75,7 +191,7 @@ mod tests {
     use zokrates_field::{Bls12_381Field, Bn128Field};
 
     #[test]
-    fn ser_deser_v1() {
+    fn ser_deser_v2() {
         let p: ir::Prog<Bn128Field> = ir::Prog::default();
 
         let mut buffer = Cursor::new(vec![]);
@@ -102,4 +218,91 @@ mod tests {
 
         assert_eq!(ProgEnum::Bls12_381Program(p), deserialized_p);
     }
+
+    #[test]
+    fn deser_v1() {
+        let p: ir::Prog<Bn128Field> = ir::Prog::default();
+
+        // a version 1 program does not have a source map
+        let mut buffer = Cursor::new(vec![]);
+        buffer.write_all(ZOKRATES_MAGIC).unwrap();
+        buffer.write_all(ZOKRATES_VERSION_1).unwrap();
+        buffer.write_all(&Bn128Field::id()).unwrap();
+        serialize_into(
+            &mut buffer,
+            &(&p.statements, &p.arguments, &p.returns),
+            Infinite,
+        )
+        .unwrap();
+
+        // rewind back to the beginning of the file
+        buffer.seek(SeekFrom::Start(0)).unwrap();
+
+        // deserialize
+        let deserialized_p = ProgEnum::deserialize(buffer).unwrap();
+
+        assert_eq!(ProgEnum::Bn128Program(p), deserialized_p);
+    }
+
+    #[test]
+    fn deser_v1_assertion() {
+        // an assertion of a version 1 program has no message
+        let p: ProgV1<Bn128Field> = ProgV1 {
+            statements: vec![
+                StatementV1::Constraint(
+                    QuadComb::from_linear_combinations(
+                        LinComb::one(),
+                        FlatVariable::new(0).into(),
+                    ),
+                    FlatVariable::new(1).into(),
+                    Some(RuntimeErrorV1::Source),
+                ),
+                StatementV1::Constraint(
+                    LinComb::one().into(),
+                    FlatVariable::new(1).into(),
+                    Some(RuntimeErrorV1::SelectRangeCheck),
+                ),
+            ],
+            arguments: vec![FlatParameter::private(FlatVariable::new(0))],
+            returns: vec![],
+        };
+
+        let mut buffer = Cursor::new(vec![]);
+        buffer.write_all(ZOKRATES_MAGIC).unwrap();
+        buffer.write_all(ZOKRATES_VERSION_1).unwrap();
+        buffer.write_all(&Bn128Field::id()).unwrap();
+        serialize_into(&mut buffer, &p, Infinite).unwrap();
+
+        // rewind back to the beginning of the file
+        buffer.seek(SeekFrom::Start(0)).unwrap();
+
+        // deserialize
+        let deserialized_p = ProgEnum::deserialize(buffer).unwrap();
+
+        assert_eq!(
+            deserialized_p,
+            ProgEnum::Bn128Program(ir::Prog {
+                statements: vec![
+                    Statement::Constraint(
+                        QuadComb::from_linear_combinations(
+                            LinComb::one(),
+                            FlatVariable::new(0).into(),
+                        ),
+                        FlatVariable::new(1).into(),
+                        Some(RuntimeError::SourceAssertion(String::from(
+                            "User assertion failed"
+                        ))),
+                    ),
+                    Statement::Constraint(
+                        LinComb::one().into(),
+                        FlatVariable::new(1).into(),
+                        Some(RuntimeError::SelectRangeCheck),
+                    ),
+                ],
+                arguments: vec![FlatParameter::private(FlatVariable::new(0))],
+                returns: vec![],
+                source_map: None,
+            })
+        );
+    }
 }
@@ -8,6 +8,7 @@ cfg_if::cfg_if! {
     }
 }
 
+mod casts;
 mod embed;
 mod flatten;
 pub mod imports;
@@ -21,7 +22,10 @@ mod zir;
 
 pub mod absy;
 pub mod compile;
+pub mod diagnostics;
 pub mod flat_absy;
+pub mod format;
 pub mod ir;
 pub mod proof_system;
+pub mod source_map;
 pub mod typed_absy;
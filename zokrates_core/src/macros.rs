@@ -1,6 +1,6 @@
 use std::fmt;
 use zokrates_field::Field;
-use zokrates_pest_ast::File;
+use zokrates_pest_ast::{File, Pragma};
 
 #[derive(Debug)]
 pub enum Error {
@@ -20,17 +20,19 @@ impl fmt::Display for Error {
 }
 
 pub fn process_macros<T: Field>(file: File) -> Result<File, Error> {
-    match &file.pragma {
-        Some(pragma) => {
-            if T::name() != pragma.curve.name {
-                Err(Error::Curve(
-                    T::name().to_string(),
-                    pragma.curve.name.clone(),
-                ))
-            } else {
-                Ok(file)
+    for pragma in &file.pragmas {
+        match pragma {
+            Pragma::Curve(pragma) => {
+                if T::name() != pragma.curve.name {
+                    return Err(Error::Curve(
+                        T::name().to_string(),
+                        pragma.curve.name.clone(),
+                    ));
+                }
             }
+            Pragma::ImmutableByDefault(_) => {}
         }
-        None => Ok(file),
     }
+
+    Ok(file)
 }
@@ -89,6 +89,7 @@ mod tests {
             ],
             returns: vec![],
             arguments: vec![],
+            source_map: None,
         };
 
         let expected = p.clone();
@@ -122,6 +123,7 @@ mod tests {
             ],
             returns: vec![],
             arguments: vec![],
+            source_map: None,
         };
 
         let expected = Prog {
@@ -137,6 +139,7 @@ mod tests {
             ],
             returns: vec![],
             arguments: vec![],
+            source_map: None,
         };
 
         assert_eq!(DuplicateOptimizer::optimize(p), expected);
@@ -231,12 +231,14 @@ mod tests {
             arguments: vec![x],
             statements: vec![Statement::definition(y, x.id), Statement::definition(z, y)],
             returns: vec![z],
+            source_map: None,
         };
 
         let optimized: Prog<Bn128Field> = Prog {
             arguments: vec![x],
             statements: vec![Statement::definition(z, x.id)],
             returns: vec![z],
+            source_map: None,
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -256,6 +258,7 @@ mod tests {
             arguments: vec![x],
             statements: vec![Statement::definition(one, x.id)],
             returns: vec![x.id],
+            source_map: None,
         };
 
         let optimized = p.clone();
@@ -290,6 +293,7 @@ mod tests {
                 Statement::constraint(z, y),
             ],
             returns: vec![z],
+            source_map: None,
         };
 
         let optimized: Prog<Bn128Field> = Prog {
@@ -299,6 +303,7 @@ mod tests {
                 Statement::constraint(z, x.id),
             ],
             returns: vec![z],
+            source_map: None,
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -334,6 +339,7 @@ mod tests {
                 Statement::definition(w, t),
             ],
             returns: vec![z, w],
+            source_map: None,
         };
 
         let optimized: Prog<Bn128Field> = Prog {
@@ -343,6 +349,7 @@ mod tests {
                 Statement::definition(w, Bn128Field::from(1)),
             ],
             returns: vec![z, w],
+            source_map: None,
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -392,6 +399,7 @@ mod tests {
                 Statement::definition(r, LinComb::from(a) + LinComb::from(b) + LinComb::from(c)),
             ],
             returns: vec![r],
+            source_map: None,
         };
 
         let expected: Prog<Bn128Field> = Prog {
@@ -412,6 +420,7 @@ mod tests {
                 ),
             ],
             returns: vec![r],
+            source_map: None,
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -449,6 +458,7 @@ mod tests {
                 Statement::definition(z, LinComb::from(x.id)),
             ],
             returns: vec![],
+            source_map: None,
         };
 
         let optimized = p.clone();
@@ -477,6 +487,7 @@ mod tests {
                 Statement::constraint(x.id, Bn128Field::from(2)),
             ],
             returns: vec![x.id],
+            source_map: None,
         };
 
         let optimized = p.clone();
@@ -265,6 +265,7 @@ mod tests {
                 FlatVariable::new(0),
                 FlatVariable::public(0),
             )],
+            source_map: None,
         };
 
         let keypair = <Ark as NonUniversalBackend<Bls12_377Field, GM17>>::setup(program.clone());
@@ -290,6 +291,7 @@ mod tests {
                 FlatVariable::new(0),
                 FlatVariable::public(0),
             )],
+            source_map: None,
         };
 
         let keypair = <Ark as NonUniversalBackend<Bw6_761Field, GM17>>::setup(program.clone());
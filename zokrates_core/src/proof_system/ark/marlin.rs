@@ -210,6 +210,7 @@ mod tests {
                 ),
                 Statement::constraint(FlatVariable::new(1), FlatVariable::public(0)),
             ],
+            source_map: None,
         };
 
         let srs = <Ark as UniversalBackend<Bls12_377Field, Marlin>>::universal_setup(5);
@@ -243,6 +244,7 @@ mod tests {
                 ),
                 Statement::constraint(FlatVariable::new(1), FlatVariable::public(0)),
             ],
+            source_map: None,
         };
 
         let srs = <Ark as UniversalBackend<Bw6_761Field, Marlin>>::universal_setup(5);
@@ -148,6 +148,7 @@ mod tests {
                 FlatVariable::new(0),
                 FlatVariable::public(0),
             )],
+            source_map: None,
         };
 
         let keypair = <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone());
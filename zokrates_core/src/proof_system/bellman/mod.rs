@@ -290,6 +290,7 @@ mod tests {
                     FlatVariable::new(0),
                     FlatVariable::public(0),
                 )],
+                source_map: None,
             };
 
             let interpreter = Interpreter::default();
@@ -313,6 +314,7 @@ mod tests {
                     FlatVariable::new(0),
                     FlatVariable::public(0),
                 )],
+                source_map: None,
             };
 
             let interpreter = Interpreter::default();
@@ -336,6 +338,7 @@ mod tests {
                     FlatVariable::one(),
                     FlatVariable::public(0),
                 )],
+                source_map: None,
             };
 
             let interpreter = Interpreter::default();
@@ -367,6 +370,7 @@ mod tests {
                         FlatVariable::public(1),
                     ),
                 ],
+                source_map: None,
             };
 
             let interpreter = Interpreter::default();
@@ -389,6 +393,7 @@ mod tests {
                     LinComb::from(FlatVariable::new(42)) + LinComb::one(),
                     FlatVariable::public(0),
                 )],
+                source_map: None,
             };
 
             let interpreter = Interpreter::default();
@@ -415,6 +420,7 @@ mod tests {
                     LinComb::from(FlatVariable::new(42)) + LinComb::from(FlatVariable::new(51)),
                     FlatVariable::public(0),
                 )],
+                source_map: None,
             };
 
             let interpreter = Interpreter::default();
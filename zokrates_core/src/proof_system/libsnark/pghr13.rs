@@ -235,6 +235,7 @@ mod tests {
                 FlatVariable::new(0),
                 FlatVariable::public(0),
             )],
+            source_map: None,
         };
 
         let keypair = <Libsnark as NonUniversalBackend<Bn128Field, PGHR13>>::setup(program.clone());
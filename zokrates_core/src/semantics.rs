@@ -6,30 +6,44 @@
 
 use crate::absy::Identifier;
 use crate::absy::*;
+use crate::casts::{self, CastType};
 use crate::typed_absy::types::GGenericsAssignment;
 use crate::typed_absy::*;
 use crate::typed_absy::{DeclarationParameter, DeclarationVariable, Variable};
+use num::ToPrimitive;
 use num_bigint::BigUint;
 use std::collections::{hash_map::Entry, BTreeSet, HashMap, HashSet};
+use std::convert::TryFrom;
 use std::fmt;
 use std::path::PathBuf;
 use zokrates_field::Field;
 
 use crate::parser::Position;
+use crate::source_map::SourceLocation;
 
 use crate::absy::types::{UnresolvedSignature, UnresolvedType, UserTypeId};
 
 use crate::typed_absy::types::{
     check_type, specialize_declaration_type, ArrayType, DeclarationArrayType, DeclarationConstant,
-    DeclarationFunctionKey, DeclarationSignature, DeclarationStructMember, DeclarationStructType,
-    DeclarationType, GenericIdentifier, StructLocation, StructMember,
+    DeclarationEnumVariant, DeclarationFunctionKey, DeclarationSignature, DeclarationStructMember,
+    DeclarationStructType, DeclarationType, GenericIdentifier, GenericParameter,
+    GenericTypeIdentifier, StructLocation, StructMember,
 };
 use std::hash::{Hash, Hasher};
 
+/// Something which helps understanding an error
+#[derive(PartialEq, Debug, Clone)]
+pub enum ErrorContext {
+    /// Other code the error refers to, such as a previous declaration, along with what it is
+    Label((Position, Position), String),
+    Note(String),
+}
+
 #[derive(PartialEq, Debug)]
 pub struct ErrorInner {
     pos: Option<(Position, Position)>,
     message: String,
+    context: Vec<ErrorContext>,
 }
 
 #[derive(PartialEq, Debug)]
@@ -47,6 +61,20 @@ impl ErrorInner {
         &self.message
     }
 
+    pub fn context(&self) -> &[ErrorContext] {
+        &self.context
+    }
+
+    fn with_label<S: Into<String>>(mut self, pos: (Position, Position), message: S) -> Self {
+        self.context.push(ErrorContext::Label(pos, message.into()));
+        self
+    }
+
+    fn with_note<S: Into<String>>(mut self, note: S) -> Self {
+        self.context.push(ErrorContext::Note(note.into()));
+        self
+    }
+
     fn in_file(self, id: &ModuleId) -> Error {
         Error {
             inner: self,
@@ -55,9 +83,56 @@ impl ErrorInner {
     }
 }
 
-type TypeMap<'ast> = HashMap<OwnedModuleId, HashMap<UserTypeId, DeclarationType<'ast>>>;
+/// A construct which is legal but likely to be a mistake
+#[derive(PartialEq, Debug)]
+pub struct WarningInner {
+    pos: Option<(Position, Position)>,
+    message: String,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Warning {
+    pub inner: WarningInner,
+    pub module_id: PathBuf,
+}
+
+impl WarningInner {
+    pub fn pos(&self) -> &Option<(Position, Position)> {
+        &self.pos
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn in_file(self, id: &ModuleId) -> Warning {
+        Warning {
+            inner: self,
+            module_id: id.to_path_buf(),
+        }
+    }
+}
+
+type GenericDeclarations<'ast> = Vec<GenericParameter<'ast>>;
+type TypeMap<'ast> =
+    HashMap<OwnedModuleId, HashMap<UserTypeId, (DeclarationType<'ast>, GenericDeclarations<'ast>)>>;
 type ConstantMap<'ast> =
     HashMap<OwnedModuleId, HashMap<ConstantIdentifier<'ast>, DeclarationType<'ast>>>;
+type MethodMap<'ast> = HashMap<StructLocation, HashSet<DeclarationFunctionKey<'ast>>>;
+/// The signature of a called function with its type parameters bound, along with its constant generic arguments, in
+/// the order of declaration
+type CallGenerics<'ast, T> = (
+    DeclarationSignature<'ast>,
+    Vec<Option<UExpression<'ast, T>>>,
+);
+
+/// The id of the module holding the methods of the struct at `location`
+///
+/// Methods are checked as ordinary functions of this module, so that later phases do not need to know about them.
+/// As it is nested under the module file, it cannot clash with the id of an actual module.
+fn methods_module_id(location: &StructLocation) -> OwnedModuleId {
+    location.module.join(&location.name)
+}
 
 /// The global state of the program during semantic checks
 #[derive(Debug)]
@@ -70,6 +145,16 @@ struct State<'ast, T> {
     types: TypeMap<'ast>,
     // The user-defined constants
     constants: ConstantMap<'ast>,
+    /// The types and constants which are not exported by their module
+    private_symbols: HashMap<OwnedModuleId, HashSet<Identifier<'ast>>>,
+    /// The functions which are not exported by their module
+    private_functions: HashSet<DeclarationFunctionKey<'ast>>,
+    /// The methods implemented on each struct, identified by the canonical location of the struct
+    methods: MethodMap<'ast>,
+    /// The modules in which variables cannot be assigned to after their definition unless they are declared `mut`
+    immutable_modules: HashSet<OwnedModuleId>,
+    /// The warnings found in each checked module
+    warnings: HashMap<OwnedModuleId, Vec<WarningInner>>,
 }
 
 /// A symbol for a given name: either a type or a group of functions. Not both!
@@ -84,9 +169,20 @@ enum SymbolType<'ast> {
 #[derive(Default)]
 struct SymbolUnifier<'ast> {
     symbols: HashMap<String, SymbolType<'ast>>,
+    /// The position of the first declaration of each name, to point at it when another declaration conflicts with it
+    positions: HashMap<String, (Position, Position)>,
 }
 
 impl<'ast> SymbolUnifier<'ast> {
+    /// Record a declaration of `id` at `pos`, returning the position of the first declaration of `id`
+    fn declare<S: Into<String>>(
+        &mut self,
+        id: S,
+        pos: (Position, Position),
+    ) -> (Position, Position) {
+        *self.positions.entry(id.into()).or_insert(pos)
+    }
+
     fn insert_type<S: Into<String>>(&mut self, id: S) -> bool {
         let e = self.symbols.entry(id.into());
         match e {
@@ -145,8 +241,20 @@ impl<'ast, T: Field> State<'ast, T> {
             typed_modules: HashMap::new(),
             types: HashMap::new(),
             constants: HashMap::new(),
+            private_symbols: HashMap::new(),
+            private_functions: HashSet::new(),
+            methods: HashMap::new(),
+            immutable_modules: HashSet::new(),
+            warnings: HashMap::new(),
         }
     }
+
+    fn is_private(&self, module_id: &ModuleId, id: Identifier<'ast>) -> bool {
+        self.private_symbols
+            .get(module_id)
+            .map(|symbols| symbols.contains(id))
+            .unwrap_or(false)
+    }
 }
 
 /// A function query in the current module.
@@ -212,13 +320,13 @@ impl<'ast, T: Field> FunctionQuery<'ast, T> {
     /// Create a new query.
     fn new(
         id: Identifier<'ast>,
-        generics: &Option<Vec<Option<UExpression<'ast, T>>>>,
+        generics_count: Option<usize>,
         inputs: &[Type<'ast, T>],
         outputs: &[Option<Type<'ast, T>>],
     ) -> Self {
         FunctionQuery {
             id,
-            generics_count: generics.as_ref().map(|g| g.len()),
+            generics_count,
             inputs: inputs.to_owned(),
             outputs: outputs.to_owned(),
         }
@@ -227,7 +335,7 @@ impl<'ast, T: Field> FunctionQuery<'ast, T> {
     /// match a `FunctionKey` against this `FunctionQuery`
     fn match_func(&self, func: &DeclarationFunctionKey<'ast>) -> bool {
         self.id == func.id
-            && self.generics_count.map(|count| count == func.signature.generics.len()).unwrap_or(true) // we do not look at the values here, this will be checked when inlining anyway
+            && self.generics_count.map(|count| count == func.signature.generics.len() + func.signature.type_generics.len()).unwrap_or(true) // we do not look at the values here, this will be checked when inlining anyway
             && self.inputs.len() == func.signature.inputs.len()
             && self
                 .inputs
@@ -264,24 +372,45 @@ impl<'ast, T: Field> FunctionQuery<'ast, T> {
 pub struct ScopedVariable<'ast, T> {
     id: Variable<'ast, T>,
     level: usize,
+    is_mutable: bool,
 }
 
 impl<'ast, T> ScopedVariable<'ast, T> {
     fn is_constant(&self) -> bool {
         self.level == 0
     }
+
+    /// The identifier this variable is referred to by in the source, which is the same for a loop variable and the
+    /// variable it shadows
+    fn key(&self) -> CoreIdentifier<'ast> {
+        match self.id.id.id {
+            CoreIdentifier::Shadow(name, _) => CoreIdentifier::Source(name),
+            ref id => id.clone(),
+        }
+    }
+}
+
+/// A variable declared in the function being checked, so that we can warn about it if it is never read
+#[derive(Debug)]
+struct DeclaredVariable<'ast> {
+    id: crate::typed_absy::Identifier<'ast>,
+    name: &'ast str,
+    level: usize,
+    pos: (Position, Position),
+    read: bool,
 }
 
 /// Identifiers of different `ScopedVariable`s should not conflict, so we define them as equivalent
 impl<'ast, T> PartialEq for ScopedVariable<'ast, T> {
     fn eq(&self, other: &Self) -> bool {
-        self.id.id == other.id.id
+        self.key() == other.key() && self.id.id.version == other.id.id.version
     }
 }
 
 impl<'ast, T> Hash for ScopedVariable<'ast, T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.id.id.hash(state);
+        self.key().hash(state);
+        self.id.id.version.hash(state);
     }
 }
 
@@ -293,6 +422,27 @@ pub struct Checker<'ast, T> {
     scope: HashSet<ScopedVariable<'ast, T>>,
     functions: HashSet<DeclarationFunctionKey<'ast>>,
     level: usize,
+    match_count: usize,
+    casts: HashMap<OwnedModuleId, HashSet<(CastType, CastType)>>,
+    /// The opaque types standing for the type parameters of the function being checked
+    type_arguments: HashMap<Identifier<'ast>, ConcreteType>,
+    /// The variables shadowed by a loop variable, along with the level of the loop, restored when the loop is exited
+    shadowed_variables: Vec<(usize, ScopedVariable<'ast, T>)>,
+    /// The methods which can be called from the function being checked
+    methods: MethodMap<'ast>,
+    /// Whether the variables of the function being checked are immutable unless declared `mut`
+    immutable_by_default: bool,
+    /// The immutable variables which were declared but not defined yet, and can therefore be assigned to once
+    uninitialized_variables: HashSet<&'ast str>,
+    /// The variables declared in the function being checked, until their scope is exited
+    declared_variables: Vec<DeclaredVariable<'ast>>,
+    /// The functions and imports declared in the module being checked, along with their position and whether they
+    /// are imports
+    declared_symbols: Vec<(&'ast str, (Position, Position), bool)>,
+    /// The names of the functions, types and constants referred to in the module being checked
+    used_symbols: HashSet<String>,
+    /// The warnings found in the module being checked
+    warnings: Vec<WarningInner>,
 }
 
 impl<'ast, T: Field> Checker<'ast, T> {
@@ -302,6 +452,17 @@ impl<'ast, T: Field> Checker<'ast, T> {
             scope: HashSet::new(),
             functions: HashSet::new(),
             level: 0,
+            match_count: 0,
+            casts: HashMap::new(),
+            type_arguments: HashMap::new(),
+            shadowed_variables: vec![],
+            methods: HashMap::new(),
+            immutable_by_default: false,
+            uninitialized_variables: HashSet::new(),
+            declared_variables: vec![],
+            declared_symbols: vec![],
+            used_symbols: HashSet::new(),
+            warnings: vec![],
         }
     }
 
@@ -310,14 +471,17 @@ impl<'ast, T: Field> Checker<'ast, T> {
     /// # Arguments
     ///
     /// * `prog` - The `Program` to be checked
-    pub fn check(prog: Program<'ast>) -> Result<TypedProgram<'ast, T>, Vec<Error>> {
+    ///
+    /// Along with the checked program, return the warnings found in its main module. Other modules are not linted, as
+    /// they are typically libraries such as the standard library.
+    pub fn check(prog: Program<'ast>) -> Result<(TypedProgram<'ast, T>, Vec<Warning>), Vec<Error>> {
         Checker::new().check_program(prog)
     }
 
     fn check_program(
         &mut self,
         program: Program<'ast>,
-    ) -> Result<TypedProgram<'ast, T>, Vec<Error>> {
+    ) -> Result<(TypedProgram<'ast, T>, Vec<Warning>), Vec<Error>> {
         let mut state = State::new(program.modules);
 
         let mut errors = vec![];
@@ -343,10 +507,21 @@ impl<'ast, T: Field> Checker<'ast, T> {
             },
         )?;
 
-        Ok(TypedProgram {
-            main: program.main,
-            modules: state.typed_modules,
-        })
+        let warnings = state
+            .warnings
+            .remove(&program.main)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|w| w.in_file(&program.main))
+            .collect();
+
+        Ok((
+            TypedProgram {
+                main: program.main,
+                modules: state.typed_modules,
+            },
+            warnings,
+        ))
     }
 
     fn check_constant_definition(
@@ -385,7 +560,14 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 StructExpression::try_from_typed(checked_expr, &struct_ty)
                     .map(TypedExpression::from)
             }
+            DeclarationType::Tuple(ref tuple_ty) => {
+                TupleExpression::try_from_typed(checked_expr, &tuple_ty).map(TypedExpression::from)
+            }
+            DeclarationType::Enum(ref enum_ty) => {
+                EnumExpression::try_from_typed(checked_expr, &enum_ty).map(TypedExpression::from)
+            }
             DeclarationType::Int => Err(checked_expr), // Integers cannot be assigned
+            DeclarationType::Generic(..) => unreachable!(), // constants cannot be declared with a type parameter
         }
         .map_err(|e| ErrorInner {
             pos: Some(pos),
@@ -396,17 +578,46 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 id,
                 ty
             ),
+            context: vec![],
         })
         .map(|e| (ty, TypedConstant::new(e)))
     }
 
+    fn check_enum_type_id(
+        &self,
+        id: &str,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast>,
+        pos: (Position, Position),
+    ) -> Result<DeclarationEnumType<'ast>, ErrorInner> {
+        match types
+            .get(module_id)
+            .unwrap()
+            .get(id)
+            .map(|(ty, _)| ty)
+            .cloned()
+        {
+            Some(DeclarationType::Enum(enum_type)) => Ok(enum_type),
+            Some(ty) => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!("Type `{}` is not an enum", ty),
+                context: vec![],
+            }),
+            None => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!("Undefined type `{}`", id),
+                context: vec![],
+            }),
+        }
+    }
+
     fn check_struct_type_declaration(
         &mut self,
         id: String,
         s: StructDefinitionNode<'ast>,
         module_id: &ModuleId,
         state: &State<'ast, T>,
-    ) -> Result<DeclarationType<'ast>, Vec<ErrorInner>> {
+    ) -> Result<(DeclarationType<'ast>, GenericDeclarations<'ast>), Vec<ErrorInner>> {
         let pos = s.pos();
         let s = s.value;
 
@@ -414,40 +625,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
         let mut fields: Vec<(_, _)> = vec![];
         let mut fields_set = HashSet::new();
 
-        let mut generics = vec![];
-        let mut generics_map = HashMap::new();
-
-        for (index, g) in s.generics.iter().enumerate() {
-            if state
-                .constants
-                .get(module_id)
-                .and_then(|m| m.get(g.value))
-                .is_some()
-            {
-                errors.push(ErrorInner {
-                    pos: Some(g.pos()),
-                    message: format!(
-                        "Generic parameter {p} conflicts with constant symbol {p}",
-                        p = g.value
-                    ),
-                });
-            } else {
-                match generics_map.insert(g.value, index).is_none() {
-                    true => {
-                        generics.push(Some(DeclarationConstant::Generic(GenericIdentifier {
-                            name: g.value,
-                            index,
-                        })));
-                    }
-                    false => {
-                        errors.push(ErrorInner {
-                            pos: Some(g.pos()),
-                            message: format!("Generic parameter {} is already declared", g.value),
-                        });
-                    }
-                }
-            }
-        }
+        let generics_map =
+            self.check_generics_declaration(&s.generics, module_id, state, &mut errors);
 
         let mut used_generics = HashSet::new();
 
@@ -468,6 +647,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     false => errors.push(ErrorInner {
                         pos: Some(pos),
                         message: format!("Duplicate key {} in struct definition", f.0,),
+                        context: vec![],
                     }),
                 },
                 Err(e) => {
@@ -482,7 +662,204 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 errors.push(ErrorInner {
                     pos: Some(pos),
                     message: format!("Generic parameter {} must be used", declared_generic),
+                    context: vec![],
+                });
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let generics = Self::generic_declarations(&generics_map);
+
+        Ok((
+            DeclarationType::Struct(
+                DeclarationStructType::new(
+                    module_id.to_path_buf(),
+                    id,
+                    generics
+                        .iter()
+                        .filter_map(|g| match g {
+                            GenericParameter::Constant(g) => {
+                                Some(Some(DeclarationConstant::Generic(g.clone())))
+                            }
+                            GenericParameter::Type(..) => None,
+                        })
+                        .collect(),
+                    fields
+                        .iter()
+                        .map(|f| DeclarationStructMember::new(f.0.clone(), f.1.clone()))
+                        .collect(),
+                )
+                .type_generics(
+                    generics
+                        .iter()
+                        .filter_map(|g| match g {
+                            GenericParameter::Type(g) => Some(DeclarationType::Generic(g.clone())),
+                            GenericParameter::Constant(..) => None,
+                        })
+                        .collect(),
+                ),
+            ),
+            generics,
+        ))
+    }
+
+    fn check_generics_declaration(
+        &self,
+        declared_generics: &[GenericDeclarationNode<'ast>],
+        module_id: &ModuleId,
+        state: &State<'ast, T>,
+        errors: &mut Vec<ErrorInner>,
+    ) -> HashMap<Identifier<'ast>, GenericParameter<'ast>> {
+        let mut generics_map = HashMap::new();
+
+        for (index, g) in declared_generics.iter().enumerate() {
+            let name = g.value.id;
+
+            let parameter = match g.value.kind {
+                GenericKind::Constant => {
+                    GenericParameter::Constant(GenericIdentifier::with_name(name).index(index))
+                }
+                GenericKind::Type => {
+                    GenericParameter::Type(GenericTypeIdentifier::with_name(name).index(index))
+                }
+            };
+
+            if state
+                .constants
+                .get(module_id)
+                .and_then(|m| m.get(name))
+                .is_some()
+            {
+                errors.push(ErrorInner {
+                    pos: Some(g.pos()),
+                    message: format!(
+                        "Generic parameter {p} conflicts with constant symbol {p}",
+                        p = name
+                    ),
+                    context: vec![],
+                });
+            } else if generics_map.insert(name, parameter).is_some() {
+                errors.push(ErrorInner {
+                    pos: Some(g.pos()),
+                    message: format!("Generic parameter {} is already declared", name),
+                    context: vec![],
+                });
+            }
+        }
+
+        generics_map
+    }
+
+    /// Build the declarations of generic parameters, in the order they were declared in
+    fn generic_declarations(
+        generics_map: &HashMap<Identifier<'ast>, GenericParameter<'ast>>,
+    ) -> GenericDeclarations<'ast> {
+        let mut generics: Vec<_> = generics_map.values().cloned().collect();
+        generics.sort_by_key(|g| match g {
+            GenericParameter::Constant(g) => g.index,
+            GenericParameter::Type(g) => g.index,
+        });
+        generics
+    }
+
+    fn check_type_definition(
+        &mut self,
+        t: TypeDefinitionNode<'ast>,
+        module_id: &ModuleId,
+        state: &State<'ast, T>,
+    ) -> Result<(DeclarationType<'ast>, GenericDeclarations<'ast>), Vec<ErrorInner>> {
+        let pos = t.pos();
+        let t = t.value;
+
+        let mut errors = vec![];
+
+        let generics_map =
+            self.check_generics_declaration(&t.generics, module_id, state, &mut errors);
+
+        let mut used_generics = HashSet::new();
+
+        let ty = self
+            .check_declaration_type(t.ty, module_id, state, &generics_map, &mut used_generics)
+            .map_err(|e| errors.push(e))
+            .ok();
+
+        // check that all declared generics were used
+        for declared_generic in generics_map.keys() {
+            if !used_generics.contains(declared_generic) {
+                errors.push(ErrorInner {
+                    pos: Some(pos),
+                    message: format!("Generic parameter {} must be used", declared_generic),
+                    context: vec![],
+                });
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok((ty.unwrap(), Self::generic_declarations(&generics_map)))
+    }
+
+    fn check_enum_type_declaration(
+        &mut self,
+        id: String,
+        e: EnumDefinitionNode<'ast>,
+        module_id: &ModuleId,
+        state: &State<'ast, T>,
+    ) -> Result<DeclarationType<'ast>, Vec<ErrorInner>> {
+        let pos = e.pos();
+        let e = e.value;
+
+        let mut errors = vec![];
+        let mut variants = vec![];
+        let mut variants_set = HashSet::new();
+
+        if e.variants.is_empty() {
+            errors.push(ErrorInner {
+                pos: Some(pos),
+                message: format!("Enum {} must have at least one variant", id),
+                context: vec![],
+            });
+        }
+
+        for variant in e.variants {
+            let variant_pos = variant.pos();
+            let variant = variant.value;
+
+            if !variants_set.insert(variant.id) {
+                errors.push(ErrorInner {
+                    pos: Some(variant_pos),
+                    message: format!("Duplicate variant {} in enum definition", variant.id),
+                    context: vec![],
                 });
+                continue;
+            }
+
+            // enums do not support generic parameters yet
+            let mut used_generics = HashSet::new();
+
+            match variant
+                .types
+                .into_iter()
+                .map(|t| {
+                    self.check_declaration_type(
+                        t,
+                        module_id,
+                        state,
+                        &HashMap::new(),
+                        &mut used_generics,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(payload) => {
+                    variants.push(DeclarationEnumVariant::new(variant.id.to_string(), payload))
+                }
+                Err(e) => errors.push(e),
             }
         }
 
@@ -490,20 +867,17 @@ impl<'ast, T: Field> Checker<'ast, T> {
             return Err(errors);
         }
 
-        Ok(DeclarationType::Struct(DeclarationStructType::new(
+        Ok(DeclarationType::Enum(DeclarationEnumType::new(
             module_id.to_path_buf(),
             id,
-            generics,
-            fields
-                .iter()
-                .map(|f| DeclarationStructMember::new(f.0.clone(), f.1.clone()))
-                .collect(),
+            variants,
         )))
     }
 
     fn check_symbol_declaration(
         &mut self,
         declaration: SymbolDeclarationNode<'ast>,
+        exported: bool,
         module_id: &ModuleId,
         state: &mut State<'ast, T>,
         functions: &mut TypedFunctionSymbols<'ast, T>,
@@ -515,25 +889,52 @@ impl<'ast, T: Field> Checker<'ast, T> {
         let pos = declaration.pos();
         let declaration = declaration.value;
 
+        let first_pos = symbol_unifier.declare(declaration.id, pos);
+
+        // keep track of the private functions and imports, to warn about the ones which are never used
+        match declaration.symbol {
+            _ if declaration.public => {}
+            Symbol::Here(SymbolDefinition::Function(..)) => {
+                self.declared_symbols.push((declaration.id, pos, false))
+            }
+            Symbol::There(..) | Symbol::Flat(..) => {
+                self.declared_symbols.push((declaration.id, pos, true))
+            }
+            _ => {}
+        }
+
         match declaration.symbol.clone() {
-            Symbol::Here(SymbolDefinition::Struct(t)) => {
-                match self.check_struct_type_declaration(
-                    declaration.id.to_string(),
-                    t.clone(),
-                    module_id,
-                    state,
-                ) {
+            Symbol::Here(SymbolDefinition::Struct(..))
+            | Symbol::Here(SymbolDefinition::Enum(..))
+            | Symbol::Here(SymbolDefinition::Type(..)) => {
+                let checked_type = match declaration.symbol.clone() {
+                    Symbol::Here(SymbolDefinition::Struct(t)) => self
+                        .check_struct_type_declaration(
+                            declaration.id.to_string(),
+                            t,
+                            module_id,
+                            state,
+                        ),
+                    Symbol::Here(SymbolDefinition::Enum(e)) => self
+                        .check_enum_type_declaration(
+                            declaration.id.to_string(),
+                            e,
+                            module_id,
+                            state,
+                        )
+                        .map(|ty| (ty, vec![])),
+                    Symbol::Here(SymbolDefinition::Type(t)) => {
+                        self.check_type_definition(t, module_id, state)
+                    }
+                    _ => unreachable!(),
+                };
+
+                match checked_type {
                     Ok(ty) => {
                         match symbol_unifier.insert_type(declaration.id) {
                             false => errors.push(
-                                ErrorInner {
-                                    pos: Some(pos),
-                                    message: format!(
-                                        "{} conflicts with another symbol",
-                                        declaration.id
-                                    ),
-                                }
-                                .in_file(module_id),
+                                Self::conflicting_symbol(declaration.id, pos, first_pos)
+                                    .in_file(module_id),
                             ),
                             true => {
                                 // there should be no entry in the map for this type yet
@@ -557,14 +958,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     Ok((d_t, c)) => {
                         match symbol_unifier.insert_constant(declaration.id) {
                             false => errors.push(
-                                ErrorInner {
-                                    pos: Some(pos),
-                                    message: format!(
-                                        "{} conflicts with another symbol",
-                                        declaration.id
-                                    ),
-                                }
-                                .in_file(module_id),
+                                Self::conflicting_symbol(declaration.id, pos, first_pos)
+                                    .in_file(module_id),
                             ),
                             true => {
                                 constants.push((
@@ -575,10 +970,10 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     ),
                                     TypedConstantSymbol::Here(c.clone()),
                                 ));
-                                self.insert_into_scope(Variable::with_id_and_type(
-                                    declaration.id,
-                                    c.get_type(),
-                                ));
+                                self.insert_into_scope(
+                                    Variable::with_id_and_type(declaration.id, c.get_type()),
+                                    false,
+                                );
                                 assert!(state
                                     .constants
                                     .entry(module_id.to_path_buf())
@@ -594,45 +989,79 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 }
             }
             Symbol::Here(SymbolDefinition::Function(f)) => {
-                match self.check_function(f, module_id, state) {
+                let signature = self.check_signature(f.value.signature.clone(), module_id, state);
+                self.return_types = None;
+
+                let funct = match signature {
+                    Ok(signature) if !signature.type_generics.is_empty() => {
+                        self.check_template(declaration.id, f, signature, module_id, state)
+                    }
+                    _ => self.check_function(f, module_id, state),
+                };
+
+                match funct {
                     Ok(funct) => {
                         match symbol_unifier
                             .insert_function(declaration.id, funct.signature.clone())
                         {
                             false => errors.push(
-                                ErrorInner {
-                                    pos: Some(pos),
-                                    message: format!(
-                                        "{} conflicts with another symbol",
-                                        declaration.id
-                                    ),
-                                }
-                                .in_file(module_id),
+                                Self::conflicting_symbol(declaration.id, pos, first_pos)
+                                    .in_file(module_id),
                             ),
                             true => {}
                         };
 
-                        self.functions.insert(
-                            DeclarationFunctionKey::with_location(
-                                module_id.to_path_buf(),
-                                declaration.id,
-                            )
-                            .signature(funct.signature.clone()),
-                        );
-                        functions.insert(
-                            DeclarationFunctionKey::with_location(
-                                module_id.to_path_buf(),
-                                declaration.id,
-                            )
-                            .signature(funct.signature.clone()),
-                            TypedFunctionSymbol::Here(funct),
-                        );
+                        let key = DeclarationFunctionKey::with_location(
+                            module_id.to_path_buf(),
+                            declaration.id,
+                        )
+                        .signature(funct.signature.clone());
+
+                        if !exported {
+                            state.private_functions.insert(key.clone());
+                        }
+
+                        self.functions.insert(key.clone());
+                        functions.insert(key, TypedFunctionSymbol::Here(funct));
                     }
                     Err(e) => {
                         errors.extend(e.into_iter().map(|inner| inner.in_file(module_id)));
                     }
                 }
             }
+            Symbol::Here(SymbolDefinition::Impl(i)) => {
+                match self.check_impl_target(declaration.id, module_id, state) {
+                    Ok(location) => {
+                        for method in i.value.methods {
+                            let pos = method.pos();
+                            let method = method.value;
+
+                            if let Err(e) = self.check_method(
+                                method.id,
+                                method.function,
+                                &location,
+                                module_id,
+                                state,
+                            ) {
+                                errors.extend(e.into_iter().map(|e| {
+                                    ErrorInner {
+                                        pos: e.pos.or(Some(pos)),
+                                        ..e
+                                    }
+                                    .in_file(module_id)
+                                }));
+                            }
+                        }
+                    }
+                    Err(e) => errors.push(
+                        ErrorInner {
+                            pos: Some(pos),
+                            ..e
+                        }
+                        .in_file(module_id),
+                    ),
+                }
+            }
             Symbol::There(import) => {
                 let pos = import.pos();
                 let import = import.value;
@@ -647,6 +1076,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             .functions
                             .iter()
                             .filter(|(k, _)| k.id == import.symbol_id)
+                            .filter(|(k, _)| !state.private_functions.contains(k))
                             .map(|(_, v)| DeclarationFunctionKey {
                                 module: import.module_id.to_path_buf(),
                                 id: import.symbol_id,
@@ -654,12 +1084,16 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             })
                             .collect();
 
+                        // types and constants which are not exported cannot be imported
+                        let private = state.is_private(&import.module_id, import.symbol_id);
+
                         // find candidates in the types
                         let type_candidate = state
                             .types
                             .entry(import.module_id.to_path_buf())
                             .or_default()
                             .get(import.symbol_id)
+                            .filter(|_| !private)
                             .cloned();
 
                         // find constant definition candidate
@@ -669,22 +1103,30 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             .or_default()
                             .iter()
                             .find(|(i, _)| *i == &import.symbol_id)
+                            .filter(|_| !private)
                             .map(|(_, c)| c)
                             .cloned();
 
                         match (function_candidates.len(), type_candidate, const_candidate) {
-                            (0, Some(t), None) => {
+                            (0, Some((t, generics)), None) => {
 
-                                // rename the type to the declared symbol
+                                // rename the type to the declared symbol, unless it is an alias which keeps the name of the type it refers to
                                 let t = match t {
-                                    DeclarationType::Struct(t) => DeclarationType::Struct(DeclarationStructType {
+                                    DeclarationType::Struct(t) if t.location.as_ref().unwrap_or(&t.canonical_location).name == import.symbol_id => DeclarationType::Struct(DeclarationStructType {
                                         location: Some(StructLocation {
                                             name: declaration.id.into(),
                                             module: module_id.to_path_buf()
                                         }),
                                         ..t
                                     }),
-                                    _ => unreachable!()
+                                    DeclarationType::Enum(t) if t.location.as_ref().unwrap_or(&t.canonical_location).name == import.symbol_id => DeclarationType::Enum(DeclarationEnumType {
+                                        location: Some(StructLocation {
+                                            name: declaration.id.into(),
+                                            module: module_id.to_path_buf()
+                                        }),
+                                        ..t
+                                    }),
+                                    t => t
                                 };
 
                                 // we imported a type, so the symbol it gets bound to should not already exist
@@ -692,13 +1134,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     false => {
                                         errors.push(Error {
                                             module_id: module_id.to_path_buf(),
-                                            inner: ErrorInner {
-                                                pos: Some(pos),
-                                                message: format!(
-                                                    "{} conflicts with another symbol",
-                                                    declaration.id,
-                                                ),
-                                            }});
+                                            inner: Self::conflicting_symbol(declaration.id, pos, first_pos),
+                                        });
                                     }
                                     true => {}
                                 };
@@ -706,27 +1143,22 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     .types
                                     .entry(module_id.to_path_buf())
                                     .or_default()
-                                    .insert(declaration.id.to_string(), t);
+                                    .insert(declaration.id.to_string(), (t, generics));
                             }
                             (0, None, Some(ty)) => {
                                 match symbol_unifier.insert_constant(declaration.id) {
                                     false => {
                                         errors.push(Error {
                                             module_id: module_id.to_path_buf(),
-                                            inner: ErrorInner {
-                                                pos: Some(pos),
-                                                message: format!(
-                                                    "{} conflicts with another symbol",
-                                                    declaration.id,
-                                                ),
-                                            }});
+                                            inner: Self::conflicting_symbol(declaration.id, pos, first_pos),
+                                        });
                                     }
                                     true => {
                                         let imported_id = CanonicalConstantIdentifier::new(import.symbol_id, import.module_id, ty.clone());
                                         let id = CanonicalConstantIdentifier::new(declaration.id, module_id.into(), ty.clone());
 
                                         constants.push((id.clone(), TypedConstantSymbol::There(imported_id)));
-                                        self.insert_into_scope(Variable::with_id_and_type(declaration.id, crate::typed_absy::types::try_from_g_type(ty.clone()).unwrap()));
+                                        self.insert_into_scope(Variable::with_id_and_type(declaration.id, crate::typed_absy::types::try_from_g_type(ty.clone()).unwrap()), false);
 
                                         state
                                             .constants
@@ -736,6 +1168,16 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     }
                                 };
                             }
+                            (0, None, None) if private => {
+                                errors.push(ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                        "Symbol {} in module {} is private",
+                                        import.symbol_id, import.module_id.display(),
+                                    ),
+                                    context: vec![],
+                                }.in_file(module_id));
+                            }
                             (0, None, None) => {
                                 errors.push(ErrorInner {
                                     pos: Some(pos),
@@ -743,6 +1185,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                         "Could not find symbol {} in module {}",
                                         import.symbol_id, import.module_id.display(),
                                     ),
+                                    context: vec![],
                                 }.in_file(module_id));
                             }
                             (_, Some(_), Some(_)) => unreachable!("collision in module we're importing from should have been caught when checking it"),
@@ -751,20 +1194,19 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                                     match symbol_unifier.insert_function(declaration.id, candidate.signature.clone()) {
                                         false => {
-                                            errors.push(ErrorInner {
-                                                pos: Some(pos),
-                                                message: format!(
-                                                    "{} conflicts with another symbol",
-                                                    declaration.id,
-                                                ),
-                                            }.in_file(module_id));
+                                            errors.push(Self::conflicting_symbol(declaration.id, pos, first_pos).in_file(module_id));
                                         },
                                         true => {}
                                     };
 
                                     let local_key = candidate.clone().id(declaration.id).module(module_id.to_path_buf());
 
+                                    if !exported {
+                                        state.private_functions.insert(local_key.clone());
+                                    }
+
                                     self.functions.insert(local_key.clone());
+
                                     functions.insert(
                                         local_key,
                                         TypedFunctionSymbol::There(candidate,
@@ -783,32 +1225,36 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 match symbol_unifier.insert_function(declaration.id, funct.signature()) {
                     false => {
                         errors.push(
-                            ErrorInner {
-                                pos: Some(pos),
-                                message: format!(
-                                    "{} conflicts with another symbol",
-                                    declaration.id
-                                ),
-                            }
-                            .in_file(module_id),
+                            Self::conflicting_symbol(declaration.id, pos, first_pos)
+                                .in_file(module_id),
                         );
                     }
                     true => {}
                 };
 
-                self.functions.insert(
-                    DeclarationFunctionKey::with_location(module_id.to_path_buf(), declaration.id)
-                        .signature(funct.signature()),
-                );
-                functions.insert(
+                let key =
                     DeclarationFunctionKey::with_location(module_id.to_path_buf(), declaration.id)
-                        .signature(funct.signature()),
-                    TypedFunctionSymbol::Flat(funct),
-                );
+                        .signature(funct.signature());
+
+                if !exported {
+                    state.private_functions.insert(key.clone());
+                }
+
+                self.functions.insert(key.clone());
+                functions.insert(key, TypedFunctionSymbol::Flat(funct));
             }
             _ => unreachable!(),
         };
 
+        // an impl block does not declare a symbol, it uses the name of the struct it implements methods on
+        if !exported && !matches!(declaration.symbol, Symbol::Here(SymbolDefinition::Impl(..))) {
+            state
+                .private_symbols
+                .entry(module_id.to_path_buf())
+                .or_default()
+                .insert(declaration.id);
+        }
+
         // return if any errors occured
         if !errors.is_empty() {
             return Err(errors);
@@ -817,6 +1263,107 @@ impl<'ast, T: Field> Checker<'ast, T> {
         Ok(())
     }
 
+    /// Check that methods can be implemented on the type `id`, returning the location of the struct it refers to
+    fn check_impl_target(
+        &self,
+        id: Identifier<'ast>,
+        module_id: &ModuleId,
+        state: &State<'ast, T>,
+    ) -> Result<StructLocation, ErrorInner> {
+        match state.types.get(module_id).and_then(|types| types.get(id)) {
+            Some((DeclarationType::Struct(struct_type), _)) => {
+                if struct_type.canonical_location.module != module_id {
+                    Err(ErrorInner {
+                        pos: None,
+                        message: format!(
+                            "Methods can only be implemented on structs declared in the same module, {} is declared in {}",
+                            id,
+                            struct_type.canonical_location.module.display()
+                        ),
+                        context: vec![],
+                    })
+                } else if !struct_type.generics.is_empty() {
+                    Err(ErrorInner {
+                        pos: None,
+                        message: format!("Methods cannot be implemented on generic struct {}", id),
+                        context: vec![],
+                    })
+                } else {
+                    Ok(struct_type.canonical_location.clone())
+                }
+            }
+            Some(_) => Err(ErrorInner {
+                pos: None,
+                message: format!(
+                    "Methods can only be implemented on structs, {} is not a struct",
+                    id
+                ),
+                context: vec![],
+            }),
+            None => Err(ErrorInner {
+                pos: None,
+                message: format!("Undefined type {}", id),
+                context: vec![],
+            }),
+        }
+    }
+
+    /// Check a method of the struct at `location`, and add it to the functions of the module holding its methods
+    fn check_method(
+        &mut self,
+        id: Identifier<'ast>,
+        function: FunctionNode<'ast>,
+        location: &StructLocation,
+        module_id: &ModuleId,
+        state: &mut State<'ast, T>,
+    ) -> Result<(), Vec<ErrorInner>> {
+        let signature = self.check_signature(function.value.signature.clone(), module_id, state)?;
+        self.return_types = None;
+
+        if !signature.type_generics.is_empty() {
+            return Err(vec![ErrorInner {
+                pos: None,
+                message: format!("Method {} cannot be generic over types", id),
+                context: vec![],
+            }]);
+        }
+
+        let function = self.check_function(function, module_id, state)?;
+
+        let methods_module_id = methods_module_id(location);
+
+        let key = DeclarationFunctionKey::with_location(methods_module_id.clone(), id)
+            .signature(function.signature.clone());
+
+        if !state
+            .methods
+            .entry(location.clone())
+            .or_default()
+            .insert(key.clone())
+        {
+            return Err(vec![ErrorInner {
+                pos: None,
+                message: format!(
+                    "Method {} is already implemented on {} with the same signature",
+                    id, location.name
+                ),
+                context: vec![],
+            }]);
+        }
+
+        state
+            .typed_modules
+            .entry(methods_module_id)
+            .or_insert_with(|| TypedModule {
+                functions: TypedFunctionSymbols::new(),
+                constants: TypedConstantSymbols::new(),
+            })
+            .functions
+            .insert(key, TypedFunctionSymbol::Here(function));
+
+        Ok(())
+    }
+
     fn check_module(
         &mut self,
         module_id: &ModuleId,
@@ -838,10 +1385,25 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 // we keep track of the introduced symbols to avoid collisions between types and functions
                 let mut symbol_unifier = SymbolUnifier::default();
 
+                if module.immutable_by_default {
+                    state.immutable_modules.insert(module_id.to_path_buf());
+                }
+
+                // a module which does not declare any symbol as `pub` exports all of them
+                let exports_all = module.exports_all();
+
+                // the lints are collected per module, as checking an import interrupts the module importing it
+                let outer_declared_symbols = std::mem::take(&mut self.declared_symbols);
+                let outer_used_symbols = std::mem::take(&mut self.used_symbols);
+                let outer_warnings = std::mem::take(&mut self.warnings);
+
                 // we go through symbol declarations and check them
                 for declaration in module.symbols {
+                    let exported = exports_all || declaration.value.public;
+
                     self.check_symbol_declaration(
                         declaration,
+                        exported,
                         module_id,
                         state,
                         &mut checked_functions,
@@ -850,6 +1412,39 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     )?
                 }
 
+                let warnings = self.module_warnings();
+                state.warnings.insert(module_id.to_path_buf(), warnings);
+
+                self.declared_symbols = outer_declared_symbols;
+                self.used_symbols = outer_used_symbols;
+                self.warnings = outer_warnings;
+
+                // add the functions implementing the casts used in this module
+                for (from, to) in self.casts.remove(module_id).unwrap_or_default() {
+                    checked_functions.extend(casts::functions(module_id, from, to));
+                }
+
+                // the methods implemented in this module refer to its constants, which we make available in the
+                // modules holding them
+                for location in state.methods.keys().filter(|l| l.module == module_id) {
+                    state
+                        .typed_modules
+                        .get_mut(&methods_module_id(location))
+                        .unwrap()
+                        .constants = checked_constants
+                        .iter()
+                        .map(|(id, _)| {
+                            (
+                                CanonicalConstantIdentifier {
+                                    module: methods_module_id(location),
+                                    ..id.clone()
+                                },
+                                TypedConstantSymbol::There(id.clone()),
+                            )
+                        })
+                        .collect();
+                }
+
                 Some(TypedModule {
                     functions: checked_functions,
                     constants: checked_constants,
@@ -869,6 +1464,36 @@ impl<'ast, T: Field> Checker<'ast, T> {
         Ok(())
     }
 
+    /// Return the warnings found in the module being checked, in the order of the source
+    fn module_warnings(&mut self) -> Vec<WarningInner> {
+        let declared_symbols = std::mem::take(&mut self.declared_symbols);
+
+        // the functions and imports which are never referred to. A glob import brings several symbols at the same
+        // position, and we do not expect all of them to be used
+        for (id, pos, is_import) in &declared_symbols {
+            let is_glob = *is_import
+                && declared_symbols
+                    .iter()
+                    .filter(|(_, p, is_import)| *is_import && p == pos)
+                    .count()
+                    > 1;
+
+            if *id != "main" && !is_glob && !self.used_symbols.contains(*id) {
+                self.warnings.push(WarningInner {
+                    pos: Some(*pos),
+                    message: match is_import {
+                        true => format!("Unused import `{}`", id),
+                        false => format!("Unused function `{}`", id),
+                    },
+                });
+            }
+        }
+
+        let mut warnings = std::mem::take(&mut self.warnings);
+        warnings.sort_by_key(|w| w.pos);
+        warnings
+    }
+
     fn check_single_main(module: &TypedModule<T>) -> Result<(), ErrorInner> {
         match module
             .functions
@@ -880,10 +1505,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
             0 => Err(ErrorInner {
                 pos: None,
                 message: "No main function found".into(),
+                context: vec![],
             }),
             n => Err(ErrorInner {
                 pos: None,
                 message: format!("Only one main function allowed, found {}", n),
+                context: vec![],
             }),
         }
     }
@@ -894,6 +1521,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
             t => Err(ErrorInner {
                 pos: Some(var.pos()),
                 message: format!("Variable in for loop cannot have type {}", t),
+                context: vec![],
             }),
         }
     }
@@ -906,6 +1534,11 @@ impl<'ast, T: Field> Checker<'ast, T> {
     ) -> Result<TypedFunction<'ast, T>, Vec<ErrorInner>> {
         assert!(self.return_types.is_none());
 
+        // the methods declared so far can be called from this function
+        self.methods = state.methods.clone();
+
+        self.immutable_by_default = state.immutable_modules.contains(module_id);
+
         self.enter_scope();
 
         let pos = funct_node.pos();
@@ -931,10 +1564,10 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             DeclarationConstant::Generic(g) => g.name,
                             _ => unreachable!(),
                         },
-                        Type::Uint(UBitwidth::B32),
+                        Type::Uint(IntBitwidth::B32),
                     );
                     // we don't have to check for conflicts here, because this was done when checking the signature
-                    self.insert_into_scope(v.clone());
+                    self.insert_into_scope(v.clone(), false);
                 }
 
                 for (arg, decl_ty) in funct.arguments.into_iter().zip(s.inputs.iter()) {
@@ -945,14 +1578,16 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     let decl_v =
                         DeclarationVariable::with_id_and_type(arg.id.value.id, decl_ty.clone());
 
-                    match self.insert_into_scope(
-                        crate::typed_absy::variable::try_from_g_variable(decl_v.clone()).unwrap(),
-                    ) {
-                        true => {}
+                    let v =
+                        crate::typed_absy::variable::try_from_g_variable(decl_v.clone()).unwrap();
+
+                    match self.insert_into_scope(v.clone(), arg.id.value.is_mutable) {
+                        true => self.declare_variable(&v, arg.id.value.id, pos),
                         false => {
                             errors.push(ErrorInner {
                                 pos: Some(pos),
-                                message: format!("Duplicate name in function definition: `{}` was previously declared as an argument or a generic constant", arg.id.value.id)
+                                message: format!("Duplicate name in function definition: `{}` was previously declared as an argument or a generic constant", arg.id.value.id),
+                                context: vec![],
                             });
                         }
                     };
@@ -966,12 +1601,15 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                 for stat in funct.statements.into_iter() {
                     let pos = Some(stat.pos());
+                    let location =
+                        SourceLocation::new(module_id.display().to_string(), stat.pos().0);
 
                     if let Statement::Return(..) = stat.value {
                         if found_return {
                             errors.push(ErrorInner {
                                 pos,
                                 message: "Expected a single return statement".to_string(),
+                                context: vec![],
                             });
                         }
 
@@ -1000,9 +1638,11 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                                 .collect::<Vec<_>>()
                                                 .join(", ")
                                         ),
+                                        context: vec![],
                                     }),
                                 }
                             };
+                            statements_checked.push(TypedStatement::Location(location));
                             statements_checked.push(statement);
                         }
                         Err(e) => {
@@ -1015,6 +1655,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     errors.push(ErrorInner {
                         pos: Some(pos),
                         message: "Expected a return statement".to_string(),
+                        context: vec![],
                     });
                 }
 
@@ -1049,41 +1690,11 @@ impl<'ast, T: Field> Checker<'ast, T> {
         let mut errors = vec![];
         let mut inputs = vec![];
         let mut outputs = vec![];
-        let mut generics = vec![];
 
-        let mut generics_map = HashMap::new();
+        let generics_map =
+            self.check_generics_declaration(&signature.generics, module_id, state, &mut errors);
 
-        for (index, g) in signature.generics.iter().enumerate() {
-            if state
-                .constants
-                .get(module_id)
-                .and_then(|m| m.get(g.value))
-                .is_some()
-            {
-                errors.push(ErrorInner {
-                    pos: Some(g.pos()),
-                    message: format!(
-                        "Generic parameter {p} conflicts with constant symbol {p}",
-                        p = g.value
-                    ),
-                });
-            } else {
-                match generics_map.insert(g.value, index).is_none() {
-                    true => {
-                        generics.push(Some(DeclarationConstant::Generic(GenericIdentifier {
-                            name: g.value,
-                            index,
-                        })));
-                    }
-                    false => {
-                        errors.push(ErrorInner {
-                            pos: Some(g.pos()),
-                            message: format!("Generic parameter {} is already declared", g.value),
-                        });
-                    }
-                }
-            }
-        }
+        let mut used_generics = HashSet::new();
 
         for t in signature.inputs {
             match self.check_declaration_type(
@@ -1091,7 +1702,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 module_id,
                 state,
                 &generics_map,
-                &mut HashSet::default(),
+                &mut used_generics,
             ) {
                 Ok(t) => {
                     inputs.push(t);
@@ -1108,7 +1719,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 module_id,
                 state,
                 &generics_map,
-                &mut HashSet::default(),
+                &mut used_generics,
             ) {
                 Ok(t) => {
                     outputs.push(t);
@@ -1125,11 +1736,37 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
         self.return_types = Some(outputs.clone());
 
-        Ok(DeclarationSignature {
-            generics,
-            inputs,
-            outputs,
-        })
+        let generics = Self::generic_declarations(&generics_map);
+
+        Ok(DeclarationSignature::new()
+            .generics(
+                generics
+                    .iter()
+                    .filter_map(|g| match g {
+                        GenericParameter::Constant(g) => {
+                            Some(Some(DeclarationConstant::Generic(g.clone())))
+                        }
+                        GenericParameter::Type(..) => None,
+                    })
+                    .collect(),
+            )
+            .type_generics(
+                generics
+                    .into_iter()
+                    .filter_map(|g| match g {
+                        // in the body of a function which is generic over types, type parameters are opaque types
+                        GenericParameter::Type(g) => {
+                            Some(match self.type_arguments.get(g.name.as_str()) {
+                                Some(ty) => ty.clone().into(),
+                                None => DeclarationType::Generic(g),
+                            })
+                        }
+                        GenericParameter::Constant(..) => None,
+                    })
+                    .collect(),
+            )
+            .inputs(inputs)
+            .outputs(outputs))
     }
 
     fn check_type(
@@ -1145,6 +1782,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
             UnresolvedType::FieldElement => Ok(Type::FieldElement),
             UnresolvedType::Boolean => Ok(Type::Boolean),
             UnresolvedType::Uint(bitwidth) => Ok(Type::uint(bitwidth)),
+            UnresolvedType::Int(bitwidth) => Ok(Type::uint(IntBitwidth::signed(bitwidth))),
             UnresolvedType::Array(t, size) => {
                 let size = self.check_expression(size, module_id, types)?;
 
@@ -1152,23 +1790,25 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                 let size = match size {
                     TypedExpression::Uint(e) => match e.bitwidth() {
-                        UBitwidth::B32 => Ok(e),
+                        IntBitwidth::B32 => Ok(e),
                         _ => Err(ErrorInner {
                             pos: Some(pos),
                             message: format!(
                             "Expected array dimension to be a u32 constant, found {} of type {}",
                             e, ty
                         ),
+                            context: vec![],
                         }),
                     },
                     TypedExpression::Int(v) => {
-                        UExpression::try_from_int(v.clone(), &UBitwidth::B32).map_err(|_| {
+                        UExpression::try_from_int(v.clone(), &IntBitwidth::B32).map_err(|_| {
                             ErrorInner {
                                 pos: Some(pos),
                                 message: format!(
                             "Expected array dimension to be a u32 constant, found {} of type {}",
                             v, ty
                         ),
+                                context: vec![],
                             }
                         })
                     }
@@ -1178,6 +1818,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             "Expected array dimension to be a u32 constant, found {} of type {}",
                             size, ty
                         ),
+                        context: vec![],
                     }),
                 }?;
 
@@ -1187,93 +1828,148 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 )))
             }
             UnresolvedType::User(id, generics) => {
-                let declaration_type =
-                    types
-                        .get(module_id)
-                        .unwrap()
-                        .get(&id)
-                        .cloned()
-                        .ok_or_else(|| ErrorInner {
-                            pos: Some(pos),
-                            message: format!("Undefined type {}", id),
-                        })?;
+                // a type parameter of the function being checked
+                if generics.is_none() {
+                    if let Some(ty) = self.type_arguments.get(id.as_str()) {
+                        return Ok(ty.clone().into());
+                    }
+                }
+
+                self.used_symbols.insert(id.clone());
+
+                let (declared_ty, declared_generics) = types
+                    .get(module_id)
+                    .unwrap()
+                    .get(&id)
+                    .cloned()
+                    .ok_or_else(|| ErrorInner {
+                        pos: Some(pos),
+                        message: format!("Undefined type {}", id),
+                        context: vec![],
+                    })?;
 
                 // absence of generics is treated as 0 generics, as we do not provide inference for now
                 let generics = generics.unwrap_or_default();
 
                 // check generics
-                match declaration_type {
-                    DeclarationType::Struct(struct_type) => {
-                        match struct_type.generics.len() == generics.len() {
-                            true => {
-                                // downcast the generics to identifiers, as this is the only possibility here
-                                let generic_identifiers = struct_type.generics.iter().map(|c| {
-                                    match c.as_ref().unwrap() {
-                                        DeclarationConstant::Generic(g) => g.clone(),
-                                        _ => unreachable!(),
-                                    }
-                                });
+                match declared_generics.len() == generics.len() {
+                    true => {
+                        // build the generic assignment for this type
+                        let mut assignment = GGenericsAssignment::default();
+
+                        for (declared_generic, g) in declared_generics.into_iter().zip(generics) {
+                            let g = g.ok_or_else(|| ErrorInner {
+                                pos: Some(pos),
+                                message:
+                                    "Expected u32 constant or identifier, but found `_`. Generic inference is not supported yet."
+                                        .into(),
+                                context: vec![],
+                            })?;
+
+                            match declared_generic {
+                                GenericParameter::Constant(declared_generic) => {
+                                    let e = match g {
+                                        GenericArgument::Expression(e) => Ok(e),
+                                        GenericArgument::Type(t) => Err(ErrorInner {
+                                            pos: Some(t.pos()),
+                                            message: format!("Expected u32 constant or identifier, found type {}", t),
+                                            context: vec![],
+                                        }),
+                                    }?;
 
-                                // build the generic assignment for this type
-                                let assignment = GGenericsAssignment(generics
-                                    .into_iter()
-                                    .zip(generic_identifiers)
-                                    .map(|(e, g)| match e {
-                                        Some(e) => {
-                                            self
-                                                .check_expression(e, module_id, types)
-                                                .and_then(|e| {
-                                                    UExpression::try_from_typed(e, &UBitwidth::B32)
-                                                        .map(|e| (g, e))
-                                                        .map_err(|e| ErrorInner {
-                                                            pos: Some(pos),
-                                                            message: format!("Expected u32 expression, but got expression of type {}", e.get_type()),
-                                                        })
+                                    let e = self.check_expression(e, module_id, types)
+                                        .and_then(|e| {
+                                            UExpression::try_from_typed(e, &IntBitwidth::B32)
+                                                .map_err(|e| ErrorInner {
+                                                    pos: Some(pos),
+                                                    message: format!("Expected u32 expression, but got expression of type {}", e.get_type()),
+                                                    context: vec![],
                                                 })
-                                        },
-                                        None => Err(ErrorInner {
-                                            pos: Some(pos),
-                                            message:
-                                                "Expected u32 constant or identifier, but found `_`. Generic inference is not supported yet."
-                                                    .into(),
-                                        })
-                                    })
-                                    .collect::<Result<_, _>>()?);
+                                        })?;
 
-                                // specialize the declared type using the generic assignment
-                                Ok(specialize_declaration_type(
-                                    DeclarationType::Struct(struct_type),
-                                    &assignment,
-                                )
-                                .unwrap())
+                                    assignment.constants.insert(declared_generic, e);
+                                }
+                                GenericParameter::Type(declared_generic) => {
+                                    let t = g.into_type().map_err(|e| ErrorInner {
+                                        pos: Some(e.pos()),
+                                        message: format!("Expected type, found expression {}", e),
+                                        context: vec![],
+                                    })?;
+
+                                    let t = self.check_type(t, module_id, types)?;
+
+                                    assignment.types.insert(declared_generic, t);
+                                }
                             }
-                            false => Err(ErrorInner {
-                                pos: Some(pos),
-                                message: format!(
-                                    "Expected {} generic argument{} on type {}, but got {}",
-                                    struct_type.generics.len(),
-                                    if struct_type.generics.len() == 1 {
-                                        ""
-                                    } else {
-                                        "s"
-                                    },
-                                    id,
-                                    generics.len()
-                                ),
-                            }),
                         }
+
+                        // specialize the declared type using the generic assignment
+                        Ok(specialize_declaration_type(declared_ty, &assignment).unwrap())
                     }
-                    _ => unreachable!("user defined types should always be structs"),
+                    false => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expected {} generic argument{} on type {}, but got {}",
+                            declared_generics.len(),
+                            if declared_generics.len() == 1 {
+                                ""
+                            } else {
+                                "s"
+                            },
+                            id,
+                            generics.len()
+                        ),
+                        context: vec![],
+                    }),
                 }
             }
+            UnresolvedType::Tuple(elements) => Ok(Type::Tuple(TupleType::new(
+                elements
+                    .into_iter()
+                    .map(|t| self.check_type(t, module_id, types))
+                    .collect::<Result<_, _>>()?,
+            ))),
+        }
+    }
+    /// Check a bound of a bit slice, which must be a `u32` literal as it determines the type of the slice
+    fn check_bit_bound(
+        &mut self,
+        bound: ExpressionNode<'ast>,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast>,
+    ) -> Result<u32, ErrorInner> {
+        let pos = bound.pos();
+
+        let bound = self.check_expression(bound, module_id, types)?;
+
+        match UExpression::try_from_typed(bound, &IntBitwidth::B32).map(|b| b.into_inner()) {
+            Ok(UExpressionInner::Value(v)) => Ok(v as u32),
+            Ok(b) => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Expected the bounds of a bit slice to be u32 literals, found {}",
+                    b.annotate(IntBitwidth::B32)
+                ),
+                context: vec![],
+            }),
+            Err(b) => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Expected the bounds of a bit slice to be u32 literals, found {} of type {}",
+                    b,
+                    b.get_type()
+                ),
+                context: vec![],
+            }),
         }
     }
+
     fn check_generic_expression(
         &mut self,
         expr: ExpressionNode<'ast>,
         module_id: &ModuleId,
         constants_map: &HashMap<ConstantIdentifier<'ast>, DeclarationType<'ast>>,
-        generics_map: &HashMap<Identifier<'ast>, usize>,
+        generics_map: &HashMap<Identifier<'ast>, GenericParameter<'ast>>,
         used_generics: &mut HashSet<Identifier<'ast>>,
     ) -> Result<DeclarationConstant<'ast>, ErrorInner> {
         let pos = expr.pos();
@@ -1292,29 +1988,40 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     "Expected array dimension to be a u32 constant or an identifier, found {}",
                     Expression::IntConstant(c)
                 ),
+                        context: vec![],
                     })
                 }
             }
             Expression::Identifier(name) => {
-                used_generics.insert(name);
+                match generics_map.contains_key(name) {
+                    true => used_generics.insert(name),
+                    false => self.used_symbols.insert(name.to_string()),
+                };
 
                 match (constants_map.get(name), generics_map.get(&name)) {
                     (Some(ty), None) => {
                         match ty {
-                            DeclarationType::Uint(UBitwidth::B32) => Ok(DeclarationConstant::Constant(CanonicalConstantIdentifier::new(name, module_id.into(), DeclarationType::Uint(UBitwidth::B32)))),
+                            DeclarationType::Uint(IntBitwidth::B32) => Ok(DeclarationConstant::Constant(CanonicalConstantIdentifier::new(name, module_id.into(), DeclarationType::Uint(IntBitwidth::B32)))),
                             _ => Err(ErrorInner {
                                 pos: Some(pos),
                                 message: format!(
                                     "Expected array dimension to be a u32 constant or an identifier, found {} of type {}",
                                     name, ty
                                 ),
+                                context: vec![],
                             })
                         }
                     }
-                    (None, Some(index)) => Ok(DeclarationConstant::Generic(GenericIdentifier { name, index: *index })),
+                    (None, Some(GenericParameter::Constant(g))) => Ok(DeclarationConstant::Generic(g.clone())),
+                    (None, Some(GenericParameter::Type(..))) => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!("Expected a constant, found type parameter {}", name),
+                        context: vec![],
+                    }),
                     _ => Err(ErrorInner {
                         pos: Some(pos),
-                        message: format!("Undeclared symbol `{}`", name)
+                        message: format!("Undeclared symbol `{}`", name),
+                        context: vec![],
                     })
                 }
             }
@@ -1324,6 +2031,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     "Expected array dimension to be a u32 constant or an identifier, found {}",
                     e
                 ),
+                context: vec![],
             }),
         }
     }
@@ -1333,7 +2041,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
         ty: UnresolvedTypeNode<'ast>,
         module_id: &ModuleId,
         state: &State<'ast, T>,
-        generics_map: &HashMap<Identifier<'ast>, usize>,
+        generics_map: &HashMap<Identifier<'ast>, GenericParameter<'ast>>,
         used_generics: &mut HashSet<Identifier<'ast>>,
     ) -> Result<DeclarationType<'ast>, ErrorInner> {
         let pos = ty.pos();
@@ -1343,6 +2051,9 @@ impl<'ast, T: Field> Checker<'ast, T> {
             UnresolvedType::FieldElement => Ok(DeclarationType::FieldElement),
             UnresolvedType::Boolean => Ok(DeclarationType::Boolean),
             UnresolvedType::Uint(bitwidth) => Ok(DeclarationType::uint(bitwidth)),
+            UnresolvedType::Int(bitwidth) => {
+                Ok(DeclarationType::uint(IntBitwidth::signed(bitwidth)))
+            }
             UnresolvedType::Array(t, size) => {
                 let checked_size = self.check_generic_expression(
                     size.clone(),
@@ -1358,7 +2069,32 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 )))
             }
             UnresolvedType::User(id, generics) => {
-                let declared_ty = state
+                // a type parameter of the declaration being checked
+                if generics.is_none() {
+                    if let Some((name, g)) = generics_map.get_key_value(id.as_str()) {
+                        used_generics.insert(*name);
+
+                        return match g {
+                            // in the body of a function which is generic over types, type parameters are opaque types
+                            GenericParameter::Type(g) => Ok(match self.type_arguments.get(name) {
+                                Some(ty) => ty.clone().into(),
+                                None => DeclarationType::Generic(g.clone()),
+                            }),
+                            GenericParameter::Constant(..) => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Expected a type, found constant parameter {}",
+                                    name
+                                ),
+                                context: vec![],
+                            }),
+                        };
+                    }
+                }
+
+                self.used_symbols.insert(id.clone());
+
+                let (declared_ty, declared_generics) = state
                     .types
                     .get(module_id)
                     .unwrap()
@@ -1367,83 +2103,102 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     .ok_or_else(|| ErrorInner {
                         pos: Some(pos),
                         message: format!("Undefined type {}", id),
+                        context: vec![],
                     })?;
 
-                match declared_ty {
-                    DeclarationType::Struct(declared_struct_ty) => {
-                        let generics = generics.unwrap_or_default();
-                        match declared_struct_ty.generics.len() == generics.len() {
-                            true => {
-                                let checked_generics: Vec<_> = generics
-                                    .into_iter()
-                                    .map(|e| match e {
-                                        Some(e) => self
-                                            .check_generic_expression(
-                                                e,
-                                                module_id,
-                                                state
-                                                    .constants
-                                                    .get(module_id)
-                                                    .unwrap_or(&HashMap::new()),
-                                                generics_map,
-                                                used_generics,
-                                            )
-                                            .map(Some),
-                                        None => Err(ErrorInner {
-                                            pos: Some(pos),
-                                            message:
-                                                "Expected u32 constant or identifier, but found `_`"
-                                                    .into(),
-                                        }),
-                                    })
-                                    .collect::<Result<_, _>>()?;
+                let generics = generics.unwrap_or_default();
+                match declared_generics.len() == generics.len() {
+                    true => {
+                        let mut assignment = GGenericsAssignment::default();
+
+                        for (declared_generic, g) in declared_generics.into_iter().zip(generics) {
+                            let g = g.ok_or_else(|| ErrorInner {
+                                pos: Some(pos),
+                                message: "Expected u32 constant or identifier, but found `_`"
+                                    .into(),
+                                context: vec![],
+                            })?;
 
-                                let mut assignment = GGenericsAssignment::default();
+                            match declared_generic {
+                                GenericParameter::Constant(declared_generic) => {
+                                    let e = match g {
+                                        GenericArgument::Expression(e) => Ok(e),
+                                        GenericArgument::Type(t) => Err(ErrorInner {
+                                            pos: Some(t.pos()),
+                                            message: format!(
+                                                "Expected u32 constant or identifier, found type {}",
+                                                t
+                                            ),
+                                            context: vec![],
+                                        }),
+                                    }?;
 
-                                assignment.0.extend(declared_struct_ty.generics.iter().zip(checked_generics.iter()).map(|(decl_g, g_val)| match decl_g.clone().unwrap() {
-                                    DeclarationConstant::Generic(g) => (g, g_val.clone().unwrap()),
-                                    _ => unreachable!("generic on declaration struct types must be generic identifiers")
-                                }));
+                                    let c = self.check_generic_expression(
+                                        e,
+                                        module_id,
+                                        state.constants.get(module_id).unwrap_or(&HashMap::new()),
+                                        generics_map,
+                                        used_generics,
+                                    )?;
 
-                                // generate actual type based on generic type and concrete generics
-                                let members = declared_struct_ty
-                                    .members
-                                    .into_iter()
-                                    .map(|m| {
-                                        Ok(DeclarationStructMember {
-                                            ty: box specialize_declaration_type(*m.ty, &assignment)
-                                                .unwrap(),
-                                            ..m
-                                        })
-                                    })
-                                    .collect::<Result<Vec<_>, _>>()?;
-
-                                Ok(DeclarationType::Struct(DeclarationStructType {
-                                    canonical_location: declared_struct_ty.canonical_location,
-                                    location: declared_struct_ty.location,
-                                    generics: checked_generics,
-                                    members,
-                                }))
+                                    assignment.constants.insert(declared_generic, c);
+                                }
+                                GenericParameter::Type(declared_generic) => {
+                                    let t = g.into_type().map_err(|e| ErrorInner {
+                                        pos: Some(e.pos()),
+                                        message: format!("Expected type, found expression {}", e),
+                                        context: vec![],
+                                    })?;
+
+                                    let t = self.check_declaration_type(
+                                        t,
+                                        module_id,
+                                        state,
+                                        generics_map,
+                                        used_generics,
+                                    )?;
+
+                                    assignment.types.insert(declared_generic, t);
+                                }
                             }
-                            false => Err(ErrorInner {
-                                pos: Some(pos),
-                                message: format!(
-                                    "Expected {} generic argument{} on type {}, but got {}",
-                                    declared_struct_ty.generics.len(),
-                                    if declared_struct_ty.generics.len() == 1 {
-                                        ""
-                                    } else {
-                                        "s"
-                                    },
-                                    id,
-                                    generics.len()
-                                ),
-                            }),
                         }
+
+                        // generate actual type based on generic type and concrete generics
+                        Ok(specialize_declaration_type(declared_ty, &assignment).unwrap())
                     }
-                    _ => Ok(declared_ty),
+                    false => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expected {} generic argument{} on type {}, but got {}",
+                            declared_generics.len(),
+                            if declared_generics.len() == 1 {
+                                ""
+                            } else {
+                                "s"
+                            },
+                            id,
+                            generics.len()
+                        ),
+                        context: vec![],
+                    }),
                 }
             }
+            UnresolvedType::Tuple(elements) => {
+                Ok(DeclarationType::Tuple(DeclarationTupleType::new(
+                    elements
+                        .into_iter()
+                        .map(|t| {
+                            self.check_declaration_type(
+                                t,
+                                module_id,
+                                state,
+                                generics_map,
+                                used_generics,
+                            )
+                        })
+                        .collect::<Result<_, _>>()?,
+                )))
+            }
         }
     }
 
@@ -1471,6 +2226,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
     ) -> Result<TypedStatement<'ast, T>, Vec<ErrorInner>> {
         self.check_for_var(&var).map_err(|e| vec![e])?;
 
+        let var_pos = var.pos();
         let var = self.check_variable(var, module_id, types).unwrap();
 
         let from = self
@@ -1482,22 +2238,24 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
         let from = match from {
             TypedExpression::Uint(from) => match from.bitwidth() {
-                UBitwidth::B32 => Ok(from),
+                IntBitwidth::B32 => Ok(from),
                 bitwidth => Err(ErrorInner {
                     pos: Some(pos),
                     message: format!(
                         "Expected lower loop bound to be of type u32, found {}",
                         Type::<T>::Uint(bitwidth)
                     ),
+                    context: vec![],
                 }),
             },
             TypedExpression::Int(v) => {
-                UExpression::try_from_int(v, &UBitwidth::B32).map_err(|_| ErrorInner {
+                UExpression::try_from_int(v, &IntBitwidth::B32).map_err(|_| ErrorInner {
                     pos: Some(pos),
                     message: format!(
                         "Expected lower loop bound to be of type u32, found {}",
                         Type::<T>::Int
                     ),
+                    context: vec![],
                 })
             }
             from => Err(ErrorInner {
@@ -1506,28 +2264,31 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     "Expected lower loop bound to be of type u32, found {}",
                     from.get_type()
                 ),
+                context: vec![],
             }),
         }
         .map_err(|e| vec![e])?;
 
         let to = match to {
             TypedExpression::Uint(to) => match to.bitwidth() {
-                UBitwidth::B32 => Ok(to),
+                IntBitwidth::B32 => Ok(to),
                 bitwidth => Err(ErrorInner {
                     pos: Some(pos),
                     message: format!(
                         "Expected upper loop bound to be of type u32, found {}",
                         Type::<T>::Uint(bitwidth)
                     ),
+                    context: vec![],
                 }),
             },
             TypedExpression::Int(v) => {
-                UExpression::try_from_int(v, &UBitwidth::B32).map_err(|_| ErrorInner {
+                UExpression::try_from_int(v, &IntBitwidth::B32).map_err(|_| ErrorInner {
                     pos: Some(pos),
                     message: format!(
                         "Expected upper loop bound to be of type u32, found {}",
                         Type::<T>::Int
                     ),
+                    context: vec![],
                 })
             }
             to => Err(ErrorInner {
@@ -1536,22 +2297,66 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     "Expected upper loop bound to be of type u32, found {}",
                     to.get_type()
                 ),
+                context: vec![],
             }),
         }
         .map_err(|e| vec![e])?;
 
-        self.insert_into_scope(var.clone());
+        let var = self.insert_loop_variable(var, var_pos);
+
+        // the body of the loop is executed several times, so the immutable variables declared outside of it cannot be
+        // defined in it
+        let uninitialized_variables = std::mem::take(&mut self.uninitialized_variables);
 
         let mut checked_statements = vec![];
 
-        for stat in statements {
-            let checked_stat = self.check_statement(stat, module_id, types)?;
-            checked_statements.push(checked_stat);
-        }
+        let checked = statements
+            .into_iter()
+            .try_for_each(|stat| -> Result<(), Vec<ErrorInner>> {
+                let location = SourceLocation::new(module_id.display().to_string(), stat.pos().0);
+                let checked_stat = self.check_statement(stat, module_id, types)?;
+                checked_statements.push(TypedStatement::Location(location));
+                checked_statements.push(checked_stat);
+                Ok(())
+            });
+
+        self.uninitialized_variables = uninitialized_variables;
+
+        checked?;
 
         Ok(TypedStatement::For(var, from, to, checked_statements))
     }
 
+    fn check_if_else_branch(
+        &mut self,
+        statements: Vec<StatementNode<'ast>>,
+        location: &SourceLocation,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast>,
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
+        let mut checked_statements = vec![];
+
+        for stat in statements {
+            if let Statement::Return(..) = stat.value {
+                return Err(vec![ErrorInner {
+                    pos: Some(stat.pos()),
+                    message: "Return statements are not allowed inside if/else blocks".into(),
+                    context: vec![],
+                }]);
+            }
+
+            let stat_location = SourceLocation::new(module_id.display().to_string(), stat.pos().0);
+            let checked_stat = self.check_statement(stat, module_id, types)?;
+            checked_statements.push(TypedStatement::Location(stat_location));
+            checked_statements.push(checked_stat);
+        }
+
+        // restore the location of the if/else statement, as merging the branches happens after them
+        checked_statements.push(TypedStatement::Location(location.clone()));
+
+        Ok(checked_statements)
+    }
+
     fn check_statement(
         &mut self,
         stat: StatementNode<'ast>,
@@ -1589,6 +2394,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     "Expected return value to be of type {}, found {}",
                                     e.1, e.0
                                 ),
+                                context: vec![],
                             }]
                         }) {
                         Ok(e) => {
@@ -1610,6 +2416,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                             .collect::<Vec<_>>()
                                             .join(", ")
                                     ),
+                                    context: vec![],
                                 }),
                             };
                             TypedStatement::Return(e)
@@ -1627,6 +2434,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                 return_types.len(),
                                 expression_list_checked.len()
                             ),
+                            context: vec![],
                         });
                         TypedStatement::Return(expression_list_checked)
                     }
@@ -1639,13 +2447,18 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 Ok(res)
             }
             Statement::Declaration(var) => {
+                let (id, is_mutable) = (var.value.id, var.value.is_mutable);
+                let var_pos = var.pos();
                 let var = self.check_variable(var, module_id, types)?;
-                match self.insert_into_scope(var.clone()) {
-                    true => Ok(TypedStatement::Declaration(var)),
-                    false => Err(ErrorInner {
-                        pos: Some(pos),
-                        message: format!("Duplicate declaration for variable named {}", var.id),
-                    }),
+                match self.insert_into_scope(var.clone(), is_mutable) {
+                    true => {
+                        self.declare_variable(&var, id, var_pos);
+                        if !is_mutable {
+                            self.uninitialized_variables.insert(id);
+                        }
+                        Ok(TypedStatement::Declaration(var))
+                    }
+                    false => Err(self.duplicate_declaration(id, pos)),
                 }
                 .map_err(|e| vec![e])
             }
@@ -1685,28 +2498,67 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         StructExpression::try_from_typed(checked_expr, struct_ty)
                             .map(TypedExpression::from)
                     }
+                    Type::Tuple(ref tuple_ty) => {
+                        TupleExpression::try_from_typed(checked_expr, tuple_ty)
+                            .map(TypedExpression::from)
+                    }
+                    Type::Enum(ref enum_ty) => {
+                        EnumExpression::try_from_typed(checked_expr, enum_ty)
+                            .map(TypedExpression::from)
+                    }
                     Type::Int => Err(checked_expr), // Integers cannot be assigned
+                    Type::Generic(..) => unreachable!(), // type parameters are replaced before checking statements
                 }
-                .map_err(|e| ErrorInner {
-                    pos: Some(pos),
-                    message: format!(
-                        "Expression `{}` of type `{}` cannot be assigned to `{}` of type `{}`",
-                        e,
-                        e.get_type(),
-                        var.clone(),
-                        var_type
-                    ),
+                .map_err(|e| {
+                    let error = ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expression `{}` of type `{}` cannot be assigned to `{}` of type `{}`",
+                            e,
+                            e.get_type(),
+                            var.clone(),
+                            var_type
+                        ),
+                        context: vec![],
+                    };
+
+                    // point at the declared type, unless it is part of this very statement
+                    let declaration = match &var {
+                        TypedAssignee::Identifier(v) => self
+                            .declared_variables
+                            .iter()
+                            .rev()
+                            .find(|d| d.id == v.id)
+                            .map(|d| d.pos)
+                            .filter(|d| d.0 < pos.0 || d.1 > pos.1),
+                        _ => None,
+                    };
+
+                    match declaration {
+                        Some(declaration) => error.with_label(
+                            declaration,
+                            format!("`{}` declared as `{}` here", var, var_type),
+                        ),
+                        None => error,
+                    }
                 })
                 .map(|rhs| TypedStatement::Definition(var, rhs))
                 .map_err(|e| vec![e])
             }
-            Statement::Assertion(e) => {
+            Statement::Assertion(e, message) => {
                 let e = self
                     .check_expression(e, module_id, types)
                     .map_err(|e| vec![e])?;
 
                 match e {
-                    TypedExpression::Boolean(e) => Ok(TypedStatement::Assertion(e)),
+                    TypedExpression::Boolean(e) => Ok(TypedStatement::Assertion(
+                        e,
+                        RuntimeError::SourceAssertion(AssertionMetadata {
+                            file: module_id.display().to_string(),
+                            position: pos.0,
+                            message,
+                        }),
+                    )),
                     e => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -1714,6 +2566,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e,
                             e.get_type(),
                         ),
+                        context: vec![],
                     }),
                 }
                 .map_err(|e| vec![e])
@@ -1727,33 +2580,70 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                 res
             }
+            Statement::IfElse(condition, consequence, alternative) => {
+                let condition_checked = self
+                    .check_expression(condition, module_id, types)
+                    .map_err(|e| vec![e])?;
+
+                let condition = match condition_checked {
+                    TypedExpression::Boolean(condition) => Ok(condition),
+                    c => Err(vec![ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "{{condition}} after `if` should be a boolean, found {}",
+                            c.get_type()
+                        ),
+                        context: vec![],
+                    }]),
+                }?;
+
+                let location = SourceLocation::new(module_id.display().to_string(), pos.0);
+
+                // each branch can define the immutable variables which are not defined yet. After the if/else statement,
+                // the variables defined by either branch are possibly defined, so only those which neither branch
+                // defines can still be defined
+                let uninitialized_variables = self.uninitialized_variables.clone();
+
+                self.enter_scope();
+                let consequence =
+                    self.check_if_else_branch(consequence, &location, module_id, types);
+                self.exit_scope();
+
+                let uninitialized_after_consequence = std::mem::replace(
+                    &mut self.uninitialized_variables,
+                    uninitialized_variables.clone(),
+                );
+
+                self.enter_scope();
+                let alternative =
+                    self.check_if_else_branch(alternative, &location, module_id, types);
+                self.exit_scope();
+
+                self.uninitialized_variables = uninitialized_variables
+                    .into_iter()
+                    .filter(|name| {
+                        uninitialized_after_consequence.contains(name)
+                            && self.uninitialized_variables.contains(name)
+                    })
+                    .collect();
+
+                Ok(TypedStatement::IfElse(
+                    condition,
+                    consequence?,
+                    alternative?,
+                ))
+            }
             Statement::MultipleDefinition(assignees, rhs) => {
+                let rhs_pos = rhs.pos();
+
                 match rhs.value {
                     // Right side has to be a function call
-                    Expression::FunctionCall(fun_id, generics, arguments) => {
-                        // check the generic arguments, if any
-                        let generics_checked: Option<Vec<Option<UExpression<'ast, T>>>> = generics
-                            .map(|generics|
-                                generics.into_iter().map(|g|
-                                    g.map(|g| {
-                                        let pos = g.pos();
-                                        self.check_expression(g, module_id, types).and_then(|g| {
-                                            UExpression::try_from_typed(g, &UBitwidth::B32).map_err(
-                                                |e| ErrorInner {
-                                                    pos: Some(pos),
-                                                    message: format!(
-                                                        "Expected {} to be of type u32, found {}",
-                                                        e,
-                                                        e.get_type(),
-                                                    ),
-                                                },
-                                            )
-                                        })
-                                    })
-                                    .transpose()
-                                )
-                            .collect::<Result<_, _>>()
-                        ).transpose().map_err(|e| vec![e])?;
+                    Expression::FunctionCall(box fun_id, generics, arguments) => {
+                        let fun_id = Self::check_callee(fun_id).map_err(|e| vec![e])?;
+                        self.used_symbols.insert(fun_id.to_string());
+
+                        // the generic arguments are checked once the function is found, as they depend on its parameters
+                        let generics_count = generics.as_ref().map(|g| g.len());
 
                         // check lhs assignees are defined
                         let (assignees, errors): (Vec<_>, Vec<_>) = assignees.into_iter().map(|a| self.check_assignee(a, module_id, types)).partition(|r| r.is_ok());
@@ -1776,10 +2666,21 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         let arguments_types: Vec<_> =
                             arguments_checked.iter().map(|a| a.get_type()).collect();
 
-                        let query = FunctionQuery::new(&fun_id, &generics_checked, &arguments_types, &assignee_types);
+                        let query = FunctionQuery::new(&fun_id, generics_count, &arguments_types, &assignee_types);
 
                         let functions = self.find_functions(&query);
 
+                        // if no function returns the assigned values, look for a function returning a tuple of them
+                        let tuple_query;
+                        let (functions, destructure, found_query) = match (functions.len(), assignees.len()) {
+                            (0, n) if n > 1 => {
+                                let tuple_type = Some(Type::Tuple(TupleType::new(assignees.iter().map(|a| a.get_type()).collect())));
+                                tuple_query = FunctionQuery::new(&fun_id, generics_count, &arguments_types, &[tuple_type]);
+                                (self.find_functions(&tuple_query), true, &tuple_query)
+                            }
+                            _ => (functions, false, &query)
+                        };
+
                         match functions.len() {
                     		// the function has to be defined
                     		1 => {
@@ -1787,27 +2688,63 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                 let mut functions = functions;
                                 let f = functions.pop().unwrap();
 
-                                let arguments_checked = arguments_checked.into_iter().zip(f.signature.inputs.iter()).map(|(a, t)| TypedExpression::align_to_type(a, t)).collect::<Result<Vec<_>, _>>().map_err(|e| vec![ErrorInner {
+                                let (signature, generics_checked) = self.check_call_generics(&f, generics, found_query, module_id, types, pos).map_err(|e| vec![e])?;
+
+                                let arguments_checked = arguments_checked.into_iter().zip(signature.inputs.iter()).map(|(a, t)| TypedExpression::align_to_type(a, t)).collect::<Result<Vec<_>, _>>().map_err(|e| vec![ErrorInner {
                                     pos: Some(pos),
-                                    message: format!("Expected function call argument to be of type {}, found {} of type {}", e.1, e.0, e.0.get_type())
+                                    message: format!("Expected function call argument to be of type {}, found {} of type {}", e.1, e.0, e.0.get_type()),
+                                    context: vec![],
                                 }])?;
 
-                                let call = TypedExpressionList::function_call(f.clone(), generics_checked.unwrap_or_else(|| vec![None; f.signature.generics.len()]), arguments_checked).annotate(Types { inner: assignees.iter().map(|a| a.get_type()).collect()});
+                                let assignee_types = Types { inner: assignees.iter().map(|a| a.get_type()).collect()};
+
+                                let call = match destructure {
+                                    false => TypedExpressionList::function_call(f.clone(), generics_checked, arguments_checked).annotate(assignee_types),
+                                    true => TypedExpressionListInner::Tuple(TupleExpression::function_call(f.clone(), generics_checked, arguments_checked).annotate(TupleType::new(assignee_types.inner.clone()))).annotate(assignee_types),
+                                };
 
                                 Ok(TypedStatement::MultipleDefinition(assignees, call))
                     		},
                     		0 => Err(ErrorInner {                         pos: Some(pos),
- message: format!("Function definition for function {} with signature {} not found.", fun_id, query) }),
+ message: format!("Function definition for function {} with signature {} not found.", fun_id, query),
+ context: vec![] }),
                             n => Err(ErrorInner {
                         pos: Some(pos),
-                        message: format!("Ambiguous call to function {}, {} candidates were found. Please be more explicit.", fun_id, n)
+                        message: format!("Ambiguous call to function {}, {} candidates were found. Please be more explicit.", fun_id, n),
+                        context: vec![],
                     })
                     	}
                     }
-                    _ => Err(ErrorInner {
-                        pos: Some(pos),
-                        message: format!("{} should be a function call", rhs),
-                    }),
+                    // Otherwise, the right side has to be a tuple with as many elements as there are assignees
+                    rhs => {
+                        let rhs = rhs.start_end(rhs_pos.0, rhs_pos.1);
+
+                        // check lhs assignees are defined
+                        let (assignees, errors): (Vec<_>, Vec<_>) = assignees.into_iter().map(|a| self.check_assignee(a, module_id, types)).partition(|r| r.is_ok());
+
+                        if !errors.is_empty() {
+                            return Err(errors.into_iter().map(|e| e.unwrap_err()).collect());
+                        }
+
+                        let assignees: Vec<_> = assignees.into_iter().map(|a| a.unwrap()).collect();
+
+                        let assignee_types = Types { inner: assignees.iter().map(|a| a.get_type()).collect() };
+
+                        let checked_rhs = self.check_expression(rhs, module_id, types).map_err(|e| vec![e])?;
+
+                        TupleExpression::try_from_typed(checked_rhs, &TupleType::new(assignee_types.inner.clone()))
+                            .map(|tuple| TypedStatement::MultipleDefinition(assignees, TypedExpressionListInner::Tuple(tuple).annotate(assignee_types.clone())))
+                            .map_err(|e| ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Expression `{}` of type `{}` cannot be destructured into ({})",
+                                    e,
+                                    e.get_type(),
+                                    assignee_types.inner.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", ")
+                                ),
+                                context: vec![],
+                            })
+                    }
                 }.map_err(|e| vec![e])
             }
         }
@@ -1823,19 +2760,35 @@ impl<'ast, T: Field> Checker<'ast, T> {
         // check that the assignee is declared
         match assignee.value {
             Assignee::Identifier(variable_name) => match self.get_scope(&variable_name) {
-                Some(var) => match var.is_constant() {
-                    true => Err(ErrorInner {
+                Some(var) if var.is_constant() => Err(ErrorInner {
+                    pos: Some(assignee.pos()),
+                    message: format!("Assignment to constant variable `{}`", variable_name),
+                    context: vec![],
+                }),
+                // an immutable variable can only be assigned to by its definition
+                Some(var)
+                    if self.immutable_by_default
+                        && !var.is_mutable
+                        && !self.uninitialized_variables.contains(variable_name) =>
+                {
+                    Err(ErrorInner {
                         pos: Some(assignee.pos()),
-                        message: format!("Assignment to constant variable `{}`", variable_name),
-                    }),
-                    false => Ok(TypedAssignee::Identifier(Variable::with_id_and_type(
-                        variable_name,
-                        var.id._type.clone(),
-                    ))),
-                },
+                        message: format!(
+                            "Assignment to immutable variable `{}`, declare it as `mut` to allow it",
+                            variable_name
+                        ),
+                        context: vec![],
+                    })
+                }
+                Some(var) => {
+                    let var = var.id.clone();
+                    self.uninitialized_variables.remove(variable_name);
+                    Ok(TypedAssignee::Identifier(var))
+                }
                 None => Err(ErrorInner {
                     pos: Some(assignee.pos()),
                     message: format!("Variable `{}` is undeclared", variable_name),
+                    context: vec![],
                 }),
             },
             Assignee::Select(box assignee, box index) => {
@@ -1855,7 +2808,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         };
 
                         let checked_typed_index =
-                            UExpression::try_from_typed(checked_index, &UBitwidth::B32).map_err(
+                            UExpression::try_from_typed(checked_index, &IntBitwidth::B32).map_err(
                                 |e| ErrorInner {
                                     pos: Some(pos),
                                     message: format!(
@@ -1863,6 +2816,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                         checked_assignee,
                                         e.get_type()
                                     ),
+                                    context: vec![],
                                 },
                             )?;
 
@@ -1877,6 +2831,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             "Cannot access element at index {} on {} of type {}",
                             index, checked_assignee, ty,
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -1899,6 +2854,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     .join(", "),
                                 member
                             ),
+                            context: vec![],
                         }),
                     },
                     ty => Err(ErrorInner {
@@ -1908,6 +2864,44 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             "Cannot access field {} on {} as of type {}",
                             member, checked_assignee, ty,
                         ),
+                        context: vec![],
+                    }),
+                }
+            }
+            Assignee::Element(box assignee, index) => {
+                let checked_assignee = self.check_assignee(assignee, module_id, types)?;
+
+                let ty = checked_assignee.get_type();
+                match &ty {
+                    Type::Tuple(tuple_ty) => {
+                        // indices which do not fit in a u32 are out of bounds as well
+                        match index
+                            .to_u32()
+                            .filter(|i| (*i as usize) < tuple_ty.elements.len())
+                        {
+                            Some(index) => Ok(TypedAssignee::Element(box checked_assignee, index)),
+                            None => {
+                                Err(ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                "Tuple of type {} has {} element{}, cannot access element {}",
+                                ty,
+                                tuple_ty.elements.len(),
+                                if tuple_ty.elements.len() == 1 { "" } else { "s" },
+                                index
+                            ),
+                                    context: vec![],
+                                })
+                            }
+                        }
+                    }
+                    ty => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot access tuple element {} on {} of type {}",
+                            index, checked_assignee, ty,
+                        ),
+                        context: vec![],
                     }),
                 }
             }
@@ -1935,6 +2929,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             "Expected spread operator to apply on array, found {}",
                             e.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -1944,6 +2939,40 @@ impl<'ast, T: Field> Checker<'ast, T> {
         }
     }
 
+    /// Check a signed integer literal, negated or not. Its magnitude is read into the unsigned type of the same
+    /// width, so that the minimum of the signed type can be written, for example `-128i8`
+    fn check_signed_constant(
+        magnitude: BigUint,
+        bitwidth: IntBitwidth,
+        negated: bool,
+        pos: (Position, Position),
+    ) -> Result<TypedExpression<'ast, T>, ErrorInner> {
+        let min = 1u128 << (bitwidth.to_usize() - 1);
+        let max = if negated { min } else { min - 1 };
+
+        match magnitude <= BigUint::from(max) {
+            true => {
+                let m = u128::from_str_radix(&magnitude.to_str_radix(16), 16).unwrap();
+                let value = if negated { m.wrapping_neg() } else { m };
+                Ok(UExpressionInner::Value(value & bitwidth.max_value())
+                    .annotate(bitwidth)
+                    .into())
+            }
+            false => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Literal {}{} is out of the range [-{}, {}] of type {}",
+                    if negated { "-" } else { "" },
+                    magnitude,
+                    min,
+                    min - 1,
+                    bitwidth.type_name()
+                ),
+                context: vec![],
+            }),
+        }
+    }
+
     fn check_expression(
         &mut self,
         expr: ExpressionNode<'ast>,
@@ -1956,29 +2985,53 @@ impl<'ast, T: Field> Checker<'ast, T> {
             Expression::IntConstant(v) => Ok(IntExpression::Value(v).into()),
             Expression::BooleanConstant(b) => Ok(BooleanExpression::Value(b).into()),
             Expression::Identifier(name) => {
+                // only constants are symbols of the module, other variables may share their name with a function
+                match self
+                    .get_scope(&name)
+                    .map(|v| (v.id.id.clone(), v.is_constant()))
+                {
+                    Some((_, true)) => {
+                        self.used_symbols.insert(name.to_string());
+                    }
+                    Some((id, false)) => self.read_variable(&id),
+                    None => {}
+                }
+
                 // check that `id` is defined in the scope
                 match self.get_scope(&name) {
+                    // a loop variable shadowing another one has a distinct identifier
                     Some(v) => match v.id.get_type() {
-                        Type::Boolean => Ok(BooleanExpression::Identifier(name.into()).into()),
-                        Type::Uint(bitwidth) => Ok(UExpressionInner::Identifier(name.into())
+                        Type::Boolean => Ok(BooleanExpression::Identifier(v.id.id.clone()).into()),
+                        Type::Uint(bitwidth) => Ok(UExpressionInner::Identifier(v.id.id.clone())
                             .annotate(bitwidth)
                             .into()),
                         Type::FieldElement => {
-                            Ok(FieldElementExpression::Identifier(name.into()).into())
+                            Ok(FieldElementExpression::Identifier(v.id.id.clone()).into())
                         }
                         Type::Array(array_type) => {
-                            Ok(ArrayExpressionInner::Identifier(name.into())
+                            Ok(ArrayExpressionInner::Identifier(v.id.id.clone())
                                 .annotate(*array_type.ty, array_type.size)
                                 .into())
                         }
-                        Type::Struct(members) => Ok(StructExpressionInner::Identifier(name.into())
-                            .annotate(members)
+                        Type::Struct(members) => {
+                            Ok(StructExpressionInner::Identifier(v.id.id.clone())
+                                .annotate(members)
+                                .into())
+                        }
+                        Type::Tuple(tuple_ty) => {
+                            Ok(TupleExpressionInner::Identifier(v.id.id.clone())
+                                .annotate(tuple_ty)
+                                .into())
+                        }
+                        Type::Enum(enum_ty) => Ok(EnumExpressionInner::Identifier(v.id.id.clone())
+                            .annotate(enum_ty)
                             .into()),
-                        Type::Int => unreachable!(),
+                        Type::Int | Type::Generic(..) => unreachable!(),
                     },
                     None => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!("Identifier \"{}\" is undefined", name),
+                        context: vec![],
                     }),
                 }
             }
@@ -1994,6 +3047,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 .map_err(|(e1, e2)| ErrorInner {
                     pos: Some(pos),
                     message: format!("Cannot apply `+` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2014,6 +3068,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             t1.get_type(),
                             t2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2029,6 +3084,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 .map_err(|(e1, e2)| ErrorInner {
                     pos: Some(pos),
                     message: format!("Cannot apply `-` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2045,6 +3101,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             t1.get_type(),
                             t2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2060,6 +3117,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 .map_err(|(e1, e2)| ErrorInner {
                     pos: Some(pos),
                     message: format!("Cannot apply `*` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2080,6 +3138,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             t1.get_type(),
                             t2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2095,6 +3154,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 .map_err(|(e1, e2)| ErrorInner {
                     pos: Some(pos),
                     message: format!("Cannot apply `/` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2115,6 +3175,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             t1.get_type(),
                             t2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2128,6 +3189,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 .map_err(|(e1, e2)| ErrorInner {
                     pos: Some(pos),
                     message: format!("Cannot apply `%` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2144,6 +3206,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             t1.get_type(),
                             t2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2155,15 +3218,19 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     Ok(e) => e.into(),
                     Err(e) => e,
                 };
-                let e2_checked = match UExpression::try_from_typed(e2_checked, &UBitwidth::B32) {
+                let e2_checked = match UExpression::try_from_typed(e2_checked, &IntBitwidth::B32) {
                     Ok(e) => e.into(),
                     Err(e) => e,
                 };
 
                 match (e1_checked, e2_checked) {
-                    (TypedExpression::FieldElement(e1), TypedExpression::Uint(e2)) => Ok(
-                        TypedExpression::FieldElement(FieldElementExpression::Pow(box e1, box e2)),
-                    ),
+                    (TypedExpression::FieldElement(e1), TypedExpression::Uint(e2))
+                        if !e2.bitwidth().is_signed() =>
+                    {
+                        Ok(TypedExpression::FieldElement(FieldElementExpression::Pow(
+                            box e1, box e2,
+                        )))
+                    }
                     (t1, t2) => Err(ErrorInner {
                         pos: Some(pos),
 
@@ -2172,9 +3239,27 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             t1.get_type(),
                             t2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
+            // negation is applied to signed literals directly, as the absolute value of the minimum is out of range
+            Expression::Neg(box ExpressionNode {
+                value: Expression::I8Constant(n),
+                ..
+            }) => Self::check_signed_constant(n, IntBitwidth::I8, true, pos),
+            Expression::Neg(box ExpressionNode {
+                value: Expression::I16Constant(n),
+                ..
+            }) => Self::check_signed_constant(n, IntBitwidth::I16, true, pos),
+            Expression::Neg(box ExpressionNode {
+                value: Expression::I32Constant(n),
+                ..
+            }) => Self::check_signed_constant(n, IntBitwidth::I32, true, pos),
+            Expression::Neg(box ExpressionNode {
+                value: Expression::I64Constant(n),
+                ..
+            }) => Self::check_signed_constant(n, IntBitwidth::I64, true, pos),
             Expression::Neg(box e) => {
                 let e = self.check_expression(e, module_id, types)?;
 
@@ -2191,9 +3276,57 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e,
                             e.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
+            Expression::Cast(box e, box ty) => {
+                let ty = self.check_type(ty, module_id, types)?;
+                let e = self.check_expression(e, module_id, types)?;
+
+                let cast_error = |e: TypedExpression<'ast, T>, ty: &Type<'ast, T>| ErrorInner {
+                    pos: Some(pos),
+                    message: format!("Cannot cast {} of type {} to {}", e, e.get_type(), ty),
+                    context: vec![],
+                };
+
+                // integer literals are simply given the target type
+                if let TypedExpression::Int(..) = e {
+                    return TypedExpression::align_to_type(e, &ty)
+                        .map_err(|(e, ty)| cast_error(e, ty));
+                }
+
+                if e.get_type() == ty {
+                    return Ok(e);
+                }
+
+                let (from, to) = match (CastType::try_from(&e.get_type()), CastType::try_from(&ty))
+                {
+                    (Ok(from), Ok(to)) => (from, to),
+                    _ => return Err(cast_error(e, &ty)),
+                };
+
+                self.casts
+                    .entry(module_id.to_path_buf())
+                    .or_default()
+                    .insert((from, to));
+
+                let key = casts::key(module_id, from, to);
+
+                match to {
+                    CastType::FieldElement => {
+                        Ok(FieldElementExpression::function_call(key, vec![], vec![e]).into())
+                    }
+                    CastType::Uint(bitwidth) => {
+                        Ok(UExpression::function_call(key, vec![], vec![e])
+                            .annotate(bitwidth)
+                            .into())
+                    }
+                    CastType::Boolean => {
+                        Ok(BooleanExpression::function_call(key, vec![], vec![e]).into())
+                    }
+                }
+            }
             Expression::Pos(box e) => {
                 let e = self.check_expression(e, module_id, types)?;
 
@@ -2210,6 +3343,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e,
                             e.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2226,6 +3360,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     .map_err(|(e1, e2)| ErrorInner {
                         pos: Some(pos),
                         message: format!("{{consequence}} and {{alternative}} in `if/else` expression should have the same type, found {}, {}", e1.get_type(), e2.get_type()),
+                        context: vec![],
                     })?;
 
                 match condition_checked {
@@ -2243,6 +3378,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             (TypedExpression::Struct(consequence), TypedExpression::Struct(alternative)) => {
                                 Ok(StructExpression::if_else(condition, consequence, alternative).into())
                             },
+                            (TypedExpression::Tuple(consequence), TypedExpression::Tuple(alternative)) => {
+                                Ok(TupleExpression::if_else(condition, consequence, alternative).into())
+                            },
+                            (TypedExpression::Enum(consequence), TypedExpression::Enum(alternative)) => {
+                                Ok(EnumExpression::if_else(condition, consequence, alternative).into())
+                            },
                             (TypedExpression::Uint(consequence), TypedExpression::Uint(alternative)) => {
                                 Ok(UExpression::if_else(condition, consequence, alternative).into())
                             },
@@ -2251,7 +3392,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             },
                             (c, a) => Err(ErrorInner {
                                 pos: Some(pos),
-                                message: format!("{{consequence}} and {{alternative}} in `if/else` expression should have the same type, found {}, {}", c.get_type(), a.get_type())
+                                message: format!("{{consequence}} and {{alternative}} in `if/else` expression should have the same type, found {}, {}", c.get_type(), a.get_type()),
+                                context: vec![],
                             })
                         }
                     }
@@ -2261,6 +3403,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             "{{condition}} after `if` should be a boolean, found {}",
                             c.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2272,6 +3415,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         T::min_value(),
                         T::max_value()
                     ),
+                    context: vec![],
                 })?,
             )
             .into()),
@@ -2279,33 +3423,58 @@ impl<'ast, T: Field> Checker<'ast, T> {
             Expression::U16Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(16).into()),
             Expression::U32Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(32).into()),
             Expression::U64Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(64).into()),
-            Expression::FunctionCall(fun_id, generics, arguments) => {
-                // check the generic arguments, if any
-                let generics_checked: Option<Vec<Option<UExpression<'ast, T>>>> = generics
-                    .map(|generics| {
-                        generics
-                            .into_iter()
-                            .map(|g| {
-                                g.map(|g| {
-                                    let pos = g.pos();
-                                    self.check_expression(g, module_id, types).and_then(|g| {
-                                        UExpression::try_from_typed(g, &UBitwidth::B32).map_err(
-                                            |e| ErrorInner {
-                                                pos: Some(pos),
-                                                message: format!(
-                                                    "Expected {} to be of type u32, found {}",
-                                                    e,
-                                                    e.get_type(),
-                                                ),
-                                            },
-                                        )
-                                    })
-                                })
-                                .transpose()
-                            })
-                            .collect::<Result<_, _>>()
-                    })
-                    .transpose()?;
+            Expression::U128Constant(n) => Ok(UExpressionInner::Value(n).annotate(128).into()),
+            Expression::OutOfRangeUintConstant(literal, bitwidth) => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!("Literal {} does not fit in u{}", literal, bitwidth),
+                context: vec![],
+            }),
+            Expression::I8Constant(n) => {
+                Self::check_signed_constant(n, IntBitwidth::I8, false, pos)
+            }
+            Expression::I16Constant(n) => {
+                Self::check_signed_constant(n, IntBitwidth::I16, false, pos)
+            }
+            Expression::I32Constant(n) => {
+                Self::check_signed_constant(n, IntBitwidth::I32, false, pos)
+            }
+            Expression::I64Constant(n) => {
+                Self::check_signed_constant(n, IntBitwidth::I64, false, pos)
+            }
+            Expression::ByteStringConstant(bytes) => {
+                if bytes.is_empty() {
+                    return Err(ErrorInner {
+                        pos: Some(pos),
+                        message: "Empty strings are not allowed".to_string(),
+                        context: vec![],
+                    });
+                }
+
+                let size = bytes.len() as u32;
+
+                // a string is an array of its bytes
+                Ok(ArrayExpressionInner::Value(
+                    bytes
+                        .into_iter()
+                        .map(|b| {
+                            TypedExpressionOrSpread::Expression(
+                                UExpressionInner::Value(b.into())
+                                    .annotate(IntBitwidth::B8)
+                                    .into(),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .into(),
+                )
+                .annotate(Type::Uint(IntBitwidth::B8), size)
+                .into())
+            }
+            Expression::FunctionCall(box fun_id, generics, arguments) => {
+                let fun_id = Self::check_callee(fun_id)?;
+                self.used_symbols.insert(fun_id.to_string());
+
+                // the generic arguments are checked once the function is found, as they depend on its parameters
+                let generics_count = generics.as_ref().map(|g| g.len());
 
                 // check the arguments
                 let mut arguments_checked = vec![];
@@ -2321,8 +3490,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                 // outside of multidef, function calls must have a single return value
                 // we use type inference to determine the type of the return, so we don't specify it
-                let query =
-                    FunctionQuery::new(&fun_id, &generics_checked, &arguments_types, &[None]);
+                let query = FunctionQuery::new(&fun_id, generics_count, &arguments_types, &[None]);
 
                 let functions = self.find_functions(&query);
 
@@ -2333,83 +3501,82 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                         let f = functions.pop().unwrap();
 
-                        let signature = f.signature;
+                        self.check_function_call(f, generics, arguments_checked, module_id, types, pos)
+                    }
+                    0 => Err(ErrorInner {
+                        pos: Some(pos),
 
-                        let arguments_checked = arguments_checked.into_iter().zip(signature.inputs.iter()).map(|(a, t)| TypedExpression::align_to_type(a, &t)).collect::<Result<Vec<_>, _>>().map_err(|e| ErrorInner {
-                           pos: Some(pos),
-                           message: format!("Expected function call argument to be of type {}, found {}", e.1, e.0)
-                        })?;
+                        message: format!(
+                            "Function definition for function {} with signature {} not found.",
+                            fun_id, query
+                        ),
+                        context: vec![],
+                    }),
+                    n => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!("Ambiguous call to function {}, {} candidates were found. Please be more explicit.", fun_id, n),
+                        context: vec![],
+                    }),
+                }
+            }
+            Expression::MethodCall(box receiver, method_id, generics, arguments) => {
+                let generics_count = generics.as_ref().map(|g| g.len());
 
-                        let generics_checked = generics_checked.unwrap_or_else(|| vec![None; signature.generics.len()]);
+                let receiver = self.check_expression(receiver, module_id, types)?;
 
-                        let mut output_types = signature.get_output_types(
-                            generics_checked.clone(),
-                            arguments_checked.iter().map(|a| a.get_type()).collect()
-                        ).map_err(|e| ErrorInner {
+                let location = match receiver.get_type() {
+                    Type::Struct(struct_type) => struct_type.canonical_location,
+                    t => {
+                        return Err(ErrorInner {
                             pos: Some(pos),
                             message: format!(
-                                "Failed to infer value for generic parameter `{}`, try providing an explicit value",
-                                e,
+                                "Cannot call method {} on {} of type {}, methods are only implemented on structs",
+                                method_id, receiver, t
                             ),
-                        })?;
+                            context: vec![],
+                        })
+                    }
+                };
 
-                        let function_key = DeclarationFunctionKey {
-                            module: module_id.to_path_buf(),
-                            id: f.id,
-                            signature: signature.clone(),
-                        };
+                // the receiver is passed as the first argument
+                let mut arguments_checked = vec![receiver];
+                for arg in arguments {
+                    let arg_checked = self.check_expression(arg, module_id, types)?;
+                    arguments_checked.push(arg_checked);
+                }
 
-                        // the return count has to be 1
-                        match output_types.len() {
-                            1 => match output_types.pop().unwrap() {
-                                Type::Int => unreachable!(),
-                                Type::FieldElement => Ok(FieldElementExpression::function_call(
-                                    function_key,
-                                    generics_checked,
-                                    arguments_checked,
-                                ).into()),
-                                Type::Boolean => Ok(BooleanExpression::function_call(
-                                    function_key,
-                                    generics_checked,
-                                    arguments_checked,
-                                ).into()),
-                                Type::Uint(bitwidth) => Ok(UExpression::function_call(
-                                    function_key,
-                                    generics_checked,
-                                    arguments_checked,
-                                ).annotate(bitwidth).into()),
-                                Type::Struct(struct_ty) => Ok(StructExpression::function_call(
-                                    function_key,
-                                    generics_checked,
-                                    arguments_checked,
-                                ).annotate(struct_ty).into()),
-                                Type::Array(array_ty) => Ok(ArrayExpression::function_call(
-                                    function_key,
-                                    generics_checked,
-                                    arguments_checked,
-                                ).annotate(*array_ty.ty, array_ty.size).into()),
-                            },
-                            n => Err(ErrorInner {
-                                pos: Some(pos),
+                let arguments_types: Vec<_> =
+                    arguments_checked.iter().map(|a| a.get_type()).collect();
 
-                                message: format!(
-                                    "{} returns {} values but is called outside of a definition",
-                                    f.id, n
-                                ),
-                            }),
-                        }
+                let query =
+                    FunctionQuery::new(method_id, generics_count, &arguments_types, &[None]);
+
+                let methods = self
+                    .methods
+                    .get(&location)
+                    .map(|methods| query.match_funcs(methods))
+                    .unwrap_or_default();
+
+                match methods.len() {
+                    1 => {
+                        let mut methods = methods;
+
+                        let f = methods.pop().unwrap();
+
+                        self.check_function_call(f, generics, arguments_checked, module_id, types, pos)
                     }
                     0 => Err(ErrorInner {
                         pos: Some(pos),
-
                         message: format!(
-                            "Function definition for function {} with signature {} not found.",
-                            fun_id, query
+                            "Method {} with signature {} not found on {}",
+                            method_id, query, location.name
                         ),
+                        context: vec![],
                     }),
                     n => Err(ErrorInner {
                         pos: Some(pos),
-                        message: format!("Ambiguous call to function {}, {} candidates were found. Please be more explicit.", fun_id, n)
+                        message: format!("Ambiguous call to method {}, {} candidates were found. Please be more explicit.", method_id, n),
+                        context: vec![],
                     }),
                 }
             }
@@ -2429,6 +3596,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         e2,
                         e2.get_type()
                     ),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2448,6 +3616,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     e2,
                                     e2.get_type()
                                 ),
+                                context: vec![],
                             })
                         }
                     }
@@ -2460,6 +3629,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e2,
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2479,6 +3649,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         e2,
                         e2.get_type()
                     ),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2498,6 +3669,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     e2,
                                     e2.get_type()
                                 ),
+                                context: vec![],
                             })
                         }
                     }
@@ -2510,6 +3682,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e2,
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2529,6 +3702,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         e2,
                         e2.get_type()
                     ),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2541,9 +3715,15 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     (TypedExpression::Array(e1), TypedExpression::Array(e2)) => {
                         Ok(BooleanExpression::ArrayEq(box e1, box e2).into())
                     }
-                    (TypedExpression::Struct(e1), TypedExpression::Struct(e2)) => {
+                    // values of a type parameter cannot be compared, as their type is unknown
+                    (TypedExpression::Struct(e1), TypedExpression::Struct(e2))
+                        if !self.is_type_parameter(e1.ty()) =>
+                    {
                         Ok(BooleanExpression::StructEq(box e1, box e2).into())
                     }
+                    (TypedExpression::Tuple(e1), TypedExpression::Tuple(e2)) => {
+                        Ok(BooleanExpression::TupleEq(box e1, box e2).into())
+                    }
                     (TypedExpression::Uint(e1), TypedExpression::Uint(e2))
                         if e1.get_type() == e2.get_type() =>
                     {
@@ -2558,6 +3738,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e2,
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2577,6 +3758,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         e2,
                         e2.get_type()
                     ),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2596,6 +3778,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     e2,
                                     e2.get_type()
                                 ),
+                                context: vec![],
                             })
                         }
                     }
@@ -2608,6 +3791,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e2,
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2627,6 +3811,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         e2,
                         e2.get_type()
                     ),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -2646,6 +3831,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     e2,
                                     e2.get_type()
                                 ),
+                                context: vec![],
                             })
                         }
                     }
@@ -2658,6 +3844,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e2,
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -2685,22 +3872,24 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     .map(|e| self.check_expression(e, module_id, types))
                                     .unwrap_or_else(|| Ok(array_size.clone().into()))?;
 
-                                let from = UExpression::try_from_typed(from, &UBitwidth::B32).map_err(|e| ErrorInner {
+                                let from = UExpression::try_from_typed(from, &IntBitwidth::B32).map_err(|e| ErrorInner {
                                                     pos: Some(pos),
                                                     message: format!(
                                                         "Expected the lower bound of the range to be a u32, found {} of type {}",
                                                         e,
                                                         e.get_type()
                                                     ),
+                                                    context: vec![],
                                                 })?;
 
-                                let to = UExpression::try_from_typed(to, &UBitwidth::B32).map_err(|e| ErrorInner {
+                                let to = UExpression::try_from_typed(to, &IntBitwidth::B32).map_err(|e| ErrorInner {
                                                     pos: Some(pos),
                                                     message: format!(
                                                         "Expected the upper bound of the range to be a u32, found {} of type {}",
                                                         e,
                                                         e.get_type()
                                                     ),
+                                                    context: vec![],
                                                 })?;
 
                                 Ok(ArrayExpressionInner::Slice(
@@ -2711,27 +3900,64 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                 .annotate(inner_type, UExpression::floor_sub(to, from))
                                 .into())
                             }
-                            e => Err(ErrorInner {
-                                pos: Some(pos),
-                                message: format!(
-                                    "Cannot access slice of expression {} of type {}",
-                                    e,
-                                    e.get_type(),
-                                ),
-                            }),
+                            TypedExpression::Uint(e) if !e.bitwidth().is_signed() => {
+                                let bitwidth = e.bitwidth().to_usize() as u32;
+
+                                let from = r
+                                    .value
+                                    .from
+                                    .map(|b| self.check_bit_bound(b, module_id, types))
+                                    .unwrap_or(Ok(0))?;
+
+                                let to = r
+                                    .value
+                                    .to
+                                    .map(|b| self.check_bit_bound(b, module_id, types))
+                                    .unwrap_or(Ok(bitwidth))?;
+
+                                match to.checked_sub(from) {
+                                    Some(width)
+                                        if to <= bitwidth
+                                            && [8, 16, 32, 64, 128].contains(&width) =>
+                                    {
+                                        Ok(e.slice(from, to).into())
+                                    }
+                                    _ => Err(ErrorInner {
+                                        pos: Some(pos),
+                                        message: format!(
+                                            "Cannot slice bits {}..{} of expression {} of type {}, expected a range of 8, 16, 32, 64 or 128 bits within the value",
+                                            from,
+                                            to,
+                                            e,
+                                            e.get_type(),
+                                        ),
+                                        context: vec![],
+                                    }),
+                                }
+                            }
+                            e => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Cannot access slice of expression {} of type {}",
+                                    e,
+                                    e.get_type(),
+                                ),
+                                context: vec![],
+                            }),
                         }
                     }
                     RangeOrExpression::Expression(index) => {
                         let index = self.check_expression(index, module_id, types)?;
 
                         let index =
-                            UExpression::try_from_typed(index, &UBitwidth::B32).map_err(|e| {
+                            UExpression::try_from_typed(index, &IntBitwidth::B32).map_err(|e| {
                                 ErrorInner {
                                     pos: Some(pos),
                                     message: format!(
                                         "Expected index to be of type u32, found {}",
                                         e
                                     ),
+                                    context: vec![],
                                 }
                             })?;
 
@@ -2745,7 +3971,29 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     Type::Boolean => Ok(BooleanExpression::select(a, index).into()),
                                     Type::Array(..) => Ok(ArrayExpression::select(a, index).into()),
                                     Type::Struct(..) => Ok(StructExpression::select(a, index).into()),
-                                    Type::Int => unreachable!(),
+                                    Type::Tuple(..) => Ok(TupleExpression::select(a, index).into()),
+                                    Type::Enum(..) => Ok(EnumExpression::select(a, index).into()),
+                                    Type::Int | Type::Generic(..) => unreachable!(),
+                                }
+                            }
+                            TypedExpression::Uint(e) if !e.bitwidth().is_signed() => {
+                                // indices which are not known yet are checked after propagation
+                                match index.as_inner() {
+                                    UExpressionInner::Value(i)
+                                        if *i >= e.bitwidth().to_usize() as u128 =>
+                                    {
+                                        Err(ErrorInner {
+                                            pos: Some(pos),
+                                            message: format!(
+                                                "Bit index {} is out of bounds for expression {} of type {}",
+                                                i,
+                                                e,
+                                                e.get_type()
+                                            ),
+                                            context: vec![],
+                                        })
+                                    }
+                                    _ => Ok(e.bit(index).into()),
                                 }
                             }
                             a => Err(ErrorInner {
@@ -2757,6 +4005,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     a,
                                     a.get_type()
                                 ),
+                                context: vec![],
                             }),
                         }
                     }
@@ -2772,7 +4021,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                         match ty {
                             Some(ty) => match ty {
-                                Type::Int => unreachable!(),
+                                Type::Int | Type::Generic(..) => unreachable!(),
                                 Type::FieldElement => {
                                     Ok(FieldElementExpression::member(s, id.to_string()).into())
                                 }
@@ -2786,6 +4035,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                 Type::Struct(..) => {
                                     Ok(StructExpression::member(s, id.to_string()).into())
                                 }
+                                Type::Tuple(..) => {
+                                    Ok(TupleExpression::member(s, id.to_string()).into())
+                                }
+                                Type::Enum(..) => {
+                                    Ok(EnumExpression::member(s, id.to_string()).into())
+                                }
                             },
                             None => Err(ErrorInner {
                                 pos: Some(pos),
@@ -2800,6 +4055,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                         .join(", "),
                                     id,
                                 ),
+                                context: vec![],
                             }),
                         }
                     }
@@ -2810,8 +4066,379 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             id,
                             e.get_type()
                         ),
+                        context: vec![],
+                    }),
+                }
+            }
+            Expression::Element(box e, index) => {
+                let e = self.check_expression(e, module_id, types)?;
+
+                match e {
+                    TypedExpression::Tuple(t) => {
+                        // indices which do not fit in a u32 are out of bounds as well
+                        let ty = index.to_u32().and_then(|index| {
+                            t.ty()
+                                .elements
+                                .get(index as usize)
+                                .cloned()
+                                .map(|ty| (index, ty))
+                        });
+
+                        match ty {
+                            Some((index, ty)) => match ty {
+                                Type::Int | Type::Generic(..) => unreachable!(),
+                                Type::FieldElement => {
+                                    Ok(FieldElementExpression::element(t, index).into())
+                                }
+                                Type::Boolean => Ok(BooleanExpression::element(t, index).into()),
+                                Type::Uint(..) => Ok(UExpression::element(t, index).into()),
+                                Type::Array(..) => Ok(ArrayExpression::element(t, index).into()),
+                                Type::Struct(..) => Ok(StructExpression::element(t, index).into()),
+                                Type::Tuple(..) => Ok(TupleExpression::element(t, index).into()),
+                                Type::Enum(..) => Ok(EnumExpression::element(t, index).into()),
+                            },
+                            None => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Tuple of type {} has {} element{}, cannot access element {}",
+                                    t.get_type(),
+                                    t.ty().elements.len(),
+                                    if t.ty().elements.len() == 1 { "" } else { "s" },
+                                    index,
+                                ),
+                                context: vec![],
+                            }),
+                        }
+                    }
+                    e => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot access tuple element {} on expression of type {}",
+                            index,
+                            e.get_type()
+                        ),
+                        context: vec![],
+                    }),
+                }
+            }
+            Expression::InlineTuple(elements) => {
+                let elements_checked = elements
+                    .into_iter()
+                    .map(|e| self.check_expression(e, module_id, types))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let ty = TupleType::new(elements_checked.iter().map(|e| e.get_type()).collect());
+
+                Ok(TupleExpressionInner::Value(elements_checked)
+                    .annotate(ty)
+                    .into())
+            }
+            Expression::EnumValue(id, variant_id, arguments) => {
+                self.used_symbols.insert(id.clone());
+                let declared_enum_type = self.check_enum_type_id(&id, module_id, types, pos)?;
+
+                let (index, variant) =
+                    declared_enum_type
+                        .variant(variant_id)
+                        .ok_or_else(|| ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Enum {} has no variant {}", id, variant_id),
+                            context: vec![],
+                        })?;
+
+                if variant.payload.elements.len() != arguments.len() {
+                    return Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Variant {}::{} expects {} argument{}, but got {}",
+                            id,
+                            variant_id,
+                            variant.payload.elements.len(),
+                            if variant.payload.elements.len() == 1 {
+                                ""
+                            } else {
+                                "s"
+                            },
+                            arguments.len()
+                        ),
+                        context: vec![],
+                    });
+                }
+
+                let arguments_checked = arguments
+                    .into_iter()
+                    .zip(variant.payload.elements.iter())
+                    .map(|(a, ty)| {
+                        let a_checked = self.check_expression(a, module_id, types)?;
+
+                        TypedExpression::align_to_type(a_checked, ty).map_err(|(e, ty)| {
+                            ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Expected argument of variant {}::{} to be of type {}, found {} of type {}",
+                                    id,
+                                    variant_id,
+                                    ty,
+                                    e,
+                                    e.get_type()
+                                ),
+                                context: vec![],
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let enum_type = match specialize_declaration_type(
+                    DeclarationType::Enum(declared_enum_type.clone()),
+                    &GGenericsAssignment::default(),
+                )
+                .unwrap()
+                {
+                    Type::Enum(enum_type) => enum_type,
+                    _ => unreachable!(),
+                };
+
+                Ok(EnumExpressionInner::Value(index as u32, arguments_checked)
+                    .annotate(enum_type)
+                    .into())
+            }
+            Expression::Match(box scrutinee, arms) => {
+                let scrutinee_checked = self.check_expression(scrutinee, module_id, types)?;
+
+                let scrutinee_checked = match scrutinee_checked {
+                    TypedExpression::Enum(e) => Ok(e),
+                    e => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot match on expression `{}` of type {}, expected an enum",
+                            e,
+                            e.get_type()
+                        ),
+                        context: vec![],
                     }),
+                }?;
+
+                let enum_type = scrutinee_checked.ty().clone();
+
+                // bind the scrutinee to a fresh variable so that it is only evaluated once
+                let scrutinee_id =
+                    crate::typed_absy::Identifier::from(CoreIdentifier::Match(self.match_count));
+                self.match_count += 1;
+
+                let scrutinee_variable =
+                    Variable::with_id_and_type(scrutinee_id.clone(), Type::Enum(enum_type.clone()));
+                let scrutinee_identifier =
+                    EnumExpressionInner::Identifier(scrutinee_id).annotate(enum_type.clone());
+
+                let mut covered = vec![false; enum_type.variants.len()];
+                let mut wildcard = false;
+                // the checked arms, along with the index of the variant they match, if any
+                let mut arms_checked = vec![];
+
+                for arm in arms {
+                    let arm_pos = arm.pos();
+                    let arm = arm.value;
+
+                    if wildcard {
+                        return Err(ErrorInner {
+                            pos: Some(arm_pos),
+                            message: format!(
+                                "Unreachable pattern `{}`, all variants are already matched by `_`",
+                                arm.pattern
+                            ),
+                            context: vec![],
+                        });
+                    }
+
+                    match arm.pattern {
+                        Pattern::Wildcard => {
+                            wildcard = true;
+
+                            let e = self.check_expression(arm.expression, module_id, types)?;
+
+                            arms_checked.push((None, e));
+                        }
+                        Pattern::Variant(id, variant_id, bindings) => {
+                            self.used_symbols.insert(id.clone());
+                            let declared_enum_type =
+                                self.check_enum_type_id(&id, module_id, types, arm_pos)?;
+
+                            if declared_enum_type.canonical_location != enum_type.canonical_location
+                            {
+                                return Err(ErrorInner {
+                                    pos: Some(arm_pos),
+                                    message: format!(
+                                        "Expected a variant of enum {}, found {}::{}",
+                                        enum_type, id, variant_id
+                                    ),
+                                    context: vec![],
+                                });
+                            }
+
+                            let (index, variant) =
+                                enum_type.variant(variant_id).ok_or_else(|| ErrorInner {
+                                    pos: Some(arm_pos),
+                                    message: format!("Enum {} has no variant {}", id, variant_id),
+                                    context: vec![],
+                                })?;
+
+                            if covered[index] {
+                                return Err(ErrorInner {
+                                    pos: Some(arm_pos),
+                                    message: format!(
+                                        "Unreachable pattern `{}`, variant {}::{} is already matched",
+                                        Pattern::Variant(id.clone(), variant_id, bindings),
+                                        id,
+                                        variant_id
+                                    ),
+                                    context: vec![],
+                                });
+                            }
+
+                            covered[index] = true;
+
+                            if variant.payload.elements.len() != bindings.len() {
+                                return Err(ErrorInner {
+                                    pos: Some(arm_pos),
+                                    message: format!(
+                                        "Variant {}::{} has {} field{}, but the pattern has {}",
+                                        id,
+                                        variant_id,
+                                        variant.payload.elements.len(),
+                                        if variant.payload.elements.len() == 1 {
+                                            ""
+                                        } else {
+                                            "s"
+                                        },
+                                        bindings.len()
+                                    ),
+                                    context: vec![],
+                                });
+                            }
+
+                            self.enter_scope();
+
+                            // define the bound variables from the payload of the scrutinee
+                            let statements = bindings
+                                .into_iter()
+                                .zip(variant.payload.elements.iter())
+                                .enumerate()
+                                .filter_map(|(i, (binding, ty))| binding.map(|b| (i, b, ty)))
+                                .map(|(i, binding, ty)| {
+                                    let v = Variable::with_id_and_type(binding, ty.clone());
+
+                                    match self.insert_into_scope(v.clone(), false) {
+                                        true => {
+                                            self.declare_variable(&v, binding, arm_pos);
+                                            Ok(TypedStatement::Definition(
+                                                v.into(),
+                                                TypedExpression::element(
+                                                    scrutinee_identifier
+                                                        .clone()
+                                                        .payload(index as u32),
+                                                    i as u32,
+                                                ),
+                                            ))
+                                        }
+                                        false => Err(self.duplicate_declaration(binding, arm_pos)),
+                                    }
+                                })
+                                .collect::<Result<Vec<_>, _>>();
+
+                            let expression = arm.expression;
+                            let e = statements.and_then(|statements| {
+                                self.check_expression(expression, module_id, types)
+                                    .map(|e| (statements, e))
+                            });
+
+                            self.exit_scope();
+
+                            let (statements, e) = e?;
+
+                            arms_checked.push((Some((index, statements)), e));
+                        }
+                    }
+                }
+
+                if !wildcard {
+                    let missing: Vec<_> = enum_type
+                        .variants
+                        .iter()
+                        .zip(covered.iter())
+                        .filter(|(_, covered)| !**covered)
+                        .map(|(v, _)| format!("{}::{}", enum_type, v.id))
+                        .collect();
+
+                    if !missing.is_empty() {
+                        return Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!(
+                                "Non-exhaustive match on enum {}, missing {}",
+                                enum_type,
+                                missing.join(", ")
+                            ),
+                            context: vec![],
+                        });
+                    }
                 }
+
+                // all arms must have the same type, which we infer from the first arm which is not an integer literal
+                let ty = arms_checked
+                    .iter()
+                    .map(|(_, e)| e.get_type())
+                    .find(|ty| !matches!(ty, Type::Int))
+                    .ok_or_else(|| ErrorInner {
+                        pos: Some(pos),
+                        message: "Cannot infer the type of this match expression, consider adding a type suffix to one of its arms"
+                            .into(),
+                        context: vec![],
+                    })?;
+
+                let arms_checked = arms_checked
+                    .into_iter()
+                    .map(|(variant, e)| {
+                        TypedExpression::align_to_type(e, &ty)
+                            .map(|e| match variant {
+                                Some((index, statements)) => {
+                                    (Some(index), TypedExpression::block(statements, e))
+                                }
+                                None => (None, e),
+                            })
+                            .map_err(|(e, ty)| ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Arms of match expression should have the same type, expected {}, found {} of type {}",
+                                    ty,
+                                    e,
+                                    e.get_type()
+                                ),
+                                context: vec![],
+                            })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                // build a chain of conditionals on the tag of the scrutinee, the last arm being the default
+                let mut arms_checked = arms_checked.into_iter().rev();
+                let (_, default) = arms_checked.next().unwrap();
+
+                let value = arms_checked.fold(default, |acc, (variant, e)| {
+                    TypedExpression::if_else(
+                        BooleanExpression::FieldEq(
+                            box FieldElementExpression::Tag(box scrutinee_identifier.clone()),
+                            box FieldElementExpression::Number(T::from(variant.unwrap())),
+                        ),
+                        e,
+                        acc,
+                    )
+                });
+
+                Ok(TypedExpression::block(
+                    vec![TypedStatement::Definition(
+                        scrutinee_variable.into(),
+                        scrutinee_checked.into(),
+                    )],
+                    value,
+                ))
             }
             Expression::InlineArray(expressions_or_spreads) => {
                 // check each expression, getting its type
@@ -2825,6 +4452,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     return Err(ErrorInner {
                         pos: Some(pos),
                         message: "Empty arrays are not allowed".to_string(),
+                        context: vec![],
                     });
                 }
 
@@ -2842,8 +4470,10 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 let unwrapped_expressions_or_spreads = match inferred_type.clone() {
                     Type::Int => expressions_or_spreads_checked,
                     t => {
-                        let target_array_ty =
-                            ArrayType::new(t, UExpressionInner::Value(0).annotate(UBitwidth::B32));
+                        let target_array_ty = ArrayType::new(
+                            t,
+                            UExpressionInner::Value(0).annotate(IntBitwidth::B32),
+                        );
 
                         expressions_or_spreads_checked
                             .into_iter()
@@ -2852,6 +4482,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     |(e, ty)| ErrorInner {
                                         pos: Some(pos),
                                         message: format!("Expected {} to have type {}", e, ty,),
+                                        context: vec![],
                                     },
                                 )
                             })
@@ -2894,7 +4525,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                 let count = self.check_expression(count, module_id, types)?;
 
-                let count = UExpression::try_from_typed(count, &UBitwidth::B32).map_err(|e| {
+                let count = UExpression::try_from_typed(count, &IntBitwidth::B32).map_err(|e| {
                     ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -2902,6 +4533,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e,
                             e.get_type(),
                         ),
+                        context: vec![],
                     }
                 })?;
 
@@ -2909,18 +4541,84 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     .annotate(ty, count)
                     .into())
             }
+            Expression::ArrayComprehension(box e, box var, box from, box to) => {
+                self.check_for_var(&var)?;
+
+                let var_pos = var.pos();
+                let var = self.check_variable(var, module_id, types).unwrap();
+
+                let from = self.check_expression(from, module_id, types)?;
+                let to = self.check_expression(to, module_id, types)?;
+
+                let from = UExpression::try_from_typed(from, &IntBitwidth::B32).map_err(|e| {
+                    ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expected lower loop bound to be of type u32, found {}",
+                            e.get_type()
+                        ),
+                        context: vec![],
+                    }
+                })?;
+
+                let to =
+                    UExpression::try_from_typed(to, &IntBitwidth::B32).map_err(|e| ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expected upper loop bound to be of type u32, found {}",
+                            e.get_type()
+                        ),
+                        context: vec![],
+                    })?;
+
+                // the loop variable is only visible in the element expression
+                self.enter_scope();
+
+                let var = self.insert_loop_variable(var, var_pos);
+
+                let e = self.check_expression(e, module_id, types);
+
+                self.exit_scope();
+
+                let e = e?;
+                let ty = e.get_type();
+
+                Ok(ArrayExpressionInner::Comprehension(
+                    box e,
+                    var,
+                    box from.clone(),
+                    box to.clone(),
+                )
+                .annotate(ty, UExpression::floor_sub(to, from))
+                .into())
+            }
             Expression::InlineStruct(id, inline_members) => {
-                let ty = match types.get(module_id).unwrap().get(&id).cloned() {
+                self.used_symbols.insert(id.clone());
+
+                let ty = match types
+                    .get(module_id)
+                    .unwrap()
+                    .get(&id)
+                    .map(|(ty, _)| ty)
+                    .cloned()
+                {
                     None => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!("Undefined type `{}`", id),
+                        context: vec![],
                     }),
                     Some(ty) => Ok(ty),
                 }?;
 
                 let declared_struct_type = match ty {
                     DeclarationType::Struct(struct_type) => struct_type,
-                    _ => unreachable!(),
+                    ty => {
+                        return Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Type `{}` is not a struct", ty),
+                            context: vec![],
+                        })
+                    }
                 };
 
                 // check that we provided the required number of values
@@ -2938,6 +4636,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                 .collect::<Vec<_>>()
                                 .join(", ")
                         ),
+                        context: vec![],
                     });
                 }
 
@@ -2970,6 +4669,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                         e.0,
                                         e.0.get_type(),
                                     ),
+                                            context: vec![],
                                         })?;
 
                                 Ok(expression_checked)
@@ -2988,6 +4688,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                         .join(", "),
                                     Expression::InlineStruct(id.clone(), inline_members.clone()),
                                 ),
+                                context: vec![],
                             }),
                         },
                     )
@@ -3007,6 +4708,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     Expression::InlineStruct(id.clone(), inline_members.clone()),
                                     declared_struct_type
                                 ),
+                                context: vec![],
                             })
                         } else {
                             Ok(StructMember {
@@ -3017,12 +4719,31 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     })
                     .collect::<Result<Vec<_>, _>>()?;
 
-                let generics = generics_map.0.values().cloned().map(Some).collect();
+                let generics = generics_map.constants.values().cloned().map(Some).collect();
+
+                let type_generics = declared_struct_type
+                    .type_generics
+                    .iter()
+                    .map(|t| {
+                        specialize_declaration_type(t.clone(), &generics_map)
+                            .ok()
+                            .filter(|ty| !Self::depends_on_int(ty))
+                            .ok_or_else(|| ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Failed to infer value for generic parameter `{}` of struct {}",
+                                    t, declared_struct_type
+                                ),
+                                context: vec![],
+                            })
+                    })
+                    .collect::<Result<_, _>>()?;
 
                 let inferred_struct_type = StructType {
                     canonical_location: declared_struct_type.canonical_location.clone(),
                     location: declared_struct_type.location,
                     generics,
+                    type_generics,
                     members,
                 };
 
@@ -3044,6 +4765,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         e1.get_type(),
                         e2.get_type()
                     ),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -3061,6 +4783,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e1.get_type(),
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -3078,6 +4801,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e1.get_type(),
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -3086,12 +4810,13 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 let e2 = self.check_expression(e2, module_id, types)?;
 
                 let e2 =
-                    UExpression::try_from_typed(e2, &UBitwidth::B32).map_err(|e| ErrorInner {
+                    UExpression::try_from_typed(e2, &IntBitwidth::B32).map_err(|e| ErrorInner {
                         pos: Some(pos),
                         message: format!(
                             "Expected the left shift right operand to have type `u32`, found {}",
                             e
                         ),
+                        context: vec![],
                     })?;
 
                 match e1 {
@@ -3105,6 +4830,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e1.get_type(),
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -3113,12 +4839,13 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 let e2 = self.check_expression(e2, module_id, types)?;
 
                 let e2 =
-                    UExpression::try_from_typed(e2, &UBitwidth::B32).map_err(|e| ErrorInner {
+                    UExpression::try_from_typed(e2, &IntBitwidth::B32).map_err(|e| ErrorInner {
                         pos: Some(pos),
                         message: format!(
                             "Expected the right shift right operand to be of type `u32`, found {}",
                             e
                         ),
+                        context: vec![],
                     })?;
 
                 match e1 {
@@ -3134,38 +4861,97 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e1.get_type(),
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
-            Expression::BitOr(box e1, box e2) => {
-                let e1_checked = self.check_expression(e1, module_id, types)?;
-                let e2_checked = self.check_expression(e2, module_id, types)?;
-
-                let (e1_checked, e2_checked) = TypedExpression::align_without_integers(
-                    e1_checked, e2_checked,
-                )
-                .map_err(|(e1, e2)| ErrorInner {
-                    pos: Some(pos),
-                    message: format!("Cannot apply `|` to {}, {}", e1.get_type(), e2.get_type()),
-                })?;
+            Expression::LeftRotate(box e1, box e2) => {
+                let e1 = self.check_expression(e1, module_id, types)?;
+                let e2 = self.check_expression(e2, module_id, types)?;
 
-                match (e1_checked, e2_checked) {
-                    (TypedExpression::Int(e1), TypedExpression::Int(e2)) => {
-                        Ok(IntExpression::Or(box e1, box e2).into())
-                    }
-                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2))
-                        if e1.bitwidth() == e2.bitwidth() =>
-                    {
-                        Ok(UExpression::or(e1, e2).into())
-                    }
-                    (e1, e2) => Err(ErrorInner {
+                let e2 =
+                    UExpression::try_from_typed(e2, &IntBitwidth::B32).map_err(|e| ErrorInner {
                         pos: Some(pos),
-
                         message: format!(
-                            "Cannot apply `|` to {}, {}",
-                            e1.get_type(),
-                            e2.get_type()
+                            "Expected the left rotation right operand to be of type `u32`, found {}",
+                            e
+                        ),
+                        context: vec![],
+                    })?;
+
+                match e1 {
+                    TypedExpression::Int(e1) => Ok(IntExpression::left_rotate(e1, e2).into()),
+                    TypedExpression::Uint(e1) => Ok(UExpression::left_rotate(e1, e2).into()),
+                    e1 => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot left-rotate {} by {}",
+                            e1.get_type(),
+                            e2.get_type()
+                        ),
+                        context: vec![],
+                    }),
+                }
+            }
+            Expression::RightRotate(box e1, box e2) => {
+                let e1 = self.check_expression(e1, module_id, types)?;
+                let e2 = self.check_expression(e2, module_id, types)?;
+
+                let e2 =
+                    UExpression::try_from_typed(e2, &IntBitwidth::B32).map_err(|e| ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expected the right rotation right operand to be of type `u32`, found {}",
+                            e
+                        ),
+                        context: vec![],
+                    })?;
+
+                match e1 {
+                    TypedExpression::Int(e1) => Ok(IntExpression::right_rotate(e1, e2).into()),
+                    TypedExpression::Uint(e1) => Ok(UExpression::right_rotate(e1, e2).into()),
+                    e1 => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot right-rotate {} by {}",
+                            e1.get_type(),
+                            e2.get_type()
+                        ),
+                        context: vec![],
+                    }),
+                }
+            }
+            Expression::BitOr(box e1, box e2) => {
+                let e1_checked = self.check_expression(e1, module_id, types)?;
+                let e2_checked = self.check_expression(e2, module_id, types)?;
+
+                let (e1_checked, e2_checked) = TypedExpression::align_without_integers(
+                    e1_checked, e2_checked,
+                )
+                .map_err(|(e1, e2)| ErrorInner {
+                    pos: Some(pos),
+                    message: format!("Cannot apply `|` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
+                })?;
+
+                match (e1_checked, e2_checked) {
+                    (TypedExpression::Int(e1), TypedExpression::Int(e2)) => {
+                        Ok(IntExpression::Or(box e1, box e2).into())
+                    }
+                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2))
+                        if e1.bitwidth() == e2.bitwidth() =>
+                    {
+                        Ok(UExpression::or(e1, e2).into())
+                    }
+                    (e1, e2) => Err(ErrorInner {
+                        pos: Some(pos),
+
+                        message: format!(
+                            "Cannot apply `|` to {}, {}",
+                            e1.get_type(),
+                            e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -3179,6 +4965,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 .map_err(|(e1, e2)| ErrorInner {
                     pos: Some(pos),
                     message: format!("Cannot apply `&` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -3198,6 +4985,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e1.get_type(),
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -3211,6 +4999,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 .map_err(|(e1, e2)| ErrorInner {
                     pos: Some(pos),
                     message: format!("Cannot apply `^` to {}, {}", e1.get_type(), e2.get_type()),
+                    context: vec![],
                 })?;
 
                 match (e1_checked, e2_checked) {
@@ -3230,6 +5019,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             e1.get_type(),
                             e2.get_type()
                         ),
+                        context: vec![],
                     }),
                 }
             }
@@ -3242,6 +5032,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     e => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!("Cannot negate {}", e.get_type()),
+                        context: vec![],
                     }),
                 }
             }
@@ -3257,20 +5048,486 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 Type::FieldElement,
             ),
             level: 0,
+            is_mutable: false,
         })
     }
 
-    fn insert_into_scope(&mut self, v: Variable<'ast, T>) -> bool {
+    /// Bring the variable of a loop declared at `pos` into the current scope and return it. If it shadows a variable
+    /// of an outer scope, it is given a distinct identifier and the outer variable is set aside until the scope of the
+    /// loop is exited
+    fn insert_loop_variable(
+        &mut self,
+        var: Variable<'ast, T>,
+        pos: (Position, Position),
+    ) -> Variable<'ast, T> {
+        let shadowed = self.scope.take(&ScopedVariable {
+            id: var.clone(),
+            level: 0,
+            is_mutable: false,
+        });
+
+        let var = match shadowed {
+            Some(shadowed) => {
+                self.shadowed_variables.push((self.level, shadowed));
+
+                let name = match var.id.id {
+                    CoreIdentifier::Source(name) => name,
+                    _ => unreachable!("loop variables are declared in the source"),
+                };
+
+                self.warnings.push(WarningInner {
+                    pos: Some(pos),
+                    message: format!(
+                        "Loop variable `{}` shadows a variable of the same name",
+                        name
+                    ),
+                });
+
+                Variable::with_id_and_type(
+                    crate::typed_absy::Identifier::from(CoreIdentifier::Shadow(name, self.level)),
+                    var._type,
+                )
+            }
+            None => var,
+        };
+
+        self.insert_into_scope(var.clone(), false);
+
+        var
+    }
+
+    /// Keep track of the declaration of `var` under `name` at `pos`, so that we can warn about it if it is never read
+    fn declare_variable(
+        &mut self,
+        var: &Variable<'ast, T>,
+        name: &'ast str,
+        pos: (Position, Position),
+    ) {
+        self.declared_variables.push(DeclaredVariable {
+            id: var.id.clone(),
+            name,
+            level: self.level,
+            pos,
+            read: false,
+        });
+    }
+
+    /// Mark the innermost declaration of the variable `id` as read
+    fn read_variable(&mut self, id: &crate::typed_absy::Identifier<'ast>) {
+        if let Some(v) = self
+            .declared_variables
+            .iter_mut()
+            .rev()
+            .find(|v| v.id == *id)
+        {
+            v.read = true;
+        }
+    }
+
+    fn insert_into_scope(&mut self, v: Variable<'ast, T>, is_mutable: bool) -> bool {
         self.scope.insert(ScopedVariable {
             id: v,
             level: self.level,
+            is_mutable,
         })
     }
 
+    /// The error for the variable `name` declared at `pos` while another variable of the same name is in scope
+    fn duplicate_declaration(&self, name: &str, pos: (Position, Position)) -> ErrorInner {
+        let error = ErrorInner {
+            pos: Some(pos),
+            message: format!("Duplicate declaration for variable named {}", name),
+            context: vec![],
+        };
+
+        let error = match self
+            .declared_variables
+            .iter()
+            .rev()
+            .find(|v| v.name == name)
+        {
+            Some(v) => error.with_label(v.pos, "first declared here"),
+            None => error,
+        };
+
+        error.with_note("variables can only be shadowed by loop variables")
+    }
+
+    /// The error for the symbol `id` declared at `pos`, which conflicts with the one declared at `first_pos`
+    fn conflicting_symbol(
+        id: &str,
+        pos: (Position, Position),
+        first_pos: (Position, Position),
+    ) -> ErrorInner {
+        let error = ErrorInner {
+            pos: Some(pos),
+            message: format!("{} conflicts with another symbol", id),
+            context: vec![],
+        };
+
+        // there is nothing else to point at if the conflict is between symbols brought by the same import
+        match first_pos == pos {
+            true => error,
+            false => error.with_label(first_pos, "first declared here"),
+        }
+    }
+
+    /// Return the name of the function called in a function call, as only functions can be called by name
+    fn check_callee(callee: ExpressionNode<'ast>) -> Result<&'ast str, ErrorInner> {
+        let pos = callee.pos();
+
+        match callee.value {
+            Expression::Identifier(id) => Ok(id),
+            e => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Expected function in function call to be an identifier, found `{}`",
+                    e
+                ),
+                context: vec![],
+            }),
+        }
+    }
+
     fn find_functions(&self, query: &FunctionQuery<'ast, T>) -> Vec<DeclarationFunctionKey<'ast>> {
         query.match_funcs(&self.functions)
     }
 
+    /// Check the explicit generic arguments of a call to `f`, given the `query` which found it, that is the types of
+    /// its arguments and the expected types of its outputs.
+    ///
+    /// If `f` is generic over types, the type parameters are inferred if they are not provided. They are only used to
+    /// type the call here: the call still refers to `f`, which gets instantiated when it is inlined.
+    fn check_call_generics(
+        &mut self,
+        f: &DeclarationFunctionKey<'ast>,
+        generics: Option<Vec<Option<GenericArgument<'ast>>>>,
+        query: &FunctionQuery<'ast, T>,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast>,
+        pos: (Position, Position),
+    ) -> Result<CallGenerics<'ast, T>, ErrorInner> {
+        // the constant generic arguments, in the order of declaration of the constant parameters
+        let mut constants = vec![];
+        let mut assignment = GGenericsAssignment::<UExpression<'ast, T>>::default();
+
+        match generics {
+            Some(generics) => {
+                for (index, g) in generics.into_iter().enumerate() {
+                    // constant and type parameters are declared in a single list, find out which one this is
+                    let type_parameter = f.signature.type_generics.iter().find_map(|t| match t {
+                        DeclarationType::Generic(t) if t.index == index => Some(t.clone()),
+                        _ => None,
+                    });
+
+                    match (type_parameter, g) {
+                        (None, None) => constants.push(None),
+                        (None, Some(GenericArgument::Expression(e))) => {
+                            let pos = e.pos();
+                            let e = self.check_expression(e, module_id, types)?;
+                            let e =
+                                UExpression::try_from_typed(e, &IntBitwidth::B32).map_err(|e| {
+                                    ErrorInner {
+                                        pos: Some(pos),
+                                        message: format!(
+                                            "Expected {} to be of type u32, found {}",
+                                            e,
+                                            e.get_type(),
+                                        ),
+                                        context: vec![],
+                                    }
+                                })?;
+                            constants.push(Some(e));
+                        }
+                        (None, Some(GenericArgument::Type(t))) => {
+                            return Err(ErrorInner {
+                                pos: Some(t.pos()),
+                                message: format!(
+                                    "Expected u32 constant or identifier, found type {}",
+                                    t
+                                ),
+                                context: vec![],
+                            })
+                        }
+                        (Some(_), None) => {}
+                        (Some(id), Some(g)) => {
+                            let t = g.into_type().map_err(|e| ErrorInner {
+                                pos: Some(e.pos()),
+                                message: format!("Expected type, found expression {}", e),
+                                context: vec![],
+                            })?;
+                            let t = self.check_type(t, module_id, types)?;
+                            assignment.types.insert(id, t);
+                        }
+                    }
+                }
+            }
+            None => constants = vec![None; f.signature.generics.len()],
+        };
+
+        if f.signature.type_generics.is_empty() {
+            return Ok((f.signature.clone(), constants));
+        }
+
+        // infer the type parameters which were not provided from the arguments and the expected outputs
+        // mismatches are ignored here, they are reported when checking the arguments against the bound signature
+        for (decl_ty, ty) in f.signature.inputs.iter().zip(query.inputs.iter()).chain(
+            f.signature
+                .outputs
+                .iter()
+                .zip(query.outputs.iter())
+                .filter_map(|(decl_ty, ty)| ty.as_ref().map(|ty| (decl_ty, ty))),
+        ) {
+            check_type(decl_ty, ty, &mut assignment);
+        }
+
+        let type_arguments = f
+            .signature
+            .type_generics
+            .iter()
+            .map(|t| match t {
+                DeclarationType::Generic(id) => assignment
+                    .types
+                    .get(id)
+                    .filter(|ty| !Self::depends_on_int(ty))
+                    .cloned()
+                    .and_then(|ty| ConcreteType::try_from(ty).ok())
+                    .map(|ty| (id.clone(), DeclarationType::from(ty)))
+                    .ok_or_else(|| ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Failed to infer value for generic parameter `{}`, try providing an explicit value",
+                            id
+                        ),
+                        context: vec![],
+                    }),
+                _ => unreachable!("type parameters are declared as generic types"),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // the constant parameters are kept, they are bound when checking the call
+        let bound_assignment = GGenericsAssignment {
+            constants: f
+                .signature
+                .generics
+                .iter()
+                .filter_map(|g| match g {
+                    Some(DeclarationConstant::Generic(id)) => {
+                        Some((id.clone(), DeclarationConstant::Generic(id.clone())))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            types: type_arguments.iter().cloned().collect(),
+        };
+
+        let specialize = |types: &[DeclarationType<'ast>]| {
+            types
+                .iter()
+                .cloned()
+                .map(|t| specialize_declaration_type(t, &bound_assignment))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+
+        let bound_signature = DeclarationSignature::new()
+            .generics(f.signature.generics.clone())
+            .type_generics(type_arguments.into_iter().map(|(_, ty)| ty).collect())
+            .inputs(specialize(&f.signature.inputs))
+            .outputs(specialize(&f.signature.outputs));
+
+        Ok((bound_signature, constants))
+    }
+
+    /// Check a call to the function `f`, which returns a single value
+    fn check_function_call(
+        &mut self,
+        f: DeclarationFunctionKey<'ast>,
+        generics: Option<Vec<Option<GenericArgument<'ast>>>>,
+        arguments_checked: Vec<TypedExpression<'ast, T>>,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast>,
+        pos: (Position, Position),
+    ) -> Result<TypedExpression<'ast, T>, ErrorInner> {
+        let arguments_types: Vec<_> = arguments_checked.iter().map(|a| a.get_type()).collect();
+
+        let query = FunctionQuery::new(f.id, None, &arguments_types, &[None]);
+
+        let (signature, generics_checked) =
+            self.check_call_generics(&f, generics, &query, module_id, types, pos)?;
+
+        let arguments_checked = arguments_checked
+            .into_iter()
+            .zip(signature.inputs.iter())
+            .map(|(a, t)| TypedExpression::align_to_type(a, &t))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Expected function call argument to be of type {}, found {}",
+                    e.1, e.0
+                ),
+                context: vec![],
+            })?;
+
+        let mut output_types = signature
+            .get_output_types(
+                generics_checked.clone(),
+                arguments_checked.iter().map(|a| a.get_type()).collect(),
+            )
+            .map_err(|e| ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                "Failed to infer value for generic parameter `{}`, try providing an explicit value",
+                e,
+            ),
+                context: vec![],
+            })?;
+
+        let function_key = DeclarationFunctionKey {
+            module: f.module,
+            id: f.id,
+            signature: f.signature,
+        };
+
+        // the return count has to be 1
+        match output_types.len() {
+            1 => match output_types.pop().unwrap() {
+                Type::Int | Type::Generic(..) => unreachable!(),
+                Type::FieldElement => Ok(FieldElementExpression::function_call(
+                    function_key,
+                    generics_checked,
+                    arguments_checked,
+                )
+                .into()),
+                Type::Boolean => Ok(BooleanExpression::function_call(
+                    function_key,
+                    generics_checked,
+                    arguments_checked,
+                )
+                .into()),
+                Type::Uint(bitwidth) => Ok(UExpression::function_call(
+                    function_key,
+                    generics_checked,
+                    arguments_checked,
+                )
+                .annotate(bitwidth)
+                .into()),
+                Type::Struct(struct_ty) => Ok(StructExpression::function_call(
+                    function_key,
+                    generics_checked,
+                    arguments_checked,
+                )
+                .annotate(struct_ty)
+                .into()),
+                Type::Array(array_ty) => Ok(ArrayExpression::function_call(
+                    function_key,
+                    generics_checked,
+                    arguments_checked,
+                )
+                .annotate(*array_ty.ty, array_ty.size)
+                .into()),
+                Type::Tuple(tuple_ty) => Ok(TupleExpression::function_call(
+                    function_key,
+                    generics_checked,
+                    arguments_checked,
+                )
+                .annotate(tuple_ty)
+                .into()),
+                Type::Enum(enum_ty) => Ok(EnumExpression::function_call(
+                    function_key,
+                    generics_checked,
+                    arguments_checked,
+                )
+                .annotate(enum_ty)
+                .into()),
+            },
+            n => Err(ErrorInner {
+                pos: Some(pos),
+
+                message: format!(
+                    "{} returns {} values but is called outside of a definition",
+                    f.id, n
+                ),
+                context: vec![],
+            }),
+        }
+    }
+
+    /// Returns whether the type `ty` contains the type of an integer literal, in which case it cannot be used as a
+    /// type argument
+    fn depends_on_int(ty: &Type<'ast, T>) -> bool {
+        match ty {
+            Type::Int => true,
+            Type::Array(array_ty) => Self::depends_on_int(&array_ty.ty),
+            Type::Tuple(tuple_ty) => tuple_ty.elements.iter().any(Self::depends_on_int),
+            Type::Struct(struct_ty) => struct_ty
+                .members
+                .iter()
+                .map(|m| &*m.ty)
+                .chain(struct_ty.type_generics.iter())
+                .any(Self::depends_on_int),
+            _ => false,
+        }
+    }
+
+    /// Check the function `f` declared as `id`, which is generic over the type parameters of its checked `signature`.
+    ///
+    /// Its body is checked once for any value of the type parameters: each of them stands for an opaque type which is
+    /// different from all other types, so that the values of these types can only be moved around. The returned
+    /// function keeps its type parameters in its signature and arguments, they are bound when it gets inlined.
+    fn check_template(
+        &mut self,
+        id: Identifier<'ast>,
+        f: FunctionNode<'ast>,
+        signature: DeclarationSignature<'ast>,
+        module_id: &ModuleId,
+        state: &State<'ast, T>,
+    ) -> Result<TypedFunction<'ast, T>, Vec<ErrorInner>> {
+        let key = DeclarationFunctionKey::with_location(module_id.to_path_buf(), id)
+            .signature(signature.clone());
+
+        self.type_arguments = signature
+            .type_generics
+            .iter()
+            .map(|t| match t {
+                DeclarationType::Generic(t) => (
+                    f.value.signature.generics[t.index].value.id,
+                    ConcreteType::Struct(t.opaque_type(&key)),
+                ),
+                _ => unreachable!("type parameters are declared as generic types"),
+            })
+            .collect();
+
+        let funct = self.check_function(f, module_id, state);
+
+        self.type_arguments.clear();
+
+        let funct = funct?;
+
+        Ok(TypedFunction {
+            arguments: funct
+                .arguments
+                .into_iter()
+                .zip(signature.inputs.iter())
+                .map(|(p, ty)| DeclarationParameter {
+                    id: DeclarationVariable::with_id_and_type(p.id.id, ty.clone()),
+                    ..p
+                })
+                .collect(),
+            signature,
+            ..funct
+        })
+    }
+
+    /// Returns whether `ty` is the opaque type standing for a type parameter of the function being checked
+    fn is_type_parameter(&self, ty: &StructType<'ast, T>) -> bool {
+        self.type_arguments.values().any(|t| match t {
+            ConcreteType::Struct(t) => t.canonical_location == ty.canonical_location,
+            _ => false,
+        })
+    }
+
     fn enter_scope(&mut self) {
         self.level += 1;
     }
@@ -3279,6 +5536,25 @@ impl<'ast, T: Field> Checker<'ast, T> {
         let current_level = self.level;
         self.scope
             .retain(|ref scoped_variable| scoped_variable.level < current_level);
+
+        // the variables of this scope cannot be read anymore
+        while matches!(self.declared_variables.last(), Some(v) if v.level == current_level) {
+            let v = self.declared_variables.pop().unwrap();
+            if !v.read {
+                self.warnings.push(WarningInner {
+                    pos: Some(v.pos),
+                    message: format!("Unused variable `{}`", v.name),
+                });
+            }
+        }
+
+        // the variables shadowed by a loop variable of this scope are visible again
+        while matches!(self.shadowed_variables.last(), Some((level, _)) if *level == current_level)
+        {
+            let (_, shadowed) = self.shadowed_variables.pop().unwrap();
+            self.scope.insert(shadowed);
+        }
+
         self.level -= 1;
     }
 }
@@ -3294,6 +5570,22 @@ mod tests {
     lazy_static! {
         static ref MODULE_ID: OwnedModuleId = OwnedModuleId::from("");
     }
+
+    /// Precede each statement with the location marker inserted when checking mocked statements
+    fn with_locations<'ast, T>(
+        statements: Vec<TypedStatement<'ast, T>>,
+    ) -> Vec<TypedStatement<'ast, T>> {
+        statements
+            .into_iter()
+            .flat_map(|s| {
+                vec![
+                    TypedStatement::Location(SourceLocation::new("", Position::mock())),
+                    s,
+                ]
+            })
+            .collect()
+    }
+
     mod constants {
         use super::*;
 
@@ -3318,6 +5610,20 @@ mod tests {
                 .check_expression(expr, &*MODULE_ID, &TypeMap::new())
                 .is_err());
         }
+
+        #[test]
+        fn uint_overflow() {
+            // `0x1ff_u8` does not fit in `u8`
+            let expr = Expression::OutOfRangeUintConstant("0x1ff", 8).mock();
+
+            assert_eq!(
+                Checker::<Bn128Field>::new()
+                    .check_expression(expr, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()
+                    .message,
+                "Literal 0x1ff does not fit in u8"
+            );
+        }
     }
 
     mod array {
@@ -3522,18 +5828,22 @@ mod tests {
 
             let foo: Module = Module {
                 symbols: vec![SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: Symbol::Here(SymbolDefinition::Function(function0())),
                 }
                 .mock()],
+                immutable_by_default: false,
             };
 
             let bar: Module = Module {
                 symbols: vec![SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: Symbol::There(SymbolImport::with_id_in_module("main", "foo").mock()),
                 }
                 .mock()],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -3578,16 +5888,19 @@ mod tests {
             let module = Module {
                 symbols: vec![
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Function(function0())),
                     }
                     .mock(),
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Function(function0())),
                     }
                     .mock(),
                 ],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -3627,7 +5940,7 @@ mod tests {
             )
             .mock()];
             f0.value.signature = UnresolvedSignature::new()
-                .generics(vec!["P".mock()])
+                .generics(vec![GenericDeclaration::constant("P").mock()])
                 .inputs(vec![UnresolvedType::array(
                     UnresolvedType::FieldElement.mock(),
                     Expression::Identifier("P").mock(),
@@ -3656,16 +5969,19 @@ mod tests {
             let module = Module {
                 symbols: vec![
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Function(f0)),
                     }
                     .mock(),
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Function(f1)),
                     }
                     .mock(),
                 ],
+                immutable_by_default: false,
             };
 
             let mut state = State::new(vec![((*MODULE_ID).clone(), module)].into_iter().collect());
@@ -3688,21 +6004,25 @@ mod tests {
 
                 let mut foo = function0();
 
-                foo.value.signature = UnresolvedSignature::new().generics(vec!["P".mock()]);
+                foo.value.signature = UnresolvedSignature::new()
+                    .generics(vec![GenericDeclaration::constant("P").mock()]);
 
                 let module = Module {
                     symbols: vec![
                         SymbolDeclaration {
+                            public: false,
                             id: "foo",
                             symbol: Symbol::Here(SymbolDefinition::Function(foo)),
                         }
                         .mock(),
                         SymbolDeclaration {
+                            public: false,
                             id: "main",
                             symbol: Symbol::Here(SymbolDefinition::Function(function0())),
                         }
                         .mock(),
                     ],
+                    immutable_by_default: false,
                 };
 
                 let mut state =
@@ -3745,16 +6065,19 @@ mod tests {
                 let module = Module {
                     symbols: vec![
                         SymbolDeclaration {
+                            public: false,
                             id: "foo",
                             symbol: Symbol::Here(SymbolDefinition::Function(foo)),
                         }
                         .mock(),
                         SymbolDeclaration {
+                            public: false,
                             id: "main",
                             symbol: Symbol::Here(SymbolDefinition::Function(function0())),
                         }
                         .mock(),
                     ],
+                    immutable_by_default: false,
                 };
 
                 let mut state =
@@ -3782,16 +6105,19 @@ mod tests {
             let module = Module {
                 symbols: vec![
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Function(function0())),
                     }
                     .mock(),
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Function(function1())),
                     }
                     .mock(),
                 ],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -3831,16 +6157,19 @@ mod tests {
             let module: Module = Module {
                 symbols: vec![
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Struct(struct0())),
                     }
                     .mock(),
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Struct(struct1())),
                     }
                     .mock(),
                 ],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -3867,11 +6196,13 @@ mod tests {
             let module = Module {
                 symbols: vec![
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Function(function0())),
                     }
                     .mock(),
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Struct(
                             StructDefinition {
@@ -3883,6 +6214,7 @@ mod tests {
                     }
                     .mock(),
                 ],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -3912,6 +6244,7 @@ mod tests {
             // should fail
 
             let bar = Module::with_symbols(vec![SymbolDeclaration {
+                public: false,
                 id: "main",
                 symbol: Symbol::Here(SymbolDefinition::Function(function0())),
             }
@@ -3920,6 +6253,7 @@ mod tests {
             let main = Module {
                 symbols: vec![
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::There(
                             SymbolImport::with_id_in_module("main", "bar").mock(),
@@ -3927,11 +6261,13 @@ mod tests {
                     }
                     .mock(),
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Struct(struct0())),
                     }
                     .mock(),
                 ],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -3960,6 +6296,7 @@ mod tests {
             // should fail
 
             let bar = Module::with_symbols(vec![SymbolDeclaration {
+                public: false,
                 id: "main",
                 symbol: Symbol::Here(SymbolDefinition::Function(function0())),
             }
@@ -3968,11 +6305,13 @@ mod tests {
             let main = Module {
                 symbols: vec![
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::Here(SymbolDefinition::Struct(struct0())),
                     }
                     .mock(),
                     SymbolDeclaration {
+                        public: false,
                         id: "foo",
                         symbol: Symbol::There(
                             SymbolImport::with_id_in_module("main", "bar").mock(),
@@ -3980,6 +6319,7 @@ mod tests {
                     }
                     .mock(),
                 ],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -3996,7 +6336,183 @@ mod tests {
                 "foo conflicts with another symbol"
             );
         }
-    }
+
+        #[test]
+        fn import_private_symbol() {
+            // // bar.code
+            // pub def main(): return
+            // def foo(): return
+            //
+            // // main.code
+            // from "bar" import main
+            // from "bar" import foo
+            //
+            // should fail on `foo`
+
+            let bar = Module::with_symbols(vec![
+                SymbolDeclaration {
+                    public: true,
+                    id: "main",
+                    symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+                }
+                .mock(),
+                SymbolDeclaration {
+                    public: false,
+                    id: "foo",
+                    symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+                }
+                .mock(),
+            ]);
+
+            let main = Module {
+                symbols: vec![
+                    SymbolDeclaration {
+                        public: false,
+                        id: "main",
+                        symbol: Symbol::There(
+                            SymbolImport::with_id_in_module("main", "bar").mock(),
+                        ),
+                    }
+                    .mock(),
+                    SymbolDeclaration {
+                        public: false,
+                        id: "foo",
+                        symbol: Symbol::There(SymbolImport::with_id_in_module("foo", "bar").mock()),
+                    }
+                    .mock(),
+                ],
+                immutable_by_default: false,
+            };
+
+            let mut state = State::<Bn128Field>::new(
+                vec![((*MODULE_ID).clone(), main), ("bar".into(), bar)]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+            let errors = checker.check_module(&*MODULE_ID, &mut state).unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(
+                errors[0].inner.message,
+                "Symbol foo in module bar is private"
+            );
+        }
+
+        #[test]
+        fn import_from_module_without_pub() {
+            // // bar.code
+            // def foo(): return
+            //
+            // // main.code
+            // from "bar" import foo
+            //
+            // should succeed, as modules without `pub` symbols export everything
+
+            let bar = Module::with_symbols(vec![SymbolDeclaration {
+                public: false,
+                id: "foo",
+                symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+            }
+            .mock()]);
+
+            let main = Module::with_symbols(vec![SymbolDeclaration {
+                public: false,
+                id: "foo",
+                symbol: Symbol::There(SymbolImport::with_id_in_module("foo", "bar").mock()),
+            }
+            .mock()]);
+
+            let mut state = State::<Bn128Field>::new(
+                vec![((*MODULE_ID).clone(), main), ("bar".into(), bar)]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+            assert!(checker.check_module(&*MODULE_ID, &mut state).is_ok());
+        }
+
+        #[test]
+        fn reexport() {
+            // // bar.code
+            // pub def foo(): return
+            // def main(): return
+            //
+            // // facade.code
+            // pub from "bar" import foo
+            // from "bar" import foo as main
+            //
+            // // main.code
+            // from "facade" import foo
+            // from "facade" import main
+            //
+            // should fail on `main` only
+
+            let bar = Module::with_symbols(vec![
+                SymbolDeclaration {
+                    public: true,
+                    id: "foo",
+                    symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+                }
+                .mock(),
+                SymbolDeclaration {
+                    public: false,
+                    id: "main",
+                    symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+                }
+                .mock(),
+            ]);
+
+            let facade = Module::with_symbols(vec![
+                SymbolDeclaration {
+                    public: true,
+                    id: "foo",
+                    symbol: Symbol::There(SymbolImport::with_id_in_module("foo", "bar").mock()),
+                }
+                .mock(),
+                SymbolDeclaration {
+                    public: false,
+                    id: "main",
+                    symbol: Symbol::There(SymbolImport::with_id_in_module("foo", "bar").mock()),
+                }
+                .mock(),
+            ]);
+
+            let main = Module::with_symbols(vec![
+                SymbolDeclaration {
+                    public: false,
+                    id: "foo",
+                    symbol: Symbol::There(SymbolImport::with_id_in_module("foo", "facade").mock()),
+                }
+                .mock(),
+                SymbolDeclaration {
+                    public: false,
+                    id: "main",
+                    symbol: Symbol::There(SymbolImport::with_id_in_module("main", "facade").mock()),
+                }
+                .mock(),
+            ]);
+
+            let mut state = State::<Bn128Field>::new(
+                vec![
+                    ((*MODULE_ID).clone(), main),
+                    ("facade".into(), facade),
+                    ("bar".into(), bar),
+                ]
+                .into_iter()
+                .collect(),
+            );
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+            let errors = checker.check_module(&*MODULE_ID, &mut state).unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(
+                errors[0].inner.message,
+                "Symbol main in module facade is private"
+            );
+        }
+    }
 
     pub fn new_with_args<'ast, T: Field>(
         scope: HashSet<ScopedVariable<'ast, T>>,
@@ -4008,6 +6524,17 @@ mod tests {
             functions,
             level,
             return_types: None,
+            match_count: 0,
+            casts: HashMap::new(),
+            type_arguments: HashMap::new(),
+            shadowed_variables: vec![],
+            methods: HashMap::new(),
+            immutable_by_default: false,
+            uninitialized_variables: HashSet::new(),
+            declared_variables: vec![],
+            declared_symbols: vec![],
+            used_symbols: HashSet::new(),
+            warnings: vec![],
         }
     }
 
@@ -4029,7 +6556,8 @@ mod tests {
                 Checker::<Bn128Field>::new().check_signature(signature, &*MODULE_ID, &state),
                 Err(vec![ErrorInner {
                     pos: Some((Position::mock(), Position::mock())),
-                    message: "Undeclared symbol `K`".to_string()
+                    message: "Undeclared symbol `K`".to_string(),
+                    context: vec![],
                 }])
             );
         }
@@ -4041,7 +6569,11 @@ mod tests {
             let state = State::new(modules);
 
             let signature = UnresolvedSignature::new()
-                .generics(vec!["K".mock(), "L".mock(), "M".mock()])
+                .generics(vec![
+                    GenericDeclaration::constant("K").mock(),
+                    GenericDeclaration::constant("L").mock(),
+                    GenericDeclaration::constant("M").mock(),
+                ])
                 .inputs(vec![UnresolvedType::Array(
                     box UnresolvedType::Array(
                         box UnresolvedType::FieldElement.mock(),
@@ -4098,7 +6630,8 @@ mod tests {
             checker.check_statement(statement, &*MODULE_ID, &TypeMap::new()),
             Err(vec![ErrorInner {
                 pos: Some((Position::mock(), Position::mock())),
-                message: "Identifier \"b\" is undefined".into()
+                message: "Identifier \"b\" is undefined".into(),
+                context: vec![],
             }])
         );
     }
@@ -4117,10 +6650,12 @@ mod tests {
         scope.insert(ScopedVariable {
             id: Variable::field_element("a"),
             level: 1,
+            is_mutable: false,
         });
         scope.insert(ScopedVariable {
             id: Variable::field_element("b"),
             level: 1,
+            is_mutable: false,
         });
 
         let mut checker: Checker<Bn128Field> = new_with_args(scope, 1, HashSet::new());
@@ -4133,6 +6668,129 @@ mod tests {
         );
     }
 
+    #[test]
+    fn if_else_statement() {
+        // if true {
+        //   a = 1
+        // } else {
+        //   field b = 2
+        //   a = b
+        // }
+        // a = b
+        // `b` is only in scope in the alternative, so the last statement should fail
+        let statement: StatementNode = Statement::IfElse(
+            Expression::BooleanConstant(true).mock(),
+            vec![Statement::Definition(
+                Assignee::Identifier("a").mock(),
+                Expression::FieldConstant(BigUint::from(1u32)).mock(),
+            )
+            .mock()],
+            vec![
+                Statement::Declaration(
+                    absy::Variable::new("b", UnresolvedType::FieldElement.mock()).mock(),
+                )
+                .mock(),
+                Statement::Definition(
+                    Assignee::Identifier("b").mock(),
+                    Expression::FieldConstant(BigUint::from(2u32)).mock(),
+                )
+                .mock(),
+                Statement::Definition(
+                    Assignee::Identifier("a").mock(),
+                    Expression::Identifier("b").mock(),
+                )
+                .mock(),
+            ],
+        )
+        .mock();
+
+        let mut scope = HashSet::new();
+        scope.insert(ScopedVariable {
+            id: Variable::field_element("a"),
+            level: 1,
+            is_mutable: false,
+        });
+
+        let mut checker: Checker<Bn128Field> = new_with_args(scope, 1, HashSet::new());
+
+        let location = || TypedStatement::Location(SourceLocation::new("", Position::mock()));
+
+        assert_eq!(
+            checker.check_statement(statement, &*MODULE_ID, &TypeMap::new()),
+            Ok(TypedStatement::IfElse(
+                BooleanExpression::Value(true),
+                with_locations(vec![TypedStatement::Definition(
+                    TypedAssignee::Identifier(typed_absy::Variable::field_element("a")),
+                    FieldElementExpression::Number(Bn128Field::from(1)).into()
+                )])
+                .into_iter()
+                .chain(std::iter::once(location()))
+                .collect(),
+                with_locations(vec![
+                    TypedStatement::Declaration(typed_absy::Variable::field_element("b")),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(typed_absy::Variable::field_element("b")),
+                        FieldElementExpression::Number(Bn128Field::from(2)).into()
+                    ),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(typed_absy::Variable::field_element("a")),
+                        FieldElementExpression::Identifier("b".into()).into()
+                    )
+                ])
+                .into_iter()
+                .chain(std::iter::once(location()))
+                .collect()
+            ))
+        );
+
+        let statement: StatementNode = Statement::Definition(
+            Assignee::Identifier("a").mock(),
+            Expression::Identifier("b").mock(),
+        )
+        .mock();
+
+        assert_eq!(
+            checker.check_statement(statement, &*MODULE_ID, &TypeMap::new()),
+            Err(vec![ErrorInner {
+                pos: Some((Position::mock(), Position::mock())),
+                message: "Identifier \"b\" is undefined".into(),
+                context: vec![],
+            }])
+        );
+    }
+
+    #[test]
+    fn return_in_if_else_statement() {
+        // if true {
+        //   return
+        // }
+        // should fail
+        let statement: StatementNode = Statement::IfElse(
+            Expression::BooleanConstant(true).mock(),
+            vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![],
+                }
+                .mock(),
+            )
+            .mock()],
+            vec![],
+        )
+        .mock();
+
+        let mut checker: Checker<Bn128Field> = Checker::new();
+        checker.enter_scope();
+
+        assert_eq!(
+            checker.check_statement(statement, &*MODULE_ID, &TypeMap::new()),
+            Err(vec![ErrorInner {
+                pos: Some((Position::mock(), Position::mock())),
+                message: "Return statements are not allowed inside if/else blocks".into(),
+                context: vec![],
+            }])
+        );
+    }
+
     #[test]
     fn declared_in_other_function() {
         // def foo():
@@ -4187,17 +6845,19 @@ mod tests {
 
         let symbols = vec![
             SymbolDeclaration {
+                public: false,
                 id: "foo",
                 symbol: Symbol::Here(SymbolDefinition::Function(foo)),
             }
             .mock(),
             SymbolDeclaration {
+                public: false,
                 id: "bar",
                 symbol: Symbol::Here(SymbolDefinition::Function(bar)),
             }
             .mock(),
         ];
-        let module = Module { symbols };
+        let module = Module::with_symbols(symbols);
 
         let mut state =
             State::<Bn128Field>::new(vec![((*MODULE_ID).clone(), module)].into_iter().collect());
@@ -4208,7 +6868,8 @@ mod tests {
             Err(vec![Error {
                 inner: ErrorInner {
                     pos: Some((Position::mock(), Position::mock())),
-                    message: "Identifier \"a\" is undefined".into()
+                    message: "Identifier \"a\" is undefined".into(),
+                    context: vec![],
                 },
                 module_id: (*MODULE_ID).clone()
             }])
@@ -4299,22 +6960,25 @@ mod tests {
 
         let symbols = vec![
             SymbolDeclaration {
+                public: false,
                 id: "foo",
                 symbol: Symbol::Here(SymbolDefinition::Function(foo)),
             }
             .mock(),
             SymbolDeclaration {
+                public: false,
                 id: "bar",
                 symbol: Symbol::Here(SymbolDefinition::Function(bar)),
             }
             .mock(),
             SymbolDeclaration {
+                public: false,
                 id: "main",
                 symbol: Symbol::Here(SymbolDefinition::Function(main)),
             }
             .mock(),
         ];
-        let module = Module { symbols };
+        let module = Module::with_symbols(symbols);
 
         let mut state =
             State::<Bn128Field>::new(vec![((*MODULE_ID).clone(), module)].into_iter().collect());
@@ -4363,7 +7027,8 @@ mod tests {
             checker.check_function(foo, &*MODULE_ID, &state),
             Err(vec![ErrorInner {
                 pos: Some((Position::mock(), Position::mock())),
-                message: "Identifier \"i\" is undefined".into()
+                message: "Identifier \"i\" is undefined".into(),
+                context: vec![],
             }])
         );
     }
@@ -4406,25 +7071,25 @@ mod tests {
             .mock(),
         ];
 
-        let for_statements_checked = vec![
-            TypedStatement::Declaration(typed_absy::Variable::uint("a", UBitwidth::B32)),
+        let for_statements_checked = with_locations(vec![
+            TypedStatement::Declaration(typed_absy::Variable::uint("a", IntBitwidth::B32)),
             TypedStatement::Definition(
-                TypedAssignee::Identifier(typed_absy::Variable::uint("a", UBitwidth::B32)),
+                TypedAssignee::Identifier(typed_absy::Variable::uint("a", IntBitwidth::B32)),
                 UExpressionInner::Identifier("i".into())
-                    .annotate(UBitwidth::B32)
+                    .annotate(IntBitwidth::B32)
                     .into(),
             ),
-        ];
+        ]);
 
-        let foo_statements_checked = vec![
+        let foo_statements_checked = with_locations(vec![
             TypedStatement::For(
-                typed_absy::Variable::uint("i", UBitwidth::B32),
+                typed_absy::Variable::uint("i", IntBitwidth::B32),
                 0u32.into(),
                 10u32.into(),
                 for_statements_checked,
             ),
             TypedStatement::Return(vec![]),
-        ];
+        ]);
 
         let foo = Function {
             arguments: vec![],
@@ -4450,20 +7115,48 @@ mod tests {
     }
 
     #[test]
-    fn arity_mismatch() {
+    fn for_index_shadowing_variable_of_other_type() {
         // def foo():
-        //   return 1, 2
-        // def bar():
-        //   field a = foo()
-        // should fail
-        let bar_statements: Vec<StatementNode> = vec![
+        //   bool i = true
+        //   for u32 i in 0..10 do
+        //     u32 a = i
+        //   endfor
+        //   bool b = i
+        //   return
+        // should pass, the loop variable being bound to a distinct identifier in the loop only
+
+        let for_statements = vec![
             Statement::Declaration(
-                absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                absy::Variable::new("a", UnresolvedType::Uint(32).mock()).mock(),
             )
             .mock(),
-            Statement::MultipleDefinition(
-                vec![Assignee::Identifier("a").mock()],
-                Expression::FunctionCall("foo", None, vec![]).mock(),
+            Statement::Definition(
+                Assignee::Identifier("a").mock(),
+                Expression::Identifier("i").mock(),
+            )
+            .mock(),
+        ];
+
+        let foo_statements = vec![
+            Statement::Declaration(absy::Variable::new("i", UnresolvedType::Boolean.mock()).mock())
+                .mock(),
+            Statement::Definition(
+                Assignee::Identifier("i").mock(),
+                Expression::BooleanConstant(true).mock(),
+            )
+            .mock(),
+            Statement::For(
+                absy::Variable::new("i", UnresolvedType::Uint(32).mock()).mock(),
+                Expression::IntConstant(0usize.into()).mock(),
+                Expression::IntConstant(10usize.into()).mock(),
+                for_statements,
+            )
+            .mock(),
+            Statement::Declaration(absy::Variable::new("b", UnresolvedType::Boolean.mock()).mock())
+                .mock(),
+            Statement::Definition(
+                Assignee::Identifier("b").mock(),
+                Expression::Identifier("i").mock(),
             )
             .mock(),
             Statement::Return(
@@ -4475,16 +7168,101 @@ mod tests {
             .mock(),
         ];
 
-        let foo = DeclarationFunctionKey {
-            module: (*MODULE_ID).clone(),
-            id: "foo",
-            signature: DeclarationSignature::new().inputs(vec![]).outputs(vec![
-                DeclarationType::FieldElement,
-                DeclarationType::FieldElement,
-            ]),
-        };
-
-        let functions = vec![foo].into_iter().collect();
+        let loop_variable = typed_absy::Variable::with_id_and_type(
+            typed_absy::Identifier::from(CoreIdentifier::Shadow("i", 2)),
+            Type::Uint(IntBitwidth::B32),
+        );
+
+        let for_statements_checked = with_locations(vec![
+            TypedStatement::Declaration(typed_absy::Variable::uint("a", IntBitwidth::B32)),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(typed_absy::Variable::uint("a", IntBitwidth::B32)),
+                UExpressionInner::Identifier(loop_variable.id.clone())
+                    .annotate(IntBitwidth::B32)
+                    .into(),
+            ),
+        ]);
+
+        let foo_statements_checked = with_locations(vec![
+            TypedStatement::Declaration(typed_absy::Variable::boolean("i")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(typed_absy::Variable::boolean("i")),
+                BooleanExpression::Value(true).into(),
+            ),
+            TypedStatement::For(
+                loop_variable,
+                0u32.into(),
+                10u32.into(),
+                for_statements_checked,
+            ),
+            TypedStatement::Declaration(typed_absy::Variable::boolean("b")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(typed_absy::Variable::boolean("b")),
+                BooleanExpression::Identifier("i".into()).into(),
+            ),
+            TypedStatement::Return(vec![]),
+        ]);
+
+        let foo = Function {
+            arguments: vec![],
+            statements: foo_statements,
+            signature: UnresolvedSignature::new(),
+        }
+        .mock();
+
+        let foo_checked = TypedFunction {
+            arguments: vec![],
+            statements: foo_statements_checked,
+            signature: DeclarationSignature::default(),
+        };
+
+        let modules = Modules::new();
+        let state = State::new(modules);
+
+        let mut checker: Checker<Bn128Field> = Checker::new();
+        assert_eq!(
+            checker.check_function(foo, &*MODULE_ID, &state),
+            Ok(foo_checked)
+        );
+    }
+
+    #[test]
+    fn arity_mismatch() {
+        // def foo():
+        //   return 1, 2
+        // def bar():
+        //   field a = foo()
+        // should fail
+        let bar_statements: Vec<StatementNode> = vec![
+            Statement::Declaration(
+                absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+            )
+            .mock(),
+            Statement::MultipleDefinition(
+                vec![Assignee::Identifier("a").mock()],
+                Expression::FunctionCall(box Expression::Identifier("foo").mock(), None, vec![])
+                    .mock(),
+            )
+            .mock(),
+            Statement::Return(
+                ExpressionList {
+                    expressions: vec![],
+                }
+                .mock(),
+            )
+            .mock(),
+        ];
+
+        let foo = DeclarationFunctionKey {
+            module: (*MODULE_ID).clone(),
+            id: "foo",
+            signature: DeclarationSignature::new().inputs(vec![]).outputs(vec![
+                DeclarationType::FieldElement,
+                DeclarationType::FieldElement,
+            ]),
+        };
+
+        let functions = vec![foo].into_iter().collect();
 
         let bar = Function {
             arguments: vec![],
@@ -4503,7 +7281,8 @@ mod tests {
                 pos: Some((Position::mock(), Position::mock())),
                 message:
                     "Function definition for function foo with signature () -> field not found."
-                        .into()
+                        .into(),
+                context: vec![],
             }])
         );
     }
@@ -4520,9 +7299,15 @@ mod tests {
             Statement::Assertion(
                 Expression::Eq(
                     box Expression::IntConstant(2usize.into()).mock(),
-                    box Expression::FunctionCall("foo", None, vec![]).mock(),
+                    box Expression::FunctionCall(
+                        box Expression::Identifier("foo").mock(),
+                        None,
+                        vec![],
+                    )
+                    .mock(),
                 )
                 .mock(),
+                None,
             )
             .mock(),
             Statement::Return(
@@ -4561,7 +7346,8 @@ mod tests {
             Err(vec![ErrorInner {
                 pos: Some((Position::mock(), Position::mock())),
                 message: "Function definition for function foo with signature () -> _ not found."
-                    .into()
+                    .into(),
+                context: vec![],
             }])
         );
     }
@@ -4579,7 +7365,8 @@ mod tests {
             .mock(),
             Statement::MultipleDefinition(
                 vec![Assignee::Identifier("a").mock()],
-                Expression::FunctionCall("foo", None, vec![]).mock(),
+                Expression::FunctionCall(box Expression::Identifier("foo").mock(), None, vec![])
+                    .mock(),
             )
             .mock(),
             Statement::Return(
@@ -4609,7 +7396,8 @@ mod tests {
 
                 message:
                     "Function definition for function foo with signature () -> field not found."
-                        .into()
+                        .into(),
+                context: vec![],
             }])
         );
     }
@@ -4664,8 +7452,12 @@ mod tests {
                     Assignee::Identifier("a").mock(),
                     Assignee::Identifier("b").mock(),
                 ],
-                Expression::FunctionCall("foo", None, vec![Expression::Identifier("x").mock()])
-                    .mock(),
+                Expression::FunctionCall(
+                    box Expression::Identifier("foo").mock(),
+                    None,
+                    vec![Expression::Identifier("x").mock()],
+                )
+                .mock(),
             )
             .mock(),
             Statement::Return(
@@ -4689,16 +7481,19 @@ mod tests {
         let module = Module {
             symbols: vec![
                 SymbolDeclaration {
+                    public: false,
                     id: "foo",
                     symbol: Symbol::Here(SymbolDefinition::Function(foo)),
                 }
                 .mock(),
                 SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: Symbol::Here(SymbolDefinition::Function(main)),
                 }
                 .mock(),
             ],
+            immutable_by_default: false,
         };
 
         let mut state =
@@ -4710,7 +7505,8 @@ mod tests {
             Err(vec![Error {
                 inner: ErrorInner {
                     pos: Some((Position::mock(), Position::mock())),
-                    message: "Identifier \"x\" is undefined".into()
+                    message: "Identifier \"x\" is undefined".into(),
+                    context: vec![],
                 },
                 module_id: (*MODULE_ID).clone()
             }])
@@ -4753,7 +7549,8 @@ mod tests {
                     Assignee::Identifier("a").mock(),
                     Assignee::Identifier("b").mock(),
                 ],
-                Expression::FunctionCall("foo", None, vec![]).mock(),
+                Expression::FunctionCall(box Expression::Identifier("foo").mock(), None, vec![])
+                    .mock(),
             )
             .mock(),
             Statement::Return(
@@ -4775,16 +7572,19 @@ mod tests {
         let module = Module {
             symbols: vec![
                 SymbolDeclaration {
+                    public: false,
                     id: "foo",
                     symbol: Symbol::Here(SymbolDefinition::Function(foo)),
                 }
                 .mock(),
                 SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: Symbol::Here(SymbolDefinition::Function(main)),
                 }
                 .mock(),
             ],
+            immutable_by_default: false,
         };
 
         let mut state =
@@ -4797,14 +7597,16 @@ mod tests {
                 Error {
                     inner: ErrorInner {
                         pos: Some((Position::mock(), Position::mock())),
-                        message: "Variable `a` is undeclared".into()
+                        message: "Variable `a` is undeclared".into(),
+                        context: vec![],
                     },
                     module_id: (*MODULE_ID).clone()
                 },
                 Error {
                     inner: ErrorInner {
                         pos: Some((Position::mock(), Position::mock())),
-                        message: "Variable `b` is undeclared".into()
+                        message: "Variable `b` is undeclared".into(),
+                        context: vec![],
                     },
                     module_id: (*MODULE_ID).clone()
                 }
@@ -4868,7 +7670,8 @@ mod tests {
                     ),
                 )
                 .mock()],
-                Expression::FunctionCall("foo", None, vec![]).mock(),
+                Expression::FunctionCall(box Expression::Identifier("foo").mock(), None, vec![])
+                    .mock(),
             )
             .mock(),
             Statement::Return(
@@ -4890,16 +7693,19 @@ mod tests {
         let module = Module {
             symbols: vec![
                 SymbolDeclaration {
+                    public: false,
                     id: "foo",
                     symbol: Symbol::Here(SymbolDefinition::Function(foo)),
                 }
                 .mock(),
                 SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: Symbol::Here(SymbolDefinition::Function(main)),
                 }
                 .mock(),
             ],
+            immutable_by_default: false,
         };
 
         let mut state =
@@ -4920,9 +7726,15 @@ mod tests {
             Statement::Assertion(
                 Expression::Eq(
                     box Expression::IntConstant(1usize.into()).mock(),
-                    box Expression::FunctionCall("foo", None, vec![]).mock(),
+                    box Expression::FunctionCall(
+                        box Expression::Identifier("foo").mock(),
+                        None,
+                        vec![],
+                    )
+                    .mock(),
                 )
                 .mock(),
+                None,
             )
             .mock(),
             Statement::Return(
@@ -4951,7 +7763,67 @@ mod tests {
                 pos: Some((Position::mock(), Position::mock())),
 
                 message: "Function definition for function foo with signature () -> _ not found."
-                    .into()
+                    .into(),
+                context: vec![],
+            }])
+        );
+    }
+
+    #[test]
+    fn call_non_identifier() {
+        // def bar():
+        //   1 == foo()(42)
+        //   return
+        // should fail
+
+        let bar_statements: Vec<StatementNode> = vec![
+            Statement::Assertion(
+                Expression::Eq(
+                    box Expression::IntConstant(1usize.into()).mock(),
+                    box Expression::FunctionCall(
+                        box Expression::FunctionCall(
+                            box Expression::Identifier("foo").mock(),
+                            None,
+                            vec![],
+                        )
+                        .mock(),
+                        None,
+                        vec![Expression::IntConstant(42usize.into()).mock()],
+                    )
+                    .mock(),
+                )
+                .mock(),
+                None,
+            )
+            .mock(),
+            Statement::Return(
+                ExpressionList {
+                    expressions: vec![],
+                }
+                .mock(),
+            )
+            .mock(),
+        ];
+
+        let bar = Function {
+            arguments: vec![],
+            statements: bar_statements,
+            signature: UnresolvedSignature::new(),
+        }
+        .mock();
+
+        let modules = Modules::new();
+        let state = State::new(modules);
+
+        let mut checker: Checker<Bn128Field> = new_with_args(HashSet::new(), 0, HashSet::new());
+        assert_eq!(
+            checker.check_function(bar, &*MODULE_ID, &state),
+            Err(vec![ErrorInner {
+                pos: Some((Position::mock(), Position::mock())),
+
+                message: "Expected function in function call to be an identifier, found `foo()`"
+                    .into(),
+                context: vec![],
             }])
         );
     }
@@ -4990,7 +7862,8 @@ mod tests {
             checker.check_function(bar, &*MODULE_ID, &state),
             Err(vec![ErrorInner {
                 pos: Some((Position::mock(), Position::mock())),
-                message: "Identifier \"a\" is undefined".into()
+                message: "Identifier \"a\" is undefined".into(),
+                context: vec![],
             }])
         );
     }
@@ -5018,7 +7891,8 @@ mod tests {
                     Assignee::Identifier("a").mock(),
                     Assignee::Identifier("b").mock(),
                 ],
-                Expression::FunctionCall("foo", None, vec![]).mock(),
+                Expression::FunctionCall(box Expression::Identifier("foo").mock(), None, vec![])
+                    .mock(),
             )
             .mock(),
             Statement::Return(
@@ -5034,7 +7908,7 @@ mod tests {
             .mock(),
         ];
 
-        let bar_statements_checked: Vec<TypedStatement<Bn128Field>> = vec![
+        let bar_statements_checked: Vec<TypedStatement<Bn128Field>> = with_locations(vec![
             TypedStatement::Declaration(typed_absy::Variable::field_element("a")),
             TypedStatement::Declaration(typed_absy::Variable::field_element("b")),
             TypedStatement::MultipleDefinition(
@@ -5059,7 +7933,7 @@ mod tests {
                 box FieldElementExpression::Identifier("b".into()),
             )
             .into()]),
-        ];
+        ]);
 
         let foo = DeclarationFunctionKey {
             module: (*MODULE_ID).clone(),
@@ -5188,18 +8062,20 @@ mod tests {
 
         let symbols = vec![
             SymbolDeclaration {
+                public: false,
                 id: "main",
                 symbol: Symbol::Here(SymbolDefinition::Function(main1)),
             }
             .mock(),
             SymbolDeclaration {
+                public: false,
                 id: "main",
                 symbol: Symbol::Here(SymbolDefinition::Function(main2)),
             }
             .mock(),
         ];
 
-        let main_module = Module { symbols };
+        let main_module = Module::with_symbols(symbols);
 
         let program = Program {
             modules: vec![((*MODULE_ID).clone(), main_module)]
@@ -5214,7 +8090,8 @@ mod tests {
             Err(vec![Error {
                 inner: ErrorInner {
                     pos: None,
-                    message: "Only one main function allowed, found 2".into()
+                    message: "Only one main function allowed, found 2".into(),
+                    context: vec![],
                 },
                 module_id: (*MODULE_ID).clone()
             }])
@@ -5250,7 +8127,14 @@ mod tests {
             s2_checked,
             Err(vec![ErrorInner {
                 pos: Some((Position::mock(), Position::mock())),
-                message: "Duplicate declaration for variable named a".into()
+                message: "Duplicate declaration for variable named a".into(),
+                context: vec![
+                    ErrorContext::Label(
+                        (Position::mock(), Position::mock()),
+                        "first declared here".into()
+                    ),
+                    ErrorContext::Note("variables can only be shadowed by loop variables".into())
+                ],
             }])
         );
     }
@@ -5284,7 +8168,55 @@ mod tests {
             s2_checked,
             Err(vec![ErrorInner {
                 pos: Some((Position::mock(), Position::mock())),
-                message: "Duplicate declaration for variable named a".into()
+                message: "Duplicate declaration for variable named a".into(),
+                context: vec![
+                    ErrorContext::Label(
+                        (Position::mock(), Position::mock()),
+                        "first declared here".into()
+                    ),
+                    ErrorContext::Note("variables can only be shadowed by loop variables".into())
+                ],
+            }])
+        );
+    }
+
+    #[test]
+    fn assign_with_different_type() {
+        //   field a
+        //   a = true
+        //
+        // should fail, pointing at the declaration of `a`
+
+        let mut checker: Checker<Bn128Field> = Checker::new();
+        let _: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker.check_statement(
+            Statement::Declaration(
+                absy::Variable::new("a", UnresolvedType::FieldElement.mock()).at(1, 11, 1),
+            )
+            .at(1, 5, 7),
+            &*MODULE_ID,
+            &TypeMap::new(),
+        );
+        let s2_checked: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::Definition(
+                    Assignee::Identifier("a").mock(),
+                    Expression::BooleanConstant(true).mock(),
+                )
+                .at(2, 5, 8),
+                &*MODULE_ID,
+                &TypeMap::new(),
+            );
+        assert_eq!(
+            s2_checked,
+            Err(vec![ErrorInner {
+                pos: Some((Position { line: 2, col: 5 }, Position { line: 2, col: 13 })),
+                message:
+                    "Expression `true` of type `bool` cannot be assigned to `a` of type `field`"
+                        .into(),
+                context: vec![ErrorContext::Label(
+                    (Position { line: 1, col: 11 }, Position { line: 1, col: 12 }),
+                    "`a` declared as `field` here".into()
+                )],
             }])
         );
     }
@@ -5299,10 +8231,12 @@ mod tests {
         ) -> (Checker<Bn128Field>, State<Bn128Field>) {
             let module: Module = Module {
                 symbols: vec![SymbolDeclaration {
+                    public: false,
                     id: "Foo",
                     symbol: Symbol::Here(SymbolDefinition::Struct(s.mock())),
                 }
                 .mock()],
+                immutable_by_default: false,
             };
 
             let mut state = State::<Bn128Field>::new(
@@ -5340,12 +8274,14 @@ mod tests {
                 ));
 
                 assert_eq!(
-                    Checker::<Bn128Field>::new().check_struct_type_declaration(
-                        "Foo".into(),
-                        declaration,
-                        &*MODULE_ID,
-                        &state
-                    ),
+                    Checker::<Bn128Field>::new()
+                        .check_struct_type_declaration(
+                            "Foo".into(),
+                            declaration,
+                            &*MODULE_ID,
+                            &state
+                        )
+                        .map(|(ty, _)| ty),
                     Ok(expected_type)
                 );
             }
@@ -5384,12 +8320,14 @@ mod tests {
                 ));
 
                 assert_eq!(
-                    Checker::<Bn128Field>::new().check_struct_type_declaration(
-                        "Foo".into(),
-                        declaration,
-                        &*MODULE_ID,
-                        &state
-                    ),
+                    Checker::<Bn128Field>::new()
+                        .check_struct_type_declaration(
+                            "Foo".into(),
+                            declaration,
+                            &*MODULE_ID,
+                            &state
+                        )
+                        .map(|(ty, _)| ty),
                     Ok(expected_type)
                 );
             }
@@ -5441,6 +8379,7 @@ mod tests {
                 let module: Module = Module {
                     symbols: vec![
                         SymbolDeclaration {
+                            public: false,
                             id: "Foo",
                             symbol: Symbol::Here(SymbolDefinition::Struct(
                                 StructDefinition {
@@ -5456,6 +8395,7 @@ mod tests {
                         }
                         .mock(),
                         SymbolDeclaration {
+                            public: false,
                             id: "Bar",
                             symbol: Symbol::Here(SymbolDefinition::Struct(
                                 StructDefinition {
@@ -5471,6 +8411,7 @@ mod tests {
                         }
                         .mock(),
                     ],
+                    immutable_by_default: false,
                 };
 
                 let mut state = State::<Bn128Field>::new(
@@ -5484,8 +8425,9 @@ mod tests {
                         .get(&*MODULE_ID)
                         .unwrap()
                         .get(&"Bar".to_string())
-                        .unwrap(),
-                    &DeclarationType::Struct(DeclarationStructType::new(
+                        .unwrap()
+                        .0,
+                    DeclarationType::Struct(DeclarationStructType::new(
                         (*MODULE_ID).clone(),
                         "Bar".into(),
                         vec![],
@@ -5513,6 +8455,7 @@ mod tests {
 
                 let module: Module = Module {
                     symbols: vec![SymbolDeclaration {
+                        public: false,
                         id: "Bar",
                         symbol: Symbol::Here(SymbolDefinition::Struct(
                             StructDefinition {
@@ -5527,6 +8470,7 @@ mod tests {
                         )),
                     }
                     .mock()],
+                    immutable_by_default: false,
                 };
 
                 let mut state = State::<Bn128Field>::new(
@@ -5546,6 +8490,7 @@ mod tests {
 
                 let module: Module = Module {
                     symbols: vec![SymbolDeclaration {
+                        public: false,
                         id: "Foo",
                         symbol: Symbol::Here(SymbolDefinition::Struct(
                             StructDefinition {
@@ -5560,6 +8505,7 @@ mod tests {
                         )),
                     }
                     .mock()],
+                    immutable_by_default: false,
                 };
 
                 let mut state = State::<Bn128Field>::new(
@@ -5581,6 +8527,7 @@ mod tests {
                 let module: Module = Module {
                     symbols: vec![
                         SymbolDeclaration {
+                            public: false,
                             id: "Foo",
                             symbol: Symbol::Here(SymbolDefinition::Struct(
                                 StructDefinition {
@@ -5596,6 +8543,7 @@ mod tests {
                         }
                         .mock(),
                         SymbolDeclaration {
+                            public: false,
                             id: "Bar",
                             symbol: Symbol::Here(SymbolDefinition::Struct(
                                 StructDefinition {
@@ -5611,6 +8559,7 @@ mod tests {
                         }
                         .mock(),
                     ],
+                    immutable_by_default: false,
                 };
 
                 let mut state = State::<Bn128Field>::new(
@@ -6032,6 +8981,7 @@ mod tests {
                 absy::Variable {
                     id: "a",
                     _type: UnresolvedType::FieldElement.mock(),
+                    is_mutable: false,
                 }
                 .mock(),
             )
@@ -6053,6 +9003,7 @@ mod tests {
                 absy::Variable {
                     id: "a",
                     _type: UnresolvedType::Uint(32).mock(),
+                    is_mutable: false,
                 }
                 .mock(),
             )
@@ -6073,7 +9024,7 @@ mod tests {
             main.value.statements = vec![Statement::Return(
                 ExpressionList {
                     expressions: vec![Expression::FunctionCall(
-                        "foo",
+                        box Expression::Identifier("foo").mock(),
                         None,
                         vec![Expression::IntConstant(0usize.into()).mock()],
                     )
@@ -6087,16 +9038,19 @@ mod tests {
 
             let m = Module::with_symbols(vec![
                 absy::SymbolDeclaration {
+                    public: false,
                     id: "foo",
                     symbol: Symbol::Here(SymbolDefinition::Function(foo_field)),
                 }
                 .mock(),
                 absy::SymbolDeclaration {
+                    public: false,
                     id: "foo",
                     symbol: Symbol::Here(SymbolDefinition::Function(foo_u32)),
                 }
                 .mock(),
                 absy::SymbolDeclaration {
+                    public: false,
                     id: "main",
                     symbol: Symbol::Here(SymbolDefinition::Function(main)),
                 }
@@ -6250,5 +9204,1815 @@ mod tests {
                 ))
             );
         }
+
+        #[test]
+        fn tuple_element() {
+            // (field, bool) a
+            // a.1 = true
+            let a: AssigneeNode =
+                Assignee::Element(box Assignee::Identifier("a").mock(), 1u32.into()).mock();
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+            checker.enter_scope();
+
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new(
+                            "a",
+                            UnresolvedType::Tuple(vec![
+                                UnresolvedType::FieldElement.mock(),
+                                UnresolvedType::Boolean.mock(),
+                            ])
+                            .mock(),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                checker.check_assignee(a, &*MODULE_ID, &TypeMap::new()),
+                Ok(TypedAssignee::Element(
+                    box TypedAssignee::Identifier(typed_absy::Variable::with_id_and_type(
+                        "a",
+                        Type::Tuple(TupleType::new(vec![Type::FieldElement, Type::Boolean])),
+                    )),
+                    1
+                ))
+            );
+        }
+
+        #[test]
+        fn tuple_element_out_of_bounds() {
+            // (field,) a
+            // a.1 = 42
+            let a: AssigneeNode =
+                Assignee::Element(box Assignee::Identifier("a").mock(), 1u32.into()).mock();
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+            checker.enter_scope();
+
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new(
+                            "a",
+                            UnresolvedType::Tuple(vec![UnresolvedType::FieldElement.mock()]).mock(),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                checker
+                    .check_assignee(a, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()
+                    .message,
+                "Tuple of type (field,) has 1 element, cannot access element 1"
+            );
+        }
+
+        #[test]
+        fn tuple_element_index_overflow() {
+            // (field,) a
+            // a.4294967296 = 42
+            let a: AssigneeNode = Assignee::Element(
+                box Assignee::Identifier("a").mock(),
+                BigUint::parse_bytes(b"4294967296", 10).unwrap(),
+            )
+            .mock();
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+            checker.enter_scope();
+
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new(
+                            "a",
+                            UnresolvedType::Tuple(vec![UnresolvedType::FieldElement.mock()]).mock(),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                checker
+                    .check_assignee(a, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()
+                    .message,
+                "Tuple of type (field,) has 1 element, cannot access element 4294967296"
+            );
+        }
+    }
+
+    mod enums {
+        use super::*;
+        use crate::typed_absy::types::EnumVariant;
+
+        /// enum Shape { Empty, Circle(field) }
+        fn create_module_with_shape() -> (Checker<'static, Bn128Field>, State<'static, Bn128Field>)
+        {
+            let module: Module = Module {
+                symbols: vec![SymbolDeclaration {
+                    public: false,
+                    id: "Shape",
+                    symbol: Symbol::Here(SymbolDefinition::Enum(
+                        EnumDefinition {
+                            variants: vec![
+                                EnumDefinitionVariant {
+                                    id: "Empty",
+                                    types: vec![],
+                                }
+                                .mock(),
+                                EnumDefinitionVariant {
+                                    id: "Circle",
+                                    types: vec![UnresolvedType::FieldElement.mock()],
+                                }
+                                .mock(),
+                            ],
+                        }
+                        .mock(),
+                    )),
+                }
+                .mock()],
+                immutable_by_default: false,
+            };
+
+            let mut state = State::<Bn128Field>::new(
+                vec![((*MODULE_ID).clone(), module)].into_iter().collect(),
+            );
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            checker.check_module(&*MODULE_ID, &mut state).unwrap();
+
+            (checker, state)
+        }
+
+        fn arm<'ast>(pattern: Pattern<'ast>, e: Expression<'ast>) -> MatchArmNode<'ast> {
+            MatchArm {
+                pattern,
+                expression: e.mock(),
+            }
+            .mock()
+        }
+
+        #[test]
+        fn duplicate_variant() {
+            // enum Shape { Empty, Empty }
+            let state = State::<Bn128Field>::new(Modules::new());
+
+            let declaration = EnumDefinition {
+                variants: vec![
+                    EnumDefinitionVariant {
+                        id: "Empty",
+                        types: vec![],
+                    }
+                    .mock(),
+                    EnumDefinitionVariant {
+                        id: "Empty",
+                        types: vec![],
+                    }
+                    .mock(),
+                ],
+            }
+            .mock();
+
+            assert_eq!(
+                Checker::<Bn128Field>::new()
+                    .check_enum_type_declaration("Shape".into(), declaration, &*MODULE_ID, &state)
+                    .unwrap_err()[0]
+                    .message,
+                "Duplicate variant Empty in enum definition"
+            );
+        }
+
+        #[test]
+        fn value() {
+            // Shape::Circle(42)
+            let (mut checker, state) = create_module_with_shape();
+
+            assert_eq!(
+                checker.check_expression(
+                    Expression::EnumValue(
+                        "Shape".into(),
+                        "Circle",
+                        vec![Expression::IntConstant(42usize.into()).mock()]
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &state.types
+                ),
+                Ok(EnumExpressionInner::Value(
+                    1,
+                    vec![FieldElementExpression::Number(Bn128Field::from(42u32)).into()]
+                )
+                .annotate(EnumType::new(
+                    "".into(),
+                    "Shape".into(),
+                    vec![
+                        EnumVariant::new("Empty".into(), vec![]),
+                        EnumVariant::new("Circle".into(), vec![Type::FieldElement])
+                    ]
+                ))
+                .into())
+            );
+        }
+
+        #[test]
+        fn value_wrong_arity() {
+            // Shape::Empty(42)
+            let (mut checker, state) = create_module_with_shape();
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::EnumValue(
+                            "Shape".into(),
+                            "Empty",
+                            vec![Expression::IntConstant(42usize.into()).mock()]
+                        )
+                        .mock(),
+                        &*MODULE_ID,
+                        &state.types
+                    )
+                    .unwrap_err()
+                    .message,
+                "Variant Shape::Empty expects 0 arguments, but got 1"
+            );
+        }
+
+        #[test]
+        fn non_exhaustive_match() {
+            // match Shape::Empty { Shape::Circle(r) => r }
+            let (mut checker, state) = create_module_with_shape();
+
+            let e = Expression::Match(
+                box Expression::EnumValue("Shape".into(), "Empty", vec![]).mock(),
+                vec![arm(
+                    Pattern::Variant("Shape".into(), "Circle", vec![Some("r")]),
+                    Expression::Identifier("r"),
+                )],
+            );
+
+            assert_eq!(
+                checker
+                    .check_expression(e.mock(), &*MODULE_ID, &state.types)
+                    .unwrap_err()
+                    .message,
+                "Non-exhaustive match on enum Shape, missing Shape::Empty"
+            );
+        }
+
+        #[test]
+        fn duplicate_arm() {
+            // match Shape::Empty { Shape::Empty => 1, Shape::Empty => 2, _ => 3 }
+            let (mut checker, state) = create_module_with_shape();
+
+            let e = Expression::Match(
+                box Expression::EnumValue("Shape".into(), "Empty", vec![]).mock(),
+                vec![
+                    arm(
+                        Pattern::Variant("Shape".into(), "Empty", vec![]),
+                        Expression::FieldConstant(1usize.into()),
+                    ),
+                    arm(
+                        Pattern::Variant("Shape".into(), "Empty", vec![]),
+                        Expression::FieldConstant(2usize.into()),
+                    ),
+                    arm(Pattern::Wildcard, Expression::FieldConstant(3usize.into())),
+                ],
+            );
+
+            assert_eq!(
+                checker
+                    .check_expression(e.mock(), &*MODULE_ID, &state.types)
+                    .unwrap_err()
+                    .message,
+                "Unreachable pattern `Shape::Empty`, variant Shape::Empty is already matched"
+            );
+        }
+
+        #[test]
+        fn match_arms_type_mismatch() {
+            // match Shape::Empty { Shape::Empty => true, _ => 3 }
+            let (mut checker, state) = create_module_with_shape();
+
+            let e = Expression::Match(
+                box Expression::EnumValue("Shape".into(), "Empty", vec![]).mock(),
+                vec![
+                    arm(
+                        Pattern::Variant("Shape".into(), "Empty", vec![]),
+                        Expression::BooleanConstant(true),
+                    ),
+                    arm(Pattern::Wildcard, Expression::IntConstant(3usize.into())),
+                ],
+            );
+
+            assert_eq!(
+                checker
+                    .check_expression(e.mock(), &*MODULE_ID, &state.types)
+                    .unwrap_err()
+                    .message,
+                "Arms of match expression should have the same type, expected bool, found 3 of type {integer}"
+            );
+        }
+    }
+
+    mod cast {
+        use super::*;
+
+        #[test]
+        fn field_to_uint() {
+            // 42f as u32
+            let e = Expression::Cast(
+                box Expression::FieldConstant(42usize.into()).mock(),
+                box UnresolvedType::Uint(32).mock(),
+            )
+            .mock();
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert_eq!(
+                checker
+                    .check_expression(e, &*MODULE_ID, &TypeMap::new())
+                    .unwrap()
+                    .get_type(),
+                Type::Uint(IntBitwidth::B32)
+            );
+            assert!(checker.casts[&*MODULE_ID]
+                .contains(&(CastType::FieldElement, CastType::Uint(IntBitwidth::B32))));
+        }
+
+        #[test]
+        fn integer_literal() {
+            // 42 as u8
+            let e = Expression::Cast(
+                box Expression::IntConstant(42usize.into()).mock(),
+                box UnresolvedType::Uint(8).mock(),
+            )
+            .mock();
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert_eq!(
+                checker.check_expression(e, &*MODULE_ID, &TypeMap::new()),
+                Ok(UExpressionInner::Value(42).annotate(IntBitwidth::B8).into())
+            );
+            assert!(checker.casts.is_empty());
+        }
+
+        #[test]
+        fn to_bool() {
+            // 42f as bool
+            let e = Expression::Cast(
+                box Expression::FieldConstant(42usize.into()).mock(),
+                box UnresolvedType::Boolean.mock(),
+            )
+            .mock();
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert_eq!(
+                checker
+                    .check_expression(e, &*MODULE_ID, &TypeMap::new())
+                    .unwrap()
+                    .get_type(),
+                Type::Boolean
+            );
+            assert!(
+                checker.casts[&*MODULE_ID].contains(&(CastType::FieldElement, CastType::Boolean))
+            );
+        }
+
+        #[test]
+        fn array_to_field() {
+            // [42f] as field
+            let e = Expression::Cast(
+                box Expression::InlineArray(vec![Expression::FieldConstant(42usize.into())
+                    .mock()
+                    .into()])
+                .mock(),
+                box UnresolvedType::FieldElement.mock(),
+            )
+            .mock();
+
+            assert_eq!(
+                Checker::<Bn128Field>::new()
+                    .check_expression(e, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()
+                    .message,
+                "Cannot cast [42f] of type field[1] to field"
+            );
+        }
+    }
+
+    mod type_aliases {
+        use super::*;
+
+        fn check_module_with_alias(
+            generics: Vec<GenericDeclarationNode<'static>>,
+            ty: UnresolvedTypeNode<'static>,
+        ) -> Result<(Checker<'static, Bn128Field>, State<'static, Bn128Field>), Vec<Error>>
+        {
+            let module = Module {
+                symbols: vec![SymbolDeclaration {
+                    public: false,
+                    id: "Vec",
+                    symbol: Symbol::Here(SymbolDefinition::Type(
+                        TypeDefinition { generics, ty }.mock(),
+                    )),
+                }
+                .mock()],
+                immutable_by_default: false,
+            };
+
+            let mut state = State::new(vec![((*MODULE_ID).clone(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            checker
+                .check_module(&*MODULE_ID, &mut state)
+                .map(|_| (checker, state))
+        }
+
+        #[test]
+        fn generic_alias() {
+            // type Vec<N> = field[N]
+            // Vec<3> is field[3]
+            let (mut checker, state) = check_module_with_alias(
+                vec![GenericDeclaration::constant("N").mock()],
+                UnresolvedType::array(
+                    UnresolvedType::FieldElement.mock(),
+                    Expression::Identifier("N").mock(),
+                )
+                .mock(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                checker.check_type(
+                    UnresolvedType::User(
+                        "Vec".into(),
+                        Some(vec![Some(GenericArgument::Expression(
+                            Expression::U32Constant(3).mock(),
+                        ))])
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &state.types
+                ),
+                Ok(Type::array((Type::FieldElement, 3u32)))
+            );
+
+            assert_eq!(
+                checker
+                    .check_type(
+                        UnresolvedType::User("Vec".into(), None).mock(),
+                        &*MODULE_ID,
+                        &state.types
+                    )
+                    .unwrap_err()
+                    .message,
+                "Expected 1 generic argument on type Vec, but got 0"
+            );
+        }
+
+        #[test]
+        fn unused_generic() {
+            // type Vec<N> = field
+            let errors = check_module_with_alias(
+                vec![GenericDeclaration::constant("N").mock()],
+                UnresolvedType::FieldElement.mock(),
+            )
+            .err()
+            .unwrap();
+
+            assert_eq!(errors[0].inner.message, "Generic parameter N must be used");
+        }
+    }
+
+    mod type_parameters {
+        use super::*;
+
+        /// Helper function to create `def id<type T>(T x) -> T: return x`
+        fn identity() -> FunctionNode<'static> {
+            let mut f = function0();
+
+            f.value.arguments = vec![absy::Parameter::private(
+                absy::Variable::new("x", UnresolvedType::User("T".into(), None).mock()).mock(),
+            )
+            .mock()];
+            f.value.statements = vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![Expression::Identifier("x").mock()],
+                }
+                .mock(),
+            )
+            .mock()];
+            f.value.signature = UnresolvedSignature::new()
+                .generics(vec![GenericDeclaration::type_parameter("T").mock()])
+                .inputs(vec![UnresolvedType::User("T".into(), None).mock()])
+                .outputs(vec![UnresolvedType::User("T".into(), None).mock()]);
+
+            f
+        }
+
+        /// Helper function to create `def main() -> ty: return e`
+        fn main_returning(
+            ty: UnresolvedTypeNode<'static>,
+            e: ExpressionNode<'static>,
+        ) -> FunctionNode<'static> {
+            let mut f = function0();
+
+            f.value.statements = vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![e],
+                }
+                .mock(),
+            )
+            .mock()];
+            f.value.signature = UnresolvedSignature::new().outputs(vec![ty]);
+
+            f
+        }
+
+        /// The key of `def id<type T>(T x) -> T`
+        fn id_key() -> DeclarationFunctionKey<'static> {
+            let t = DeclarationType::Generic(GenericTypeIdentifier::with_name("T").index(0));
+
+            DeclarationFunctionKey::with_location((*MODULE_ID).clone(), "id").signature(
+                DeclarationSignature::new()
+                    .type_generics(vec![t.clone()])
+                    .inputs(vec![t.clone()])
+                    .outputs(vec![t]),
+            )
+        }
+
+        /// The expression returned by `main`
+        fn main_return_expression(
+            state: &State<'static, Bn128Field>,
+        ) -> TypedExpression<'static, Bn128Field> {
+            let main = state.typed_modules[&*MODULE_ID]
+                .functions
+                .iter()
+                .find(|(k, _)| k.id == "main")
+                .unwrap()
+                .1;
+
+            match main {
+                TypedFunctionSymbol::Here(f) => match f.statements.last() {
+                    Some(TypedStatement::Return(expressions)) => expressions[0].clone(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            }
+        }
+
+        fn check_module_with_functions(
+            functions: Vec<(&'static str, FunctionNode<'static>)>,
+        ) -> Result<State<'static, Bn128Field>, Vec<Error>> {
+            let module = Module {
+                symbols: functions
+                    .into_iter()
+                    .map(|(id, f)| {
+                        SymbolDeclaration {
+                            public: false,
+                            id,
+                            symbol: Symbol::Here(SymbolDefinition::Function(f)),
+                        }
+                        .mock()
+                    })
+                    .collect(),
+                immutable_by_default: false,
+            };
+
+            let mut state = State::new(vec![((*MODULE_ID).clone(), module)].into_iter().collect());
+
+            Checker::new()
+                .check_module(&*MODULE_ID, &mut state)
+                .map(|_| state)
+        }
+
+        #[test]
+        fn inferred_type_argument() {
+            // def id<type T>(T x) -> T:
+            //   return x
+            // def main() -> field:
+            //   return id(42f)
+            //
+            // should check `id` once, and call it with `T` bound to `field`
+
+            let state = check_module_with_functions(vec![
+                ("id", identity()),
+                (
+                    "main",
+                    main_returning(
+                        UnresolvedType::FieldElement.mock(),
+                        Expression::FunctionCall(
+                            box Expression::Identifier("id").mock(),
+                            None,
+                            vec![Expression::FieldConstant(42usize.into()).mock()],
+                        )
+                        .mock(),
+                    ),
+                ),
+            ])
+            .unwrap();
+
+            let functions = &state.typed_modules[&*MODULE_ID].functions;
+
+            assert!(matches!(
+                functions.get(&id_key()),
+                Some(TypedFunctionSymbol::Here(..))
+            ));
+            assert_eq!(functions.len(), 2);
+            assert_eq!(
+                main_return_expression(&state),
+                FieldElementExpression::function_call(
+                    id_key(),
+                    vec![],
+                    vec![FieldElementExpression::Number(42usize.into()).into()]
+                )
+                .into()
+            );
+        }
+
+        #[test]
+        fn explicit_type_argument() {
+            // def id<type T>(T x) -> T:
+            //   return x
+            // def main() -> u8:
+            //   return id::<u8>(42)
+            //
+            // should call `id` with `T` bound to `u8`
+
+            let state = check_module_with_functions(vec![
+                ("id", identity()),
+                (
+                    "main",
+                    main_returning(
+                        UnresolvedType::Uint(8).mock(),
+                        Expression::FunctionCall(
+                            box Expression::Identifier("id").mock(),
+                            Some(vec![Some(GenericArgument::Type(
+                                UnresolvedType::Uint(8).mock(),
+                            ))]),
+                            vec![Expression::IntConstant(42usize.into()).mock()],
+                        )
+                        .mock(),
+                    ),
+                ),
+            ])
+            .unwrap();
+
+            assert_eq!(
+                main_return_expression(&state),
+                UExpression::function_call(
+                    id_key(),
+                    vec![],
+                    vec![UExpressionInner::Value(42).annotate(IntBitwidth::B8).into()]
+                )
+                .annotate(IntBitwidth::B8)
+                .into()
+            );
+        }
+
+        #[test]
+        fn integer_literal_is_not_inferred() {
+            // def id<type T>(T x) -> T:
+            //   return x
+            // def main() -> u8:
+            //   return id(42)
+            //
+            // should fail as the type of `42` is not known
+
+            let errors = check_module_with_functions(vec![
+                ("id", identity()),
+                (
+                    "main",
+                    main_returning(
+                        UnresolvedType::Uint(8).mock(),
+                        Expression::FunctionCall(
+                            box Expression::Identifier("id").mock(),
+                            None,
+                            vec![Expression::IntConstant(42usize.into()).mock()],
+                        )
+                        .mock(),
+                    ),
+                ),
+            ])
+            .unwrap_err();
+
+            assert_eq!(
+                errors[0].inner.message,
+                "Failed to infer value for generic parameter `T`, try providing an explicit value"
+            );
+        }
+
+        #[test]
+        fn uncalled_generic_function_is_checked() {
+            // def add<type T>(T a, T b) -> T:
+            //   return a + b
+            // def main() -> field:
+            //   return 1
+            //
+            // should fail as `+` cannot be applied to values of any type, even though `add` is not called
+
+            let t = || UnresolvedType::User("T".into(), None).mock();
+
+            let mut add = function0();
+            add.value.arguments = vec![
+                absy::Parameter::private(absy::Variable::new("a", t()).mock()).mock(),
+                absy::Parameter::private(absy::Variable::new("b", t()).mock()).mock(),
+            ];
+            add.value.statements = vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![Expression::Add(
+                        box Expression::Identifier("a").mock(),
+                        box Expression::Identifier("b").mock(),
+                    )
+                    .mock()],
+                }
+                .mock(),
+            )
+            .mock()];
+            add.value.signature = UnresolvedSignature::new()
+                .generics(vec![GenericDeclaration::type_parameter("T").mock()])
+                .inputs(vec![t(), t()])
+                .outputs(vec![t()]);
+
+            let errors = check_module_with_functions(vec![
+                ("add", add),
+                (
+                    "main",
+                    main_returning(
+                        UnresolvedType::FieldElement.mock(),
+                        Expression::IntConstant(1usize.into()).mock(),
+                    ),
+                ),
+            ])
+            .unwrap_err();
+
+            assert_eq!(errors[0].inner.message, "Cannot apply `+` to T, T");
+        }
+
+        #[test]
+        fn type_parameter_used_as_constant() {
+            // def foo<type T>(field[T] a):
+            //   return
+            //
+            // should fail as T is declared as a type
+
+            let mut foo = function0();
+
+            let t = || {
+                UnresolvedType::array(
+                    UnresolvedType::FieldElement.mock(),
+                    Expression::Identifier("T").mock(),
+                )
+                .mock()
+            };
+
+            foo.value.arguments =
+                vec![absy::Parameter::private(absy::Variable::new("a", t()).mock()).mock()];
+            foo.value.signature = UnresolvedSignature::new()
+                .generics(vec![GenericDeclaration::type_parameter("T").mock()])
+                .inputs(vec![t()]);
+
+            let errors = check_module_with_functions(vec![("foo", foo)]).unwrap_err();
+
+            assert_eq!(
+                errors[0].inner.message,
+                "Expected a constant, found type parameter T"
+            );
+        }
+
+        #[test]
+        fn constant_parameter_used_as_type() {
+            // def foo<N>(N a):
+            //   return
+            //
+            // should fail as N is declared as a constant
+
+            let mut foo = function0();
+
+            let t = || UnresolvedType::User("N".into(), None).mock();
+
+            foo.value.arguments =
+                vec![absy::Parameter::private(absy::Variable::new("a", t()).mock()).mock()];
+            foo.value.signature = UnresolvedSignature::new()
+                .generics(vec![GenericDeclaration::constant("N").mock()])
+                .inputs(vec![t()]);
+
+            let errors = check_module_with_functions(vec![("foo", foo)]).unwrap_err();
+
+            assert_eq!(
+                errors[0].inner.message,
+                "Expected a type, found constant parameter N"
+            );
+        }
+    }
+    mod methods {
+        use super::*;
+
+        /// Helper function to create `struct Foo { field foo }`
+        fn foo_struct() -> SymbolDeclarationNode<'static> {
+            SymbolDeclaration {
+                public: false,
+                id: "Foo",
+                symbol: Symbol::Here(SymbolDefinition::Struct(
+                    StructDefinition {
+                        generics: vec![],
+                        fields: vec![StructDefinitionField {
+                            id: "foo",
+                            ty: UnresolvedType::FieldElement.mock(),
+                        }
+                        .mock()],
+                    }
+                    .mock(),
+                )),
+            }
+            .mock()
+        }
+
+        /// Helper function to create `impl ty { def get(self) -> field: return self.foo }`
+        fn get_impl(ty: &'static str) -> SymbolDeclarationNode<'static> {
+            let mut get = function0();
+
+            get.value.arguments = vec![absy::Parameter::public(
+                absy::Variable::new("self", UnresolvedType::User(ty.into(), None).mock()).mock(),
+            )
+            .mock()];
+            get.value.statements = vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![Expression::Member(
+                        box Expression::Identifier("self").mock(),
+                        box "foo",
+                    )
+                    .mock()],
+                }
+                .mock(),
+            )
+            .mock()];
+            get.value.signature = UnresolvedSignature::new()
+                .inputs(vec![UnresolvedType::User(ty.into(), None).mock()])
+                .outputs(vec![UnresolvedType::FieldElement.mock()]);
+
+            SymbolDeclaration {
+                public: false,
+                id: ty,
+                symbol: Symbol::Here(SymbolDefinition::Impl(
+                    ImplDefinition {
+                        methods: vec![MethodDefinition {
+                            id: "get",
+                            function: get,
+                        }
+                        .mock()],
+                    }
+                    .mock(),
+                )),
+            }
+            .mock()
+        }
+
+        /// Helper function to create `def main(ty x) -> field: return x.method()`
+        fn main_calling(
+            ty: UnresolvedTypeNode<'static>,
+            method: &'static str,
+        ) -> SymbolDeclarationNode<'static> {
+            let mut main = function0();
+
+            main.value.arguments =
+                vec![absy::Parameter::private(absy::Variable::new("x", ty.clone()).mock()).mock()];
+            main.value.statements = vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![Expression::MethodCall(
+                        box Expression::Identifier("x").mock(),
+                        method,
+                        None,
+                        vec![],
+                    )
+                    .mock()],
+                }
+                .mock(),
+            )
+            .mock()];
+            main.value.signature = UnresolvedSignature::new()
+                .inputs(vec![ty])
+                .outputs(vec![UnresolvedType::FieldElement.mock()]);
+
+            SymbolDeclaration {
+                public: false,
+                id: "main",
+                symbol: Symbol::Here(SymbolDefinition::Function(main)),
+            }
+            .mock()
+        }
+
+        fn check_module_with_symbols(
+            symbols: Vec<SymbolDeclarationNode<'static>>,
+        ) -> Result<State<'static, Bn128Field>, Vec<Error>> {
+            let module = Module::with_symbols(symbols);
+
+            let mut state = State::new(vec![((*MODULE_ID).clone(), module)].into_iter().collect());
+
+            Checker::new()
+                .check_module(&*MODULE_ID, &mut state)
+                .map(|_| state)
+        }
+
+        #[test]
+        fn method_call() {
+            // struct Foo { field foo }
+            // impl Foo {
+            //   def get(self) -> field:
+            //     return self.foo
+            // }
+            // def main(Foo x) -> field:
+            //   return x.get()
+            //
+            // should add `get` to the module holding the methods of `Foo`
+
+            let state = check_module_with_symbols(vec![
+                foo_struct(),
+                get_impl("Foo"),
+                main_calling(UnresolvedType::User("Foo".into(), None).mock(), "get"),
+            ])
+            .unwrap();
+
+            let methods = &state.typed_modules[&MODULE_ID.join("Foo")].functions;
+
+            assert_eq!(methods.len(), 1);
+            assert_eq!(methods.keys().next().unwrap().id, "get");
+            // methods are not functions of the module they are declared in
+            assert_eq!(state.typed_modules[&*MODULE_ID].functions.len(), 1);
+        }
+
+        #[test]
+        fn undefined_method() {
+            // struct Foo { field foo }
+            // def main(Foo x) -> field:
+            //   return x.get()
+            //
+            // should fail as `get` is not implemented on `Foo`
+
+            let errors = check_module_with_symbols(vec![
+                foo_struct(),
+                main_calling(UnresolvedType::User("Foo".into(), None).mock(), "get"),
+            ])
+            .unwrap_err();
+
+            assert!(errors[0]
+                .inner
+                .message
+                .starts_with("Method get with signature"));
+        }
+
+        #[test]
+        fn method_on_non_struct() {
+            // def main(field x) -> field:
+            //   return x.get()
+            //
+            // should fail as methods are only implemented on structs
+
+            let errors = check_module_with_symbols(vec![main_calling(
+                UnresolvedType::FieldElement.mock(),
+                "get",
+            )])
+            .unwrap_err();
+
+            assert_eq!(
+                errors[0].inner.message,
+                "Cannot call method get on x of type field, methods are only implemented on structs"
+            );
+        }
+
+        #[test]
+        fn impl_on_undefined_type() {
+            // impl Bar {
+            //   def get(self) -> field:
+            //     return self.foo
+            // }
+            //
+            // should fail as `Bar` is not defined
+
+            let errors = check_module_with_symbols(vec![get_impl("Bar")]).unwrap_err();
+
+            assert_eq!(errors[0].inner.message, "Undefined type Bar");
+        }
+    }
+
+    mod mutability {
+        use super::*;
+
+        /// Helper function to create a checker in a function of a module marked `#pragma immutable_by_default`, in
+        /// which `a` is a `field[2]`
+        fn checker(is_mutable: bool) -> Checker<'static, Bn128Field> {
+            let mut scope = HashSet::new();
+            scope.insert(ScopedVariable {
+                id: Variable::array("a", Type::FieldElement, 2u32),
+                level: 1,
+                is_mutable,
+            });
+
+            let mut checker = new_with_args(scope, 1, HashSet::new());
+            checker.immutable_by_default = true;
+            checker
+        }
+
+        fn assign_a() -> StatementNode<'static> {
+            Statement::Definition(
+                Assignee::Identifier("a").mock(),
+                Expression::Identifier("a").mock(),
+            )
+            .mock()
+        }
+
+        fn assign_a_element() -> StatementNode<'static> {
+            Statement::Definition(
+                Assignee::Select(
+                    box Assignee::Identifier("a").mock(),
+                    box RangeOrExpression::Expression(
+                        Expression::IntConstant(0usize.into()).mock(),
+                    ),
+                )
+                .mock(),
+                Expression::FieldConstant(BigUint::from(1u32)).mock(),
+            )
+            .mock()
+        }
+
+        #[test]
+        fn assign_immutable() {
+            // field[2] a = ...
+            // a = a
+            //
+            // should fail as `a` is not `mut`
+
+            let errors = checker(false)
+                .check_statement(assign_a(), &*MODULE_ID, &TypeMap::new())
+                .unwrap_err();
+
+            assert_eq!(
+                errors[0].message,
+                "Assignment to immutable variable `a`, declare it as `mut` to allow it"
+            );
+        }
+
+        #[test]
+        fn assign_immutable_element() {
+            // field[2] a = ...
+            // a[0] = 1
+            //
+            // should fail as `a` is not `mut`
+
+            let errors = checker(false)
+                .check_statement(assign_a_element(), &*MODULE_ID, &TypeMap::new())
+                .unwrap_err();
+
+            assert_eq!(
+                errors[0].message,
+                "Assignment to immutable variable `a`, declare it as `mut` to allow it"
+            );
+        }
+
+        #[test]
+        fn assign_mutable() {
+            // field[2] mut a = ...
+            // a = a
+            // a[0] = 1
+            //
+            // should succeed as `a` is `mut`
+
+            let mut checker = checker(true);
+
+            assert!(checker
+                .check_statement(assign_a(), &*MODULE_ID, &TypeMap::new())
+                .is_ok());
+            assert!(checker
+                .check_statement(assign_a_element(), &*MODULE_ID, &TypeMap::new())
+                .is_ok());
+        }
+
+        #[test]
+        fn assign_immutable_without_pragma() {
+            // field[2] a = ...
+            // a = a
+            //
+            // should succeed as the module does not make variables immutable by default
+
+            let mut checker = checker(false);
+            checker.immutable_by_default = false;
+
+            assert!(checker
+                .check_statement(assign_a(), &*MODULE_ID, &TypeMap::new())
+                .is_ok());
+        }
+
+        #[test]
+        fn define_immutable() {
+            // field b = 1
+            // b = 2
+            //
+            // should fail on the second statement only
+
+            let mut checker = checker(false);
+
+            let statements = vec![
+                Statement::Declaration(
+                    absy::Variable::new("b", UnresolvedType::FieldElement.mock()).mock(),
+                )
+                .mock(),
+                Statement::Definition(
+                    Assignee::Identifier("b").mock(),
+                    Expression::FieldConstant(BigUint::from(1u32)).mock(),
+                )
+                .mock(),
+            ];
+
+            for statement in statements {
+                assert!(checker
+                    .check_statement(statement, &*MODULE_ID, &TypeMap::new())
+                    .is_ok());
+            }
+
+            let errors = checker
+                .check_statement(
+                    Statement::Definition(
+                        Assignee::Identifier("b").mock(),
+                        Expression::FieldConstant(BigUint::from(2u32)).mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap_err();
+
+            assert_eq!(
+                errors[0].message,
+                "Assignment to immutable variable `b`, declare it as `mut` to allow it"
+            );
+        }
+
+        fn declare_b() -> StatementNode<'static> {
+            Statement::Declaration(
+                absy::Variable::new("b", UnresolvedType::FieldElement.mock()).mock(),
+            )
+            .mock()
+        }
+
+        fn define_b(value: u32) -> StatementNode<'static> {
+            Statement::Definition(
+                Assignee::Identifier("b").mock(),
+                Expression::FieldConstant(BigUint::from(value)).mock(),
+            )
+            .mock()
+        }
+
+        #[test]
+        fn define_immutable_in_both_branches() {
+            // field b
+            // if true then b = 1 else b = 2 fi
+            // b = 3
+            //
+            // should fail on the last statement only
+
+            let mut checker = checker(false);
+
+            let statements = vec![
+                declare_b(),
+                Statement::IfElse(
+                    Expression::BooleanConstant(true).mock(),
+                    vec![define_b(1)],
+                    vec![define_b(2)],
+                )
+                .mock(),
+            ];
+
+            for statement in statements {
+                assert!(checker
+                    .check_statement(statement, &*MODULE_ID, &TypeMap::new())
+                    .is_ok());
+            }
+
+            let errors = checker
+                .check_statement(define_b(3), &*MODULE_ID, &TypeMap::new())
+                .unwrap_err();
+
+            assert_eq!(
+                errors[0].message,
+                "Assignment to immutable variable `b`, declare it as `mut` to allow it"
+            );
+        }
+
+        #[test]
+        fn define_immutable_in_one_branch() {
+            // field b
+            // if c then b = 1 else fi
+            // b = 2
+            //
+            // should fail on the last statement, as `b` would be defined twice when `c` is true
+
+            let mut checker = checker(false);
+
+            let statements = vec![
+                declare_b(),
+                Statement::IfElse(
+                    Expression::BooleanConstant(true).mock(),
+                    vec![define_b(1)],
+                    vec![],
+                )
+                .mock(),
+            ];
+
+            for statement in statements {
+                assert!(checker
+                    .check_statement(statement, &*MODULE_ID, &TypeMap::new())
+                    .is_ok());
+            }
+
+            let errors = checker
+                .check_statement(define_b(2), &*MODULE_ID, &TypeMap::new())
+                .unwrap_err();
+
+            assert_eq!(
+                errors[0].message,
+                "Assignment to immutable variable `b`, declare it as `mut` to allow it"
+            );
+        }
+
+        #[test]
+        fn define_immutable_in_neither_branch() {
+            // field b
+            // if c then else fi
+            // b = 1
+            //
+            // should succeed
+
+            let mut checker = checker(false);
+
+            let statements = vec![
+                declare_b(),
+                Statement::IfElse(Expression::BooleanConstant(true).mock(), vec![], vec![]).mock(),
+                define_b(1),
+            ];
+
+            for statement in statements {
+                assert!(checker
+                    .check_statement(statement, &*MODULE_ID, &TypeMap::new())
+                    .is_ok());
+            }
+        }
+
+        #[test]
+        fn define_immutable_in_loop() {
+            // field b
+            // for u32 i in 0..3 do
+            //   b = 1
+            // endfor
+            //
+            // should fail as `b` would be defined at each iteration
+
+            let mut checker = checker(false);
+
+            assert!(checker
+                .check_statement(declare_b(), &*MODULE_ID, &TypeMap::new())
+                .is_ok());
+
+            let errors = checker
+                .check_statement(
+                    Statement::For(
+                        absy::Variable::new("i", UnresolvedType::Uint(32).mock()).mock(),
+                        Expression::IntConstant(0usize.into()).mock(),
+                        Expression::IntConstant(3usize.into()).mock(),
+                        vec![define_b(1)],
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap_err();
+
+            assert_eq!(
+                errors[0].message,
+                "Assignment to immutable variable `b`, declare it as `mut` to allow it"
+            );
+        }
+
+        #[test]
+        fn define_immutable_declared_in_loop() {
+            // for u32 i in 0..3 do
+            //   field b
+            //   b = 1
+            // endfor
+            //
+            // should succeed as `b` is declared at each iteration
+
+            assert!(checker(false)
+                .check_statement(
+                    Statement::For(
+                        absy::Variable::new("i", UnresolvedType::Uint(32).mock()).mock(),
+                        Expression::IntConstant(0usize.into()).mock(),
+                        Expression::IntConstant(3usize.into()).mock(),
+                        vec![declare_b(), define_b(1)],
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .is_ok());
+        }
+    }
+
+    mod strings {
+        use super::*;
+
+        #[test]
+        fn byte_string() {
+            // b"ab" should be checked as the u8[2] array [97, 98]
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert_eq!(
+                checker.check_expression(
+                    Expression::ByteStringConstant(b"ab".to_vec()).mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                ),
+                Ok(ArrayExpressionInner::Value(
+                    vec![
+                        TypedExpressionOrSpread::Expression(
+                            UExpressionInner::Value(97).annotate(IntBitwidth::B8).into()
+                        ),
+                        TypedExpressionOrSpread::Expression(
+                            UExpressionInner::Value(98).annotate(IntBitwidth::B8).into()
+                        ),
+                    ]
+                    .into()
+                )
+                .annotate(Type::Uint(IntBitwidth::B8), 2u32)
+                .into())
+            );
+        }
+
+        #[test]
+        fn empty_byte_string() {
+            // b"" should fail as empty arrays are not allowed
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::ByteStringConstant(vec![]).mock(),
+                        &*MODULE_ID,
+                        &TypeMap::new()
+                    )
+                    .unwrap_err()
+                    .message,
+                "Empty strings are not allowed"
+            );
+        }
+    }
+
+    mod comprehensions {
+        use super::*;
+
+        fn comprehension(ty: UnresolvedType<'static>) -> ExpressionNode<'static> {
+            // [i for <ty> i in 0..3]
+            Expression::ArrayComprehension(
+                box Expression::Identifier("i").mock(),
+                box absy::Variable::new("i", ty.mock()).mock(),
+                box Expression::IntConstant(0usize.into()).mock(),
+                box Expression::IntConstant(3usize.into()).mock(),
+            )
+            .mock()
+        }
+
+        #[test]
+        fn comprehension_of_index() {
+            // [i for u32 i in 0..3] should be checked as a u32 array of size 3 - 0
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert_eq!(
+                checker.check_expression(
+                    comprehension(UnresolvedType::Uint(32)),
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                ),
+                Ok(ArrayExpressionInner::Comprehension(
+                    box UExpressionInner::Identifier("i".into())
+                        .annotate(IntBitwidth::B32)
+                        .into(),
+                    typed_absy::Variable::uint("i", IntBitwidth::B32),
+                    box 0u32.into(),
+                    box 3u32.into(),
+                )
+                .annotate(
+                    Type::Uint(IntBitwidth::B32),
+                    UExpression::floor_sub(3u32.into(), 0u32.into())
+                )
+                .into())
+            );
+        }
+
+        #[test]
+        fn comprehension_shadowing_variable_of_other_type() {
+            // [i for u32 i in 0..3] where `i` is a field element should be an array of u32
+
+            let mut scope = HashSet::new();
+            scope.insert(ScopedVariable {
+                id: Variable::field_element("i"),
+                level: 1,
+                is_mutable: false,
+            });
+
+            let mut checker: Checker<Bn128Field> = new_with_args(scope, 1, HashSet::new());
+
+            let e = checker
+                .check_expression(
+                    comprehension(UnresolvedType::Uint(32)),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            match e {
+                TypedExpression::Array(a) => {
+                    assert_eq!(*a.inner_type(), Type::Uint(IntBitwidth::B32))
+                }
+                _ => unreachable!(),
+            }
+
+            // the outer variable is visible again after the comprehension
+            assert_eq!(
+                checker.check_expression(
+                    Expression::Identifier("i").mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                ),
+                Ok(FieldElementExpression::Identifier("i".into()).into())
+            );
+        }
+
+        #[test]
+        fn index_out_of_scope() {
+            // the index of a comprehension is not visible after it
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert!(checker
+                .check_expression(
+                    comprehension(UnresolvedType::Uint(32)),
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                )
+                .is_ok());
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::Identifier("i").mock(),
+                        &*MODULE_ID,
+                        &TypeMap::new()
+                    )
+                    .unwrap_err()
+                    .message,
+                "Identifier \"i\" is undefined"
+            );
+        }
+
+        #[test]
+        fn field_index() {
+            // [i for field i in 0..3] should fail as the index must be a u32
+
+            let mut checker: Checker<Bn128Field> = Checker::new();
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        comprehension(UnresolvedType::FieldElement),
+                        &*MODULE_ID,
+                        &TypeMap::new()
+                    )
+                    .unwrap_err()
+                    .message,
+                "Variable in for loop cannot have type field"
+            );
+        }
+    }
+
+    mod bit_access {
+        use super::*;
+
+        fn bits(from: usize, to: usize) -> ExpressionNode<'static> {
+            // 42u32[from..to]
+            Expression::Select(
+                box Expression::U32Constant(42).mock(),
+                box RangeOrExpression::Range(
+                    absy::Range {
+                        from: Some(Expression::IntConstant(from.into()).mock()),
+                        to: Some(Expression::IntConstant(to.into()).mock()),
+                    }
+                    .mock(),
+                ),
+            )
+            .mock()
+        }
+
+        #[test]
+        fn slice() {
+            // 42u32[24..32] should be a u8
+            assert_eq!(
+                Checker::<Bn128Field>::new().check_expression(
+                    bits(24, 32),
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                ),
+                Ok(UExpressionInner::Slice(
+                    box UExpressionInner::Value(42).annotate(IntBitwidth::B32),
+                    24,
+                    32
+                )
+                .annotate(IntBitwidth::B8)
+                .into())
+            );
+        }
+
+        #[test]
+        fn slice_of_invalid_width() {
+            // 42u32[0..12] should fail as there is no 12 bit integer type
+            assert_eq!(
+                Checker::<Bn128Field>::new()
+                    .check_expression(bits(0, 12), &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()
+                    .message,
+                "Cannot slice bits 0..12 of expression 42 of type u32, expected a range of 8, 16, 32, 64 or 128 bits within the value"
+            );
+        }
+
+        #[test]
+        fn bit_out_of_bounds() {
+            // 42u32[32] should fail as a u32 has 32 bits
+            let e = Expression::Select(
+                box Expression::U32Constant(42).mock(),
+                box RangeOrExpression::Expression(Expression::IntConstant(32usize.into()).mock()),
+            )
+            .mock();
+
+            assert_eq!(
+                Checker::<Bn128Field>::new()
+                    .check_expression(e, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()
+                    .message,
+                "Bit index 32 is out of bounds for expression 42 of type u32"
+            );
+        }
+    }
+
+    mod warnings {
+        use super::*;
+
+        fn messages(warnings: &[WarningInner]) -> Vec<&str> {
+            warnings.iter().map(|w| w.message()).collect()
+        }
+
+        #[test]
+        fn unused_variable() {
+            // field a
+            // `a` is never read
+
+            let mut checker: Checker<Bn128Field> = new_with_args(HashSet::new(), 0, HashSet::new());
+
+            checker.enter_scope();
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+            checker.exit_scope();
+
+            assert_eq!(messages(&checker.warnings), vec!["Unused variable `a`"]);
+        }
+
+        #[test]
+        fn read_variable() {
+            // field a
+            // a
+            // `a` is read
+
+            let mut checker: Checker<Bn128Field> = new_with_args(HashSet::new(), 0, HashSet::new());
+
+            checker.enter_scope();
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+            checker
+                .check_expression(
+                    Expression::Identifier("a").mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+            checker.exit_scope();
+
+            assert!(checker.warnings.is_empty());
+        }
+
+        #[test]
+        fn loop_variable_shadowing() {
+            // [i for u32 i in 0..3] where `i` is already defined
+
+            let mut scope = HashSet::new();
+            scope.insert(ScopedVariable {
+                id: Variable::field_element("i"),
+                level: 1,
+                is_mutable: false,
+            });
+
+            let mut checker: Checker<Bn128Field> = new_with_args(scope, 1, HashSet::new());
+
+            checker
+                .check_expression(
+                    Expression::ArrayComprehension(
+                        box Expression::Identifier("i").mock(),
+                        box absy::Variable::new("i", UnresolvedType::Uint(32).mock()).mock(),
+                        box Expression::IntConstant(0usize.into()).mock(),
+                        box Expression::IntConstant(3usize.into()).mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                messages(&checker.warnings),
+                vec!["Loop variable `i` shadows a variable of the same name"]
+            );
+        }
+
+        #[test]
+        fn unused_function() {
+            // def foo():
+            //   return
+            // def main():
+            //   return
+            // `foo` is never called
+
+            let symbols = vec![
+                SymbolDeclaration {
+                    public: false,
+                    id: "foo",
+                    symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+                }
+                .mock(),
+                SymbolDeclaration {
+                    public: false,
+                    id: "main",
+                    symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+                }
+                .mock(),
+            ];
+
+            let program = Program {
+                modules: vec![((*MODULE_ID).clone(), Module::with_symbols(symbols))]
+                    .into_iter()
+                    .collect(),
+                main: (*MODULE_ID).clone(),
+            };
+
+            let (_, warnings) = Checker::<Bn128Field>::new().check_program(program).unwrap();
+
+            assert_eq!(
+                warnings,
+                vec![Warning {
+                    inner: WarningInner {
+                        pos: Some((Position::mock(), Position::mock())),
+                        message: "Unused function `foo`".into()
+                    },
+                    module_id: (*MODULE_ID).clone()
+                }]
+            );
+        }
+
+        #[test]
+        fn unused_import_shadowed_by_variable() {
+            // // bar.zok
+            // def main():
+            //   return
+            //
+            // // main.zok
+            // from "bar" import main as foo
+            // def main():
+            //   field foo = 1
+            //   assert(foo == 1)
+            //   return
+            //
+            // the variable `foo` is read, but the import `foo` is never used
+
+            let bar = Module::with_symbols(vec![SymbolDeclaration {
+                public: false,
+                id: "main",
+                symbol: Symbol::Here(SymbolDefinition::Function(function0())),
+            }
+            .mock()]);
+
+            let statements = vec![
+                Statement::Declaration(
+                    absy::Variable::new("foo", UnresolvedType::FieldElement.mock()).mock(),
+                )
+                .mock(),
+                Statement::Definition(
+                    Assignee::Identifier("foo").mock(),
+                    Expression::FieldConstant(BigUint::from(1u32)).mock(),
+                )
+                .mock(),
+                Statement::Assertion(
+                    Expression::Eq(
+                        box Expression::Identifier("foo").mock(),
+                        box Expression::FieldConstant(BigUint::from(1u32)).mock(),
+                    )
+                    .mock(),
+                    None,
+                )
+                .mock(),
+                Statement::Return(
+                    ExpressionList {
+                        expressions: vec![],
+                    }
+                    .mock(),
+                )
+                .mock(),
+            ];
+
+            let main = Module::with_symbols(vec![
+                SymbolDeclaration {
+                    public: false,
+                    id: "foo",
+                    symbol: Symbol::There(SymbolImport::with_id_in_module("main", "bar").mock()),
+                }
+                .mock(),
+                SymbolDeclaration {
+                    public: false,
+                    id: "main",
+                    symbol: Symbol::Here(SymbolDefinition::Function(
+                        Function {
+                            arguments: vec![],
+                            statements,
+                            signature: UnresolvedSignature::new(),
+                        }
+                        .mock(),
+                    )),
+                }
+                .mock(),
+            ]);
+
+            let program = Program {
+                modules: vec![((*MODULE_ID).clone(), main), ("bar".into(), bar)]
+                    .into_iter()
+                    .collect(),
+                main: (*MODULE_ID).clone(),
+            };
+
+            let (_, warnings) = Checker::<Bn128Field>::new().check_program(program).unwrap();
+
+            assert_eq!(
+                warnings,
+                vec![Warning {
+                    inner: WarningInner {
+                        pos: Some((Position::mock(), Position::mock())),
+                        message: "Unused import `foo`".into()
+                    },
+                    module_id: (*MODULE_ID).clone()
+                }]
+            );
+        }
     }
 }
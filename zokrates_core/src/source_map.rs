@@ -0,0 +1,265 @@
+//! Module containing the structures used to map compiled statements back to the source code
+//!
+//! During compilation, each source statement is preceded by a marker holding its `SourceLocation`.
+//! Once functions are inlined, markers also hold the `CallStack` leading to the statement.
+//! These markers are carried through the intermediate representations and collected into a
+//! `SourceMap` when the flat program is converted to `ir`.
+
+use crate::parser::Position;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A location in the source code, the start of a statement in a given file
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct SourceLocation {
+    pub file: String,
+    pub position: Position,
+}
+
+impl SourceLocation {
+    pub fn new<S: Into<String>>(file: S, position: Position) -> Self {
+        SourceLocation {
+            file: file.into(),
+            position,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        SourceLocation::new("main.zok", Position::mock())
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.position)
+    }
+}
+
+/// A function being executed, along with the location it was called from, if any
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct Frame {
+    pub function: String,
+    pub call_site: Option<SourceLocation>,
+}
+
+impl Frame {
+    pub fn new<S: Into<String>>(function: S, call_site: Option<SourceLocation>) -> Self {
+        Frame {
+            function: function.into(),
+            call_site,
+        }
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.call_site {
+            Some(call_site) => write!(f, "{} (called at {})", self.function, call_site),
+            None => write!(f, "{}", self.function),
+        }
+    }
+}
+
+/// The frames leading to a statement, outermost first
+pub type CallStack = Vec<Frame>;
+
+/// A side table mapping each statement of a program to the location it was compiled from, if any
+/// File names and call stacks are stored once and referred to by index
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SourceMap {
+    files: Vec<String>,
+    stacks: Vec<CallStack>,
+    locations: Vec<Option<Entry>>,
+    /// The index of each call stack in `stacks`. It is not serialized, and rebuilt when a statement is pushed
+    #[serde(skip)]
+    stack_indices: HashMap<CallStack, usize>,
+}
+
+// `stack_indices` is derived from `stacks`, so it is left out of comparisons
+impl PartialEq for SourceMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.files == other.files
+            && self.stacks == other.stacks
+            && self.locations == other.locations
+    }
+}
+
+impl Eq for SourceMap {}
+
+impl Hash for SourceMap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.files.hash(state);
+        self.stacks.hash(state);
+        self.locations.hash(state);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+struct Entry {
+    file: usize,
+    position: Position,
+    stack: usize,
+}
+
+impl SourceMap {
+    /// Register the location of the next statement, along with the call stack leading to it
+    pub fn push(&mut self, location: Option<(&SourceLocation, &[Frame])>) {
+        let entry = location.map(|(location, stack)| {
+            let file = match self.files.iter().position(|f| *f == location.file) {
+                Some(index) => index,
+                None => {
+                    self.files.push(location.file.clone());
+                    self.files.len() - 1
+                }
+            };
+
+            // the index is missing if this map was deserialized
+            if self.stack_indices.len() != self.stacks.len() {
+                self.stack_indices = self
+                    .stacks
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .map(|(index, stack)| (stack, index))
+                    .collect();
+            }
+
+            let stack = match self.stack_indices.get(stack) {
+                Some(index) => *index,
+                None => {
+                    self.stacks.push(stack.to_vec());
+                    self.stack_indices
+                        .insert(stack.to_vec(), self.stacks.len() - 1);
+                    self.stacks.len() - 1
+                }
+            };
+
+            Entry {
+                file,
+                position: location.position,
+                stack,
+            }
+        });
+
+        self.locations.push(entry);
+    }
+
+    /// Get the location of the statement at `index`, if any
+    pub fn get(&self, index: usize) -> Option<SourceLocation> {
+        self.entry(index)
+            .map(|e| SourceLocation::new(self.files[e.file].clone(), e.position))
+    }
+
+    /// Get the call stack leading to the statement at `index`, if any
+    pub fn stack(&self, index: usize) -> Option<&[Frame]> {
+        self.entry(index).map(|e| self.stacks[e.stack].as_slice())
+    }
+
+    fn entry(&self, index: usize) -> Option<&Entry> {
+        self.locations.get(index).and_then(|e| e.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.locations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    /// Realign this map after a transformation where the statement at index `i` was replaced by `counts[i]` statements
+    pub fn realign(self, counts: &[usize]) -> Self {
+        assert_eq!(self.locations.len(), counts.len());
+
+        SourceMap {
+            locations: self
+                .locations
+                .into_iter()
+                .zip(counts)
+                .flat_map(|(location, count)| std::iter::repeat(location).take(*count))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_get() {
+        let mut map = SourceMap::default();
+
+        let a = SourceLocation::new("a.zok", Position { line: 1, col: 5 });
+        let b = SourceLocation::new("b.zok", Position { line: 2, col: 5 });
+        let c = SourceLocation::new("a.zok", Position { line: 3, col: 5 });
+
+        let main = vec![Frame::new("a.zok/main", None)];
+        let call = vec![
+            Frame::new("a.zok/main", None),
+            Frame::new("b.zok/main", Some(c.clone())),
+        ];
+
+        map.push(Some((&a, &main)));
+        map.push(None);
+        map.push(Some((&b, &call)));
+        map.push(Some((&c, &main)));
+
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.files.len(), 2);
+        assert_eq!(map.stacks.len(), 2);
+
+        assert_eq!(map.get(0), Some(a));
+        assert_eq!(map.get(1), None);
+        assert_eq!(map.get(2), Some(b));
+        assert_eq!(map.get(3), Some(c));
+        assert_eq!(map.get(4), None);
+
+        assert_eq!(map.stack(0), Some(main.as_slice()));
+        assert_eq!(map.stack(1), None);
+        assert_eq!(map.stack(2), Some(call.as_slice()));
+        assert_eq!(map.stack(3), Some(main.as_slice()));
+    }
+
+    #[test]
+    fn realign() {
+        let mut map = SourceMap::default();
+
+        let a = SourceLocation::new("a.zok", Position { line: 1, col: 5 });
+        let b = SourceLocation::new("a.zok", Position { line: 2, col: 5 });
+
+        map.push(Some((&a, &[])));
+        map.push(None);
+        map.push(Some((&b, &[])));
+
+        // the first statement is removed, the second one is kept and the last one is expanded to two statements
+        let map = map.realign(&[0, 1, 2]);
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(0), None);
+        assert_eq!(map.get(1), Some(b.clone()));
+        assert_eq!(map.get(2), Some(b));
+    }
+
+    #[test]
+    fn push_after_deserialization() {
+        let mut map = SourceMap::default();
+
+        let a = SourceLocation::new("a.zok", Position { line: 1, col: 5 });
+        let main = vec![Frame::new("a.zok/main", None)];
+
+        map.push(Some((&a, &main)));
+
+        let mut map: SourceMap =
+            serde_json::from_str(&serde_json::to_string(&map).unwrap()).unwrap();
+
+        map.push(Some((&a, &main)));
+
+        assert_eq!(map.stacks.len(), 1);
+        assert_eq!(map.stack(1), Some(main.as_slice()));
+    }
+}
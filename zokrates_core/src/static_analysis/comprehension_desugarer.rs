@@ -0,0 +1,183 @@
+// Desugar array comprehensions into for-loops, so that they get unrolled by the reducer.
+
+// `[e for u32 i in from..to]` becomes
+// {
+//     #COMPREHENSION_n = [0; to - from]
+//     for u32 i in from..to do
+//         #COMPREHENSION_n[i - from] = e
+//     endfor
+//     #COMPREHENSION_n
+// }
+// where the zero value depends on the type of `e`. Each comprehension gets its own identifier, so that nested
+// comprehensions do not write to the array of the enclosing one.
+
+use crate::typed_absy::folder::*;
+use crate::typed_absy::*;
+use zokrates_field::Field;
+
+#[derive(Default)]
+pub struct ComprehensionDesugarer {
+    count: usize,
+}
+
+impl ComprehensionDesugarer {
+    pub fn desugar<T: Field>(p: TypedProgram<T>) -> TypedProgram<T> {
+        ComprehensionDesugarer::default().fold_program(p)
+    }
+}
+
+/// The value an array built by a comprehension is initialized to, before its elements are written
+fn zero<T: Field>(ty: Type<T>) -> TypedExpression<T> {
+    match ty {
+        Type::FieldElement => FieldElementExpression::Number(T::from(0)).into(),
+        Type::Boolean => BooleanExpression::Value(false).into(),
+        Type::Uint(bitwidth) => UExpressionInner::Value(0).annotate(bitwidth).into(),
+        Type::Array(array_ty) => {
+            ArrayExpressionInner::Repeat(box zero(*array_ty.ty.clone()), box array_ty.size.clone())
+                .annotate(*array_ty.ty, array_ty.size)
+                .into()
+        }
+        Type::Struct(struct_ty) => StructExpressionInner::Value(
+            struct_ty
+                .members
+                .iter()
+                .map(|m| zero(*m.ty.clone()))
+                .collect(),
+        )
+        .annotate(struct_ty)
+        .into(),
+        Type::Tuple(tuple_ty) => {
+            TupleExpressionInner::Value(tuple_ty.elements.iter().cloned().map(zero).collect())
+                .annotate(tuple_ty)
+                .into()
+        }
+        // default to the first variant
+        Type::Enum(enum_ty) => EnumExpressionInner::Value(
+            0,
+            enum_ty.variants[0]
+                .payload
+                .elements
+                .iter()
+                .cloned()
+                .map(zero)
+                .collect(),
+        )
+        .annotate(enum_ty)
+        .into(),
+        Type::Int | Type::Generic(..) => unreachable!(),
+    }
+}
+
+impl<'ast, T: Field> Folder<'ast, T> for ComprehensionDesugarer {
+    fn fold_array_expression_inner(
+        &mut self,
+        ty: &ArrayType<'ast, T>,
+        e: ArrayExpressionInner<'ast, T>,
+    ) -> ArrayExpressionInner<'ast, T> {
+        match e {
+            ArrayExpressionInner::Comprehension(box e, v, box from, box to) => {
+                // desugar the comprehensions nested in this one first
+                let e = self.fold_expression(e);
+                let from = self.fold_uint_expression(from);
+                let to = self.fold_uint_expression(to);
+
+                let array = Variable::with_id_and_type(
+                    CoreIdentifier::Comprehension(self.count),
+                    Type::Array(ty.clone()),
+                );
+                self.count += 1;
+
+                let index = UExpressionInner::Identifier(v.id.clone()).annotate(IntBitwidth::B32)
+                    - from.clone();
+
+                let statements = vec![
+                    TypedStatement::Definition(array.clone().into(), zero(Type::Array(ty.clone()))),
+                    TypedStatement::For(
+                        v,
+                        from,
+                        to,
+                        vec![TypedStatement::Definition(
+                            TypedAssignee::Select(box array.clone().into(), box index),
+                            e,
+                        )],
+                    ),
+                ];
+
+                ArrayExpressionInner::Block(BlockExpression::new(
+                    statements,
+                    ArrayExpressionInner::Identifier(array.id)
+                        .annotate(*ty.ty.clone(), ty.size.clone()),
+                ))
+            }
+            e => fold_array_expression_inner(self, ty, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zokrates_field::Bn128Field;
+
+    #[test]
+    fn desugar_comprehension() {
+        // [i for u32 i in 0..3]
+        // {
+        //     #COMPREHENSION_0 = [0; 3]
+        //     for u32 i in 0..3 do
+        //         #COMPREHENSION_0[i - 0] = i
+        //     endfor
+        //     #COMPREHENSION_0
+        // }
+
+        let ty = ArrayType::new(Type::Uint(IntBitwidth::B32), 3u32);
+
+        let e = ArrayExpressionInner::<Bn128Field>::Comprehension(
+            box UExpressionInner::Identifier("i".into())
+                .annotate(IntBitwidth::B32)
+                .into(),
+            Variable::uint("i", IntBitwidth::B32),
+            box 0u32.into(),
+            box 3u32.into(),
+        );
+
+        let array =
+            Variable::with_id_and_type(CoreIdentifier::Comprehension(0), Type::Array(ty.clone()));
+
+        let expected = ArrayExpressionInner::Block(BlockExpression::new(
+            vec![
+                TypedStatement::Definition(
+                    array.clone().into(),
+                    ArrayExpressionInner::Repeat(
+                        box UExpressionInner::Value(0).annotate(IntBitwidth::B32).into(),
+                        box 3u32.into(),
+                    )
+                    .annotate(Type::Uint(IntBitwidth::B32), 3u32)
+                    .into(),
+                ),
+                TypedStatement::For(
+                    Variable::uint("i", IntBitwidth::B32),
+                    0u32.into(),
+                    3u32.into(),
+                    vec![TypedStatement::Definition(
+                        TypedAssignee::Select(
+                            box array.clone().into(),
+                            box (UExpressionInner::Identifier("i".into())
+                                .annotate(IntBitwidth::B32)
+                                - 0u32.into()),
+                        ),
+                        UExpressionInner::Identifier("i".into())
+                            .annotate(IntBitwidth::B32)
+                            .into(),
+                    )],
+                ),
+            ],
+            ArrayExpressionInner::Identifier(array.id).annotate(Type::Uint(IntBitwidth::B32), 3u32),
+        ));
+
+        assert_eq!(
+            ComprehensionDesugarer::default().fold_array_expression_inner(&ty, e),
+            expected
+        );
+    }
+}
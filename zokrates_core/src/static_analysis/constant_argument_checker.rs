@@ -2,8 +2,10 @@ use crate::embed::FlatEmbed;
 use crate::typed_absy::TypedProgram;
 use crate::typed_absy::{
     result_folder::ResultFolder,
-    result_folder::{fold_expression_list_inner, fold_uint_expression_inner},
-    Constant, TypedExpressionListInner, Types, UBitwidth, UExpressionInner,
+    result_folder::{
+        fold_boolean_expression, fold_expression_list_inner, fold_uint_expression_inner,
+    },
+    BooleanExpression, Constant, IntBitwidth, TypedExpressionListInner, Types, UExpressionInner,
 };
 use std::fmt;
 use zokrates_field::Field;
@@ -30,7 +32,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ConstantArgumentChecker {
 
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntBitwidth,
         e: UExpressionInner<'ast, T>,
     ) -> Result<UExpressionInner<'ast, T>, Error> {
         match e {
@@ -43,7 +45,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ConstantArgumentChecker {
                     by => Err(Error(format!(
                         "Cannot shift by a variable value, found `{} << {}`",
                         e,
-                        by.clone().annotate(UBitwidth::B32)
+                        by.clone().annotate(IntBitwidth::B32)
                     ))),
                 }
             }
@@ -56,7 +58,33 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ConstantArgumentChecker {
                     by => Err(Error(format!(
                         "Cannot shift by a variable value, found `{} >> {}`",
                         e,
-                        by.clone().annotate(UBitwidth::B32)
+                        by.clone().annotate(IntBitwidth::B32)
+                    ))),
+                }
+            }
+            UExpressionInner::LeftRotate(box e, box by) => {
+                let e = self.fold_uint_expression(e)?;
+                let by = self.fold_uint_expression(by)?;
+
+                match by.as_inner() {
+                    UExpressionInner::Value(_) => Ok(UExpressionInner::LeftRotate(box e, box by)),
+                    by => Err(Error(format!(
+                        "Cannot rotate by a variable value, found `{} <<< {}`",
+                        e,
+                        by.clone().annotate(IntBitwidth::B32)
+                    ))),
+                }
+            }
+            UExpressionInner::RightRotate(box e, box by) => {
+                let e = self.fold_uint_expression(e)?;
+                let by = self.fold_uint_expression(by)?;
+
+                match by.as_inner() {
+                    UExpressionInner::Value(_) => Ok(UExpressionInner::RightRotate(box e, box by)),
+                    by => Err(Error(format!(
+                        "Cannot rotate by a variable value, found `{} >>> {}`",
+                        e,
+                        by.clone().annotate(IntBitwidth::B32)
                     ))),
                 }
             }
@@ -64,6 +92,35 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ConstantArgumentChecker {
         }
     }
 
+    fn fold_boolean_expression(
+        &mut self,
+        e: BooleanExpression<'ast, T>,
+    ) -> Result<BooleanExpression<'ast, T>, Error> {
+        match e {
+            BooleanExpression::UintBit(box e, box index) => {
+                let e = self.fold_uint_expression(e)?;
+                let index = self.fold_uint_expression(index)?;
+
+                match index.as_inner() {
+                    UExpressionInner::Value(i) if *i < e.bitwidth().to_usize() as u128 => {
+                        Ok(BooleanExpression::UintBit(box e, box index))
+                    }
+                    UExpressionInner::Value(i) => Err(Error(format!(
+                        "Bit index {} is out of bounds for `{}` of type {}",
+                        i,
+                        e,
+                        e.bitwidth().type_name()
+                    ))),
+                    _ => Err(Error(format!(
+                        "Cannot access a bit at a variable index, found `{}[{}]`",
+                        e, index
+                    ))),
+                }
+            }
+            e => fold_boolean_expression(self, e),
+        }
+    }
+
     fn fold_expression_list_inner(
         &mut self,
         tys: &Types<'ast, T>,
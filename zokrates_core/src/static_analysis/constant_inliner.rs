@@ -226,7 +226,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ConstantInliner<'ast, T> {
 
     fn fold_uint_expression_inner(
         &mut self,
-        size: UBitwidth,
+        size: IntBitwidth,
         e: UExpressionInner<'ast, T>,
     ) -> Result<UExpressionInner<'ast, T>, Self::Error> {
         match e {
@@ -277,6 +277,42 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ConstantInliner<'ast, T> {
             e => fold_struct_expression_inner(self, ty, e),
         }
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> Result<TupleExpressionInner<'ast, T>, Self::Error> {
+        match e {
+            TupleExpressionInner::Identifier(ref id) => {
+                match self.get_constant_for_identifier(id) {
+                    Some(c) => {
+                        let e: TupleExpression<'ast, T> = c.try_into().unwrap();
+                        Ok(e.into_inner())
+                    }
+                    None => fold_tuple_expression_inner(self, ty, e),
+                }
+            }
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> Result<EnumExpressionInner<'ast, T>, Self::Error> {
+        match e {
+            EnumExpressionInner::Identifier(ref id) => match self.get_constant_for_identifier(id) {
+                Some(c) => {
+                    let e: EnumExpression<'ast, T> = c.try_into().unwrap();
+                    Ok(e.into_inner())
+                }
+                None => fold_enum_expression_inner(self, ty, e),
+            },
+            e => fold_enum_expression_inner(self, ty, e),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -480,22 +516,22 @@ mod tests {
             statements: vec![TypedStatement::Return(vec![UExpressionInner::Identifier(
                 Identifier::from(const_id),
             )
-            .annotate(UBitwidth::B32)
+            .annotate(IntBitwidth::B32)
             .into()])],
             signature: DeclarationSignature::new()
                 .inputs(vec![])
-                .outputs(vec![DeclarationType::Uint(UBitwidth::B32)]),
+                .outputs(vec![DeclarationType::Uint(IntBitwidth::B32)]),
         };
 
         let constants: TypedConstantSymbols<_> = vec![(
             CanonicalConstantIdentifier::new(
                 const_id,
                 "main".into(),
-                DeclarationType::Uint(UBitwidth::B32),
+                DeclarationType::Uint(IntBitwidth::B32),
             ),
             TypedConstantSymbol::Here(TypedConstant::new(
                 UExpressionInner::Value(1u128)
-                    .annotate(UBitwidth::B32)
+                    .annotate(IntBitwidth::B32)
                     .into(),
             )),
         )]
@@ -511,7 +547,7 @@ mod tests {
                         DeclarationFunctionKey::with_location("main", "main").signature(
                             DeclarationSignature::new()
                                 .inputs(vec![])
-                                .outputs(vec![DeclarationType::Uint(UBitwidth::B32)]),
+                                .outputs(vec![DeclarationType::Uint(IntBitwidth::B32)]),
                         ),
                         TypedFunctionSymbol::Here(main),
                     )]
@@ -529,11 +565,11 @@ mod tests {
         let expected_main = TypedFunction {
             arguments: vec![],
             statements: vec![TypedStatement::Return(vec![UExpressionInner::Value(1u128)
-                .annotate(UBitwidth::B32)
+                .annotate(IntBitwidth::B32)
                 .into()])],
             signature: DeclarationSignature::new()
                 .inputs(vec![])
-                .outputs(vec![DeclarationType::Uint(UBitwidth::B32)]),
+                .outputs(vec![DeclarationType::Uint(IntBitwidth::B32)]),
         };
 
         let expected_program: TypedProgram<Bn128Field> = TypedProgram {
@@ -545,7 +581,7 @@ mod tests {
                         DeclarationFunctionKey::with_location("main", "main").signature(
                             DeclarationSignature::new()
                                 .inputs(vec![])
-                                .outputs(vec![DeclarationType::Uint(UBitwidth::B32)]),
+                                .outputs(vec![DeclarationType::Uint(IntBitwidth::B32)]),
                         ),
                         TypedFunctionSymbol::Here(expected_main),
                     )]
@@ -575,13 +611,13 @@ mod tests {
                 FieldElementExpression::select(
                     ArrayExpressionInner::Identifier(Identifier::from(const_id))
                         .annotate(GType::FieldElement, 2usize),
-                    UExpressionInner::Value(0u128).annotate(UBitwidth::B32),
+                    UExpressionInner::Value(0u128).annotate(IntBitwidth::B32),
                 )
                 .into(),
                 FieldElementExpression::select(
                     ArrayExpressionInner::Identifier(Identifier::from(const_id))
                         .annotate(GType::FieldElement, 2usize),
-                    UExpressionInner::Value(1u128).annotate(UBitwidth::B32),
+                    UExpressionInner::Value(1u128).annotate(IntBitwidth::B32),
                 )
                 .into(),
             )
@@ -650,7 +686,7 @@ mod tests {
                         .into(),
                     )
                     .annotate(GType::FieldElement, 2usize),
-                    UExpressionInner::Value(0u128).annotate(UBitwidth::B32),
+                    UExpressionInner::Value(0u128).annotate(IntBitwidth::B32),
                 )
                 .into(),
                 FieldElementExpression::select(
@@ -662,7 +698,7 @@ mod tests {
                         .into(),
                     )
                     .annotate(GType::FieldElement, 2usize),
-                    UExpressionInner::Value(1u128).annotate(UBitwidth::B32),
+                    UExpressionInner::Value(1u128).annotate(IntBitwidth::B32),
                 )
                 .into(),
             )
@@ -82,6 +82,9 @@ impl<T: Field> FlatStatement<T> {
                     .collect(),
                 ..d
             })),
+            FlatStatement::Location(location, stack) => {
+                Some(FlatStatement::Location(location, stack))
+            }
         }
     }
 }
@@ -1,4 +1,5 @@
-use crate::typed_absy::types::UBitwidth;
+use crate::source_map::{CallStack, Frame, SourceLocation};
+use crate::typed_absy::types::IntBitwidth;
 use crate::typed_absy::{self, Expr};
 use crate::zir;
 use std::marker::PhantomData;
@@ -9,6 +10,12 @@ use std::convert::{TryFrom, TryInto};
 #[derive(Default)]
 pub struct Flattener<T: Field> {
     phantom: PhantomData<T>,
+    /// The location of the statement being flattened
+    location: Option<SourceLocation>,
+    /// The stack of calls leading to the statement being flattened
+    stack: CallStack,
+    /// The number of temporary variables introduced so far
+    temporary_count: usize,
 }
 
 fn flatten_identifier_rec<'ast>(
@@ -16,7 +23,7 @@ fn flatten_identifier_rec<'ast>(
     ty: &typed_absy::types::ConcreteType,
 ) -> Vec<zir::Variable<'ast>> {
     match ty {
-        typed_absy::ConcreteType::Int => unreachable!(),
+        typed_absy::ConcreteType::Int | typed_absy::ConcreteType::Generic(..) => unreachable!(),
         typed_absy::ConcreteType::FieldElement => vec![zir::Variable {
             id: zir::Identifier::Source(id),
             _type: zir::Type::FieldElement,
@@ -46,6 +53,128 @@ fn flatten_identifier_rec<'ast>(
                 )
             })
             .collect(),
+        typed_absy::types::ConcreteType::Tuple(tuple_type) => tuple_type
+            .elements
+            .iter()
+            .enumerate()
+            .flat_map(|(i, ty)| {
+                flatten_identifier_rec(zir::SourceIdentifier::Element(box id.clone(), i as u32), ty)
+            })
+            .collect(),
+        typed_absy::types::ConcreteType::Enum(enum_type) => std::iter::once(zir::Variable {
+            id: zir::Identifier::Source(zir::SourceIdentifier::Tag(box id.clone())),
+            _type: zir::Type::FieldElement,
+        })
+        .chain(
+            enum_type
+                .variants
+                .iter()
+                .enumerate()
+                .flat_map(|(i, variant)| {
+                    flatten_identifier_rec(
+                        zir::SourceIdentifier::Payload(box id.clone(), i as u32),
+                        &typed_absy::types::ConcreteType::tuple(variant.payload.clone()),
+                    )
+                }),
+        )
+        .collect(),
+    }
+}
+
+// the value of each primitive of a given type, used to fill the payloads of the inactive variants of an enum
+fn default_values<'ast, T: Field>(
+    ty: &typed_absy::types::ConcreteType,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    match ty {
+        typed_absy::types::ConcreteType::Int | typed_absy::types::ConcreteType::Generic(..) => {
+            unreachable!()
+        }
+        typed_absy::types::ConcreteType::FieldElement => {
+            vec![zir::FieldElementExpression::Number(T::zero()).into()]
+        }
+        typed_absy::types::ConcreteType::Boolean => {
+            vec![zir::BooleanExpression::Value(false).into()]
+        }
+        typed_absy::types::ConcreteType::Uint(bitwidth) => vec![zir::UExpressionInner::Value(0)
+            .annotate(bitwidth.to_usize())
+            .into()],
+        typed_absy::types::ConcreteType::Array(array_type) => {
+            let inner = default_values(&array_type.ty);
+            (0..array_type.size).flat_map(|_| inner.clone()).collect()
+        }
+        typed_absy::types::ConcreteType::Struct(struct_type) => struct_type
+            .members
+            .iter()
+            .flat_map(|member| default_values(&member.ty))
+            .collect(),
+        typed_absy::types::ConcreteType::Tuple(tuple_type) => tuple_type
+            .elements
+            .iter()
+            .flat_map(default_values)
+            .collect(),
+        typed_absy::types::ConcreteType::Enum(enum_type) => {
+            std::iter::once(zir::FieldElementExpression::Number(T::zero()).into())
+                .chain(
+                    enum_type.variants.iter().flat_map(|variant| {
+                        variant.payload.elements.iter().flat_map(default_values)
+                    }),
+                )
+                .collect()
+        }
+    }
+}
+
+// the tags of all enums found in a value of a given type, given the flattened variables of that value
+fn enum_tags<'ast>(
+    ty: &typed_absy::types::ConcreteType,
+    variables: &[zir::Variable<'ast>],
+) -> Vec<(zir::Variable<'ast>, usize)> {
+    match ty {
+        typed_absy::types::ConcreteType::Array(array_type) => {
+            let size = array_type.ty.get_primitive_count();
+            (0..array_type.size)
+                .flat_map(|i| enum_tags(&array_type.ty, &variables[i * size..(i + 1) * size]))
+                .collect()
+        }
+        typed_absy::types::ConcreteType::Struct(struct_type) => struct_type
+            .members
+            .iter()
+            .scan(0, |offset, member| {
+                let size = member.ty.get_primitive_count();
+                let tags = enum_tags(&member.ty, &variables[*offset..*offset + size]);
+                *offset += size;
+                Some(tags)
+            })
+            .flatten()
+            .collect(),
+        typed_absy::types::ConcreteType::Tuple(tuple_type) => tuple_type
+            .elements
+            .iter()
+            .scan(0, |offset, ty| {
+                let size = ty.get_primitive_count();
+                let tags = enum_tags(ty, &variables[*offset..*offset + size]);
+                *offset += size;
+                Some(tags)
+            })
+            .flatten()
+            .collect(),
+        typed_absy::types::ConcreteType::Enum(enum_type) => {
+            std::iter::once((variables[0].clone(), enum_type.variants.len()))
+                .chain(enum_tags(
+                    &typed_absy::types::ConcreteType::tuple(
+                        typed_absy::types::ConcreteTupleType::new(
+                            enum_type
+                                .variants
+                                .iter()
+                                .flat_map(|variant| variant.payload.elements.clone())
+                                .collect(),
+                        ),
+                    ),
+                    &variables[1..],
+                ))
+                .collect()
+        }
+        _ => vec![],
     }
 }
 
@@ -107,6 +236,26 @@ impl<'ast, T: Field> Flatten<'ast, T> for typed_absy::StructExpression<'ast, T>
     }
 }
 
+impl<'ast, T: Field> Flatten<'ast, T> for typed_absy::TupleExpression<'ast, T> {
+    fn flatten(
+        self,
+        f: &mut Flattener<T>,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        f.fold_tuple_expression(statements_buffer, self)
+    }
+}
+
+impl<'ast, T: Field> Flatten<'ast, T> for typed_absy::EnumExpression<'ast, T> {
+    fn flatten(
+        self,
+        f: &mut Flattener<T>,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        f.fold_enum_expression(statements_buffer, self)
+    }
+}
+
 impl<'ast, T: Field> Flattener<T> {
     pub fn flatten(p: typed_absy::TypedProgram<T>) -> zir::ZirProgram<T> {
         let mut f = Flattener::default();
@@ -135,6 +284,31 @@ impl<'ast, T: Field> Flattener<T> {
             .collect()
     }
 
+    /// Bind `e` to a fresh variable, so that it is only evaluated once when it is used several times
+    fn define_temporary(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        e: zir::UExpression<'ast, T>,
+    ) -> zir::UExpression<'ast, T> {
+        match e.as_inner() {
+            zir::UExpressionInner::Identifier(..) | zir::UExpressionInner::Value(..) => e,
+            _ => {
+                let bitwidth = e.bitwidth();
+                let id = zir::Identifier::Source(self.fold_name(typed_absy::Identifier::from(
+                    typed_absy::CoreIdentifier::Temporary(self.temporary_count),
+                )));
+                self.temporary_count += 1;
+
+                statements_buffer.push(zir::ZirStatement::Definition(
+                    zir::Variable::uint(id.clone(), bitwidth),
+                    e.into(),
+                ));
+
+                zir::UExpressionInner::Identifier(id).annotate(bitwidth)
+            }
+        }
+    }
+
     fn fold_name(&mut self, n: typed_absy::Identifier<'ast>) -> zir::SourceIdentifier<'ast> {
         zir::SourceIdentifier::Basic(n)
     }
@@ -191,6 +365,27 @@ impl<'ast, T: Field> Flattener<T> {
 
                 let a = self.fold_assignee(a);
 
+                a[offset..offset + size].to_vec()
+            }
+            typed_absy::TypedAssignee::Element(box a, index) => {
+                use typed_absy::Typed;
+
+                let tuple_ty = match typed_absy::ConcreteType::try_from(a.get_type()).unwrap() {
+                    typed_absy::ConcreteType::Tuple(tuple_ty) => tuple_ty,
+                    _ => unreachable!(),
+                };
+
+                let offset: usize = tuple_ty
+                    .elements
+                    .iter()
+                    .take(index as usize)
+                    .map(|ty| ty.get_primitive_count())
+                    .sum();
+
+                let size = tuple_ty.elements[index as usize].get_primitive_count();
+
+                let a = self.fold_assignee(a);
+
                 a[offset..offset + size].to_vec()
             }
         }
@@ -240,6 +435,10 @@ impl<'ast, T: Field> Flattener<T> {
             typed_absy::TypedExpression::Struct(e) => {
                 self.fold_struct_expression(statements_buffer, e)
             }
+            typed_absy::TypedExpression::Tuple(e) => {
+                self.fold_tuple_expression(statements_buffer, e)
+            }
+            typed_absy::TypedExpression::Enum(e) => self.fold_enum_expression(statements_buffer, e),
             typed_absy::TypedExpression::Int(_) => unreachable!(),
         }
     }
@@ -261,6 +460,22 @@ impl<'ast, T: Field> Flattener<T> {
         fold_struct_expression(self, statements_buffer, e)
     }
 
+    fn fold_tuple_expression(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        e: typed_absy::TupleExpression<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_tuple_expression(self, statements_buffer, e)
+    }
+
+    fn fold_enum_expression(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        e: typed_absy::EnumExpression<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_enum_expression(self, statements_buffer, e)
+    }
+
     fn fold_expression_list(
         &mut self,
         statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
@@ -305,6 +520,14 @@ impl<'ast, T: Field> Flattener<T> {
         fold_select_expression(self, statements_buffer, select)
     }
 
+    fn fold_element_expression<E>(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        element: typed_absy::ElementExpression<'ast, T, E>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_element_expression(self, statements_buffer, element)
+    }
+
     fn fold_field_expression(
         &mut self,
         statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
@@ -330,7 +553,7 @@ impl<'ast, T: Field> Flattener<T> {
     fn fold_uint_expression_inner(
         &mut self,
         statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
-        bitwidth: UBitwidth,
+        bitwidth: IntBitwidth,
         e: typed_absy::UExpressionInner<'ast, T>,
     ) -> zir::UExpressionInner<'ast, T> {
         fold_uint_expression_inner(self, statements_buffer, bitwidth, e)
@@ -353,6 +576,24 @@ impl<'ast, T: Field> Flattener<T> {
     ) -> Vec<zir::ZirExpression<'ast, T>> {
         fold_struct_expression_inner(self, statements_buffer, ty, e)
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        ty: &typed_absy::types::ConcreteTupleType,
+        e: typed_absy::TupleExpressionInner<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_tuple_expression_inner(self, statements_buffer, ty, e)
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        ty: &typed_absy::types::ConcreteEnumType,
+        e: typed_absy::EnumExpressionInner<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_enum_expression_inner(self, statements_buffer, ty, e)
+    }
 }
 
 fn fold_statement<'ast, T: Field>(
@@ -379,11 +620,18 @@ fn fold_statement<'ast, T: Field>(
         typed_absy::TypedStatement::Declaration(..) => {
             unreachable!()
         }
-        typed_absy::TypedStatement::Assertion(e) => {
+        typed_absy::TypedStatement::Assertion(e, error) => {
             let e = f.fold_boolean_expression(statements_buffer, e);
-            vec![zir::ZirStatement::Assertion(e)]
+            let error = match error {
+                typed_absy::RuntimeError::SourceAssertion(metadata) => {
+                    zir::RuntimeError::SourceAssertion(metadata.to_string())
+                }
+                typed_absy::RuntimeError::SelectRangeCheck => zir::RuntimeError::SelectRangeCheck,
+            };
+            vec![zir::ZirStatement::Assertion(e, error)]
         }
         typed_absy::TypedStatement::For(..) => unreachable!(),
+        typed_absy::TypedStatement::IfElse(..) => unreachable!(),
         typed_absy::TypedStatement::MultipleDefinition(variables, elist) => {
             vec![zir::ZirStatement::MultipleDefinition(
                 variables
@@ -393,8 +641,27 @@ fn fold_statement<'ast, T: Field>(
                 f.fold_expression_list(statements_buffer, elist),
             )]
         }
-        typed_absy::TypedStatement::PushCallLog(..) => vec![],
-        typed_absy::TypedStatement::PopCallLog => vec![],
+        typed_absy::TypedStatement::Location(location) => {
+            f.location = Some(location.clone());
+            vec![zir::ZirStatement::Location(location, f.stack.clone())]
+        }
+        typed_absy::TypedStatement::PushCallLog(key, _) => {
+            f.stack.push(Frame::new(
+                format!("{}/{}", key.module.display(), key.id),
+                f.location.clone(),
+            ));
+            vec![]
+        }
+        typed_absy::TypedStatement::PopCallLog => {
+            // statements following an inlined call belong to the call site
+            f.location = f.stack.pop().unwrap().call_site;
+            f.location
+                .clone()
+                .map(|location| zir::ZirStatement::Location(location, f.stack.clone()))
+                .into_iter()
+                .collect()
+        }
+        typed_absy::TypedStatement::LoopIteration => vec![],
     };
 
     statements_buffer.extend(res);
@@ -442,6 +709,7 @@ fn fold_array_expression_inner<'ast, T: Field>(
             exprs
         }
         typed_absy::ArrayExpressionInner::FunctionCall(..) => unreachable!(),
+        typed_absy::ArrayExpressionInner::Comprehension(..) => unreachable!(),
         typed_absy::ArrayExpressionInner::IfElse(c) => {
             f.fold_if_else_expression(statements_buffer, c)
         }
@@ -451,6 +719,9 @@ fn fold_array_expression_inner<'ast, T: Field>(
         typed_absy::ArrayExpressionInner::Select(select) => {
             f.fold_select_expression(statements_buffer, select)
         }
+        typed_absy::ArrayExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
         typed_absy::ArrayExpressionInner::Slice(box array, box from, box to) => {
             let array = f.fold_array_expression(statements_buffer, array);
             let from = f.fold_uint_expression(statements_buffer, from);
@@ -526,6 +797,158 @@ fn fold_struct_expression_inner<'ast, T: Field>(
         typed_absy::StructExpressionInner::Select(select) => {
             f.fold_select_expression(statements_buffer, select)
         }
+        typed_absy::StructExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
+    }
+}
+
+fn fold_tuple_expression_inner<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ty: &typed_absy::types::ConcreteTupleType,
+    tuple: typed_absy::TupleExpressionInner<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    match tuple {
+        typed_absy::TupleExpressionInner::Block(block) => {
+            block
+                .statements
+                .into_iter()
+                .for_each(|s| f.fold_statement(statements_buffer, s));
+            f.fold_tuple_expression(statements_buffer, *block.value)
+        }
+        typed_absy::TupleExpressionInner::Identifier(id) => {
+            let variables = flatten_identifier_rec(
+                f.fold_name(id),
+                &typed_absy::types::ConcreteType::tuple(ty.clone()),
+            );
+            variables
+                .into_iter()
+                .map(|v| match v._type {
+                    zir::Type::FieldElement => zir::FieldElementExpression::Identifier(v.id).into(),
+                    zir::Type::Boolean => zir::BooleanExpression::Identifier(v.id).into(),
+                    zir::Type::Uint(bitwidth) => zir::UExpressionInner::Identifier(v.id)
+                        .annotate(bitwidth)
+                        .into(),
+                })
+                .collect()
+        }
+        typed_absy::TupleExpressionInner::Value(exprs) => exprs
+            .into_iter()
+            .flat_map(|e| f.fold_expression(statements_buffer, e))
+            .collect(),
+        typed_absy::TupleExpressionInner::FunctionCall(..) => unreachable!(),
+        typed_absy::TupleExpressionInner::IfElse(c) => {
+            f.fold_if_else_expression(statements_buffer, c)
+        }
+        typed_absy::TupleExpressionInner::Member(m) => {
+            f.fold_member_expression(statements_buffer, m)
+        }
+        typed_absy::TupleExpressionInner::Select(select) => {
+            f.fold_select_expression(statements_buffer, select)
+        }
+        typed_absy::TupleExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
+        typed_absy::TupleExpressionInner::Payload(box e, variant) => {
+            let enum_ty = typed_absy::types::ConcreteEnumType::try_from(e.ty().clone()).unwrap();
+
+            let offset: usize = 1 + enum_ty
+                .variants
+                .iter()
+                .take(variant as usize)
+                .map(|variant| {
+                    variant
+                        .payload
+                        .elements
+                        .iter()
+                        .map(|ty| ty.get_primitive_count())
+                        .sum::<usize>()
+                })
+                .sum::<usize>();
+
+            let size = ty
+                .elements
+                .iter()
+                .map(|ty| ty.get_primitive_count())
+                .sum::<usize>();
+
+            let e = f.fold_enum_expression(statements_buffer, e);
+
+            e[offset..offset + size].to_vec()
+        }
+    }
+}
+
+fn fold_enum_expression_inner<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ty: &typed_absy::types::ConcreteEnumType,
+    e: typed_absy::EnumExpressionInner<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    match e {
+        typed_absy::EnumExpressionInner::Block(block) => {
+            block
+                .statements
+                .into_iter()
+                .for_each(|s| f.fold_statement(statements_buffer, s));
+            f.fold_enum_expression(statements_buffer, *block.value)
+        }
+        typed_absy::EnumExpressionInner::Identifier(id) => {
+            let variables = flatten_identifier_rec(
+                f.fold_name(id),
+                &typed_absy::types::ConcreteType::enumeration(ty.clone()),
+            );
+            variables
+                .into_iter()
+                .map(|v| match v._type {
+                    zir::Type::FieldElement => zir::FieldElementExpression::Identifier(v.id).into(),
+                    zir::Type::Boolean => zir::BooleanExpression::Identifier(v.id).into(),
+                    zir::Type::Uint(bitwidth) => zir::UExpressionInner::Identifier(v.id)
+                        .annotate(bitwidth)
+                        .into(),
+                })
+                .collect()
+        }
+        typed_absy::EnumExpressionInner::Value(active, exprs) => {
+            let mut exprs = Some(exprs);
+
+            std::iter::once(zir::FieldElementExpression::Number(T::from(active)).into())
+                .chain(
+                    ty.variants
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, variant)| match i == active as usize {
+                            true => exprs
+                                .take()
+                                .unwrap()
+                                .into_iter()
+                                .flat_map(|e| f.fold_expression(statements_buffer, e))
+                                .collect::<Vec<_>>(),
+                            false => variant
+                                .payload
+                                .elements
+                                .iter()
+                                .flat_map(default_values)
+                                .collect(),
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .collect()
+        }
+        typed_absy::EnumExpressionInner::FunctionCall(..) => unreachable!(),
+        typed_absy::EnumExpressionInner::IfElse(c) => {
+            f.fold_if_else_expression(statements_buffer, c)
+        }
+        typed_absy::EnumExpressionInner::Member(m) => {
+            f.fold_member_expression(statements_buffer, m)
+        }
+        typed_absy::EnumExpressionInner::Select(select) => {
+            f.fold_select_expression(statements_buffer, select)
+        }
+        typed_absy::EnumExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
     }
 }
 
@@ -565,6 +988,30 @@ fn fold_member_expression<'ast, T: Field, E>(
     s[offset..offset + size].to_vec()
 }
 
+fn fold_element_expression<'ast, T: Field, E>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    element: typed_absy::ElementExpression<'ast, T, E>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    let t = *element.tuple;
+    let index = element.index as usize;
+
+    let tuple_ty = typed_absy::types::ConcreteTupleType::try_from(t.ty().clone()).unwrap();
+
+    let size = tuple_ty.elements[index].get_primitive_count();
+
+    let offset: usize = tuple_ty
+        .elements
+        .iter()
+        .take(index)
+        .map(|ty| ty.get_primitive_count())
+        .sum();
+
+    let t = f.fold_tuple_expression(statements_buffer, t);
+
+    t[offset..offset + size].to_vec()
+}
+
 fn fold_select_expression<'ast, T: Field, E>(
     f: &mut Flattener<T>,
     statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
@@ -749,12 +1196,23 @@ fn fold_field_expression<'ast, T: Field>(
             .unwrap()
             .try_into()
             .unwrap(),
+        typed_absy::FieldElementExpression::Element(element) => f
+            .fold_element_expression(statements_buffer, element)
+            .pop()
+            .unwrap()
+            .try_into()
+            .unwrap(),
         typed_absy::FieldElementExpression::Member(m) => f
             .fold_member_expression(statements_buffer, m)
             .pop()
             .unwrap()
             .try_into()
             .unwrap(),
+        typed_absy::FieldElementExpression::Tag(box e) => f
+            .fold_enum_expression(statements_buffer, e)
+            .swap_remove(0)
+            .try_into()
+            .unwrap(),
         typed_absy::FieldElementExpression::Block(block) => {
             block
                 .statements
@@ -842,12 +1300,30 @@ fn fold_boolean_expression<'ast, T: Field>(
 
             conjunction_tree(&e1, &e2)
         }
+        typed_absy::BooleanExpression::TupleEq(box e1, box e2) => {
+            let e1 = f.fold_tuple_expression(statements_buffer, e1);
+            let e2 = f.fold_tuple_expression(statements_buffer, e2);
+
+            assert_eq!(e1.len(), e2.len());
+
+            conjunction_tree(&e1, &e2)
+        }
         typed_absy::BooleanExpression::UintEq(box e1, box e2) => {
             let e1 = f.fold_uint_expression(statements_buffer, e1);
             let e2 = f.fold_uint_expression(statements_buffer, e2);
 
             zir::BooleanExpression::UintEq(box e1, box e2)
         }
+        typed_absy::BooleanExpression::UintBit(box e, box index) => {
+            let e = f.fold_uint_expression(statements_buffer, e);
+
+            let index = match index.as_inner() {
+                typed_absy::UExpressionInner::Value(index) => index,
+                _ => unreachable!("static analysis should have made sure that this is constant"),
+            };
+
+            zir::BooleanExpression::UintBit(box e, *index as u32)
+        }
         typed_absy::BooleanExpression::FieldLt(box e1, box e2) => {
             let e1 = f.fold_field_expression(statements_buffer, e1);
             let e2 = f.fold_field_expression(statements_buffer, e2);
@@ -869,23 +1345,23 @@ fn fold_boolean_expression<'ast, T: Field>(
             zir::BooleanExpression::FieldGe(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintLt(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let e1 = fold_ordered_uint_expression(f, statements_buffer, e1);
+            let e2 = fold_ordered_uint_expression(f, statements_buffer, e2);
             zir::BooleanExpression::UintLt(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintLe(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let e1 = fold_ordered_uint_expression(f, statements_buffer, e1);
+            let e2 = fold_ordered_uint_expression(f, statements_buffer, e2);
             zir::BooleanExpression::UintLe(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintGt(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let e1 = fold_ordered_uint_expression(f, statements_buffer, e1);
+            let e2 = fold_ordered_uint_expression(f, statements_buffer, e2);
             zir::BooleanExpression::UintGt(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintGe(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let e1 = fold_ordered_uint_expression(f, statements_buffer, e1);
+            let e2 = fold_ordered_uint_expression(f, statements_buffer, e2);
             zir::BooleanExpression::UintGe(box e1, box e2)
         }
         typed_absy::BooleanExpression::Or(box e1, box e2) => {
@@ -915,6 +1391,12 @@ fn fold_boolean_expression<'ast, T: Field>(
             .unwrap()
             .try_into()
             .unwrap(),
+        typed_absy::BooleanExpression::Element(element) => f
+            .fold_element_expression(statements_buffer, element)
+            .pop()
+            .unwrap()
+            .try_into()
+            .unwrap(),
         typed_absy::BooleanExpression::Member(m) => f
             .fold_member_expression(statements_buffer, m)
             .pop()
@@ -936,7 +1418,7 @@ fn fold_uint_expression<'ast, T: Field>(
 fn fold_uint_expression_inner<'ast, T: Field>(
     f: &mut Flattener<T>,
     statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
-    bitwidth: UBitwidth,
+    bitwidth: IntBitwidth,
     e: typed_absy::UExpressionInner<'ast, T>,
 ) -> zir::UExpressionInner<'ast, T> {
     match e {
@@ -981,13 +1463,42 @@ fn fold_uint_expression_inner<'ast, T: Field>(
             let left = f.fold_uint_expression(statements_buffer, left);
             let right = f.fold_uint_expression(statements_buffer, right);
 
-            zir::UExpressionInner::Div(box left, box right)
+            match bitwidth.is_signed() {
+                // divide the absolute values and negate the quotient if the signs differ
+                true => {
+                    let left = f.define_temporary(statements_buffer, left);
+                    let right = f.define_temporary(statements_buffer, right);
+
+                    let signs_differ =
+                        zir::BooleanExpression::Not(box zir::BooleanExpression::BoolEq(
+                            box is_negative(left.clone()),
+                            box is_negative(right.clone()),
+                        ));
+
+                    let quotient = f.define_temporary(statements_buffer, abs(left).div(abs(right)));
+
+                    negate_if(signs_differ, quotient).into_inner()
+                }
+                false => zir::UExpressionInner::Div(box left, box right),
+            }
         }
         typed_absy::UExpressionInner::Rem(box left, box right) => {
             let left = f.fold_uint_expression(statements_buffer, left);
             let right = f.fold_uint_expression(statements_buffer, right);
 
-            zir::UExpressionInner::Rem(box left, box right)
+            match bitwidth.is_signed() {
+                // the remainder takes the sign of the dividend
+                true => {
+                    let left = f.define_temporary(statements_buffer, left);
+                    let right = f.define_temporary(statements_buffer, right);
+
+                    let remainder =
+                        f.define_temporary(statements_buffer, abs(left.clone()).rem(abs(right)));
+
+                    negate_if(is_negative(left), remainder).into_inner()
+                }
+                false => zir::UExpressionInner::Rem(box left, box right),
+            }
         }
         typed_absy::UExpressionInner::Xor(box left, box right) => {
             let left = f.fold_uint_expression(statements_buffer, left);
@@ -1025,7 +1536,44 @@ fn fold_uint_expression_inner<'ast, T: Field>(
                 _ => unreachable!("static analysis should have made sure that this is constant"),
             };
 
-            zir::UExpressionInner::RightShift(box e, *by as u32)
+            match bitwidth.is_signed() {
+                // shift in ones for negative values, which is shifting in zeros in the complement
+                true => {
+                    let e = f.define_temporary(statements_buffer, e);
+
+                    zir::UExpressionInner::IfElse(
+                        box is_negative(e.clone()),
+                        box e.clone().not().right_shift(*by as u32).not(),
+                        box e.right_shift(*by as u32),
+                    )
+                }
+                false => zir::UExpressionInner::RightShift(box e, *by as u32),
+            }
+        }
+        typed_absy::UExpressionInner::LeftRotate(box e, box by) => {
+            let e = f.fold_uint_expression(statements_buffer, e);
+
+            let by = match by.as_inner() {
+                typed_absy::UExpressionInner::Value(by) => by,
+                _ => unreachable!("static analysis should have made sure that this is constant"),
+            };
+
+            zir::UExpressionInner::LeftRotate(box e, (*by % bitwidth.to_usize() as u128) as u32)
+        }
+        typed_absy::UExpressionInner::RightRotate(box e, box by) => {
+            let e = f.fold_uint_expression(statements_buffer, e);
+
+            let by = match by.as_inner() {
+                typed_absy::UExpressionInner::Value(by) => by,
+                _ => unreachable!("static analysis should have made sure that this is constant"),
+            };
+
+            zir::UExpressionInner::RightRotate(box e, (*by % bitwidth.to_usize() as u128) as u32)
+        }
+        typed_absy::UExpressionInner::Slice(box e, from, to) => {
+            let e = f.fold_uint_expression(statements_buffer, e);
+
+            zir::UExpressionInner::Slice(box e, from, to)
         }
         typed_absy::UExpressionInner::Not(box e) => {
             let e = f.fold_uint_expression(statements_buffer, e);
@@ -1057,6 +1605,13 @@ fn fold_uint_expression_inner<'ast, T: Field>(
         )
         .unwrap()
         .into_inner(),
+        typed_absy::UExpressionInner::Element(element) => zir::UExpression::try_from(
+            f.fold_element_expression(statements_buffer, element)
+                .pop()
+                .unwrap(),
+        )
+        .unwrap()
+        .into_inner(),
         typed_absy::UExpressionInner::Member(m) => zir::UExpression::try_from(
             f.fold_member_expression(statements_buffer, m)
                 .pop()
@@ -1074,21 +1629,117 @@ fn fold_uint_expression_inner<'ast, T: Field>(
     }
 }
 
+/// Check whether `e`, a signed integer in two's complement, is negative
+fn is_negative<T: Field>(e: zir::UExpression<T>) -> zir::BooleanExpression<T> {
+    let bitwidth = e.bitwidth();
+
+    zir::BooleanExpression::UintEq(
+        box e.right_shift(bitwidth.to_usize() as u32 - 1),
+        box zir::UExpressionInner::Value(1).annotate(bitwidth),
+    )
+}
+
+/// Negate `e`, a signed integer in two's complement, if `condition` holds. `e` is used twice, so it should be an
+/// identifier or a value
+fn negate_if<'ast, T: Field>(
+    condition: zir::BooleanExpression<'ast, T>,
+    e: zir::UExpression<'ast, T>,
+) -> zir::UExpression<'ast, T> {
+    use crate::zir::IfElse;
+
+    let bitwidth = e.bitwidth();
+
+    zir::UExpression::if_else(
+        condition,
+        zir::UExpressionInner::Value(0)
+            .annotate(bitwidth)
+            .sub(e.clone()),
+        e,
+    )
+}
+
+/// Get the absolute value of `e`, a signed integer in two's complement. `e` is used several times, so it should be an
+/// identifier or a value
+fn abs<T: Field>(e: zir::UExpression<T>) -> zir::UExpression<T> {
+    negate_if(is_negative(e.clone()), e)
+}
+
+/// Fold an operand of a comparison. The sign bit of signed integers is flipped, so that comparing
+/// the results as unsigned integers gives the signed order
+fn fold_ordered_uint_expression<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    e: typed_absy::UExpression<'ast, T>,
+) -> zir::UExpression<'ast, T> {
+    let signed = e.bitwidth().is_signed();
+    let e = f.fold_uint_expression(statements_buffer, e);
+
+    match signed {
+        true => {
+            let bitwidth = e.bitwidth();
+            e.xor(zir::UExpressionInner::Value(1 << (bitwidth.to_usize() - 1)).annotate(bitwidth))
+        }
+        false => e,
+    }
+}
+
 fn fold_function<'ast, T: Field>(
     f: &mut Flattener<T>,
     fun: typed_absy::TypedFunction<'ast, T>,
 ) -> zir::ZirFunction<'ast, T> {
     let mut main_statements_buffer = vec![];
 
+    // the tag of an enum argument must designate one of its variants
+    let arguments: Vec<_> = fun
+        .arguments
+        .into_iter()
+        .map(|a| {
+            let ty = typed_absy::types::ConcreteType::try_from(
+                crate::typed_absy::types::try_from_g_type::<
+                    crate::typed_absy::types::DeclarationConstant<'ast>,
+                    crate::typed_absy::UExpression<'ast, T>,
+                >(a.id._type.clone())
+                .unwrap(),
+            )
+            .unwrap();
+            (ty, f.fold_declaration_parameter(a))
+        })
+        .collect();
+
+    for (ty, parameters) in &arguments {
+        let variables: Vec<_> = parameters.iter().map(|p| p.id.clone()).collect();
+
+        for (tag, count) in enum_tags(ty, &variables) {
+            let tag = zir::FieldElementExpression::Identifier(tag.id);
+
+            let product = (1..count).fold(tag.clone(), |acc, i| {
+                zir::FieldElementExpression::Mult(
+                    box acc,
+                    box zir::FieldElementExpression::Sub(
+                        box tag.clone(),
+                        box zir::FieldElementExpression::Number(T::from(i)),
+                    ),
+                )
+            });
+
+            main_statements_buffer.push(zir::ZirStatement::Assertion(
+                zir::BooleanExpression::FieldEq(
+                    box product,
+                    box zir::FieldElementExpression::Number(T::zero()),
+                ),
+                zir::RuntimeError::EnumTagCheck,
+            ));
+        }
+    }
+
     fun.statements
         .into_iter()
         .for_each(|s| f.fold_statement(&mut main_statements_buffer, s));
 
     zir::ZirFunction {
-        arguments: fun
-            .arguments
+        arguments: arguments
             .into_iter()
-            .flat_map(|a| f.fold_declaration_parameter(a))
+            .flat_map(|(_, parameters)| parameters)
             .collect(),
         statements: main_statements_buffer,
         signature: typed_absy::types::ConcreteSignature::try_from(
@@ -1132,10 +1783,36 @@ fn fold_struct_expression<'ast, T: Field>(
     )
 }
 
+fn fold_tuple_expression<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    e: typed_absy::TupleExpression<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    f.fold_tuple_expression_inner(
+        statements_buffer,
+        &typed_absy::types::ConcreteTupleType::try_from(e.ty().clone()).unwrap(),
+        e.into_inner(),
+    )
+}
+
+fn fold_enum_expression<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    e: typed_absy::EnumExpression<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    f.fold_enum_expression_inner(
+        statements_buffer,
+        &typed_absy::types::ConcreteEnumType::try_from(e.ty().clone()).unwrap(),
+        e.into_inner(),
+    )
+}
+
 fn fold_program<'ast, T: Field>(
     f: &mut Flattener<T>,
     mut p: typed_absy::TypedProgram<'ast, T>,
 ) -> zir::ZirProgram<'ast, T> {
+    f.stack = vec![Frame::new(format!("{}/main", p.main.display()), None)];
+
     let main_module = p.modules.remove(&p.main).unwrap();
 
     let main_function = main_module
@@ -5,6 +5,7 @@
 //! @date 2018
 
 mod branch_isolator;
+mod comprehension_desugarer;
 mod constant_argument_checker;
 mod constant_inliner;
 mod flat_propagation;
@@ -18,16 +19,18 @@ mod variable_write_remover;
 mod zir_propagation;
 
 use self::branch_isolator::Isolator;
+use self::comprehension_desugarer::ComprehensionDesugarer;
 use self::constant_argument_checker::ConstantArgumentChecker;
 use self::flatten_complex_types::Flattener;
 use self::out_of_bounds::OutOfBoundsChecker;
-use self::propagation::Propagator;
+use self::propagation::{Lints, Propagator};
 use self::reducer::reduce_program;
 use self::uint_optimizer::UintOptimizer;
 use self::unconstrained_vars::UnconstrainedVariableDetector;
 use self::variable_write_remover::VariableWriteRemover;
 use crate::compile::CompileConfig;
 use crate::ir::Prog;
+use crate::parser::Position;
 use crate::static_analysis::constant_inliner::ConstantInliner;
 use crate::static_analysis::zir_propagation::ZirPropagator;
 use crate::typed_absy::{abi::Abi, TypedProgram};
@@ -109,8 +112,41 @@ impl fmt::Display for Error {
     }
 }
 
+/// A construct of the main module which static analysis shows to be useless
+#[derive(Debug, PartialEq)]
+pub enum Warning {
+    Propagation(self::propagation::Warning),
+}
+
+impl From<propagation::Warning> for Warning {
+    fn from(w: propagation::Warning) -> Self {
+        Warning::Propagation(w)
+    }
+}
+
+impl Warning {
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Warning::Propagation(w) => w.position(),
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::Propagation(w) => write!(f, "{}", w),
+        }
+    }
+}
+
 impl<'ast, T: Field> TypedProgram<'ast, T> {
-    pub fn analyse(self, config: &CompileConfig) -> Result<(ZirProgram<'ast, T>, Abi), Error> {
+    pub fn analyse(
+        self,
+        config: &CompileConfig,
+    ) -> Result<(ZirProgram<'ast, T>, Abi, Vec<Warning>), Error> {
+        let mut warnings = vec![];
+
         // inline user-defined constants
         log::debug!("Static analyser: Inline constants");
         let r = ConstantInliner::inline(self).map_err(Error::from)?;
@@ -127,9 +163,15 @@ impl<'ast, T: Field> TypedProgram<'ast, T> {
             r
         };
 
+        // desugar array comprehensions into for-loops
+        log::debug!("Static analyser: Desugar comprehensions");
+        let r = ComprehensionDesugarer::desugar(r);
+        log::trace!("\n{}", r);
+
         // reduce the program to a single function
         log::debug!("Static analyser: Reduce program");
-        let r = reduce_program(r).map_err(Error::from)?;
+        let mut lints = Lints::default();
+        let r = reduce_program(r, &mut lints).map_err(Error::from)?;
         log::trace!("\n{}", r);
 
         // generate abi
@@ -138,7 +180,8 @@ impl<'ast, T: Field> TypedProgram<'ast, T> {
 
         // propagate
         log::debug!("Static analyser: Propagate");
-        let r = Propagator::propagate(r).map_err(Error::from)?;
+        let (r, propagation_warnings) = Propagator::propagate(r, lints).map_err(Error::from)?;
+        warnings.extend(propagation_warnings.into_iter().map(Warning::from));
         log::trace!("\n{}", r);
 
         // remove assignment to variable index
@@ -171,7 +214,7 @@ impl<'ast, T: Field> TypedProgram<'ast, T> {
         let zir = UintOptimizer::optimize(zir);
         log::trace!("\n{}", zir);
 
-        Ok((zir, abi))
+        Ok((zir, abi, warnings))
     }
 }
 
@@ -8,10 +8,13 @@
 //! @date 2018
 
 use crate::embed::FlatEmbed;
+use crate::parser::Position;
+use crate::source_map::SourceLocation;
 use crate::typed_absy::result_folder::*;
 use crate::typed_absy::types::Type;
 use crate::typed_absy::*;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
 use zokrates_field::Field;
@@ -45,24 +48,188 @@ impl fmt::Display for Error {
     }
 }
 
+/// A construct which propagation shows to be useless
+#[derive(Debug, PartialEq)]
+pub enum Warning {
+    ConstantCondition(String, bool, String, Position),
+    TrueAssertion(String, Position),
+}
+
+impl Warning {
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Warning::ConstantCondition(_, _, _, position) => Some(*position),
+            Warning::TrueAssertion(_, position) => Some(*position),
+        }
+    }
+
+    fn file(&self) -> &str {
+        match self {
+            Warning::ConstantCondition(_, _, file, _) => file,
+            Warning::TrueAssertion(file, _) => file,
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::ConstantCondition(condition, value, ..) => {
+                write!(f, "Condition `{}` is always {}", condition, value)
+            }
+            Warning::TrueAssertion(..) => write!(f, "Assertion is always true"),
+        }
+    }
+}
+
+/// The state of the search for useless constructs in a program, shared by the propagation passes over its main function
+#[derive(Default)]
+pub struct Lints<'ast> {
+    // the constructs found to be useless, without duplicates
+    warnings: Vec<Warning>,
+    // the variables whose value is set by the instantiation of the code rather than by the code itself: the arguments
+    // and generic parameters of inlined calls, the indices of unrolled loops, the scrutinees of lowered `match`
+    // expressions, and the variables computed from them
+    instantiated: HashSet<Identifier<'ast>>,
+}
+
 pub struct Propagator<'ast, 'a, T: Field> {
     // constants keeps track of constant expressions
     // we currently do not support partially constant expressions: `field [x, 1][1]` is not considered constant, `field [0, 1][1]` is
     constants: &'a mut Constants<'ast, T>,
+    // the search for useless constructs. It only happens when propagating the main function of a program, as the other
+    // uses of the propagator only fold parts of it
+    lints: Option<&'a mut Lints<'ast>>,
+    // the location of the statement being propagated, and the locations of the calls it was inlined from
+    location: Option<SourceLocation>,
+    call_sites: Vec<Option<SourceLocation>>,
+    // whether the statements being propagated bind the arguments or generic parameters of an inlined call, or the
+    // index of an unrolled loop, which is the case until the next source statement
+    binding: bool,
+}
+
+/// A folder which finds out whether an expression reads any of the given variables
+struct VariableReadDetector<'ast, 'b> {
+    variables: &'b HashSet<Identifier<'ast>>,
+    found: bool,
+}
+
+impl<'ast, 'b, T: Field> Folder<'ast, T> for VariableReadDetector<'ast, 'b> {
+    fn fold_name(&mut self, n: Identifier<'ast>) -> Identifier<'ast> {
+        self.found |= self.variables.contains(&n);
+        n
+    }
+}
+
+/// Get the variable at the root of an assignee
+fn root<'b, 'ast, T>(assignee: &'b TypedAssignee<'ast, T>) -> &'b Variable<'ast, T> {
+    match assignee {
+        TypedAssignee::Identifier(v) => v,
+        TypedAssignee::Select(box a, _)
+        | TypedAssignee::Member(box a, _)
+        | TypedAssignee::Element(box a, _) => root(a),
+    }
+}
+
+/// Compare two integer values of type `bitwidth`, taking their sign into account
+fn compare(v1: u128, v2: u128, bitwidth: IntBitwidth) -> Ordering {
+    match bitwidth.is_signed() {
+        true => to_signed(v1, bitwidth).cmp(&to_signed(v2, bitwidth)),
+        false => v1.cmp(&v2),
+    }
 }
 
 impl<'ast, 'a, T: Field> Propagator<'ast, 'a, T> {
     pub fn with_constants(constants: &'a mut Constants<'ast, T>) -> Self {
-        Propagator { constants }
+        Propagator {
+            constants,
+            lints: None,
+            location: None,
+            call_sites: vec![],
+            binding: false,
+        }
+    }
+
+    /// Propagate constants, collecting the useless constructs found along the way into `lints`
+    pub fn with_lints(constants: &'a mut Constants<'ast, T>, lints: &'a mut Lints<'ast>) -> Self {
+        Propagator {
+            lints: Some(lints),
+            ..Self::with_constants(constants)
+        }
     }
 
-    pub fn propagate(p: TypedProgram<'ast, T>) -> Result<TypedProgram<'ast, T>, Error> {
+    /// Propagate constants in the main function of `p`, returning the warnings about the constructs of its main module
+    /// which turn out to be useless, including those found by the passes which produced `lints`
+    pub fn propagate(
+        p: TypedProgram<'ast, T>,
+        mut lints: Lints<'ast>,
+    ) -> Result<(TypedProgram<'ast, T>, Vec<Warning>), Error> {
         let mut constants = Constants::new();
+        let main_file = p.main.display().to_string();
 
-        Propagator {
-            constants: &mut constants,
+        let p = Propagator::with_lints(&mut constants, &mut lints).fold_program(p)?;
+
+        // conditions and assertions inlined from other modules are out of the user's control
+        let warnings = lints
+            .warnings
+            .into_iter()
+            .filter(|w| w.file() == main_file)
+            .collect();
+
+        Ok((p, warnings))
+    }
+
+    fn warn(&mut self, warning: Warning) {
+        if let Some(lints) = self.lints.as_mut() {
+            if !lints.warnings.contains(&warning) {
+                lints.warnings.push(warning);
+            }
+        }
+    }
+
+    /// Whether the expression visited by `visit` reads a variable whose value is set by the instantiation of the code
+    fn reads_instantiated<F: FnOnce(&mut VariableReadDetector<'ast, '_>)>(&self, visit: F) -> bool {
+        match self.lints.as_ref() {
+            Some(lints) if !lints.instantiated.is_empty() => {
+                let mut detector = VariableReadDetector {
+                    variables: &lints.instantiated,
+                    found: false,
+                };
+                visit(&mut detector);
+                detector.found
+            }
+            _ => false,
+        }
+    }
+
+    /// Record the variables defined by `s` whose value is set by the instantiation of the code
+    fn track_instantiated(&mut self, s: &TypedStatement<'ast, T>) {
+        let (assignees, instantiated) = match s {
+            TypedStatement::Definition(assignee, expr) => (
+                vec![assignee],
+                self.binding
+                    || self.reads_instantiated(|d| {
+                        d.fold_expression(expr.clone());
+                    }),
+            ),
+            TypedStatement::MultipleDefinition(assignees, expression_list) => (
+                assignees.iter().collect(),
+                self.binding
+                    || self.reads_instantiated(|d| {
+                        d.fold_expression_list(expression_list.clone());
+                    }),
+            ),
+            _ => return,
+        };
+
+        if let Some(lints) = self.lints.as_mut() {
+            for assignee in assignees {
+                let id = &root(assignee).id;
+                if instantiated || matches!(id.id, CoreIdentifier::Match(..)) {
+                    lints.instantiated.insert(id.clone());
+                }
+            }
         }
-        .fold_program(p)
     }
 
     // get a mutable reference to the constant corresponding to a given assignee if any, otherwise
@@ -123,6 +290,20 @@ impl<'ast, 'a, T: Field> Propagator<'ast, 'a, T> {
                 }
                 e => e,
             },
+            TypedAssignee::Element(box assignee, index) => {
+                match self.try_get_constant_mut(&assignee) {
+                    Ok((v, c)) => match c {
+                        TypedExpression::Tuple(a) => match a.as_inner_mut() {
+                            TupleExpressionInner::Value(value) => {
+                                Ok((v, &mut value[*index as usize]))
+                            }
+                            _ => unreachable!("should be a tuple value"),
+                        },
+                        _ => unreachable!("should be a tuple expression"),
+                    },
+                    e => e,
+                }
+            }
         }
     }
 }
@@ -180,32 +361,58 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
         _: &E::Ty,
         e: IfElseExpression<'ast, T, E>,
     ) -> Result<IfElseOrExpression<'ast, T, E>, Self::Error> {
-        Ok(
-            match (
-                self.fold_boolean_expression(*e.condition)?,
-                e.consequence.fold(self)?,
-                e.alternative.fold(self)?,
-            ) {
-                (BooleanExpression::Value(true), consequence, _) => {
-                    IfElseOrExpression::Expression(consequence.into_inner())
+        // when the condition is known, only the branch being taken is visited: after loop unrolling, a branch
+        // guarded by the loop index such as `if i < 2 then a[i + 1] else 0 fi` can be unreachable and out of bounds,
+        // and the statements of an unreachable block must not define constants
+        //
+        // a condition which is constant because of the unrolling of a loop, the instantiation of a call or the lowering
+        // of a `match` expression is not reported, as it is not constant in the code as written
+        let condition = match (self.lints.is_some(), self.location.as_ref()) {
+            (true, Some(location))
+                if !self.reads_instantiated(|d| {
+                    d.fold_boolean_expression(*e.condition.clone());
+                }) =>
+            {
+                Some((e.condition.to_string(), location.clone()))
+            }
+            _ => None,
+        };
+
+        Ok(match self.fold_boolean_expression(*e.condition)? {
+            BooleanExpression::Value(value) => {
+                if let Some((condition, location)) = condition {
+                    self.warn(Warning::ConstantCondition(
+                        condition,
+                        value,
+                        location.file,
+                        location.position,
+                    ));
                 }
-                (BooleanExpression::Value(false), _, alternative) => {
-                    IfElseOrExpression::Expression(alternative.into_inner())
+
+                match value {
+                    true => IfElseOrExpression::Expression(e.consequence.fold(self)?.into_inner()),
+                    false => IfElseOrExpression::Expression(e.alternative.fold(self)?.into_inner()),
                 }
-                (_, consequence, alternative) if consequence == alternative => {
-                    IfElseOrExpression::Expression(consequence.into_inner())
+            }
+            condition => {
+                match (e.consequence.fold(self)?, e.alternative.fold(self)?) {
+                    (consequence, alternative) if consequence == alternative => {
+                        IfElseOrExpression::Expression(consequence.into_inner())
+                    }
+                    (consequence, alternative) => IfElseOrExpression::IfElse(
+                        IfElseExpression::new(condition, consequence, alternative),
+                    ),
                 }
-                (condition, consequence, alternative) => IfElseOrExpression::IfElse(
-                    IfElseExpression::new(condition, consequence, alternative),
-                ),
-            },
-        )
+            }
+        })
     }
 
     fn fold_statement(
         &mut self,
         s: TypedStatement<'ast, T>,
     ) -> Result<Vec<TypedStatement<'ast, T>>, Error> {
+        self.track_instantiated(&s);
+
         match s {
             // propagation to the defined variable if rhs is a constant
             TypedStatement::Definition(assignee, expr) => {
@@ -299,7 +506,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                         fn process_u_from_bits<'ast, T: Field>(
                             variables: Vec<TypedAssignee<'ast, T>>,
                             mut arguments: Vec<TypedExpression<'ast, T>>,
-                            bitwidth: UBitwidth,
+                            bitwidth: IntBitwidth,
                         ) -> TypedExpression<'ast, T> {
                             assert_eq!(variables.len(), 1);
                             assert_eq!(arguments.len(), 1);
@@ -345,7 +552,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                         fn process_u_to_bits<'ast, T: Field>(
                             variables: Vec<TypedAssignee<'ast, T>>,
                             arguments: Vec<TypedExpression<'ast, T>>,
-                            bitwidth: UBitwidth,
+                            bitwidth: IntBitwidth,
                         ) -> TypedExpression<'ast, T> {
                             assert_eq!(variables.len(), 1);
                             assert_eq!(arguments.len(), 1);
@@ -358,7 +565,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                                     let mut num = v;
                                     let mut res = vec![];
 
-                                    for i in (0..bitwidth as u32).rev() {
+                                    for i in (0..bitwidth.to_usize() as u32).rev() {
                                         if 2u128.pow(i) <= num {
                                             num -= 2u128.pow(i);
                                             res.push(true);
@@ -385,45 +592,95 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                             true => {
                                 let r: Option<TypedExpression<'ast, T>> = match embed {
                                     FlatEmbed::BitArrayLe => None, // todo
+                                    FlatEmbed::U128FromBits => Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::B128,
+                                    )),
                                     FlatEmbed::U64FromBits => Some(process_u_from_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B64,
+                                        IntBitwidth::B64,
                                     )),
                                     FlatEmbed::U32FromBits => Some(process_u_from_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B32,
+                                        IntBitwidth::B32,
                                     )),
                                     FlatEmbed::U16FromBits => Some(process_u_from_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B16,
+                                        IntBitwidth::B16,
                                     )),
                                     FlatEmbed::U8FromBits => Some(process_u_from_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B8,
+                                        IntBitwidth::B8,
+                                    )),
+                                    FlatEmbed::U128ToBits => Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::B128,
                                     )),
                                     FlatEmbed::U64ToBits => Some(process_u_to_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B64,
+                                        IntBitwidth::B64,
                                     )),
                                     FlatEmbed::U32ToBits => Some(process_u_to_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B32,
+                                        IntBitwidth::B32,
                                     )),
                                     FlatEmbed::U16ToBits => Some(process_u_to_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B16,
+                                        IntBitwidth::B16,
                                     )),
                                     FlatEmbed::U8ToBits => Some(process_u_to_bits(
                                         assignees.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B8,
+                                        IntBitwidth::B8,
+                                    )),
+                                    FlatEmbed::I64FromBits => Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I64,
+                                    )),
+                                    FlatEmbed::I32FromBits => Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I32,
+                                    )),
+                                    FlatEmbed::I16FromBits => Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I16,
+                                    )),
+                                    FlatEmbed::I8FromBits => Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I8,
+                                    )),
+                                    FlatEmbed::I64ToBits => Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I64,
+                                    )),
+                                    FlatEmbed::I32ToBits => Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I32,
+                                    )),
+                                    FlatEmbed::I16ToBits => Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I16,
+                                    )),
+                                    FlatEmbed::I8ToBits => Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        IntBitwidth::I8,
                                     )),
                                     FlatEmbed::Unpack => {
                                         assert_eq!(assignees.len(), 1);
@@ -606,30 +863,60 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                         invalidations.chain(std::iter::once(def)).collect()
                     }
+                    TypedExpressionListInner::Tuple(..) => unreachable!(
+                        "tuple destructuring should have been removed by the SSA transformation"
+                    ),
                 };
 
                 Ok(statements)
             }
-            TypedStatement::Assertion(e) => {
+            TypedStatement::Assertion(e, error) => {
                 let e_str = e.to_string();
                 let expr = self.fold_boolean_expression(e)?;
                 match expr {
                     BooleanExpression::Value(v) if !v => Err(Error::AssertionFailed(format!(
-                        "Assertion failed on expression `{}`",
-                        e_str
+                        "{} on expression `{}`",
+                        error, e_str
                     ))),
-                    _ => Ok(vec![TypedStatement::Assertion(expr)]),
+                    BooleanExpression::Value(_) => {
+                        if let RuntimeError::SourceAssertion(metadata) = &error {
+                            self.warn(Warning::TrueAssertion(
+                                metadata.file.clone(),
+                                metadata.position,
+                            ));
+                        }
+                        Ok(vec![TypedStatement::Assertion(expr, error)])
+                    }
+                    _ => Ok(vec![TypedStatement::Assertion(expr, error)]),
                 }
             }
-            s @ TypedStatement::PushCallLog(..) => Ok(vec![s]),
-            s @ TypedStatement::PopCallLog => Ok(vec![s]),
+            TypedStatement::Location(location) => {
+                self.location = Some(location.clone());
+                self.binding = false;
+                Ok(vec![TypedStatement::Location(location)])
+            }
+            s @ TypedStatement::PushCallLog(..) => {
+                self.call_sites.push(self.location.clone());
+                self.binding = true;
+                Ok(vec![s])
+            }
+            s @ TypedStatement::PopCallLog => {
+                // statements following an inlined call belong to the call site
+                self.location = self.call_sites.pop().flatten();
+                self.binding = false;
+                Ok(vec![s])
+            }
+            s @ TypedStatement::LoopIteration => {
+                self.binding = true;
+                Ok(vec![s])
+            }
             s => fold_statement(self, s),
         }
     }
 
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntBitwidth,
         e: UExpressionInner<'ast, T>,
     ) -> Result<UExpressionInner<'ast, T>, Error> {
         match e {
@@ -644,11 +931,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 + v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.wrapping_add(v2) & bitwidth.max_value()),
+                ),
                 (e, UExpressionInner::Value(v)) | (UExpressionInner::Value(v), e) => match v {
                     0 => Ok(e),
                     _ => Ok(UExpressionInner::Add(
@@ -665,11 +950,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1.wrapping_sub(v2)) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.wrapping_sub(v2) & bitwidth.max_value()),
+                ),
                 (e, UExpressionInner::Value(v)) => match v {
                     0 => Ok(e),
                     _ => Ok(UExpressionInner::Sub(
@@ -686,11 +969,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        v1.saturating_sub(v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.saturating_sub(v2) & bitwidth.max_value()),
+                ),
                 (e, UExpressionInner::Value(v)) => match v {
                     0 => Ok(e),
                     _ => Ok(UExpressionInner::FloorSub(
@@ -707,11 +988,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 * v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.wrapping_mul(v2) & bitwidth.max_value()),
+                ),
                 (e, UExpressionInner::Value(v)) | (UExpressionInner::Value(v), e) => match v {
                     0 => Ok(UExpressionInner::Value(0)),
                     1 => Ok(e),
@@ -730,9 +1009,12 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
                 (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 / v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
+                    Ok(UExpressionInner::Value(match bitwidth.is_signed() {
+                        true => {
+                            from_signed(to_signed(v1, bitwidth) / to_signed(v2, bitwidth), bitwidth)
+                        }
+                        false => v1 / v2,
+                    }))
                 }
                 (e, UExpressionInner::Value(v)) => match v {
                     1 => Ok(e),
@@ -751,9 +1033,12 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
                 (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 % v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
+                    Ok(UExpressionInner::Value(match bitwidth.is_signed() {
+                        true => {
+                            from_signed(to_signed(v1, bitwidth) % to_signed(v2, bitwidth), bitwidth)
+                        }
+                        false => v1 % v2,
+                    }))
                 }
                 (e, UExpressionInner::Value(v)) => match v {
                     1 => Ok(UExpressionInner::Value(0)),
@@ -772,11 +1057,14 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let by = self.fold_uint_expression(by)?;
                 match (e.into_inner(), by.into_inner()) {
                     (UExpressionInner::Value(v), UExpressionInner::Value(by)) => {
-                        Ok(UExpressionInner::Value(v >> by))
+                        Ok(UExpressionInner::Value(match bitwidth.is_signed() {
+                            true => from_signed(to_signed(v, bitwidth) >> by, bitwidth),
+                            false => v >> by,
+                        }))
                     }
                     (e, by) => Ok(UExpressionInner::RightShift(
                         box e.annotate(bitwidth),
-                        box by.annotate(UBitwidth::B32),
+                        box by.annotate(IntBitwidth::B32),
                     )),
                 }
             }
@@ -784,12 +1072,62 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let e = self.fold_uint_expression(e)?;
                 let by = self.fold_uint_expression(by)?;
                 match (e.into_inner(), by.into_inner()) {
-                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => Ok(
-                        UExpressionInner::Value((v << by) & (2_u128.pow(bitwidth as u32) - 1)),
-                    ),
+                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => {
+                        Ok(UExpressionInner::Value((v << by) & bitwidth.max_value()))
+                    }
                     (e, by) => Ok(UExpressionInner::LeftShift(
                         box e.annotate(bitwidth),
-                        box by.annotate(UBitwidth::B32),
+                        box by.annotate(IntBitwidth::B32),
+                    )),
+                }
+            }
+            UExpressionInner::LeftRotate(box e, box by) => {
+                let e = self.fold_uint_expression(e)?;
+                let by = self.fold_uint_expression(by)?;
+                match (e.into_inner(), by.into_inner()) {
+                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => {
+                        let bits = bitwidth.to_usize() as u32;
+                        let by = (by % bits as u128) as u32;
+                        Ok(UExpressionInner::Value(
+                            ((v << by) | v.checked_shr(bits - by).unwrap_or(0))
+                                & bitwidth.max_value(),
+                        ))
+                    }
+                    (e, by) => Ok(UExpressionInner::LeftRotate(
+                        box e.annotate(bitwidth),
+                        box by.annotate(IntBitwidth::B32),
+                    )),
+                }
+            }
+            UExpressionInner::RightRotate(box e, box by) => {
+                let e = self.fold_uint_expression(e)?;
+                let by = self.fold_uint_expression(by)?;
+                match (e.into_inner(), by.into_inner()) {
+                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => {
+                        let bits = bitwidth.to_usize() as u32;
+                        let by = (by % bits as u128) as u32;
+                        Ok(UExpressionInner::Value(
+                            ((v >> by) | v.checked_shl(bits - by).unwrap_or(0))
+                                & bitwidth.max_value(),
+                        ))
+                    }
+                    (e, by) => Ok(UExpressionInner::RightRotate(
+                        box e.annotate(bitwidth),
+                        box by.annotate(IntBitwidth::B32),
+                    )),
+                }
+            }
+            UExpressionInner::Slice(box e, from, to) => {
+                let e = self.fold_uint_expression(e)?;
+                let e_bitwidth = e.bitwidth();
+                match e.into_inner() {
+                    UExpressionInner::Value(v) => Ok(UExpressionInner::Value(
+                        (v >> (e_bitwidth.to_usize() - to as usize)) & bitwidth.max_value(),
+                    )),
+                    e => Ok(UExpressionInner::Slice(
+                        box e.annotate(e_bitwidth),
+                        from,
+                        to,
                     )),
                 }
             }
@@ -831,9 +1169,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
             UExpressionInner::Not(box e) => {
                 let e = self.fold_uint_expression(e)?.into_inner();
                 match e {
-                    UExpressionInner::Value(v) => Ok(UExpressionInner::Value(
-                        (!v) & (2_u128.pow(bitwidth as u32) - 1),
-                    )),
+                    UExpressionInner::Value(v) => {
+                        Ok(UExpressionInner::Value((!v) & bitwidth.max_value()))
+                    }
                     e => Ok(UExpressionInner::Not(box e.annotate(bitwidth))),
                 }
             }
@@ -841,8 +1179,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let e = self.fold_uint_expression(e)?.into_inner();
                 match e {
                     UExpressionInner::Value(v) => Ok(UExpressionInner::Value(
-                        (0u128.wrapping_sub(v))
-                            % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
+                        0u128.wrapping_sub(v) & bitwidth.max_value(),
                     )),
                     e => Ok(UExpressionInner::Neg(box e.annotate(bitwidth))),
                 }
@@ -930,13 +1267,23 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                     }
                     (e1, UExpressionInner::Value(n2)) => Ok(FieldElementExpression::Pow(
                         box e1,
-                        box UExpressionInner::Value(n2).annotate(UBitwidth::B32),
+                        box UExpressionInner::Value(n2).annotate(IntBitwidth::B32),
                     )),
                     (_, e2) => Err(Error::NonConstantExponent(
-                        e2.annotate(UBitwidth::B32).to_string(),
+                        e2.annotate(IntBitwidth::B32).to_string(),
                     )),
                 }
             }
+            FieldElementExpression::Tag(box e) => {
+                let e = self.fold_enum_expression(e)?;
+
+                match e.as_inner() {
+                    EnumExpressionInner::Value(variant, _) => {
+                        Ok(FieldElementExpression::Number(T::from(*variant)))
+                    }
+                    _ => Ok(FieldElementExpression::Tag(box e)),
+                }
+            }
             e => fold_field_expression(self, e),
         }
     }
@@ -973,6 +1320,30 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
         }
     }
 
+    fn fold_element_expression<
+        E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    >(
+        &mut self,
+        _: &E::Ty,
+        e: ElementExpression<'ast, T, E>,
+    ) -> Result<ElementOrExpression<'ast, T, E>, Self::Error> {
+        let index = e.index;
+
+        let tuple = self.fold_tuple_expression(*e.tuple)?;
+
+        let ty = tuple.ty().clone();
+
+        match tuple.into_inner() {
+            TupleExpressionInner::Value(mut v) => Ok(ElementOrExpression::Expression(
+                E::from(v.swap_remove(index as usize)).into_inner(),
+            )),
+            inner => Ok(ElementOrExpression::Element(ElementExpression::new(
+                inner.annotate(ty),
+                index,
+            ))),
+        }
+    }
+
     fn fold_select_expression<
         E: Expr<'ast, T> + Select<'ast, T> + From<TypedExpression<'ast, T>>,
     >(
@@ -1026,7 +1397,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                             E::select(
                                 ArrayExpressionInner::Identifier(id)
                                     .annotate(inner_type, size as u32),
-                                UExpressionInner::Value(n).annotate(UBitwidth::B32),
+                                UExpressionInner::Value(n).annotate(IntBitwidth::B32),
                             )
                             .into_inner(),
                         )),
@@ -1034,7 +1405,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 }
                 (a, i) => Ok(SelectOrExpression::Select(SelectExpression::new(
                     a.annotate(inner_type, size as u32),
-                    i.annotate(UBitwidth::B32),
+                    i.annotate(IntBitwidth::B32),
                 ))),
             },
             _ => Ok(SelectOrExpression::Select(SelectExpression::new(
@@ -1125,6 +1496,70 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
         }
     }
 
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> Result<TupleExpressionInner<'ast, T>, Error> {
+        match e {
+            TupleExpressionInner::Identifier(id) => match self.constants.get(&id) {
+                Some(e) => match e {
+                    TypedExpression::Tuple(e) => Ok(e.as_inner().clone()),
+                    _ => panic!("constant stored for a tuple should be a tuple"),
+                },
+                None => Ok(TupleExpressionInner::Identifier(id)),
+            },
+            TupleExpressionInner::Value(v) => {
+                let v = v.into_iter().zip(ty.elements.iter()).enumerate().map(|(index, (v, element_ty))|
+                    match self.fold_expression(v) {
+                        Ok(v) => match (ConcreteType::try_from(v.get_type().clone()), ConcreteType::try_from(element_ty.clone())) {
+                            (Ok(t1), Ok(t2)) => if t1 == t2 { Ok(v) } else { Err(Error::Type(format!(
+                                "Tuple element `{}` in tuple of type `{}` expected to have type `{}`, found type `{}`",
+                                index, ty, t2, t1
+                            ))) },
+                            _ => Ok(v)
+                        }
+                        e => e
+                    }
+                ).collect::<Result<_, _>>()?;
+
+                Ok(TupleExpressionInner::Value(v))
+            }
+            TupleExpressionInner::Payload(box e, variant) => {
+                let e = self.fold_enum_expression(e)?;
+                let enum_ty = e.ty().clone();
+
+                match e.into_inner() {
+                    EnumExpressionInner::Value(active, v) if active == variant => {
+                        Ok(TupleExpressionInner::Value(v))
+                    }
+                    inner => Ok(TupleExpressionInner::Payload(
+                        box inner.annotate(enum_ty),
+                        variant,
+                    )),
+                }
+            }
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> Result<EnumExpressionInner<'ast, T>, Error> {
+        match e {
+            EnumExpressionInner::Identifier(id) => match self.constants.get(&id) {
+                Some(e) => match e {
+                    TypedExpression::Enum(e) => Ok(e.as_inner().clone()),
+                    _ => panic!("constant stored for an enum should be an enum"),
+                },
+                None => Ok(EnumExpressionInner::Identifier(id)),
+            },
+            e => fold_enum_expression_inner(self, ty, e),
+        }
+    }
+
     fn fold_boolean_expression(
         &mut self,
         e: BooleanExpression<'ast, T>,
@@ -1164,6 +1599,20 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                     _ => Ok(BooleanExpression::UintEq(box e1, box e2)),
                 }
             }
+            BooleanExpression::UintBit(box e, box index) => {
+                let e = self.fold_uint_expression(e)?;
+                let index = self.fold_uint_expression(index)?;
+
+                let bitwidth = e.bitwidth().to_usize() as u128;
+
+                match (e.as_inner(), index.as_inner()) {
+                    // out of bounds indices are reported by the constant argument checker
+                    (UExpressionInner::Value(v), UExpressionInner::Value(i)) if *i < bitwidth => {
+                        Ok(BooleanExpression::Value((v >> (bitwidth - 1 - i)) & 1 == 1))
+                    }
+                    _ => Ok(BooleanExpression::UintBit(box e, box index)),
+                }
+            }
             BooleanExpression::BoolEq(box e1, box e2) => {
                 let e1 = self.fold_boolean_expression(e1)?;
                 let e2 = self.fold_boolean_expression(e2)?;
@@ -1211,6 +1660,24 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 Ok(BooleanExpression::StructEq(box e1, box e2))
             }
+            BooleanExpression::TupleEq(box e1, box e2) => {
+                let e1 = self.fold_tuple_expression(e1)?;
+                let e2 = self.fold_tuple_expression(e2)?;
+
+                if let (Ok(t1), Ok(t2)) = (
+                    ConcreteType::try_from(e1.get_type()),
+                    ConcreteType::try_from(e2.get_type()),
+                ) {
+                    if t1 != t2 {
+                        return Err(Error::Type(format!(
+                            "Cannot compare {} of type {} to {} of type {}",
+                            e1, t1, e2, t2
+                        )));
+                    }
+                };
+
+                Ok(BooleanExpression::TupleEq(box e1, box e2))
+            }
             BooleanExpression::FieldLt(box e1, box e2) => {
                 let e1 = self.fold_field_expression(e1)?;
                 let e2 = self.fold_field_expression(e2)?;
@@ -1260,9 +1727,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let e2 = self.fold_uint_expression(e2)?;
 
                 match (e1.as_inner(), e2.as_inner()) {
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 < n2))
-                    }
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
+                        BooleanExpression::Value(compare(*n1, *n2, e1.bitwidth) == Ordering::Less),
+                    ),
                     _ => Ok(BooleanExpression::UintLt(box e1, box e2)),
                 }
             }
@@ -1272,7 +1739,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 match (e1.as_inner(), e2.as_inner()) {
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 <= n2))
+                        Ok(BooleanExpression::Value(
+                            compare(*n1, *n2, e1.bitwidth) != Ordering::Greater,
+                        ))
                     }
                     _ => Ok(BooleanExpression::UintLe(box e1, box e2)),
                 }
@@ -1283,7 +1752,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 match (e1.as_inner(), e2.as_inner()) {
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 > n2))
+                        Ok(BooleanExpression::Value(
+                            compare(*n1, *n2, e1.bitwidth) == Ordering::Greater,
+                        ))
                     }
                     _ => Ok(BooleanExpression::UintGt(box e1, box e2)),
                 }
@@ -1293,9 +1764,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let e2 = self.fold_uint_expression(e2)?;
 
                 match (e1.as_inner(), e2.as_inner()) {
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 >= n2))
-                    }
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
+                        BooleanExpression::Value(compare(*n1, *n2, e1.bitwidth) != Ordering::Less),
+                    ),
                     _ => Ok(BooleanExpression::UintGe(box e1, box e2)),
                 }
             }
@@ -1457,6 +1928,26 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn if_else_ignores_untaken_branch() {
+                let e = FieldElementExpression::if_else(
+                    BooleanExpression::Value(true),
+                    FieldElementExpression::Number(Bn128Field::from(2)),
+                    FieldElementExpression::select(
+                        ArrayExpressionInner::Value(
+                            vec![FieldElementExpression::Number(Bn128Field::from(1)).into()].into(),
+                        )
+                        .annotate(Type::FieldElement, 1usize),
+                        UExpressionInner::Value(1).annotate(IntBitwidth::B32),
+                    ),
+                );
+
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new()).fold_field_expression(e),
+                    Ok(FieldElementExpression::Number(Bn128Field::from(2)))
+                );
+            }
+
             #[test]
             fn select() {
                 let e = FieldElementExpression::select(
@@ -1470,7 +1961,7 @@ mod tests {
                     )
                     .annotate(Type::FieldElement, 3usize),
                     UExpressionInner::Add(box 1u32.into(), box 1u32.into())
-                        .annotate(UBitwidth::B32),
+                        .annotate(IntBitwidth::B32),
                 );
 
                 assert_eq!(
@@ -1811,5 +2302,102 @@ mod tests {
                 );
             }
         }
+
+        #[cfg(test)]
+        mod signed {
+            use super::*;
+
+            fn value<'ast>(v: i128) -> UExpression<'ast, Bn128Field> {
+                UExpressionInner::Value(from_signed(v, IntBitwidth::I8)).annotate(IntBitwidth::I8)
+            }
+
+            #[test]
+            fn div() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new())
+                        .fold_uint_expression(value(-7) / value(2)),
+                    Ok(value(-3))
+                );
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new())
+                        .fold_uint_expression(value(-128) / value(-1)),
+                    Ok(value(-128))
+                );
+            }
+
+            #[test]
+            fn rem() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new())
+                        .fold_uint_expression(value(-7) % value(2)),
+                    Ok(value(-1))
+                );
+            }
+
+            #[test]
+            fn right_shift() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new())
+                        .fold_uint_expression(value(-8).right_shift(1u32.into())),
+                    Ok(value(-4))
+                );
+            }
+
+            #[test]
+            fn lt() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new()).fold_boolean_expression(
+                        BooleanExpression::UintLt(box value(-1), box value(1))
+                    ),
+                    Ok(BooleanExpression::Value(true))
+                );
+            }
+        }
+
+        #[cfg(test)]
+        mod uint128 {
+            use super::*;
+
+            fn value<'ast>(v: u128) -> UExpression<'ast, Bn128Field> {
+                UExpressionInner::Value(v).annotate(IntBitwidth::B128)
+            }
+
+            #[test]
+            fn add() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new())
+                        .fold_uint_expression(value(u128::MAX) + value(2)),
+                    Ok(value(1))
+                );
+            }
+
+            #[test]
+            fn mult() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new())
+                        .fold_uint_expression(value(u128::MAX) * value(u128::MAX)),
+                    Ok(value(1))
+                );
+            }
+
+            #[test]
+            fn div() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new())
+                        .fold_uint_expression(value(u128::MAX) / value(2)),
+                    Ok(value(u128::MAX >> 1))
+                );
+            }
+
+            #[test]
+            fn lt() {
+                assert_eq!(
+                    Propagator::with_constants(&mut Constants::new()).fold_boolean_expression(
+                        BooleanExpression::UintLt(box value(1), box value(u128::MAX))
+                    ),
+                    Ok(BooleanExpression::Value(true))
+                );
+            }
+        }
     }
 }
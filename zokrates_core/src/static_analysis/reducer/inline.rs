@@ -28,6 +28,7 @@
 use crate::embed::FlatEmbed;
 use crate::static_analysis::reducer::Output;
 use crate::static_analysis::reducer::ShallowTransformer;
+use crate::static_analysis::reducer::TypeArgumentsBinder;
 use crate::static_analysis::reducer::Versions;
 use crate::typed_absy::types::{ConcreteGenericsAssignment, IntoTypes};
 use crate::typed_absy::CoreIdentifier;
@@ -164,6 +165,12 @@ pub fn inline_call<'a, 'ast, T: Field, E: Expr<'ast, T>>(
 
     assert_eq!(f.arguments.len(), arguments.len());
 
+    // bind the type parameters of the function to the types found at this call site
+    let f = match decl_key.signature.type_generics.is_empty() {
+        true => f,
+        false => TypeArgumentsBinder::bind(f, &decl_key, &assignment),
+    };
+
     let (ssa_f, incomplete_data) = match ShallowTransformer::transform(f, &assignment, versions) {
         Output::Complete(v) => (v, None),
         Output::Incomplete(statements, for_loop_versions) => (statements, Some(for_loop_versions)),
@@ -13,6 +13,7 @@
 
 mod inline;
 mod shallow_ssa;
+mod type_arguments;
 
 use self::inline::{inline_call, InlineError};
 use crate::typed_absy::result_folder::*;
@@ -31,8 +32,9 @@ use crate::typed_absy::{
 use zokrates_field::Field;
 
 use self::shallow_ssa::ShallowTransformer;
+use self::type_arguments::TypeArgumentsBinder;
 
-use crate::static_analysis::Propagator;
+use crate::static_analysis::{Lints, Propagator};
 
 use std::fmt;
 
@@ -425,6 +427,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Reducer<'ast, 'a, T> {
                                 .flatten()
                                 .collect();
 
+                            out_statements.push(TypedStatement::LoopIteration);
                             out_statements.extend(statements);
                         }
 
@@ -486,7 +489,11 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Reducer<'ast, 'a, T> {
     }
 }
 
-pub fn reduce_program<T: Field>(p: TypedProgram<T>) -> Result<TypedProgram<T>, Error> {
+/// Reduce `p` to its main function, collecting the useless constructs found by propagation into `lints`
+pub fn reduce_program<'ast, T: Field>(
+    p: TypedProgram<'ast, T>,
+    lints: &mut Lints<'ast>,
+) -> Result<TypedProgram<'ast, T>, Error> {
     let main_module = p.modules.get(&p.main).unwrap().clone();
 
     let (main_key, main_function) = main_module
@@ -500,9 +507,10 @@ pub fn reduce_program<T: Field>(p: TypedProgram<T>) -> Result<TypedProgram<T>, E
         _ => unreachable!(),
     };
 
-    match main_function.signature.generics.len() {
+    match main_function.signature.generics.len() + main_function.signature.type_generics.len() {
         0 => {
-            let main_function = reduce_function(main_function, GGenericsAssignment::default(), &p)?;
+            let main_function =
+                reduce_function(main_function, GGenericsAssignment::default(), &p, lints)?;
 
             Ok(TypedProgram {
                 main: p.main.clone(),
@@ -530,6 +538,7 @@ fn reduce_function<'ast, T: Field>(
     f: TypedFunction<'ast, T>,
     generics: ConcreteGenericsAssignment<'ast>,
     program: &TypedProgram<'ast, T>,
+    lints: &mut Lints<'ast>,
 ) -> Result<TypedFunction<'ast, T>, Error> {
     let mut versions = Versions::default();
 
@@ -574,7 +583,7 @@ fn reduce_function<'ast, T: Field>(
 
                         let new_f = Sub::new(&substitutions).fold_function(new_f);
 
-                        let new_f = Propagator::with_constants(&mut constants)
+                        let new_f = Propagator::with_lints(&mut constants, lints)
                             .fold_function(new_f)
                             .map_err(|e| Error::Incompatible(format!("{}", e)))?;
 
@@ -585,7 +594,7 @@ fn reduce_function<'ast, T: Field>(
 
                         let new_f = Sub::new(&substitutions).fold_function(new_f);
 
-                        f = Propagator::with_constants(&mut constants)
+                        f = Propagator::with_lints(&mut constants, lints)
                             .fold_function(new_f)
                             .map_err(|e| Error::Incompatible(format!("{}", e)))?;
 
@@ -618,9 +627,9 @@ mod tests {
     use crate::typed_absy::types::DeclarationSignature;
     use crate::typed_absy::{
         ArrayExpression, ArrayExpressionInner, DeclarationFunctionKey, DeclarationType,
-        DeclarationVariable, FieldElementExpression, GenericIdentifier, Identifier,
+        DeclarationVariable, FieldElementExpression, GenericIdentifier, Identifier, IntBitwidth,
         OwnedTypedModuleId, Select, Type, TypedExpression, TypedExpressionList,
-        TypedExpressionOrSpread, Types, UBitwidth, UExpressionInner, Variable,
+        TypedExpressionOrSpread, Types, UExpressionInner, Variable,
     };
     use zokrates_field::Bn128Field;
 
@@ -666,13 +675,13 @@ mod tests {
             arguments: vec![DeclarationVariable::field_element("a").into()],
             statements: vec![
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     TypedExpression::Uint(42u32.into()),
                 ),
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     UExpressionInner::Identifier("n".into())
-                        .annotate(UBitwidth::B32)
+                        .annotate(IntBitwidth::B32)
                         .into(),
                 ),
                 TypedStatement::Definition(
@@ -693,9 +702,9 @@ mod tests {
                     .annotate(Types::new(vec![Type::FieldElement])),
                 ),
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     UExpressionInner::Identifier("n".into())
-                        .annotate(UBitwidth::B32)
+                        .annotate(IntBitwidth::B32)
                         .into(),
                 ),
                 TypedStatement::Return(vec![FieldElementExpression::Identifier("a".into()).into()]),
@@ -737,7 +746,7 @@ mod tests {
             .collect(),
         };
 
-        let reduced = reduce_program(p);
+        let reduced = reduce_program(p, &mut Lints::default());
 
         let expected_main = TypedFunction {
             arguments: vec![DeclarationVariable::field_element("a").into()],
@@ -860,13 +869,13 @@ mod tests {
             arguments: vec![DeclarationVariable::field_element("a").into()],
             statements: vec![
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     TypedExpression::Uint(42u32.into()),
                 ),
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     UExpressionInner::Identifier("n".into())
-                        .annotate(UBitwidth::B32)
+                        .annotate(IntBitwidth::B32)
                         .into(),
                 ),
                 TypedStatement::Definition(
@@ -890,9 +899,9 @@ mod tests {
                     .annotate(Types::new(vec![Type::array((Type::FieldElement, 1u32))])),
                 ),
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     UExpressionInner::Identifier("n".into())
-                        .annotate(UBitwidth::B32)
+                        .annotate(IntBitwidth::B32)
                         .into(),
                 ),
                 TypedStatement::Return(vec![(FieldElementExpression::Identifier("a".into())
@@ -937,7 +946,7 @@ mod tests {
             .collect(),
         };
 
-        let reduced = reduce_program(p);
+        let reduced = reduce_program(p, &mut Lints::default());
 
         let expected_main = TypedFunction {
             arguments: vec![DeclarationVariable::field_element("a").into()],
@@ -953,11 +962,12 @@ mod tests {
                 TypedStatement::PushCallLog(
                     DeclarationFunctionKey::with_location("main", "foo")
                         .signature(foo_signature.clone()),
-                    GGenericsAssignment(
-                        vec![(GenericIdentifier::with_name("K").index(0), 1)]
+                    GGenericsAssignment {
+                        constants: vec![(GenericIdentifier::with_name("K").index(0), 1)]
                             .into_iter()
                             .collect(),
-                    ),
+                        ..Default::default()
+                    },
                 ),
                 TypedStatement::Definition(
                     Variable::array(Identifier::from("a").version(1), Type::FieldElement, 1u32)
@@ -1079,13 +1089,13 @@ mod tests {
             arguments: vec![DeclarationVariable::field_element("a").into()],
             statements: vec![
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     TypedExpression::Uint(2u32.into()),
                 ),
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     UExpressionInner::Identifier("n".into())
-                        .annotate(UBitwidth::B32)
+                        .annotate(IntBitwidth::B32)
                         .into(),
                 ),
                 TypedStatement::Definition(
@@ -1093,10 +1103,10 @@ mod tests {
                         "b",
                         Type::FieldElement,
                         UExpressionInner::Sub(
-                            box UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32),
+                            box UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32),
                             box 1u32.into(),
                         )
-                        .annotate(UBitwidth::B32),
+                        .annotate(IntBitwidth::B32),
                     )
                     .into(),
                     ArrayExpressionInner::Value(
@@ -1118,9 +1128,9 @@ mod tests {
                     .annotate(Types::new(vec![Type::array((Type::FieldElement, 1u32))])),
                 ),
                 TypedStatement::Definition(
-                    Variable::uint("n", UBitwidth::B32).into(),
+                    Variable::uint("n", IntBitwidth::B32).into(),
                     UExpressionInner::Identifier("n".into())
-                        .annotate(UBitwidth::B32)
+                        .annotate(IntBitwidth::B32)
                         .into(),
                 ),
                 TypedStatement::Return(vec![(FieldElementExpression::Identifier("a".into())
@@ -1165,7 +1175,7 @@ mod tests {
             .collect(),
         };
 
-        let reduced = reduce_program(p);
+        let reduced = reduce_program(p, &mut Lints::default());
 
         let expected_main = TypedFunction {
             arguments: vec![DeclarationVariable::field_element("a").into()],
@@ -1181,11 +1191,12 @@ mod tests {
                 TypedStatement::PushCallLog(
                     DeclarationFunctionKey::with_location("main", "foo")
                         .signature(foo_signature.clone()),
-                    GGenericsAssignment(
-                        vec![(GenericIdentifier::with_name("K").index(0), 1)]
+                    GGenericsAssignment {
+                        constants: vec![(GenericIdentifier::with_name("K").index(0), 1)]
                             .into_iter()
                             .collect(),
-                    ),
+                        ..Default::default()
+                    },
                 ),
                 TypedStatement::Definition(
                     Variable::array(Identifier::from("a").version(1), Type::FieldElement, 1u32)
@@ -1302,7 +1313,7 @@ mod tests {
                     Variable::array(
                         "ret",
                         Type::FieldElement,
-                        UExpressionInner::Identifier("K".into()).annotate(UBitwidth::B32),
+                        UExpressionInner::Identifier("K".into()).annotate(IntBitwidth::B32),
                     )
                     .into(),
                     ArrayExpressionInner::Slice(
@@ -1323,29 +1334,29 @@ mod tests {
                             )
                             .annotate(
                                 Type::FieldElement,
-                                UExpressionInner::Identifier("K".into()).annotate(UBitwidth::B32)
+                                UExpressionInner::Identifier("K".into()).annotate(IntBitwidth::B32)
                                     + 1u32.into(),
                             )
                             .into()],
                         )
                         .annotate(
                             Type::FieldElement,
-                            UExpressionInner::Identifier("K".into()).annotate(UBitwidth::B32)
+                            UExpressionInner::Identifier("K".into()).annotate(IntBitwidth::B32)
                                 + 1u32.into(),
                         ),
                         box 0u32.into(),
-                        box UExpressionInner::Identifier("K".into()).annotate(UBitwidth::B32),
+                        box UExpressionInner::Identifier("K".into()).annotate(IntBitwidth::B32),
                     )
                     .annotate(
                         Type::FieldElement,
-                        UExpressionInner::Identifier("K".into()).annotate(UBitwidth::B32),
+                        UExpressionInner::Identifier("K".into()).annotate(IntBitwidth::B32),
                     )
                     .into(),
                 ),
                 TypedStatement::Return(vec![ArrayExpressionInner::Identifier("ret".into())
                     .annotate(
                         Type::FieldElement,
-                        UExpressionInner::Identifier("K".into()).annotate(UBitwidth::B32),
+                        UExpressionInner::Identifier("K".into()).annotate(IntBitwidth::B32),
                     )
                     .into()]),
             ],
@@ -1365,7 +1376,7 @@ mod tests {
                 ArrayExpressionInner::Identifier("a".into())
                     .annotate(
                         Type::FieldElement,
-                        UExpressionInner::Identifier("K".into()).annotate(UBitwidth::B32),
+                        UExpressionInner::Identifier("K".into()).annotate(IntBitwidth::B32),
                     )
                     .into(),
             ])],
@@ -1424,7 +1435,7 @@ mod tests {
             .collect(),
         };
 
-        let reduced = reduce_program(p);
+        let reduced = reduce_program(p, &mut Lints::default());
 
         let expected_main = TypedFunction {
             arguments: vec![],
@@ -1432,20 +1443,22 @@ mod tests {
                 TypedStatement::PushCallLog(
                     DeclarationFunctionKey::with_location("main", "foo")
                         .signature(foo_signature.clone()),
-                    GGenericsAssignment(
-                        vec![(GenericIdentifier::with_name("K").index(0), 1)]
+                    GGenericsAssignment {
+                        constants: vec![(GenericIdentifier::with_name("K").index(0), 1)]
                             .into_iter()
                             .collect(),
-                    ),
+                        ..Default::default()
+                    },
                 ),
                 TypedStatement::PushCallLog(
                     DeclarationFunctionKey::with_location("main", "bar")
                         .signature(foo_signature.clone()),
-                    GGenericsAssignment(
-                        vec![(GenericIdentifier::with_name("K").index(0), 2)]
+                    GGenericsAssignment {
+                        constants: vec![(GenericIdentifier::with_name("K").index(0), 2)]
                             .into_iter()
                             .collect(),
-                    ),
+                        ..Default::default()
+                    },
                 ),
                 TypedStatement::PopCallLog,
                 TypedStatement::PopCallLog,
@@ -1562,7 +1575,7 @@ mod tests {
             .collect(),
         };
 
-        let reduced = reduce_program(p);
+        let reduced = reduce_program(p, &mut Lints::default());
 
         assert_eq!(
             reduced,
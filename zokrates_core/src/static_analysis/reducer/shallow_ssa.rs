@@ -24,10 +24,43 @@
 //		endfor
 //		return b_3 // we leave versions b_1 and b_2 to make b accessible and modifiable inside the for-loop
 
+// If/else statements are turned into a single definition of a tuple holding the variables assigned in the branches.
+// Each branch becomes a block which starts by copying these variables, so that a branch cannot overwrite a value
+// which the other branch still uses
+
+// Example:
+// def main(field a, bool c) -> field:
+//		field b = 1
+//		if c {
+//			a = a + 1
+//		} else {
+//			b = 2
+//		}
+//		return a + b
+
+// Should be turned into
+// def main(field a_0, bool c_0) -> field:
+//		field b_0 = 1
+//		(field, field) #IF_ELSE_VALUES = if c_0 then {
+//			a_1 = a_0
+//			b_1 = b_0
+//			a_2 = a_1 + 1
+//			(a_2, b_1)
+//		} else {
+//			a_3 = a_0
+//			b_2 = b_0
+//			b_3 = 2
+//			(a_3, b_3)
+//		} fi
+//		a_4 = #IF_ELSE_VALUES.0
+//		b_4 = #IF_ELSE_VALUES.1
+//		return a_4 + b_4
+
 use crate::typed_absy::folder::*;
 use crate::typed_absy::types::ConcreteGenericsAssignment;
-use crate::typed_absy::types::Type;
+use crate::typed_absy::types::{TupleType, Type};
 use crate::typed_absy::*;
+use std::collections::HashSet;
 
 use zokrates_field::Field;
 
@@ -100,13 +133,13 @@ impl<'ast, 'a> ShallowTransformer<'ast, 'a> {
         let mut f = f;
 
         f.statements = generics
-            .0
+            .constants
             .iter()
             .map(|(g, v)| {
                 TypedStatement::Definition(
                     TypedAssignee::Identifier(Variable::with_id_and_type(
                         g.name,
-                        Type::Uint(UBitwidth::B32),
+                        Type::Uint(IntBitwidth::B32),
                     )),
                     UExpression::from(*v as u32).into(),
                 )
@@ -120,6 +153,109 @@ impl<'ast, 'a> ShallowTransformer<'ast, 'a> {
 
         fold_function(self, f)
     }
+
+    fn fold_if_else_branch<T: Field>(
+        &mut self,
+        statements: Vec<TypedStatement<'ast, T>>,
+        variables: &[Variable<'ast, T>],
+        values_before: &[TypedExpression<'ast, T>],
+        ty: &TupleType<'ast, T>,
+    ) -> TupleExpression<'ast, T> {
+        let copies: Vec<_> = variables
+            .iter()
+            .zip(values_before)
+            .map(|(v, value)| {
+                let v = self.issue_next_ssa_variable(v.clone());
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(self.fold_variable(v)),
+                    value.clone(),
+                )
+            })
+            .collect();
+
+        let statements = copies
+            .into_iter()
+            .chain(statements.into_iter().flat_map(|s| self.fold_statement(s)))
+            .collect();
+
+        let values = variables
+            .iter()
+            .map(|v| self.fold_expression(v.clone().into()))
+            .collect();
+
+        TupleExpression::block(
+            statements,
+            TupleExpressionInner::Value(values).annotate(ty.clone()),
+        )
+    }
+}
+
+/// Collect the variables which are assigned in `branches` but declared outside of them, in order of first assignment
+fn assigned_outer_variables<'ast, T: Clone>(
+    branches: &[&[TypedStatement<'ast, T>]],
+) -> Vec<Variable<'ast, T>> {
+    fn assignee_variable<'a, 'ast, T>(a: &'a TypedAssignee<'ast, T>) -> &'a Variable<'ast, T> {
+        match a {
+            TypedAssignee::Identifier(v) => v,
+            TypedAssignee::Select(box a, _)
+            | TypedAssignee::Member(box a, _)
+            | TypedAssignee::Element(box a, _) => assignee_variable(a),
+        }
+    }
+
+    // `declared` holds the variables declared in the enclosing scopes within `branches`, which an assignment in
+    // `statements` refers to rather than to an outer variable of the same name
+    fn collect<'ast, T: Clone>(
+        statements: &[TypedStatement<'ast, T>],
+        assigned: &mut Vec<Variable<'ast, T>>,
+        mut declared: HashSet<CoreIdentifier<'ast>>,
+    ) {
+        for s in statements {
+            match s {
+                TypedStatement::Declaration(v) => {
+                    declared.insert(v.id.id.clone());
+                }
+                TypedStatement::Definition(a, _) => {
+                    let v = assignee_variable(a);
+                    if !declared.contains(&v.id.id) {
+                        assigned.push(v.clone());
+                    }
+                }
+                TypedStatement::MultipleDefinition(assignees, _) => assigned.extend(
+                    assignees
+                        .iter()
+                        .map(assignee_variable)
+                        .filter(|v| !declared.contains(&v.id.id))
+                        .cloned(),
+                ),
+                TypedStatement::For(v, _, _, statements) => {
+                    // the loop variable is only declared in the loop body
+                    let mut loop_declared = declared.clone();
+                    loop_declared.insert(v.id.id.clone());
+                    collect(statements, assigned, loop_declared);
+                }
+                TypedStatement::IfElse(_, consequence, alternative) => {
+                    collect(consequence, assigned, declared.clone());
+                    collect(alternative, assigned, declared.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut assigned = vec![];
+
+    // each branch is its own scope
+    for statements in branches {
+        collect(statements, &mut assigned, HashSet::new());
+    }
+
+    let mut seen = HashSet::new();
+
+    assigned
+        .into_iter()
+        .filter(|v| seen.insert(v.id.id.clone()))
+        .collect()
 }
 
 impl<'ast, 'a, T: Field> Folder<'ast, T> for ShallowTransformer<'ast, 'a> {
@@ -139,6 +275,41 @@ impl<'ast, 'a, T: Field> Folder<'ast, T> for ShallowTransformer<'ast, 'a> {
 
                 vec![TypedStatement::Definition(a, e)]
             }
+            TypedStatement::MultipleDefinition(
+                assignees,
+                TypedExpressionList {
+                    inner: TypedExpressionListInner::Tuple(tuple),
+                    ..
+                },
+            ) => {
+                let tuple = self.fold_tuple_expression(tuple);
+
+                // bind the tuple to a fresh variable so that it is only evaluated once
+                let tuple_variable = Variable::with_id_and_type(
+                    self.issue_next_identifier(CoreIdentifier::Destructuring),
+                    tuple.get_type(),
+                );
+                let tuple_identifier = TupleExpressionInner::Identifier(tuple_variable.id.clone())
+                    .annotate(tuple.ty().clone());
+
+                let definitions: Vec<_> = assignees
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, a)| {
+                        TypedStatement::Definition(
+                            a,
+                            TypedExpression::element(tuple_identifier.clone(), index as u32),
+                        )
+                    })
+                    .collect();
+
+                std::iter::once(TypedStatement::Definition(
+                    tuple_variable.into(),
+                    tuple.into(),
+                ))
+                .chain(definitions.into_iter().flat_map(|s| self.fold_statement(s)))
+                .collect()
+            }
             TypedStatement::MultipleDefinition(assignees, exprs) => {
                 let exprs = self.fold_expression_list(exprs);
                 let assignees = assignees
@@ -162,6 +333,50 @@ impl<'ast, 'a, T: Field> Folder<'ast, T> for ShallowTransformer<'ast, 'a> {
                 self.for_loop_backups.push(versions_before_loop);
                 vec![TypedStatement::For(v, from, to, stats)]
             }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                let condition = self.fold_boolean_expression(condition);
+
+                let variables = assigned_outer_variables(&[&consequence, &alternative]);
+
+                let values_before: Vec<_> = variables
+                    .iter()
+                    .map(|v| self.fold_expression(v.clone().into()))
+                    .collect();
+
+                let ty = TupleType::new(values_before.iter().map(|e| e.get_type()).collect());
+
+                let consequence =
+                    self.fold_if_else_branch(consequence, &variables, &values_before, &ty);
+                let alternative =
+                    self.fold_if_else_branch(alternative, &variables, &values_before, &ty);
+
+                // bind the values of the branches to a fresh variable and define the new versions from it
+                let tuple_variable = Variable::with_id_and_type(
+                    self.issue_next_identifier(CoreIdentifier::IfElse),
+                    Type::Tuple(ty.clone()),
+                );
+                let tuple_identifier =
+                    TupleExpressionInner::Identifier(tuple_variable.id.clone()).annotate(ty);
+
+                let definitions: Vec<_> = variables
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, v)| {
+                        let v = self.issue_next_ssa_variable(v);
+                        TypedStatement::Definition(
+                            TypedAssignee::Identifier(self.fold_variable(v)),
+                            TypedExpression::element(tuple_identifier.clone(), index as u32),
+                        )
+                    })
+                    .collect();
+
+                std::iter::once(TypedStatement::Definition(
+                    tuple_variable.into(),
+                    TupleExpression::if_else(condition, consequence, alternative).into(),
+                ))
+                .chain(definitions)
+                .collect()
+            }
             s => fold_statement(self, s),
         }
     }
@@ -200,8 +415,8 @@ mod tests {
         #[test]
         fn detect_non_constant_bound() {
             let loops: Vec<TypedStatement<Bn128Field>> = vec![TypedStatement::For(
-                Variable::uint("i", UBitwidth::B32),
-                UExpressionInner::Identifier("i".into()).annotate(UBitwidth::B32),
+                Variable::uint("i", IntBitwidth::B32),
+                UExpressionInner::Identifier("i".into()).annotate(IntBitwidth::B32),
                 2u32.into(),
                 vec![],
             )];
@@ -402,6 +617,96 @@ mod tests {
             );
         }
 
+        #[test]
+        fn tuple_destructuring() {
+            use crate::typed_absy::types::Type;
+
+            // field a
+            // a = 2
+            // field b
+            // a, b = (a, 3)
+
+            // should be turned into
+            // a_0 = 2
+            // #DESTRUCTURED_TUPLE = (a_0, 3)
+            // a_1 = #DESTRUCTURED_TUPLE.0
+            // b_0 = #DESTRUCTURED_TUPLE.1
+
+            let mut versions = Versions::new();
+
+            let mut u = ShallowTransformer::with_versions(&mut versions);
+
+            let s = TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("a")),
+                FieldElementExpression::Number(Bn128Field::from(2)).into(),
+            );
+            assert_eq!(
+                u.fold_statement(s),
+                vec![TypedStatement::Definition(
+                    TypedAssignee::Identifier(Variable::field_element(
+                        Identifier::from("a").version(0)
+                    )),
+                    FieldElementExpression::Number(Bn128Field::from(2)).into()
+                )]
+            );
+
+            let tuple_ty = TupleType::new(vec![Type::FieldElement, Type::FieldElement]);
+
+            let s: TypedStatement<Bn128Field> = TypedStatement::MultipleDefinition(
+                vec![
+                    Variable::field_element("a").into(),
+                    Variable::field_element("b").into(),
+                ],
+                TypedExpressionListInner::Tuple(
+                    TupleExpressionInner::Value(vec![
+                        FieldElementExpression::Identifier("a".into()).into(),
+                        FieldElementExpression::Number(Bn128Field::from(3)).into(),
+                    ])
+                    .annotate(tuple_ty.clone()),
+                )
+                .annotate(Types::new(vec![Type::FieldElement, Type::FieldElement])),
+            );
+
+            let tuple_identifier = Identifier::from(CoreIdentifier::Destructuring).version(0);
+
+            assert_eq!(
+                u.fold_statement(s),
+                vec![
+                    TypedStatement::Definition(
+                        Variable::with_id_and_type(
+                            tuple_identifier.clone(),
+                            Type::Tuple(tuple_ty.clone())
+                        )
+                        .into(),
+                        TupleExpressionInner::Value(vec![
+                            FieldElementExpression::Identifier(Identifier::from("a").version(0))
+                                .into(),
+                            FieldElementExpression::Number(Bn128Field::from(3)).into(),
+                        ])
+                        .annotate(tuple_ty.clone())
+                        .into()
+                    ),
+                    TypedStatement::Definition(
+                        Variable::field_element(Identifier::from("a").version(1)).into(),
+                        FieldElementExpression::element(
+                            TupleExpressionInner::Identifier(tuple_identifier.clone())
+                                .annotate(tuple_ty.clone()),
+                            0
+                        )
+                        .into()
+                    ),
+                    TypedStatement::Definition(
+                        Variable::field_element(Identifier::from("b").version(0)).into(),
+                        FieldElementExpression::element(
+                            TupleExpressionInner::Identifier(tuple_identifier).annotate(tuple_ty),
+                            1
+                        )
+                        .into()
+                    )
+                ]
+            );
+        }
+
         #[test]
         fn incremental_array_definition() {
             // field[2] a = [1, 1]
@@ -571,6 +876,130 @@ mod tests {
 
             assert_eq!(u.fold_statement(s.clone()), vec![s]);
         }
+
+        #[test]
+        fn if_else() {
+            // field a = 1
+            // if c {
+            //    a = 2
+            // } else {
+            // }
+
+            // should be turned into
+            // a_0 = 1
+            // (field) #IF_ELSE_VALUES = if c_0 then { a_1 = a_0; a_2 = 2; (a_2) } else { a_3 = a_0; (a_3) } fi
+            // a_4 = #IF_ELSE_VALUES.0
+
+            let mut versions = Versions::new();
+
+            let mut u = ShallowTransformer::with_versions(&mut versions);
+
+            let s: TypedStatement<Bn128Field> = TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("a")),
+                FieldElementExpression::Number(Bn128Field::from(1)).into(),
+            );
+            u.fold_statement(s);
+
+            let s = TypedStatement::IfElse(
+                BooleanExpression::Identifier("c".into()),
+                vec![TypedStatement::Definition(
+                    TypedAssignee::Identifier(Variable::field_element("a")),
+                    FieldElementExpression::Number(Bn128Field::from(2)).into(),
+                )],
+                vec![],
+            );
+
+            let ty = TupleType::new(vec![Type::FieldElement]);
+            let tuple_id = Identifier::from(CoreIdentifier::IfElse).version(0);
+            let a = |version| Identifier::from("a").version(version);
+            let copy = |version| {
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(Variable::field_element(a(version))),
+                    FieldElementExpression::Identifier(a(0)).into(),
+                )
+            };
+            let value = |version| {
+                TupleExpressionInner::Value(vec![
+                    FieldElementExpression::Identifier(a(version)).into()
+                ])
+                .annotate(ty.clone())
+            };
+
+            assert_eq!(
+                u.fold_statement(s),
+                vec![
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::with_id_and_type(
+                            tuple_id.clone(),
+                            Type::Tuple(ty.clone())
+                        )),
+                        TupleExpression::if_else(
+                            BooleanExpression::Identifier("c".into()),
+                            TupleExpression::block(
+                                vec![
+                                    copy(1),
+                                    TypedStatement::Definition(
+                                        TypedAssignee::Identifier(Variable::field_element(a(2))),
+                                        FieldElementExpression::Number(Bn128Field::from(2)).into(),
+                                    )
+                                ],
+                                value(2)
+                            ),
+                            TupleExpression::block(vec![copy(3)], value(3))
+                        )
+                        .into()
+                    ),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element(a(4))),
+                        TypedExpression::element(
+                            TupleExpressionInner::Identifier(tuple_id).annotate(ty.clone()),
+                            0
+                        )
+                    )
+                ]
+            );
+        }
+
+        #[test]
+        fn if_else_with_shadowing_loop() {
+            // u32 i = 0
+            // u32 s = 0
+            // if c {
+            //    i = 1
+            //    for u32 i in 0..2 do
+            //        s = s + i
+            //    endfor
+            // }
+
+            // the loop variable is only declared in the loop body, so the outer `i` is still assigned in the branch,
+            // along with `s`
+
+            let consequence: Vec<TypedStatement<Bn128Field>> = vec![
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(Variable::uint("i", IntBitwidth::B32)),
+                    UExpression::from(1u32).into(),
+                ),
+                TypedStatement::For(
+                    Variable::uint("i", IntBitwidth::B32),
+                    0u32.into(),
+                    2u32.into(),
+                    vec![TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::uint("s", IntBitwidth::B32)),
+                        (UExpressionInner::Identifier("s".into()).annotate(IntBitwidth::B32)
+                            + UExpressionInner::Identifier("i".into()).annotate(IntBitwidth::B32))
+                        .into(),
+                    )],
+                ),
+            ];
+
+            assert_eq!(
+                assigned_outer_variables(&[&consequence[..], &[]]),
+                vec![
+                    Variable::uint("i", IntBitwidth::B32),
+                    Variable::uint("s", IntBitwidth::B32)
+                ]
+            );
+        }
     }
 
     mod for_loop {
@@ -615,13 +1044,13 @@ mod tests {
                 arguments: vec![DeclarationVariable::field_element("a").into()],
                 statements: vec![
                     TypedStatement::Definition(
-                        Variable::uint("n", UBitwidth::B32).into(),
+                        Variable::uint("n", IntBitwidth::B32).into(),
                         TypedExpression::Uint(42u32.into()),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint("n", UBitwidth::B32).into(),
+                        Variable::uint("n", IntBitwidth::B32).into(),
                         UExpressionInner::Identifier("n".into())
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             .into(),
                     ),
                     TypedStatement::Definition(
@@ -629,10 +1058,10 @@ mod tests {
                         FieldElementExpression::Identifier("a".into()).into(),
                     ),
                     TypedStatement::For(
-                        Variable::uint("i", UBitwidth::B32),
-                        UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32),
-                        UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32)
-                            * UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32),
+                        Variable::uint("i", IntBitwidth::B32),
+                        UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32),
+                        UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32)
+                            * UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32),
                         vec![TypedStatement::Definition(
                             Variable::field_element("a").into(),
                             FieldElementExpression::Identifier("a".into()).into(),
@@ -643,10 +1072,10 @@ mod tests {
                         FieldElementExpression::Identifier("a".into()).into(),
                     ),
                     TypedStatement::For(
-                        Variable::uint("i", UBitwidth::B32),
-                        UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32),
-                        UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32)
-                            * UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32),
+                        Variable::uint("i", IntBitwidth::B32),
+                        UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32),
+                        UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32)
+                            * UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32),
                         vec![TypedStatement::Definition(
                             Variable::field_element("a").into(),
                             FieldElementExpression::Identifier("a".into()).into(),
@@ -672,11 +1101,12 @@ mod tests {
 
             let ssa = ShallowTransformer::transform(
                 f,
-                &GGenericsAssignment(
-                    vec![(GenericIdentifier::with_name("K").index(0), 1)]
+                &GGenericsAssignment {
+                    constants: vec![(GenericIdentifier::with_name("K").index(0), 1)]
                         .into_iter()
                         .collect(),
-                ),
+                    ..Default::default()
+                },
                 &mut versions,
             );
 
@@ -684,17 +1114,17 @@ mod tests {
                 arguments: vec![DeclarationVariable::field_element("a").into()],
                 statements: vec![
                     TypedStatement::Definition(
-                        Variable::uint("K", UBitwidth::B32).into(),
+                        Variable::uint("K", IntBitwidth::B32).into(),
                         TypedExpression::Uint(1u32.into()),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint("n", UBitwidth::B32).into(),
+                        Variable::uint("n", IntBitwidth::B32).into(),
                         TypedExpression::Uint(42u32.into()),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint(Identifier::from("n").version(1), UBitwidth::B32).into(),
+                        Variable::uint(Identifier::from("n").version(1), IntBitwidth::B32).into(),
                         UExpressionInner::Identifier("n".into())
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             .into(),
                     ),
                     TypedStatement::Definition(
@@ -702,13 +1132,13 @@ mod tests {
                         FieldElementExpression::Identifier("a".into()).into(),
                     ),
                     TypedStatement::For(
-                        Variable::uint("i", UBitwidth::B32),
+                        Variable::uint("i", IntBitwidth::B32),
                         UExpressionInner::Identifier(Identifier::from("n").version(1))
-                            .annotate(UBitwidth::B32),
+                            .annotate(IntBitwidth::B32),
                         UExpressionInner::Identifier(Identifier::from("n").version(1))
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             * UExpressionInner::Identifier(Identifier::from("n").version(1))
-                                .annotate(UBitwidth::B32),
+                                .annotate(IntBitwidth::B32),
                         vec![TypedStatement::Definition(
                             Variable::field_element("a").into(),
                             FieldElementExpression::Identifier("a".into()).into(),
@@ -719,13 +1149,13 @@ mod tests {
                         FieldElementExpression::Identifier(Identifier::from("a").version(3)).into(),
                     ),
                     TypedStatement::For(
-                        Variable::uint("i", UBitwidth::B32),
+                        Variable::uint("i", IntBitwidth::B32),
                         UExpressionInner::Identifier(Identifier::from("n").version(3))
-                            .annotate(UBitwidth::B32),
+                            .annotate(IntBitwidth::B32),
                         UExpressionInner::Identifier(Identifier::from("n").version(3))
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             * UExpressionInner::Identifier(Identifier::from("n").version(3))
-                                .annotate(UBitwidth::B32),
+                                .annotate(IntBitwidth::B32),
                         vec![TypedStatement::Definition(
                             Variable::field_element("a").into(),
                             FieldElementExpression::Identifier("a".into()).into(),
@@ -802,13 +1232,13 @@ mod tests {
                 arguments: vec![DeclarationVariable::field_element("a").into()],
                 statements: vec![
                     TypedStatement::Definition(
-                        Variable::uint("n", UBitwidth::B32).into(),
+                        Variable::uint("n", IntBitwidth::B32).into(),
                         TypedExpression::Uint(42u32.into()),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint("n", UBitwidth::B32).into(),
+                        Variable::uint("n", IntBitwidth::B32).into(),
                         UExpressionInner::Identifier("n".into())
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             .into(),
                     ),
                     TypedStatement::Definition(
@@ -820,16 +1250,16 @@ mod tests {
                         TypedExpressionList::function_call(
                             DeclarationFunctionKey::with_location("main", "foo"),
                             vec![Some(
-                                UExpressionInner::Identifier("n".into()).annotate(UBitwidth::B32),
+                                UExpressionInner::Identifier("n".into()).annotate(IntBitwidth::B32),
                             )],
                             vec![FieldElementExpression::Identifier("a".into()).into()],
                         )
                         .annotate(Types::new(vec![Type::FieldElement])),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint("n", UBitwidth::B32).into(),
+                        Variable::uint("n", IntBitwidth::B32).into(),
                         UExpressionInner::Identifier("n".into())
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             .into(),
                     ),
                     TypedStatement::Definition(
@@ -839,7 +1269,7 @@ mod tests {
                                 DeclarationFunctionKey::with_location("main", "foo"),
                                 vec![Some(
                                     UExpressionInner::Identifier("n".into())
-                                        .annotate(UBitwidth::B32),
+                                        .annotate(IntBitwidth::B32),
                                 )],
                                 vec![FieldElementExpression::Identifier("a".into()).into()],
                             ))
@@ -861,11 +1291,12 @@ mod tests {
 
             let ssa = ShallowTransformer::transform(
                 f,
-                &GGenericsAssignment(
-                    vec![(GenericIdentifier::with_name("K").index(0), 1)]
+                &GGenericsAssignment {
+                    constants: vec![(GenericIdentifier::with_name("K").index(0), 1)]
                         .into_iter()
                         .collect(),
-                ),
+                    ..Default::default()
+                },
                 &mut versions,
             );
 
@@ -873,17 +1304,17 @@ mod tests {
                 arguments: vec![DeclarationVariable::field_element("a").into()],
                 statements: vec![
                     TypedStatement::Definition(
-                        Variable::uint("K", UBitwidth::B32).into(),
+                        Variable::uint("K", IntBitwidth::B32).into(),
                         TypedExpression::Uint(1u32.into()),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint("n", UBitwidth::B32).into(),
+                        Variable::uint("n", IntBitwidth::B32).into(),
                         TypedExpression::Uint(42u32.into()),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint(Identifier::from("n").version(1), UBitwidth::B32).into(),
+                        Variable::uint(Identifier::from("n").version(1), IntBitwidth::B32).into(),
                         UExpressionInner::Identifier("n".into())
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             .into(),
                     ),
                     TypedStatement::Definition(
@@ -896,7 +1327,7 @@ mod tests {
                             DeclarationFunctionKey::with_location("main", "foo"),
                             vec![Some(
                                 UExpressionInner::Identifier(Identifier::from("n").version(1))
-                                    .annotate(UBitwidth::B32),
+                                    .annotate(IntBitwidth::B32),
                             )],
                             vec![FieldElementExpression::Identifier(
                                 Identifier::from("a").version(1),
@@ -906,9 +1337,9 @@ mod tests {
                         .annotate(Types::new(vec![Type::FieldElement])),
                     ),
                     TypedStatement::Definition(
-                        Variable::uint(Identifier::from("n").version(2), UBitwidth::B32).into(),
+                        Variable::uint(Identifier::from("n").version(2), IntBitwidth::B32).into(),
                         UExpressionInner::Identifier(Identifier::from("n").version(1))
-                            .annotate(UBitwidth::B32)
+                            .annotate(IntBitwidth::B32)
                             .into(),
                     ),
                     TypedStatement::Definition(
@@ -918,7 +1349,7 @@ mod tests {
                                 DeclarationFunctionKey::with_location("main", "foo"),
                                 vec![Some(
                                     UExpressionInner::Identifier(Identifier::from("n").version(2))
-                                        .annotate(UBitwidth::B32),
+                                        .annotate(IntBitwidth::B32),
                                 )],
                                 vec![FieldElementExpression::Identifier(
                                     Identifier::from("a").version(2),
@@ -0,0 +1,198 @@
+// The body of a function which is generic over types is checked once, with each type parameter standing for an
+// opaque struct type. When a call to this function is inlined, its type parameters are bound to the types inferred
+// from the call site, so the opaque types are replaced with these types.
+
+// Given:
+// ```
+// def swap<type T>(T a, T b) -> (T, T):
+//		return b, a
+// ```
+//
+// The call site
+// ```
+// swap(x, y) // with field x, y
+// ```
+//
+// Inlines the body
+// ```
+// def swap(field a, field b) -> (field, field):
+//		return b, a
+// ```
+
+// Notes:
+// - Expressions of an opaque type are struct expressions, which are rebuilt as expressions of the bound type
+// - The keys of the functions called in the body keep their own type parameters, they are bound when these calls get
+//   inlined in turn
+
+use crate::typed_absy::folder::Folder;
+use crate::typed_absy::types::{ConcreteGenericsAssignment, StructLocation};
+use crate::typed_absy::*;
+use std::collections::HashMap;
+use zokrates_field::Field;
+
+pub struct TypeArgumentsBinder<'ast, 'a> {
+    // the value of the type parameters, by identifier in the declaration types
+    assignment: &'a ConcreteGenericsAssignment<'ast>,
+    // the value of the type parameters, by location of the opaque types standing for them in the body
+    opaque_types: HashMap<StructLocation, ConcreteType>,
+}
+
+impl<'ast, 'a> TypeArgumentsBinder<'ast, 'a> {
+    pub fn bind<T: Field>(
+        f: TypedFunction<'ast, T>,
+        key: &DeclarationFunctionKey<'ast>,
+        assignment: &'a ConcreteGenericsAssignment<'ast>,
+    ) -> TypedFunction<'ast, T> {
+        let opaque_types = assignment
+            .types
+            .iter()
+            .map(|(id, ty)| (id.opaque_type(key).canonical_location, ty.clone()))
+            .collect();
+
+        TypeArgumentsBinder {
+            assignment,
+            opaque_types,
+        }
+        .fold_function(f)
+    }
+
+    fn fold_opaque_expression<T: Field>(
+        &mut self,
+        ty: Type<'ast, T>,
+        e: StructExpressionInner<'ast, T>,
+    ) -> TypedExpression<'ast, T> {
+        match e {
+            StructExpressionInner::Identifier(id) => Variable::with_id_and_type(id, ty).into(),
+            StructExpressionInner::Block(block) => TypedExpression::block(
+                block
+                    .statements
+                    .into_iter()
+                    .flat_map(|s| self.fold_statement(s))
+                    .collect(),
+                self.fold_expression((*block.value).into()),
+            ),
+            StructExpressionInner::IfElse(e) => TypedExpression::if_else(
+                self.fold_boolean_expression(*e.condition),
+                self.fold_expression((*e.consequence).into()),
+                self.fold_expression((*e.alternative).into()),
+            ),
+            StructExpressionInner::Select(e) => TypedExpression::select(
+                self.fold_array_expression(*e.array),
+                self.fold_uint_expression(*e.index),
+            ),
+            StructExpressionInner::Member(e) => {
+                TypedExpression::member(self.fold_struct_expression(*e.struc), e.id)
+            }
+            StructExpressionInner::Element(e) => {
+                TypedExpression::element(self.fold_tuple_expression(*e.tuple), e.index)
+            }
+            StructExpressionInner::FunctionCall(e) => {
+                let key = e.function_key;
+                let generics = e
+                    .generics
+                    .into_iter()
+                    .map(|g| g.map(|g| self.fold_uint_expression(g)))
+                    .collect();
+                let arguments = e
+                    .arguments
+                    .into_iter()
+                    .map(|a| self.fold_expression(a))
+                    .collect();
+
+                match ty {
+                    Type::FieldElement => {
+                        FieldElementExpression::function_call(key, generics, arguments).into()
+                    }
+                    Type::Boolean => {
+                        BooleanExpression::function_call(key, generics, arguments).into()
+                    }
+                    Type::Uint(bitwidth) => UExpression::function_call(key, generics, arguments)
+                        .annotate(bitwidth)
+                        .into(),
+                    Type::Array(array_ty) => {
+                        ArrayExpression::function_call(key, generics, arguments)
+                            .annotate(*array_ty.ty, array_ty.size)
+                            .into()
+                    }
+                    Type::Struct(struct_ty) => {
+                        StructExpression::function_call(key, generics, arguments)
+                            .annotate(struct_ty)
+                            .into()
+                    }
+                    Type::Tuple(tuple_ty) => {
+                        TupleExpression::function_call(key, generics, arguments)
+                            .annotate(tuple_ty)
+                            .into()
+                    }
+                    Type::Enum(enum_ty) => EnumExpression::function_call(key, generics, arguments)
+                        .annotate(enum_ty)
+                        .into(),
+                    Type::Int | Type::Generic(..) => unreachable!(),
+                }
+            }
+            StructExpressionInner::Value(..) => {
+                unreachable!("values of a type parameter cannot be built")
+            }
+        }
+    }
+}
+
+impl<'ast, 'a, T: Field> Folder<'ast, T> for TypeArgumentsBinder<'ast, 'a> {
+    fn fold_declaration_function_key(
+        &mut self,
+        key: DeclarationFunctionKey<'ast>,
+    ) -> DeclarationFunctionKey<'ast> {
+        key
+    }
+
+    fn fold_declaration_type(&mut self, t: DeclarationType<'ast>) -> DeclarationType<'ast> {
+        match t {
+            DeclarationType::Generic(id) => self.assignment.types.get(&id).unwrap().clone().into(),
+            DeclarationType::Array(array_type) => DeclarationType::Array(
+                <Self as Folder<'ast, T>>::fold_declaration_array_type(self, array_type),
+            ),
+            DeclarationType::Struct(struct_type) => DeclarationType::Struct(
+                <Self as Folder<'ast, T>>::fold_declaration_struct_type(self, struct_type),
+            ),
+            DeclarationType::Tuple(tuple_type) => DeclarationType::Tuple(
+                <Self as Folder<'ast, T>>::fold_declaration_tuple_type(self, tuple_type),
+            ),
+            DeclarationType::Enum(enum_type) => DeclarationType::Enum(
+                <Self as Folder<'ast, T>>::fold_declaration_enum_type(self, enum_type),
+            ),
+            t => t,
+        }
+    }
+
+    fn fold_type(&mut self, t: Type<'ast, T>) -> Type<'ast, T> {
+        match t {
+            Type::Struct(struct_ty) => match self.opaque_types.get(&struct_ty.canonical_location) {
+                Some(ty) => ty.clone().into(),
+                None => Type::Struct(self.fold_struct_type(struct_ty)),
+            },
+            Type::Array(array_type) => Type::Array(self.fold_array_type(array_type)),
+            Type::Tuple(tuple_type) => Type::Tuple(self.fold_tuple_type(tuple_type)),
+            Type::Enum(enum_type) => Type::Enum(self.fold_enum_type(enum_type)),
+            t => t,
+        }
+    }
+
+    fn fold_expression(&mut self, e: TypedExpression<'ast, T>) -> TypedExpression<'ast, T> {
+        match e {
+            TypedExpression::Struct(e) => match self.opaque_types.get(&e.ty().canonical_location) {
+                Some(ty) => {
+                    let ty = ty.clone().into();
+                    self.fold_opaque_expression(ty, e.into_inner())
+                }
+                None => self.fold_struct_expression(e).into(),
+            },
+            TypedExpression::FieldElement(e) => self.fold_field_expression(e).into(),
+            TypedExpression::Boolean(e) => self.fold_boolean_expression(e).into(),
+            TypedExpression::Uint(e) => self.fold_uint_expression(e).into(),
+            TypedExpression::Array(e) => self.fold_array_expression(e).into(),
+            TypedExpression::Tuple(e) => self.fold_tuple_expression(e).into(),
+            TypedExpression::Enum(e) => self.fold_enum_expression(e).into(),
+            TypedExpression::Int(e) => self.fold_int_expression(e).into(),
+        }
+    }
+}
@@ -96,6 +96,11 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
 
                 BooleanExpression::UintEq(box left, box right)
             }
+            BooleanExpression::UintBit(box e, index) => {
+                let e = self.fold_uint_expression(e);
+
+                BooleanExpression::UintBit(box force_reduce(e), index)
+            }
             BooleanExpression::UintLt(box left, box right) => {
                 let left = self.fold_uint_expression(left);
                 let right = self.fold_uint_expression(right);
@@ -145,9 +150,9 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
 
         let range = e.bitwidth.to_usize();
 
-        let range_max: T = (2_u128.pow(range as u32) - 1).into();
+        let range_max: T = e.bitwidth.max_value().into();
 
-        assert!(range < max_bitwidth / 2);
+        assert!(range + 1 < max_bitwidth);
 
         let inner = e.inner;
 
@@ -314,6 +319,8 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 let left_max = left.metadata.clone().unwrap().max;
                 let right_max = right.metadata.clone().unwrap().max;
 
+                let requires_limbs = e.bitwidth.requires_limbs::<T>();
+
                 let (should_reduce_left, should_reduce_right, max) = left_max
                     .checked_mul(&right_max)
                     .map(|max| (false, false, max))
@@ -325,7 +332,11 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                                 left_max
                                     .checked_mul(&range_max.clone())
                                     .map(|max| (false, true, max))
-                                    .unwrap_or_else(|| (true, true, range_max.clone() * range_max))
+                                    .unwrap_or_else(|| match requires_limbs {
+                                        // the product is computed on limbs, see the flattener
+                                        true => (true, true, range_max.clone() + range_max),
+                                        false => (true, true, range_max.clone() * range_max),
+                                    })
                             })
                     });
 
@@ -368,9 +379,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 let e = self.fold_uint_expression(e);
 
                 let e_max: num_bigint::BigUint = e.metadata.as_ref().unwrap().max.to_biguint();
-                let max = e_max
-                    .shl(by as usize)
-                    .bitand(&(2_u128.pow(range as u32) - 1).into());
+                let max = e_max.shl(by as usize).bitand(&range_max.to_biguint());
 
                 let max = T::try_from(max).unwrap();
 
@@ -381,14 +390,30 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 let e = self.fold_uint_expression(e);
 
                 let e_max: num_bigint::BigUint = e.metadata.as_ref().unwrap().max.to_biguint();
-                let max = e_max
-                    .bitand(&(2_u128.pow(range as u32) - 1).into())
-                    .shr(by as usize);
+                let max = e_max.bitand(&range_max.to_biguint()).shr(by as usize);
 
                 let max = T::try_from(max).unwrap();
 
                 UExpression::right_shift(force_reduce(e), by).with_max(max)
             }
+            LeftRotate(box e, by) => {
+                // reduce the term to permute its bits
+                let e = self.fold_uint_expression(e);
+
+                UExpression::left_rotate(force_reduce(e), by).with_max(range_max)
+            }
+            RightRotate(box e, by) => {
+                // reduce the term to permute its bits
+                let e = self.fold_uint_expression(e);
+
+                UExpression::right_rotate(force_reduce(e), by).with_max(range_max)
+            }
+            Slice(box e, from, to) => {
+                // reduce the term to access its bits
+                let e = self.fold_uint_expression(e);
+
+                UExpression::slice(force_reduce(e), from, to).with_max(range_max)
+            }
             IfElse(box condition, box consequence, box alternative) => {
                 let condition = self.fold_boolean_expression(condition);
                 let consequence = self.fold_uint_expression(consequence);
@@ -449,7 +474,17 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 ZirExpressionList::EmbedCall(embed, generics, arguments),
             ) => {
                 match embed {
-                    FlatEmbed::U64FromBits => {
+                    FlatEmbed::U128FromBits => {
+                        assert_eq!(lhs.len(), 1);
+                        self.register(
+                            lhs[0].clone(),
+                            UMetadata {
+                                max: T::from(2).pow(128) - T::from(1),
+                                should_reduce: ShouldReduce::False,
+                            },
+                        );
+                    }
+                    FlatEmbed::U64FromBits | FlatEmbed::I64FromBits => {
                         assert_eq!(lhs.len(), 1);
                         self.register(
                             lhs[0].clone(),
@@ -459,7 +494,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                             },
                         );
                     }
-                    FlatEmbed::U32FromBits => {
+                    FlatEmbed::U32FromBits | FlatEmbed::I32FromBits => {
                         assert_eq!(lhs.len(), 1);
                         self.register(
                             lhs[0].clone(),
@@ -469,7 +504,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                             },
                         );
                     }
-                    FlatEmbed::U16FromBits => {
+                    FlatEmbed::U16FromBits | FlatEmbed::I16FromBits => {
                         assert_eq!(lhs.len(), 1);
                         self.register(
                             lhs[0].clone(),
@@ -479,7 +514,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                             },
                         );
                     }
-                    FlatEmbed::U8FromBits => {
+                    FlatEmbed::U8FromBits | FlatEmbed::I8FromBits => {
                         assert_eq!(lhs.len(), 1);
                         self.register(
                             lhs[0].clone(),
@@ -496,7 +531,12 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                     FlatEmbed::U8ToBits
                     | FlatEmbed::U16ToBits
                     | FlatEmbed::U32ToBits
-                    | FlatEmbed::U64ToBits => {
+                    | FlatEmbed::U64ToBits
+                    | FlatEmbed::U128ToBits
+                    | FlatEmbed::I8ToBits
+                    | FlatEmbed::I16ToBits
+                    | FlatEmbed::I32ToBits
+                    | FlatEmbed::I64ToBits => {
                         vec![ZirStatement::MultipleDefinition(
                             lhs,
                             ZirExpressionList::EmbedCall(
@@ -531,7 +571,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                     }
                 }
             }
-            ZirStatement::Assertion(BooleanExpression::UintEq(box left, box right)) => {
+            ZirStatement::Assertion(BooleanExpression::UintEq(box left, box right), error) => {
                 let left = self.fold_uint_expression(left);
                 let right = self.fold_uint_expression(right);
 
@@ -539,9 +579,10 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 let left = force_reduce(left);
                 let right = force_reduce(right);
 
-                vec![ZirStatement::Assertion(BooleanExpression::UintEq(
-                    box left, box right,
-                ))]
+                vec![ZirStatement::Assertion(
+                    BooleanExpression::UintEq(box left, box right),
+                    error,
+                )]
             }
             s => fold_statement(self, s),
         }
@@ -1,27 +1,48 @@
 use crate::flat_absy::{FlatParameter, FlatVariable};
-use crate::ir::visitor::Visitor;
-use crate::ir::Directive;
+use crate::ir::visitor::{self, Visitor};
 use crate::ir::Prog;
-use std::collections::HashSet;
+use crate::ir::{Directive, Statement};
+use crate::source_map::SourceLocation;
+use std::collections::HashMap;
 use std::fmt;
 use zokrates_field::Field;
 
 #[derive(Debug, Default)]
 pub struct UnconstrainedVariableDetector {
-    pub(self) variables: HashSet<FlatVariable>,
+    /// The unconstrained variables, along with the index of the statement introducing them, if any
+    pub(self) variables: HashMap<FlatVariable, Option<usize>>,
+    /// The index of the statement being visited
+    statement: usize,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Error(usize);
+pub struct Error {
+    count: usize,
+    locations: Vec<SourceLocation>,
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
             "Found unconstrained variables during IR analysis (found {} occurrence{})",
-            self.0,
-            if self.0 == 1 { "" } else { "s" }
-        )
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )?;
+
+        if !self.locations.is_empty() {
+            write!(
+                f,
+                ", introduced at {}",
+                self.locations
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+
+        Ok(())
     }
 }
 
@@ -33,7 +54,18 @@ impl UnconstrainedVariableDetector {
         if instance.variables.is_empty() {
             Ok(())
         } else {
-            Err(Error(instance.variables.len()))
+            let mut locations: Vec<_> = instance
+                .variables
+                .values()
+                .filter_map(|index| index.and_then(|index| p.location(index)))
+                .collect();
+            locations.sort();
+            locations.dedup();
+
+            Err(Error {
+                count: instance.variables.len(),
+                locations,
+            })
         }
     }
 }
@@ -41,14 +73,20 @@ impl UnconstrainedVariableDetector {
 impl<T: Field> Visitor<T> for UnconstrainedVariableDetector {
     fn visit_argument(&mut self, p: &FlatParameter) {
         if p.private {
-            self.variables.insert(p.id);
+            self.variables.insert(p.id, None);
         }
     }
     fn visit_variable(&mut self, v: &FlatVariable) {
         self.variables.remove(v);
     }
+    fn visit_statement(&mut self, s: &Statement<T>) {
+        visitor::visit_statement(self, s);
+        self.statement += 1;
+    }
     fn visit_directive(&mut self, d: &Directive<T>) {
-        self.variables.extend(d.outputs.iter());
+        let statement = self.statement;
+        self.variables
+            .extend(d.outputs.iter().map(|o| (*o, Some(statement))));
     }
 }
 
@@ -58,6 +96,7 @@ mod tests {
     use crate::flat_absy::FlatVariable;
     use crate::ir::{LinComb, Prog, QuadComb, Statement};
     use crate::solvers::Solver;
+    use crate::source_map::SourceMap;
     use zokrates_field::Bn128Field;
 
     #[test]
@@ -81,6 +120,7 @@ mod tests {
                 LinComb::summand(1, out_0),
             )],
             returns: vec![out_0],
+            source_map: None,
         };
 
         let result = UnconstrainedVariableDetector::detect(&p);
@@ -90,6 +130,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unconstrained_directive_outputs() {
+        // def main() -> (1):
+        //     # _0, _1 = ConditionEq(42 * ~one) // main.zok:42:42
+        //     (1 * ~one) * (42 * ~one) == 1 * ~out_0
+        //     return ~out_0
+
+        let _0 = FlatVariable::new(0);
+        let _1 = FlatVariable::new(1);
+
+        let one = FlatVariable::one();
+        let out_0 = FlatVariable::public(0);
+
+        let mut source_map = SourceMap::default();
+        source_map.push(Some((&SourceLocation::mock(), &[])));
+        source_map.push(None);
+
+        let p: Prog<Bn128Field> = Prog {
+            arguments: vec![],
+            statements: vec![
+                Statement::Directive(Directive {
+                    inputs: vec![LinComb::summand(42, one).into()],
+                    outputs: vec![_0, _1],
+                    solver: Solver::ConditionEq,
+                }),
+                Statement::constraint(
+                    QuadComb::from_linear_combinations(
+                        LinComb::summand(1, one),
+                        LinComb::summand(42, one),
+                    ),
+                    LinComb::summand(1, out_0),
+                ),
+            ],
+            returns: vec![out_0],
+            source_map: Some(source_map),
+        };
+
+        let result = UnconstrainedVariableDetector::detect(&p);
+        assert_eq!(
+            result.expect_err("expected an error").to_string(),
+            "Found unconstrained variables during IR analysis (found 2 occurrences), introduced at main.zok:42:42"
+        );
+    }
+
     #[test]
     fn constrained_private_input() {
         // def main(_0) -> (1):
@@ -103,6 +187,7 @@ mod tests {
             arguments: vec![_0],
             statements: vec![Statement::definition(out_0, LinComb::from(_0.id))],
             returns: vec![out_0],
+            source_map: None,
         };
 
         let result = UnconstrainedVariableDetector::detect(&p);
@@ -156,6 +241,7 @@ mod tests {
                 ),
             ],
             returns: vec![out_0],
+            source_map: None,
         };
 
         let result = UnconstrainedVariableDetector::detect(&p);
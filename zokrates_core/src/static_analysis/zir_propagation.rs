@@ -51,9 +51,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
         s: ZirStatement<'ast, T>,
     ) -> Result<Vec<ZirStatement<'ast, T>>, Self::Error> {
         match s {
-            ZirStatement::Assertion(e) => match self.fold_boolean_expression(e)? {
+            ZirStatement::Assertion(e, error) => match self.fold_boolean_expression(e)? {
                 BooleanExpression::Value(true) => Ok(vec![]),
-                e => Ok(vec![ZirStatement::Assertion(e)]),
+                e => Ok(vec![ZirStatement::Assertion(e, error)]),
             },
             ZirStatement::Definition(a, e) => {
                 let e = self.fold_expression(e)?;
@@ -367,6 +367,17 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                     }
                 }
             }
+            BooleanExpression::UintBit(box e, index) => {
+                let e = self.fold_uint_expression(e)?;
+                let bitwidth = e.bitwidth().to_usize() as u32;
+
+                match e.as_inner() {
+                    UExpressionInner::Value(n) => Ok(BooleanExpression::Value(
+                        (n >> (bitwidth - 1 - index)) & 1 == 1,
+                    )),
+                    _ => Ok(BooleanExpression::UintBit(box e, index)),
+                }
+            }
             BooleanExpression::BoolEq(box e1, box e2) => {
                 match (
                     self.fold_boolean_expression(e1)?,
@@ -472,7 +483,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e1.into_inner(), e2.into_inner()) {
                     (UExpressionInner::Value(0), e) | (e, UExpressionInner::Value(0)) => Ok(e),
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 + n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
+                        UExpressionInner::Value(n1.wrapping_add(n2) & bitwidth.max_value()),
                     ),
                     (e1, e2) => Ok(UExpressionInner::Add(
                         box e1.annotate(bitwidth),
@@ -486,11 +497,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
 
                 match (e1.into_inner(), e2.into_inner()) {
                     (e, UExpressionInner::Value(0)) => Ok(e),
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(UExpressionInner::Value(
-                            n1.wrapping_sub(n2) % 2_u128.pow(bitwidth.to_usize() as u32),
-                        ))
-                    }
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
+                        UExpressionInner::Value(n1.wrapping_sub(n2) & bitwidth.max_value()),
+                    ),
                     (e1, e2) => Ok(UExpressionInner::Sub(
                         box e1.annotate(bitwidth),
                         box e2.annotate(bitwidth),
@@ -507,7 +516,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                     }
                     (e, UExpressionInner::Value(1)) | (UExpressionInner::Value(1), e) => Ok(e),
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 * n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
+                        UExpressionInner::Value(n1.wrapping_mul(n2) & bitwidth.max_value()),
                     ),
                     (e1, e2) => Ok(UExpressionInner::Mult(
                         box e1.annotate(bitwidth),
@@ -522,9 +531,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e1.into_inner(), e2.into_inner()) {
                     (_, UExpressionInner::Value(n)) if n == 0 => Err(Error::DivisionByZero),
                     (e, UExpressionInner::Value(n)) if n == 1 => Ok(e),
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 / n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
-                    ),
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
+                        Ok(UExpressionInner::Value((n1 / n2) & bitwidth.max_value()))
+                    }
                     (e1, e2) => Ok(UExpressionInner::Div(
                         box e1.annotate(bitwidth),
                         box e2.annotate(bitwidth),
@@ -536,9 +545,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 let e2 = self.fold_uint_expression(e2)?;
 
                 match (e1.into_inner(), e2.into_inner()) {
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 % n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
-                    ),
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
+                        Ok(UExpressionInner::Value((n1 % n2) & bitwidth.max_value()))
+                    }
                     (e1, e2) => Ok(UExpressionInner::Rem(
                         box e1.annotate(bitwidth),
                         box e2.annotate(bitwidth),
@@ -566,7 +575,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
 
                 match (e1.into_inner(), e2.into_inner()) {
                     (e, UExpressionInner::Value(n)) | (UExpressionInner::Value(n), e)
-                        if n == 2_u128.pow(bitwidth.to_usize() as u32) - 1 =>
+                        if n == bitwidth.max_value() =>
                     {
                         Ok(e)
                     }
@@ -589,7 +598,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e1.into_inner(), e2.into_inner()) {
                     (e, UExpressionInner::Value(0)) | (UExpressionInner::Value(0), e) => Ok(e),
                     (_, UExpressionInner::Value(n)) | (UExpressionInner::Value(n), _)
-                        if n == 2_u128.pow(bitwidth.to_usize() as u32) - 1 =>
+                        if n == bitwidth.max_value() =>
                     {
                         Ok(UExpressionInner::Value(n))
                     }
@@ -607,9 +616,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e.into_inner(), by) {
                     (e, 0) => Ok(e),
                     (_, by) if by >= bitwidth as u32 => Ok(UExpressionInner::Value(0)),
-                    (UExpressionInner::Value(n), by) => Ok(UExpressionInner::Value(
-                        (n << by) & (2_u128.pow(bitwidth as u32) - 1),
-                    )),
+                    (UExpressionInner::Value(n), by) => {
+                        Ok(UExpressionInner::Value((n << by) & bitwidth.max_value()))
+                    }
                     (e, by) => Ok(UExpressionInner::LeftShift(box e.annotate(bitwidth), by)),
                 }
             }
@@ -622,12 +631,49 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                     (e, by) => Ok(UExpressionInner::RightShift(box e.annotate(bitwidth), by)),
                 }
             }
-            UExpressionInner::Not(box e) => {
+            UExpressionInner::LeftRotate(box e, by) => {
+                let e = self.fold_uint_expression(e)?;
+                let bits = bitwidth.to_usize() as u32;
+                match (e.into_inner(), by % bits) {
+                    (e, 0) => Ok(e),
+                    (UExpressionInner::Value(n), by) => Ok(UExpressionInner::Value(
+                        ((n << by) | (n >> (bits - by))) & bitwidth.max_value(),
+                    )),
+                    (e, by) => Ok(UExpressionInner::LeftRotate(box e.annotate(bitwidth), by)),
+                }
+            }
+            UExpressionInner::RightRotate(box e, by) => {
+                let e = self.fold_uint_expression(e)?;
+                let bits = bitwidth.to_usize() as u32;
+                match (e.into_inner(), by % bits) {
+                    (e, 0) => Ok(e),
+                    (UExpressionInner::Value(n), by) => Ok(UExpressionInner::Value(
+                        ((n >> by) | (n << (bits - by))) & bitwidth.max_value(),
+                    )),
+                    (e, by) => Ok(UExpressionInner::RightRotate(box e.annotate(bitwidth), by)),
+                }
+            }
+            UExpressionInner::Slice(box e, from, to) => {
                 let e = self.fold_uint_expression(e)?;
+                let e_bitwidth = e.bitwidth();
                 match e.into_inner() {
+                    e if from == 0 && to as usize == e_bitwidth.to_usize() => Ok(e),
                     UExpressionInner::Value(n) => Ok(UExpressionInner::Value(
-                        !n & (2_u128.pow(bitwidth as u32) - 1),
+                        (n >> (e_bitwidth.to_usize() - to as usize)) & bitwidth.max_value(),
+                    )),
+                    e => Ok(UExpressionInner::Slice(
+                        box e.annotate(e_bitwidth),
+                        from,
+                        to,
                     )),
+                }
+            }
+            UExpressionInner::Not(box e) => {
+                let e = self.fold_uint_expression(e)?;
+                match e.into_inner() {
+                    UExpressionInner::Value(n) => {
+                        Ok(UExpressionInner::Value(!n & bitwidth.max_value()))
+                    }
                     e => Ok(UExpressionInner::Not(box e.annotate(bitwidth))),
                 }
             }
@@ -654,21 +700,25 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::zir::RuntimeError;
     use zokrates_field::Bn128Field;
 
     #[test]
     fn propagation() {
         // assert([x, 1] == [y, 1])
-        let statements = vec![ZirStatement::Assertion(BooleanExpression::And(
-            box BooleanExpression::FieldEq(
-                box FieldElementExpression::Identifier("x".into()),
-                box FieldElementExpression::Identifier("y".into()),
-            ),
-            box BooleanExpression::FieldEq(
-                box FieldElementExpression::Number(Bn128Field::from(1)),
-                box FieldElementExpression::Number(Bn128Field::from(1)),
+        let statements = vec![ZirStatement::Assertion(
+            BooleanExpression::And(
+                box BooleanExpression::FieldEq(
+                    box FieldElementExpression::Identifier("x".into()),
+                    box FieldElementExpression::Identifier("y".into()),
+                ),
+                box BooleanExpression::FieldEq(
+                    box FieldElementExpression::Number(Bn128Field::from(1)),
+                    box FieldElementExpression::Number(Bn128Field::from(1)),
+                ),
             ),
-        ))];
+            RuntimeError::mock(),
+        )];
 
         let mut propagator = ZirPropagator::default();
         let statements: Vec<ZirStatement<_>> = statements
@@ -682,10 +732,13 @@ mod tests {
 
         assert_eq!(
             statements,
-            vec![ZirStatement::Assertion(BooleanExpression::FieldEq(
-                box FieldElementExpression::Identifier("x".into()),
-                box FieldElementExpression::Identifier("y".into()),
-            ))]
+            vec![ZirStatement::Assertion(
+                BooleanExpression::FieldEq(
+                    box FieldElementExpression::Identifier("x".into()),
+                    box FieldElementExpression::Identifier("y".into()),
+                ),
+                RuntimeError::mock()
+            )]
         );
     }
 
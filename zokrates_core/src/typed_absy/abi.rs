@@ -21,6 +21,7 @@ impl Abi {
     pub fn signature(&self) -> ConcreteSignature {
         ConcreteSignature {
             generics: vec![],
+            type_generics: vec![],
             inputs: self.inputs.iter().map(|i| i.ty.clone()).collect(),
             outputs: self.outputs.clone(),
         }
@@ -31,7 +32,8 @@ impl Abi {
 mod tests {
     use super::*;
     use crate::typed_absy::types::{
-        ConcreteArrayType, ConcreteFunctionKey, ConcreteStructMember, ConcreteStructType, UBitwidth,
+        ConcreteArrayType, ConcreteEnumType, ConcreteEnumVariant, ConcreteFunctionKey,
+        ConcreteStructMember, ConcreteStructType, ConcreteTupleType, IntBitwidth,
     };
     use crate::typed_absy::{
         parameter::DeclarationParameter, variable::DeclarationVariable, ConcreteType,
@@ -177,17 +179,17 @@ mod tests {
                 AbiInput {
                     name: String::from("a"),
                     public: true,
-                    ty: ConcreteType::Uint(UBitwidth::B8),
+                    ty: ConcreteType::Uint(IntBitwidth::B8),
                 },
                 AbiInput {
                     name: String::from("b"),
                     public: true,
-                    ty: ConcreteType::Uint(UBitwidth::B16),
+                    ty: ConcreteType::Uint(IntBitwidth::B16),
                 },
                 AbiInput {
                     name: String::from("c"),
                     public: true,
-                    ty: ConcreteType::Uint(UBitwidth::B32),
+                    ty: ConcreteType::Uint(IntBitwidth::B32),
                 },
             ],
             outputs: vec![],
@@ -491,4 +493,112 @@ mod tests {
         let de_abi: Abi = serde_json::from_str(json.as_ref()).unwrap();
         assert_eq!(de_abi, abi);
     }
+
+    #[test]
+    fn serialize_tuple() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: false,
+                ty: ConcreteType::Tuple(ConcreteTupleType::new(vec![
+                    ConcreteType::FieldElement,
+                    ConcreteType::Array(ConcreteArrayType::new(ConcreteType::Boolean, 2usize)),
+                ])),
+            }],
+            outputs: vec![ConcreteType::Tuple(ConcreteTupleType::new(vec![]))],
+        };
+
+        let json = serde_json::to_string_pretty(&abi).unwrap();
+        assert_eq!(
+            &json,
+            r#"{
+  "inputs": [
+    {
+      "name": "a",
+      "public": false,
+      "type": "tuple",
+      "components": {
+        "elements": [
+          {
+            "type": "field"
+          },
+          {
+            "type": "array",
+            "components": {
+              "size": 2,
+              "type": "bool"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "outputs": [
+    {
+      "type": "tuple",
+      "components": {
+        "elements": []
+      }
+    }
+  ]
+}"#
+        );
+
+        let de_abi: Abi = serde_json::from_str(json.as_ref()).unwrap();
+        assert_eq!(de_abi, abi);
+    }
+
+    #[test]
+    fn serialize_enum() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: false,
+                ty: ConcreteType::Enum(ConcreteEnumType::new(
+                    "".into(),
+                    "Shape".into(),
+                    vec![
+                        ConcreteEnumVariant::new("Empty".into(), vec![]),
+                        ConcreteEnumVariant::new("Circle".into(), vec![ConcreteType::FieldElement]),
+                    ],
+                )),
+            }],
+            outputs: vec![],
+        };
+
+        let json = serde_json::to_string_pretty(&abi).unwrap();
+        assert_eq!(
+            &json,
+            r#"{
+  "inputs": [
+    {
+      "name": "a",
+      "public": false,
+      "type": "enum",
+      "components": {
+        "name": "Shape",
+        "variants": [
+          {
+            "name": "Empty",
+            "elements": []
+          },
+          {
+            "name": "Circle",
+            "elements": [
+              {
+                "type": "field"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "outputs": []
+}"#
+        );
+
+        let de_abi: Abi = serde_json::from_str(json.as_ref()).unwrap();
+        assert_eq!(de_abi, abi);
+    }
 }
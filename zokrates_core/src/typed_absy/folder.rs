@@ -38,6 +38,18 @@ impl<'ast, T: Field> Fold<'ast, T> for ArrayExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Fold<'ast, T> for TupleExpression<'ast, T> {
+    fn fold<F: Folder<'ast, T>>(self, f: &mut F) -> Self {
+        f.fold_tuple_expression(self)
+    }
+}
+
+impl<'ast, T: Field> Fold<'ast, T> for EnumExpression<'ast, T> {
+    fn fold<F: Folder<'ast, T>>(self, f: &mut F) -> Self {
+        f.fold_enum_expression(self)
+    }
+}
+
 pub trait Folder<'ast, T: Field>: Sized {
     fn fold_program(&mut self, p: TypedProgram<'ast, T>) -> TypedProgram<'ast, T> {
         fold_program(self, p)
@@ -121,6 +133,8 @@ pub trait Folder<'ast, T: Field>: Sized {
         match t {
             Array(array_type) => Array(self.fold_array_type(array_type)),
             Struct(struct_type) => Struct(self.fold_struct_type(struct_type)),
+            Tuple(tuple_type) => Tuple(self.fold_tuple_type(tuple_type)),
+            Enum(enum_type) => Enum(self.fold_enum_type(enum_type)),
             t => t,
         }
     }
@@ -143,6 +157,11 @@ pub trait Folder<'ast, T: Field>: Sized {
                 .into_iter()
                 .map(|g| g.map(|g| self.fold_uint_expression(g)))
                 .collect(),
+            type_generics: t
+                .type_generics
+                .into_iter()
+                .map(|t| self.fold_type(t))
+                .collect(),
             members: t
                 .members
                 .into_iter()
@@ -155,12 +174,34 @@ pub trait Folder<'ast, T: Field>: Sized {
         }
     }
 
+    fn fold_tuple_type(&mut self, t: TupleType<'ast, T>) -> TupleType<'ast, T> {
+        TupleType {
+            elements: t.elements.into_iter().map(|t| self.fold_type(t)).collect(),
+        }
+    }
+
+    fn fold_enum_type(&mut self, t: EnumType<'ast, T>) -> EnumType<'ast, T> {
+        EnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| EnumVariant {
+                    payload: self.fold_tuple_type(v.payload),
+                    ..v
+                })
+                .collect(),
+            ..t
+        }
+    }
+
     fn fold_declaration_type(&mut self, t: DeclarationType<'ast>) -> DeclarationType<'ast> {
         use self::GType::*;
 
         match t {
             Array(array_type) => Array(self.fold_declaration_array_type(array_type)),
             Struct(struct_type) => Struct(self.fold_declaration_struct_type(struct_type)),
+            Tuple(tuple_type) => Tuple(self.fold_declaration_tuple_type(tuple_type)),
+            Enum(enum_type) => Enum(self.fold_declaration_enum_type(enum_type)),
             t => t,
         }
     }
@@ -185,6 +226,11 @@ pub trait Folder<'ast, T: Field>: Sized {
                 .into_iter()
                 .map(|g| g.map(|g| self.fold_declaration_constant(g)))
                 .collect(),
+            type_generics: t
+                .type_generics
+                .into_iter()
+                .map(|t| self.fold_declaration_type(t))
+                .collect(),
             members: t
                 .members
                 .into_iter()
@@ -197,6 +243,36 @@ pub trait Folder<'ast, T: Field>: Sized {
         }
     }
 
+    fn fold_declaration_tuple_type(
+        &mut self,
+        t: DeclarationTupleType<'ast>,
+    ) -> DeclarationTupleType<'ast> {
+        DeclarationTupleType {
+            elements: t
+                .elements
+                .into_iter()
+                .map(|t| self.fold_declaration_type(t))
+                .collect(),
+        }
+    }
+
+    fn fold_declaration_enum_type(
+        &mut self,
+        t: DeclarationEnumType<'ast>,
+    ) -> DeclarationEnumType<'ast> {
+        DeclarationEnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| DeclarationEnumVariant {
+                    payload: self.fold_declaration_tuple_type(v.payload),
+                    ..v
+                })
+                .collect(),
+            ..t
+        }
+    }
+
     fn fold_assignee(&mut self, a: TypedAssignee<'ast, T>) -> TypedAssignee<'ast, T> {
         fold_assignee(self, a)
     }
@@ -247,6 +323,8 @@ pub trait Folder<'ast, T: Field>: Sized {
             TypedExpression::Uint(e) => self.fold_uint_expression(e).into(),
             TypedExpression::Array(e) => self.fold_array_expression(e).into(),
             TypedExpression::Struct(e) => self.fold_struct_expression(e).into(),
+            TypedExpression::Tuple(e) => self.fold_tuple_expression(e).into(),
+            TypedExpression::Enum(e) => self.fold_enum_expression(e).into(),
             TypedExpression::Int(e) => self.fold_int_expression(e).into(),
         }
     }
@@ -282,6 +360,16 @@ pub trait Folder<'ast, T: Field>: Sized {
         fold_member_expression(self, ty, e)
     }
 
+    fn fold_element_expression<
+        E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    >(
+        &mut self,
+        ty: &E::Ty,
+        e: ElementExpression<'ast, T, E>,
+    ) -> ElementOrExpression<'ast, T, E> {
+        fold_element_expression(self, ty, e)
+    }
+
     fn fold_function_call_expression<
         E: Id<'ast, T> + From<TypedExpression<'ast, T>> + Expr<'ast, T> + FunctionCall<'ast, T>,
     >(
@@ -313,6 +401,14 @@ pub trait Folder<'ast, T: Field>: Sized {
         fold_struct_expression(self, e)
     }
 
+    fn fold_tuple_expression(&mut self, e: TupleExpression<'ast, T>) -> TupleExpression<'ast, T> {
+        fold_tuple_expression(self, e)
+    }
+
+    fn fold_enum_expression(&mut self, e: EnumExpression<'ast, T>) -> EnumExpression<'ast, T> {
+        fold_enum_expression(self, e)
+    }
+
     fn fold_expression_list(
         &mut self,
         es: TypedExpressionList<'ast, T>,
@@ -350,7 +446,7 @@ pub trait Folder<'ast, T: Field>: Sized {
 
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntBitwidth,
         e: UExpressionInner<'ast, T>,
     ) -> UExpressionInner<'ast, T> {
         fold_uint_expression_inner(self, bitwidth, e)
@@ -371,6 +467,22 @@ pub trait Folder<'ast, T: Field>: Sized {
     ) -> StructExpressionInner<'ast, T> {
         fold_struct_expression_inner(self, ty, e)
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> TupleExpressionInner<'ast, T> {
+        fold_tuple_expression_inner(self, ty, e)
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> EnumExpressionInner<'ast, T> {
+        fold_enum_expression_inner(self, ty, e)
+    }
 }
 
 pub fn fold_module<'ast, T: Field, F: Folder<'ast, T>>(
@@ -416,7 +528,9 @@ pub fn fold_statement<'ast, T: Field, F: Folder<'ast, T>>(
             TypedStatement::Definition(f.fold_assignee(a), f.fold_expression(e))
         }
         TypedStatement::Declaration(v) => TypedStatement::Declaration(f.fold_variable(v)),
-        TypedStatement::Assertion(e) => TypedStatement::Assertion(f.fold_boolean_expression(e)),
+        TypedStatement::Assertion(e, error) => {
+            TypedStatement::Assertion(f.fold_boolean_expression(e), error)
+        }
         TypedStatement::For(v, from, to, statements) => TypedStatement::For(
             f.fold_variable(v),
             f.fold_uint_expression(from),
@@ -426,6 +540,17 @@ pub fn fold_statement<'ast, T: Field, F: Folder<'ast, T>>(
                 .flat_map(|s| f.fold_statement(s))
                 .collect(),
         ),
+        TypedStatement::IfElse(condition, consequence, alternative) => TypedStatement::IfElse(
+            f.fold_boolean_expression(condition),
+            consequence
+                .into_iter()
+                .flat_map(|s| f.fold_statement(s))
+                .collect(),
+            alternative
+                .into_iter()
+                .flat_map(|s| f.fold_statement(s))
+                .collect(),
+        ),
         TypedStatement::MultipleDefinition(assignees, elist) => TypedStatement::MultipleDefinition(
             assignees.into_iter().map(|a| f.fold_assignee(a)).collect(),
             f.fold_expression_list(elist),
@@ -471,6 +596,10 @@ pub fn fold_array_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => ArrayExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        ArrayExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => ArrayExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
         ArrayExpressionInner::Slice(box array, box from, box to) => {
             let array = f.fold_array_expression(array);
             let from = f.fold_uint_expression(from);
@@ -482,6 +611,13 @@ pub fn fold_array_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             let count = f.fold_uint_expression(count);
             ArrayExpressionInner::Repeat(box e, box count)
         }
+        ArrayExpressionInner::Comprehension(box e, v, box from, box to) => {
+            let e = f.fold_expression(e);
+            let v = f.fold_variable(v);
+            let from = f.fold_uint_expression(from);
+            let to = f.fold_uint_expression(to);
+            ArrayExpressionInner::Comprehension(box e, v, box from, box to)
+        }
     }
 }
 
@@ -518,6 +654,94 @@ pub fn fold_struct_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => StructExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        StructExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => StructExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+    }
+}
+
+pub fn fold_tuple_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    ty: &TupleType<'ast, T>,
+    e: TupleExpressionInner<'ast, T>,
+) -> TupleExpressionInner<'ast, T> {
+    match e {
+        TupleExpressionInner::Block(block) => {
+            TupleExpressionInner::Block(f.fold_block_expression(block))
+        }
+        TupleExpressionInner::Identifier(id) => TupleExpressionInner::Identifier(f.fold_name(id)),
+        TupleExpressionInner::Value(exprs) => {
+            TupleExpressionInner::Value(exprs.into_iter().map(|e| f.fold_expression(e)).collect())
+        }
+        TupleExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call) {
+                FunctionCallOrExpression::FunctionCall(function_call) => {
+                    TupleExpressionInner::FunctionCall(function_call)
+                }
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        TupleExpressionInner::IfElse(c) => match f.fold_if_else_expression(ty, c) {
+            IfElseOrExpression::IfElse(s) => TupleExpressionInner::IfElse(s),
+            IfElseOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Select(select) => match f.fold_select_expression(ty, select) {
+            SelectOrExpression::Select(s) => TupleExpressionInner::Select(s),
+            SelectOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Member(m) => match f.fold_member_expression(ty, m) {
+            MemberOrExpression::Member(m) => TupleExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => TupleExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Payload(box e, variant) => {
+            TupleExpressionInner::Payload(box f.fold_enum_expression(e), variant)
+        }
+    }
+}
+
+pub fn fold_enum_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    ty: &EnumType<'ast, T>,
+    e: EnumExpressionInner<'ast, T>,
+) -> EnumExpressionInner<'ast, T> {
+    match e {
+        EnumExpressionInner::Block(block) => {
+            EnumExpressionInner::Block(f.fold_block_expression(block))
+        }
+        EnumExpressionInner::Identifier(id) => EnumExpressionInner::Identifier(f.fold_name(id)),
+        EnumExpressionInner::Value(variant, exprs) => EnumExpressionInner::Value(
+            variant,
+            exprs.into_iter().map(|e| f.fold_expression(e)).collect(),
+        ),
+        EnumExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call) {
+                FunctionCallOrExpression::FunctionCall(function_call) => {
+                    EnumExpressionInner::FunctionCall(function_call)
+                }
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        EnumExpressionInner::IfElse(c) => match f.fold_if_else_expression(ty, c) {
+            IfElseOrExpression::IfElse(s) => EnumExpressionInner::IfElse(s),
+            IfElseOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Select(select) => match f.fold_select_expression(ty, select) {
+            SelectOrExpression::Select(s) => EnumExpressionInner::Select(s),
+            SelectOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Member(m) => match f.fold_member_expression(ty, m) {
+            MemberOrExpression::Member(m) => EnumExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => EnumExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     }
 }
 
@@ -593,6 +817,15 @@ pub fn fold_field_expression<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => FieldElementExpression::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        FieldElementExpression::Element(element) => {
+            match f.fold_element_expression(&Type::FieldElement, element) {
+                ElementOrExpression::Element(m) => FieldElementExpression::Element(m),
+                ElementOrExpression::Expression(u) => u,
+            }
+        }
+        FieldElementExpression::Tag(box e) => {
+            FieldElementExpression::Tag(box f.fold_enum_expression(e))
+        }
     }
 }
 
@@ -629,6 +862,22 @@ pub fn fold_member_expression<
     ))
 }
 
+pub fn fold_element_expression<
+    'ast,
+    T: Field,
+    E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    F: Folder<'ast, T>,
+>(
+    f: &mut F,
+    _: &E::Ty,
+    e: ElementExpression<'ast, T, E>,
+) -> ElementOrExpression<'ast, T, E> {
+    ElementOrExpression::Element(ElementExpression::new(
+        f.fold_tuple_expression(*e.tuple),
+        e.index,
+    ))
+}
+
 pub fn fold_select_expression<
     'ast,
     T: Field,
@@ -680,11 +929,21 @@ pub fn fold_boolean_expression<'ast, T: Field, F: Folder<'ast, T>>(
             let e2 = f.fold_struct_expression(e2);
             BooleanExpression::StructEq(box e1, box e2)
         }
+        BooleanExpression::TupleEq(box e1, box e2) => {
+            let e1 = f.fold_tuple_expression(e1);
+            let e2 = f.fold_tuple_expression(e2);
+            BooleanExpression::TupleEq(box e1, box e2)
+        }
         BooleanExpression::UintEq(box e1, box e2) => {
             let e1 = f.fold_uint_expression(e1);
             let e2 = f.fold_uint_expression(e2);
             BooleanExpression::UintEq(box e1, box e2)
         }
+        BooleanExpression::UintBit(box e, box index) => {
+            let e = f.fold_uint_expression(e);
+            let index = f.fold_uint_expression(index);
+            BooleanExpression::UintBit(box e, box index)
+        }
         BooleanExpression::FieldLt(box e1, box e2) => {
             let e1 = f.fold_field_expression(e1);
             let e2 = f.fold_field_expression(e2);
@@ -760,6 +1019,10 @@ pub fn fold_boolean_expression<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => BooleanExpression::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        BooleanExpression::Element(m) => match f.fold_element_expression(&Type::Boolean, m) {
+            ElementOrExpression::Element(m) => BooleanExpression::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     }
 }
 
@@ -775,7 +1038,7 @@ pub fn fold_uint_expression<'ast, T: Field, F: Folder<'ast, T>>(
 
 pub fn fold_uint_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
     f: &mut F,
-    ty: UBitwidth,
+    ty: IntBitwidth,
     e: UExpressionInner<'ast, T>,
 ) -> UExpressionInner<'ast, T> {
     match e {
@@ -848,6 +1111,23 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
 
             UExpressionInner::RightShift(box e, box by)
         }
+        UExpressionInner::LeftRotate(box e, box by) => {
+            let e = f.fold_uint_expression(e);
+            let by = f.fold_uint_expression(by);
+
+            UExpressionInner::LeftRotate(box e, box by)
+        }
+        UExpressionInner::RightRotate(box e, box by) => {
+            let e = f.fold_uint_expression(e);
+            let by = f.fold_uint_expression(by);
+
+            UExpressionInner::RightRotate(box e, box by)
+        }
+        UExpressionInner::Slice(box e, from, to) => {
+            let e = f.fold_uint_expression(e);
+
+            UExpressionInner::Slice(box e, from, to)
+        }
         UExpressionInner::Not(box e) => {
             let e = f.fold_uint_expression(e);
 
@@ -883,6 +1163,10 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => UExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        UExpressionInner::Element(m) => match f.fold_element_expression(&ty, m) {
+            ElementOrExpression::Element(m) => UExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     }
 }
 
@@ -959,6 +1243,11 @@ fn fold_signature<'ast, T: Field, F: Folder<'ast, T>>(
 ) -> DeclarationSignature<'ast> {
     DeclarationSignature {
         generics: s.generics,
+        type_generics: s
+            .type_generics
+            .into_iter()
+            .map(|t| f.fold_declaration_type(t))
+            .collect(),
         inputs: s
             .inputs
             .into_iter()
@@ -1036,6 +1325,9 @@ pub fn fold_expression_list_inner<'ast, T: Field, F: Folder<'ast, T>>(
                     .collect(),
             )
         }
+        TypedExpressionListInner::Tuple(tuple) => {
+            TypedExpressionListInner::Tuple(f.fold_tuple_expression(tuple))
+        }
     }
 }
 
@@ -1050,6 +1342,28 @@ pub fn fold_struct_expression<'ast, T: Field, F: Folder<'ast, T>>(
     }
 }
 
+pub fn fold_tuple_expression<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    e: TupleExpression<'ast, T>,
+) -> TupleExpression<'ast, T> {
+    let ty = f.fold_tuple_type(e.ty);
+    TupleExpression {
+        inner: f.fold_tuple_expression_inner(&ty, e.inner),
+        ty,
+    }
+}
+
+pub fn fold_enum_expression<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    e: EnumExpression<'ast, T>,
+) -> EnumExpression<'ast, T> {
+    let ty = f.fold_enum_type(e.ty);
+    EnumExpression {
+        inner: f.fold_enum_expression_inner(&ty, e.inner),
+        ty,
+    }
+}
+
 pub fn fold_constant<'ast, T: Field, F: Folder<'ast, T>>(
     f: &mut F,
     c: TypedConstant<'ast, T>,
@@ -1094,6 +1408,9 @@ pub fn fold_assignee<'ast, T: Field, F: Folder<'ast, T>>(
             TypedAssignee::Select(box f.fold_assignee(a), box f.fold_uint_expression(index))
         }
         TypedAssignee::Member(box s, m) => TypedAssignee::Member(box f.fold_assignee(s), m),
+        TypedAssignee::Element(box s, index) => {
+            TypedAssignee::Element(box f.fold_assignee(s), index)
+        }
     }
 }
 
@@ -5,6 +5,12 @@ use std::fmt;
 pub enum CoreIdentifier<'ast> {
     Source(&'ast str),
     Call(usize),
+    Match(usize),
+    Temporary(usize),
+    Comprehension(usize),
+    IfElse,
+    Destructuring,
+    Shadow(&'ast str, usize),
 }
 
 impl<'ast> fmt::Display for CoreIdentifier<'ast> {
@@ -12,6 +18,12 @@ impl<'ast> fmt::Display for CoreIdentifier<'ast> {
         match self {
             CoreIdentifier::Source(s) => write!(f, "{}", s),
             CoreIdentifier::Call(i) => write!(f, "#CALL_RETURN_AT_INDEX_{}", i),
+            CoreIdentifier::Match(i) => write!(f, "#MATCH_SCRUTINEE_{}", i),
+            CoreIdentifier::Temporary(i) => write!(f, "#TEMPORARY_{}", i),
+            CoreIdentifier::Comprehension(i) => write!(f, "#COMPREHENSION_{}", i),
+            CoreIdentifier::IfElse => write!(f, "#IF_ELSE_VALUES"),
+            CoreIdentifier::Destructuring => write!(f, "#DESTRUCTURED_TUPLE"),
+            CoreIdentifier::Shadow(s, level) => write!(f, "#SHADOWED_{}_AT_LEVEL_{}", s, level),
         }
     }
 }
@@ -1,12 +1,14 @@
 use crate::typed_absy::types::{
-    ArrayType, DeclarationArrayType, DeclarationConstant, DeclarationStructMember,
-    DeclarationStructType, DeclarationType, GArrayType, GStructType, GType, GenericIdentifier,
-    StructType, Type,
+    ArrayType, DeclarationArrayType, DeclarationConstant, DeclarationEnumType,
+    DeclarationEnumVariant, DeclarationStructMember, DeclarationStructType, DeclarationTupleType,
+    DeclarationType, EnumType, GArrayType, GEnumType, GStructType, GTupleType, GType,
+    GenericIdentifier, StructType, TupleType, Type,
 };
-use crate::typed_absy::UBitwidth;
+use crate::typed_absy::IntBitwidth;
 use crate::typed_absy::{
-    ArrayExpression, ArrayExpressionInner, BooleanExpression, Expr, FieldElementExpression, IfElse,
-    IfElseExpression, Select, SelectExpression, StructExpression, StructExpressionInner, Typed,
+    ArrayExpression, ArrayExpressionInner, BooleanExpression, EnumExpression, EnumExpressionInner,
+    Expr, FieldElementExpression, IfElse, IfElseExpression, Select, SelectExpression,
+    StructExpression, StructExpressionInner, TupleExpression, TupleExpressionInner, Typed,
     TypedExpression, TypedExpressionOrSpread, TypedSpread, UExpression, UExpressionInner,
 };
 use num_bigint::BigUint;
@@ -39,7 +41,7 @@ trait IntegerInference: Sized {
     fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)>;
 }
 
-impl<'ast, T> IntegerInference for Type<'ast, T> {
+impl<'ast, T: Clone> IntegerInference for Type<'ast, T> {
     type Pattern = DeclarationType<'ast>;
 
     fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)> {
@@ -66,12 +68,20 @@ impl<'ast, T> IntegerInference for Type<'ast, T> {
                 t.get_common_pattern(u)
                     .map_err(|(t, u)| (Type::Struct(t), Type::Struct(u)))?,
             )),
+            (Type::Tuple(t), Type::Tuple(u)) => Ok(DeclarationType::Tuple(
+                t.get_common_pattern(u)
+                    .map_err(|(t, u)| (Type::Tuple(t), Type::Tuple(u)))?,
+            )),
+            (Type::Enum(t), Type::Enum(u)) => Ok(DeclarationType::Enum(
+                t.get_common_pattern(u)
+                    .map_err(|(t, u)| (Type::Enum(t), Type::Enum(u)))?,
+            )),
             (t, u) => Err((t, u)),
         }
     }
 }
 
-impl<'ast, T> IntegerInference for ArrayType<'ast, T> {
+impl<'ast, T: Clone> IntegerInference for ArrayType<'ast, T> {
     type Pattern = DeclarationArrayType<'ast>;
 
     fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)> {
@@ -87,7 +97,7 @@ impl<'ast, T> IntegerInference for ArrayType<'ast, T> {
     }
 }
 
-impl<'ast, T> IntegerInference for StructType<'ast, T> {
+impl<'ast, T: Clone> IntegerInference for StructType<'ast, T> {
     type Pattern = DeclarationStructType<'ast>;
 
     fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)> {
@@ -115,6 +125,68 @@ impl<'ast, T> IntegerInference for StructType<'ast, T> {
                     g.map(|_| DeclarationConstant::Generic(GenericIdentifier::with_name("DUMMY")))
                 })
                 .collect(),
+            type_generics: self
+                .type_generics
+                .into_iter()
+                .zip(other.type_generics.into_iter())
+                .map(|(t, u)| match t.get_common_pattern(u) {
+                    Ok(ty) => ty,
+                    Err(..) => unreachable!(
+                        "struct instances of the same struct should always have the same type arguments"
+                    ),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<'ast, T: Clone> IntegerInference for TupleType<'ast, T> {
+    type Pattern = DeclarationTupleType<'ast>;
+
+    fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)> {
+        if self.elements.len() != other.elements.len() {
+            return Err((self, other));
+        }
+
+        Ok(DeclarationTupleType::new(
+            self.elements
+                .iter()
+                .cloned()
+                .zip(other.elements.iter().cloned())
+                .map(|(t, u)| t.get_common_pattern(u))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| (self, other))?,
+        ))
+    }
+}
+
+impl<'ast, T: Clone> IntegerInference for EnumType<'ast, T> {
+    type Pattern = DeclarationEnumType<'ast>;
+
+    fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)> {
+        if self.canonical_location != other.canonical_location {
+            return Err((self, other));
+        }
+
+        Ok(DeclarationEnumType {
+            variants: self
+                .variants
+                .into_iter()
+                .zip(other.variants.into_iter())
+                .map(
+                    |(v_t, v_u)| match v_t.payload.get_common_pattern(v_u.payload) {
+                        Ok(payload) => DeclarationEnumVariant {
+                            id: v_t.id,
+                            payload,
+                        },
+                        Err(..) => unreachable!(
+                            "instances of the same enum should always have a common type"
+                        ),
+                    },
+                )
+                .collect(),
+            canonical_location: self.canonical_location,
+            location: self.location,
         })
     }
 }
@@ -196,6 +268,46 @@ impl<'ast, T: Field> TypedExpression<'ast, T> {
                         .into(),
                 ))
             }
+            (Tuple(lhs), Tuple(rhs)) => {
+                let common_type = lhs
+                    .get_type()
+                    .get_common_pattern(rhs.get_type())
+                    .map_err(|_| (lhs.clone().into(), rhs.clone().into()))?;
+
+                let common_type = match common_type {
+                    DeclarationType::Tuple(ty) => ty,
+                    _ => unreachable!(),
+                };
+
+                Ok((
+                    TupleExpression::try_from_int(lhs.clone(), &common_type)
+                        .map_err(|lhs| (lhs.clone(), rhs.clone().into()))?
+                        .into(),
+                    TupleExpression::try_from_int(rhs, &common_type)
+                        .map_err(|rhs| (lhs.clone().into(), rhs.clone()))?
+                        .into(),
+                ))
+            }
+            (Enum(lhs), Enum(rhs)) => {
+                let common_type = lhs
+                    .get_type()
+                    .get_common_pattern(rhs.get_type())
+                    .map_err(|_| (lhs.clone().into(), rhs.clone().into()))?;
+
+                let common_type = match common_type {
+                    DeclarationType::Enum(ty) => ty,
+                    _ => unreachable!(),
+                };
+
+                Ok((
+                    EnumExpression::try_from_int(lhs.clone(), &common_type)
+                        .map_err(|lhs| (lhs.clone(), rhs.clone().into()))?
+                        .into(),
+                    EnumExpression::try_from_int(rhs, &common_type)
+                        .map_err(|rhs| (lhs.clone().into(), rhs.clone()))?
+                        .into(),
+                ))
+            }
             (Uint(lhs), Uint(rhs)) => Ok((lhs.into(), rhs.into())),
             (Boolean(lhs), Boolean(rhs)) => Ok((lhs.into(), rhs.into())),
             (FieldElement(lhs), FieldElement(rhs)) => Ok((lhs.into(), rhs.into())),
@@ -222,7 +334,15 @@ impl<'ast, T: Field> TypedExpression<'ast, T> {
             GType::Struct(struct_ty) => {
                 StructExpression::try_from_typed(e, struct_ty).map(TypedExpression::from)
             }
+            GType::Tuple(tuple_ty) => {
+                TupleExpression::try_from_typed(e, tuple_ty).map(TypedExpression::from)
+            }
+            GType::Enum(enum_ty) => {
+                EnumExpression::try_from_typed(e, enum_ty).map(TypedExpression::from)
+            }
             GType::Int => Err(e),
+            // the value of a type parameter is inferred from the expression
+            GType::Generic(..) => Ok(e),
         }
         .map_err(|e| (e, ty))
     }
@@ -247,6 +367,8 @@ pub enum IntExpression<'ast, T> {
     Not(Box<IntExpression<'ast, T>>),
     LeftShift(Box<IntExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     RightShift(Box<IntExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    LeftRotate(Box<IntExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    RightRotate(Box<IntExpression<'ast, T>>, Box<UExpression<'ast, T>>),
 }
 
 impl<'ast, T> Add for IntExpression<'ast, T> {
@@ -330,6 +452,14 @@ impl<'ast, T> IntExpression<'ast, T> {
         IntExpression::RightShift(box self, box by)
     }
 
+    pub fn left_rotate(self, by: UExpression<'ast, T>) -> Self {
+        IntExpression::LeftRotate(box self, box by)
+    }
+
+    pub fn right_rotate(self, by: UExpression<'ast, T>) -> Self {
+        IntExpression::RightRotate(box self, box by)
+    }
+
     pub fn pos(self) -> Self {
         IntExpression::Pos(box self)
     }
@@ -353,6 +483,8 @@ impl<'ast, T: fmt::Display> fmt::Display for IntExpression<'ast, T> {
             IntExpression::Mult(ref lhs, ref rhs) => write!(f, "({} * {})", lhs, rhs),
             IntExpression::RightShift(ref e, ref by) => write!(f, "({} >> {})", e, by),
             IntExpression::LeftShift(ref e, ref by) => write!(f, "({} << {})", e, by),
+            IntExpression::LeftRotate(ref e, ref by) => write!(f, "({} <<< {})", e, by),
+            IntExpression::RightRotate(ref e, ref by) => write!(f, "({} >>> {})", e, by),
             IntExpression::Not(ref e) => write!(f, "!{}", e),
             IntExpression::IfElse(ref c) => write!(f, "{}", c),
         }
@@ -396,7 +528,7 @@ impl<'ast, T: Field> FieldElementExpression<'ast, T> {
             )),
             IntExpression::Pow(box e1, box e2) => Ok(Self::Pow(
                 box Self::try_from_int(e1)?,
-                box UExpression::try_from_int(e2, &UBitwidth::B32)?,
+                box UExpression::try_from_int(e2, &IntBitwidth::B32)?,
             )),
             IntExpression::Div(box e1, box e2) => Ok(Self::Div(
                 box Self::try_from_int(e1)?,
@@ -454,7 +586,7 @@ impl<'ast, T: Field> FieldElementExpression<'ast, T> {
 impl<'ast, T: Field> UExpression<'ast, T> {
     pub fn try_from_typed(
         e: TypedExpression<'ast, T>,
-        bitwidth: &UBitwidth,
+        bitwidth: &IntBitwidth,
     ) -> Result<Self, TypedExpression<'ast, T>> {
         match e {
             TypedExpression::Uint(e) => match e.bitwidth == *bitwidth {
@@ -470,13 +602,18 @@ impl<'ast, T: Field> UExpression<'ast, T> {
 
     pub fn try_from_int(
         i: IntExpression<'ast, T>,
-        bitwidth: &UBitwidth,
+        bitwidth: &IntBitwidth,
     ) -> Result<Self, IntExpression<'ast, T>> {
         use self::IntExpression::*;
 
         match i {
             Value(i) => {
-                if i <= BigUint::from(2u128.pow(bitwidth.to_usize() as u32) - 1) {
+                let max = match bitwidth.is_signed() {
+                    true => 2u128.pow(bitwidth.to_usize() as u32 - 1) - 1,
+                    false => bitwidth.max_value(),
+                };
+
+                if i <= BigUint::from(max) {
                     Ok(UExpressionInner::Value(
                         u128::from_str_radix(&i.to_str_radix(16), 16).unwrap(),
                     )
@@ -489,6 +626,20 @@ impl<'ast, T: Field> UExpression<'ast, T> {
                 Ok(Self::try_from_int(e1, bitwidth)? + Self::try_from_int(e2, bitwidth)?)
             }
             Pos(box e) => Ok(Self::pos(Self::try_from_int(e, bitwidth)?)),
+            // the negation of a literal can reach the signed minimum, whose absolute value is out of range
+            Neg(box Value(i)) if bitwidth.is_signed() => {
+                if i <= BigUint::from(2u128.pow(bitwidth.to_usize() as u32 - 1)) {
+                    Ok(UExpressionInner::Value(
+                        u128::from_str_radix(&i.to_str_radix(16), 16)
+                            .unwrap()
+                            .wrapping_neg()
+                            & bitwidth.max_value(),
+                    )
+                    .annotate(*bitwidth))
+                } else {
+                    Err(Neg(box Value(i)))
+                }
+            }
             Neg(box e) => Ok(Self::neg(Self::try_from_int(e, bitwidth)?)),
             Sub(box e1, box e2) => {
                 Ok(Self::try_from_int(e1, bitwidth)? - Self::try_from_int(e2, bitwidth)?)
@@ -523,6 +674,14 @@ impl<'ast, T: Field> UExpression<'ast, T> {
                 Self::try_from_int(e1, bitwidth)?,
                 e2,
             )),
+            LeftRotate(box e1, box e2) => Ok(UExpression::left_rotate(
+                Self::try_from_int(e1, bitwidth)?,
+                e2,
+            )),
+            RightRotate(box e1, box e2) => Ok(UExpression::right_rotate(
+                Self::try_from_int(e1, bitwidth)?,
+                e2,
+            )),
             IfElse(c) => Ok(UExpression::if_else(
                 *c.condition,
                 Self::try_from_int(*c.consequence, bitwidth)?,
@@ -580,7 +739,7 @@ impl<'ast, T: Field> ArrayExpression<'ast, T> {
         }
     }
 
-    // precondition: `array` is only made of inline arrays and repeat constructs unless it does not contain the Integer type
+    // precondition: `array` is only made of inline arrays, repeat constructs and comprehensions unless it does not contain the Integer type
     pub fn try_from_int<S: PartialEq<UExpression<'ast, T>>>(
         array: Self,
         target_array_ty: &GArrayType<S>,
@@ -630,8 +789,28 @@ impl<'ast, T: Field> ArrayExpression<'ast, T> {
                         .map_err(|(e, _)| e),
                 }
             }
+            ArrayExpressionInner::Comprehension(box e, v, box from, box to) => {
+                match &*target_array_ty.ty {
+                    GType::Int => Ok(ArrayExpressionInner::Comprehension(
+                        box e, v, box from, box to,
+                    )
+                    .annotate(Type::Int, array_ty.size)),
+                    // try to align the element to the target type
+                    t => TypedExpression::align_to_type(e, &t)
+                        .map(|e| {
+                            let ty = e.get_type().clone();
+
+                            ArrayExpressionInner::Comprehension(box e, v, box from, box to)
+                                .annotate(ty, array_ty.size)
+                        })
+                        .map_err(|(e, _)| e),
+                }
+            }
             a => {
-                if *target_array_ty.ty == *array_ty.ty {
+                // the value of a type parameter is inferred from the expression
+                if *target_array_ty.ty == *array_ty.ty
+                    || matches!(*target_array_ty.ty, GType::Generic(..))
+                {
                     Ok(a.annotate(*array_ty.ty, array_ty.size))
                 } else {
                     Err(a.annotate(*array_ty.ty, array_ty.size).into())
@@ -684,6 +863,88 @@ impl<'ast, T: Field> StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> TupleExpression<'ast, T> {
+    pub fn try_from_int<S: PartialEq<UExpression<'ast, T>>>(
+        tuple: Self,
+        target_tuple_ty: &GTupleType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        let tuple_ty = tuple.ty().clone();
+
+        if tuple_ty.elements.len() != target_tuple_ty.elements.len() {
+            return Err(tuple.into());
+        }
+
+        match tuple.into_inner() {
+            TupleExpressionInner::Value(inline_tuple) => inline_tuple
+                .into_iter()
+                .zip(target_tuple_ty.elements.iter())
+                .map(|(value, target_ty)| TypedExpression::align_to_type(value, target_ty))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|v| {
+                    let ty = TupleType::new(v.iter().map(|e| e.get_type()).collect());
+                    TupleExpressionInner::Value(v).annotate(ty)
+                })
+                .map_err(|(v, _)| v),
+            t => {
+                if *target_tuple_ty == tuple_ty {
+                    Ok(t.annotate(tuple_ty))
+                } else {
+                    Err(t.annotate(tuple_ty).into())
+                }
+            }
+        }
+    }
+
+    pub fn try_from_typed<S: PartialEq<UExpression<'ast, T>>>(
+        e: TypedExpression<'ast, T>,
+        target_tuple_ty: &GTupleType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        match e {
+            TypedExpression::Tuple(e) => Self::try_from_int(e, target_tuple_ty),
+            e => Err(e),
+        }
+    }
+}
+
+impl<'ast, T: Field> EnumExpression<'ast, T> {
+    pub fn try_from_int<S: PartialEq<UExpression<'ast, T>>>(
+        e: Self,
+        target_enum_ty: &GEnumType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        let enum_ty = e.ty().clone();
+
+        if *target_enum_ty != enum_ty {
+            return Err(e.into());
+        }
+
+        match e.into_inner() {
+            EnumExpressionInner::Value(variant, payload) => payload
+                .into_iter()
+                .zip(
+                    target_enum_ty.variants[variant as usize]
+                        .payload
+                        .elements
+                        .iter(),
+                )
+                .map(|(value, target_ty)| TypedExpression::align_to_type(value, target_ty))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|v| EnumExpressionInner::Value(variant, v).annotate(enum_ty.clone()))
+                .map_err(|(v, _)| v),
+            e => Ok(e.annotate(enum_ty)),
+        }
+    }
+
+    pub fn try_from_typed<S: PartialEq<UExpression<'ast, T>>>(
+        e: TypedExpression<'ast, T>,
+        target_enum_ty: &GEnumType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        match e {
+            TypedExpression::Enum(e) => Self::try_from_int(e, target_enum_ty),
+            e => Err(e),
+        }
+    }
+}
+
 impl<'ast, T> From<BigUint> for IntExpression<'ast, T> {
     fn from(v: BigUint) -> Self {
         IntExpression::Value(v)
@@ -763,7 +1024,7 @@ mod tests {
         let t: UExpression<Bn128Field> = 42u32.into();
         let t_a: ArrayExpression<Bn128Field> =
             ArrayExpressionInner::Value(vec![t.clone().into()].into())
-                .annotate(Type::Uint(UBitwidth::B32), 1u32);
+                .annotate(Type::Uint(IntBitwidth::B32), 1u32);
         let i: UExpression<Bn128Field> = 0u32.into();
         let c: BooleanExpression<Bn128Field> = true.into();
 
@@ -803,7 +1064,7 @@ mod tests {
 
         for (r, e) in expressions
             .into_iter()
-            .map(|e| UExpression::try_from_int(e, &UBitwidth::B32).unwrap())
+            .map(|e| UExpression::try_from_int(e, &IntBitwidth::B32).unwrap())
             .zip(expected)
         {
             assert_eq!(r, e);
@@ -816,7 +1077,7 @@ mod tests {
 
         for e in should_error
             .into_iter()
-            .map(|e| UExpression::try_from_int(e, &UBitwidth::B32))
+            .map(|e| UExpression::try_from_int(e, &IntBitwidth::B32))
         {
             assert!(e.is_err());
         }
@@ -20,18 +20,23 @@ pub use self::identifier::CoreIdentifier;
 pub use self::parameter::{DeclarationParameter, GParameter};
 pub use self::types::{
     CanonicalConstantIdentifier, ConcreteFunctionKey, ConcreteSignature, ConcreteType,
-    ConstantIdentifier, DeclarationArrayType, DeclarationFunctionKey, DeclarationSignature,
-    DeclarationStructType, DeclarationType, GArrayType, GStructType, GType, GenericIdentifier,
-    IntoTypes, Signature, StructType, Type, Types, UBitwidth,
+    ConstantIdentifier, DeclarationArrayType, DeclarationEnumType, DeclarationFunctionKey,
+    DeclarationSignature, DeclarationStructType, DeclarationTupleType, DeclarationType, EnumType,
+    GArrayType, GEnumType, GStructType, GTupleType, GType, GenericIdentifier,
+    GenericTypeIdentifier, IntBitwidth, IntoTypes, Signature, StructType, TupleType, Type, Types,
 };
 use crate::typed_absy::types::ConcreteGenericsAssignment;
 
 pub use self::variable::{ConcreteVariable, DeclarationVariable, GVariable, Variable};
+use crate::parser::Position;
+use crate::source_map::SourceLocation;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
 
 pub use crate::typed_absy::integer::IntExpression;
-pub use crate::typed_absy::uint::{bitwidth, UExpression, UExpressionInner, UMetadata};
+pub use crate::typed_absy::uint::{
+    bitwidth, from_signed, to_signed, UExpression, UExpressionInner, UMetadata,
+};
 
 use crate::embed::FlatEmbed;
 
@@ -338,6 +343,7 @@ pub enum TypedAssignee<'ast, T> {
     Identifier(Variable<'ast, T>),
     Select(Box<TypedAssignee<'ast, T>>, Box<UExpression<'ast, T>>),
     Member(Box<TypedAssignee<'ast, T>>, MemberId),
+    Element(Box<TypedAssignee<'ast, T>>, u32),
 }
 
 #[derive(Clone, PartialEq, Hash, Eq, Debug)]
@@ -357,6 +363,7 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedSpread<'ast, T> {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, PartialEq, Debug, Hash, Eq)]
 pub enum TypedExpressionOrSpread<'ast, T> {
     Expression(TypedExpression<'ast, T>),
@@ -418,6 +425,18 @@ impl<'ast, T> From<StructExpression<'ast, T>> for TypedExpressionOrSpread<'ast,
     }
 }
 
+impl<'ast, T> From<TupleExpression<'ast, T>> for TypedExpressionOrSpread<'ast, T> {
+    fn from(e: TupleExpression<'ast, T>) -> Self {
+        TypedExpressionOrSpread::Expression(e.into())
+    }
+}
+
+impl<'ast, T> From<EnumExpression<'ast, T>> for TypedExpressionOrSpread<'ast, T> {
+    fn from(e: EnumExpression<'ast, T>) -> Self {
+        TypedExpressionOrSpread::Expression(e.into())
+    }
+}
+
 impl<'ast, T> From<TypedExpression<'ast, T>> for TypedExpressionOrSpread<'ast, T> {
     fn from(e: TypedExpression<'ast, T>) -> Self {
         TypedExpressionOrSpread::Expression(e)
@@ -471,6 +490,13 @@ impl<'ast, T: Clone> Typed<'ast, T> for TypedAssignee<'ast, T> {
                     _ => unreachable!("a struct access should only be defined over structs"),
                 }
             }
+            TypedAssignee::Element(ref t, index) => {
+                let t_type = t.get_type();
+                match t_type {
+                    Type::Tuple(tuple_ty) => tuple_ty.elements[index as usize].clone(),
+                    _ => unreachable!("a tuple access should only be defined over tuples"),
+                }
+            }
         }
     }
 }
@@ -481,6 +507,40 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedAssignee<'ast, T> {
             TypedAssignee::Identifier(ref s) => write!(f, "{}", s.id),
             TypedAssignee::Select(ref a, ref e) => write!(f, "{}[{}]", a, e),
             TypedAssignee::Member(ref s, ref m) => write!(f, "{}.{}", s, m),
+            TypedAssignee::Element(ref t, index) => write!(f, "{}.{}", t, index),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+pub struct AssertionMetadata {
+    pub file: String,
+    pub position: Position,
+    pub message: Option<String>,
+}
+
+impl fmt::Display for AssertionMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Assertion failed at {}:{}", self.file, self.position)?;
+        match &self.message {
+            Some(m) => write!(f, ": \"{}\"", m),
+            None => write!(f, ""),
+        }
+    }
+}
+
+/// The reason for an assertion, reported when the assertion fails at runtime
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+pub enum RuntimeError {
+    SourceAssertion(AssertionMetadata),
+    SelectRangeCheck,
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuntimeError::SourceAssertion(metadata) => write!(f, "{}", metadata),
+            RuntimeError::SelectRangeCheck => write!(f, "Range check on array access"),
         }
     }
 }
@@ -492,20 +552,28 @@ pub enum TypedStatement<'ast, T> {
     Return(Vec<TypedExpression<'ast, T>>),
     Definition(TypedAssignee<'ast, T>, TypedExpression<'ast, T>),
     Declaration(Variable<'ast, T>),
-    Assertion(BooleanExpression<'ast, T>),
+    Assertion(BooleanExpression<'ast, T>, RuntimeError),
     For(
         Variable<'ast, T>,
         UExpression<'ast, T>,
         UExpression<'ast, T>,
         Vec<TypedStatement<'ast, T>>,
     ),
+    IfElse(
+        BooleanExpression<'ast, T>,
+        Vec<TypedStatement<'ast, T>>,
+        Vec<TypedStatement<'ast, T>>,
+    ),
     MultipleDefinition(Vec<TypedAssignee<'ast, T>>, TypedExpressionList<'ast, T>),
     // Aux
+    Location(SourceLocation),
     PushCallLog(
         DeclarationFunctionKey<'ast>,
         ConcreteGenericsAssignment<'ast>,
     ),
     PopCallLog,
+    // the start of an iteration of an unrolled loop, whose first statement defines the loop variable
+    LoopIteration,
 }
 
 impl<'ast, T: fmt::Display> TypedStatement<'ast, T> {
@@ -520,6 +588,20 @@ impl<'ast, T: fmt::Display> TypedStatement<'ast, T> {
                 }
                 write!(f, "{}endfor", "\t".repeat(depth))
             }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                write!(f, "{}", "\t".repeat(depth))?;
+                writeln!(f, "if {} {{", condition)?;
+                for s in consequence {
+                    s.fmt_indented(f, depth + 1)?;
+                    writeln!(f)?;
+                }
+                writeln!(f, "{}}} else {{", "\t".repeat(depth))?;
+                for s in alternative {
+                    s.fmt_indented(f, depth + 1)?;
+                    writeln!(f)?;
+                }
+                write!(f, "{}}}", "\t".repeat(depth))
+            }
             s => write!(f, "{}{}", "\t".repeat(depth), s),
         }
     }
@@ -540,7 +622,16 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
             }
             TypedStatement::Declaration(ref var) => write!(f, "{}", var),
             TypedStatement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
-            TypedStatement::Assertion(ref e) => write!(f, "assert({})", e),
+            TypedStatement::Assertion(ref e, ref error) => {
+                write!(f, "assert({}", e)?;
+                match error {
+                    RuntimeError::SourceAssertion(metadata) => match &metadata.message {
+                        Some(m) => write!(f, ", \"{}\")", m),
+                        None => write!(f, ")"),
+                    },
+                    error => write!(f, ") // {}", error),
+                }
+            }
             TypedStatement::For(ref var, ref start, ref stop, ref list) => {
                 writeln!(f, "for {} in {}..{} do", var, start, stop)?;
                 for l in list {
@@ -548,6 +639,17 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 }
                 write!(f, "\tendfor")
             }
+            TypedStatement::IfElse(ref condition, ref consequence, ref alternative) => {
+                writeln!(f, "if {} {{", condition)?;
+                for l in consequence {
+                    writeln!(f, "\t\t{}", l)?;
+                }
+                writeln!(f, "\t}} else {{")?;
+                for l in alternative {
+                    writeln!(f, "\t\t{}", l)?;
+                }
+                write!(f, "\t}}")
+            }
             TypedStatement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -557,6 +659,7 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 }
                 write!(f, " = {}", rhs)
             }
+            TypedStatement::Location(ref location) => write!(f, "// {}", location),
             TypedStatement::PushCallLog(ref key, ref generics) => write!(
                 f,
                 "// PUSH CALL TO {}/{}::<{}>",
@@ -565,6 +668,7 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 generics,
             ),
             TypedStatement::PopCallLog => write!(f, "// POP CALL",),
+            TypedStatement::LoopIteration => write!(f, "// LOOP ITERATION"),
         }
     }
 }
@@ -582,6 +686,8 @@ pub enum TypedExpression<'ast, T> {
     Uint(UExpression<'ast, T>),
     Array(ArrayExpression<'ast, T>),
     Struct(StructExpression<'ast, T>),
+    Tuple(TupleExpression<'ast, T>),
+    Enum(EnumExpression<'ast, T>),
     Int(IntExpression<'ast, T>),
 }
 
@@ -621,6 +727,18 @@ impl<'ast, T> From<StructExpression<'ast, T>> for TypedExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> From<TupleExpression<'ast, T>> for TypedExpression<'ast, T> {
+    fn from(e: TupleExpression<'ast, T>) -> TypedExpression<T> {
+        TypedExpression::Tuple(e)
+    }
+}
+
+impl<'ast, T> From<EnumExpression<'ast, T>> for TypedExpression<'ast, T> {
+    fn from(e: EnumExpression<'ast, T>) -> TypedExpression<T> {
+        TypedExpression::Enum(e)
+    }
+}
+
 impl<'ast, T: fmt::Display> fmt::Display for TypedExpression<'ast, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -629,6 +747,8 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedExpression<'ast, T> {
             TypedExpression::Uint(ref e) => write!(f, "{}", e),
             TypedExpression::Array(ref e) => write!(f, "{}", e),
             TypedExpression::Struct(ref s) => write!(f, "{}", s),
+            TypedExpression::Tuple(ref t) => write!(f, "{}", t),
+            TypedExpression::Enum(ref e) => write!(f, "{}", e),
             TypedExpression::Int(ref s) => write!(f, "{}", s),
         }
     }
@@ -663,6 +783,74 @@ impl<'ast, T: fmt::Display> fmt::Display for StructExpression<'ast, T> {
             StructExpressionInner::IfElse(ref c) => write!(f, "{}", c),
             StructExpressionInner::Member(ref m) => write!(f, "{}", m),
             StructExpressionInner::Select(ref select) => write!(f, "{}", select),
+            StructExpressionInner::Element(ref element) => write!(f, "{}", element),
+        }
+    }
+}
+
+impl<'ast, T: fmt::Display> fmt::Display for TupleExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.inner {
+            TupleExpressionInner::Block(ref block) => write!(f, "{}", block),
+            TupleExpressionInner::Identifier(ref var) => write!(f, "{}", var),
+            TupleExpressionInner::Value(ref values) => write!(
+                f,
+                "({}{})",
+                values
+                    .iter()
+                    .map(|o| o.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                // a single element tuple is disambiguated from a parenthesized expression with a trailing comma
+                if values.len() == 1 { "," } else { "" }
+            ),
+            TupleExpressionInner::FunctionCall(ref function_call) => {
+                write!(f, "{}", function_call)
+            }
+            TupleExpressionInner::IfElse(ref c) => write!(f, "{}", c),
+            TupleExpressionInner::Member(ref m) => write!(f, "{}", m),
+            TupleExpressionInner::Select(ref select) => write!(f, "{}", select),
+            TupleExpressionInner::Element(ref element) => write!(f, "{}", element),
+            TupleExpressionInner::Payload(ref e, variant) => {
+                write!(f, "{}::{}", e, e.ty().variants[variant as usize].id)
+            }
+        }
+    }
+}
+
+impl<'ast, T: fmt::Display> fmt::Display for EnumExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.inner {
+            EnumExpressionInner::Block(ref block) => write!(f, "{}", block),
+            EnumExpressionInner::Identifier(ref var) => write!(f, "{}", var),
+            EnumExpressionInner::Value(variant, ref values) => {
+                write!(
+                    f,
+                    "{}::{}",
+                    self.ty.name(),
+                    self.ty.variants[variant as usize].id
+                )?;
+                if values.is_empty() {
+                    write!(f, "")
+                } else {
+                    write!(
+                        f,
+                        "({})",
+                        values
+                            .iter()
+                            .map(|o| o.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                }
+            }
+            EnumExpressionInner::FunctionCall(ref function_call) => {
+                write!(f, "{}", function_call)
+            }
+            EnumExpressionInner::IfElse(ref c) => write!(f, "{}", c),
+            EnumExpressionInner::Member(ref m) => write!(f, "{}", m),
+            EnumExpressionInner::Select(ref select) => write!(f, "{}", select),
+            EnumExpressionInner::Element(ref element) => write!(f, "{}", element),
         }
     }
 }
@@ -675,6 +863,8 @@ impl<'ast, T: Clone> Typed<'ast, T> for TypedExpression<'ast, T> {
             TypedExpression::Array(ref e) => e.get_type(),
             TypedExpression::Uint(ref e) => e.get_type(),
             TypedExpression::Struct(ref s) => s.get_type(),
+            TypedExpression::Tuple(ref t) => t.get_type(),
+            TypedExpression::Enum(ref e) => e.get_type(),
             TypedExpression::Int(_) => Type::Int,
         }
     }
@@ -692,6 +882,18 @@ impl<'ast, T: Clone> Typed<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Typed<'ast, T> for TupleExpression<'ast, T> {
+    fn get_type(&self) -> Type<'ast, T> {
+        Type::Tuple(self.ty.clone())
+    }
+}
+
+impl<'ast, T: Clone> Typed<'ast, T> for EnumExpression<'ast, T> {
+    fn get_type(&self) -> Type<'ast, T> {
+        Type::Enum(self.ty.clone())
+    }
+}
+
 impl<'ast, T: Clone> Typed<'ast, T> for FieldElementExpression<'ast, T> {
     fn get_type(&self) -> Type<'ast, T> {
         Type::FieldElement
@@ -731,6 +933,8 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedExpressionList<'ast, T> {
 pub enum TypedExpressionListInner<'ast, T> {
     FunctionCall(FunctionCallExpression<'ast, T, TypedExpressionList<'ast, T>>),
     EmbedCall(FlatEmbed, Vec<u32>, Vec<TypedExpression<'ast, T>>),
+    // the destructuring of a tuple into its elements
+    Tuple(TupleExpression<'ast, T>),
 }
 
 impl<'ast, T> MultiTyped<'ast, T> for TypedExpressionList<'ast, T> {
@@ -805,6 +1009,29 @@ impl<'ast, T: fmt::Display, E> fmt::Display for SelectExpression<'ast, T, E> {
     }
 }
 
+#[derive(Clone, PartialEq, Debug, Hash, Eq)]
+pub struct ElementExpression<'ast, T, E> {
+    pub tuple: Box<TupleExpression<'ast, T>>,
+    pub index: u32,
+    ty: PhantomData<E>,
+}
+
+impl<'ast, T, E> ElementExpression<'ast, T, E> {
+    pub fn new(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        ElementExpression {
+            tuple: box tuple,
+            index,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'ast, T: fmt::Display, E> fmt::Display for ElementExpression<'ast, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.tuple, self.index)
+    }
+}
+
 #[derive(Clone, PartialEq, Debug, Hash, Eq)]
 pub struct IfElseExpression<'ast, T, E> {
     pub condition: Box<BooleanExpression<'ast, T>>,
@@ -916,6 +1143,9 @@ pub enum FieldElementExpression<'ast, T> {
     FunctionCall(FunctionCallExpression<'ast, T, Self>),
     Member(MemberExpression<'ast, T, Self>),
     Select(SelectExpression<'ast, T, Self>),
+    Element(ElementExpression<'ast, T, Self>),
+    // the index of the active variant of an enum
+    Tag(Box<EnumExpression<'ast, T>>),
 }
 impl<'ast, T> Add for FieldElementExpression<'ast, T> {
     type Output = Self;
@@ -1000,7 +1230,9 @@ pub enum BooleanExpression<'ast, T> {
         Box<StructExpression<'ast, T>>,
         Box<StructExpression<'ast, T>>,
     ),
+    TupleEq(Box<TupleExpression<'ast, T>>, Box<TupleExpression<'ast, T>>),
     UintEq(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintBit(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Or(
         Box<BooleanExpression<'ast, T>>,
         Box<BooleanExpression<'ast, T>>,
@@ -1014,6 +1246,7 @@ pub enum BooleanExpression<'ast, T> {
     Member(MemberExpression<'ast, T, Self>),
     FunctionCall(FunctionCallExpression<'ast, T, Self>),
     Select(SelectExpression<'ast, T, Self>),
+    Element(ElementExpression<'ast, T, Self>),
 }
 
 impl<'ast, T> From<bool> for BooleanExpression<'ast, T> {
@@ -1120,12 +1353,20 @@ pub enum ArrayExpressionInner<'ast, T> {
     IfElse(IfElseExpression<'ast, T, ArrayExpression<'ast, T>>),
     Member(MemberExpression<'ast, T, ArrayExpression<'ast, T>>),
     Select(SelectExpression<'ast, T, ArrayExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, ArrayExpression<'ast, T>>),
     Slice(
         Box<ArrayExpression<'ast, T>>,
         Box<UExpression<'ast, T>>,
         Box<UExpression<'ast, T>>,
     ),
     Repeat(Box<TypedExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    // `[e for v in from..to]`, desugared into a loop before reduction
+    Comprehension(
+        Box<TypedExpression<'ast, T>>,
+        Variable<'ast, T>,
+        Box<UExpression<'ast, T>>,
+        Box<UExpression<'ast, T>>,
+    ),
 }
 
 impl<'ast, T> ArrayExpressionInner<'ast, T> {
@@ -1184,6 +1425,7 @@ pub enum StructExpressionInner<'ast, T> {
     IfElse(IfElseExpression<'ast, T, StructExpression<'ast, T>>),
     Member(MemberExpression<'ast, T, StructExpression<'ast, T>>),
     Select(SelectExpression<'ast, T, StructExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, StructExpression<'ast, T>>),
 }
 
 impl<'ast, T> StructExpressionInner<'ast, T> {
@@ -1192,6 +1434,101 @@ impl<'ast, T> StructExpressionInner<'ast, T> {
     }
 }
 
+#[derive(Clone, PartialEq, Debug, Hash, Eq)]
+pub struct TupleExpression<'ast, T> {
+    ty: TupleType<'ast, T>,
+    inner: TupleExpressionInner<'ast, T>,
+}
+
+impl<'ast, T> TupleExpression<'ast, T> {
+    pub fn ty(&self) -> &TupleType<'ast, T> {
+        &self.ty
+    }
+
+    pub fn as_inner(&self) -> &TupleExpressionInner<'ast, T> {
+        &self.inner
+    }
+
+    pub fn as_inner_mut(&mut self) -> &mut TupleExpressionInner<'ast, T> {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> TupleExpressionInner<'ast, T> {
+        self.inner
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Hash, Eq)]
+pub enum TupleExpressionInner<'ast, T> {
+    Block(BlockExpression<'ast, T, TupleExpression<'ast, T>>),
+    Identifier(Identifier<'ast>),
+    Value(Vec<TypedExpression<'ast, T>>),
+    FunctionCall(FunctionCallExpression<'ast, T, TupleExpression<'ast, T>>),
+    IfElse(IfElseExpression<'ast, T, TupleExpression<'ast, T>>),
+    Member(MemberExpression<'ast, T, TupleExpression<'ast, T>>),
+    Select(SelectExpression<'ast, T, TupleExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, TupleExpression<'ast, T>>),
+    // the payload of a given variant of an enum, only meaningful if that variant is active
+    Payload(Box<EnumExpression<'ast, T>>, u32),
+}
+
+impl<'ast, T> TupleExpressionInner<'ast, T> {
+    pub fn annotate(self, ty: TupleType<'ast, T>) -> TupleExpression<'ast, T> {
+        TupleExpression { ty, inner: self }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Hash, Eq)]
+pub struct EnumExpression<'ast, T> {
+    ty: EnumType<'ast, T>,
+    inner: EnumExpressionInner<'ast, T>,
+}
+
+impl<'ast, T> EnumExpression<'ast, T> {
+    pub fn ty(&self) -> &EnumType<'ast, T> {
+        &self.ty
+    }
+
+    pub fn as_inner(&self) -> &EnumExpressionInner<'ast, T> {
+        &self.inner
+    }
+
+    pub fn as_inner_mut(&mut self) -> &mut EnumExpressionInner<'ast, T> {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> EnumExpressionInner<'ast, T> {
+        self.inner
+    }
+}
+
+impl<'ast, T: Clone> EnumExpression<'ast, T> {
+    /// The payload of the variant at index `variant`, as a tuple
+    pub fn payload(self, variant: u32) -> TupleExpression<'ast, T> {
+        let payload_ty = self.ty.variants[variant as usize].payload.clone();
+        TupleExpressionInner::Payload(box self, variant).annotate(payload_ty)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Hash, Eq)]
+pub enum EnumExpressionInner<'ast, T> {
+    Block(BlockExpression<'ast, T, EnumExpression<'ast, T>>),
+    Identifier(Identifier<'ast>),
+    // the index of the active variant and its payload
+    Value(u32, Vec<TypedExpression<'ast, T>>),
+    FunctionCall(FunctionCallExpression<'ast, T, EnumExpression<'ast, T>>),
+    IfElse(IfElseExpression<'ast, T, EnumExpression<'ast, T>>),
+    Member(MemberExpression<'ast, T, EnumExpression<'ast, T>>),
+    Select(SelectExpression<'ast, T, EnumExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, EnumExpression<'ast, T>>),
+}
+
+impl<'ast, T> EnumExpressionInner<'ast, T> {
+    pub fn annotate(self, ty: EnumType<'ast, T>) -> EnumExpression<'ast, T> {
+        EnumExpression { ty, inner: self }
+    }
+}
+
 // Downcasts
 // Due to the fact that we keep TypedExpression simple, we end up with ArrayExpressionInner::Value whose elements are any TypedExpression, but we enforce by
 // construction that these elements are of the type declared in the corresponding ArrayExpression. As we know this by construction, we can downcast the TypedExpression to the correct type
@@ -1250,6 +1587,24 @@ impl<'ast, T> From<TypedExpression<'ast, T>> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> From<TypedExpression<'ast, T>> for TupleExpression<'ast, T> {
+    fn from(te: TypedExpression<'ast, T>) -> TupleExpression<'ast, T> {
+        match te {
+            TypedExpression::Tuple(e) => e,
+            _ => unreachable!("downcast failed"),
+        }
+    }
+}
+
+impl<'ast, T> From<TypedExpression<'ast, T>> for EnumExpression<'ast, T> {
+    fn from(te: TypedExpression<'ast, T>) -> EnumExpression<'ast, T> {
+        match te {
+            TypedExpression::Enum(e) => e,
+            _ => unreachable!("downcast failed"),
+        }
+    }
+}
+
 // `TypedExpressionList` can technically not be constructed from `TypedExpression`
 // However implementing `From<TypedExpression>` is required for `TypedExpressionList` to be `Expr`, which makes generic treatment of function calls possible
 // This could maybe be avoided by splitting the `Expr` trait into many, but I did not find a way
@@ -1289,6 +1644,18 @@ impl<'ast, T> From<TypedConstant<'ast, T>> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> From<TypedConstant<'ast, T>> for TupleExpression<'ast, T> {
+    fn from(tc: TypedConstant<'ast, T>) -> TupleExpression<'ast, T> {
+        tc.expression.into()
+    }
+}
+
+impl<'ast, T> From<TypedConstant<'ast, T>> for EnumExpression<'ast, T> {
+    fn from(tc: TypedConstant<'ast, T>) -> EnumExpression<'ast, T> {
+        tc.expression.into()
+    }
+}
+
 impl<'ast, T> From<TypedConstant<'ast, T>> for IntExpression<'ast, T> {
     fn from(tc: TypedConstant<'ast, T>) -> IntExpression<'ast, T> {
         tc.expression.into()
@@ -1329,6 +1696,8 @@ impl<'ast, T: fmt::Display> fmt::Display for FieldElementExpression<'ast, T> {
             }
             FieldElementExpression::Member(ref m) => write!(f, "{}", m),
             FieldElementExpression::Select(ref select) => write!(f, "{}", select),
+            FieldElementExpression::Element(ref element) => write!(f, "{}", element),
+            FieldElementExpression::Tag(ref e) => write!(f, "TAG({})", e),
         }
     }
 }
@@ -1337,7 +1706,10 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.inner {
             UExpressionInner::Block(ref block) => write!(f, "{}", block,),
-            UExpressionInner::Value(ref v) => write!(f, "{}", v),
+            UExpressionInner::Value(ref v) => match self.bitwidth.is_signed() {
+                true => write!(f, "{}", to_signed(*v, self.bitwidth)),
+                false => write!(f, "{}", v),
+            },
             UExpressionInner::Identifier(ref var) => write!(f, "{}", var),
             UExpressionInner::Add(ref lhs, ref rhs) => write!(f, "({} + {})", lhs, rhs),
             UExpressionInner::And(ref lhs, ref rhs) => write!(f, "({} & {})", lhs, rhs),
@@ -1351,6 +1723,11 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
             UExpressionInner::Div(ref lhs, ref rhs) => write!(f, "({} / {})", lhs, rhs),
             UExpressionInner::Rem(ref lhs, ref rhs) => write!(f, "({} % {})", lhs, rhs),
             UExpressionInner::RightShift(ref e, ref by) => write!(f, "({} >> {})", e, by),
+            UExpressionInner::LeftRotate(ref e, ref by) => write!(f, "({} <<< {})", e, by),
+            UExpressionInner::RightRotate(ref e, ref by) => write!(f, "({} >>> {})", e, by),
+            UExpressionInner::Slice(ref e, ref from, ref to) => {
+                write!(f, "{}[{}..{}]", e, from, to)
+            }
             UExpressionInner::LeftShift(ref e, ref by) => write!(f, "({} << {})", e, by),
             UExpressionInner::Not(ref e) => write!(f, "!{}", e),
             UExpressionInner::Neg(ref e) => write!(f, "(-{})", e),
@@ -1359,6 +1736,7 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
             UExpressionInner::FunctionCall(ref function_call) => write!(f, "{}", function_call),
             UExpressionInner::IfElse(ref c) => write!(f, "{}", c),
             UExpressionInner::Member(ref m) => write!(f, "{}", m),
+            UExpressionInner::Element(ref element) => write!(f, "{}", element),
         }
     }
 }
@@ -1380,7 +1758,9 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             BooleanExpression::BoolEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::ArrayEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::StructEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::TupleEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::UintEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::UintBit(ref e, ref index) => write!(f, "{}[{}]", e, index),
             BooleanExpression::Or(ref lhs, ref rhs) => write!(f, "{} || {}", lhs, rhs),
             BooleanExpression::And(ref lhs, ref rhs) => write!(f, "{} && {}", lhs, rhs),
             BooleanExpression::Not(ref exp) => write!(f, "!{}", exp),
@@ -1389,6 +1769,7 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             BooleanExpression::IfElse(ref c) => write!(f, "{}", c),
             BooleanExpression::Member(ref m) => write!(f, "{}", m),
             BooleanExpression::Select(ref select) => write!(f, "{}", select),
+            BooleanExpression::Element(ref element) => write!(f, "{}", element),
         }
     }
 }
@@ -1411,12 +1792,16 @@ impl<'ast, T: fmt::Display> fmt::Display for ArrayExpressionInner<'ast, T> {
             ArrayExpressionInner::IfElse(ref c) => write!(f, "{}", c),
             ArrayExpressionInner::Member(ref m) => write!(f, "{}", m),
             ArrayExpressionInner::Select(ref select) => write!(f, "{}", select),
+            ArrayExpressionInner::Element(ref element) => write!(f, "{}", element),
             ArrayExpressionInner::Slice(ref a, ref from, ref to) => {
                 write!(f, "{}[{}..{}]", a, from, to)
             }
             ArrayExpressionInner::Repeat(ref e, ref count) => {
                 write!(f, "[{}; {}]", e, count)
             }
+            ArrayExpressionInner::Comprehension(ref e, ref var, ref from, ref to) => {
+                write!(f, "[{} for {} in {}..{}]", e, var, from, to)
+            }
         }
     }
 }
@@ -1449,6 +1834,7 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedExpressionListInner<'ast, T> {
                 }
                 write!(f, ")")
             }
+            TypedExpressionListInner::Tuple(ref tuple) => write!(f, "{}", tuple),
         }
     }
 }
@@ -1464,8 +1850,10 @@ impl<'ast, T: Field> From<Variable<'ast, T>> for TypedExpression<'ast, T> {
                 .annotate(*ty.ty, ty.size)
                 .into(),
             Type::Struct(ty) => StructExpressionInner::Identifier(v.id).annotate(ty).into(),
+            Type::Tuple(ty) => TupleExpressionInner::Identifier(v.id).annotate(ty).into(),
+            Type::Enum(ty) => EnumExpressionInner::Identifier(v.id).annotate(ty).into(),
             Type::Uint(w) => UExpressionInner::Identifier(v.id).annotate(w).into(),
-            Type::Int => unreachable!(),
+            Type::Int | Type::Generic(..) => unreachable!(),
         }
     }
 }
@@ -1529,7 +1917,7 @@ impl<'ast, T: Clone> Expr<'ast, T> for BooleanExpression<'ast, T> {
 
 impl<'ast, T: Clone> Expr<'ast, T> for UExpression<'ast, T> {
     type Inner = UExpressionInner<'ast, T>;
-    type Ty = UBitwidth;
+    type Ty = IntBitwidth;
 
     fn ty(&self) -> &Self::Ty {
         &self.bitwidth
@@ -1569,6 +1957,48 @@ impl<'ast, T: Clone> Expr<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Expr<'ast, T> for TupleExpression<'ast, T> {
+    type Inner = TupleExpressionInner<'ast, T>;
+    type Ty = TupleType<'ast, T>;
+
+    fn ty(&self) -> &Self::Ty {
+        &self.ty
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        self.inner
+    }
+
+    fn as_inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn as_inner_mut(&mut self) -> &mut Self::Inner {
+        &mut self.inner
+    }
+}
+
+impl<'ast, T: Clone> Expr<'ast, T> for EnumExpression<'ast, T> {
+    type Inner = EnumExpressionInner<'ast, T>;
+    type Ty = EnumType<'ast, T>;
+
+    fn ty(&self) -> &Self::Ty {
+        &self.ty
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        self.inner
+    }
+
+    fn as_inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn as_inner_mut(&mut self) -> &mut Self::Inner {
+        &mut self.inner
+    }
+}
+
 impl<'ast, T: Clone> Expr<'ast, T> for ArrayExpression<'ast, T> {
     type Inner = ArrayExpressionInner<'ast, T>;
     type Ty = ArrayType<'ast, T>;
@@ -1648,6 +2078,11 @@ pub enum MemberOrExpression<'ast, T, E: Expr<'ast, T>> {
     Expression(E::Inner),
 }
 
+pub enum ElementOrExpression<'ast, T, E: Expr<'ast, T>> {
+    Element(ElementExpression<'ast, T, E>),
+    Expression(E::Inner),
+}
+
 pub enum IfElseOrExpression<'ast, T, E: Expr<'ast, T>> {
     IfElse(IfElseExpression<'ast, T, E>),
     Expression(E::Inner),
@@ -1726,6 +2161,66 @@ impl<'ast, T: Clone> IfElse<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> IfElse<'ast, T> for TupleExpression<'ast, T> {
+    fn if_else(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+    ) -> Self {
+        let ty = consequence.ty().clone();
+        TupleExpressionInner::IfElse(IfElseExpression::new(condition, consequence, alternative))
+            .annotate(ty)
+    }
+}
+
+impl<'ast, T: Clone> IfElse<'ast, T> for EnumExpression<'ast, T> {
+    fn if_else(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+    ) -> Self {
+        let ty = consequence.ty().clone();
+        EnumExpressionInner::IfElse(IfElseExpression::new(condition, consequence, alternative))
+            .annotate(ty)
+    }
+}
+
+impl<'ast, T: Clone> IfElse<'ast, T> for TypedExpression<'ast, T> {
+    fn if_else(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+    ) -> Self {
+        match (consequence, alternative) {
+            (TypedExpression::FieldElement(c), TypedExpression::FieldElement(a)) => {
+                FieldElementExpression::if_else(condition, c, a).into()
+            }
+            (TypedExpression::Boolean(c), TypedExpression::Boolean(a)) => {
+                BooleanExpression::if_else(condition, c, a).into()
+            }
+            (TypedExpression::Uint(c), TypedExpression::Uint(a)) => {
+                UExpression::if_else(condition, c, a).into()
+            }
+            (TypedExpression::Array(c), TypedExpression::Array(a)) => {
+                ArrayExpression::if_else(condition, c, a).into()
+            }
+            (TypedExpression::Struct(c), TypedExpression::Struct(a)) => {
+                StructExpression::if_else(condition, c, a).into()
+            }
+            (TypedExpression::Tuple(c), TypedExpression::Tuple(a)) => {
+                TupleExpression::if_else(condition, c, a).into()
+            }
+            (TypedExpression::Enum(c), TypedExpression::Enum(a)) => {
+                EnumExpression::if_else(condition, c, a).into()
+            }
+            (TypedExpression::Int(c), TypedExpression::Int(a)) => {
+                IntExpression::if_else(condition, c, a).into()
+            }
+            _ => unreachable!("consequence and alternative should have the same type"),
+        }
+    }
+}
+
 pub trait Select<'ast, T> {
     fn select<I: Into<UExpression<'ast, T>>>(array: ArrayExpression<'ast, T>, index: I) -> Self;
 }
@@ -1753,10 +2248,13 @@ impl<'ast, T: Clone> Select<'ast, T> for TypedExpression<'ast, T> {
         match *array.ty().ty {
             Type::Array(..) => ArrayExpression::select(array, index).into(),
             Type::Struct(..) => StructExpression::select(array, index).into(),
+            Type::Tuple(..) => TupleExpression::select(array, index).into(),
+            Type::Enum(..) => EnumExpression::select(array, index).into(),
             Type::FieldElement => FieldElementExpression::select(array, index).into(),
             Type::Boolean => BooleanExpression::select(array, index).into(),
             Type::Int => IntExpression::select(array, index).into(),
             Type::Uint(..) => UExpression::select(array, index).into(),
+            Type::Generic(..) => unreachable!(),
         }
     }
 }
@@ -1794,6 +2292,28 @@ impl<'ast, T: Clone> Select<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Select<'ast, T> for TupleExpression<'ast, T> {
+    fn select<I: Into<UExpression<'ast, T>>>(array: ArrayExpression<'ast, T>, index: I) -> Self {
+        let tuple_ty = match array.inner_type().clone() {
+            Type::Tuple(tuple_ty) => tuple_ty,
+            _ => unreachable!(),
+        };
+
+        TupleExpressionInner::Select(SelectExpression::new(array, index.into())).annotate(tuple_ty)
+    }
+}
+
+impl<'ast, T: Clone> Select<'ast, T> for EnumExpression<'ast, T> {
+    fn select<I: Into<UExpression<'ast, T>>>(array: ArrayExpression<'ast, T>, index: I) -> Self {
+        let enum_ty = match array.inner_type().clone() {
+            Type::Enum(enum_ty) => enum_ty,
+            _ => unreachable!(),
+        };
+
+        EnumExpressionInner::Select(SelectExpression::new(array, index.into())).annotate(enum_ty)
+    }
+}
+
 pub trait Member<'ast, T>: Sized {
     fn member(s: StructExpression<'ast, T>, id: MemberId) -> Self;
 }
@@ -1852,6 +2372,137 @@ impl<'ast, T: Clone> Member<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Member<'ast, T> for TupleExpression<'ast, T> {
+    fn member(s: StructExpression<'ast, T>, id: MemberId) -> Self {
+        let ty = s.ty().members.iter().find(|member| id == member.id);
+        let tuple_ty = match ty {
+            Some(crate::typed_absy::types::StructMember {
+                ty: box Type::Tuple(tuple_ty),
+                ..
+            }) => tuple_ty.clone(),
+            _ => unreachable!(),
+        };
+        TupleExpressionInner::Member(MemberExpression::new(s, id)).annotate(tuple_ty)
+    }
+}
+
+impl<'ast, T: Clone> Member<'ast, T> for EnumExpression<'ast, T> {
+    fn member(s: StructExpression<'ast, T>, id: MemberId) -> Self {
+        let ty = s.ty().members.iter().find(|member| id == member.id);
+        let enum_ty = match ty {
+            Some(crate::typed_absy::types::StructMember {
+                ty: box Type::Enum(enum_ty),
+                ..
+            }) => enum_ty.clone(),
+            _ => unreachable!(),
+        };
+        EnumExpressionInner::Member(MemberExpression::new(s, id)).annotate(enum_ty)
+    }
+}
+
+impl<'ast, T: Clone> Member<'ast, T> for TypedExpression<'ast, T> {
+    fn member(s: StructExpression<'ast, T>, id: MemberId) -> Self {
+        match &*s
+            .ty()
+            .members
+            .iter()
+            .find(|member| id == member.id)
+            .unwrap()
+            .ty
+        {
+            Type::Array(..) => ArrayExpression::member(s, id).into(),
+            Type::Struct(..) => StructExpression::member(s, id).into(),
+            Type::Tuple(..) => TupleExpression::member(s, id).into(),
+            Type::Enum(..) => EnumExpression::member(s, id).into(),
+            Type::FieldElement => FieldElementExpression::member(s, id).into(),
+            Type::Boolean => BooleanExpression::member(s, id).into(),
+            Type::Uint(..) => UExpression::member(s, id).into(),
+            Type::Int | Type::Generic(..) => unreachable!(),
+        }
+    }
+}
+
+pub trait Element<'ast, T>: Sized {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self;
+}
+
+impl<'ast, T> Element<'ast, T> for FieldElementExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        FieldElementExpression::Element(ElementExpression::new(tuple, index))
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for BooleanExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        BooleanExpression::Element(ElementExpression::new(tuple, index))
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for UExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let bitwidth = match &tuple.ty().elements[index as usize] {
+            Type::Uint(bitwidth) => *bitwidth,
+            _ => unreachable!(),
+        };
+        UExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(bitwidth)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for ArrayExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let (ty, size) = match &tuple.ty().elements[index as usize] {
+            Type::Array(array_ty) => (*array_ty.ty.clone(), array_ty.size.clone()),
+            _ => unreachable!(),
+        };
+        ArrayExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(ty, size)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for StructExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let struct_ty = match &tuple.ty().elements[index as usize] {
+            Type::Struct(struct_ty) => struct_ty.clone(),
+            _ => unreachable!(),
+        };
+        StructExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(struct_ty)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for TupleExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let tuple_ty = match &tuple.ty().elements[index as usize] {
+            Type::Tuple(tuple_ty) => tuple_ty.clone(),
+            _ => unreachable!(),
+        };
+        TupleExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(tuple_ty)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for EnumExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let enum_ty = match &tuple.ty().elements[index as usize] {
+            Type::Enum(enum_ty) => enum_ty.clone(),
+            _ => unreachable!(),
+        };
+        EnumExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(enum_ty)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for TypedExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        match &tuple.ty().elements[index as usize] {
+            Type::Array(..) => ArrayExpression::element(tuple, index).into(),
+            Type::Struct(..) => StructExpression::element(tuple, index).into(),
+            Type::Tuple(..) => TupleExpression::element(tuple, index).into(),
+            Type::Enum(..) => EnumExpression::element(tuple, index).into(),
+            Type::FieldElement => FieldElementExpression::element(tuple, index).into(),
+            Type::Boolean => BooleanExpression::element(tuple, index).into(),
+            Type::Uint(..) => UExpression::element(tuple, index).into(),
+            Type::Int | Type::Generic(..) => unreachable!(),
+        }
+    }
+}
+
 pub trait Id<'ast, T>: Expr<'ast, T> {
     fn identifier(id: Identifier<'ast>) -> Self::Inner;
 }
@@ -1886,6 +2537,18 @@ impl<'ast, T: Field> Id<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Id<'ast, T> for TupleExpression<'ast, T> {
+    fn identifier(id: Identifier<'ast>) -> Self::Inner {
+        TupleExpressionInner::Identifier(id)
+    }
+}
+
+impl<'ast, T: Field> Id<'ast, T> for EnumExpression<'ast, T> {
+    fn identifier(id: Identifier<'ast>) -> Self::Inner {
+        EnumExpressionInner::Identifier(id)
+    }
+}
+
 // `TypedExpressionList` does not have an Identifier variant
 // However implementing `From<TypedExpression>` is required for `TypedExpressionList` to be `Expr`, which makes generic treatment of function calls possible
 // This could maybe be avoided by splitting the `Expr` trait into many, but I did not find a way
@@ -1953,6 +2616,26 @@ impl<'ast, T: Field> FunctionCall<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> FunctionCall<'ast, T> for TupleExpression<'ast, T> {
+    fn function_call(
+        key: DeclarationFunctionKey<'ast>,
+        generics: Vec<Option<UExpression<'ast, T>>>,
+        arguments: Vec<TypedExpression<'ast, T>>,
+    ) -> Self::Inner {
+        TupleExpressionInner::FunctionCall(FunctionCallExpression::new(key, generics, arguments))
+    }
+}
+
+impl<'ast, T: Field> FunctionCall<'ast, T> for EnumExpression<'ast, T> {
+    fn function_call(
+        key: DeclarationFunctionKey<'ast>,
+        generics: Vec<Option<UExpression<'ast, T>>>,
+        arguments: Vec<TypedExpression<'ast, T>>,
+    ) -> Self::Inner {
+        EnumExpressionInner::FunctionCall(FunctionCallExpression::new(key, generics, arguments))
+    }
+}
+
 impl<'ast, T: Field> FunctionCall<'ast, T> for TypedExpressionList<'ast, T> {
     fn function_call(
         key: DeclarationFunctionKey<'ast>,
@@ -2004,6 +2687,37 @@ impl<'ast, T: Field> Block<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Block<'ast, T> for TupleExpression<'ast, T> {
+    fn block(statements: Vec<TypedStatement<'ast, T>>, value: Self) -> Self {
+        let tuple_ty = value.ty().clone();
+
+        TupleExpressionInner::Block(BlockExpression::new(statements, value)).annotate(tuple_ty)
+    }
+}
+
+impl<'ast, T: Field> Block<'ast, T> for EnumExpression<'ast, T> {
+    fn block(statements: Vec<TypedStatement<'ast, T>>, value: Self) -> Self {
+        let enum_ty = value.ty().clone();
+
+        EnumExpressionInner::Block(BlockExpression::new(statements, value)).annotate(enum_ty)
+    }
+}
+
+impl<'ast, T: Field> Block<'ast, T> for TypedExpression<'ast, T> {
+    fn block(statements: Vec<TypedStatement<'ast, T>>, value: Self) -> Self {
+        match value {
+            TypedExpression::FieldElement(e) => FieldElementExpression::block(statements, e).into(),
+            TypedExpression::Boolean(e) => BooleanExpression::block(statements, e).into(),
+            TypedExpression::Uint(e) => UExpression::block(statements, e).into(),
+            TypedExpression::Array(e) => ArrayExpression::block(statements, e).into(),
+            TypedExpression::Struct(e) => StructExpression::block(statements, e).into(),
+            TypedExpression::Tuple(e) => TupleExpression::block(statements, e).into(),
+            TypedExpression::Enum(e) => EnumExpression::block(statements, e).into(),
+            TypedExpression::Int(..) => unreachable!("integer expressions cannot be blocks"),
+        }
+    }
+}
+
 pub trait Constant: Sized {
     // return whether this is constant
     fn is_constant(&self) -> bool;
@@ -2176,6 +2890,55 @@ impl<'ast, T: Field> Constant for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Constant for TupleExpression<'ast, T> {
+    fn is_constant(&self) -> bool {
+        match self.as_inner() {
+            TupleExpressionInner::Value(v) => v.iter().all(|e| e.is_constant()),
+            _ => false,
+        }
+    }
+
+    fn into_canonical_constant(self) -> Self {
+        let tuple_ty = self.ty().clone();
+
+        match self.into_inner() {
+            TupleExpressionInner::Value(expressions) => TupleExpressionInner::Value(
+                expressions
+                    .into_iter()
+                    .map(|e| e.into_canonical_constant())
+                    .collect(),
+            )
+            .annotate(tuple_ty),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<'ast, T: Field> Constant for EnumExpression<'ast, T> {
+    fn is_constant(&self) -> bool {
+        match self.as_inner() {
+            EnumExpressionInner::Value(_, v) => v.iter().all(|e| e.is_constant()),
+            _ => false,
+        }
+    }
+
+    fn into_canonical_constant(self) -> Self {
+        let enum_ty = self.ty().clone();
+
+        match self.into_inner() {
+            EnumExpressionInner::Value(variant, expressions) => EnumExpressionInner::Value(
+                variant,
+                expressions
+                    .into_iter()
+                    .map(|e| e.into_canonical_constant())
+                    .collect(),
+            )
+            .annotate(enum_ty),
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl<'ast, T: Field> Constant for TypedExpression<'ast, T> {
     fn is_constant(&self) -> bool {
         match self {
@@ -2183,6 +2946,8 @@ impl<'ast, T: Field> Constant for TypedExpression<'ast, T> {
             TypedExpression::Boolean(e) => e.is_constant(),
             TypedExpression::Array(e) => e.is_constant(),
             TypedExpression::Struct(e) => e.is_constant(),
+            TypedExpression::Tuple(e) => e.is_constant(),
+            TypedExpression::Enum(e) => e.is_constant(),
             TypedExpression::Uint(e) => e.is_constant(),
             _ => unreachable!(),
         }
@@ -2194,6 +2959,8 @@ impl<'ast, T: Field> Constant for TypedExpression<'ast, T> {
             TypedExpression::Boolean(e) => e.into_canonical_constant().into(),
             TypedExpression::Array(e) => e.into_canonical_constant().into(),
             TypedExpression::Struct(e) => e.into_canonical_constant().into(),
+            TypedExpression::Tuple(e) => e.into_canonical_constant().into(),
+            TypedExpression::Enum(e) => e.into_canonical_constant().into(),
             TypedExpression::Uint(e) => e.into_canonical_constant().into(),
             _ => unreachable!(),
         }
@@ -38,6 +38,18 @@ impl<'ast, T: Field> ResultFold<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> ResultFold<'ast, T> for TupleExpression<'ast, T> {
+    fn fold<F: ResultFolder<'ast, T>>(self, f: &mut F) -> Result<Self, F::Error> {
+        f.fold_tuple_expression(self)
+    }
+}
+
+impl<'ast, T: Field> ResultFold<'ast, T> for EnumExpression<'ast, T> {
+    fn fold<F: ResultFolder<'ast, T>>(self, f: &mut F) -> Result<Self, F::Error> {
+        f.fold_enum_expression(self)
+    }
+}
+
 pub trait ResultFolder<'ast, T: Field>: Sized {
     type Error;
 
@@ -156,6 +168,8 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         match t {
             Array(array_type) => Ok(Array(self.fold_array_type(array_type)?)),
             Struct(struct_type) => Ok(Struct(self.fold_struct_type(struct_type)?)),
+            Tuple(tuple_type) => Ok(Tuple(self.fold_tuple_type(tuple_type)?)),
+            Enum(enum_type) => Ok(Enum(self.fold_enum_type(enum_type)?)),
             t => Ok(t),
         }
     }
@@ -191,6 +205,16 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         fold_member_expression(self, ty, e)
     }
 
+    fn fold_element_expression<
+        E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    >(
+        &mut self,
+        ty: &E::Ty,
+        e: ElementExpression<'ast, T, E>,
+    ) -> Result<ElementOrExpression<'ast, T, E>, Self::Error> {
+        fold_element_expression(self, ty, e)
+    }
+
     fn fold_select_expression<
         E: Expr<'ast, T> + Select<'ast, T> + From<TypedExpression<'ast, T>>,
     >(
@@ -231,6 +255,11 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
                 .into_iter()
                 .map(|g| g.map(|g| self.fold_uint_expression(g)).transpose())
                 .collect::<Result<Vec<_>, _>>()?,
+            type_generics: t
+                .type_generics
+                .into_iter()
+                .map(|t| self.fold_type(t))
+                .collect::<Result<_, _>>()?,
             members: t
                 .members
                 .into_iter()
@@ -244,6 +273,35 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         })
     }
 
+    fn fold_tuple_type(
+        &mut self,
+        t: TupleType<'ast, T>,
+    ) -> Result<TupleType<'ast, T>, Self::Error> {
+        Ok(TupleType {
+            elements: t
+                .elements
+                .into_iter()
+                .map(|t| self.fold_type(t))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn fold_enum_type(&mut self, t: EnumType<'ast, T>) -> Result<EnumType<'ast, T>, Self::Error> {
+        Ok(EnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| {
+                    Ok(EnumVariant {
+                        payload: self.fold_tuple_type(v.payload)?,
+                        ..v
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            ..t
+        })
+    }
+
     fn fold_declaration_type(
         &mut self,
         t: DeclarationType<'ast>,
@@ -253,6 +311,8 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         match t {
             Array(array_type) => Ok(Array(self.fold_declaration_array_type(array_type)?)),
             Struct(struct_type) => Ok(Struct(self.fold_declaration_struct_type(struct_type)?)),
+            Tuple(tuple_type) => Ok(Tuple(self.fold_declaration_tuple_type(tuple_type)?)),
+            Enum(enum_type) => Ok(Enum(self.fold_declaration_enum_type(enum_type)?)),
             t => Ok(t),
         }
     }
@@ -277,6 +337,11 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
                 .into_iter()
                 .map(|g| g.map(|g| self.fold_declaration_constant(g)).transpose())
                 .collect::<Result<Vec<_>, _>>()?,
+            type_generics: t
+                .type_generics
+                .into_iter()
+                .map(|t| self.fold_declaration_type(t))
+                .collect::<Result<_, _>>()?,
             members: t
                 .members
                 .into_iter()
@@ -290,6 +355,38 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         })
     }
 
+    fn fold_declaration_tuple_type(
+        &mut self,
+        t: DeclarationTupleType<'ast>,
+    ) -> Result<DeclarationTupleType<'ast>, Self::Error> {
+        Ok(DeclarationTupleType {
+            elements: t
+                .elements
+                .into_iter()
+                .map(|t| self.fold_declaration_type(t))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn fold_declaration_enum_type(
+        &mut self,
+        t: DeclarationEnumType<'ast>,
+    ) -> Result<DeclarationEnumType<'ast>, Self::Error> {
+        Ok(DeclarationEnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| {
+                    Ok(DeclarationEnumVariant {
+                        payload: self.fold_declaration_tuple_type(v.payload)?,
+                        ..v
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            ..t
+        })
+    }
+
     fn fold_assignee(
         &mut self,
         a: TypedAssignee<'ast, T>,
@@ -337,6 +434,8 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
             TypedExpression::Uint(e) => Ok(self.fold_uint_expression(e)?.into()),
             TypedExpression::Array(e) => Ok(self.fold_array_expression(e)?.into()),
             TypedExpression::Struct(e) => Ok(self.fold_struct_expression(e)?.into()),
+            TypedExpression::Tuple(e) => Ok(self.fold_tuple_expression(e)?.into()),
+            TypedExpression::Enum(e) => Ok(self.fold_enum_expression(e)?.into()),
             TypedExpression::Int(e) => Ok(self.fold_int_expression(e)?.into()),
         }
     }
@@ -355,6 +454,20 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         fold_struct_expression(self, e)
     }
 
+    fn fold_tuple_expression(
+        &mut self,
+        e: TupleExpression<'ast, T>,
+    ) -> Result<TupleExpression<'ast, T>, Self::Error> {
+        fold_tuple_expression(self, e)
+    }
+
+    fn fold_enum_expression(
+        &mut self,
+        e: EnumExpression<'ast, T>,
+    ) -> Result<EnumExpression<'ast, T>, Self::Error> {
+        fold_enum_expression(self, e)
+    }
+
     fn fold_expression_list_inner(
         &mut self,
         tys: &Types<'ast, T>,
@@ -398,7 +511,7 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
 
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntBitwidth,
         e: UExpressionInner<'ast, T>,
     ) -> Result<UExpressionInner<'ast, T>, Self::Error> {
         fold_uint_expression_inner(self, bitwidth, e)
@@ -418,6 +531,22 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
     ) -> Result<StructExpressionInner<'ast, T>, Self::Error> {
         fold_struct_expression_inner(self, ty, e)
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> Result<TupleExpressionInner<'ast, T>, Self::Error> {
+        fold_tuple_expression_inner(self, ty, e)
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> Result<EnumExpressionInner<'ast, T>, Self::Error> {
+        fold_enum_expression_inner(self, ty, e)
+    }
 }
 
 pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
@@ -435,7 +564,9 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
             TypedStatement::Definition(f.fold_assignee(a)?, f.fold_expression(e)?)
         }
         TypedStatement::Declaration(v) => TypedStatement::Declaration(f.fold_variable(v)?),
-        TypedStatement::Assertion(e) => TypedStatement::Assertion(f.fold_boolean_expression(e)?),
+        TypedStatement::Assertion(e, error) => {
+            TypedStatement::Assertion(f.fold_boolean_expression(e)?, error)
+        }
         TypedStatement::For(v, from, to, statements) => TypedStatement::For(
             f.fold_variable(v)?,
             f.fold_uint_expression(from)?,
@@ -448,6 +579,23 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 .flatten()
                 .collect(),
         ),
+        TypedStatement::IfElse(condition, consequence, alternative) => TypedStatement::IfElse(
+            f.fold_boolean_expression(condition)?,
+            consequence
+                .into_iter()
+                .map(|s| f.fold_statement(s))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flatten()
+                .collect(),
+            alternative
+                .into_iter()
+                .map(|s| f.fold_statement(s))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flatten()
+                .collect(),
+        ),
         TypedStatement::MultipleDefinition(variables, elist) => TypedStatement::MultipleDefinition(
             variables
                 .into_iter()
@@ -494,6 +642,10 @@ pub fn fold_array_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
             SelectOrExpression::Select(m) => ArrayExpressionInner::Select(m),
             SelectOrExpression::Expression(u) => u,
         },
+        ArrayExpressionInner::Element(element) => match f.fold_element_expression(ty, element)? {
+            ElementOrExpression::Element(m) => ArrayExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
         ArrayExpressionInner::Slice(box array, box from, box to) => {
             let array = f.fold_array_expression(array)?;
             let from = f.fold_uint_expression(from)?;
@@ -505,6 +657,13 @@ pub fn fold_array_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
             let count = f.fold_uint_expression(count)?;
             ArrayExpressionInner::Repeat(box e, box count)
         }
+        ArrayExpressionInner::Comprehension(box e, v, box from, box to) => {
+            let e = f.fold_expression(e)?;
+            let v = f.fold_variable(v)?;
+            let from = f.fold_uint_expression(from)?;
+            let to = f.fold_uint_expression(to)?;
+            ArrayExpressionInner::Comprehension(box e, v, box from, box to)
+        }
     };
     Ok(e)
 }
@@ -520,6 +679,9 @@ pub fn fold_assignee<'ast, T: Field, F: ResultFolder<'ast, T>>(
             box f.fold_uint_expression(index)?,
         )),
         TypedAssignee::Member(box s, m) => Ok(TypedAssignee::Member(box f.fold_assignee(s)?, m)),
+        TypedAssignee::Element(box s, index) => {
+            Ok(TypedAssignee::Element(box f.fold_assignee(s)?, index))
+        }
     }
 }
 
@@ -559,6 +721,98 @@ pub fn fold_struct_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
             SelectOrExpression::Select(m) => StructExpressionInner::Select(m),
             SelectOrExpression::Expression(u) => u,
         },
+        StructExpressionInner::Element(element) => match f.fold_element_expression(ty, element)? {
+            ElementOrExpression::Element(m) => StructExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+    };
+    Ok(e)
+}
+
+pub fn fold_tuple_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    ty: &TupleType<'ast, T>,
+    e: TupleExpressionInner<'ast, T>,
+) -> Result<TupleExpressionInner<'ast, T>, F::Error> {
+    let e = match e {
+        TupleExpressionInner::Block(block) => {
+            TupleExpressionInner::Block(f.fold_block_expression(block)?)
+        }
+        TupleExpressionInner::Identifier(id) => TupleExpressionInner::Identifier(f.fold_name(id)?),
+        TupleExpressionInner::Value(exprs) => TupleExpressionInner::Value(
+            exprs
+                .into_iter()
+                .map(|e| f.fold_expression(e))
+                .collect::<Result<_, _>>()?,
+        ),
+        TupleExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call)? {
+                FunctionCallOrExpression::FunctionCall(c) => TupleExpressionInner::FunctionCall(c),
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        TupleExpressionInner::IfElse(c) => match f.fold_if_else_expression(ty, c)? {
+            IfElseOrExpression::IfElse(c) => TupleExpressionInner::IfElse(c),
+            IfElseOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Member(m) => match f.fold_member_expression(ty, m)? {
+            MemberOrExpression::Member(m) => TupleExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Select(select) => match f.fold_select_expression(ty, select)? {
+            SelectOrExpression::Select(m) => TupleExpressionInner::Select(m),
+            SelectOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Element(element) => match f.fold_element_expression(ty, element)? {
+            ElementOrExpression::Element(m) => TupleExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Payload(box e, variant) => {
+            TupleExpressionInner::Payload(box f.fold_enum_expression(e)?, variant)
+        }
+    };
+    Ok(e)
+}
+
+pub fn fold_enum_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    ty: &EnumType<'ast, T>,
+    e: EnumExpressionInner<'ast, T>,
+) -> Result<EnumExpressionInner<'ast, T>, F::Error> {
+    let e = match e {
+        EnumExpressionInner::Block(block) => {
+            EnumExpressionInner::Block(f.fold_block_expression(block)?)
+        }
+        EnumExpressionInner::Identifier(id) => EnumExpressionInner::Identifier(f.fold_name(id)?),
+        EnumExpressionInner::Value(variant, exprs) => EnumExpressionInner::Value(
+            variant,
+            exprs
+                .into_iter()
+                .map(|e| f.fold_expression(e))
+                .collect::<Result<_, _>>()?,
+        ),
+        EnumExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call)? {
+                FunctionCallOrExpression::FunctionCall(c) => EnumExpressionInner::FunctionCall(c),
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        EnumExpressionInner::IfElse(c) => match f.fold_if_else_expression(ty, c)? {
+            IfElseOrExpression::IfElse(c) => EnumExpressionInner::IfElse(c),
+            IfElseOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Member(m) => match f.fold_member_expression(ty, m)? {
+            MemberOrExpression::Member(m) => EnumExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Select(select) => match f.fold_select_expression(ty, select)? {
+            SelectOrExpression::Select(m) => EnumExpressionInner::Select(m),
+            SelectOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Element(element) => match f.fold_element_expression(ty, element)? {
+            ElementOrExpression::Element(m) => EnumExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     };
     Ok(e)
 }
@@ -636,6 +890,15 @@ pub fn fold_field_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 SelectOrExpression::Expression(u) => u,
             }
         }
+        FieldElementExpression::Element(element) => {
+            match f.fold_element_expression(&Type::FieldElement, element)? {
+                ElementOrExpression::Element(m) => FieldElementExpression::Element(m),
+                ElementOrExpression::Expression(u) => u,
+            }
+        }
+        FieldElementExpression::Tag(box e) => {
+            FieldElementExpression::Tag(box f.fold_enum_expression(e)?)
+        }
     };
     Ok(e)
 }
@@ -701,6 +964,22 @@ pub fn fold_member_expression<
     )))
 }
 
+pub fn fold_element_expression<
+    'ast,
+    T: Field,
+    E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    F: ResultFolder<'ast, T>,
+>(
+    f: &mut F,
+    _: &E::Ty,
+    e: ElementExpression<'ast, T, E>,
+) -> Result<ElementOrExpression<'ast, T, E>, F::Error> {
+    Ok(ElementOrExpression::Element(ElementExpression::new(
+        f.fold_tuple_expression(*e.tuple)?,
+        e.index,
+    )))
+}
+
 pub fn fold_select_expression<
     'ast,
     T: Field,
@@ -770,11 +1049,21 @@ pub fn fold_boolean_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
             let e2 = f.fold_struct_expression(e2)?;
             BooleanExpression::StructEq(box e1, box e2)
         }
+        BooleanExpression::TupleEq(box e1, box e2) => {
+            let e1 = f.fold_tuple_expression(e1)?;
+            let e2 = f.fold_tuple_expression(e2)?;
+            BooleanExpression::TupleEq(box e1, box e2)
+        }
         BooleanExpression::UintEq(box e1, box e2) => {
             let e1 = f.fold_uint_expression(e1)?;
             let e2 = f.fold_uint_expression(e2)?;
             BooleanExpression::UintEq(box e1, box e2)
         }
+        BooleanExpression::UintBit(box e, box index) => {
+            let e = f.fold_uint_expression(e)?;
+            let index = f.fold_uint_expression(index)?;
+            BooleanExpression::UintBit(box e, box index)
+        }
         BooleanExpression::FieldLt(box e1, box e2) => {
             let e1 = f.fold_field_expression(e1)?;
             let e2 = f.fold_field_expression(e2)?;
@@ -849,6 +1138,10 @@ pub fn fold_boolean_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
             MemberOrExpression::Member(m) => BooleanExpression::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        BooleanExpression::Element(m) => match f.fold_element_expression(&Type::Boolean, m)? {
+            ElementOrExpression::Element(m) => BooleanExpression::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     };
     Ok(e)
 }
@@ -865,7 +1158,7 @@ pub fn fold_uint_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
 
 pub fn fold_uint_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
     f: &mut F,
-    ty: UBitwidth,
+    ty: IntBitwidth,
     e: UExpressionInner<'ast, T>,
 ) -> Result<UExpressionInner<'ast, T>, F::Error> {
     let e = match e {
@@ -938,6 +1231,23 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
 
             UExpressionInner::RightShift(box e, box by)
         }
+        UExpressionInner::LeftRotate(box e, box by) => {
+            let e = f.fold_uint_expression(e)?;
+            let by = f.fold_uint_expression(by)?;
+
+            UExpressionInner::LeftRotate(box e, box by)
+        }
+        UExpressionInner::RightRotate(box e, box by) => {
+            let e = f.fold_uint_expression(e)?;
+            let by = f.fold_uint_expression(by)?;
+
+            UExpressionInner::RightRotate(box e, box by)
+        }
+        UExpressionInner::Slice(box e, from, to) => {
+            let e = f.fold_uint_expression(e)?;
+
+            UExpressionInner::Slice(box e, from, to)
+        }
         UExpressionInner::Not(box e) => {
             let e = f.fold_uint_expression(e)?;
 
@@ -971,6 +1281,10 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
             MemberOrExpression::Member(m) => UExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        UExpressionInner::Element(m) => match f.fold_element_expression(&ty, m)? {
+            ElementOrExpression::Element(m) => UExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     };
     Ok(e)
 }
@@ -1014,6 +1328,11 @@ fn fold_signature<'ast, T: Field, F: ResultFolder<'ast, T>>(
 ) -> Result<DeclarationSignature<'ast>, F::Error> {
     Ok(DeclarationSignature {
         generics: s.generics,
+        type_generics: s
+            .type_generics
+            .into_iter()
+            .map(|t| f.fold_declaration_type(t))
+            .collect::<Result<_, _>>()?,
         inputs: s
             .inputs
             .into_iter()
@@ -1095,6 +1414,9 @@ pub fn fold_expression_list_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
                     .collect::<Result<_, _>>()?,
             ))
         }
+        TypedExpressionListInner::Tuple(tuple) => Ok(TypedExpressionListInner::Tuple(
+            f.fold_tuple_expression(tuple)?,
+        )),
     }
 }
 
@@ -1109,6 +1431,28 @@ pub fn fold_struct_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
     })
 }
 
+pub fn fold_tuple_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    e: TupleExpression<'ast, T>,
+) -> Result<TupleExpression<'ast, T>, F::Error> {
+    let ty = f.fold_tuple_type(e.ty)?;
+    Ok(TupleExpression {
+        inner: f.fold_tuple_expression_inner(&ty, e.inner)?,
+        ty,
+    })
+}
+
+pub fn fold_enum_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    e: EnumExpression<'ast, T>,
+) -> Result<EnumExpression<'ast, T>, F::Error> {
+    let ty = f.fold_enum_type(e.ty)?;
+    Ok(EnumExpression {
+        inner: f.fold_enum_expression_inner(&ty, e.inner)?,
+        ty,
+    })
+}
+
 pub fn fold_constant<'ast, T: Field, F: ResultFolder<'ast, T>>(
     f: &mut F,
     c: TypedConstant<'ast, T>,
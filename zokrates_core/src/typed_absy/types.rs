@@ -32,7 +32,23 @@ impl<'ast, T> IntoTypes<'ast, T> for ArrayType<'ast, T> {
     }
 }
 
-impl<'ast, T> IntoTypes<'ast, T> for UBitwidth {
+impl<'ast, T> IntoTypes<'ast, T> for TupleType<'ast, T> {
+    fn into_types(self) -> Types<'ast, T> {
+        Types {
+            inner: vec![Type::Tuple(self)],
+        }
+    }
+}
+
+impl<'ast, T> IntoTypes<'ast, T> for EnumType<'ast, T> {
+    fn into_types(self) -> Types<'ast, T> {
+        Types {
+            inner: vec![Type::Enum(self)],
+        }
+    }
+}
+
+impl<'ast, T> IntoTypes<'ast, T> for IntBitwidth {
     fn into_types(self) -> Types<'ast, T> {
         Types {
             inner: vec![Type::Uint(self)],
@@ -57,47 +73,96 @@ impl<'ast, T> Types<'ast, T> {
     }
 }
 
-#[derive(Debug, Clone, Eq, Ord)]
-pub struct GenericIdentifier<'ast> {
-    pub name: &'ast str,
+/// The identifier of a generic parameter, which only depends on its position in the declaration
+#[derive(Debug, Clone, Eq)]
+pub struct GGenericIdentifier<N> {
+    pub name: N,
     pub index: usize,
 }
 
+/// The identifier of a constant generic parameter, for example `N` in `def foo<N>(field[N] a)`
+pub type GenericIdentifier<'ast> = GGenericIdentifier<&'ast str>;
+/// The identifier of a type parameter, for example `T` in `def swap<T>(T a, T b)`
+///
+/// Type parameters appear inside types, which do not carry a lifetime, so they own their name
+pub type GenericTypeIdentifier = GGenericIdentifier<String>;
+
 impl<'ast> GenericIdentifier<'ast> {
     pub fn with_name(name: &'ast str) -> Self {
         Self { name, index: 0 }
     }
+}
+
+impl GenericTypeIdentifier {
+    pub fn with_name<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            index: 0,
+        }
+    }
+
+    /// The opaque type this type parameter stands for in the body of the function `key`, which is different from all
+    /// other types so that the values of this type can only be moved around
+    ///
+    /// No module can be found at the location of this type, so it cannot be declared by the program
+    pub fn opaque_type(&self, key: &DeclarationFunctionKey) -> ConcreteStructType {
+        ConcreteStructType::new(key.module.join(key.id), self.name.clone(), vec![], vec![])
+    }
+}
 
+impl<N> GGenericIdentifier<N> {
     pub fn index(mut self, index: usize) -> Self {
         self.index = index;
         self
     }
 }
 
-impl<'ast> PartialEq for GenericIdentifier<'ast> {
+impl<N> PartialEq for GGenericIdentifier<N> {
     fn eq(&self, other: &Self) -> bool {
         self.index == other.index
     }
 }
 
-impl<'ast> PartialOrd for GenericIdentifier<'ast> {
+impl<N> PartialOrd for GGenericIdentifier<N> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.index.partial_cmp(&other.index)
     }
 }
 
-impl<'ast> Hash for GenericIdentifier<'ast> {
+impl<N: Eq> Ord for GGenericIdentifier<N> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl<N> Hash for GGenericIdentifier<N> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.index.hash(state);
     }
 }
 
-impl<'ast> fmt::Display for GenericIdentifier<'ast> {
+impl<N: fmt::Display> fmt::Display for GGenericIdentifier<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.name)
     }
 }
 
+/// A generic parameter, which is either a constant or a type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GenericParameter<'ast> {
+    Constant(GenericIdentifier<'ast>),
+    Type(GenericTypeIdentifier),
+}
+
+impl<'ast> fmt::Display for GenericParameter<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenericParameter::Constant(id) => write!(f, "{}", id),
+            GenericParameter::Type(id) => write!(f, "{}", id),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SpecializationError;
 
@@ -176,7 +241,7 @@ impl<'ast> fmt::Display for DeclarationConstant<'ast> {
 
 impl<'ast, T> From<usize> for UExpression<'ast, T> {
     fn from(i: usize) -> Self {
-        UExpressionInner::Value(i as u128).annotate(UBitwidth::B32)
+        UExpressionInner::Value(i as u128).annotate(IntBitwidth::B32)
     }
 }
 
@@ -184,13 +249,13 @@ impl<'ast, T> From<DeclarationConstant<'ast>> for UExpression<'ast, T> {
     fn from(c: DeclarationConstant<'ast>) -> Self {
         match c {
             DeclarationConstant::Generic(i) => {
-                UExpressionInner::Identifier(i.name.into()).annotate(UBitwidth::B32)
+                UExpressionInner::Identifier(i.name.into()).annotate(IntBitwidth::B32)
             }
             DeclarationConstant::Concrete(v) => {
-                UExpressionInner::Value(v as u128).annotate(UBitwidth::B32)
+                UExpressionInner::Value(v as u128).annotate(IntBitwidth::B32)
             }
             DeclarationConstant::Constant(v) => {
-                UExpressionInner::Identifier(Identifier::from(v.id)).annotate(UBitwidth::B32)
+                UExpressionInner::Identifier(Identifier::from(v.id)).annotate(IntBitwidth::B32)
             }
         }
     }
@@ -200,7 +265,7 @@ impl<'ast, T> TryInto<usize> for UExpression<'ast, T> {
     type Error = SpecializationError;
 
     fn try_into(self) -> Result<usize, Self::Error> {
-        assert_eq!(self.bitwidth, UBitwidth::B32);
+        assert_eq!(self.bitwidth, IntBitwidth::B32);
 
         match self.into_inner() {
             UExpressionInner::Value(v) => Ok(v as usize),
@@ -349,6 +414,8 @@ pub struct GStructType<S> {
     #[serde(skip)]
     pub location: Option<StructLocation>,
     pub generics: Vec<Option<S>>,
+    #[serde(skip, default = "Vec::new")]
+    pub type_generics: Vec<GType<S>>,
     pub members: Vec<GStructMember<S>>,
 }
 
@@ -368,6 +435,12 @@ impl<'ast, S, R: PartialEq<S>> PartialEq<GStructType<S>> for GStructType<R> {
                     (None, None) => true,
                     _ => false,
                 })
+            && self.type_generics.len() == other.type_generics.len()
+            && self
+                .type_generics
+                .iter()
+                .zip(other.type_generics.iter())
+                .all(|(a, b)| a == b)
     }
 }
 
@@ -375,6 +448,7 @@ impl<S: Hash> Hash for GStructType<S> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.canonical_location.hash(state);
         self.generics.hash(state);
+        self.type_generics.hash(state);
     }
 }
 
@@ -394,6 +468,11 @@ fn try_from_g_struct_type<T: TryInto<U>, U>(
                 None => Ok(None),
             })
             .collect::<Result<_, _>>()?,
+        type_generics: t
+            .type_generics
+            .into_iter()
+            .map(try_from_g_type)
+            .collect::<Result<_, _>>()?,
         members: t
             .members
             .into_iter()
@@ -433,10 +512,16 @@ impl<S> GStructType<S> {
             canonical_location: StructLocation { module, name },
             location: None,
             generics,
+            type_generics: vec![],
             members,
         }
     }
 
+    pub fn type_generics(mut self, type_generics: Vec<GType<S>>) -> Self {
+        self.type_generics = type_generics;
+        self
+    }
+
     pub fn members_count(&self) -> usize {
         self.members.len()
     }
@@ -467,8 +552,221 @@ impl<S> IntoIterator for GStructType<S> {
     }
 }
 
+#[derive(Clone, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord, Debug)]
+pub struct GTupleType<S> {
+    pub elements: Vec<GType<S>>,
+}
+
+pub type DeclarationTupleType<'ast> = GTupleType<DeclarationConstant<'ast>>;
+pub type ConcreteTupleType = GTupleType<usize>;
+pub type TupleType<'ast, T> = GTupleType<UExpression<'ast, T>>;
+
+impl<'ast, S, R: PartialEq<S>> PartialEq<GTupleType<S>> for GTupleType<R> {
+    fn eq(&self, other: &GTupleType<S>) -> bool {
+        self.elements.len() == other.elements.len()
+            && self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for GTupleType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}{})",
+            self.elements
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            if self.elements.len() == 1 { "," } else { "" }
+        )
+    }
+}
+
+fn try_from_g_tuple_type<T: TryInto<U>, U>(
+    t: GTupleType<T>,
+) -> Result<GTupleType<U>, SpecializationError> {
+    Ok(GTupleType {
+        elements: t
+            .elements
+            .into_iter()
+            .map(try_from_g_type)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+impl<'ast, T> TryFrom<TupleType<'ast, T>> for ConcreteTupleType {
+    type Error = SpecializationError;
+
+    fn try_from(t: TupleType<'ast, T>) -> Result<Self, Self::Error> {
+        try_from_g_tuple_type(t)
+    }
+}
+
+impl<'ast, T> From<ConcreteTupleType> for TupleType<'ast, T> {
+    fn from(t: ConcreteTupleType) -> Self {
+        try_from_g_tuple_type(t).unwrap()
+    }
+}
+
+impl<'ast> From<ConcreteTupleType> for DeclarationTupleType<'ast> {
+    fn from(t: ConcreteTupleType) -> Self {
+        try_from_g_tuple_type(t).unwrap()
+    }
+}
+
+impl<S> GTupleType<S> {
+    pub fn new(elements: Vec<GType<S>>) -> Self {
+        GTupleType { elements }
+    }
+
+    pub fn elements_count(&self) -> usize {
+        self.elements.len()
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct GEnumVariant<S> {
+    #[serde(rename = "name")]
+    pub id: String,
+    #[serde(flatten)]
+    pub payload: GTupleType<S>,
+}
+
+pub type DeclarationEnumVariant<'ast> = GEnumVariant<DeclarationConstant<'ast>>;
+pub type ConcreteEnumVariant = GEnumVariant<usize>;
+pub type EnumVariant<'ast, T> = GEnumVariant<UExpression<'ast, T>>;
+
+impl<'ast, S, R: PartialEq<S>> PartialEq<GEnumVariant<S>> for GEnumVariant<R> {
+    fn eq(&self, other: &GEnumVariant<S>) -> bool {
+        self.id == other.id && self.payload == other.payload
+    }
+}
+
+impl<S> GEnumVariant<S> {
+    pub fn new(id: String, payload: Vec<GType<S>>) -> Self {
+        GEnumVariant {
+            id,
+            payload: GTupleType::new(payload),
+        }
+    }
+}
+
+fn try_from_g_enum_variant<T: TryInto<U>, U>(
+    t: GEnumVariant<T>,
+) -> Result<GEnumVariant<U>, SpecializationError> {
+    Ok(GEnumVariant {
+        id: t.id,
+        payload: try_from_g_tuple_type(t.payload)?,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct GEnumType<S> {
+    #[serde(flatten)]
+    pub canonical_location: StructLocation,
+    #[serde(skip)]
+    pub location: Option<StructLocation>,
+    pub variants: Vec<GEnumVariant<S>>,
+}
+
+pub type DeclarationEnumType<'ast> = GEnumType<DeclarationConstant<'ast>>;
+pub type ConcreteEnumType = GEnumType<usize>;
+pub type EnumType<'ast, T> = GEnumType<UExpression<'ast, T>>;
+
+impl<'ast, S, R: PartialEq<S>> PartialEq<GEnumType<S>> for GEnumType<R> {
+    fn eq(&self, other: &GEnumType<S>) -> bool {
+        self.canonical_location == other.canonical_location
+    }
+}
+
+impl<S: Hash> Hash for GEnumType<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_location.hash(state);
+    }
+}
+
+impl<S: Eq> Eq for GEnumType<S> {}
+
+impl<S> fmt::Display for GEnumType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn try_from_g_enum_type<T: TryInto<U>, U>(
+    t: GEnumType<T>,
+) -> Result<GEnumType<U>, SpecializationError> {
+    Ok(GEnumType {
+        location: t.location,
+        canonical_location: t.canonical_location,
+        variants: t
+            .variants
+            .into_iter()
+            .map(try_from_g_enum_variant)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+impl<'ast, T> TryFrom<EnumType<'ast, T>> for ConcreteEnumType {
+    type Error = SpecializationError;
+
+    fn try_from(t: EnumType<'ast, T>) -> Result<Self, Self::Error> {
+        try_from_g_enum_type(t)
+    }
+}
+
+impl<'ast, T> From<ConcreteEnumType> for EnumType<'ast, T> {
+    fn from(t: ConcreteEnumType) -> Self {
+        try_from_g_enum_type(t).unwrap()
+    }
+}
+
+impl<'ast> From<ConcreteEnumType> for DeclarationEnumType<'ast> {
+    fn from(t: ConcreteEnumType) -> Self {
+        try_from_g_enum_type(t).unwrap()
+    }
+}
+
+impl<S> GEnumType<S> {
+    pub fn new(module: PathBuf, name: String, variants: Vec<GEnumVariant<S>>) -> Self {
+        GEnumType {
+            canonical_location: StructLocation { module, name },
+            location: None,
+            variants,
+        }
+    }
+
+    /// Find a variant by name, returning its index and definition
+    pub fn variant(&self, id: &str) -> Option<(usize, &GEnumVariant<S>)> {
+        self.variants.iter().enumerate().find(|(_, v)| v.id == id)
+    }
+
+    fn location(&self) -> &StructLocation {
+        &self.location.as_ref().unwrap_or(&self.canonical_location)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.location().name
+    }
+
+    pub fn module(&self) -> &Path {
+        &self.location().module
+    }
+}
+
+/// The bitwidth and signedness of a fixed-size integer type, unsigned for `B8` to `B128` and signed for `I8` to `I64`
+///
+/// Both kinds share the representation of unsigned integers: a signed integer is stored as the unsigned integer of the
+/// same bitwidth holding its two's complement, which is why they share `Type::Uint` and the uint pipeline. Operations
+/// on the representation, such as `to_usize` and `max_value`, are the same for both kinds. Operations on the value,
+/// such as division, right shifts, comparisons or conversions to field elements, must check `is_signed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
-pub enum UBitwidth {
+pub enum IntBitwidth {
     #[serde(rename = "8")]
     B8 = 8,
     #[serde(rename = "16")]
@@ -477,27 +775,75 @@ pub enum UBitwidth {
     B32 = 32,
     #[serde(rename = "64")]
     B64 = 64,
-}
+    #[serde(rename = "128")]
+    B128 = 128,
+    #[serde(rename = "i8")]
+    I8,
+    #[serde(rename = "i16")]
+    I16,
+    #[serde(rename = "i32")]
+    I32,
+    #[serde(rename = "i64")]
+    I64,
+}
+
+impl IntBitwidth {
+    pub fn signed(b: usize) -> Self {
+        match b {
+            8 => IntBitwidth::I8,
+            16 => IntBitwidth::I16,
+            32 => IntBitwidth::I32,
+            64 => IntBitwidth::I64,
+            _ => unreachable!(),
+        }
+    }
 
-impl UBitwidth {
     pub fn to_usize(self) -> usize {
-        self as u32 as usize
+        match self {
+            IntBitwidth::B8 | IntBitwidth::I8 => 8,
+            IntBitwidth::B16 | IntBitwidth::I16 => 16,
+            IntBitwidth::B32 | IntBitwidth::I32 => 32,
+            IntBitwidth::B64 | IntBitwidth::I64 => 64,
+            IntBitwidth::B128 => 128,
+        }
+    }
+
+    /// The largest representation of this bitwidth, `2 ** bitwidth - 1`, which is `-1` for signed integers
+    pub fn max_value(self) -> u128 {
+        u128::MAX >> (128 - self.to_usize())
+    }
+
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            IntBitwidth::I8 | IntBitwidth::I16 | IntBitwidth::I32 | IntBitwidth::I64
+        )
+    }
+
+    /// The name of the integer type of this bitwidth, for example `u32` or `i8`
+    pub fn type_name(self) -> String {
+        format!(
+            "{}{}",
+            if self.is_signed() { "i" } else { "u" },
+            self.to_usize()
+        )
     }
 }
 
-impl From<usize> for UBitwidth {
+impl From<usize> for IntBitwidth {
     fn from(b: usize) -> Self {
         match b {
-            8 => UBitwidth::B8,
-            16 => UBitwidth::B16,
-            32 => UBitwidth::B32,
-            64 => UBitwidth::B64,
+            8 => IntBitwidth::B8,
+            16 => IntBitwidth::B16,
+            32 => IntBitwidth::B32,
+            64 => IntBitwidth::B64,
+            128 => IntBitwidth::B128,
             _ => unreachable!(),
         }
     }
 }
 
-impl fmt::Display for UBitwidth {
+impl fmt::Display for IntBitwidth {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.to_usize())
     }
@@ -509,8 +855,11 @@ pub enum GType<S> {
     Boolean,
     Array(GArrayType<S>),
     Struct(GStructType<S>),
-    Uint(UBitwidth),
+    Tuple(GTupleType<S>),
+    Enum(GEnumType<S>),
+    Uint(IntBitwidth),
     Int,
+    Generic(GenericTypeIdentifier),
 }
 
 impl<Z: Serialize> Serialize for GType<Z> {
@@ -535,15 +884,28 @@ impl<Z: Serialize> Serialize for GType<Z> {
                 map.serialize_entry("components", struct_type)?;
                 map.end()
             }
-            GType::Uint(width) => s.serialize_newtype_variant(
-                "Type",
-                4,
-                "type",
-                format!("u{}", width.to_usize()).as_str(),
-            ),
+            GType::Tuple(tuple_type) => {
+                let mut map = s.serialize_map(Some(2))?;
+                map.serialize_entry("type", "tuple")?;
+                map.serialize_entry("components", tuple_type)?;
+                map.end()
+            }
+            GType::Enum(enum_type) => {
+                let mut map = s.serialize_map(Some(2))?;
+                map.serialize_entry("type", "enum")?;
+                map.serialize_entry("components", enum_type)?;
+                map.end()
+            }
+            GType::Uint(width) => {
+                s.serialize_newtype_variant("Type", 4, "type", width.type_name().as_str())
+            }
             GType::Int => Err(S::Error::custom(
                 "Cannot serialize Int type as it's not allowed in function signatures".to_string(),
             )),
+            GType::Generic(id) => Err(S::Error::custom(format!(
+                "Cannot serialize type parameter {} as it's not allowed in the main function signature",
+                id
+            ))),
         }
     }
 }
@@ -558,6 +920,8 @@ impl<'de, S: Deserialize<'de>> Deserialize<'de> for GType<S> {
         enum Components<S> {
             Array(GArrayType<S>),
             Struct(GStructType<S>),
+            Tuple(GTupleType<S>),
+            Enum(GEnumType<S>),
         }
 
         #[derive(Deserialize)]
@@ -600,10 +964,33 @@ impl<'de, S: Deserialize<'de>> Deserialize<'de> for GType<S> {
                     _ => Err(D::Error::custom("invalid `components` variant".to_string())),
                 }
             }
-            "u8" => strict_type(mapping, GType::Uint(UBitwidth::B8)),
-            "u16" => strict_type(mapping, GType::Uint(UBitwidth::B16)),
-            "u32" => strict_type(mapping, GType::Uint(UBitwidth::B32)),
-            "u64" => strict_type(mapping, GType::Uint(UBitwidth::B64)),
+            "tuple" => {
+                let components = mapping
+                    .components
+                    .ok_or_else(|| D::Error::custom("missing `components` field".to_string()))?;
+                match components {
+                    Components::Tuple(tuple_type) => Ok(GType::Tuple(tuple_type)),
+                    _ => Err(D::Error::custom("invalid `components` variant".to_string())),
+                }
+            }
+            "enum" => {
+                let components = mapping
+                    .components
+                    .ok_or_else(|| D::Error::custom("missing `components` field".to_string()))?;
+                match components {
+                    Components::Enum(enum_type) => Ok(GType::Enum(enum_type)),
+                    _ => Err(D::Error::custom("invalid `components` variant".to_string())),
+                }
+            }
+            "u8" => strict_type(mapping, GType::Uint(IntBitwidth::B8)),
+            "u16" => strict_type(mapping, GType::Uint(IntBitwidth::B16)),
+            "u32" => strict_type(mapping, GType::Uint(IntBitwidth::B32)),
+            "u64" => strict_type(mapping, GType::Uint(IntBitwidth::B64)),
+            "u128" => strict_type(mapping, GType::Uint(IntBitwidth::B128)),
+            "i8" => strict_type(mapping, GType::Uint(IntBitwidth::I8)),
+            "i16" => strict_type(mapping, GType::Uint(IntBitwidth::I16)),
+            "i32" => strict_type(mapping, GType::Uint(IntBitwidth::I32)),
+            "i64" => strict_type(mapping, GType::Uint(IntBitwidth::I64)),
             t => Err(D::Error::custom(format!("invalid type `{}`", t))),
         }
     }
@@ -620,8 +1007,11 @@ impl<'ast, S, R: PartialEq<S>> PartialEq<GType<S>> for GType<R> {
         match (self, other) {
             (Array(l), Array(r)) => l == r,
             (Struct(l), Struct(r)) => l == r,
+            (Tuple(l), Tuple(r)) => l == r,
+            (Enum(l), Enum(r)) => l == r,
             (FieldElement, FieldElement) | (Boolean, Boolean) => true,
             (Uint(l), Uint(r)) => l == r,
+            (Generic(l), Generic(r)) => l == r,
             _ => false,
         }
     }
@@ -635,6 +1025,9 @@ pub fn try_from_g_type<T: TryInto<U>, U>(t: GType<T>) -> Result<GType<U>, Specia
         GType::Uint(bitwidth) => Ok(GType::Uint(bitwidth)),
         GType::Array(array_type) => Ok(GType::Array(try_from_g_array_type(array_type)?)),
         GType::Struct(struct_type) => Ok(GType::Struct(try_from_g_struct_type(struct_type)?)),
+        GType::Tuple(tuple_type) => Ok(GType::Tuple(try_from_g_tuple_type(tuple_type)?)),
+        GType::Enum(enum_type) => Ok(GType::Enum(try_from_g_enum_type(enum_type)?)),
+        GType::Generic(id) => Ok(GType::Generic(id)),
     }
 }
 
@@ -690,10 +1083,13 @@ impl<S: fmt::Display> fmt::Display for GType<S> {
         match self {
             GType::FieldElement => write!(f, "field"),
             GType::Boolean => write!(f, "bool"),
-            GType::Uint(ref bitwidth) => write!(f, "u{}", bitwidth),
+            GType::Uint(ref bitwidth) => write!(f, "{}", bitwidth.type_name()),
             GType::Int => write!(f, "{{integer}}"),
             GType::Array(ref array_type) => write!(f, "{}", array_type),
             GType::Struct(ref struct_type) => write!(f, "{}", struct_type),
+            GType::Tuple(ref tuple_type) => write!(f, "{}", tuple_type),
+            GType::Enum(ref enum_type) => write!(f, "{}", enum_type),
+            GType::Generic(ref id) => write!(f, "{}", id),
         }
     }
 }
@@ -704,7 +1100,7 @@ impl<S: fmt::Display> fmt::Display for GStructType<S> {
             f,
             "{}{}",
             self.name(),
-            if !self.generics.is_empty() {
+            if !self.generics.is_empty() || !self.type_generics.is_empty() {
                 format!(
                     "<{}>",
                     self.generics
@@ -713,6 +1109,7 @@ impl<S: fmt::Display> fmt::Display for GStructType<S> {
                             .as_ref()
                             .map(|g| g.to_string())
                             .unwrap_or_else(|| '_'.to_string()))
+                        .chain(self.type_generics.iter().map(|t| t.to_string()))
                         .collect::<Vec<_>>()
                         .join(", ")
                 )
@@ -732,7 +1129,15 @@ impl<S> GType<S> {
         GType::Struct(struct_ty.into())
     }
 
-    pub fn uint<W: Into<UBitwidth>>(b: W) -> Self {
+    pub fn tuple<U: Into<GTupleType<S>>>(tuple_ty: U) -> Self {
+        GType::Tuple(tuple_ty.into())
+    }
+
+    pub fn enumeration<U: Into<GEnumType<S>>>(enum_ty: U) -> Self {
+        GType::Enum(enum_ty.into())
+    }
+
+    pub fn uint<W: Into<IntBitwidth>>(b: W) -> Self {
         GType::Uint(b.into())
     }
 }
@@ -745,6 +1150,8 @@ impl<'ast, T: fmt::Display + PartialEq + fmt::Debug> Type<'ast, T> {
             true
         } else {
             match (self, other) {
+                // any type can be given to a type parameter, consistency is checked when inferring its value
+                (_, Generic(..)) => true,
                 (Int, FieldElement) | (Int, Uint(..)) => true,
                 (Array(l), Array(r)) => match l.ty.can_be_specialized_to(&r.ty) {
                     true => {
@@ -766,6 +1173,14 @@ impl<'ast, T: fmt::Display + PartialEq + fmt::Debug> Type<'ast, T> {
                             .zip(r.members.iter())
                             .all(|(m, d_m)| m.ty.can_be_specialized_to(&*d_m.ty))
                 }
+                (Tuple(l), Tuple(r)) => {
+                    l.elements.len() == r.elements.len()
+                        && l.elements
+                            .iter()
+                            .zip(r.elements.iter())
+                            .all(|(e, d_e)| e.can_be_specialized_to(d_e))
+                }
+                (Enum(l), Enum(r)) => l.canonical_location == r.canonical_location,
                 _ => false,
             }
         }
@@ -776,9 +1191,9 @@ impl ConcreteType {
     fn to_slug(&self) -> String {
         match self {
             GType::FieldElement => String::from("f"),
-            GType::Int => unreachable!(),
+            GType::Int | GType::Generic(..) => unreachable!(),
             GType::Boolean => String::from("b"),
-            GType::Uint(bitwidth) => format!("u{}", bitwidth),
+            GType::Uint(bitwidth) => bitwidth.type_name(),
             GType::Array(array_type) => format!("{}[{}]", array_type.ty.to_slug(), array_type.size),
             GType::Struct(struct_type) => format!(
                 "{{{}}}",
@@ -788,6 +1203,33 @@ impl ConcreteType {
                     .collect::<Vec<_>>()
                     .join(",")
             ),
+            GType::Tuple(tuple_type) => format!(
+                "({})",
+                tuple_type
+                    .elements
+                    .iter()
+                    .map(|e| e.to_slug())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            GType::Enum(enum_type) => format!(
+                "<{}>",
+                enum_type
+                    .variants
+                    .iter()
+                    .map(|v| format!(
+                        "{}({})",
+                        v.id,
+                        v.payload
+                            .elements
+                            .iter()
+                            .map(|e| e.to_slug())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("|")
+            ),
         }
     }
 }
@@ -800,11 +1242,25 @@ impl ConcreteType {
             GType::Boolean => 1,
             GType::Uint(_) => 1,
             GType::Array(array_type) => array_type.size * array_type.ty.get_primitive_count(),
-            GType::Int => unreachable!(),
+            GType::Int | GType::Generic(..) => unreachable!(),
             GType::Struct(struct_type) => struct_type
                 .iter()
                 .map(|member| member.ty.get_primitive_count())
                 .sum(),
+            GType::Tuple(tuple_type) => tuple_type
+                .elements
+                .iter()
+                .map(|e| e.get_primitive_count())
+                .sum(),
+            // the tag followed by the payloads of all variants
+            GType::Enum(enum_type) => {
+                1 + enum_type
+                    .variants
+                    .iter()
+                    .flat_map(|v| v.payload.elements.iter())
+                    .map(|e| e.get_primitive_count())
+                    .sum::<usize>()
+            }
         }
     }
 }
@@ -829,14 +1285,20 @@ impl<'ast, S: fmt::Display> fmt::Display for GFunctionKey<'ast, S> {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-pub struct GGenericsAssignment<'ast, S>(pub BTreeMap<GenericIdentifier<'ast>, S>);
+pub struct GGenericsAssignment<'ast, S> {
+    pub constants: BTreeMap<GenericIdentifier<'ast>, S>,
+    pub types: BTreeMap<GenericTypeIdentifier, GType<S>>,
+}
 
 pub type ConcreteGenericsAssignment<'ast> = GGenericsAssignment<'ast, usize>;
 pub type GenericsAssignment<'ast, T> = GGenericsAssignment<'ast, UExpression<'ast, T>>;
 
 impl<'ast, S> Default for GGenericsAssignment<'ast, S> {
     fn default() -> Self {
-        GGenericsAssignment(BTreeMap::new())
+        GGenericsAssignment {
+            constants: BTreeMap::new(),
+            types: BTreeMap::new(),
+        }
     }
 }
 
@@ -845,9 +1307,10 @@ impl<'ast, S: fmt::Display> fmt::Display for GGenericsAssignment<'ast, S> {
         write!(
             f,
             "{}",
-            self.0
+            self.constants
                 .iter()
                 .map(|(k, v)| format!("{}: {}", k, v))
+                .chain(self.types.iter().map(|(k, v)| format!("{}: {}", k, v)))
                 .collect::<Vec<_>>()
                 .join(", ")
         )
@@ -934,24 +1397,43 @@ use std::collections::btree_map::Entry;
 pub fn check_type<'ast, S: Clone + PartialEq + PartialEq<usize>>(
     decl_ty: &DeclarationType<'ast>,
     ty: &GType<S>,
-    constants: &mut GGenericsAssignment<'ast, S>,
+    generics: &mut GGenericsAssignment<'ast, S>,
 ) -> bool {
     match (decl_ty, ty) {
+        // if the declared type is a type parameter, we insert into the map, or check if the concrete type
+        // matches if this parameter is already in the map
+        (DeclarationType::Generic(id), ty) => match generics.types.entry(id.clone()) {
+            Entry::Occupied(mut e) => match (e.get(), ty) {
+                // an integer literal takes the type of the other values given to the same parameter
+                (GType::Int, GType::FieldElement) | (GType::Int, GType::Uint(..)) => {
+                    e.insert(ty.clone());
+                    true
+                }
+                (GType::FieldElement, GType::Int) | (GType::Uint(..), GType::Int) => true,
+                (t, ty) => t == ty,
+            },
+            Entry::Vacant(e) => {
+                e.insert(ty.clone());
+                true
+            }
+        },
         (DeclarationType::Array(t0), GType::Array(t1)) => {
             let s1 = t1.size.clone();
 
             // both the inner type and the size must match
-            check_type(&t0.ty, &t1.ty, constants)
+            check_type(&t0.ty, &t1.ty, generics)
                 && match &t0.size {
                     // if the declared size is an identifier, we insert into the map, or check if the concrete size
                     // matches if this identifier is already in the map
-                    DeclarationConstant::Generic(id) => match constants.0.entry(id.clone()) {
-                        Entry::Occupied(e) => *e.get() == s1,
-                        Entry::Vacant(e) => {
-                            e.insert(s1);
-                            true
+                    DeclarationConstant::Generic(id) => {
+                        match generics.constants.entry(id.clone()) {
+                            Entry::Occupied(e) => *e.get() == s1,
+                            Entry::Vacant(e) => {
+                                e.insert(s1);
+                                true
+                            }
                         }
-                    },
+                    }
                     DeclarationConstant::Concrete(s0) => s1 == *s0 as usize,
                     // in the case of a constant, we do not know the value yet, so we optimistically assume it's correct
                     // if it does not match, it will be caught during inlining
@@ -963,6 +1445,23 @@ pub fn check_type<'ast, S: Clone + PartialEq + PartialEq<usize>>(
         (DeclarationType::Uint(b0), GType::Uint(b1)) => b0 == b1,
         (DeclarationType::Struct(s0), GType::Struct(s1)) => {
             s0.canonical_location == s1.canonical_location
+                && s0.type_generics.len() == s1.type_generics.len()
+                && s0
+                    .type_generics
+                    .iter()
+                    .zip(s1.type_generics.iter())
+                    .all(|(d_t, t)| check_type(d_t, t, generics))
+        }
+        (DeclarationType::Tuple(t0), GType::Tuple(t1)) => {
+            t0.elements.len() == t1.elements.len()
+                && t0
+                    .elements
+                    .iter()
+                    .zip(t1.elements.iter())
+                    .all(|(d_e, e)| check_type(d_e, e, generics))
+        }
+        (DeclarationType::Enum(e0), GType::Enum(e1)) => {
+            e0.canonical_location == e1.canonical_location
         }
         _ => false,
     }
@@ -991,15 +1490,24 @@ pub fn specialize_declaration_type<
 >(
     decl_ty: DeclarationType<'ast>,
     generics: &GGenericsAssignment<'ast, S>,
-) -> Result<GType<S>, GenericIdentifier<'ast>> {
+) -> Result<GType<S>, GenericParameter<'ast>> {
     Ok(match decl_ty {
         DeclarationType::Int => unreachable!(),
+        DeclarationType::Generic(id) => generics
+            .types
+            .get(&id)
+            .cloned()
+            .ok_or(GenericParameter::Type(id))?,
         DeclarationType::Array(t0) => {
             // let s1 = t1.size.clone();
 
             let ty = box specialize_declaration_type(*t0.ty, &generics)?;
             let size = match t0.size {
-                DeclarationConstant::Generic(s) => generics.0.get(&s).cloned().ok_or(s),
+                DeclarationConstant::Generic(s) => generics
+                    .constants
+                    .get(&s)
+                    .cloned()
+                    .ok_or(GenericParameter::Constant(s)),
                 DeclarationConstant::Concrete(s) => Ok(s.into()),
                 DeclarationConstant::Constant(c) => Ok(c.into()),
             }?;
@@ -1024,22 +1532,50 @@ pub fn specialize_declaration_type<
                 .into_iter()
                 .map(|g| match g {
                     Some(constant) => match constant {
-                        DeclarationConstant::Generic(s) => {
-                            generics.0.get(&s).cloned().ok_or(s).map(Some)
-                        }
+                        DeclarationConstant::Generic(s) => generics
+                            .constants
+                            .get(&s)
+                            .cloned()
+                            .ok_or(GenericParameter::Constant(s))
+                            .map(Some),
                         DeclarationConstant::Concrete(s) => Ok(Some(s.into())),
-                        DeclarationConstant::Constant(..) => {
-                            unreachable!(
-                                "identifiers should have been removed in constant inlining"
-                            )
-                        }
+                        DeclarationConstant::Constant(c) => Ok(Some(c.into())),
                     },
                     _ => Ok(None),
                 })
                 .collect::<Result<_, _>>()?,
+            type_generics: s0
+                .type_generics
+                .into_iter()
+                .map(|t| specialize_declaration_type(t, generics))
+                .collect::<Result<_, _>>()?,
             canonical_location: s0.canonical_location,
             location: s0.location,
         }),
+        DeclarationType::Tuple(t0) => GType::Tuple(GTupleType {
+            elements: t0
+                .elements
+                .into_iter()
+                .map(|e| specialize_declaration_type(e, generics))
+                .collect::<Result<_, _>>()?,
+        }),
+        DeclarationType::Enum(e0) => GType::Enum(GEnumType {
+            variants: e0
+                .variants
+                .into_iter()
+                .map(|v| {
+                    let id = v.id;
+                    v.payload
+                        .elements
+                        .into_iter()
+                        .map(|e| specialize_declaration_type(e, generics))
+                        .collect::<Result<_, _>>()
+                        .map(|payload| GEnumVariant::new(id, payload))
+                })
+                .collect::<Result<_, _>>()?,
+            canonical_location: e0.canonical_location,
+            location: e0.location,
+        }),
     })
 }
 
@@ -1054,22 +1590,29 @@ pub mod signature {
     #[derive(Clone, Serialize, Deserialize, Eq, Debug)]
     pub struct GSignature<S> {
         pub generics: Vec<Option<S>>,
+        /// The type parameters of a function template, or the type arguments of one of its instances
+        #[serde(skip)]
+        pub type_generics: Vec<GType<S>>,
         pub inputs: Vec<GType<S>>,
         pub outputs: Vec<GType<S>>,
     }
 
     impl<S: PartialEq> PartialEq for GSignature<S> {
         fn eq(&self, other: &Self) -> bool {
-            self.inputs == other.inputs && self.outputs == other.outputs
+            self.type_generics == other.type_generics
+                && self.inputs == other.inputs
+                && self.outputs == other.outputs
         }
     }
 
     impl<S: PartialOrd> PartialOrd for GSignature<S> {
         fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            self.inputs
-                .partial_cmp(&other.inputs)
-                .map(|c| self.outputs.partial_cmp(&other.outputs).map(|d| c.then(d)))
-                .unwrap()
+            Some(
+                self.type_generics
+                    .partial_cmp(&other.type_generics)?
+                    .then(self.inputs.partial_cmp(&other.inputs)?)
+                    .then(self.outputs.partial_cmp(&other.outputs)?),
+            )
         }
     }
 
@@ -1081,6 +1624,7 @@ pub mod signature {
 
     impl<S: Hash> Hash for GSignature<S> {
         fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            self.type_generics.hash(state);
             self.inputs.hash(state);
             self.outputs.hash(state);
         }
@@ -1090,6 +1634,7 @@ pub mod signature {
         fn default() -> Self {
             GSignature {
                 generics: vec![],
+                type_generics: vec![],
                 inputs: vec![],
                 outputs: vec![],
             }
@@ -1132,7 +1677,7 @@ pub mod signature {
                 _ => unreachable!(),
             });
 
-            constants.0.extend(
+            constants.constants.extend(
                 decl_generics
                     .zip(values.into_iter())
                     .filter_map(|(g, v)| v.map(|v| (g, v as usize))),
@@ -1145,7 +1690,9 @@ pub mod signature {
                 .zip(signature.inputs.iter().chain(signature.outputs.iter()))
                 .all(|(decl_ty, ty)| check_type(decl_ty, ty, &mut constants));
 
-            if constants.0.len() != self.generics.len() {
+            if constants.constants.len() != self.generics.len()
+                || constants.types.len() != self.type_generics.len()
+            {
                 return Err(SpecializationError);
             }
 
@@ -1159,13 +1706,13 @@ pub mod signature {
             &self,
             generics: Vec<Option<UExpression<'ast, T>>>,
             inputs: Vec<Type<'ast, T>>,
-        ) -> Result<Vec<Type<'ast, T>>, GenericIdentifier<'ast>> {
+        ) -> Result<Vec<Type<'ast, T>>, GenericParameter<'ast>> {
             // we keep track of the value of constants in a map, as a given constant can only have one value
             let mut constants = GenericsAssignment::default();
 
             // initialise the map with the explicitly provided generics
             constants
-                .0
+                .constants
                 .extend(self.generics.iter().zip(generics).filter_map(|(g, v)| {
                     // only add to the map when there's indeed a generic value being provided
                     v.map(|v| {
@@ -1207,6 +1754,11 @@ pub mod signature {
                     None => Ok(None),
                 })
                 .collect::<Result<_, _>>()?,
+            type_generics: t
+                .type_generics
+                .into_iter()
+                .map(try_from_g_type)
+                .collect::<Result<_, _>>()?,
             inputs: t
                 .inputs
                 .into_iter()
@@ -1242,7 +1794,7 @@ pub mod signature {
 
     impl<S: fmt::Display> fmt::Display for GSignature<S> {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            if !self.generics.is_empty() {
+            if !self.generics.is_empty() || !self.type_generics.is_empty() {
                 write!(
                     f,
                     "<{}>",
@@ -1252,6 +1804,7 @@ pub mod signature {
                             .as_ref()
                             .map(|g| g.to_string())
                             .unwrap_or_else(|| '_'.to_string()))
+                        .chain(self.type_generics.iter().map(|t| t.to_string()))
                         .collect::<Vec<_>>()
                         .join(", ")
                 )?;
@@ -1292,6 +1845,11 @@ pub mod signature {
             self
         }
 
+        pub fn type_generics(mut self, type_generics: Vec<GType<S>>) -> Self {
+            self.type_generics = type_generics;
+            self
+        }
+
         pub fn inputs(mut self, inputs: Vec<GType<S>>) -> Self {
             self.inputs = inputs;
             self
@@ -1,4 +1,4 @@
-use crate::typed_absy::types::UBitwidth;
+use crate::typed_absy::types::IntBitwidth;
 use crate::typed_absy::*;
 use std::ops::{Add, Div, Mul, Neg, Not, Rem, Sub};
 use zokrates_field::Field;
@@ -104,21 +104,47 @@ impl<'ast, T: Field> UExpression<'ast, T> {
 
     pub fn left_shift(self, by: UExpression<'ast, T>) -> UExpression<'ast, T> {
         let bitwidth = self.bitwidth;
-        assert_eq!(by.bitwidth, UBitwidth::B32);
+        assert_eq!(by.bitwidth, IntBitwidth::B32);
         UExpressionInner::LeftShift(box self, box by).annotate(bitwidth)
     }
 
     pub fn right_shift(self, by: UExpression<'ast, T>) -> UExpression<'ast, T> {
         let bitwidth = self.bitwidth;
-        assert_eq!(by.bitwidth, UBitwidth::B32);
+        assert_eq!(by.bitwidth, IntBitwidth::B32);
         UExpressionInner::RightShift(box self, box by).annotate(bitwidth)
     }
 
+    pub fn left_rotate(self, by: UExpression<'ast, T>) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        assert_eq!(by.bitwidth, IntBitwidth::B32);
+        UExpressionInner::LeftRotate(box self, box by).annotate(bitwidth)
+    }
+
+    pub fn right_rotate(self, by: UExpression<'ast, T>) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        assert_eq!(by.bitwidth, IntBitwidth::B32);
+        UExpressionInner::RightRotate(box self, box by).annotate(bitwidth)
+    }
+
     pub fn floor_sub(self, other: Self) -> UExpression<'ast, T> {
         let bitwidth = self.bitwidth;
         assert_eq!(bitwidth, other.bitwidth);
         UExpressionInner::FloorSub(box self, box other).annotate(bitwidth)
     }
+
+    /// The bits `from..to` of this value, most significant bit first, as an unsigned integer of width `to - from`
+    pub fn slice(self, from: u32, to: u32) -> UExpression<'ast, T> {
+        assert!(!self.bitwidth.is_signed());
+        assert!(to as usize <= self.bitwidth.to_usize());
+        UExpressionInner::Slice(box self, from, to).annotate((to - from) as usize)
+    }
+
+    /// The bit at `index` of this value, most significant bit first
+    pub fn bit(self, index: UExpression<'ast, T>) -> BooleanExpression<'ast, T> {
+        assert!(!self.bitwidth.is_signed());
+        assert_eq!(index.bitwidth, IntBitwidth::B32);
+        BooleanExpression::UintBit(box self, box index)
+    }
 }
 
 impl<'ast, T: Field> From<u128> for UExpressionInner<'ast, T> {
@@ -141,26 +167,26 @@ pub struct UMetadata {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UExpression<'ast, T> {
-    pub bitwidth: UBitwidth,
+    pub bitwidth: IntBitwidth,
     pub metadata: Option<UMetadata>,
     pub inner: UExpressionInner<'ast, T>,
 }
 
 impl<'ast, T> From<u32> for UExpression<'ast, T> {
     fn from(u: u32) -> Self {
-        UExpressionInner::Value(u as u128).annotate(UBitwidth::B32)
+        UExpressionInner::Value(u as u128).annotate(IntBitwidth::B32)
     }
 }
 
 impl<'ast, T> From<u16> for UExpression<'ast, T> {
     fn from(u: u16) -> Self {
-        UExpressionInner::Value(u as u128).annotate(UBitwidth::B16)
+        UExpressionInner::Value(u as u128).annotate(IntBitwidth::B16)
     }
 }
 
 impl<'ast, T> From<u8> for UExpression<'ast, T> {
     fn from(u: u8) -> Self {
-        UExpressionInner::Value(u as u128).annotate(UBitwidth::B8)
+        UExpressionInner::Value(u as u128).annotate(IntBitwidth::B8)
     }
 }
 
@@ -193,13 +219,17 @@ pub enum UExpressionInner<'ast, T> {
     FunctionCall(FunctionCallExpression<'ast, T, UExpression<'ast, T>>),
     LeftShift(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     RightShift(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    LeftRotate(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    RightRotate(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    Slice(Box<UExpression<'ast, T>>, u32, u32),
     IfElse(IfElseExpression<'ast, T, UExpression<'ast, T>>),
     Member(MemberExpression<'ast, T, UExpression<'ast, T>>),
     Select(SelectExpression<'ast, T, UExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, UExpression<'ast, T>>),
 }
 
 impl<'ast, T> UExpressionInner<'ast, T> {
-    pub fn annotate<W: Into<UBitwidth>>(self, bitwidth: W) -> UExpression<'ast, T> {
+    pub fn annotate<W: Into<IntBitwidth>>(self, bitwidth: W) -> UExpression<'ast, T> {
         UExpression {
             metadata: None,
             bitwidth: bitwidth.into(),
@@ -221,8 +251,22 @@ pub fn bitwidth(a: u128) -> Bitwidth {
     (128 - a.leading_zeros()) as Bitwidth
 }
 
+/// Interpret `v`, the representation of an integer of type `bitwidth`, taking its sign into account
+pub fn to_signed(v: u128, bitwidth: IntBitwidth) -> i128 {
+    let bits = bitwidth.to_usize() as u32;
+    match bitwidth.is_signed() && v >= 2u128.pow(bits - 1) {
+        true => v as i128 - 2i128.pow(bits),
+        false => v as i128,
+    }
+}
+
+/// Get the representation of `v` as an integer of type `bitwidth`, wrapping around on overflow
+pub fn from_signed(v: i128, bitwidth: IntBitwidth) -> u128 {
+    (v as u128) & bitwidth.max_value()
+}
+
 impl<'ast, T> UExpression<'ast, T> {
-    pub fn bitwidth(&self) -> UBitwidth {
+    pub fn bitwidth(&self) -> IntBitwidth {
         self.bitwidth
     }
 
@@ -1,4 +1,4 @@
-use crate::typed_absy::types::{DeclarationConstant, GStructType, UBitwidth};
+use crate::typed_absy::types::{DeclarationConstant, GStructType, IntBitwidth};
 use crate::typed_absy::types::{GType, SpecializationError};
 use crate::typed_absy::Identifier;
 use crate::typed_absy::UExpression;
@@ -50,7 +50,7 @@ impl<'ast, S: Clone> GVariable<'ast, S> {
         Self::with_id_and_type(id, GType::Boolean)
     }
 
-    pub fn uint<I: Into<Identifier<'ast>>, W: Into<UBitwidth>>(id: I, bitwidth: W) -> Self {
+    pub fn uint<I: Into<Identifier<'ast>>, W: Into<IntBitwidth>>(id: I, bitwidth: W) -> Self {
         Self::with_id_and_type(id, GType::uint(bitwidth))
     }
 
@@ -12,7 +12,9 @@ impl From<typed_absy::types::ConcreteSignature> for zir::types::Signature {
 
 fn from_type(t: typed_absy::types::ConcreteType) -> Vec<zir::types::Type> {
     match t {
-        typed_absy::types::ConcreteType::Int => unreachable!(),
+        typed_absy::types::ConcreteType::Int | typed_absy::types::ConcreteType::Generic(..) => {
+            unreachable!()
+        }
         typed_absy::types::ConcreteType::FieldElement => vec![zir::Type::FieldElement],
         typed_absy::types::ConcreteType::Boolean => vec![zir::Type::Boolean],
         typed_absy::types::ConcreteType::Uint(bitwidth) => {
@@ -26,5 +28,19 @@ fn from_type(t: typed_absy::types::ConcreteType) -> Vec<zir::types::Type> {
             .into_iter()
             .flat_map(|struct_member| from_type(*struct_member.ty))
             .collect(),
+        typed_absy::types::ConcreteType::Tuple(tuple_type) => tuple_type
+            .elements
+            .into_iter()
+            .flat_map(from_type)
+            .collect(),
+        typed_absy::types::ConcreteType::Enum(enum_type) => {
+            std::iter::once(zir::Type::FieldElement)
+                .chain(
+                    enum_type.variants.into_iter().flat_map(|variant| {
+                        variant.payload.elements.into_iter().flat_map(from_type)
+                    }),
+                )
+                .collect()
+        }
     }
 }
@@ -13,6 +13,9 @@ pub enum SourceIdentifier<'ast> {
     Basic(CoreIdentifier<'ast>),
     Select(Box<SourceIdentifier<'ast>>, usize),
     Member(Box<SourceIdentifier<'ast>>, MemberId),
+    Element(Box<SourceIdentifier<'ast>>, u32),
+    Tag(Box<SourceIdentifier<'ast>>),
+    Payload(Box<SourceIdentifier<'ast>>, u32),
 }
 
 impl<'ast> fmt::Display for SourceIdentifier<'ast> {
@@ -21,6 +24,9 @@ impl<'ast> fmt::Display for SourceIdentifier<'ast> {
             SourceIdentifier::Basic(i) => write!(f, "{}", i),
             SourceIdentifier::Select(box i, index) => write!(f, "{}~{}", i, index),
             SourceIdentifier::Member(box i, m) => write!(f, "{}.{}", i, m),
+            SourceIdentifier::Element(box i, index) => write!(f, "{}.{}", i, index),
+            SourceIdentifier::Tag(box i) => write!(f, "{}::tag", i),
+            SourceIdentifier::Payload(box i, variant) => write!(f, "{}::{}", i, variant),
         }
     }
 }
@@ -13,6 +13,7 @@ pub use self::variable::Variable;
 pub use crate::zir::uint::{ShouldReduce, UExpression, UExpressionInner, UMetadata};
 
 use crate::embed::FlatEmbed;
+use crate::source_map::{CallStack, SourceLocation};
 use crate::zir::types::Signature;
 use std::convert::TryFrom;
 use std::fmt;
@@ -86,6 +87,31 @@ impl<'ast, T: fmt::Debug> fmt::Debug for ZirFunction<'ast, T> {
 
 pub type ZirAssignee<'ast> = Variable<'ast>;
 
+/// The reason for an assertion, reported when the assertion fails at runtime
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+pub enum RuntimeError {
+    SourceAssertion(String),
+    SelectRangeCheck,
+    EnumTagCheck,
+}
+
+impl RuntimeError {
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        RuntimeError::SourceAssertion(String::default())
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuntimeError::SourceAssertion(message) => write!(f, "{}", message),
+            RuntimeError::SelectRangeCheck => write!(f, "Range check on array access"),
+            RuntimeError::EnumTagCheck => write!(f, "Range check on enum tag"),
+        }
+    }
+}
+
 /// A statement in a `ZirFunction`
 #[derive(Clone, PartialEq, Hash, Eq)]
 pub enum ZirStatement<'ast, T> {
@@ -96,8 +122,9 @@ pub enum ZirStatement<'ast, T> {
         Vec<ZirStatement<'ast, T>>,
         Vec<ZirStatement<'ast, T>>,
     ),
-    Assertion(BooleanExpression<'ast, T>),
+    Assertion(BooleanExpression<'ast, T>, RuntimeError),
     MultipleDefinition(Vec<ZirAssignee<'ast>>, ZirExpressionList<'ast, T>),
+    Location(SourceLocation, CallStack),
 }
 
 impl<'ast, T: fmt::Debug> fmt::Debug for ZirStatement<'ast, T> {
@@ -119,10 +146,15 @@ impl<'ast, T: fmt::Debug> fmt::Debug for ZirStatement<'ast, T> {
             ZirStatement::IfElse(ref condition, ref lhs, ref rhs) => {
                 write!(f, "IfElse({:?}, {:?}, {:?})", condition, lhs, rhs)
             }
-            ZirStatement::Assertion(ref e) => write!(f, "Assertion({:?})", e),
+            ZirStatement::Assertion(ref e, ref error) => {
+                write!(f, "Assertion({:?}, {:?})", e, error)
+            }
             ZirStatement::MultipleDefinition(ref lhs, ref rhs) => {
                 write!(f, "MultipleDefinition({:?}, {:?})", lhs, rhs)
             }
+            ZirStatement::Location(ref location, ref stack) => {
+                write!(f, "Location({}, {:?})", location, stack)
+            }
         }
     }
 }
@@ -158,7 +190,9 @@ impl<'ast, T: fmt::Display> fmt::Display for ZirStatement<'ast, T> {
                         .join("\n")
                 )
             }
-            ZirStatement::Assertion(ref e) => write!(f, "assert({})", e),
+            ZirStatement::Assertion(ref e, ref error) => {
+                write!(f, "assert({}) // {}", e, error)
+            }
             ZirStatement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -168,6 +202,7 @@ impl<'ast, T: fmt::Display> fmt::Display for ZirStatement<'ast, T> {
                 }
                 write!(f, " = {}", rhs)
             }
+            ZirStatement::Location(ref location, _) => write!(f, "// {}", location),
         }
     }
 }
@@ -323,6 +358,7 @@ pub enum BooleanExpression<'ast, T> {
     UintGe(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     UintGt(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     UintEq(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintBit(Box<UExpression<'ast, T>>, u32),
     BoolEq(
         Box<BooleanExpression<'ast, T>>,
         Box<BooleanExpression<'ast, T>>,
@@ -463,6 +499,11 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
             UExpressionInner::Or(ref lhs, ref rhs) => write!(f, "({} | {})", lhs, rhs),
             UExpressionInner::LeftShift(ref e, ref by) => write!(f, "({} << {})", e, by),
             UExpressionInner::RightShift(ref e, ref by) => write!(f, "({} >> {})", e, by),
+            UExpressionInner::LeftRotate(ref e, ref by) => write!(f, "({} <<< {})", e, by),
+            UExpressionInner::RightRotate(ref e, ref by) => write!(f, "({} >>> {})", e, by),
+            UExpressionInner::Slice(ref e, ref from, ref to) => {
+                write!(f, "{}[{}..{}]", e, from, to)
+            }
             UExpressionInner::Not(ref e) => write!(f, "!{}", e),
             UExpressionInner::IfElse(ref condition, ref consequent, ref alternative) => write!(
                 f,
@@ -498,6 +539,7 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             BooleanExpression::FieldEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::BoolEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::UintEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::UintBit(ref e, ref index) => write!(f, "{}[{}]", e, index),
             BooleanExpression::Or(ref lhs, ref rhs) => write!(f, "{} || {}", lhs, rhs),
             BooleanExpression::And(ref lhs, ref rhs) => write!(f, "{} && {}", lhs, rhs),
             BooleanExpression::Not(ref exp) => write!(f, "!{}", exp),
@@ -137,7 +137,9 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 .flatten()
                 .collect(),
         ),
-        ZirStatement::Assertion(e) => ZirStatement::Assertion(f.fold_boolean_expression(e)?),
+        ZirStatement::Assertion(e, error) => {
+            ZirStatement::Assertion(f.fold_boolean_expression(e)?, error)
+        }
         ZirStatement::MultipleDefinition(variables, elist) => ZirStatement::MultipleDefinition(
             variables
                 .into_iter()
@@ -145,6 +147,7 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 .collect::<Result<_, _>>()?,
             f.fold_expression_list(elist)?,
         ),
+        ZirStatement::Location(location, stack) => ZirStatement::Location(location, stack),
     };
     Ok(vec![res])
 }
@@ -226,6 +229,10 @@ pub fn fold_boolean_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
             let e2 = f.fold_uint_expression(e2)?;
             BooleanExpression::UintEq(box e1, box e2)
         }
+        BooleanExpression::UintBit(box e, index) => {
+            let e = f.fold_uint_expression(e)?;
+            BooleanExpression::UintBit(box e, index)
+        }
         BooleanExpression::FieldLt(box e1, box e2) => {
             let e1 = f.fold_field_expression(e1)?;
             let e2 = f.fold_field_expression(e2)?;
@@ -371,6 +378,21 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
 
             UExpressionInner::RightShift(box e, by)
         }
+        UExpressionInner::LeftRotate(box e, by) => {
+            let e = f.fold_uint_expression(e)?;
+
+            UExpressionInner::LeftRotate(box e, by)
+        }
+        UExpressionInner::RightRotate(box e, by) => {
+            let e = f.fold_uint_expression(e)?;
+
+            UExpressionInner::RightRotate(box e, by)
+        }
+        UExpressionInner::Slice(box e, from, to) => {
+            let e = f.fold_uint_expression(e)?;
+
+            UExpressionInner::Slice(box e, from, to)
+        }
         UExpressionInner::Not(box e) => {
             let e = f.fold_uint_expression(e)?;
 
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use zokrates_field::Field;
 
 pub type MemberId = String;
 
@@ -20,6 +21,8 @@ pub enum UBitwidth {
     B32 = 32,
     #[serde(rename = "64")]
     B64 = 64,
+    #[serde(rename = "128")]
+    B128 = 128,
 }
 
 impl UBitwidth {
@@ -29,8 +32,20 @@ impl UBitwidth {
             UBitwidth::B16 => 16,
             UBitwidth::B32 => 32,
             UBitwidth::B64 => 64,
+            UBitwidth::B128 => 128,
         }
     }
+
+    /// The largest value of this bitwidth, `2 ** bitwidth - 1`
+    pub fn max_value(self) -> u128 {
+        u128::MAX >> (128 - self.to_usize())
+    }
+
+    /// Whether the product of two values of this bitwidth may not fit in the field, in which case
+    /// multiplications are carried out on limbs of half the bitwidth
+    pub fn requires_limbs<T: Field>(self) -> bool {
+        2 * self.to_usize() >= T::get_required_bits() - 1
+    }
 }
 
 impl From<usize> for UBitwidth {
@@ -40,6 +55,7 @@ impl From<usize> for UBitwidth {
             16 => UBitwidth::B16,
             32 => UBitwidth::B32,
             64 => UBitwidth::B64,
+            128 => UBitwidth::B128,
             _ => unreachable!(),
         }
     }
@@ -71,6 +71,26 @@ impl<'ast, T: Field> UExpression<'ast, T> {
         let bitwidth = self.bitwidth;
         UExpressionInner::RightShift(box self, by).annotate(bitwidth)
     }
+
+    pub fn left_rotate(self, by: u32) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        UExpressionInner::LeftRotate(box self, by).annotate(bitwidth)
+    }
+
+    pub fn right_rotate(self, by: u32) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        UExpressionInner::RightRotate(box self, by).annotate(bitwidth)
+    }
+
+    pub fn slice(self, from: u32, to: u32) -> UExpression<'ast, T> {
+        assert!(to as usize <= self.bitwidth.to_usize());
+        UExpressionInner::Slice(box self, from, to).annotate((to - from) as usize)
+    }
+
+    pub fn bit(self, index: u32) -> BooleanExpression<'ast, T> {
+        assert!((index as usize) < self.bitwidth.to_usize());
+        BooleanExpression::UintBit(box self, index)
+    }
 }
 
 impl<'ast, T: Field> From<u128> for UExpressionInner<'ast, T> {
@@ -184,6 +204,9 @@ pub enum UExpressionInner<'ast, T> {
     Or(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     LeftShift(Box<UExpression<'ast, T>>, u32),
     RightShift(Box<UExpression<'ast, T>>, u32),
+    LeftRotate(Box<UExpression<'ast, T>>, u32),
+    RightRotate(Box<UExpression<'ast, T>>, u32),
+    Slice(Box<UExpression<'ast, T>>, u32, u32),
     Not(Box<UExpression<'ast, T>>),
     IfElse(
         Box<BooleanExpression<'ast, T>>,
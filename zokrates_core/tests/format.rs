@@ -0,0 +1,142 @@
+extern crate zokrates_common;
+extern crate zokrates_core;
+extern crate zokrates_field;
+extern crate zokrates_pest_ast;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use zokrates_common::Resolver;
+use zokrates_core::absy::Module;
+use zokrates_core::compile::{compile, CompileConfig};
+use zokrates_core::format::format;
+use zokrates_core::ir;
+use zokrates_field::Bn128Field;
+use zokrates_fs_resolver::FileSystemResolver;
+
+const STDLIB: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../zokrates_stdlib/stdlib");
+const STDLIB_TESTS: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../zokrates_stdlib/tests/tests"
+);
+const EXAMPLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../zokrates_cli/examples");
+
+/// Find the `.zok` files under `dir`
+fn sources(dir: &Path) -> Vec<PathBuf> {
+    let mut res = vec![];
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            res.extend(sources(&path));
+        } else if path.extension().map(|e| e == "zok").unwrap_or(false) {
+            res.push(path);
+        }
+    }
+
+    res.sort();
+    res
+}
+
+/// A resolver formatting the modules it resolves
+struct FormattingResolver<'a>(FileSystemResolver<'a>);
+
+impl<'a> Resolver<io::Error> for FormattingResolver<'a> {
+    fn resolve(
+        &self,
+        current_location: PathBuf,
+        import_location: PathBuf,
+    ) -> Result<(String, PathBuf), io::Error> {
+        let (source, location) = self.0.resolve(current_location, import_location)?;
+        let source =
+            format(&source).map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        Ok((source, location))
+    }
+}
+
+#[test]
+fn format_is_idempotent_and_preserves_ast() {
+    let files = sources(Path::new(STDLIB))
+        .into_iter()
+        .chain(sources(Path::new(EXAMPLES)));
+
+    for path in files {
+        let source = fs::read_to_string(&path).unwrap();
+
+        // some examples are syntactically invalid on purpose
+        let ast = match zokrates_pest_ast::generate_ast(&source) {
+            Ok(ast) => ast,
+            Err(_) => continue,
+        };
+
+        let formatted = format(&source)
+            .unwrap_or_else(|e| panic!("{} could not be formatted: {}", path.display(), e));
+
+        assert_eq!(
+            format(&formatted).unwrap(),
+            formatted,
+            "formatting {} is not idempotent",
+            path.display()
+        );
+
+        let formatted_ast = zokrates_pest_ast::generate_ast(&formatted)
+            .unwrap_or_else(|e| panic!("{} was formatted to invalid code: {}", path.display(), e));
+
+        assert!(
+            Module::from(ast) == Module::from(formatted_ast),
+            "formatting {} changes its meaning",
+            path.display()
+        );
+    }
+}
+
+fn compile_stdlib_test(
+    path: &Path,
+    source: String,
+    resolver: &dyn Resolver<io::Error>,
+) -> Option<ir::Prog<Bn128Field>> {
+    compile::<Bn128Field, _>(
+        source,
+        path.to_path_buf(),
+        Some(resolver),
+        &CompileConfig::default(),
+    )
+    .ok()
+    .map(|artifacts| ir::Prog {
+        // statements are expected to move
+        source_map: None,
+        ..artifacts.prog().clone()
+    })
+}
+
+#[test]
+fn format_preserves_compiled_program() {
+    // compiling the tests of the standard library needs a larger stack than the one of the test threads
+    let builder = std::thread::Builder::new().stack_size(8388608);
+
+    builder
+        .spawn(|| {
+            let stdlib = FileSystemResolver::with_stdlib_root(STDLIB);
+            let formatting = FormattingResolver(FileSystemResolver::with_stdlib_root(STDLIB));
+
+            for path in sources(Path::new(STDLIB_TESTS)) {
+                let source = fs::read_to_string(&path).unwrap();
+                let formatted = format(&source).unwrap();
+
+                // the program and all the modules it imports are formatted
+                let expected = compile_stdlib_test(&path, source, &stdlib);
+                let actual = compile_stdlib_test(&path, formatted, &formatting);
+
+                // otherwise the comparison would pass for two failed compilations
+                assert!(expected.is_some(), "{} does not compile", path.display());
+                assert!(
+                    expected == actual,
+                    "formatting {} changes the compiled program",
+                    path.display()
+                );
+            }
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
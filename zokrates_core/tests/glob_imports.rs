@@ -0,0 +1,76 @@
+extern crate zokrates_core;
+extern crate zokrates_field;
+
+use std::io;
+use zokrates_core::compile::{compile, CompileConfig, CompileErrorInner};
+use zokrates_field::Bn128Field;
+use zokrates_fs_resolver::FileSystemResolver;
+
+fn stdlib_path() -> String {
+    std::fs::canonicalize(
+        std::env::current_dir()
+            .unwrap()
+            .join("../zokrates_stdlib/stdlib"),
+    )
+    .unwrap()
+    .to_str()
+    .unwrap()
+    .to_string()
+}
+
+#[test]
+fn glob_import_stdlib_constants() {
+    let source = r#"
+from "hashes/poseidon/constants" import *
+
+def main() -> field:
+    return POSEIDON_C[0][0] + POSEIDON_M[0][0][0]
+"#
+    .to_string();
+
+    let stdlib_path = stdlib_path();
+
+    let res = compile::<Bn128Field, io::Error>(
+        source,
+        "./path/to/file".into(),
+        Some(&FileSystemResolver::with_stdlib_root(&stdlib_path)),
+        &CompileConfig::default(),
+    );
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn glob_import_stdlib_main() {
+    // a module which only defines `main` exports nothing to a glob import
+    let source = r#"
+from "hashes/sha256/512bit" import *
+
+def main(u32[8] a, u32[8] b) -> u32[8]:
+    return a
+"#
+    .to_string();
+
+    let stdlib_path = stdlib_path();
+
+    let errors = compile::<Bn128Field, io::Error>(
+        source,
+        "./path/to/file".into(),
+        Some(&FileSystemResolver::with_stdlib_root(&stdlib_path)),
+        &CompileConfig::default(),
+    )
+    .unwrap_err()
+    .0;
+
+    assert_eq!(errors.len(), 1);
+    match errors[0].value() {
+        CompileErrorInner::ImportError(e) => {
+            assert_eq!(
+                e.message(),
+                "Module hashes/sha256/512bit exports no symbol to import with a glob import: import its `main` function by name instead"
+            );
+            assert_eq!(e.pos().unwrap().0.line, 2);
+        }
+        _ => unreachable!(),
+    }
+}
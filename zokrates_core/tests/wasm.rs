@@ -21,6 +21,7 @@ fn generate_proof() {
             FlatVariable::new(0),
             FlatVariable::new(0),
         )],
+        source_map: None,
     };
 
     let interpreter = Interpreter::default();
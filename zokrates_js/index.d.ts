@@ -61,6 +61,7 @@ declare module 'zokrates-js' {
 
   export interface ZoKratesProvider {
     compile(source: string, options?: CompileOptions): CompilationArtifacts;
+    format(source: string): string;
     setup(program: Uint8Array): SetupKeypair;
     computeWitness(artifacts: CompilationArtifacts, args: any[]): ComputationResult;
     exportSolidityVerifier(verificationKey: VerificationKey): string;
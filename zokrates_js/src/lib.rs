@@ -130,6 +130,17 @@ pub fn compile(
     Ok(JsValue::from_serde(&result).unwrap())
 }
 
+#[wasm_bindgen]
+pub fn format(source: JsValue) -> Result<JsValue, JsValue> {
+    let source = source
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("Expected the source to be a string"))?;
+    let formatted =
+        zokrates_core::format::format(&source).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(JsValue::from_str(&formatted))
+}
+
 #[wasm_bindgen]
 pub fn compute_witness(program: &[u8], abi: JsValue, args: JsValue) -> Result<JsValue, JsValue> {
     let program_flattened = deserialize_program(program)?;
@@ -150,7 +161,11 @@ pub fn compute_witness(program: &[u8], abi: JsValue, args: JsValue) -> Result<Js
         .map_err(|err| JsValue::from_str(&format!("Execution failed: {}", err)))?;
 
     let return_values: serde_json::Value =
-        zokrates_abi::Values::decode(witness.return_values(), signature.outputs).into_serde_json();
+        zokrates_abi::Values::decode(witness.return_values(), signature.outputs)
+            .map_err(|err| {
+                JsValue::from_str(&format!("Could not decode the return values: {}", err))
+            })?
+            .into_serde_json();
 
     let result = ComputationResult {
         witness: format!("{}", witness),
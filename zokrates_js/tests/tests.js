@@ -54,6 +54,17 @@ describe('tests', function() {
         });
     });
 
+    describe("formatting", () => {
+        it('should format', function() {
+            const formatted = this.zokrates.format("def main() -> field: return 42");
+            assert.equal(formatted, "def main() -> field:\n    return 42\n");
+        });
+
+        it('should throw on invalid code', function() {
+            assert.throws(() => this.zokrates.format(":-)"));
+        });
+    });
+
     describe("computation", () => {
         it('should compute with valid inputs', function() {
             assert.doesNotThrow(() => {
@@ -50,6 +50,9 @@ module.exports = (dep) => {
                 abi
             }
         },
+        format: (source) => {
+            return zokrates.format(source);
+        },
         setup: (program) => {
             const { vk, pk } = zokrates.setup(program);
             return {
@@ -0,0 +1,994 @@
+//! Module containing the index of the symbols and variables of a program, from which the server answers requests
+//!
+//! The index is built from the `absy`, which holds the positions of declarations. When the program is semantically
+//! correct, types are taken from the `typed_absy`, where aliases and generic parameters are resolved. Otherwise we
+//! fall back to the types as written in the source.
+
+use crate::protocol::{self, CompletionItem, DocumentSymbol, Kind, Position, Range, TextEdit};
+use crate::resolver::OverlayResolver;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use typed_arena::Arena;
+use zokrates_core::absy::{
+    FunctionNode, Module, Node, Program, Statement, StatementNode, Symbol, SymbolDeclarationNode,
+    SymbolDefinition, GLOB_IMPORT_ID,
+};
+use zokrates_core::compile::{check, check_semantics, parse_program_with_recovery, CompileConfig};
+use zokrates_core::diagnostics;
+use zokrates_core::source_map::SourceLocation;
+use zokrates_core::typed_absy::{
+    TypedConstantSymbol, TypedFunctionSymbol, TypedProgram, TypedStatement,
+};
+use zokrates_field::Field;
+
+/// Check the program whose entry point is `path`, returning the diagnostics found in each of its modules
+pub fn diagnostics<T: Field>(
+    path: &Path,
+    resolver: &OverlayResolver,
+) -> HashMap<PathBuf, Vec<protocol::Diagnostic>> {
+    let source = resolver.read(path).unwrap_or_default();
+
+    let found: Vec<diagnostics::Diagnostic> = match check::<T, io::Error>(
+        source,
+        path.to_path_buf(),
+        Some(resolver),
+        &CompileConfig::default(),
+    ) {
+        Ok(warnings) => warnings.iter().map(diagnostics::Diagnostic::from).collect(),
+        Err(errors) => errors.0.iter().map(diagnostics::Diagnostic::from).collect(),
+    };
+
+    // always report the document itself, so that its previous diagnostics are cleared
+    let mut res = HashMap::new();
+    res.insert(path.to_path_buf(), vec![]);
+
+    for d in &found {
+        res.entry(d.file.clone())
+            .or_insert_with(Vec::new)
+            .push(protocol::Diagnostic::from(d));
+    }
+
+    res
+}
+
+/// A symbol declared in a module, or a method of an `impl` block
+#[derive(Debug, Clone)]
+pub struct SymbolInfo {
+    pub name: String,
+    pub kind: Kind,
+    /// The range of the whole declaration
+    pub range: Range,
+    /// The range of the name of the symbol in its declaration
+    pub selection: Range,
+    /// The declaration as shown when hovering the symbol
+    pub detail: String,
+    /// The module and the identifier of the symbol imported by this one, if any
+    pub import: Option<(PathBuf, String)>,
+    pub children: Vec<SymbolInfo>,
+}
+
+/// A variable declared in a function
+#[derive(Debug, Clone)]
+pub struct VariableInfo {
+    pub name: String,
+    pub selection: Range,
+    /// The range in which the variable can be used
+    pub scope: Range,
+    pub detail: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ModuleIndex {
+    pub source: String,
+    pub symbols: Vec<SymbolInfo>,
+    pub variables: Vec<VariableInfo>,
+}
+
+/// The declaration an identifier refers to
+#[derive(Debug, PartialEq)]
+pub struct Definition<'a> {
+    pub module: &'a Path,
+    pub selection: Range,
+    pub detail: String,
+}
+
+/// The symbols and variables of a program, by module
+#[derive(Debug, Default)]
+pub struct Index {
+    pub modules: HashMap<PathBuf, ModuleIndex>,
+}
+
+impl Index {
+    pub fn build<T: Field>(path: &Path, resolver: &OverlayResolver) -> Self {
+        let arena = Arena::new();
+        let source: &str = arena.alloc(resolver.read(path).unwrap_or_default());
+
+        let mut index = Index::default();
+
+        match parse_program_with_recovery::<T, io::Error>(
+            source,
+            path.to_path_buf(),
+            Some(resolver),
+            &arena,
+        ) {
+            Ok((program, _)) => {
+                let typed = check_semantics::<T>(Program {
+                    modules: program.modules.clone(),
+                    main: program.main.clone(),
+                })
+                .map(|(typed_program, _)| TypedInfo::new(&typed_program))
+                .unwrap_or_default();
+
+                for (module_id, module) in &program.modules {
+                    let source = match module_id.as_path() == path {
+                        true => source.to_string(),
+                        false => resolver.read(module_id).unwrap_or_default(),
+                    };
+
+                    index.modules.insert(
+                        module_id.clone(),
+                        ModuleIndex::build(module_id, module, source, &typed, resolver),
+                    );
+                }
+            }
+            Err(_) => {
+                // fall back to the document alone, for example if one of its imports cannot be resolved
+                let recovered = zokrates_pest_ast::recover(source).1.unwrap_or_default();
+                let recovered = arena.alloc(recovered);
+
+                if let Ok(ast) = zokrates_pest_ast::generate_ast(recovered) {
+                    index.modules.insert(
+                        path.to_path_buf(),
+                        ModuleIndex::build(
+                            path,
+                            &Module::from(ast),
+                            source.to_string(),
+                            &TypedInfo::default(),
+                            resolver,
+                        ),
+                    );
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Find the declaration of the identifier at `position` in the module `path`
+    pub fn definition(&self, path: &Path, position: Position) -> Option<Definition> {
+        let (path, module) = self.modules.get_key_value(path)?;
+        let (name, _) = word_at(&module.source, position)?;
+
+        // variables shadow the symbols of the module
+        let variable = module
+            .variables
+            .iter()
+            .filter(|v| v.name == name)
+            .filter(|v| v.scope.contains(position) || v.selection.contains(position))
+            .max_by_key(|v| v.selection.start);
+
+        if let Some(v) = variable {
+            return Some(Definition {
+                module: path,
+                selection: v.selection,
+                detail: v.detail.clone(),
+            });
+        }
+
+        let symbol = module.symbols.iter().find(|s| s.name == name)?;
+        let (path, symbol) = self.original(path, symbol);
+
+        // functions can be overloaded, in which case we show all of them
+        let detail = self.modules[path]
+            .symbols
+            .iter()
+            .filter(|s| s.name == symbol.name && s.kind == symbol.kind)
+            .map(|s| s.detail.clone())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(Definition {
+            module: path,
+            selection: symbol.selection,
+            detail: if detail.is_empty() {
+                symbol.detail.clone()
+            } else {
+                detail
+            },
+        })
+    }
+
+    /// Follow the imports of `symbol` to the module in which it is declared
+    fn original<'a>(
+        &'a self,
+        path: &'a Path,
+        symbol: &'a SymbolInfo,
+    ) -> (&'a Path, &'a SymbolInfo) {
+        let mut res = (path, symbol);
+
+        // imports cannot be cyclic, but we bound the search in case the program is incorrect
+        for _ in 0..self.modules.len() {
+            let imported = res.1.import.as_ref().and_then(|(module, id)| {
+                let (path, module) = self.modules.get_key_value(module)?;
+                module
+                    .symbols
+                    .iter()
+                    .find(|s| &s.name == id)
+                    .map(|s| (path.as_path(), s))
+            });
+
+            match imported {
+                Some(imported) => res = imported,
+                None => break,
+            }
+        }
+
+        res
+    }
+
+    pub fn document_symbols(&self, path: &Path) -> Vec<DocumentSymbol> {
+        fn document_symbol(symbol: &SymbolInfo) -> DocumentSymbol {
+            DocumentSymbol {
+                name: symbol.name.clone(),
+                detail: symbol.detail.lines().next().map(String::from),
+                kind: symbol.kind.symbol_kind(),
+                range: symbol.range,
+                selection_range: symbol.selection,
+                children: symbol.children.iter().map(document_symbol).collect(),
+            }
+        }
+
+        self.modules
+            .get(path)
+            .map(|m| m.symbols.iter().map(document_symbol).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn completion(
+        &self,
+        path: &Path,
+        position: Position,
+        resolver: &OverlayResolver,
+        stdlib_root_path: &Path,
+    ) -> Vec<CompletionItem> {
+        // the document may not parse while an import is being written, so we read it again
+        let source = resolver.read(path).unwrap_or_default();
+        let line = source.lines().nth(position.line).unwrap_or("");
+        let prefix: String = line.chars().take(position.character).collect();
+        let statement = prefix.trim_start();
+        let statement = statement.strip_prefix("pub ").unwrap_or(statement);
+
+        // in the path of an import, suggest the modules of the standard library
+        if (statement.starts_with("from \"") || statement.starts_with("import \""))
+            && prefix.matches('"').count() == 1
+        {
+            let quote = prefix.chars().position(|c| c == '"').unwrap() + 1;
+            let range = Range::new(Position::new(position.line, quote), position);
+
+            return stdlib_modules(stdlib_root_path)
+                .into_iter()
+                .map(|m| CompletionItem {
+                    label: m.clone(),
+                    kind: Kind::Module.completion_kind(),
+                    detail: None,
+                    text_edit: Some(TextEdit { range, new_text: m }),
+                })
+                .collect();
+        }
+
+        // in the symbols imported from a module, suggest the symbols it exports
+        if let Some(import_location) = imported_module(statement) {
+            let module_path = resolver.path(path, Path::new(import_location));
+
+            return resolver
+                .read(&module_path)
+                .map(|source| exported_symbols(&source))
+                .unwrap_or_default();
+        }
+
+        let module = match self.modules.get(path) {
+            Some(module) => module,
+            None => return vec![],
+        };
+
+        // otherwise, suggest the variables in scope and the symbols of the module
+        let mut variables: Vec<&VariableInfo> = module
+            .variables
+            .iter()
+            .filter(|v| v.scope.contains(position))
+            .collect();
+        variables.sort_by_key(|v| std::cmp::Reverse(v.selection.start));
+
+        let mut items: Vec<CompletionItem> = vec![];
+
+        for v in variables {
+            if !items.iter().any(|i| i.label == v.name) {
+                items.push(CompletionItem {
+                    label: v.name.clone(),
+                    kind: Kind::Variable.completion_kind(),
+                    detail: Some(v.detail.clone()),
+                    text_edit: None,
+                });
+            }
+        }
+
+        for s in &module.symbols {
+            if s.name == GLOB_IMPORT_ID || items.iter().any(|i| i.label == s.name) {
+                continue;
+            }
+
+            let (_, original) = self.original(path, s);
+
+            items.push(CompletionItem {
+                label: s.name.clone(),
+                kind: original.kind.completion_kind(),
+                detail: original.detail.lines().next().map(String::from),
+                text_edit: None,
+            });
+        }
+
+        items
+    }
+}
+
+impl ModuleIndex {
+    fn build(
+        module_id: &Path,
+        module: &Module,
+        source: String,
+        typed: &TypedInfo,
+        resolver: &OverlayResolver,
+    ) -> Self {
+        let mut builder = Builder {
+            module_id,
+            source: &source,
+            typed,
+            resolver,
+            variables: vec![],
+        };
+
+        let symbols = module
+            .symbols
+            .iter()
+            .filter(|s| s.value.id != GLOB_IMPORT_ID)
+            .map(|s| builder.symbol(s))
+            .collect();
+
+        let variables = builder.variables;
+
+        ModuleIndex {
+            source,
+            symbols,
+            variables,
+        }
+    }
+}
+
+/// The types found in a typed program, identified by the positions of their declarations
+#[derive(Debug, Default)]
+struct TypedInfo {
+    /// The types of the variables, by file, position of the declaring statement and name
+    variables: HashMap<(String, usize, usize, String), String>,
+    /// The argument names and types, and the output types of the functions, by module and name
+    functions: HashMap<(PathBuf, String), Vec<FunctionTypes>>,
+    constants: HashMap<(PathBuf, String), String>,
+}
+
+#[derive(Debug)]
+struct FunctionTypes {
+    names: Vec<String>,
+    arguments: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl TypedInfo {
+    fn new<T: Field>(program: &TypedProgram<T>) -> Self {
+        let mut res = TypedInfo::default();
+
+        for (module_id, module) in &program.modules {
+            for (key, symbol) in &module.functions {
+                if let TypedFunctionSymbol::Here(f) = symbol {
+                    res.functions
+                        .entry((module_id.clone(), key.id.to_string()))
+                        .or_insert_with(Vec::new)
+                        .push(FunctionTypes {
+                            names: f.arguments.iter().map(|a| a.id.id.id.to_string()).collect(),
+                            arguments: f.arguments.iter().map(|a| a.id._type.to_string()).collect(),
+                            outputs: f.signature.outputs.iter().map(|o| o.to_string()).collect(),
+                        });
+
+                    res.statements(&f.statements, &mut None);
+                }
+            }
+
+            for (id, symbol) in &module.constants {
+                if let TypedConstantSymbol::Here(..) = symbol {
+                    res.constants
+                        .insert((module_id.clone(), id.id.to_string()), id.ty.to_string());
+                }
+            }
+        }
+
+        res
+    }
+
+    fn statements<T: Field>(
+        &mut self,
+        statements: &[TypedStatement<T>],
+        location: &mut Option<SourceLocation>,
+    ) {
+        for s in statements {
+            match s {
+                TypedStatement::Location(l) => *location = Some(l.clone()),
+                TypedStatement::Declaration(v) => {
+                    self.variable(location, v.id.id.to_string(), &v._type)
+                }
+                TypedStatement::For(v, _, _, statements) => {
+                    self.variable(location, v.id.id.to_string(), &v._type);
+                    self.statements(statements, &mut location.clone());
+                }
+                TypedStatement::IfElse(_, consequence, alternative) => {
+                    self.statements(consequence, &mut location.clone());
+                    self.statements(alternative, &mut location.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn variable<U: fmt::Display>(
+        &mut self,
+        location: &Option<SourceLocation>,
+        name: String,
+        ty: &U,
+    ) {
+        if let Some(l) = location {
+            self.variables.insert(
+                (l.file.clone(), l.position.line, l.position.col, name),
+                ty.to_string(),
+            );
+        }
+    }
+
+    /// Find the types of a function given the names of its arguments, as functions can be overloaded
+    fn function(&self, module_id: &Path, id: &str, names: &[String]) -> Option<&FunctionTypes> {
+        self.functions
+            .get(&(module_id.to_path_buf(), id.to_string()))?
+            .iter()
+            .find(|f| f.names == names)
+    }
+}
+
+struct Builder<'a> {
+    module_id: &'a Path,
+    source: &'a str,
+    typed: &'a TypedInfo,
+    resolver: &'a OverlayResolver<'a>,
+    variables: Vec<VariableInfo>,
+}
+
+impl<'a> Builder<'a> {
+    fn symbol(&mut self, declaration: &SymbolDeclarationNode) -> SymbolInfo {
+        let name = declaration.value.id;
+        let range = range(declaration);
+
+        let (kind, detail, import, children) = match &declaration.value.symbol {
+            Symbol::Here(SymbolDefinition::Function(f)) => {
+                (Kind::Function, self.function(name, f), None, vec![])
+            }
+            Symbol::Here(SymbolDefinition::Struct(s)) => {
+                let generics = match s.value.generics.is_empty() {
+                    true => String::new(),
+                    false => format!(
+                        "<{}>",
+                        s.value
+                            .generics
+                            .iter()
+                            .map(|g| g.value.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                let fields: String = s
+                    .value
+                    .fields
+                    .iter()
+                    .map(|f| format!("    {} {}\n", f.value.ty, f.value.id))
+                    .collect();
+
+                (
+                    Kind::Struct,
+                    format!("struct {}{} {{\n{}}}", name, generics, fields),
+                    None,
+                    vec![],
+                )
+            }
+            Symbol::Here(SymbolDefinition::Enum(e)) => (
+                Kind::Enum,
+                format!("enum {} {}", name, e.value),
+                None,
+                vec![],
+            ),
+            Symbol::Here(SymbolDefinition::Constant(c)) => {
+                let ty = self
+                    .typed
+                    .constants
+                    .get(&(self.module_id.to_path_buf(), name.to_string()))
+                    .cloned()
+                    .unwrap_or_else(|| c.value.ty.to_string());
+
+                (
+                    Kind::Constant,
+                    format!("const {} {}", ty, name),
+                    None,
+                    vec![],
+                )
+            }
+            Symbol::Here(SymbolDefinition::Type(t)) => (
+                Kind::Type,
+                format!("type {}{}", name, t.value),
+                None,
+                vec![],
+            ),
+            Symbol::Here(SymbolDefinition::Impl(i)) => {
+                let methods = i
+                    .value
+                    .methods
+                    .iter()
+                    .map(|m| {
+                        let range = self::range(m);
+
+                        SymbolInfo {
+                            name: m.value.id.to_string(),
+                            kind: Kind::Method,
+                            range,
+                            selection: find(self.source, range.start, m.value.id),
+                            detail: self.function(m.value.id, &m.value.function),
+                            import: None,
+                            children: vec![],
+                        }
+                    })
+                    .collect();
+
+                (Kind::Impl, format!("impl {}", name), None, methods)
+            }
+            Symbol::Here(SymbolDefinition::Import(i)) => (
+                Kind::Module,
+                i.value.to_string(),
+                Some((
+                    self.resolver.path(self.module_id, i.value.source),
+                    i.value.id.id.to_string(),
+                )),
+                vec![],
+            ),
+            Symbol::There(i) => (
+                Kind::Module,
+                i.value.to_string(),
+                Some((i.value.module_id.clone(), i.value.symbol_id.to_string())),
+                vec![],
+            ),
+            Symbol::Flat(f) => (
+                Kind::Function,
+                format!("def {}{}", name, f.signature()),
+                None,
+                vec![],
+            ),
+        };
+
+        SymbolInfo {
+            name: name.to_string(),
+            kind,
+            range,
+            selection: find(self.source, range.start, name),
+            detail,
+            import,
+            children,
+        }
+    }
+
+    /// Index the arguments and variables of a function, returning its signature
+    fn function(&mut self, name: &str, function: &FunctionNode) -> String {
+        let f = &function.value;
+
+        let names: Vec<String> = f
+            .arguments
+            .iter()
+            .map(|a| a.value.id.value.id.to_string())
+            .collect();
+
+        let (arguments, outputs) = match self.typed.function(self.module_id, name, &names) {
+            Some(types) => (types.arguments.clone(), types.outputs.clone()),
+            None => (
+                f.arguments
+                    .iter()
+                    .map(|a| a.value.id.value._type.to_string())
+                    .collect(),
+                f.signature.outputs.iter().map(|o| o.to_string()).collect(),
+            ),
+        };
+
+        // arguments can be used in the whole function
+        let scope = range(function);
+
+        for (a, ty) in f.arguments.iter().zip(&arguments) {
+            let variable = &a.value.id;
+
+            self.variables.push(VariableInfo {
+                name: variable.value.id.to_string(),
+                selection: find(self.source, range(variable).start, variable.value.id),
+                scope,
+                detail: format!("{} {}", ty, variable.value.id),
+            });
+        }
+
+        self.statements(&f.statements, scope.end);
+
+        let generics = match f.signature.generics.is_empty() {
+            true => String::new(),
+            false => format!(
+                "<{}>",
+                f.signature
+                    .generics
+                    .iter()
+                    .map(|g| g.value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+
+        let arguments = f
+            .arguments
+            .iter()
+            .zip(&arguments)
+            .map(|(a, ty)| {
+                format!(
+                    "{}{} {}",
+                    if a.value.private { "private " } else { "" },
+                    ty,
+                    a.value.id.value.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let outputs = match outputs.len() {
+            0 => String::new(),
+            1 => format!(" -> {}", outputs[0]),
+            _ => format!(" -> ({})", outputs.join(", ")),
+        };
+
+        format!("def {}{}({}){}", name, generics, arguments, outputs)
+    }
+
+    /// Index the variables declared in `statements`, which can be used until `scope_end`
+    fn statements(&mut self, statements: &[StatementNode], scope_end: Position) {
+        for s in statements {
+            let statement_range = range(s);
+
+            match &s.value {
+                Statement::Declaration(v) => {
+                    // the variable can be used once it is defined, that is after the statement
+                    self.variable(
+                        s,
+                        v.value.id,
+                        &v.value._type,
+                        range(v),
+                        Range::new(statement_range.end, scope_end),
+                    );
+                }
+                Statement::For(v, _, _, statements) => {
+                    self.variable(s, v.value.id, &v.value._type, range(v), statement_range);
+                    self.statements(statements, statement_range.end);
+                }
+                Statement::IfElse(_, consequence, alternative) => {
+                    self.statements(consequence, statement_range.end);
+                    self.statements(alternative, statement_range.end);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn variable<U: fmt::Display>(
+        &mut self,
+        statement: &StatementNode,
+        name: &str,
+        declared_type: &U,
+        declaration: Range,
+        scope: Range,
+    ) {
+        let ty = self
+            .typed
+            .variables
+            .get(&(
+                self.module_id.display().to_string(),
+                statement.start.line,
+                statement.start.col,
+                name.to_string(),
+            ))
+            .cloned()
+            .unwrap_or_else(|| declared_type.to_string());
+
+        self.variables.push(VariableInfo {
+            name: name.to_string(),
+            selection: find(self.source, declaration.start, name),
+            scope,
+            detail: format!("{} {}", ty, name),
+        });
+    }
+}
+
+fn range<V>(node: &Node<V>) -> Range {
+    Range::new(
+        Position::from_one_based(node.start.line, node.start.col),
+        Position::from_one_based(node.end.line, node.end.col),
+    )
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Returns the identifier at `position`, unless it is a member or a method, which we do not resolve as this requires
+/// the type of the expression they are accessed on
+fn word_at(source: &str, position: Position) -> Option<(String, Range)> {
+    let chars: Vec<char> = source.lines().nth(position.line)?.chars().collect();
+
+    let mut start = std::cmp::min(position.character, chars.len());
+    while start > 0 && is_identifier_char(chars[start - 1]) {
+        start -= 1;
+    }
+
+    let mut end = std::cmp::min(position.character, chars.len());
+    while end < chars.len() && is_identifier_char(chars[end]) {
+        end += 1;
+    }
+
+    if start == end || chars[start].is_ascii_digit() {
+        return None;
+    }
+
+    // `a.b` is a member access, but `0..b` is a range
+    if start > 0 && chars[start - 1] == '.' && (start < 2 || chars[start - 2] != '.') {
+        return None;
+    }
+
+    Some((
+        chars[start..end].iter().collect(),
+        Range::new(
+            Position::new(position.line, start),
+            Position::new(position.line, end),
+        ),
+    ))
+}
+
+/// Find the first occurrence of the identifier `name` from `from`, outside of string literals
+fn find(source: &str, from: Position, name: &str) -> Range {
+    let name: Vec<char> = name.chars().collect();
+
+    for (i, line) in source.lines().enumerate().skip(from.line) {
+        let chars: Vec<char> = line.chars().collect();
+        let first = if i == from.line { from.character } else { 0 };
+        let mut in_string = false;
+
+        for j in 0..chars.len() {
+            if chars[j] == '"' {
+                in_string = !in_string;
+            } else if !in_string
+                && j >= first
+                && chars[j..].starts_with(&name)
+                && (j == 0 || !is_identifier_char(chars[j - 1]))
+                && !chars
+                    .get(j + name.len())
+                    .map(|c| is_identifier_char(*c))
+                    .unwrap_or(false)
+            {
+                return Range::new(Position::new(i, j), Position::new(i, j + name.len()));
+            }
+        }
+    }
+
+    Range::new(from, from)
+}
+
+/// Returns the module imported in `from "module" import ...`, if `statement` is such an import
+fn imported_module(statement: &str) -> Option<&str> {
+    let rest = statement.strip_prefix("from \"")?;
+    let end = rest.find('"')?;
+
+    match rest[end + 1..].trim_start().starts_with("import") {
+        true => Some(&rest[..end]),
+        false => None,
+    }
+}
+
+fn exported_symbols(source: &str) -> Vec<CompletionItem> {
+    let recovered = match zokrates_pest_ast::recover(source).1 {
+        Some(recovered) => recovered,
+        None => return vec![],
+    };
+
+    let ast = match zokrates_pest_ast::generate_ast(&recovered) {
+        Ok(ast) => ast,
+        Err(_) => return vec![],
+    };
+
+    let module = Module::from(ast);
+
+    module
+        .symbols
+        .iter()
+        .filter(|s| s.value.id != GLOB_IMPORT_ID && module.exports(s.value.id))
+        .map(|s| {
+            let kind = match &s.value.symbol {
+                Symbol::Here(SymbolDefinition::Function(..)) | Symbol::Flat(..) => Kind::Function,
+                Symbol::Here(SymbolDefinition::Struct(..)) => Kind::Struct,
+                Symbol::Here(SymbolDefinition::Enum(..)) => Kind::Enum,
+                Symbol::Here(SymbolDefinition::Constant(..)) => Kind::Constant,
+                Symbol::Here(SymbolDefinition::Type(..)) => Kind::Type,
+                Symbol::Here(SymbolDefinition::Impl(..)) => Kind::Impl,
+                Symbol::Here(SymbolDefinition::Import(..)) | Symbol::There(..) => Kind::Module,
+            };
+
+            CompletionItem {
+                label: s.value.id.to_string(),
+                kind: kind.completion_kind(),
+                detail: None,
+                text_edit: None,
+            }
+        })
+        .collect()
+}
+
+/// Returns the modules of the standard library, as they are imported
+fn stdlib_modules(stdlib_root_path: &Path) -> Vec<String> {
+    fn visit(dir: &Path, root: &Path, res: &mut Vec<String>) {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            if path.is_dir() {
+                visit(&path, root, res);
+            } else if path.extension().map(|e| e == "zok").unwrap_or(false) {
+                if let Ok(relative) = path.with_extension("").strip_prefix(root) {
+                    res.push(
+                        relative
+                            .components()
+                            .map(|c| c.as_os_str().to_string_lossy())
+                            .collect::<Vec<_>>()
+                            .join("/"),
+                    );
+                }
+            }
+        }
+    }
+
+    let mut res = vec![];
+    visit(stdlib_root_path, stdlib_root_path, &mut res);
+    res.sort();
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zokrates_field::Bn128Field;
+
+    fn index(documents: &HashMap<PathBuf, String>, path: &str) -> Index {
+        let resolver = OverlayResolver::new(documents, Path::new("/stdlib"));
+        Index::build::<Bn128Field>(Path::new(path), &resolver)
+    }
+
+    #[test]
+    fn word() {
+        let source = "    return a.b + c[0..N]";
+
+        assert_eq!(
+            word_at(source, Position::new(0, 12)).map(|w| w.0),
+            Some("a".to_string())
+        );
+        assert_eq!(word_at(source, Position::new(0, 13)), None);
+        assert_eq!(
+            word_at(source, Position::new(0, 23)).map(|w| w.0),
+            Some("N".to_string())
+        );
+    }
+
+    #[test]
+    fn variables() {
+        let mut documents = HashMap::new();
+        documents.insert(
+            PathBuf::from("/main.zok"),
+            r#"type Array = field[2]
+
+def main(Array a) -> field:
+    field b = a[0]
+    for u32 i in 0..2 do
+        b = b + a[i]
+    endfor
+    return b
+"#
+            .to_string(),
+        );
+
+        let index = index(&documents, "/main.zok");
+        let main = Path::new("/main.zok");
+
+        // types are resolved
+        let a = index.definition(main, Position::new(3, 14)).unwrap();
+        assert_eq!(a.detail, "field[2] a");
+        assert_eq!(
+            a.selection,
+            Range::new(Position::new(2, 15), Position::new(2, 16))
+        );
+
+        // the variable in scope is found
+        let b = index.definition(main, Position::new(7, 11)).unwrap();
+        assert_eq!(
+            b.selection,
+            Range::new(Position::new(3, 10), Position::new(3, 11))
+        );
+
+        let i = index.definition(main, Position::new(5, 18)).unwrap();
+        assert_eq!(i.detail, "u32 i");
+    }
+
+    #[test]
+    fn imports() {
+        let mut documents = HashMap::new();
+        documents.insert(
+            PathBuf::from("/main.zok"),
+            r#"from "./lib" import double as twice
+
+def main(field a) -> field:
+    return twice(a)
+"#
+            .to_string(),
+        );
+        documents.insert(
+            PathBuf::from("/lib.zok"),
+            r#"def double(field x) -> field:
+    return 2 * x
+"#
+            .to_string(),
+        );
+
+        let index = index(&documents, "/main.zok");
+
+        let twice = index
+            .definition(Path::new("/main.zok"), Position::new(3, 12))
+            .unwrap();
+
+        assert_eq!(
+            twice,
+            Definition {
+                module: Path::new("/lib.zok"),
+                selection: Range::new(Position::new(0, 4), Position::new(0, 10)),
+                detail: "def double(field x) -> field".to_string()
+            }
+        );
+
+        let symbols = index.document_symbols(Path::new("/main.zok"));
+        assert_eq!(
+            symbols.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            vec!["twice", "main"]
+        );
+    }
+
+    #[test]
+    fn import_completion() {
+        assert_eq!(imported_module("from \"./lib\" import a, "), Some("./lib"));
+        assert_eq!(imported_module("from \"./lib"), None);
+
+        let items = exported_symbols("const field A = 1\ndef foo() -> field:\n    return A\n");
+        assert_eq!(
+            items.iter().map(|i| i.label.as_str()).collect::<Vec<_>>(),
+            vec!["A", "foo"]
+        );
+    }
+}
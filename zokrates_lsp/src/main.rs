@@ -0,0 +1,37 @@
+//! A language server for ZoKrates, communicating with the editor over stdio
+//!
+//! The standard library is looked up in `ZOKRATES_STDLIB`, or in `~/.zokrates/stdlib` by default. Clients can override
+//! it, as well as the curve used to check programs, with the `stdlibPath` and `curve` initialization options.
+
+mod analysis;
+mod protocol;
+mod resolver;
+mod rpc;
+mod server;
+mod uri;
+
+use server::Server;
+use std::io;
+use std::path::PathBuf;
+
+fn main() {
+    let stdlib_root_path = std::env::var("ZOKRATES_STDLIB")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            dirs::home_dir()
+                .map(|p| p.join(".zokrates/stdlib"))
+                .unwrap_or_default()
+        });
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    let code = Server::new(stdlib_root_path)
+        .run(&mut stdin.lock(), &mut stdout.lock())
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            1
+        });
+
+    std::process::exit(code);
+}
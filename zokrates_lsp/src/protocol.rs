@@ -0,0 +1,256 @@
+//! The subset of the language server protocol structures used by the server
+
+use serde::{Deserialize, Serialize};
+use zokrates_core::diagnostics;
+
+/// A position in a document, with lines and characters starting at 0
+///
+/// The server counts characters in chars, while the protocol counts them in UTF-16 code units, so positions are
+/// converted when they are exchanged with the client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub character: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, character: usize) -> Self {
+        Position { line, character }
+    }
+
+    /// Convert a position of the compiler, with lines and columns starting at 1
+    pub fn from_one_based(line: usize, col: usize) -> Self {
+        Position::new(line.saturating_sub(1), col.saturating_sub(1))
+    }
+
+    /// Convert a position in `source` sent by the client, whose character is counted in UTF-16 code units, to one
+    /// counted in chars
+    pub fn to_chars(self, source: &str) -> Self {
+        let mut units = 0;
+        let mut character = 0;
+
+        for c in source.lines().nth(self.line).unwrap_or("").chars() {
+            if units + c.len_utf16() > self.character {
+                return Position::new(self.line, character);
+            }
+            units += c.len_utf16();
+            character += 1;
+        }
+
+        // positions past the end of the line are kept as they are
+        Position::new(self.line, character + self.character - units)
+    }
+
+    /// Convert a position in `source` to be sent to the client, counting its character in UTF-16 code units
+    pub fn to_utf16(self, source: &str) -> Self {
+        let line: Vec<char> = source
+            .lines()
+            .nth(self.line)
+            .unwrap_or("")
+            .chars()
+            .collect();
+        let taken = std::cmp::min(self.character, line.len());
+
+        let units: usize = line[..taken].iter().map(|c| c.len_utf16()).sum();
+
+        Position::new(self.line, units + self.character - taken)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Range {
+    pub fn new(start: Position, end: Position) -> Self {
+        Range { start, end }
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.start <= position && position <= self.end
+    }
+
+    pub fn to_utf16(self, source: &str) -> Self {
+        Range::new(self.start.to_utf16(source), self.end.to_utf16(source))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Location {
+    pub uri: String,
+    pub range: Range,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: u8,
+    pub source: String,
+    pub message: String,
+}
+
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+
+impl Diagnostic {
+    /// An error which does not point to a specific part of the document
+    pub fn error(message: String) -> Self {
+        Diagnostic {
+            range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+            severity: SEVERITY_ERROR,
+            source: "zokrates".to_string(),
+            message,
+        }
+    }
+}
+
+impl From<&diagnostics::Diagnostic> for Diagnostic {
+    fn from(d: &diagnostics::Diagnostic) -> Self {
+        let range = d
+            .label
+            .as_ref()
+            .map(|label| {
+                Range::new(
+                    Position::from_one_based(label.start.line, label.start.col),
+                    Position::from_one_based(label.end.line, label.end.col),
+                )
+            })
+            .unwrap_or_else(|| Range::new(Position::new(0, 0), Position::new(0, 0)));
+
+        Diagnostic {
+            range,
+            severity: match d.severity {
+                diagnostics::Severity::Error => SEVERITY_ERROR,
+                diagnostics::Severity::Warning => SEVERITY_WARNING,
+            },
+            source: "zokrates".to_string(),
+            message: std::iter::once(d.message.clone())
+                .chain(d.notes.iter().map(|n| format!("note: {}", n)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+/// The kind of a symbol, mapped to the `SymbolKind` and `CompletionItemKind` of the protocol
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Module,
+    Function,
+    Method,
+    Struct,
+    Enum,
+    Constant,
+    Type,
+    Impl,
+    Variable,
+}
+
+impl Kind {
+    pub fn symbol_kind(&self) -> u8 {
+        match self {
+            Kind::Module => 2,
+            Kind::Impl => 5,
+            Kind::Method => 6,
+            Kind::Enum => 10,
+            Kind::Function => 12,
+            Kind::Variable => 13,
+            Kind::Constant => 14,
+            Kind::Struct => 23,
+            Kind::Type => 26,
+        }
+    }
+
+    pub fn completion_kind(&self) -> u8 {
+        match self {
+            Kind::Method => 2,
+            Kind::Function => 3,
+            Kind::Variable => 6,
+            Kind::Impl => 7,
+            Kind::Module => 9,
+            Kind::Enum => 13,
+            Kind::Constant => 21,
+            Kind::Struct => 22,
+            Kind::Type => 25,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TextEdit {
+    pub range: Range,
+    #[serde(rename = "newText")]
+    pub new_text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(rename = "textEdit", skip_serializing_if = "Option::is_none")]
+    pub text_edit: Option<TextEdit>,
+}
+
+impl CompletionItem {
+    pub fn into_utf16(self, source: &str) -> Self {
+        CompletionItem {
+            text_edit: self.text_edit.map(|e| TextEdit {
+                range: e.range.to_utf16(source),
+                ..e
+            }),
+            ..self
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DocumentSymbol {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub kind: u8,
+    pub range: Range,
+    #[serde(rename = "selectionRange")]
+    pub selection_range: Range,
+    pub children: Vec<DocumentSymbol>,
+}
+
+impl DocumentSymbol {
+    pub fn into_utf16(self, source: &str) -> Self {
+        DocumentSymbol {
+            range: self.range.to_utf16(source),
+            selection_range: self.selection_range.to_utf16(source),
+            children: self
+                .children
+                .into_iter()
+                .map(|c| c.into_utf16(source))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf16() {
+        // `𝔽` is a single char but two UTF-16 code units
+        let source = "def main():\n    assert(\"𝔽\" == \"𝔽\")\n";
+
+        assert_eq!(Position::new(1, 15).to_utf16(source), Position::new(1, 16));
+        assert_eq!(Position::new(1, 16).to_chars(source), Position::new(1, 15));
+
+        // the characters before any non-ASCII one are the same in both encodings
+        assert_eq!(Position::new(1, 4).to_utf16(source), Position::new(1, 4));
+
+        // positions past the end of the line are kept relative to it
+        assert_eq!(Position::new(0, 13).to_utf16(source), Position::new(0, 13));
+        assert_eq!(Position::new(1, 30).to_chars(source), Position::new(1, 28));
+    }
+}
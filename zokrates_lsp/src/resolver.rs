@@ -0,0 +1,128 @@
+//! A resolver which reads the documents open in the editor, falling back to the file system
+
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use zokrates_common::Resolver;
+
+pub struct OverlayResolver<'a> {
+    /// The content of the documents open in the editor, which may not be saved
+    documents: &'a HashMap<PathBuf, String>,
+    stdlib_root_path: &'a Path,
+}
+
+impl<'a> OverlayResolver<'a> {
+    pub fn new(documents: &'a HashMap<PathBuf, String>, stdlib_root_path: &'a Path) -> Self {
+        OverlayResolver {
+            documents,
+            stdlib_root_path,
+        }
+    }
+
+    /// Returns the path of the module imported as `import_location` from `current_location`
+    pub fn path(&self, current_location: &Path, import_location: &Path) -> PathBuf {
+        // paths starting with `./` or `../` are interpreted relative to the current file
+        // other paths `abc/def` are interpreted relative to the standard library root path
+        let base = match import_location.components().next() {
+            Some(Component::CurDir) | Some(Component::ParentDir) => current_location
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+            _ => self.stdlib_root_path.to_path_buf(),
+        };
+
+        normalize(&base.join(import_location).with_extension("zok"))
+    }
+
+    /// Returns the content of the document at `path`, reading it from the file system if it is not open
+    pub fn read(&self, path: &Path) -> io::Result<String> {
+        match self.documents.get(path) {
+            Some(source) => Ok(source.clone()),
+            None => read_to_string(path),
+        }
+    }
+}
+
+impl<'a> Resolver<io::Error> for OverlayResolver<'a> {
+    fn resolve(
+        &self,
+        current_location: PathBuf,
+        import_location: PathBuf,
+    ) -> Result<(String, PathBuf), io::Error> {
+        let path = self.path(&current_location, &import_location);
+
+        if !self.documents.contains_key(&path) && !path.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("No file found at {}", import_location.display()),
+            ));
+        }
+
+        Ok((self.read(&path)?, path))
+    }
+}
+
+/// Remove the `.` and `..` components of a path without accessing the file system, so that a module has the same
+/// path whichever way it is imported
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut res = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !res.pop() {
+                    res.push(component);
+                }
+            }
+            c => res.push(c),
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_document() {
+        let mut documents = HashMap::new();
+        documents.insert(PathBuf::from("/project/lib.zok"), "// unsaved".to_string());
+
+        let resolver = OverlayResolver::new(&documents, Path::new("/stdlib"));
+
+        assert_eq!(
+            resolver
+                .resolve("/project/src/main.zok".into(), "../lib".into())
+                .unwrap(),
+            ("// unsaved".to_string(), PathBuf::from("/project/lib.zok"))
+        );
+    }
+
+    #[test]
+    fn stdlib() {
+        let documents = HashMap::new();
+        let resolver = OverlayResolver::new(&documents, Path::new("/stdlib"));
+
+        assert_eq!(
+            resolver.path(
+                Path::new("/project/main.zok"),
+                Path::new("hashes/sha256/512bit")
+            ),
+            PathBuf::from("/stdlib/hashes/sha256/512bit.zok")
+        );
+    }
+
+    #[test]
+    fn not_found() {
+        let documents = HashMap::new();
+        let resolver = OverlayResolver::new(&documents, Path::new("/stdlib"));
+
+        assert!(resolver
+            .resolve("/project/main.zok".into(), "./missing".into())
+            .is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Reading and writing JSON-RPC messages framed by a `Content-Length` header, as specified by the language server
+//! protocol
+
+use serde_json::Value;
+use std::io::{self, BufRead, Write};
+
+/// Read the next message, returning `None` once the input is closed
+///
+/// A message whose content is not valid JSON is returned as an error, so that the server can answer it and keep
+/// running. Errors in the headers are fatal, as the next message cannot be found.
+pub fn read_message<R: BufRead>(
+    reader: &mut R,
+) -> io::Result<Option<Result<Value, serde_json::Error>>> {
+    let mut content_length = None;
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+
+        // headers are terminated by an empty line
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some(length) = header.strip_prefix("Content-Length:") {
+            content_length = Some(length.trim().parse::<usize>().map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", header, e))
+            })?);
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "Missing Content-Length header")
+    })?;
+
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+
+    Ok(Some(serde_json::from_slice(&content)))
+}
+
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> io::Result<()> {
+    let content = serde_json::to_string(message)?;
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn round_trip() {
+        let message = json!({"jsonrpc": "2.0", "id": 1, "method": "shutdown"});
+
+        let mut buffer = vec![];
+        write_message(&mut buffer, &message).unwrap();
+        write_message(&mut buffer, &message).unwrap();
+
+        let mut reader = &buffer[..];
+        assert_eq!(
+            read_message(&mut reader).unwrap().unwrap().unwrap(),
+            message
+        );
+        assert_eq!(
+            read_message(&mut reader).unwrap().unwrap().unwrap(),
+            message
+        );
+        assert!(read_message(&mut reader).unwrap().is_none());
+    }
+
+    #[test]
+    fn malformed_content() {
+        let mut buffer = b"Content-Length: 5\r\n\r\n{foo}".to_vec();
+        write_message(&mut buffer, &json!({"jsonrpc": "2.0", "method": "exit"})).unwrap();
+
+        // the next message can still be read after one which is not valid json
+        let mut reader = &buffer[..];
+        assert!(read_message(&mut reader).unwrap().unwrap().is_err());
+        assert!(read_message(&mut reader).unwrap().unwrap().is_ok());
+    }
+
+    #[test]
+    fn missing_length() {
+        let mut reader = &b"Content-Type: foo\r\n\r\n{}"[..];
+        assert!(read_message(&mut reader).is_err());
+    }
+}
@@ -0,0 +1,478 @@
+//! The language server, answering the requests of the client and publishing diagnostics whenever a document changes
+
+use crate::analysis::{self, Index};
+use crate::protocol::{Diagnostic, Location, Position};
+use crate::resolver::OverlayResolver;
+use crate::rpc::{read_message, write_message};
+use crate::uri;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Bw6_761Field, Field};
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// Documents are sent in full on each change
+const TEXT_DOCUMENT_SYNC_FULL: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Curve {
+    Bn128,
+    Bls12_381,
+    Bls12_377,
+    Bw6_761,
+}
+
+impl Curve {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bn128" => Some(Curve::Bn128),
+            "bls12_381" => Some(Curve::Bls12_381),
+            "bls12_377" => Some(Curve::Bls12_377),
+            "bw6_761" => Some(Curve::Bw6_761),
+            _ => None,
+        }
+    }
+}
+
+pub struct Server {
+    /// The content of the open documents, which takes precedence over the file system
+    documents: HashMap<PathBuf, String>,
+    stdlib_root_path: PathBuf,
+    curve: Curve,
+    /// The files for which diagnostics were published when checking each open document
+    published: HashMap<PathBuf, HashSet<PathBuf>>,
+    shutdown: bool,
+}
+
+/// The outcome of a request which cannot be answered
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+impl ResponseError {
+    fn invalid_params() -> Self {
+        ResponseError {
+            code: INVALID_PARAMS,
+            message: "Invalid params".to_string(),
+        }
+    }
+}
+
+impl Server {
+    pub fn new(stdlib_root_path: PathBuf) -> Self {
+        Server {
+            documents: HashMap::new(),
+            stdlib_root_path,
+            curve: Curve::Bn128,
+            published: HashMap::new(),
+            shutdown: false,
+        }
+    }
+
+    /// Serve the messages read from `reader` until the `exit` notification, returning the exit code of the process
+    pub fn run<R: BufRead, W: Write>(&mut self, reader: &mut R, writer: &mut W) -> io::Result<i32> {
+        while let Some(message) = read_message(reader)? {
+            let message = match message {
+                Ok(message) => message,
+                Err(e) => {
+                    // the id of a request which cannot be parsed is unknown, so the response has none
+                    write_message(
+                        writer,
+                        &json!({
+                            "jsonrpc": "2.0",
+                            "id": Value::Null,
+                            "error": {"code": PARSE_ERROR, "message": format!("Parse error: {}", e)}
+                        }),
+                    )?;
+                    continue;
+                }
+            };
+
+            // responses to the requests of the server have no method, and must not be answered
+            let method = match message["method"].as_str() {
+                Some(method) => method,
+                None => continue,
+            };
+            let params = &message["params"];
+
+            match message.get("id") {
+                // requests have an id, and expect a response
+                Some(id) => {
+                    let response = match self.request(method, params) {
+                        Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+                        Err(e) => json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": {"code": e.code, "message": e.message}
+                        }),
+                    };
+
+                    write_message(writer, &response)?;
+                }
+                None => {
+                    if method == "exit" {
+                        return Ok(if self.shutdown { 0 } else { 1 });
+                    }
+
+                    for notification in self.notification(method, params) {
+                        write_message(writer, &notification)?;
+                    }
+                }
+            }
+        }
+
+        // the input was closed without an `exit` notification
+        Ok(1)
+    }
+
+    fn request(&mut self, method: &str, params: &Value) -> Result<Value, ResponseError> {
+        match method {
+            "initialize" => {
+                let options = &params["initializationOptions"];
+
+                if let Some(path) = options["stdlibPath"].as_str() {
+                    self.stdlib_root_path = PathBuf::from(path);
+                }
+
+                if let Some(curve) = options["curve"].as_str() {
+                    self.curve = Curve::from_name(curve).ok_or_else(|| ResponseError {
+                        code: INVALID_PARAMS,
+                        message: format!("Unknown curve {}", curve),
+                    })?;
+                }
+
+                Ok(json!({
+                    "capabilities": {
+                        "textDocumentSync": TEXT_DOCUMENT_SYNC_FULL,
+                        "hoverProvider": true,
+                        "definitionProvider": true,
+                        "completionProvider": {"triggerCharacters": ["\"", "/"]},
+                        "documentSymbolProvider": true
+                    },
+                    "serverInfo": {
+                        "name": "zokrates-lsp",
+                        "version": env!("CARGO_PKG_VERSION")
+                    }
+                }))
+            }
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            "textDocument/hover" => {
+                let (path, position) = self.text_document_position(params)?;
+
+                Ok(self
+                    .index(&path)?
+                    .definition(&path, position)
+                    .map(|d| {
+                        json!({
+                            "contents": {
+                                "kind": "markdown",
+                                "value": format!("```zokrates\n{}\n```", d.detail)
+                            }
+                        })
+                    })
+                    .unwrap_or(Value::Null))
+            }
+            "textDocument/definition" => {
+                let (path, position) = self.text_document_position(params)?;
+
+                Ok(self
+                    .index(&path)?
+                    .definition(&path, position)
+                    .map(|d| {
+                        json!(Location {
+                            uri: uri::from_path(d.module),
+                            range: d.selection.to_utf16(&self.source(d.module))
+                        })
+                    })
+                    .unwrap_or(Value::Null))
+            }
+            "textDocument/completion" => {
+                let (path, position) = self.text_document_position(params)?;
+                let resolver = OverlayResolver::new(&self.documents, &self.stdlib_root_path);
+                let source = self.source(&path);
+
+                Ok(json!(self
+                    .index(&path)?
+                    .completion(&path, position, &resolver, &self.stdlib_root_path)
+                    .into_iter()
+                    .map(|item| item.into_utf16(&source))
+                    .collect::<Vec<_>>()))
+            }
+            "textDocument/documentSymbol" => {
+                let path = text_document(params)?;
+
+                let source = self.source(&path);
+
+                Ok(json!(self
+                    .index(&path)?
+                    .document_symbols(&path)
+                    .into_iter()
+                    .map(|symbol| symbol.into_utf16(&source))
+                    .collect::<Vec<_>>()))
+            }
+            _ => Err(ResponseError {
+                code: METHOD_NOT_FOUND,
+                message: format!("Unknown method {}", method),
+            }),
+        }
+    }
+
+    /// Handle a notification, returning the notifications to send back
+    fn notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let path = match text_document(params) {
+            Ok(path) => path,
+            Err(_) => return vec![],
+        };
+
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or("");
+                self.documents.insert(path.clone(), text.to_string());
+                self.publish_diagnostics(&path)
+            }
+            "textDocument/didChange" => {
+                // with full synchronisation, the last change holds the whole document
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+
+                match text {
+                    Some(text) => {
+                        self.documents.insert(path.clone(), text.to_string());
+                        self.publish_diagnostics(&path)
+                    }
+                    None => vec![],
+                }
+            }
+            "textDocument/didSave" => self.publish_diagnostics(&path),
+            "textDocument/didClose" => {
+                self.documents.remove(&path);
+
+                self.published
+                    .remove(&path)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|file| publish_diagnostics_notification(&file, vec![]))
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Check the program whose entry point is `path`, and publish the diagnostics found in each of its files. Files
+    /// which had diagnostics on the previous check and do not anymore have theirs cleared.
+    fn publish_diagnostics(&mut self, path: &Path) -> Vec<Value> {
+        let resolver = OverlayResolver::new(&self.documents, &self.stdlib_root_path);
+
+        let diagnostics = catch_panic(|| match self.curve {
+            Curve::Bn128 => analysis::diagnostics::<Bn128Field>(path, &resolver),
+            Curve::Bls12_381 => analysis::diagnostics::<Bls12_381Field>(path, &resolver),
+            Curve::Bls12_377 => analysis::diagnostics::<Bls12_377Field>(path, &resolver),
+            Curve::Bw6_761 => analysis::diagnostics::<Bw6_761Field>(path, &resolver),
+        })
+        .unwrap_or_else(|message| {
+            std::iter::once((path.to_path_buf(), vec![Diagnostic::error(message)])).collect()
+        });
+
+        let diagnostics: HashMap<PathBuf, Vec<Diagnostic>> = diagnostics
+            .into_iter()
+            .map(|(file, diagnostics)| {
+                let source = resolver.read(&file).unwrap_or_default();
+                let diagnostics = diagnostics
+                    .into_iter()
+                    .map(|d| Diagnostic {
+                        range: d.range.to_utf16(&source),
+                        ..d
+                    })
+                    .collect();
+                (file, diagnostics)
+            })
+            .collect();
+
+        let files: HashSet<PathBuf> = diagnostics.keys().cloned().collect();
+        let previous = self
+            .published
+            .insert(path.to_path_buf(), files.clone())
+            .unwrap_or_default();
+
+        let mut notifications: Vec<Value> = diagnostics
+            .into_iter()
+            .map(|(file, diagnostics)| publish_diagnostics_notification(&file, diagnostics))
+            .collect();
+
+        notifications.extend(
+            previous
+                .difference(&files)
+                .map(|file| publish_diagnostics_notification(file, vec![])),
+        );
+
+        notifications
+    }
+
+    /// The content of the document at `path`, to convert the positions exchanged with the client
+    fn source(&self, path: &Path) -> String {
+        OverlayResolver::new(&self.documents, &self.stdlib_root_path)
+            .read(path)
+            .unwrap_or_default()
+    }
+
+    fn text_document_position(&self, params: &Value) -> Result<(PathBuf, Position), ResponseError> {
+        let path = text_document(params)?;
+        let position: Position = serde_json::from_value(params["position"].clone())
+            .map_err(|_| ResponseError::invalid_params())?;
+        let position = position.to_chars(&self.source(&path));
+
+        Ok((path, position))
+    }
+
+    fn index(&self, path: &Path) -> Result<Index, ResponseError> {
+        fn build<T: Field>(server: &Server, path: &Path) -> Index {
+            let resolver = OverlayResolver::new(&server.documents, &server.stdlib_root_path);
+            Index::build::<T>(path, &resolver)
+        }
+
+        catch_panic(|| match self.curve {
+            Curve::Bn128 => build::<Bn128Field>(self, path),
+            Curve::Bls12_381 => build::<Bls12_381Field>(self, path),
+            Curve::Bls12_377 => build::<Bls12_377Field>(self, path),
+            Curve::Bw6_761 => build::<Bw6_761Field>(self, path),
+        })
+        .map_err(|message| ResponseError {
+            code: INTERNAL_ERROR,
+            message,
+        })
+    }
+}
+
+/// Run an analysis of the compiler, turning a panic into an error message so that the server keeps running
+fn catch_panic<R, F: FnOnce() -> R>(f: F) -> Result<R, String> {
+    // the analyses only read the state of the server, so it cannot be left inconsistent by a panic
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|payload| {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+
+        format!("The compiler unexpectedly panicked: {}", reason)
+    })
+}
+
+fn publish_diagnostics_notification(path: &Path, diagnostics: Vec<Diagnostic>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": {
+            "uri": uri::from_path(path),
+            "diagnostics": diagnostics
+        }
+    })
+}
+
+fn text_document(params: &Value) -> Result<PathBuf, ResponseError> {
+    params["textDocument"]["uri"]
+        .as_str()
+        .and_then(uri::to_path)
+        .ok_or_else(ResponseError::invalid_params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_method() {
+        let mut server = Server::new(PathBuf::from("/stdlib"));
+
+        let error = server.request("foo", &Value::Null).err().unwrap();
+        assert_eq!(error.code, METHOD_NOT_FOUND);
+
+        let error = server
+            .request("textDocument/hover", &json!({"textDocument": {}}))
+            .err()
+            .unwrap();
+        assert_eq!(error.code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn parse_error() {
+        let mut input = b"Content-Length: 5\r\n\r\n{foo}".to_vec();
+        write_message(&mut input, &json!({"jsonrpc": "2.0", "method": "exit"})).unwrap();
+
+        let mut server = Server::new(PathBuf::from("/stdlib"));
+        let mut output = vec![];
+
+        // the server answers the malformed message and keeps running until the `exit` notification
+        assert_eq!(server.run(&mut &input[..], &mut output).unwrap(), 1);
+
+        let response = read_message(&mut &output[..]).unwrap().unwrap().unwrap();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+    }
+
+    #[test]
+    fn ignore_responses() {
+        let mut input = vec![];
+        write_message(
+            &mut input,
+            &json!({"jsonrpc": "2.0", "id": 1, "result": Value::Null}),
+        )
+        .unwrap();
+        write_message(&mut input, &json!({"jsonrpc": "2.0", "method": "exit"})).unwrap();
+
+        let mut server = Server::new(PathBuf::from("/stdlib"));
+        let mut output = vec![];
+
+        assert_eq!(server.run(&mut &input[..], &mut output).unwrap(), 1);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn catch_compiler_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_panic::<(), _>(|| panic!("index out of bounds")),
+            Err("The compiler unexpectedly panicked: index out of bounds".to_string())
+        );
+    }
+
+    #[test]
+    fn clear_diagnostics() {
+        let mut server = Server::new(PathBuf::from("/stdlib"));
+        let uri = "file:///main.zok";
+
+        let notifications = server.notification(
+            "textDocument/didOpen",
+            &json!({"textDocument": {"uri": uri, "text": "def main() -> field:\n    return a\n"}}),
+        );
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0]["params"]["uri"], uri);
+        assert_eq!(
+            notifications[0]["params"]["diagnostics"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let notifications = server.notification(
+            "textDocument/didClose",
+            &json!({"textDocument": {"uri": uri}}),
+        );
+        assert_eq!(notifications.len(), 1);
+        assert!(notifications[0]["params"]["diagnostics"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+//! Conversion between `file` uris, used by the language server protocol, and paths
+
+use std::path::{Path, PathBuf};
+use url::Url;
+
+/// Returns the path of a `file` uri, or `None` for other schemes
+pub fn to_path(uri: &str) -> Option<PathBuf> {
+    Url::parse(uri).ok()?.to_file_path().ok()
+}
+
+/// Returns the `file` uri of a path, percent encoding the characters which are not allowed in uris. Relative paths,
+/// which uris cannot represent, are returned as they are.
+pub fn from_path(path: &Path) -> String {
+    Url::from_file_path(path)
+        .map(|uri| uri.to_string())
+        .unwrap_or_else(|_| path.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(windows))]
+    #[test]
+    fn round_trip() {
+        let path = PathBuf::from("/home/alice/my circuits/main.zok");
+        let uri = from_path(&path);
+
+        assert_eq!(uri, "file:///home/alice/my%20circuits/main.zok");
+        assert_eq!(to_path(&uri), Some(path));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn escaped_path() {
+        assert_eq!(
+            to_path("file:///home/alice/%C3%A9t%C3%A9%23%25/main.zok"),
+            Some(PathBuf::from("/home/alice/été#%/main.zok"))
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn drive_letter() {
+        assert_eq!(
+            to_path("file:///C:/Users/alice/my%20circuits/main.zok"),
+            Some(PathBuf::from(r"C:\Users\alice\my circuits\main.zok"))
+        );
+        assert_eq!(
+            from_path(Path::new(r"C:\Users\alice\main.zok")),
+            "file:///C:/Users/alice/main.zok"
+        );
+    }
+
+    #[test]
+    fn other_scheme() {
+        assert_eq!(to_path("untitled:Untitled-1"), None);
+    }
+}
@@ -0,0 +1,225 @@
+extern crate serde_json;
+
+#[cfg(test)]
+mod stdio {
+    use serde_json::{json, Value};
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::path::Path;
+    use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+    struct Client {
+        child: Child,
+        stdin: ChildStdin,
+        stdout: BufReader<ChildStdout>,
+        id: u64,
+    }
+
+    impl Client {
+        fn spawn() -> Self {
+            let mut child = Command::new(env!("CARGO_BIN_EXE_zokrates-lsp"))
+                .env(
+                    "ZOKRATES_STDLIB",
+                    concat!(env!("CARGO_MANIFEST_DIR"), "/../zokrates_stdlib/stdlib"),
+                )
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap();
+
+            let stdin = child.stdin.take().unwrap();
+            let stdout = BufReader::new(child.stdout.take().unwrap());
+
+            Client {
+                child,
+                stdin,
+                stdout,
+                id: 0,
+            }
+        }
+
+        fn send(&mut self, message: Value) {
+            let content = message.to_string();
+            write!(
+                self.stdin,
+                "Content-Length: {}\r\n\r\n{}",
+                content.len(),
+                content
+            )
+            .unwrap();
+            self.stdin.flush().unwrap();
+        }
+
+        fn receive(&mut self) -> Value {
+            let mut content_length = 0;
+
+            loop {
+                let mut header = String::new();
+                self.stdout.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+
+                if header.is_empty() {
+                    break;
+                }
+
+                if let Some(length) = header.strip_prefix("Content-Length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            self.stdout.read_exact(&mut content).unwrap();
+            serde_json::from_slice(&content).unwrap()
+        }
+
+        fn request(&mut self, method: &str, params: Value) -> Value {
+            self.id += 1;
+            let id = self.id;
+
+            self.send(json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params}));
+
+            // skip the notifications sent in the meantime
+            loop {
+                let message = self.receive();
+                if message["id"] == id {
+                    assert_eq!(message["error"], Value::Null, "{}", message);
+                    return message["result"].clone();
+                }
+            }
+        }
+
+        fn notify(&mut self, method: &str, params: Value) {
+            self.send(json!({"jsonrpc": "2.0", "method": method, "params": params}));
+        }
+
+        /// Wait for the diagnostics published for `uri`
+        fn diagnostics(&mut self, uri: &str) -> Vec<Value> {
+            loop {
+                let message = self.receive();
+                if message["method"] == "textDocument/publishDiagnostics"
+                    && message["params"]["uri"] == uri
+                {
+                    return message["params"]["diagnostics"].as_array().unwrap().clone();
+                }
+            }
+        }
+    }
+
+    fn uri(path: &Path) -> String {
+        format!("file://{}", path.display())
+    }
+
+    #[test]
+    fn session() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // the library is only on disk, the main module is an unsaved buffer
+        let lib = dir.path().join("lib.zok");
+        fs::write(&lib, "def double(field x) -> field:\n    return 2 * x\n").unwrap();
+
+        let main = uri(&dir.path().join("main.zok"));
+        let source = "from \"./lib\" import double\n\ndef main(field a) -> field:\n    field b = double(a)\n    return b + c\n";
+
+        let mut client = Client::spawn();
+
+        let result = client.request("initialize", json!({"capabilities": {}}));
+        assert_eq!(result["capabilities"]["hoverProvider"], json!(true));
+        client.notify("initialized", json!({}));
+
+        // diagnostics are published when a document is opened
+        client.notify(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": main, "languageId": "zokrates", "version": 1, "text": source}}),
+        );
+        let diagnostics = client.diagnostics(&main);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["message"], "Identifier \"c\" is undefined");
+        assert_eq!(
+            diagnostics[0]["range"]["start"],
+            json!({"line": 4, "character": 15})
+        );
+
+        // hover shows the type of a variable
+        let hover = client.request(
+            "textDocument/hover",
+            json!({"textDocument": {"uri": main}, "position": {"line": 4, "character": 11}}),
+        );
+        assert!(hover["contents"]["value"]
+            .as_str()
+            .unwrap()
+            .contains("field b"));
+
+        // go to the definition of an imported function
+        let definition = client.request(
+            "textDocument/definition",
+            json!({"textDocument": {"uri": main}, "position": {"line": 3, "character": 15}}),
+        );
+        assert_eq!(definition["uri"], uri(&lib));
+        assert_eq!(
+            definition["range"],
+            json!({"start": {"line": 0, "character": 4}, "end": {"line": 0, "character": 10}})
+        );
+
+        // fixing the error clears the diagnostics
+        client.notify(
+            "textDocument/didChange",
+            json!({
+                "textDocument": {"uri": main, "version": 2},
+                "contentChanges": [{"text": source.replace("b + c", "b + a")}]
+            }),
+        );
+        assert!(client.diagnostics(&main).is_empty());
+
+        let symbols = client.request(
+            "textDocument/documentSymbol",
+            json!({"textDocument": {"uri": main}}),
+        );
+        let names: Vec<&str> = symbols
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["double", "main"]);
+
+        // variables and symbols are completed in a function
+        let completion = client.request(
+            "textDocument/completion",
+            json!({"textDocument": {"uri": main}, "position": {"line": 4, "character": 11}}),
+        );
+        let labels: Vec<&str> = completion
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["label"].as_str().unwrap())
+            .collect();
+        assert!(labels.contains(&"a"));
+        assert!(labels.contains(&"b"));
+        assert!(labels.contains(&"double"));
+
+        // modules of the standard library are completed in imports
+        client.notify(
+            "textDocument/didChange",
+            json!({
+                "textDocument": {"uri": main, "version": 3},
+                "contentChanges": [{"text": format!("from \"utils/pack/\n{}", source)}]
+            }),
+        );
+        client.diagnostics(&main);
+
+        let completion = client.request(
+            "textDocument/completion",
+            json!({"textDocument": {"uri": main}, "position": {"line": 0, "character": 17}}),
+        );
+        assert!(completion
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|i| i["label"] == "utils/pack/bool/pack256"));
+
+        client.request("shutdown", Value::Null);
+        client.notify("exit", Value::Null);
+
+        assert_eq!(client.child.wait().unwrap().code(), Some(0));
+    }
+}
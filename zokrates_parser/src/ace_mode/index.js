@@ -37,7 +37,7 @@ ace.define("ace/mode/zokrates_highlight_rules",["require","exports","module","ac
     var ZoKratesHighlightRules = function () {
 
         var keywords = (
-            "assert|as|bool|byte|const|def|do|else|endfor|export|false|field|for|if|then|fi|import|from|in|private|public|return|struct|true|u8|u16|u32|u64"
+            "assert|as|bool|byte|const|def|do|else|endfor|export|false|field|for|if|then|fi|import|impl|from|in|mut|private|public|return|struct|true|type|u8|u16|u32|u64|u128"
         );
 
         var keywordMapper = this.createKeywordMapper({
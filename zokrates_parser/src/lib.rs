@@ -4,9 +4,10 @@ extern crate pest;
 #[macro_use]
 extern crate pest_derive;
 
-use pest::error::Error;
+use pest::error::{Error, LineColLocation};
 use pest::iterators::Pairs;
 use pest::Parser;
+use std::ops::Range;
 
 #[derive(Parser)]
 #[grammar = "zokrates.pest"]
@@ -16,6 +17,160 @@ pub fn parse(input: &str) -> Result<Pairs<Rule>, Error<Rule>> {
     ZoKratesParser::parse(Rule::file, input)
 }
 
+/// The keywords which start a declaration, or a pragma
+const DECLARATION_KEYWORDS: [&str; 10] = [
+    "import", "from", "struct", "enum", "type", "const", "def", "impl", "pub", "#pragma",
+];
+
+/// Find all syntax errors of `input`. When a syntax error is found, the statement it is in is blanked out, or the whole
+/// declaration if that does not help, and parsing starts over. Return the errors along with the source in which the
+/// code which could not be parsed is blanked out, if this source can be parsed. Positions in this source are the
+/// same as in `input`.
+///
+/// Declarations are expected to start at the beginning of a line and statements to start on their own line, which
+/// is how programs are usually formatted.
+pub fn parse_with_recovery(input: &str) -> (Vec<Error<Rule>>, Option<String>) {
+    let mut lines: Vec<String> = input.split('\n').map(String::from).collect();
+    let mut errors = vec![];
+
+    loop {
+        let error = match parse(&lines.join("\n")) {
+            Ok(_) => return (errors, Some(lines.join("\n"))),
+            Err(e) => e,
+        };
+
+        let line = std::cmp::min(error_line(&error), lines.len() - 1);
+        let declaration = declaration_range(&lines, line);
+
+        // blank out the statement first, and keep it that way if the declaration it is in can be parsed up to the
+        // end of the statement. An error on a line closing a block means that the statement was opening a block
+        // which is not fully blanked out, so we blank out the whole declaration instead
+        let recovered = statement_range(&lines, line, &declaration).and_then(|statement| {
+            let attempt = blank(&lines, &statement);
+            match parse(&attempt.join("\n")) {
+                Ok(_) => Some(attempt),
+                Err(e) => {
+                    let next_line = std::cmp::min(error_line(&e), lines.len() - 1);
+                    match !declaration.contains(&next_line)
+                        || (next_line >= statement.end && block_depth_delta(&lines[next_line]) >= 0)
+                    {
+                        true => Some(attempt),
+                        false => None,
+                    }
+                }
+            }
+        });
+
+        let recovered = recovered.unwrap_or_else(|| blank(&lines, &declaration));
+
+        errors.push(error);
+
+        // if blanking out the code did not change anything, we cannot recover
+        if recovered == lines {
+            return (errors, None);
+        }
+
+        lines = recovered;
+    }
+}
+
+/// The zero-based line of `error`
+fn error_line(error: &Error<Rule>) -> usize {
+    match error.line_col {
+        LineColLocation::Pos((line, _)) => line - 1,
+        LineColLocation::Span((line, _), _) => line - 1,
+    }
+}
+
+fn starts_declaration(line: &str) -> bool {
+    let word: String = line
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '#')
+        .collect();
+    DECLARATION_KEYWORDS.contains(&word.as_str())
+}
+
+/// The lines of the declaration which contains `line`. Code before the first declaration is considered a declaration
+fn declaration_range(lines: &[String], line: usize) -> Range<usize> {
+    let start = (0..=line)
+        .rev()
+        .find(|i| starts_declaration(&lines[*i]))
+        .unwrap_or(0);
+
+    let end = (start + 1..lines.len())
+        .find(|i| starts_declaration(&lines[*i]))
+        .unwrap_or(lines.len());
+
+    start..end
+}
+
+/// The lines of the statement starting at `line` in `declaration`, including the blocks it opens. There is none if
+/// `line` is the first line of the declaration
+fn statement_range(
+    lines: &[String],
+    line: usize,
+    declaration: &Range<usize>,
+) -> Option<Range<usize>> {
+    if line == declaration.start {
+        return None;
+    }
+
+    let mut depth = 0;
+
+    let end = (line..declaration.end)
+        .find(|i| {
+            depth += block_depth_delta(&lines[*i]);
+            depth <= 0
+        })
+        .map(|i| i + 1)
+        .unwrap_or(declaration.end);
+
+    Some(line..end)
+}
+
+/// The number of blocks opened minus the number of blocks closed on `line`
+fn block_depth_delta(line: &str) -> isize {
+    // ignore comments and strings
+    let line = line.split("//").next().unwrap();
+    let line: String = line.split('"').step_by(2).collect::<Vec<_>>().join(" ");
+
+    let braces = line
+        .chars()
+        .map(|c| match c {
+            '{' => 1,
+            '}' => -1,
+            _ => 0,
+        })
+        .sum::<isize>();
+
+    let loops = line
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map(|word| match word {
+            "do" => 1,
+            "endfor" => -1,
+            _ => 0,
+        })
+        .sum::<isize>();
+
+    braces + loops
+}
+
+/// A copy of `lines` where the characters of the lines in `range` are replaced with spaces, so that positions in
+/// other lines are preserved
+fn blank(lines: &[String], range: &Range<usize>) -> Vec<String> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| match range.contains(&i) {
+            true => line
+                .chars()
+                .map(|c| if c == '\r' { c } else { ' ' })
+                .collect(),
+            false => line.clone(),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -332,5 +487,221 @@ mod tests {
             let parse = ZoKratesParser::parse(Rule::iteration_statement, input);
             assert!(parse.is_ok());
         }
+
+        #[test]
+        fn parse_if_else_statement() {
+            let input = "if a == 1 {\n b = 1\n } else if a == 2 {\n b = 2\n } else {\n b = 3\n }";
+
+            let parse = ZoKratesParser::parse(Rule::if_else_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_if_statement_without_else() {
+            let input = "if a == 1 {\n b = 1\n }";
+
+            let parse = ZoKratesParser::parse(Rule::if_else_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_if_statement_with_empty_block() {
+            for &input in &["if c {}", "if c { }", "if c {} else {}", "if a == b {\n}"] {
+                let parse = ZoKratesParser::parse(Rule::if_else_statement, input);
+                assert!(parse.is_ok());
+                let parse = parse.unwrap();
+                assert_eq!(parse.as_str(), input);
+                // the block is not parsed as the members of an inline struct
+                assert!(parse
+                    .flatten()
+                    .all(|p| p.as_rule() != Rule::inline_struct_expression));
+            }
+        }
+
+        #[test]
+        fn parse_if_statement_with_identifier_condition() {
+            for &(input, condition) in &[
+                ("if c {\n b = 1\n }", "c"),
+                ("if !c {\n b = 1\n } else {\n b = 2\n }", "!c"),
+                ("if a == b {\n b = 1\n }", "a == b"),
+                // inline structs are allowed within delimiters
+                (
+                    "if (p == Point { x: 1 }) {\n b = 1\n }",
+                    "(p == Point { x: 1 }) ",
+                ),
+            ] {
+                let parse = ZoKratesParser::parse(Rule::if_else_statement, input).unwrap();
+                assert_eq!(parse.as_str(), input);
+                let pair = parse
+                    .flatten()
+                    .find(|p| p.as_rule() == Rule::condition_expression)
+                    .unwrap();
+                assert_eq!(pair.as_str().trim_end(), condition.trim_end());
+            }
+        }
+
+        #[test]
+        fn parse_tuple_type() {
+            let input = "(field, bool[2], (u32,))";
+
+            let parse = ZoKratesParser::parse(Rule::ty, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_inline_tuple_and_element_access() {
+            let input = "(field, bool) t = (a.0, (b,))";
+
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_assertion_with_message() {
+            let input = r#"assert(a == 1, "a should be one")"#;
+
+            let parse = ZoKratesParser::parse(Rule::expression_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_enum_definition() {
+            let input = "enum Message {\n Ping,\n Transfer(field, u32[2]),\n}\n";
+
+            let parse = ZoKratesParser::parse(Rule::ty_enum_definition, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_impl_definition() {
+            let input = "impl Point {\n def add(self, Point other) -> Point:\n return self\n}\n";
+
+            let parse = ZoKratesParser::parse(Rule::impl_definition, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_mut_bindings() {
+            let input = "#pragma curve bn128\n#pragma immutable_by_default\ndef main(field mut a) -> field:\n field[2] mut b = [a, a]\n b[0] = 1\n return b[0]\n";
+
+            let parse = ZoKratesParser::parse(Rule::file, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_string_literals() {
+            let input = r#"u8[8] s = [...b"a\x00", ..."\\\"", ...b"\n\r\t\0"]"#;
+
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_array_comprehensions() {
+            let input = "field[2][3] m = [[x[i] * y[j] for u32 j in 0..3] for u32 i in 0..N]";
+
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_enum_value_and_match() {
+            let input = "field a = match Message::Transfer(1, [2, 3]) {\n Message::Ping => 0,\n Message::Transfer(x, _) => x,\n _ => 1\n }";
+
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_type_parameter() {
+            let input = "def first<type T, N>(T[N] a) -> T:\n return a[0]\n";
+
+            let parse = ZoKratesParser::parse(Rule::function_definition, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_number_literals() {
+            let input =
+                "field[6] a = [1_000_000, 1_000u32, 0xdead_beef, 0x1_u8, 0b1010_0110, 0b1f]";
+
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert!(parse.is_ok());
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_number_literals_with_misplaced_separator() {
+            assert!(ZoKratesParser::parse(Rule::hex_literal, "0x_ff").is_err());
+            assert!(ZoKratesParser::parse(Rule::binary_literal, "0b_1").is_err());
+        }
+    }
+
+    mod recovery {
+        use super::*;
+
+        #[test]
+        fn errors_in_several_declarations() {
+            let input = "def foo() -> field:\n    field a = 1 +\n    return 1\n\ndef main() -> field:\n    field b = (2\n    return 2\n";
+
+            let (errors, source) = parse_with_recovery(input);
+
+            assert_eq!(
+                errors.iter().map(error_line).collect::<Vec<_>>(),
+                vec![1, 5]
+            );
+            // only the erroneous statements are blanked out
+            assert_eq!(
+                source.unwrap(),
+                "def foo() -> field:\n                 \n    return 1\n\ndef main() -> field:\n                \n    return 2\n"
+            );
+        }
+
+        #[test]
+        fn error_opening_a_block() {
+            // the loop is not recognized, so `endfor` cannot be parsed once the first line is blanked out
+            let input = "def main() -> field:\n    for u32 i in 0..3 d\n        field a = 1\n    endfor\n    return 1\n";
+
+            let (errors, source) = parse_with_recovery(input);
+
+            assert_eq!(errors.len(), 1);
+            assert!(source.unwrap().trim().is_empty());
+        }
+
+        #[test]
+        fn error_in_block() {
+            // the statement in the loop is blanked out
+            let input = "def main() -> field:\n    for u32 i in 0..3 do\n        field a = 1 *\n    endfor\n    return 1\n";
+
+            let (errors, source) = parse_with_recovery(input);
+
+            assert_eq!(errors.len(), 1);
+            assert_eq!(
+                source.unwrap(),
+                "def main() -> field:\n    for u32 i in 0..3 do\n                     \n    endfor\n    return 1\n"
+            );
+        }
+
+        #[test]
+        fn no_error() {
+            let input = "def main() -> field:\n    return 1\n";
+
+            assert_eq!(
+                parse_with_recovery(input),
+                (vec![], Some(input.to_string()))
+            );
+        }
     }
 }
@@ -1,23 +1,30 @@
 use from_pest::FromPest;
-use pest::error::Error as PestError;
+use pest::error::{Error as PestError, ErrorVariant, LineColLocation};
 use pest::iterators::Pairs;
 use std::fmt;
-use zokrates_parser::parse;
 use zokrates_parser::Rule;
+use zokrates_parser::{parse, parse_with_recovery};
 #[macro_use]
 extern crate lazy_static;
 
 pub use ast::{
-    Access, Arguments, ArrayAccess, ArrayInitializerExpression, ArrayType, AssertionStatement,
-    Assignee, AssigneeAccess, BasicOrStructType, BasicType, BinaryExpression, BinaryOperator,
-    CallAccess, ConstantDefinition, ConstantGenericValue, DecimalLiteralExpression, DecimalNumber,
-    DecimalSuffix, DefinitionStatement, ExplicitGenerics, Expression, FieldType, File,
-    FromExpression, FunctionDefinition, HexLiteralExpression, HexNumberExpression,
-    IdentifierExpression, ImportDirective, ImportSource, ImportSymbol, InlineArrayExpression,
-    InlineStructExpression, InlineStructMember, IterationStatement, LiteralExpression, Parameter,
-    PostfixExpression, Range, RangeOrExpression, ReturnStatement, Span, Spread, SpreadOrExpression,
-    Statement, StructDefinition, StructField, SymbolDeclaration, TernaryExpression, ToExpression,
-    Type, TypedIdentifier, TypedIdentifierOrAssignee, UnaryExpression, UnaryOperator, Underscore,
+    Access, AnyString, Arguments, ArrayAccess, ArrayComprehensionExpression,
+    ArrayInitializerExpression, ArrayType, AssertionStatement, Assignee, AssigneeAccess,
+    BasicOrStructOrTupleType, BasicType, BinaryExpression, BinaryLiteralExpression, BinaryNumber,
+    BinaryOperator, BlockStatement, ByteStringPrefix, CallAccess, CastExpression,
+    ConstantDefinition, ConstantGenericValue, CurvePragma, DecimalLiteralExpression, DecimalNumber,
+    DecimalSuffix, DefinitionStatement, ElementAccess, ElseStatement, EnumDefinition,
+    EnumValueExpression, EnumVariant, ExplicitGenerics, Expression, FieldType, File,
+    FromExpression, FunctionDefinition, GenericParameter, HexLiteralExpression, HexNumber,
+    IdentifierExpression, IfElseStatement, ImmutableByDefaultPragma, ImplDefinition,
+    ImportDirective, ImportGlob, ImportSource, ImportSymbol, InlineArrayExpression,
+    InlineStructExpression, InlineStructMember, InlineTupleExpression, IterationStatement,
+    LiteralExpression, MatchArm, MatchExpression, MethodDefinition, MutModifier, Parameter,
+    Pattern, PatternBinding, PostfixExpression, Pragma, PubModifier, Range, RangeOrExpression,
+    ReturnStatement, SelfParameter, Span, Spread, SpreadOrExpression, Statement, StringContent,
+    StringLiteralExpression, StructDefinition, StructField, SymbolDeclaration, TernaryExpression,
+    ToExpression, TupleType, Type, TypeDefinition, TypeParameter, TypedIdentifier,
+    TypedIdentifierOrAssignee, UnaryExpression, UnaryOperator, Underscore, VariantPattern,
     Visibility,
 };
 
@@ -50,7 +57,9 @@ mod ast {
             Operator::new(Rule::op_bit_xor, Assoc::Left),
             Operator::new(Rule::op_bit_and, Assoc::Left),
             Operator::new(Rule::op_left_shift, Assoc::Left)
-                | Operator::new(Rule::op_right_shift, Assoc::Left),
+                | Operator::new(Rule::op_right_shift, Assoc::Left)
+                | Operator::new(Rule::op_left_rotate, Assoc::Left)
+                | Operator::new(Rule::op_right_rotate, Assoc::Left),
             Operator::new(Rule::op_add, Assoc::Left) | Operator::new(Rule::op_sub, Assoc::Left),
             Operator::new(Rule::op_mul, Assoc::Left)
                 | Operator::new(Rule::op_div, Assoc::Left)
@@ -89,6 +98,10 @@ mod ast {
             Rule::op_bit_or => Expression::binary(BinaryOperator::BitOr, lhs, rhs, span),
             Rule::op_right_shift => Expression::binary(BinaryOperator::RightShift, lhs, rhs, span),
             Rule::op_left_shift => Expression::binary(BinaryOperator::LeftShift, lhs, rhs, span),
+            Rule::op_right_rotate => {
+                Expression::binary(BinaryOperator::RightRotate, lhs, rhs, span)
+            }
+            Rule::op_left_rotate => Expression::binary(BinaryOperator::LeftRotate, lhs, rhs, span),
             _ => unreachable!(),
         })
     }
@@ -98,18 +111,22 @@ mod ast {
         PREC_CLIMBER.climb(pair.into_inner(), build_factor, infix_rule)
     }
 
-    // Create an Expression from a `unaried_term`.
-    // Precondition: `pair` MUST be a `unaried_term`
+    // Create an Expression from a `unaried_term` or a `condition_unaried_term`.
+    // Precondition: `pair` MUST be a `unaried_term` or a `condition_unaried_term`
     fn build_factor(pair: Pair<Rule>) -> Box<Expression> {
-        Box::new(Expression::from(
-            UnariedTerm::from_pest(&mut Pairs::single(pair)).unwrap(),
-        ))
+        let term = match pair.as_rule() {
+            Rule::condition_unaried_term => UnariedTerm::from(
+                ConditionUnariedTerm::from_pest(&mut Pairs::single(pair)).unwrap(),
+            ),
+            _ => UnariedTerm::from_pest(&mut Pairs::single(pair)).unwrap(),
+        };
+        Box::new(Expression::from(term))
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::file))]
     pub struct File<'ast> {
-        pub pragma: Option<Pragma<'ast>>,
+        pub pragmas: Vec<Pragma<'ast>>,
         pub declarations: Vec<SymbolDeclaration<'ast>>,
         pub eoi: EOI,
         #[pest_ast(outer())]
@@ -118,12 +135,26 @@ mod ast {
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::pragma))]
-    pub struct Pragma<'ast> {
+    pub enum Pragma<'ast> {
+        Curve(CurvePragma<'ast>),
+        ImmutableByDefault(ImmutableByDefaultPragma<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::curve_pragma))]
+    pub struct CurvePragma<'ast> {
         pub curve: Curve<'ast>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::immutable_by_default_pragma))]
+    pub struct ImmutableByDefaultPragma<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::curve))]
     pub struct Curve<'ast> {
@@ -140,14 +171,26 @@ mod ast {
         Import(ImportDirective<'ast>),
         Constant(ConstantDefinition<'ast>),
         Struct(StructDefinition<'ast>),
+        Enum(EnumDefinition<'ast>),
+        Type(TypeDefinition<'ast>),
         Function(FunctionDefinition<'ast>),
+        Impl(ImplDefinition<'ast>),
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::pub_modifier))]
+    pub struct PubModifier {}
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::mut_modifier))]
+    pub struct MutModifier {}
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_struct_definition))]
     pub struct StructDefinition<'ast> {
+        pub public: Option<PubModifier>,
         pub id: IdentifierExpression<'ast>,
-        pub generics: Vec<IdentifierExpression<'ast>>,
+        pub generics: Vec<GenericParameter<'ast>>,
         pub fields: Vec<StructField<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
@@ -162,11 +205,31 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_enum_definition))]
+    pub struct EnumDefinition<'ast> {
+        pub public: Option<PubModifier>,
+        pub id: IdentifierExpression<'ast>,
+        pub variants: Vec<EnumVariant<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::enum_variant))]
+    pub struct EnumVariant<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub types: Vec<Type<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::function_definition))]
     pub struct FunctionDefinition<'ast> {
+        pub public: Option<PubModifier>,
         pub id: IdentifierExpression<'ast>,
-        pub generics: Vec<IdentifierExpression<'ast>>,
+        pub generics: Vec<GenericParameter<'ast>>,
         pub parameters: Vec<Parameter<'ast>>,
         pub returns: Vec<Type<'ast>>,
         pub statements: Vec<Statement<'ast>>,
@@ -174,9 +237,62 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    pub enum GenericParameter<'ast> {
+        Type(TypeParameter<'ast>),
+        Constant(IdentifierExpression<'ast>),
+    }
+
+    impl<'ast> GenericParameter<'ast> {
+        pub fn span(&self) -> &Span<'ast> {
+            match self {
+                GenericParameter::Type(t) => &t.span,
+                GenericParameter::Constant(c) => &c.span,
+            }
+        }
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::type_parameter))]
+    pub struct TypeParameter<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::impl_definition))]
+    pub struct ImplDefinition<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub methods: Vec<MethodDefinition<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::method_definition))]
+    pub struct MethodDefinition<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub generics: Vec<GenericParameter<'ast>>,
+        pub receiver: SelfParameter<'ast>,
+        pub parameters: Vec<Parameter<'ast>>,
+        pub returns: Vec<Type<'ast>>,
+        pub statements: Vec<Statement<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::self_parameter))]
+    pub struct SelfParameter<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::const_definition))]
     pub struct ConstantDefinition<'ast> {
+        pub public: Option<PubModifier>,
         pub ty: Type<'ast>,
         pub id: IdentifierExpression<'ast>,
         pub expression: Expression<'ast>,
@@ -184,6 +300,17 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::type_definition))]
+    pub struct TypeDefinition<'ast> {
+        pub public: Option<PubModifier>,
+        pub id: IdentifierExpression<'ast>,
+        pub generics: Vec<GenericParameter<'ast>>,
+        pub ty: Type<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::import_directive))]
     pub enum ImportDirective<'ast> {
@@ -209,10 +336,16 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::import_glob))]
+    pub struct ImportGlob {}
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::from_import_directive))]
     pub struct FromImportDirective<'ast> {
+        pub public: Option<PubModifier>,
         pub source: ImportSource<'ast>,
+        pub glob: Option<ImportGlob>,
         pub symbols: Vec<ImportSymbol<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
@@ -233,6 +366,7 @@ mod ast {
         Basic(BasicType<'ast>),
         Array(ArrayType<'ast>),
         Struct(StructType<'ast>),
+        Tuple(TupleType<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -244,6 +378,11 @@ mod ast {
         U16(U16Type<'ast>),
         U32(U32Type<'ast>),
         U64(U64Type<'ast>),
+        U128(U128Type<'ast>),
+        I8(I8Type<'ast>),
+        I16(I16Type<'ast>),
+        I32(I32Type<'ast>),
+        I64(I64Type<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -256,17 +395,18 @@ mod ast {
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_array))]
     pub struct ArrayType<'ast> {
-        pub ty: BasicOrStructType<'ast>,
+        pub ty: BasicOrStructOrTupleType<'ast>,
         pub dimensions: Vec<Expression<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::ty_basic_or_struct))]
-    pub enum BasicOrStructType<'ast> {
+    #[pest_ast(rule(Rule::ty_basic_or_struct_or_tuple))]
+    pub enum BasicOrStructOrTupleType<'ast> {
         Struct(StructType<'ast>),
         Basic(BasicType<'ast>),
+        Tuple(TupleType<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -304,6 +444,41 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_u128))]
+    pub struct U128Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i8))]
+    pub struct I8Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i16))]
+    pub struct I16Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i32))]
+    pub struct I32Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i64))]
+    pub struct I64Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_struct))]
     pub struct StructType<'ast> {
@@ -313,11 +488,20 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_tuple))]
+    pub struct TupleType<'ast> {
+        pub elements: Vec<Type<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::parameter))]
     pub struct Parameter<'ast> {
         pub visibility: Option<Visibility>,
         pub ty: Type<'ast>,
+        pub mutable: Option<MutModifier>,
         pub id: IdentifierExpression<'ast>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
@@ -346,6 +530,7 @@ mod ast {
         Definition(DefinitionStatement<'ast>),
         Assertion(AssertionStatement<'ast>),
         Iteration(IterationStatement<'ast>),
+        IfElse(IfElseStatement<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -361,6 +546,16 @@ mod ast {
     #[pest_ast(rule(Rule::expression_statement))]
     pub struct AssertionStatement<'ast> {
         pub expression: Expression<'ast>,
+        pub message: Option<AnyString<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::quoted_string))]
+    pub struct AnyString<'ast> {
+        #[pest_ast(outer(with(span_into_unquoted_str)))]
+        pub value: String,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
@@ -377,6 +572,31 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::if_else_statement))]
+    pub struct IfElseStatement<'ast> {
+        pub condition: Expression<'ast>,
+        pub consequence: BlockStatement<'ast>,
+        pub alternative: Option<ElseStatement<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::else_statement))]
+    pub enum ElseStatement<'ast> {
+        IfElse(Box<IfElseStatement<'ast>>),
+        Block(BlockStatement<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::block_statement))]
+    pub struct BlockStatement<'ast> {
+        pub statements: Vec<Statement<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::return_statement))]
     pub struct ReturnStatement<'ast> {
@@ -392,6 +612,8 @@ mod ast {
         BitOr,
         RightShift,
         LeftShift,
+        RightRotate,
+        LeftRotate,
         Or,
         And,
         Add,
@@ -413,24 +635,33 @@ mod ast {
         Ternary(TernaryExpression<'ast>),
         Binary(BinaryExpression<'ast>),
         Unary(UnaryExpression<'ast>),
+        Cast(CastExpression<'ast>),
         Postfix(PostfixExpression<'ast>),
         Identifier(IdentifierExpression<'ast>),
         Literal(LiteralExpression<'ast>),
         InlineArray(InlineArrayExpression<'ast>),
         InlineStruct(InlineStructExpression<'ast>),
+        InlineTuple(InlineTupleExpression<'ast>),
         ArrayInitializer(ArrayInitializerExpression<'ast>),
+        ArrayComprehension(ArrayComprehensionExpression<'ast>),
+        Match(MatchExpression<'ast>),
+        EnumValue(EnumValueExpression<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::term))]
     pub enum Term<'ast> {
         Expression(Expression<'ast>),
+        InlineTuple(InlineTupleExpression<'ast>),
         InlineStruct(InlineStructExpression<'ast>),
         Ternary(TernaryExpression<'ast>),
+        Match(MatchExpression<'ast>),
+        EnumValue(EnumValueExpression<'ast>),
         Postfix(PostfixExpression<'ast>),
         Primary(PrimaryExpression<'ast>),
         InlineArray(InlineArrayExpression<'ast>),
         ArrayInitializer(ArrayInitializerExpression<'ast>),
+        ArrayComprehension(ArrayComprehensionExpression<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -468,22 +699,43 @@ mod ast {
     struct UnariedTerm<'ast> {
         op: Option<UnaryOperator>,
         expression: PoweredTerm<'ast>,
+        casts: Vec<Cast<'ast>>,
+        #[pest_ast(outer())]
+        span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::cast))]
+    struct Cast<'ast> {
+        ty: Type<'ast>,
         #[pest_ast(outer())]
         span: Span<'ast>,
     }
 
     impl<'ast> From<UnariedTerm<'ast>> for Expression<'ast> {
         fn from(t: UnariedTerm<'ast>) -> Self {
+            let start = t.span.start_pos();
+            let end = t.expression.span.end_pos();
+
             let expression = Expression::from(t.expression);
 
-            match t.op {
+            // the unary operator binds tighter than the casts, so that `-a as u32` is `(-a) as u32`
+            let expression = match t.op {
                 Some(sign) => Expression::Unary(UnaryExpression {
                     op: sign,
                     expression: Box::new(expression),
-                    span: t.span,
+                    span: start.span(&end),
                 }),
                 None => expression,
-            }
+            };
+
+            t.casts.into_iter().fold(expression, |expression, cast| {
+                Expression::Cast(CastExpression {
+                    expression: Box::new(expression),
+                    ty: cast.ty,
+                    span: start.span(&cast.span.end_pos()),
+                })
+            })
         }
     }
 
@@ -516,7 +768,86 @@ mod ast {
                 Term::Primary(e) => e.into(),
                 Term::InlineArray(e) => Expression::InlineArray(e),
                 Term::InlineStruct(e) => Expression::InlineStruct(e),
+                Term::InlineTuple(e) => Expression::InlineTuple(e),
                 Term::ArrayInitializer(e) => Expression::ArrayInitializer(e),
+                Term::ArrayComprehension(e) => Expression::ArrayComprehension(e),
+                Term::Match(e) => Expression::Match(e),
+                Term::EnumValue(e) => Expression::EnumValue(e),
+            }
+        }
+    }
+
+    // the terms of the condition of an `if` statement, which are terms other than inline structs
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::condition_term))]
+    enum ConditionTerm<'ast> {
+        Expression(Expression<'ast>),
+        InlineTuple(InlineTupleExpression<'ast>),
+        Ternary(TernaryExpression<'ast>),
+        Match(MatchExpression<'ast>),
+        EnumValue(EnumValueExpression<'ast>),
+        Postfix(PostfixExpression<'ast>),
+        Primary(PrimaryExpression<'ast>),
+        InlineArray(InlineArrayExpression<'ast>),
+        ArrayInitializer(ArrayInitializerExpression<'ast>),
+        ArrayComprehension(ArrayComprehensionExpression<'ast>),
+    }
+
+    impl<'ast> From<ConditionTerm<'ast>> for Term<'ast> {
+        fn from(t: ConditionTerm<'ast>) -> Self {
+            match t {
+                ConditionTerm::Expression(e) => Term::Expression(e),
+                ConditionTerm::InlineTuple(e) => Term::InlineTuple(e),
+                ConditionTerm::Ternary(e) => Term::Ternary(e),
+                ConditionTerm::Match(e) => Term::Match(e),
+                ConditionTerm::EnumValue(e) => Term::EnumValue(e),
+                ConditionTerm::Postfix(e) => Term::Postfix(e),
+                ConditionTerm::Primary(e) => Term::Primary(e),
+                ConditionTerm::InlineArray(e) => Term::InlineArray(e),
+                ConditionTerm::ArrayInitializer(e) => Term::ArrayInitializer(e),
+                ConditionTerm::ArrayComprehension(e) => Term::ArrayComprehension(e),
+            }
+        }
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::condition_powered_term))]
+    struct ConditionPoweredTerm<'ast> {
+        base: ConditionTerm<'ast>,
+        op: Option<PowOperator>,
+        exponent: Option<ExponentExpression<'ast>>,
+        #[pest_ast(outer())]
+        span: Span<'ast>,
+    }
+
+    impl<'ast> From<ConditionPoweredTerm<'ast>> for PoweredTerm<'ast> {
+        fn from(t: ConditionPoweredTerm<'ast>) -> Self {
+            PoweredTerm {
+                base: t.base.into(),
+                op: t.op,
+                exponent: t.exponent,
+                span: t.span,
+            }
+        }
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::condition_unaried_term))]
+    struct ConditionUnariedTerm<'ast> {
+        op: Option<UnaryOperator>,
+        expression: ConditionPoweredTerm<'ast>,
+        casts: Vec<Cast<'ast>>,
+        #[pest_ast(outer())]
+        span: Span<'ast>,
+    }
+
+    impl<'ast> From<ConditionUnariedTerm<'ast>> for UnariedTerm<'ast> {
+        fn from(t: ConditionUnariedTerm<'ast>) -> Self {
+            UnariedTerm {
+                op: t.op,
+                expression: t.expression.into(),
+                casts: t.casts,
+                span: t.span,
             }
         }
     }
@@ -627,6 +958,66 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::inline_tuple_expression))]
+    pub struct InlineTupleExpression<'ast> {
+        pub elements: Vec<Expression<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::match_expression))]
+    pub struct MatchExpression<'ast> {
+        pub expression: Box<Expression<'ast>>,
+        pub arms: Vec<MatchArm<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::match_arm))]
+    pub struct MatchArm<'ast> {
+        pub pattern: Pattern<'ast>,
+        pub expression: Expression<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::pattern))]
+    pub enum Pattern<'ast> {
+        Wildcard(Underscore<'ast>),
+        Variant(VariantPattern<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::variant_pattern))]
+    pub struct VariantPattern<'ast> {
+        pub ty: IdentifierExpression<'ast>,
+        pub variant: IdentifierExpression<'ast>,
+        pub bindings: Vec<PatternBinding<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::pattern_binding))]
+    pub enum PatternBinding<'ast> {
+        Wildcard(Underscore<'ast>),
+        Identifier(IdentifierExpression<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::enum_value_expression))]
+    pub struct EnumValueExpression<'ast> {
+        pub ty: IdentifierExpression<'ast>,
+        pub variant: IdentifierExpression<'ast>,
+        pub arguments: Option<Arguments<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::array_initializer_expression))]
     pub struct ArrayInitializerExpression<'ast> {
@@ -636,6 +1027,18 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::array_comprehension_expression))]
+    pub struct ArrayComprehensionExpression<'ast> {
+        pub expression: Box<Expression<'ast>>,
+        pub ty: Type<'ast>,
+        pub index: IdentifierExpression<'ast>,
+        pub from: Box<Expression<'ast>>,
+        pub to: Box<Expression<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::typed_identifier_or_assignee))]
     pub enum TypedIdentifierOrAssignee<'ast> {
@@ -647,6 +1050,7 @@ mod ast {
     #[pest_ast(rule(Rule::typed_identifier))]
     pub struct TypedIdentifier<'ast> {
         pub ty: Type<'ast>,
+        pub mutable: Option<MutModifier>,
         pub identifier: IdentifierExpression<'ast>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
@@ -659,6 +1063,7 @@ mod ast {
         Call(CallAccess<'ast>),
         Select(ArrayAccess<'ast>),
         Member(MemberAccess<'ast>),
+        Element(ElementAccess<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -666,6 +1071,7 @@ mod ast {
     pub enum AssigneeAccess<'ast> {
         Select(ArrayAccess<'ast>),
         Member(MemberAccess<'ast>),
+        Element(ElementAccess<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -691,6 +1097,7 @@ mod ast {
         Value(LiteralExpression<'ast>),
         Identifier(IdentifierExpression<'ast>),
         Underscore(Underscore<'ast>),
+        Type(Type<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -724,6 +1131,14 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::element_access))]
+    pub struct ElementAccess<'ast> {
+        pub index: DecimalNumber<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, PartialEq, Clone)]
     pub struct BinaryExpression<'ast> {
         pub op: BinaryOperator,
@@ -739,6 +1154,13 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct CastExpression<'ast> {
+        pub expression: Box<Expression<'ast>>,
+        pub ty: Type<'ast>,
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::conditional_expression))]
     pub struct TernaryExpression<'ast> {
@@ -787,8 +1209,13 @@ mod ast {
                 Expression::Postfix(p) => &p.span,
                 Expression::InlineArray(a) => &a.span,
                 Expression::InlineStruct(s) => &s.span,
+                Expression::InlineTuple(t) => &t.span,
                 Expression::ArrayInitializer(a) => &a.span,
+                Expression::ArrayComprehension(a) => &a.span,
                 Expression::Unary(u) => &u.span,
+                Expression::Cast(c) => &c.span,
+                Expression::Match(m) => &m.span,
+                Expression::EnumValue(e) => &e.span,
             }
         }
     }
@@ -806,7 +1233,7 @@ mod ast {
             let pair = clone.next().ok_or(::from_pest::ConversionError::NoMatch)?;
             // this should be an expression
             match pair.as_rule() {
-                Rule::expression => {
+                Rule::expression | Rule::condition_expression => {
                     // we can replace `pest` with the clone we tried with and got pairs from to create the AST
                     *pest = clone;
                     Ok(*climb(pair))
@@ -822,6 +1249,8 @@ mod ast {
         DecimalLiteral(DecimalLiteralExpression<'ast>),
         BooleanLiteral(BooleanLiteralExpression<'ast>),
         HexLiteral(HexLiteralExpression<'ast>),
+        BinaryLiteral(BinaryLiteralExpression<'ast>),
+        StringLiteral(StringLiteralExpression<'ast>),
     }
 
     impl<'ast> LiteralExpression<'ast> {
@@ -830,6 +1259,8 @@ mod ast {
                 LiteralExpression::DecimalLiteral(n) => &n.span,
                 LiteralExpression::BooleanLiteral(c) => &c.span,
                 LiteralExpression::HexLiteral(h) => &h.span,
+                LiteralExpression::BinaryLiteral(b) => &b.span,
+                LiteralExpression::StringLiteral(s) => &s.span,
             }
         }
     }
@@ -841,6 +1272,11 @@ mod ast {
         U16(U16Suffix<'ast>),
         U32(U32Suffix<'ast>),
         U64(U64Suffix<'ast>),
+        U128(U128Suffix<'ast>),
+        I8(I8Suffix<'ast>),
+        I16(I16Suffix<'ast>),
+        I32(I32Suffix<'ast>),
+        I64(I64Suffix<'ast>),
         Field(FieldSuffix<'ast>),
     }
 
@@ -872,6 +1308,41 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_u128))]
+    pub struct U128Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i8))]
+    pub struct I8Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i16))]
+    pub struct I16Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i32))]
+    pub struct I32Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i64))]
+    pub struct I64Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::decimal_suffix_field))]
     pub struct FieldSuffix<'ast> {
@@ -905,25 +1376,21 @@ mod ast {
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::hex_literal))]
-    pub struct HexLiteralExpression<'ast> {
-        pub value: HexNumberExpression<'ast>,
+    #[pest_ast(rule(Rule::string_literal))]
+    pub struct StringLiteralExpression<'ast> {
+        pub prefix: Option<ByteStringPrefix>,
+        pub content: StringContent<'ast>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::hex_number))]
-    pub enum HexNumberExpression<'ast> {
-        U8(U8NumberExpression<'ast>),
-        U16(U16NumberExpression<'ast>),
-        U32(U32NumberExpression<'ast>),
-        U64(U64NumberExpression<'ast>),
-    }
+    #[pest_ast(rule(Rule::byte_string_prefix))]
+    pub struct ByteStringPrefix {}
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::hex_number_u8))]
-    pub struct U8NumberExpression<'ast> {
+    #[pest_ast(rule(Rule::string_content))]
+    pub struct StringContent<'ast> {
         #[pest_ast(outer(with(span_into_str)))]
         pub value: String,
         #[pest_ast(outer())]
@@ -931,28 +1398,33 @@ mod ast {
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::hex_number_u16))]
-    pub struct U16NumberExpression<'ast> {
-        #[pest_ast(outer(with(span_into_str)))]
-        pub value: String,
+    #[pest_ast(rule(Rule::hex_literal))]
+    pub struct HexLiteralExpression<'ast> {
+        pub value: HexNumber<'ast>,
+        pub suffix: Option<DecimalSuffix<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::hex_number_u32))]
-    pub struct U32NumberExpression<'ast> {
-        #[pest_ast(outer(with(span_into_str)))]
-        pub value: String,
+    #[pest_ast(rule(Rule::hex_number))]
+    pub struct HexNumber<'ast> {
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::hex_number_u64))]
-    pub struct U64NumberExpression<'ast> {
-        #[pest_ast(outer(with(span_into_str)))]
-        pub value: String,
+    #[pest_ast(rule(Rule::binary_literal))]
+    pub struct BinaryLiteralExpression<'ast> {
+        pub value: BinaryNumber<'ast>,
+        pub suffix: Option<DecimalSuffix<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::binary_number))]
+    pub struct BinaryNumber<'ast> {
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
@@ -979,6 +1451,11 @@ mod ast {
         span.as_str().to_string()
     }
 
+    fn span_into_unquoted_str(span: Span) -> String {
+        let s = span.as_str();
+        s[1..s.len() - 1].to_string()
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::EOI))]
     #[allow(clippy::upper_case_acronyms)]
@@ -1002,11 +1479,57 @@ impl fmt::Display for Error {
     }
 }
 
+impl Error {
+    /// The start and end of the input this error points to, as one-based lines and columns
+    pub fn line_col(&self) -> ((usize, usize), (usize, usize)) {
+        match self.0.line_col {
+            LineColLocation::Pos(pos) => (pos, pos),
+            LineColLocation::Span(start, end) => (start, end),
+        }
+    }
+
+    /// The description of this error, without the excerpt of the input
+    pub fn message(&self) -> String {
+        fn enumerate(rules: &[Rule]) -> String {
+            let rules: Vec<_> = rules.iter().map(|r| format!("{:?}", r)).collect();
+            match rules.len() {
+                1 => rules[0].clone(),
+                2 => format!("{} or {}", rules[0], rules[1]),
+                l => format!("{}, or {}", rules[..l - 1].join(", "), rules[l - 1]),
+            }
+        }
+
+        match &self.0.variant {
+            ErrorVariant::ParsingError {
+                positives,
+                negatives,
+            } => match (negatives.is_empty(), positives.is_empty()) {
+                (false, false) => format!(
+                    "unexpected {}; expected {}",
+                    enumerate(negatives),
+                    enumerate(positives)
+                ),
+                (false, true) => format!("unexpected {}", enumerate(negatives)),
+                (true, false) => format!("expected {}", enumerate(positives)),
+                (true, true) => "unknown parsing error".to_string(),
+            },
+            ErrorVariant::CustomError { message } => message.clone(),
+        }
+    }
+}
+
 pub fn generate_ast(input: &str) -> Result<ast::File, Error> {
     let parse_tree = parse(input).map_err(Error)?;
     Ok(Prog::from(parse_tree).0)
 }
 
+/// Find all syntax errors of `input`, recovering at declaration and statement boundaries. Along with the errors,
+/// return a copy of `input` in which the code which could not be parsed is blanked out, if it can be parsed
+pub fn recover(input: &str) -> (Vec<Error>, Option<String>) {
+    let (errors, source) = parse_with_recovery(input);
+    (errors.into_iter().map(Error).collect(), source)
+}
+
 #[cfg(test)]
 mod tests {
     use super::ast::*;
@@ -1073,7 +1596,7 @@ mod tests {
         assert_eq!(
             generate_ast(&source),
             Ok(File {
-                pragma: None,
+                pragmas: vec![],
                 declarations: vec![
                     SymbolDeclaration::Import(ImportDirective::Main(MainImportDirective {
                         source: ImportSource {
@@ -1084,6 +1607,7 @@ mod tests {
                         span: Span::new(&source, 0, 29).unwrap()
                     })),
                     SymbolDeclaration::Function(FunctionDefinition {
+                        public: None,
                         generics: vec![],
                         id: IdentifierExpression {
                             value: String::from("main"),
@@ -1134,7 +1658,7 @@ mod tests {
         assert_eq!(
             generate_ast(&source),
             Ok(File {
-                pragma: None,
+                pragmas: vec![],
                 declarations: vec![
                     SymbolDeclaration::Import(ImportDirective::Main(MainImportDirective {
                         source: ImportSource {
@@ -1145,6 +1669,7 @@ mod tests {
                         span: Span::new(&source, 0, 29).unwrap()
                     })),
                     SymbolDeclaration::Function(FunctionDefinition {
+                        public: None,
                         generics: vec![],
                         id: IdentifierExpression {
                             value: String::from("main"),
@@ -1219,7 +1744,7 @@ mod tests {
         assert_eq!(
             generate_ast(&source),
             Ok(File {
-                pragma: None,
+                pragmas: vec![],
                 declarations: vec![
                     SymbolDeclaration::Import(ImportDirective::Main(MainImportDirective {
                         source: ImportSource {
@@ -1230,6 +1755,7 @@ mod tests {
                         span: Span::new(&source, 0, 29).unwrap()
                     })),
                     SymbolDeclaration::Function(FunctionDefinition {
+                        public: None,
                         generics: vec![],
                         id: IdentifierExpression {
                             value: String::from("main"),
@@ -1288,8 +1814,9 @@ mod tests {
         assert_eq!(
             generate_ast(&source),
             Ok(File {
-                pragma: None,
+                pragmas: vec![],
                 declarations: vec![SymbolDeclaration::Function(FunctionDefinition {
+                    public: None,
                     generics: vec![],
                     id: IdentifierExpression {
                         value: String::from("main"),
@@ -1326,8 +1853,9 @@ mod tests {
         assert_eq!(
             generate_ast(&source),
             Ok(File {
-                pragma: None,
+                pragmas: vec![],
                 declarations: vec![SymbolDeclaration::Function(FunctionDefinition {
+                    public: None,
                     generics: vec![],
                     id: IdentifierExpression {
                         value: String::from("main"),
@@ -1343,6 +1871,7 @@ mod tests {
                                 ty: Type::Basic(BasicType::Field(FieldType {
                                     span: Span::new(&source, 23, 28).unwrap()
                                 })),
+                                mutable: None,
                                 identifier: IdentifierExpression {
                                     value: String::from("a"),
                                     span: Span::new(&source, 29, 30).unwrap(),
@@ -1414,6 +1943,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn error_location() {
+        let source = "def main() -> field:\n    return 1 + *\n";
+
+        let error = generate_ast(&source).unwrap_err();
+
+        assert_eq!((error.line_col().0).0, 2);
+        assert!(error.message().starts_with("expected"));
+    }
+
     #[test]
     fn playground() {
         let source = r#"import "foo" as bar